@@ -5,6 +5,7 @@ use clap::{ArgGroup, Args, Subcommand};
 use log::*;
 use nym_bandwidth_controller::acquire::state::State;
 use nym_bin_common::completions::ArgShell;
+use nym_bin_common::output_format::OutputFormat;
 use nym_credential_storage::persistent_storage::PersistentStorage;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 
@@ -16,6 +17,23 @@ pub(crate) enum Command {
     /// Run the binary to obtain a credential
     Run(Run),
 
+    /// List all credentials stored by the client
+    List(List),
+
+    /// Show details of a single stored credential
+    Show(Show),
+
+    /// Export unconsumed credentials into a passphrase-encrypted file. The passphrase is read from
+    /// the `NYM_CREDENTIAL_PASSPHRASE` environment variable or prompted for if it's not set
+    Export(Export),
+
+    /// Import credentials from a file created with the `export` command. The passphrase is read from
+    /// the `NYM_CREDENTIAL_PASSPHRASE` environment variable or prompted for if it's not set
+    Import(Import),
+
+    /// Remove consumed credentials and credentials that can no longer be spent
+    Purge(Purge),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -52,6 +70,90 @@ pub(crate) struct Run {
     pub(crate) recovery_mode: bool,
 }
 
+#[derive(Args)]
+pub(crate) struct List {
+    /// Home directory of the client whose credentials are to be listed.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Also include credentials that have already been consumed
+    #[clap(long)]
+    pub(crate) include_consumed: bool,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    pub(crate) output: OutputFormat,
+}
+
+#[derive(Args)]
+pub(crate) struct Show {
+    /// Home directory of the client holding the credential.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Id of the credential, as displayed by the `list` command
+    #[clap(long)]
+    pub(crate) id: i64,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    pub(crate) output: OutputFormat,
+}
+
+#[derive(Args)]
+pub(crate) struct Export {
+    /// Home directory of the client whose credentials are to be exported.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Path to the file the encrypted credentials will be written to
+    #[clap(long)]
+    pub(crate) output_file: std::path::PathBuf,
+
+    /// Remove the exported credentials from the client storage, so that they could not
+    /// be accidentally spent twice
+    #[clap(long)]
+    pub(crate) remove: bool,
+}
+
+#[derive(Args)]
+pub(crate) struct Import {
+    /// Home directory of the client that is supposed to use the imported credentials.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Path to the file created with the `export` command
+    #[clap(long)]
+    pub(crate) input_file: std::path::PathBuf,
+}
+
+#[derive(Args)]
+#[clap(group(
+ArgGroup::new("purge")
+.required(true)
+.multiple(true)
+.args(&["consumed", "stale"]),
+))]
+pub(crate) struct Purge {
+    /// Home directory of the client whose credentials are to be purged.
+    #[clap(long)]
+    pub(crate) client_home_directory: std::path::PathBuf,
+
+    /// Remove credentials that have already been consumed
+    #[clap(long)]
+    pub(crate) consumed: bool,
+
+    /// Remove credentials issued in a past DKG epoch whose verification keys are no longer
+    /// available, meaning the credentials can't be verified anymore
+    #[clap(long)]
+    pub(crate) stale: bool,
+
+    /// Only display the credentials that would have been removed
+    #[clap(long)]
+    pub(crate) dry_run: bool,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    pub(crate) output: OutputFormat,
+}
+
 pub(crate) async fn recover_credentials<C: DkgQueryClient + Send + Sync>(
     client: &C,
     recovery_storage: &RecoveryStorage,
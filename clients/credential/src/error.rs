@@ -33,4 +33,19 @@ pub enum CredentialClientError {
 
     #[error("Could not get system time")]
     SysTimeError(#[from] SystemTimeError),
+
+    #[error("Failed to encrypt or decrypt the credentials: {0}")]
+    StoreCipher(#[from] nym_store_cipher::Error),
+
+    #[error("Failed to (de)serialize the credentials: {0}")]
+    Serde(#[from] serde_json::Error),
+
+    #[error("There is no credential with id {id}")]
+    CredentialNotFound { id: i64 },
+
+    #[error("The export file {path:?} already exists")]
+    ExportFileExists { path: std::path::PathBuf },
+
+    #[error("The provided passphrases do not match")]
+    PassphraseMismatch,
 }
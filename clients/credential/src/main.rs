@@ -3,20 +3,19 @@
 
 mod commands;
 mod error;
+mod management;
 mod recovery_storage;
 
 use commands::*;
 use error::Result;
 use log::*;
 use nym_bin_common::completions::fig_generate;
-use nym_config::DEFAULT_DATA_DIR;
 use nym_network_defaults::{setup_env, NymNetworkDetails};
 use std::process::exit;
 use std::time::{Duration, SystemTime};
 
 use clap::{CommandFactory, Parser};
 use nym_bin_common::logging::setup_logging;
-use nym_client_core::config::disk_persistence::CommonClientPaths;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 use nym_validator_client::nyxd::{Coin, CosmWasmClient};
 use nym_validator_client::Config;
@@ -72,13 +71,7 @@ async fn main() -> Result<()> {
 
     match args.command {
         Command::Run(r) => {
-            // we assume the structure of <home-dir>/data
-            let data_dir = r.client_home_directory.join(DEFAULT_DATA_DIR);
-            let paths = CommonClientPaths::new_default(data_dir);
-            let db_path = paths.credentials_database;
-
-            let shared_storage =
-                nym_credential_storage::initialise_persistent_storage(db_path).await;
+            let shared_storage = management::open_storage(&r.client_home_directory).await;
             let recovery_storage = recovery_storage::RecoveryStorage::new(r.recovery_dir)?;
 
             let network_details = NymNetworkDetails::new_from_env();
@@ -120,6 +113,18 @@ async fn main() -> Result<()> {
                 recover_credentials(&client.nyxd, &recovery_storage, &shared_storage).await?;
             }
         }
+        Command::List(l) => management::list_credentials(l).await?,
+        Command::Show(s) => management::show_credential(s).await?,
+        Command::Export(e) => management::export_credentials(e).await?,
+        Command::Import(i) => management::import_credentials(i).await?,
+        Command::Purge(p) => {
+            let network_details = NymNetworkDetails::new_from_env();
+            let config = Config::try_from_nym_network_details(&network_details).expect(
+                "failed to construct valid validator client config with the provided network",
+            );
+            let client = nym_validator_client::Client::new_query(config)?;
+            management::purge_credentials(p, &client.nyxd).await?
+        }
         Command::Completions(c) => c.generate(&mut Cli::command(), bin_name),
         Command::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
@@ -0,0 +1,628 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use log::*;
+use nym_client_core::config::disk_persistence::CommonClientPaths;
+use nym_config::DEFAULT_DATA_DIR;
+use nym_credential_storage::error::StorageError;
+use nym_credential_storage::models::CoconutCredential;
+use nym_credential_storage::persistent_storage::PersistentStorage;
+use nym_credential_storage::storage::Storage;
+use nym_store_cipher::{EncryptedData, ExportedStoreCipher, KdfInfo, StoreCipher};
+use nym_validator_client::nyxd::traits::DkgQueryClient;
+use nym_validator_client::CoconutApiClient;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+use crate::commands::{Export, Import, List, Purge, Show};
+use crate::error::{CredentialClientError, Result};
+
+pub(crate) async fn open_storage<P: AsRef<Path>>(client_home_directory: P) -> PersistentStorage {
+    // we assume the structure of <home-dir>/data
+    let data_dir = client_home_directory.as_ref().join(DEFAULT_DATA_DIR);
+    let paths = CommonClientPaths::new_default(data_dir);
+    nym_credential_storage::initialise_persistent_storage(paths.credentials_database).await
+}
+
+// the passphrase is never accepted as an argument so that it wouldn't end up
+// in the shell history or the process list
+const PASSPHRASE_ENV: &str = "NYM_CREDENTIAL_PASSPHRASE";
+
+fn read_passphrase(confirm: bool) -> Result<String> {
+    if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+        return Ok(passphrase);
+    }
+
+    let passphrase = rpassword::prompt_password("Enter the passphrase: ")?;
+    if confirm && rpassword::prompt_password("Confirm the passphrase: ")? != passphrase {
+        return Err(CredentialClientError::PassphraseMismatch);
+    }
+    Ok(passphrase)
+}
+
+#[derive(Serialize)]
+struct CredentialEntry {
+    id: i64,
+    value: String,
+    info: String,
+    epoch_id: String,
+    consumed: bool,
+}
+
+impl From<&CoconutCredential> for CredentialEntry {
+    fn from(credential: &CoconutCredential) -> Self {
+        CredentialEntry {
+            id: credential.id,
+            value: credential.voucher_value.clone(),
+            info: credential.voucher_info.clone(),
+            epoch_id: credential.epoch_id.clone(),
+            consumed: credential.consumed,
+        }
+    }
+}
+
+impl Display for CredentialEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {} ({}) issued in epoch {}",
+            self.id, self.value, self.info, self.epoch_id
+        )?;
+        if self.consumed {
+            write!(f, " [consumed]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct CredentialList {
+    credentials: Vec<CredentialEntry>,
+}
+
+impl Display for CredentialList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.credentials.is_empty() {
+            return write!(f, "no credentials");
+        }
+        for (i, credential) in self.credentials.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{credential}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct CredentialDetails {
+    #[serde(flatten)]
+    entry: CredentialEntry,
+    signature: String,
+}
+
+impl Display for CredentialDetails {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "id: {}", self.entry.id)?;
+        writeln!(f, "value: {}", self.entry.value)?;
+        writeln!(f, "type: {}", self.entry.info)?;
+        writeln!(f, "epoch: {}", self.entry.epoch_id)?;
+        writeln!(f, "consumed: {}", self.entry.consumed)?;
+        write!(f, "signature: {}", self.signature)
+    }
+}
+
+// the secret parts of the credential (serial and binding numbers) are only ever written to disk
+// in the encrypted form
+#[derive(Serialize, Deserialize)]
+struct ExportedCredential {
+    voucher_value: String,
+    voucher_info: String,
+    serial_number: String,
+    binding_number: String,
+    signature: String,
+    epoch_id: String,
+}
+
+impl From<CoconutCredential> for ExportedCredential {
+    fn from(credential: CoconutCredential) -> Self {
+        ExportedCredential {
+            voucher_value: credential.voucher_value,
+            voucher_info: credential.voucher_info,
+            serial_number: credential.serial_number,
+            binding_number: credential.binding_number,
+            signature: credential.signature,
+            epoch_id: credential.epoch_id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CredentialsExportFile {
+    cipher: ExportedStoreCipher,
+    credentials: EncryptedData,
+}
+
+#[derive(Serialize)]
+struct PurgeResult {
+    dry_run: bool,
+    removed: Vec<CredentialEntry>,
+}
+
+impl Display for PurgeResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let verb = if self.dry_run {
+            "would be removed"
+        } else {
+            "removed"
+        };
+        write!(f, "{} credential(s) {verb}", self.removed.len())?;
+        for credential in &self.removed {
+            write!(f, "\n{credential}")?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) async fn list_credentials(args: List) -> Result<()> {
+    let storage = open_storage(&args.client_home_directory).await;
+    let credentials = storage
+        .get_all_coconut_credentials()
+        .await?
+        .iter()
+        .filter(|credential| args.include_consumed || !credential.consumed)
+        .map(Into::into)
+        .collect();
+
+    println!("{}", args.output.format(&CredentialList { credentials }));
+    Ok(())
+}
+
+pub(crate) async fn show_credential(args: Show) -> Result<()> {
+    let storage = open_storage(&args.client_home_directory).await;
+    let credential = storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .find(|credential| credential.id == args.id)
+        .ok_or(CredentialClientError::CredentialNotFound { id: args.id })?;
+
+    let details = CredentialDetails {
+        entry: (&credential).into(),
+        signature: credential.signature,
+    };
+    println!("{}", args.output.format(&details));
+    Ok(())
+}
+
+pub(crate) async fn export_credentials(args: Export) -> Result<()> {
+    if args.output_file.exists() {
+        return Err(CredentialClientError::ExportFileExists {
+            path: args.output_file,
+        });
+    }
+
+    let passphrase = read_passphrase(true)?;
+    let storage = open_storage(&args.client_home_directory).await;
+    export_stored_credentials(&storage, &args, &passphrase).await
+}
+
+async fn export_stored_credentials<S>(storage: &S, args: &Export, passphrase: &str) -> Result<()>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    let credentials: Vec<_> = storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .filter(|credential| !credential.consumed)
+        .collect();
+    let ids: Vec<_> = credentials.iter().map(|credential| credential.id).collect();
+    let exported: Vec<ExportedCredential> = credentials.into_iter().map(Into::into).collect();
+
+    let cipher =
+        StoreCipher::new_aes256gcm(passphrase.as_bytes(), KdfInfo::new_with_default_settings()?)?;
+    let export_file = CredentialsExportFile {
+        cipher: cipher.export_aes256gcm()?,
+        credentials: cipher.encrypt_json_value(&exported)?,
+    };
+
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&args.output_file)?;
+    serde_json::to_writer_pretty(file, &export_file)?;
+    info!(
+        "Exported {} credential(s) to {:?}",
+        exported.len(),
+        args.output_file
+    );
+
+    if args.remove {
+        for id in ids {
+            storage.remove_coconut_credential(id).await?;
+        }
+        info!("Removed the exported credentials from the client storage");
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn import_credentials(args: Import) -> Result<()> {
+    let passphrase = read_passphrase(false)?;
+    let storage = open_storage(&args.client_home_directory).await;
+    let imported = import_into_storage(&storage, &args, &passphrase).await?;
+    info!("Imported {imported} credential(s)");
+
+    Ok(())
+}
+
+async fn import_into_storage<S>(storage: &S, args: &Import, passphrase: &str) -> Result<usize>
+where
+    S: Storage<StorageError = StorageError>,
+{
+    let export_file: CredentialsExportFile =
+        serde_json::from_reader(File::open(&args.input_file)?)?;
+    let cipher = StoreCipher::import_aes256gcm(passphrase.as_bytes(), export_file.cipher)?;
+    let credentials: Vec<ExportedCredential> =
+        cipher.decrypt_json_value(export_file.credentials)?;
+
+    let mut known_signatures: HashSet<_> = storage
+        .get_all_coconut_credentials()
+        .await?
+        .into_iter()
+        .map(|credential| credential.signature)
+        .collect();
+
+    let mut imported = 0;
+    for credential in credentials {
+        if !known_signatures.insert(credential.signature.clone()) {
+            warn!(
+                "Credential worth {} issued in epoch {} is already present in the storage - skipping it",
+                credential.voucher_value, credential.epoch_id
+            );
+            continue;
+        }
+        storage
+            .insert_coconut_credential(
+                credential.voucher_value,
+                credential.voucher_info,
+                credential.serial_number,
+                credential.binding_number,
+                credential.signature,
+                credential.epoch_id,
+            )
+            .await?;
+        imported += 1;
+    }
+
+    Ok(imported)
+}
+
+// A credential issued in a past epoch can only be verified for as long as the verification keys
+// of that epoch are still published in the DKG contract.
+async fn stale_epochs<C>(client: &C, credentials: &[CoconutCredential]) -> Result<HashSet<String>>
+where
+    C: DkgQueryClient + Send + Sync,
+{
+    let current_epoch = client.get_current_epoch().await?.epoch_id;
+    let mut usable = HashMap::new();
+    for credential in credentials {
+        if usable.contains_key(&credential.epoch_id) {
+            continue;
+        }
+        let is_usable = match credential.epoch_id.parse() {
+            Ok(epoch_id) if epoch_id == current_epoch => true,
+            Ok(epoch_id) => !CoconutApiClient::all_coconut_api_clients(client, epoch_id)
+                .await?
+                .is_empty(),
+            Err(_) => {
+                warn!(
+                    "Credential {} has an invalid epoch id '{}'",
+                    credential.id, credential.epoch_id
+                );
+                false
+            }
+        };
+        usable.insert(credential.epoch_id.clone(), is_usable);
+    }
+
+    Ok(usable
+        .into_iter()
+        .filter(|(_, is_usable)| !is_usable)
+        .map(|(epoch_id, _)| epoch_id)
+        .collect())
+}
+
+pub(crate) async fn purge_credentials<C>(args: Purge, client: &C) -> Result<()>
+where
+    C: DkgQueryClient + Send + Sync,
+{
+    let storage = open_storage(&args.client_home_directory).await;
+    let result = purge_stored_credentials(&storage, &args, client).await?;
+    println!("{}", args.output.format(&result));
+    Ok(())
+}
+
+async fn purge_stored_credentials<S, C>(
+    storage: &S,
+    args: &Purge,
+    client: &C,
+) -> Result<PurgeResult>
+where
+    S: Storage<StorageError = StorageError>,
+    C: DkgQueryClient + Send + Sync,
+{
+    let credentials = storage.get_all_coconut_credentials().await?;
+
+    let stale = if args.stale {
+        stale_epochs(client, &credentials).await?
+    } else {
+        HashSet::new()
+    };
+
+    let to_remove: Vec<_> = credentials
+        .iter()
+        .filter(|credential| {
+            (args.consumed && credential.consumed) || stale.contains(&credential.epoch_id)
+        })
+        .collect();
+
+    if !args.dry_run {
+        for credential in &to_remove {
+            storage.remove_coconut_credential(credential.id).await?;
+        }
+    }
+
+    Ok(PurgeResult {
+        dry_run: args.dry_run,
+        removed: to_remove.into_iter().map(Into::into).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use nym_bin_common::output_format::OutputFormat;
+    use nym_coconut_dkg_common::msg::QueryMsg as DkgQueryMsg;
+    use nym_coconut_dkg_common::types::{Epoch, EpochId};
+    use nym_coconut_dkg_common::verification_key::PagedVKSharesResponse;
+    use nym_credential_storage::ephemeral_storage::EphemeralStorage;
+    use nym_validator_client::nyxd::error::NyxdError;
+    use std::path::PathBuf;
+
+    const PASSPHRASE: &str = "my-secret-passphrase";
+
+    // none of the past epochs has its verification keys published anymore
+    struct MockDkgClient {
+        current_epoch: EpochId,
+    }
+
+    #[async_trait]
+    impl DkgQueryClient for MockDkgClient {
+        async fn query_dkg_contract<T>(
+            &self,
+            query: DkgQueryMsg,
+        ) -> std::result::Result<T, NyxdError>
+        where
+            for<'a> T: Deserialize<'a>,
+        {
+            let response = match query {
+                DkgQueryMsg::GetCurrentEpochState {} => serde_json::to_value(Epoch {
+                    epoch_id: self.current_epoch,
+                    ..Default::default()
+                }),
+                DkgQueryMsg::GetVerificationKeys { .. } => {
+                    serde_json::to_value(PagedVKSharesResponse {
+                        shares: Vec::new(),
+                        per_page: 10,
+                        start_next_after: None,
+                    })
+                }
+                other => panic!("unexpected dkg query: {other:?}"),
+            };
+            Ok(serde_json::from_value(response.unwrap()).unwrap())
+        }
+    }
+
+    async fn insert_credential(storage: &EphemeralStorage, signature: &str, epoch_id: &str) {
+        storage
+            .insert_coconut_credential(
+                "1000".to_string(),
+                "BandwidthVoucher".to_string(),
+                format!("serial-{signature}"),
+                format!("binding-{signature}"),
+                signature.to_string(),
+                epoch_id.to_string(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn stored_signatures(storage: &EphemeralStorage) -> Vec<String> {
+        storage
+            .get_all_coconut_credentials()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|credential| credential.signature)
+            .collect()
+    }
+
+    fn purge_args(consumed: bool, stale: bool, dry_run: bool) -> Purge {
+        Purge {
+            client_home_directory: PathBuf::new(),
+            consumed,
+            stale,
+            dry_run,
+            output: OutputFormat::default(),
+        }
+    }
+
+    fn export_args(output_file: PathBuf, remove: bool) -> Export {
+        Export {
+            client_home_directory: PathBuf::new(),
+            output_file,
+            remove,
+        }
+    }
+
+    fn import_args(input_file: PathBuf) -> Import {
+        Import {
+            client_home_directory: PathBuf::new(),
+            input_file,
+        }
+    }
+
+    #[tokio::test]
+    async fn purging_removes_consumed_and_stale_credentials() {
+        let client = MockDkgClient { current_epoch: 5 };
+        let storage = nym_credential_storage::initialise_ephemeral_storage();
+        insert_credential(&storage, "current", "5").await;
+        insert_credential(&storage, "consumed", "5").await;
+        insert_credential(&storage, "stale", "3").await;
+        insert_credential(&storage, "invalid-epoch", "foomp").await;
+        storage.consume_coconut_credential(1).await.unwrap();
+
+        let result = purge_stored_credentials(&storage, &purge_args(true, false, false), &client)
+            .await
+            .unwrap();
+        let removed: Vec<_> = result.removed.iter().map(|entry| entry.id).collect();
+        assert_eq!(removed, vec![1]);
+        assert_eq!(
+            stored_signatures(&storage).await,
+            vec!["current", "stale", "invalid-epoch"]
+        );
+
+        // dry run only reports the credentials
+        let result = purge_stored_credentials(&storage, &purge_args(false, true, true), &client)
+            .await
+            .unwrap();
+        let removed: Vec<_> = result.removed.iter().map(|entry| entry.id).collect();
+        assert_eq!(removed, vec![2, 3]);
+        assert_eq!(stored_signatures(&storage).await.len(), 3);
+
+        let result = purge_stored_credentials(&storage, &purge_args(false, true, false), &client)
+            .await
+            .unwrap();
+        assert_eq!(result.removed.len(), 2);
+        assert_eq!(stored_signatures(&storage).await, vec!["current"]);
+    }
+
+    #[tokio::test]
+    async fn exported_credentials_can_be_imported() {
+        let dir = tempfile::tempdir().unwrap();
+        let export_file = dir.path().join("credentials.json");
+
+        let source = nym_credential_storage::initialise_ephemeral_storage();
+        insert_credential(&source, "first", "1").await;
+        insert_credential(&source, "second", "1").await;
+        insert_credential(&source, "spent", "1").await;
+        source.consume_coconut_credential(2).await.unwrap();
+
+        export_stored_credentials(
+            &source,
+            &export_args(export_file.clone(), false),
+            PASSPHRASE,
+        )
+        .await
+        .unwrap();
+        // without `--remove` the credentials are kept in the source storage
+        assert_eq!(stored_signatures(&source).await.len(), 3);
+
+        // the secrets never get written in plaintext
+        let raw = std::fs::read_to_string(&export_file).unwrap();
+        assert!(!raw.contains("serial-first"));
+
+        let target = nym_credential_storage::initialise_ephemeral_storage();
+        assert!(import_into_storage(
+            &target,
+            &import_args(export_file.clone()),
+            "wrong-passphrase"
+        )
+        .await
+        .is_err());
+        assert!(stored_signatures(&target).await.is_empty());
+
+        let imported = import_into_storage(&target, &import_args(export_file), PASSPHRASE)
+            .await
+            .unwrap();
+        assert_eq!(imported, 2);
+
+        let original = source.get_all_coconut_credentials().await.unwrap();
+        let imported = target.get_all_coconut_credentials().await.unwrap();
+        assert_eq!(imported.len(), 2);
+        for (original, imported) in original.iter().zip(imported.iter()) {
+            assert_eq!(original.voucher_value, imported.voucher_value);
+            assert_eq!(original.voucher_info, imported.voucher_info);
+            assert_eq!(original.serial_number, imported.serial_number);
+            assert_eq!(original.binding_number, imported.binding_number);
+            assert_eq!(original.signature, imported.signature);
+            assert_eq!(original.epoch_id, imported.epoch_id);
+            assert!(!imported.consumed);
+        }
+    }
+
+    #[tokio::test]
+    async fn importing_skips_already_known_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let export_file = dir.path().join("credentials.json");
+
+        let source = nym_credential_storage::initialise_ephemeral_storage();
+        insert_credential(&source, "known", "1").await;
+        insert_credential(&source, "new", "1").await;
+        export_stored_credentials(
+            &source,
+            &export_args(export_file.clone(), false),
+            PASSPHRASE,
+        )
+        .await
+        .unwrap();
+
+        let target = nym_credential_storage::initialise_ephemeral_storage();
+        insert_credential(&target, "known", "1").await;
+
+        let imported = import_into_storage(&target, &import_args(export_file.clone()), PASSPHRASE)
+            .await
+            .unwrap();
+        assert_eq!(imported, 1);
+        assert_eq!(stored_signatures(&target).await, vec!["known", "new"]);
+
+        // importing the same file again is a no-op
+        let imported = import_into_storage(&target, &import_args(export_file), PASSPHRASE)
+            .await
+            .unwrap();
+        assert_eq!(imported, 0);
+        assert_eq!(stored_signatures(&target).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn exporting_with_remove_deletes_the_exported_credentials() {
+        let dir = tempfile::tempdir().unwrap();
+        let export_file = dir.path().join("credentials.json");
+
+        let source = nym_credential_storage::initialise_ephemeral_storage();
+        insert_credential(&source, "first", "1").await;
+        insert_credential(&source, "spent", "1").await;
+        insert_credential(&source, "second", "1").await;
+        source.consume_coconut_credential(1).await.unwrap();
+
+        export_stored_credentials(&source, &export_args(export_file.clone(), true), PASSPHRASE)
+            .await
+            .unwrap();
+        // consumed credentials are neither exported nor removed
+        assert_eq!(stored_signatures(&source).await, vec!["spent"]);
+
+        let target = nym_credential_storage::initialise_ephemeral_storage();
+        let imported = import_into_storage(&target, &import_args(export_file), PASSPHRASE)
+            .await
+            .unwrap();
+        assert_eq!(imported, 2);
+        assert_eq!(stored_signatures(&target).await, vec!["first", "second"]);
+    }
+}
@@ -79,6 +79,7 @@ impl SocketClient {
         let ClientState {
             shared_lane_queue_lengths,
             reply_controller_sender,
+            name_resolver,
             ..
         } = client_state;
 
@@ -89,6 +90,7 @@ impl SocketClient {
             self_address,
             shared_lane_queue_lengths,
             reply_controller_sender,
+            name_resolver,
             Some(packet_type),
         );
 
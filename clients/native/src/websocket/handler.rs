@@ -4,6 +4,7 @@
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
 use log::*;
+use nym_client_core::client::name_resolver::{Destination, NameResolver};
 use nym_client_core::client::replies::reply_controller::requests::ReplyControllerSender;
 use nym_client_core::client::{
     inbound_messages::{InputMessage, InputMessageSender},
@@ -11,7 +12,11 @@ use nym_client_core::client::{
         ReceivedBufferMessage, ReceivedBufferRequestSender, ReconstructedMessagesReceiver,
     },
 };
-use nym_client_websocket_requests::{requests::ClientRequest, responses::ServerResponse};
+use nym_client_websocket_requests::{
+    error::{Error as RequestError, ErrorKind},
+    requests::ClientRequest,
+    responses::ServerResponse,
+};
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
 use nym_sphinx::params::PacketType;
@@ -42,6 +47,7 @@ pub(crate) struct HandlerBuilder {
     self_full_address: Recipient,
     lane_queue_lengths: LaneQueueLengths,
     reply_controller_sender: ReplyControllerSender,
+    name_resolver: NameResolver,
     packet_type: Option<PacketType>,
 }
 
@@ -53,6 +59,7 @@ impl HandlerBuilder {
         self_full_address: &Recipient,
         lane_queue_lengths: LaneQueueLengths,
         reply_controller_sender: ReplyControllerSender,
+        name_resolver: NameResolver,
         packet_type: Option<PacketType>,
     ) -> Self {
         Self {
@@ -62,6 +69,7 @@ impl HandlerBuilder {
             self_full_address: *self_full_address,
             lane_queue_lengths,
             reply_controller_sender,
+            name_resolver,
             packet_type,
         }
     }
@@ -77,6 +85,7 @@ impl HandlerBuilder {
             received_response_type: Default::default(),
             lane_queue_lengths: self.lane_queue_lengths.clone(),
             reply_controller_sender: self.reply_controller_sender.clone(),
+            name_resolver: self.name_resolver.clone(),
             packet_type: self.packet_type,
         }
     }
@@ -91,6 +100,7 @@ pub(crate) struct Handler {
     received_response_type: ReceivedResponseType,
     lane_queue_lengths: LaneQueueLengths,
     reply_controller_sender: ReplyControllerSender,
+    name_resolver: NameResolver,
     packet_type: Option<PacketType>,
 }
 
@@ -180,6 +190,32 @@ impl Handler {
         self.get_lane_queue_length(connection_id).await
     }
 
+    async fn handle_send_to_name(
+        &mut self,
+        name: String,
+        message: Vec<u8>,
+        connection_id: Option<u64>,
+    ) -> Option<ServerResponse> {
+        let resolved = match name.parse::<Destination>() {
+            Ok(destination) => self.name_resolver.resolve(&destination).await,
+            Err(err) => Err(err),
+        };
+
+        match resolved {
+            Ok(recipient) => {
+                debug!("resolved '{name}' into {recipient}");
+                self.handle_send(recipient, message, connection_id).await
+            }
+            Err(err) => {
+                warn!("failed to resolve '{name}': {err}");
+                Some(ServerResponse::Error(RequestError::new(
+                    ErrorKind::NameResolutionFailure,
+                    err.to_string(),
+                )))
+            }
+        }
+    }
+
     async fn handle_send_anonymous(
         &mut self,
         recipient: Recipient,
@@ -281,6 +317,12 @@ impl Handler {
             ClientRequest::SelfAddress => Some(self.handle_self_address()),
             ClientRequest::ClosedConnection(id) => self.handle_closed_connection(id),
             ClientRequest::GetLaneQueueLength(id) => self.handle_get_lane_queue_length(id).await,
+
+            ClientRequest::SendToName {
+                name,
+                message,
+                connection_id,
+            } => self.handle_send_to_name(name, message, connection_id).await,
         }
     }
 
@@ -47,6 +47,9 @@ pub enum ErrorKind {
     /// The received request is malformed.
     MalformedRequest = 0x04,
 
+    /// The recipient name specified in the request could not be resolved into a nym address.
+    NameResolutionFailure = 0x05,
+
     // that's an arbitrary division but let's keep 1-127 (hex 0x01 - 0x7F) values request-specific
     // and 128-254 (hex 0x80 - 0xFE) for responses
     /// The received response contained no data.
@@ -74,6 +77,9 @@ impl TryFrom<u8> for ErrorKind {
             _ if value == (ErrorKind::TooShortRequest as u8) => Ok(ErrorKind::TooShortRequest),
             _ if value == (ErrorKind::UnknownRequest as u8) => Ok(ErrorKind::UnknownRequest),
             _ if value == (ErrorKind::MalformedRequest as u8) => Ok(ErrorKind::MalformedRequest),
+            _ if value == (ErrorKind::NameResolutionFailure as u8) => {
+                Ok(ErrorKind::NameResolutionFailure)
+            }
 
             _ if value == (ErrorKind::EmptyResponse as u8) => Ok(ErrorKind::EmptyResponse),
             _ if value == (ErrorKind::TooShortResponse as u8) => Ok(ErrorKind::TooShortResponse),
@@ -97,6 +103,7 @@ impl ErrorKind {
             ErrorKind::TooShortRequest => "received request did not contain enough data",
             ErrorKind::UnknownRequest => "unknown request type",
             ErrorKind::MalformedRequest => "malformed request",
+            ErrorKind::NameResolutionFailure => "failed to resolve the recipient name",
 
             ErrorKind::EmptyResponse => "received response contained no data",
             ErrorKind::TooShortResponse => "received response did not contain enough data",
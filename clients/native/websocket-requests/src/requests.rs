@@ -30,6 +30,9 @@ enum ClientRequestTag {
 
     /// Value tag representing [`GetLaneQueueLength`] variant of the [`ClientRequest`]
     GetLaneQueueLength = 0x05,
+
+    /// Value tag representing [`SendToName`] variant of the [`ClientRequest`]
+    SendToName = 0x06,
 }
 
 impl TryFrom<u8> for ClientRequestTag {
@@ -43,6 +46,7 @@ impl TryFrom<u8> for ClientRequestTag {
             _ if value == (Self::SelfAddress as u8) => Ok(Self::SelfAddress),
             _ if value == (Self::ClosedConnection as u8) => Ok(Self::ClosedConnection),
             _ if value == (Self::GetLaneQueueLength as u8) => Ok(Self::GetLaneQueueLength),
+            _ if value == (Self::SendToName as u8) => Ok(Self::SendToName),
             n => Err(error::Error::new(
                 ErrorKind::UnknownRequest,
                 format!("{n} does not correspond to any valid request tag"),
@@ -94,6 +98,16 @@ pub enum ClientRequest {
     ClosedConnection(u64),
 
     GetLaneQueueLength(u64),
+
+    /// Equivalent of the [`Send`] variant, but the recipient is specified with a name registered
+    /// in the name service that the client is going to resolve into the actual nym address.
+    ///
+    /// Ends up with `NymMessage::Plain` variant
+    SendToName {
+        name: String,
+        message: Vec<u8>,
+        connection_id: Option<u64>,
+    },
 }
 
 // we could have been parsing it directly TryFrom<WsMessage>, but we want to retain
@@ -382,6 +396,85 @@ impl ClientRequest {
         Ok(ClientRequest::GetLaneQueueLength(connection_id))
     }
 
+    // SEND_TO_NAME_REQUEST_TAG || name_len || name || conn_id || data_len || data
+    fn serialize_send_to_name(name: String, data: Vec<u8>, connection_id: Option<u64>) -> Vec<u8> {
+        let name_len_bytes = (name.len() as u64).to_be_bytes();
+        let data_len_bytes = (data.len() as u64).to_be_bytes();
+        let conn_id_bytes = connection_id.unwrap_or(0).to_be_bytes();
+
+        std::iter::once(ClientRequestTag::SendToName as u8)
+            .chain(name_len_bytes.into_iter())
+            .chain(name.into_bytes().into_iter())
+            .chain(conn_id_bytes.into_iter())
+            .chain(data_len_bytes.into_iter())
+            .chain(data.into_iter())
+            .collect()
+    }
+
+    // SEND_TO_NAME_REQUEST_TAG || name_len || name || conn_id || data_len || data
+    fn deserialize_send_to_name(b: &[u8]) -> Result<Self, error::Error> {
+        // we need to have at least 1 (tag) + 3*sizeof<u64> bytes
+        if b.len() < 1 + 3 * size_of::<u64>() {
+            return Err(error::Error::new(
+                ErrorKind::TooShortRequest,
+                "not enough data provided to recover 'send_to_name'".to_string(),
+            ));
+        }
+
+        // this MUST match because it was called by 'deserialize'
+        debug_assert_eq!(b[0], ClientRequestTag::SendToName as u8);
+
+        let name_len = u64::from_be_bytes(b[1..1 + size_of::<u64>()].try_into().unwrap()) as usize;
+        let name_end = 1 + size_of::<u64>() + name_len;
+        if b.len() < name_end + 2 * size_of::<u64>() {
+            return Err(error::Error::new(
+                ErrorKind::TooShortRequest,
+                "not enough data provided to recover 'send_to_name'".to_string(),
+            ));
+        }
+
+        let name = match String::from_utf8(b[1 + size_of::<u64>()..name_end].to_vec()) {
+            Ok(name) => name,
+            Err(err) => {
+                return Err(error::Error::new(
+                    ErrorKind::MalformedRequest,
+                    format!("malformed name: {err}"),
+                ))
+            }
+        };
+
+        let connection_id =
+            u64::from_be_bytes(b[name_end..name_end + size_of::<u64>()].try_into().unwrap());
+        let connection_id = if connection_id == 0 {
+            None
+        } else {
+            Some(connection_id)
+        };
+
+        let data_len = u64::from_be_bytes(
+            b[name_end + size_of::<u64>()..name_end + 2 * size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        );
+        let data = &b[name_end + 2 * size_of::<u64>()..];
+        if data.len() as u64 != data_len {
+            return Err(error::Error::new(
+                ErrorKind::MalformedRequest,
+                format!(
+                    "data len has inconsistent length. specified: {} got: {}",
+                    data_len,
+                    data.len()
+                ),
+            ));
+        }
+
+        Ok(ClientRequest::SendToName {
+            name,
+            message: data.to_vec(),
+            connection_id,
+        })
+    }
+
     pub fn serialize(self) -> Vec<u8> {
         match self {
             ClientRequest::Send {
@@ -408,6 +501,12 @@ impl ClientRequest {
             ClientRequest::ClosedConnection(id) => Self::serialize_closed_connection(id),
 
             ClientRequest::GetLaneQueueLength(id) => Self::serialize_get_lane_queue_lengths(id),
+
+            ClientRequest::SendToName {
+                name,
+                message,
+                connection_id,
+            } => Self::serialize_send_to_name(name, message, connection_id),
         }
     }
 
@@ -431,6 +530,7 @@ impl ClientRequest {
             ClientRequestTag::SelfAddress => Self::deserialize_self_address(b),
             ClientRequestTag::ClosedConnection => Self::deserialize_closed_connection(b),
             ClientRequestTag::GetLaneQueueLength => Self::deserialize_get_lane_queue_length(b),
+            ClientRequestTag::SendToName => Self::deserialize_send_to_name(b),
         }
     }
 
@@ -480,6 +580,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn send_to_name_request_serialization_works() {
+        let send_request = ClientRequest::SendToName {
+            name: "foomp-service".to_string(),
+            message: b"foomp".to_vec(),
+            connection_id: Some(42),
+        };
+
+        let bytes = send_request.serialize();
+        let recovered = ClientRequest::deserialize(&bytes).unwrap();
+        match recovered {
+            ClientRequest::SendToName {
+                name,
+                message,
+                connection_id,
+            } => {
+                assert_eq!(name, "foomp-service");
+                assert_eq!(message, b"foomp".to_vec());
+                assert_eq!(connection_id, Some(42))
+            }
+            _ => unreachable!(),
+        }
+
+        // make sure we don't panic on truncated names
+        let mut bytes = ClientRequest::SendToName {
+            name: "foomp-service".to_string(),
+            message: Vec::new(),
+            connection_id: None,
+        }
+        .serialize();
+        bytes.truncate(12);
+        assert!(ClientRequest::deserialize(&bytes).is_err());
+    }
+
     #[test]
     fn send_anonymous_request_serialization_works() {
         let original_recipient = Recipient::try_from_base58_string("CytBseW6yFXUMzz4SGAKdNLGR7q3sJLLYxyBGvutNEQV.4QXYyEVc5fUDjmmi8PrHN9tdUFV4PCvSJE1278cHyvoe@4sBbL1ngf1vtNqykydQKTFh26sQCw888GpUqvPvyNB4f").unwrap();
@@ -56,6 +56,16 @@ impl TryInto<ClientRequest> for ClientRequestText {
                 connection_id,
             } => {
                 let message_bytes = message.into_bytes();
+
+                // names registered with the name service can't contain '@', unlike nym addresses
+                if !recipient.contains('@') {
+                    return Ok(ClientRequest::SendToName {
+                        name: recipient,
+                        message: message_bytes,
+                        connection_id,
+                    });
+                }
+
                 let recipient = Recipient::try_from_base58_string(recipient).map_err(|err| {
                     Self::Error::new(ErrorKind::MalformedRequest, err.to_string())
                 })?;
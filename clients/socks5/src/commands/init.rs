@@ -12,6 +12,7 @@ use crate::{
 use clap::Args;
 use nym_bin_common::output_format::OutputFormat;
 use nym_client_core::client::key_manager::persistence::OnDiskKeys;
+use nym_client_core::client::name_resolver::Destination;
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::addressing::clients::Recipient;
 use serde::Serialize;
@@ -25,9 +26,9 @@ pub(crate) struct Init {
     #[clap(long)]
     id: String,
 
-    /// Address of the socks5 provider to send messages to.
+    /// Address of the socks5 provider to send messages to, or the name it has been registered with.
     #[clap(long)]
-    provider: Recipient,
+    provider: Destination,
 
     /// Specifies whether this client is going to use an anonymous sender tag for communication with the service provider.
     /// While this is going to hide its actual address information, it will make the actual communication
@@ -38,6 +39,16 @@ pub(crate) struct Init {
     #[clap(long, alias = "use_anonymous_sender_tag")]
     use_reply_surbs: Option<bool>,
 
+    /// Comma separated list of addresses (or registered names) of additional socks5 providers
+    /// that can be used if the primary one becomes unavailable.
+    #[clap(long, value_delimiter = ',')]
+    additional_providers: Option<Vec<Destination>>,
+
+    /// Specifies whether the network requesters announced in the service provider directory
+    /// should also be used for new connections.
+    #[clap(long)]
+    use_provider_directory: Option<bool>,
+
     /// Id of the gateway we are going to connect to.
     #[clap(long)]
     gateway: Option<identity::PublicKey>,
@@ -65,6 +76,14 @@ pub(crate) struct Init {
     #[clap(short, long)]
     port: Option<u16>,
 
+    /// Specifies whether the client should also accept HTTP proxy connections
+    #[clap(long)]
+    http_proxy: Option<bool>,
+
+    /// Port for the HTTP proxy socket to listen on
+    #[clap(long)]
+    http_proxy_port: Option<u16>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -88,6 +107,10 @@ impl From<Init> for OverrideConfig {
         OverrideConfig {
             nym_apis: init_config.nym_apis,
             port: init_config.port,
+            http_proxy: init_config.http_proxy,
+            http_proxy_port: init_config.http_proxy_port,
+            additional_providers: init_config.additional_providers,
+            use_provider_directory: init_config.use_provider_directory,
             use_anonymous_replies: init_config.use_reply_surbs,
             fastmode: init_config.fastmode,
             no_cover: init_config.no_cover,
@@ -103,6 +126,7 @@ pub struct InitResults {
     #[serde(flatten)]
     client_core: nym_client_core::init::InitResults,
     socks5_listening_port: u16,
+    http_proxy_listening_port: Option<u16>,
     client_address: String,
 }
 
@@ -111,6 +135,12 @@ impl InitResults {
         Self {
             client_core: nym_client_core::init::InitResults::new(&config.core.base, address),
             socks5_listening_port: config.core.socks5.listening_port,
+            http_proxy_listening_port: config
+                .core
+                .socks5
+                .http_proxy
+                .enabled
+                .then_some(config.core.socks5.http_proxy.listening_port),
             client_address: address.to_string(),
         }
     }
@@ -120,6 +150,9 @@ impl Display for InitResults {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "{}", self.client_core)?;
         writeln!(f, "SOCKS5 listening port: {}", self.socks5_listening_port)?;
+        if let Some(http_proxy_port) = self.http_proxy_listening_port {
+            writeln!(f, "HTTP proxy listening port: {http_proxy_port}")?;
+        }
         write!(f, "Address of this client: {}", self.client_address)
     }
 }
@@ -11,11 +11,13 @@ use lazy_static::lazy_static;
 use log::{error, info};
 use nym_bin_common::build_information::BinaryBuildInformation;
 use nym_bin_common::completions::{fig_generate, ArgShell};
+use nym_client_core::client::name_resolver::Destination;
 use nym_config::OptionalSet;
 use nym_sphinx::params::PacketType;
 use std::error::Error;
 
 pub mod init;
+pub(crate) mod provider_info;
 pub(crate) mod run;
 pub(crate) mod upgrade;
 
@@ -51,6 +53,9 @@ pub(crate) enum Commands {
     /// Try to upgrade the client
     Upgrade(upgrade::Upgrade),
 
+    /// Query the configured service provider for its capabilities and outbound policy
+    ProviderInfo(provider_info::ProviderInfo),
+
     /// Generate shell completions
     Completions(ArgShell),
 
@@ -63,6 +68,10 @@ pub(crate) struct OverrideConfig {
     nym_apis: Option<Vec<url::Url>>,
     port: Option<u16>,
     use_anonymous_replies: Option<bool>,
+    additional_providers: Option<Vec<Destination>>,
+    use_provider_directory: Option<bool>,
+    http_proxy: Option<bool>,
+    http_proxy_port: Option<u16>,
     fastmode: bool,
     no_cover: bool,
     nyxd_urls: Option<Vec<url::Url>>,
@@ -77,6 +86,7 @@ pub(crate) async fn execute(args: &Cli) -> Result<(), Box<dyn Error + Send + Syn
         Commands::Init(m) => init::execute(m).await?,
         Commands::Run(m) => run::execute(m).await?,
         Commands::Upgrade(m) => upgrade::execute(m),
+        Commands::ProviderInfo(m) => provider_info::execute(m).await?,
         Commands::Completions(s) => s.generate(&mut Cli::command(), bin_name),
         Commands::GenerateFigSpec => fig_generate(&mut Cli::command(), bin_name),
     }
@@ -97,7 +107,11 @@ pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
         .with_base(BaseClientConfig::with_disabled_cover_traffic, args.no_cover)
         .with_base(BaseClientConfig::with_packet_type, packet_type)
         .with_optional(Config::with_anonymous_replies, args.use_anonymous_replies)
+        .with_optional(Config::with_additional_providers, args.additional_providers)
+        .with_optional(Config::with_provider_directory, args.use_provider_directory)
         .with_optional(Config::with_port, args.port)
+        .with_optional(Config::with_http_proxy, args.http_proxy)
+        .with_optional(Config::with_http_proxy_port, args.http_proxy_port)
         .with_optional_base_custom_env(
             BaseClientConfig::with_custom_nym_apis,
             args.nym_apis,
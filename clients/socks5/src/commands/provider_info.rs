@@ -0,0 +1,113 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::commands::try_load_current_config;
+use clap::Args;
+use nym_bin_common::output_format::OutputFormat;
+use nym_client_core::client::base_client::storage::OnDiskPersistent;
+use nym_service_providers_common::interface::ProviderCapabilities;
+use nym_socks5_client_core::error::Socks5ClientCoreError;
+use nym_socks5_client_core::socks::provider_control::ProviderControlHandle;
+use nym_socks5_client_core::NymClient;
+use serde::Serialize;
+use std::fmt::Display;
+
+#[derive(Args, Clone)]
+pub(crate) struct ProviderInfo {
+    /// Id of the nym-mixnet-client we want to use for querying its service provider.
+    #[clap(long)]
+    id: String,
+
+    /// Only retrieve the capabilities of the provider without downloading its full allow list.
+    #[clap(long)]
+    skip_policy: bool,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProviderInfoResults {
+    provider: String,
+
+    #[serde(flatten)]
+    capabilities: ProviderCapabilities,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_hosts: Option<Vec<String>>,
+}
+
+impl Display for ProviderInfoResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Service provider: {}", self.provider)?;
+        writeln!(
+            f,
+            "Supported features: {}",
+            self.capabilities.features.join(", ")
+        )?;
+        match &self.capabilities.policy {
+            None => write!(f, "Outbound policy: n/a")?,
+            Some(policy) if policy.open => write!(f, "Outbound policy: open proxy")?,
+            Some(policy) => write!(
+                f,
+                "Outbound policy: {} allowed entries (hash: {})",
+                policy.total_entries, policy.hash
+            )?,
+        }
+        if let Some(allowed_hosts) = &self.allowed_hosts {
+            for host in allowed_hosts {
+                write!(f, "\n  {host}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) async fn execute(
+    args: &ProviderInfo,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    eprintln!("Starting client {}...", args.id);
+
+    let config = try_load_current_config(&args.id)?;
+    let provider = config.core.socks5.provider_mix_address.clone();
+
+    let storage =
+        OnDiskPersistent::from_paths(config.storage_paths.common_paths, &config.core.base.debug)
+            .await?;
+    let mut started = NymClient::new(config.core, storage).start().await?;
+
+    eprintln!("Querying {provider} for its capabilities...");
+    let res = query_provider(&started.provider_control, provider, args.skip_policy).await;
+
+    started.shutdown_handle.signal_shutdown().ok();
+    started.shutdown_handle.wait_for_shutdown().await;
+
+    println!("{}", args.output.format(&res?));
+    Ok(())
+}
+
+async fn query_provider(
+    provider_control: &ProviderControlHandle,
+    provider: String,
+    skip_policy: bool,
+) -> Result<ProviderInfoResults, Socks5ClientCoreError> {
+    let capabilities = provider_control.get_capabilities().await?;
+
+    let has_allow_list = capabilities
+        .policy
+        .as_ref()
+        .map(|policy| !policy.open && policy.total_entries > 0)
+        .unwrap_or_default();
+
+    let allowed_hosts = if has_allow_list && !skip_policy {
+        Some(provider_control.get_full_policy().await?)
+    } else {
+        None
+    };
+
+    Ok(ProviderInfoResults {
+        provider,
+        capabilities,
+        allowed_hosts,
+    })
+}
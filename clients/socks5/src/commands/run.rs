@@ -11,9 +11,9 @@ use clap::Args;
 use log::*;
 use nym_bin_common::version_checker::is_minor_version_compatible;
 use nym_client_core::client::base_client::storage::OnDiskPersistent;
+use nym_client_core::client::name_resolver::Destination;
 use nym_crypto::asymmetric::identity;
 use nym_socks5_client_core::NymClient;
-use nym_sphinx::addressing::clients::Recipient;
 
 #[derive(Args, Clone)]
 pub(crate) struct Run {
@@ -30,9 +30,19 @@ pub(crate) struct Run {
     #[clap(long, alias = "use_anonymous_sender_tag")]
     use_anonymous_replies: Option<bool>,
 
-    /// Address of the socks5 provider to send messages to.
+    /// Address of the socks5 provider to send messages to, or the name it has been registered with.
     #[clap(long)]
-    provider: Option<Recipient>,
+    provider: Option<Destination>,
+
+    /// Comma separated list of addresses (or registered names) of additional socks5 providers
+    /// that can be used if the primary one becomes unavailable.
+    #[clap(long, value_delimiter = ',')]
+    additional_providers: Option<Vec<Destination>>,
+
+    /// Specifies whether the network requesters announced in the service provider directory
+    /// should also be used for new connections.
+    #[clap(long)]
+    use_provider_directory: Option<bool>,
 
     /// Id of the gateway we want to connect to. If overridden, it is user's responsibility to
     /// ensure prior registration happened
@@ -51,6 +61,14 @@ pub(crate) struct Run {
     #[clap(short, long)]
     port: Option<u16>,
 
+    /// Specifies whether the client should also accept HTTP proxy connections
+    #[clap(long)]
+    http_proxy: Option<bool>,
+
+    /// Port for the HTTP proxy socket to listen on
+    #[clap(long)]
+    http_proxy_port: Option<u16>,
+
     /// Mostly debug-related option to increase default traffic rate so that you would not need to
     /// modify config post init
     #[clap(long, hide = true)]
@@ -74,6 +92,10 @@ impl From<Run> for OverrideConfig {
         OverrideConfig {
             nym_apis: run_config.nym_apis,
             port: run_config.port,
+            http_proxy: run_config.http_proxy,
+            http_proxy_port: run_config.http_proxy_port,
+            additional_providers: run_config.additional_providers,
+            use_provider_directory: run_config.use_provider_directory,
             use_anonymous_replies: run_config.use_anonymous_replies,
             fastmode: run_config.fastmode,
             no_cover: run_config.no_cover,
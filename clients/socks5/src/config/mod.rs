@@ -4,6 +4,7 @@
 use crate::config::persistence::SocksClientPaths;
 use crate::config::template::CONFIG_TEMPLATE;
 use nym_bin_common::logging::LoggingSettings;
+use nym_client_core::client::name_resolver::Destination;
 use nym_config::{
     must_get_home, read_config_from_toml_file, save_formatted_config_to_file, NymConfigTemplate,
     DEFAULT_CONFIG_DIR, DEFAULT_CONFIG_FILENAME, DEFAULT_DATA_DIR, NYM_DIR,
@@ -107,6 +108,27 @@ impl Config {
         self
     }
 
+    pub fn with_additional_providers(mut self, providers: Vec<Destination>) -> Self {
+        self.core.socks5.additional_providers =
+            providers.into_iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    pub fn with_provider_directory(mut self, use_provider_directory: bool) -> Self {
+        self.core.socks5.use_provider_directory = use_provider_directory;
+        self
+    }
+
+    pub fn with_http_proxy(mut self, enabled: bool) -> Self {
+        self.core.socks5.http_proxy.enabled = enabled;
+        self
+    }
+
+    pub fn with_http_proxy_port(mut self, port: u16) -> Self {
+        self.core.socks5.http_proxy.listening_port = port;
+        self
+    }
+
     // poor man's 'builder' method
 
     pub fn with_base<F, T>(mut self, f: F, val: T) -> Self
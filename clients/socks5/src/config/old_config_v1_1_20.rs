@@ -104,6 +104,10 @@ impl From<Socks5V1_1_20> for Socks5 {
             provider_interface_version: value.provider_interface_version,
             socks5_protocol_version: value.socks5_protocol_version,
             send_anonymously: value.send_anonymously,
+            additional_providers: Vec::new(),
+            use_provider_directory: false,
+            provider_selection: Default::default(),
+            http_proxy: Default::default(),
             socks5_debug: value.socks5_debug.into(),
         }
     }
@@ -121,6 +125,7 @@ impl From<Socks5DebugV1_1_20> for Socks5Debug {
         Socks5Debug {
             connection_start_surbs: value.connection_start_surbs,
             per_request_surbs: value.per_request_surbs,
+            ..Default::default()
         }
     }
 }
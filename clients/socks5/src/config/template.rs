@@ -93,6 +93,38 @@ listening_port = {{ core.socks5.listening_port }}
 # Note that some service providers might not support this.
 send_anonymously = {{ core.socks5.send_anonymously }}
 
+# Mix addresses of additional providers that can be used for new connections alongside,
+# or instead of, the provider specified by `provider_mix_address`.
+additional_providers = [
+    {{#each core.socks5.additional_providers }}
+        '{{this}}',
+    {{/each}}
+]
+
+# Specifies whether the network requesters announced in the service provider directory contract
+# should also be considered for new connections.
+use_provider_directory = {{ core.socks5.use_provider_directory }}
+
+# Strategy used for choosing the provider for each new connection.
+# Possible values are 'failover', 'round_robin' and 'random'.
+provider_selection = '{{ core.socks5.provider_selection }}'
+
+[core.socks5.http_proxy]
+
+# Specifies whether the client should also accept HTTP proxy connections, i.e. `CONNECT` tunnels
+# and plain HTTP requests, for applications that don't support socks.
+enabled = {{ core.socks5.http_proxy.enabled }}
+
+# The port on which the client will be listening for incoming HTTP proxy requests.
+listening_port = {{ core.socks5.http_proxy.listening_port }}
+
+# Username the HTTP proxy clients have to present using basic authentication.
+# If empty, no authentication is required.
+username = '{{ core.socks5.http_proxy.username }}'
+
+# Password the HTTP proxy clients have to present alongside the `username`.
+password = '{{ core.socks5.http_proxy.password }}'
+
 ##### logging configuration options #####
 
 [logging]
@@ -159,6 +159,10 @@ pub struct GatewayConnectionWasm {
     /// How long we're willing to wait for a response to a message sent to the gateway,
     /// before giving up on it.
     pub gateway_response_timeout_ms: u64,
+
+    /// Amount of remaining bandwidth (in bytes) below which the client is going to automatically
+    /// claim more of it. Set to 0 to disable the automatic top-up.
+    pub bandwidth_low_water_mark: i64,
 }
 
 impl From<GatewayConnectionWasm> for ConfigGatewayConnection {
@@ -167,6 +171,7 @@ impl From<GatewayConnectionWasm> for ConfigGatewayConnection {
             gateway_response_timeout: Duration::from_millis(
                 gateway_connection.gateway_response_timeout_ms,
             ),
+            bandwidth_low_water_mark: gateway_connection.bandwidth_low_water_mark,
         }
     }
 }
@@ -176,6 +181,7 @@ impl From<ConfigGatewayConnection> for GatewayConnectionWasm {
         GatewayConnectionWasm {
             gateway_response_timeout_ms: gateway_connection.gateway_response_timeout.as_millis()
                 as u64,
+            bandwidth_low_water_mark: gateway_connection.bandwidth_low_water_mark,
         }
     }
 }
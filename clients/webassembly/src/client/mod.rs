@@ -20,7 +20,6 @@ use nym_client_core::client::base_client::{
     BaseClientBuilder, ClientInput, ClientOutput, ClientState, CredentialsToggle,
 };
 use nym_client_core::client::inbound_messages::InputMessage;
-use nym_client_core::client::replies::reply_storage::browser_backend;
 use nym_credential_storage::ephemeral_storage::EphemeralStorage;
 use nym_sphinx::params::PacketType;
 use nym_task::connections::TransmissionLane;
@@ -33,7 +32,7 @@ use rand::{thread_rng, RngCore};
 use std::sync::Arc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
-use wasm_utils::{check_promise_result, console_log, PromisableResult};
+use wasm_utils::{check_promise_result, console_log, console_warn, PromisableResult};
 
 pub mod config;
 mod helpers;
@@ -49,9 +48,9 @@ pub struct NymClient {
     // so that it could be restored after the check is done
     _full_topology: Option<NymTopology>,
 
-    // even though we don't use graceful shutdowns, other components rely on existence of this struct
-    // and if it's dropped, everything will start going offline
-    _task_manager: TaskManager,
+    // other components rely on existence of this struct and if it's dropped,
+    // everything will start going offline
+    task_manager: TaskManager,
 
     packet_type: PacketType,
 }
@@ -63,7 +62,6 @@ pub struct NymClientBuilder {
     preferred_gateway: Option<IdentityKey>,
 
     storage_passphrase: Option<String>,
-    reply_surb_storage_backend: browser_backend::Backend,
 
     on_message: js_sys::Function,
 
@@ -83,9 +81,6 @@ impl NymClientBuilder {
         storage_passphrase: Option<String>,
     ) -> Self {
         NymClientBuilder {
-            reply_surb_storage_backend: setup_reply_surb_storage_backend(
-                config.base.debug.reply_surbs,
-            ),
             config,
             custom_topology: None,
             storage_passphrase,
@@ -114,9 +109,6 @@ impl NymClientBuilder {
         let full_config = Config::new_tester_config(NODE_TESTER_CLIENT_ID);
 
         NymClientBuilder {
-            reply_surb_storage_backend: setup_reply_surb_storage_backend(
-                full_config.base.debug.reply_surbs,
-            ),
             config: full_config,
             custom_topology: Some(topology.into()),
             on_message,
@@ -150,7 +142,13 @@ impl NymClientBuilder {
 
         let nym_api_endpoints = self.config.base.client.nym_api_urls.clone();
 
-        // TODO: this will have to be re-used for surbs. but this is a problem for another PR.
+        let reply_storage_backend = setup_reply_surb_storage_backend(
+            &self.config.base.client.id,
+            self.storage_passphrase.as_ref().map(|p| p.as_bytes()),
+            &self.config.base.debug.reply_surbs,
+        )
+        .await?;
+
         let client_store =
             ClientStorage::new_async(&self.config.base.client.id, self.storage_passphrase.take())
                 .await?;
@@ -180,7 +178,7 @@ impl NymClientBuilder {
             &self.config.base.debug,
             client_store,
             self.bandwidth_controller,
-            self.reply_surb_storage_backend,
+            reply_storage_backend,
             disabled_credentials,
             nym_api_endpoints,
         );
@@ -201,7 +199,7 @@ impl NymClientBuilder {
             client_input: Arc::new(client_input),
             client_state: Arc::new(started_client.client_state),
             _full_topology: None,
-            _task_manager: started_client.task_manager,
+            task_manager: started_client.task_manager,
             packet_type: self.config.base.debug.traffic.packet_type,
         })
     }
@@ -243,6 +241,15 @@ impl NymClient {
         self.self_address.clone()
     }
 
+    /// Signals all the client tasks to stop, giving it a chance to flush its reply-related data
+    /// to the underlying storage. It should be called before the page gets unloaded,
+    /// for example from a `pagehide` event handler.
+    pub fn disconnect(&self) {
+        if self.task_manager.signal_shutdown().is_err() {
+            console_warn!("the client has already been disconnected");
+        }
+    }
+
     pub fn try_construct_test_packet_request(
         &self,
         mixnode_identity: String,
@@ -2,10 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::WasmClientError;
+use crate::storage::errors::ClientStorageError;
 use crate::storage::ClientStorage;
 use crate::topology::WasmNymTopology;
 use js_sys::Promise;
-use nym_client_core::client::replies::reply_storage::browser_backend;
+use nym_client_core::client::replies::reply_storage::{
+    browser_backend, CombinedReplyStorage, ReplyStorageBackend,
+};
 use nym_client_core::config;
 use nym_client_core::config::GatewayEndpointConfig;
 use nym_client_core::init::GatewaySetup;
@@ -19,17 +22,43 @@ use rand::{CryptoRng, Rng};
 use url::Url;
 use wasm_bindgen::prelude::wasm_bindgen;
 use wasm_bindgen_futures::future_to_promise;
-use wasm_utils::{console_log, PromisableResult};
-
-// don't get too excited about the name, under the hood it's just a big fat placeholder
-// with no disk_persistence
-pub(crate) fn setup_reply_surb_storage_backend(
-    config: config::ReplySurbs,
-) -> browser_backend::Backend {
-    browser_backend::Backend::new(
+use wasm_utils::{console_log, console_warn, PromisableResult};
+
+async fn setup_fresh_reply_surb_storage_backend(
+    client_id: &str,
+    passphrase: Option<&[u8]>,
+    config: &config::ReplySurbs,
+) -> Result<browser_backend::Backend, ClientStorageError> {
+    console_log!("creating fresh reply surb storage");
+    let mut backend = browser_backend::Backend::init(client_id, passphrase).await?;
+
+    let mem_store = CombinedReplyStorage::new(
         config.minimum_reply_surb_storage_threshold,
         config.maximum_reply_surb_storage_threshold,
-    )
+    );
+    backend.init_fresh(&mem_store).await?;
+
+    Ok(backend)
+}
+
+pub(crate) async fn setup_reply_surb_storage_backend(
+    client_id: &str,
+    passphrase: Option<&[u8]>,
+    config: &config::ReplySurbs,
+) -> Result<browser_backend::Backend, ClientStorageError> {
+    match browser_backend::Backend::try_load(client_id, passphrase).await {
+        Ok(backend) => Ok(backend),
+        // we failed to even open the storage (for example because of invalid passphrase),
+        // so there's no point in attempting to recreate it
+        Err(err @ browser_backend::StorageError::InternalStorageError { .. }) => Err(err.into()),
+        Err(browser_backend::StorageError::MissingReplySurbStorageMetadata) => {
+            setup_fresh_reply_surb_storage_backend(client_id, passphrase, config).await
+        }
+        Err(err) => {
+            console_warn!("failed to load the existing reply surb storage: {err}. We're going to create a fresh one instead");
+            setup_fresh_reply_surb_storage_backend(client_id, passphrase, config).await
+        }
+    }
 }
 
 pub(crate) fn parse_recipient(recipient: &str) -> Result<Recipient, WasmClientError> {
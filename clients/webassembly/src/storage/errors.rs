@@ -1,6 +1,7 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use nym_client_core::client::replies::reply_storage::browser_backend;
 use thiserror::Error;
 use wasm_bindgen::JsValue;
 use wasm_utils::simple_js_error;
@@ -14,6 +15,12 @@ pub enum ClientStorageError {
         source: StorageError,
     },
 
+    #[error("failed to use the reply storage: {source}")]
+    ReplyStorageError {
+        #[from]
+        source: browser_backend::StorageError,
+    },
+
     #[error("{typ} cryptographic key is not available in storage")]
     CryptoKeyNotInStorage { typ: String },
 }
@@ -5,16 +5,19 @@ use crate::error::BandwidthControllerError;
 use nym_coconut_interface::{Base58, Parameters};
 use nym_credential_storage::storage::Storage;
 use nym_credentials::coconut::bandwidth::{BandwidthVoucher, TOTAL_ATTRIBUTES};
-use nym_credentials::coconut::utils::obtain_aggregate_signature;
+use nym_credentials::coconut::ticketbook::{ticket_value, ticketbook_deposit_info, TicketBook};
+use nym_credentials::coconut::utils::{
+    obtain_aggregate_signature, obtain_aggregate_ticket_signature,
+};
 use nym_crypto::asymmetric::{encryption, identity};
-use nym_network_defaults::VOUCHER_INFO;
+use nym_network_defaults::{TICKET_INFO, VOUCHER_INFO};
 use nym_validator_client::nyxd::traits::CoconutBandwidthSigningClient;
 use nym_validator_client::nyxd::traits::DkgQueryClient;
 use nym_validator_client::nyxd::tx::Hash;
 use nym_validator_client::nyxd::Coin;
 use nym_validator_client::CoconutApiClient;
 use rand::rngs::OsRng;
-use state::{KeyPair, State};
+use state::{KeyPair, State, TicketBookState};
 use std::str::FromStr;
 
 pub mod state;
@@ -55,6 +58,49 @@ where
     Ok(state)
 }
 
+/// Deposits the specified amount so that it could be issued as a ticket book of the provided number
+/// of tickets, each of which can be spent independently, for example at a different gateway.
+pub async fn deposit_ticketbook<C>(
+    client: &C,
+    amount: Coin,
+    tickets: u32,
+) -> Result<TicketBookState, BandwidthControllerError>
+where
+    C: CoconutBandwidthSigningClient,
+{
+    let mut rng = OsRng;
+    let signing_keypair = KeyPair::from(identity::KeyPair::new(&mut rng));
+    let encryption_keypair = KeyPair::from(encryption::KeyPair::new(&mut rng));
+    let params = Parameters::new(TOTAL_ATTRIBUTES).unwrap();
+    let deposit_value = amount.amount.to_string();
+
+    // make sure the amount can be split before actually depositing anything
+    ticket_value(&deposit_value, tickets)?;
+
+    let tx_hash = client
+        .deposit(
+            amount,
+            ticketbook_deposit_info(tickets),
+            signing_keypair.public_key.clone(),
+            encryption_keypair.public_key.clone(),
+            None,
+        )
+        .await?
+        .transaction_hash
+        .to_string();
+
+    let ticketbook = TicketBook::new(
+        &params,
+        deposit_value,
+        tickets,
+        Hash::from_str(&tx_hash).map_err(|_| BandwidthControllerError::InvalidTxHash)?,
+        identity::PrivateKey::from_base58_string(&signing_keypair.private_key)?,
+        encryption::PrivateKey::from_base58_string(&encryption_keypair.private_key)?,
+    )?;
+
+    Ok(TicketBookState { ticketbook, params })
+}
+
 pub async fn get_credential<C, St>(
     state: &State,
     client: &C,
@@ -91,3 +137,54 @@ where
         .await
         .map_err(|err| BandwidthControllerError::CredentialStorageError(Box::new(err)))
 }
+
+/// Obtains signatures on all tickets of the ticket book and stores them as separate credentials.
+/// Tickets are derived deterministically, so if this fails midway, it can be safely retried
+/// as the nym-apis will return the signatures they have already issued.
+pub async fn get_ticketbook_credentials<C, St>(
+    state: &TicketBookState,
+    client: &C,
+    storage: &St,
+) -> Result<(), BandwidthControllerError>
+where
+    C: DkgQueryClient + Send + Sync,
+    St: Storage,
+    <St as Storage>::StorageError: Send + Sync + 'static,
+{
+    let epoch_id = client.get_current_epoch().await?.epoch_id;
+    let threshold = client
+        .get_current_epoch_threshold()
+        .await?
+        .ok_or(BandwidthControllerError::NoThreshold)?;
+    let coconut_api_clients = CoconutApiClient::all_coconut_api_clients(client, epoch_id).await?;
+
+    // obtain all signatures before storing anything so that a retry wouldn't result in duplicates
+    let mut tickets = Vec::with_capacity(state.ticketbook.tickets() as usize);
+    for index in 0..state.ticketbook.tickets() {
+        let ticket = state.ticketbook.ticket(&state.params, index)?;
+        let signature = obtain_aggregate_ticket_signature(
+            &state.params,
+            &ticket,
+            index,
+            &coconut_api_clients,
+            threshold,
+        )
+        .await?;
+        tickets.push((ticket, signature));
+    }
+
+    for (ticket, signature) in tickets {
+        storage
+            .insert_coconut_credential(
+                ticket.get_voucher_value(),
+                TICKET_INFO.to_string(),
+                ticket.get_private_attributes()[0].to_bs58(),
+                ticket.get_private_attributes()[1].to_bs58(),
+                signature.to_bs58(),
+                epoch_id.to_string(),
+            )
+            .await
+            .map_err(|err| BandwidthControllerError::CredentialStorageError(Box::new(err)))?;
+    }
+    Ok(())
+}
@@ -3,6 +3,7 @@
 
 use nym_coconut_interface::Parameters;
 use nym_credentials::coconut::bandwidth::{BandwidthVoucher, TOTAL_ATTRIBUTES};
+use nym_credentials::coconut::ticketbook::TicketBook;
 
 use nym_crypto::asymmetric::{encryption, identity};
 
@@ -42,3 +43,17 @@ impl State {
         }
     }
 }
+
+pub struct TicketBookState {
+    pub ticketbook: TicketBook,
+    pub params: Parameters,
+}
+
+impl TicketBookState {
+    pub fn new(ticketbook: TicketBook) -> Self {
+        TicketBookState {
+            ticketbook,
+            params: Parameters::new(TOTAL_ATTRIBUTES).unwrap(),
+        }
+    }
+}
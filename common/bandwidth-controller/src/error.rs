@@ -43,3 +43,18 @@ pub enum BandwidthControllerError {
     #[error("Threshold not set yet")]
     NoThreshold,
 }
+
+impl BandwidthControllerError {
+    /// Checks whether the error was caused by the credential storage not containing
+    /// any more unspent credentials.
+    pub fn is_out_of_credentials(&self) -> bool {
+        match self {
+            BandwidthControllerError::StorageError(StorageError::NoCredential) => true,
+            BandwidthControllerError::CredentialStorageError(source) => matches!(
+                source.downcast_ref::<StorageError>(),
+                Some(StorageError::NoCredential)
+            ),
+            _ => false,
+        }
+    }
+}
@@ -8,6 +8,7 @@ use crate::client::inbound_messages::{InputMessage, InputMessageReceiver, InputM
 use crate::client::key_manager::persistence::KeyStore;
 use crate::client::key_manager::ManagedKeys;
 use crate::client::mix_traffic::{BatchMixMessageSender, MixTrafficController};
+use crate::client::name_resolver::NameResolver;
 use crate::client::real_messages_control;
 use crate::client::real_messages_control::RealMessagesController;
 use crate::client::received_buffer::{
@@ -100,6 +101,7 @@ pub struct ClientState {
     pub shared_lane_queue_lengths: LaneQueueLengths,
     pub reply_controller_sender: ReplyControllerSender,
     pub topology_accessor: TopologyAccessor,
+    pub name_resolver: NameResolver,
 }
 
 pub enum ClientInputStatus {
@@ -354,6 +356,11 @@ where
         );
 
         gateway_client.set_disabled_credentials_mode(self.disabled_credentials);
+        gateway_client.with_bandwidth_low_water_mark(
+            self.debug_config
+                .gateway_connection
+                .bandwidth_low_water_mark,
+        );
 
         let shared_key = gateway_client
             .authenticate_and_start()
@@ -529,6 +536,7 @@ where
         )
         .await?;
 
+        let name_resolver = NameResolver::new(self.nym_api_endpoints.clone());
         let topology_provider = Self::setup_topology_provider(
             self.custom_topology_provider.take(),
             self.nym_api_endpoints,
@@ -621,6 +629,7 @@ where
                 shared_lane_queue_lengths,
                 reply_controller_sender,
                 topology_accessor: shared_topology_accessor,
+                name_resolver,
             },
             task_manager,
         })
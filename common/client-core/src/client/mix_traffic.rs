@@ -1,10 +1,20 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::helpers::{get_time_now, new_interval_stream, Instant};
+use crate::error::ClientCoreStatusMessage;
 use crate::spawn_future;
+use futures::channel::oneshot;
+use futures::StreamExt;
 use log::*;
+use nym_bandwidth_controller::error::BandwidthControllerError;
+use nym_coconut_interface::Credential;
+use nym_gateway_client::error::GatewayClientError;
 use nym_gateway_client::GatewayClient;
 use nym_sphinx::forwarding::packet::MixPacket;
+use nym_task::TaskClient;
+use std::collections::VecDeque;
+use std::time::Duration;
 
 use nym_credential_storage::storage::Storage;
 #[cfg(not(target_arch = "wasm32"))]
@@ -20,6 +30,120 @@ pub type BatchMixMessageReceiver = tokio::sync::mpsc::Receiver<Vec<MixPacket>>;
 pub const MIX_MESSAGE_RECEIVER_BUFFER_SIZE: usize = 32;
 const MAX_FAILURE_COUNT: usize = 100;
 
+// how long to wait before attempting to top up the bandwidth again after a failed attempt
+const BANDWIDTH_TOP_UP_RETRY_BACKOFF: Duration = Duration::from_secs(30);
+
+// maximum number of packets held while waiting for more bandwidth. If it's exceeded,
+// the oldest packets are dropped (they'd most likely get retransmitted anyway)
+const MAX_PENDING_PACKETS: usize = 2000;
+
+type PreparedCredential = Result<(Credential, i64), BandwidthControllerError>;
+
+/// Batches of packets that couldn't have been sent yet due to insufficient bandwidth.
+struct PendingBatches<T> {
+    batches: VecDeque<Vec<T>>,
+    total: usize,
+    limit: usize,
+}
+
+impl<T> PendingBatches<T> {
+    fn new(limit: usize) -> Self {
+        PendingBatches {
+            batches: VecDeque::new(),
+            total: 0,
+            limit,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    fn front(&self) -> Option<&[T]> {
+        self.batches.front().map(|batch| batch.as_slice())
+    }
+
+    fn pop_front(&mut self) -> Option<Vec<T>> {
+        let batch = self.batches.pop_front()?;
+        self.total -= batch.len();
+        Some(batch)
+    }
+
+    /// Pushes the batch at the back of the queue and returns the number of packets
+    /// that had to be dropped in order not to exceed the limit.
+    fn push(&mut self, batch: Vec<T>) -> usize {
+        self.total += batch.len();
+        self.batches.push_back(batch);
+
+        let mut dropped = 0;
+        // always keep the most recent batch
+        while self.total > self.limit && self.batches.len() > 1 {
+            dropped += self
+                .pop_front()
+                .map(|batch| batch.len())
+                .unwrap_or_default();
+        }
+        dropped
+    }
+}
+
+#[derive(Debug, Default)]
+struct TopUpState {
+    /// Specifies whether a new credential is currently being prepared in the background.
+    in_progress: bool,
+
+    /// Time of the last failed attempt at claiming more bandwidth, if any.
+    last_failure: Option<Instant>,
+
+    /// Specifies whether we have already notified about running out of the bandwidth credentials.
+    reported_out_of_credentials: bool,
+}
+
+impl TopUpState {
+    fn should_attempt(&self, bandwidth_required: bool, now: Instant) -> bool {
+        if !bandwidth_required || self.in_progress {
+            return false;
+        }
+        match self.last_failure {
+            Some(last_failure) => {
+                now.duration_since(last_failure) >= BANDWIDTH_TOP_UP_RETRY_BACKOFF
+            }
+            None => true,
+        }
+    }
+
+    fn started(&mut self) {
+        self.in_progress = true;
+    }
+
+    fn succeeded(&mut self) {
+        self.in_progress = false;
+        self.last_failure = None;
+        self.reported_out_of_credentials = false;
+    }
+
+    fn failed(&mut self, now: Instant) {
+        self.in_progress = false;
+        self.last_failure = Some(now);
+    }
+
+    /// Returns whether running out of the credentials should be reported,
+    /// which only happens once until we manage to claim more bandwidth again.
+    fn ran_out_of_credentials(&mut self, now: Instant) -> bool {
+        self.failed(now);
+        !std::mem::replace(&mut self.reported_out_of_credentials, true)
+    }
+}
+
+async fn wait_for_credential(
+    receiver: &mut Option<oneshot::Receiver<PreparedCredential>>,
+) -> Result<PreparedCredential, oneshot::Canceled> {
+    match receiver {
+        Some(receiver) => receiver.await,
+        None => futures::future::pending().await,
+    }
+}
+
 pub struct MixTrafficController<C, St: Storage> {
     // TODO: most likely to be replaced by some higher level construct as
     // later on gateway_client will need to be accessible by other entities
@@ -29,6 +153,14 @@ pub struct MixTrafficController<C, St: Storage> {
     // TODO: this is temporary work-around.
     // in long run `gateway_client` will be moved away from `MixTrafficController` anyway.
     consecutive_gateway_failure_count: usize,
+
+    /// Packets waiting for enough bandwidth to become available.
+    pending: PendingBatches<MixPacket>,
+
+    /// Channel on which the credential prepared in the background is going to be delivered.
+    prepared_credential: Option<oneshot::Receiver<PreparedCredential>>,
+
+    top_up: TopUpState,
 }
 
 impl<C, St> MixTrafficController<C, St>
@@ -47,55 +179,198 @@ where
                 gateway_client,
                 mix_rx: message_receiver,
                 consecutive_gateway_failure_count: 0,
+                pending: PendingBatches::new(MAX_PENDING_PACKETS),
+                prepared_credential: None,
+                top_up: TopUpState::default(),
             },
             message_sender,
         )
     }
 
-    async fn on_messages(&mut self, mut mix_packets: Vec<MixPacket>) {
-        debug_assert!(!mix_packets.is_empty());
+    fn bandwidth_required(&self) -> bool {
+        self.gateway_client.requires_bandwidth_top_up()
+            || self
+                .pending
+                .front()
+                .map(|batch| !self.gateway_client.has_enough_bandwidth(batch))
+                .unwrap_or_default()
+    }
 
-        let result = if mix_packets.len() == 1 {
-            let mix_packet = mix_packets.pop().unwrap();
-            self.gateway_client.send_mix_packet(mix_packet).await
-        } else {
-            self.gateway_client
-                .batch_send_mix_packets(mix_packets)
-                .await
+    /// Attempts to claim more bandwidth if we're running low on it.
+    /// Returns whether the bandwidth has been claimed straight away.
+    async fn maybe_top_up_bandwidth(&mut self, shutdown: &mut TaskClient) -> bool {
+        if !self
+            .top_up
+            .should_attempt(self.bandwidth_required(), get_time_now())
+        {
+            return false;
+        }
+
+        info!(
+            "remaining bandwidth ({} bytes) is insufficient - attempting to claim more",
+            self.gateway_client.remaining_bandwidth()
+        );
+
+        match self.gateway_client.shared_bandwidth_controller() {
+            Some(bandwidth_controller) => {
+                // preparing the credential involves talking to the validators, so do it in the background
+                // and keep on sending whatever we can in the meantime
+                let (credential_sender, credential_receiver) = oneshot::channel();
+                spawn_future(async move {
+                    let prepared = bandwidth_controller.prepare_coconut_credential().await;
+                    // the receiver is only gone if we're shutting down
+                    let _ = credential_sender.send(prepared);
+                });
+                self.prepared_credential = Some(credential_receiver);
+                self.top_up.started();
+                false
+            }
+            None => {
+                // in the disabled credentials mode it's just a single request to the gateway
+                let result = self.gateway_client.claim_bandwidth().await;
+                self.on_top_up_result(result, shutdown)
+            }
+        }
+    }
+
+    async fn on_prepared_credential(
+        &mut self,
+        prepared: Result<PreparedCredential, oneshot::Canceled>,
+        shutdown: &mut TaskClient,
+    ) {
+        self.prepared_credential = None;
+
+        let result = match prepared {
+            Ok(Ok((credential, credential_id))) => {
+                self.gateway_client
+                    .claim_bandwidth_with_credential(credential, credential_id)
+                    .await
+            }
+            Ok(Err(err)) if err.is_out_of_credentials() => {
+                Err(GatewayClientError::NoMoreBandwidthCredentials)
+            }
+            Ok(Err(err)) => Err(err.into()),
+            Err(_) => {
+                warn!("the bandwidth credential preparation has been aborted");
+                self.top_up.failed(get_time_now());
+                return;
+            }
         };
+        self.on_top_up_result(result, shutdown);
+    }
 
+    fn on_top_up_result(
+        &mut self,
+        result: Result<(), GatewayClientError>,
+        shutdown: &mut TaskClient,
+    ) -> bool {
         match result {
-            Err(err) => {
-                error!("Failed to send sphinx packet(s) to the gateway! - {err}");
-                self.consecutive_gateway_failure_count += 1;
-                if self.consecutive_gateway_failure_count == MAX_FAILURE_COUNT {
-                    // todo: in the future this should initiate a 'graceful' shutdown or try
-                    // to reconnect?
-                    panic!("failed to send sphinx packet to the gateway {MAX_FAILURE_COUNT} times in a row - assuming the gateway is dead. Can't do anything about it yet :(")
+            Ok(_) => {
+                info!(
+                    "claimed more bandwidth. we now have {} bytes available",
+                    self.gateway_client.remaining_bandwidth()
+                );
+                self.top_up.succeeded();
+                true
+            }
+            Err(GatewayClientError::NoMoreBandwidthCredentials) => {
+                warn!("there are no more bandwidth credentials available to claim more bandwidth with");
+                if self.top_up.ran_out_of_credentials(get_time_now()) {
+                    shutdown.send_status_msg(Box::new(
+                        ClientCoreStatusMessage::OutOfBandwidthCredentials,
+                    ));
                 }
+                false
             }
-            Ok(_) => {
-                trace!("We *might* have managed to forward sphinx packet(s) to the gateway!");
-                self.consecutive_gateway_failure_count = 0;
+            Err(err) => {
+                warn!("failed to claim more bandwidth - {err}");
+                self.top_up.failed(get_time_now());
+                false
             }
         }
     }
 
-    pub fn start_with_shutdown(mut self, mut shutdown: nym_task::TaskClient) {
+    async fn send_pending_packets(&mut self) {
+        while let Some(batch) = self.pending.front() {
+            if !self.gateway_client.has_enough_bandwidth(batch) {
+                trace!("not enough bandwidth to send the pending packets - they will be sent once we claim more");
+                break;
+            }
+
+            let mut mix_packets = self.pending.pop_front().unwrap_or_default();
+            let result = if mix_packets.len() == 1 {
+                let mix_packet = mix_packets.pop().unwrap();
+                self.gateway_client.send_mix_packet(mix_packet).await
+            } else {
+                self.gateway_client
+                    .batch_send_mix_packets(mix_packets)
+                    .await
+            };
+
+            match result {
+                // running out of bandwidth doesn't mean there's anything wrong with the gateway
+                Err(err @ GatewayClientError::NotEnoughBandwidth(..)) => {
+                    warn!("Failed to send sphinx packet(s) to the gateway - {err}");
+                }
+                Err(err) => {
+                    error!("Failed to send sphinx packet(s) to the gateway! - {err}");
+                    self.consecutive_gateway_failure_count += 1;
+                    if self.consecutive_gateway_failure_count == MAX_FAILURE_COUNT {
+                        // todo: in the future this should initiate a 'graceful' shutdown or try
+                        // to reconnect?
+                        panic!("failed to send sphinx packet to the gateway {MAX_FAILURE_COUNT} times in a row - assuming the gateway is dead. Can't do anything about it yet :(")
+                    }
+                }
+                Ok(_) => {
+                    trace!("We *might* have managed to forward sphinx packet(s) to the gateway!");
+                    self.consecutive_gateway_failure_count = 0;
+                }
+            }
+        }
+    }
+
+    async fn on_messages(&mut self, mix_packets: Vec<MixPacket>, shutdown: &mut TaskClient) {
+        debug_assert!(!mix_packets.is_empty());
+
+        let dropped = self.pending.push(mix_packets);
+        if dropped > 0 {
+            warn!(
+                "dropped {dropped} packets that have been waiting for more bandwidth for too long"
+            );
+        }
+
+        self.send_pending_packets().await;
+        if self.maybe_top_up_bandwidth(shutdown).await {
+            self.send_pending_packets().await;
+        }
+    }
+
+    pub fn start_with_shutdown(mut self, mut shutdown: TaskClient) {
         spawn_future(async move {
             debug!("Started MixTrafficController with graceful shutdown support");
 
+            let mut top_up_retry = new_interval_stream(BANDWIDTH_TOP_UP_RETRY_BACKOFF);
+
             loop {
                 tokio::select! {
                     mix_packets = self.mix_rx.recv() => match mix_packets {
                         Some(mix_packets) => {
-                            self.on_messages(mix_packets).await;
+                            self.on_messages(mix_packets, &mut shutdown).await;
                         },
                         None => {
                             log::trace!("MixTrafficController: Stopping since channel closed");
                             break;
                         }
                     },
+                    prepared = wait_for_credential(&mut self.prepared_credential) => {
+                        self.on_prepared_credential(prepared, &mut shutdown).await;
+                        self.send_pending_packets().await;
+                    },
+                    _ = top_up_retry.next() => {
+                        if !self.pending.is_empty() && self.maybe_top_up_bandwidth(&mut shutdown).await {
+                            self.send_pending_packets().await;
+                        }
+                    },
                     _ = shutdown.recv_with_delay() => {
                         log::trace!("MixTrafficController: Received shutdown");
                         break;
@@ -107,3 +382,85 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_up_is_only_attempted_when_bandwidth_is_required() {
+        let state = TopUpState::default();
+        let now = get_time_now();
+
+        assert!(!state.should_attempt(false, now));
+        assert!(state.should_attempt(true, now));
+    }
+
+    #[test]
+    fn only_single_top_up_is_performed_at_a_time() {
+        let mut state = TopUpState::default();
+        let now = get_time_now();
+
+        state.started();
+        assert!(!state.should_attempt(true, now));
+
+        state.succeeded();
+        assert!(state.should_attempt(true, now));
+    }
+
+    #[test]
+    fn failed_top_up_is_retried_after_backoff() {
+        let mut state = TopUpState::default();
+        let now = get_time_now();
+
+        state.started();
+        state.failed(now);
+        assert!(!state.should_attempt(true, now));
+        assert!(!state.should_attempt(true, now + BANDWIDTH_TOP_UP_RETRY_BACKOFF / 2));
+        assert!(state.should_attempt(true, now + BANDWIDTH_TOP_UP_RETRY_BACKOFF));
+    }
+
+    #[test]
+    fn running_out_of_credentials_is_reported_once() {
+        let mut state = TopUpState::default();
+        let now = get_time_now();
+
+        assert!(state.ran_out_of_credentials(now));
+        assert!(!state.ran_out_of_credentials(now + BANDWIDTH_TOP_UP_RETRY_BACKOFF));
+        assert!(!state.should_attempt(true, now));
+
+        // once we manage to get more bandwidth, we should report it again
+        state.succeeded();
+        assert!(state.ran_out_of_credentials(now));
+    }
+
+    #[test]
+    fn pending_batches_are_returned_in_order() {
+        let mut pending = PendingBatches::new(10);
+        assert!(pending.is_empty());
+
+        assert_eq!(pending.push(vec![1, 2]), 0);
+        assert_eq!(pending.push(vec![3]), 0);
+
+        assert_eq!(pending.front(), Some([1, 2].as_slice()));
+        assert_eq!(pending.pop_front(), Some(vec![1, 2]));
+        assert_eq!(pending.pop_front(), Some(vec![3]));
+        assert!(pending.pop_front().is_none());
+        assert!(pending.is_empty());
+    }
+
+    #[test]
+    fn oldest_pending_batches_are_dropped_above_the_limit() {
+        let mut pending = PendingBatches::new(5);
+
+        assert_eq!(pending.push(vec![1, 2]), 0);
+        assert_eq!(pending.push(vec![3, 4]), 0);
+        assert_eq!(pending.push(vec![5, 6]), 2);
+        assert_eq!(pending.pop_front(), Some(vec![3, 4]));
+
+        // the most recent batch is always kept
+        assert_eq!(pending.push(vec![7, 8, 9, 10, 11, 12]), 2);
+        assert_eq!(pending.pop_front(), Some(vec![7, 8, 9, 10, 11, 12]));
+        assert!(pending.is_empty());
+    }
+}
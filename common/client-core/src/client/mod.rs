@@ -7,6 +7,7 @@ pub(crate) mod helpers;
 pub mod inbound_messages;
 pub mod key_manager;
 pub mod mix_traffic;
+pub mod name_resolver;
 pub mod real_messages_control;
 pub mod received_buffer;
 pub mod replies;
@@ -0,0 +1,407 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use log::{debug, warn};
+use nym_name_service_common::{NameEntry, NymName};
+use nym_sphinx::addressing::clients::{Recipient, RecipientFormattingError};
+use nym_validator_client::client::NymApiClient;
+use nym_validator_client::ValidatorClientError;
+use rand::prelude::SliceRandom;
+use rand::thread_rng;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+use url::Url;
+
+/// Default amount of time the names retrieved from the nym API are considered valid for.
+pub const DEFAULT_NAME_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+// a name that is not present in the cache causes the names to be re-downloaded,
+// but not more often than this, so that typos wouldn't flood the nym API with requests
+const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, thiserror::Error)]
+pub enum NameResolutionError {
+    #[error("'{name}' is neither a valid nym address nor a valid name")]
+    InvalidName { name: String },
+
+    #[error("'{address}' is not a valid nym address: {source}")]
+    MalformedAddress {
+        address: String,
+        #[source]
+        source: RecipientFormattingError,
+    },
+
+    #[error("the name '{name}' is not registered")]
+    NameNotFound { name: String },
+
+    #[error("the registration of the name '{name}' has expired")]
+    NameExpired { name: String },
+
+    #[error("the name '{name}' points to a malformed nym address '{address}': {source}")]
+    MalformedRegisteredAddress {
+        name: String,
+        address: String,
+        #[source]
+        source: RecipientFormattingError,
+    },
+
+    #[error("failed to retrieve the registered names from any nym API: {source}")]
+    NymApiFailure {
+        #[source]
+        source: ValidatorClientError,
+    },
+
+    #[error("there are no nym APIs available to resolve names with")]
+    NoNymApisAvailable,
+}
+
+/// Destination of a message that is either an explicit nym address or a name registered
+/// with the name service that is going to be resolved into one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Destination {
+    Address(Recipient),
+    Name(NymName),
+}
+
+impl Destination {
+    pub fn as_address(&self) -> Option<&Recipient> {
+        match self {
+            Destination::Address(address) => Some(address),
+            Destination::Name(_) => None,
+        }
+    }
+}
+
+impl From<Recipient> for Destination {
+    fn from(address: Recipient) -> Self {
+        Destination::Address(address)
+    }
+}
+
+impl From<NymName> for Destination {
+    fn from(name: NymName) -> Self {
+        Destination::Name(name)
+    }
+}
+
+impl FromStr for Destination {
+    type Err = NameResolutionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // names can't contain neither '.' nor '@', so there's no ambiguity here
+        if s.contains('@') || s.contains('.') {
+            Recipient::try_from_base58_string(s)
+                .map(Destination::Address)
+                .map_err(|source| NameResolutionError::MalformedAddress {
+                    address: s.to_string(),
+                    source,
+                })
+        } else {
+            NymName::new(s)
+                .map(Destination::Name)
+                .map_err(|_| NameResolutionError::InvalidName {
+                    name: s.to_string(),
+                })
+        }
+    }
+}
+
+impl Display for Destination {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Destination::Address(address) => write!(f, "{address}"),
+            Destination::Name(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedName {
+    address: String,
+    expires_at: u64,
+}
+
+#[derive(Debug, Default)]
+struct NameCache {
+    names: HashMap<String, CachedName>,
+    last_refresh: Option<OffsetDateTime>,
+}
+
+impl NameCache {
+    fn rebuild(&mut self, entries: Vec<NameEntry>, now: OffsetDateTime) {
+        let mut names: HashMap<String, CachedName> = HashMap::new();
+        for entry in entries {
+            let registered = entry.name;
+            let name = registered.name.to_string();
+            // an expired name might have been registered again, in which case the contract would
+            // hold both entries. the most recent registration is the only relevant one
+            if let Some(existing) = names.get(&name) {
+                if existing.expires_at >= registered.expires_at {
+                    continue;
+                }
+            }
+            names.insert(
+                name,
+                CachedName {
+                    address: registered.address.to_string(),
+                    expires_at: registered.expires_at,
+                },
+            );
+        }
+        self.names = names;
+        self.last_refresh = Some(now);
+    }
+
+    fn is_stale(&self, now: OffsetDateTime, ttl: Duration) -> bool {
+        match self.last_refresh {
+            None => true,
+            Some(last_refresh) => last_refresh + ttl <= now,
+        }
+    }
+
+    fn lookup(
+        &self,
+        name: &NymName,
+        now: OffsetDateTime,
+    ) -> Result<Recipient, NameResolutionError> {
+        let Some(cached) = self.names.get(name.as_str()) else {
+            return Err(NameResolutionError::NameNotFound {
+                name: name.to_string(),
+            });
+        };
+
+        if cached.expires_at <= now.unix_timestamp() as u64 {
+            return Err(NameResolutionError::NameExpired {
+                name: name.to_string(),
+            });
+        }
+
+        Recipient::try_from_base58_string(&cached.address).map_err(|source| {
+            NameResolutionError::MalformedRegisteredAddress {
+                name: name.to_string(),
+                address: cached.address.clone(),
+                source,
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+struct NameResolverInner {
+    nym_api_urls: Vec<Url>,
+    cache_ttl: Duration,
+    cache: RwLock<NameCache>,
+}
+
+/// Resolves names registered with the name service into nym addresses using the list of names
+/// served by the nym API. The whole list is cached for `cache_ttl` and the registration expiry
+/// is checked on every lookup, so an expired name is never resolved, even if it's still cached.
+#[derive(Debug, Clone)]
+pub struct NameResolver {
+    inner: Arc<NameResolverInner>,
+}
+
+impl NameResolver {
+    pub fn new(nym_api_urls: Vec<Url>) -> Self {
+        Self::new_with_cache_ttl(nym_api_urls, DEFAULT_NAME_CACHE_TTL)
+    }
+
+    pub fn new_with_cache_ttl(mut nym_api_urls: Vec<Url>, cache_ttl: Duration) -> Self {
+        nym_api_urls.shuffle(&mut thread_rng());
+
+        NameResolver {
+            inner: Arc::new(NameResolverInner {
+                nym_api_urls,
+                cache_ttl,
+                cache: RwLock::new(NameCache::default()),
+            }),
+        }
+    }
+
+    async fn fetch_names(&self) -> Result<Vec<NameEntry>, NameResolutionError> {
+        let mut last_error = None;
+        for nym_api_url in &self.inner.nym_api_urls {
+            let client = NymApiClient::new(nym_api_url.clone());
+            match client.get_registered_names().await {
+                Ok(names) => return Ok(names),
+                Err(err) => {
+                    warn!("failed to retrieve registered names from {nym_api_url}: {err}");
+                    last_error = Some(err)
+                }
+            }
+        }
+
+        match last_error {
+            Some(source) => Err(NameResolutionError::NymApiFailure { source }),
+            None => Err(NameResolutionError::NoNymApisAvailable),
+        }
+    }
+
+    async fn refresh(&self) -> Result<(), NameResolutionError> {
+        debug!("refreshing the cache of registered names");
+        let names = self.fetch_names().await?;
+        self.inner
+            .cache
+            .write()
+            .await
+            .rebuild(names, OffsetDateTime::now_utc());
+        Ok(())
+    }
+
+    /// Attempts to resolve the provided name into the nym address it's registered for.
+    pub async fn resolve_name(&self, name: &NymName) -> Result<Recipient, NameResolutionError> {
+        let now = OffsetDateTime::now_utc();
+        let needs_refresh = {
+            let cache = self.inner.cache.read().await;
+            if cache.is_stale(now, self.inner.cache_ttl) {
+                true
+            } else {
+                match cache.lookup(name, now) {
+                    Ok(address) => return Ok(address),
+                    // the name might have been registered since we last looked
+                    Err(NameResolutionError::NameNotFound { .. }) => {
+                        cache.is_stale(now, MIN_REFRESH_INTERVAL)
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        };
+
+        if needs_refresh {
+            self.refresh().await?;
+        }
+
+        self.inner.cache.read().await.lookup(name, now)
+    }
+
+    /// Returns the nym address of the provided destination, resolving it if it's a name.
+    pub async fn resolve(
+        &self,
+        destination: &Destination,
+    ) -> Result<Recipient, NameResolutionError> {
+        match destination {
+            Destination::Address(address) => Ok(*address),
+            Destination::Name(name) => self.resolve_name(name).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_name_service_common::{Addr, Address, Coin, RegisteredName};
+
+    const ADDRESS: &str = "CytBseW6yFXUMzz4SGAKdNLGR7q3sJLLYxyBGvutNEQV.4QXYyEVc5fUDjmmi8PrHN9tdUFV4PCvSJE1278cHyvoe@4sBbL1ngf1vtNqykydQKTFh26sQCw888GpUqvPvyNB4f";
+
+    fn entry(name_id: u32, name: &str, address: &str, expires_at: u64) -> NameEntry {
+        NameEntry::new(
+            name_id,
+            RegisteredName {
+                name: NymName::new(name).unwrap(),
+                address: Address::new(address),
+                owner: Addr::unchecked("owner"),
+                block_height: 1,
+                expires_at,
+                deposit: Coin::new(100, "unym"),
+            },
+        )
+    }
+
+    #[test]
+    fn parsing_destination() {
+        assert!(matches!(
+            ADDRESS.parse::<Destination>().unwrap(),
+            Destination::Address(_)
+        ));
+        assert_eq!(
+            "alice".parse::<Destination>().unwrap(),
+            Destination::Name(NymName::new("alice").unwrap())
+        );
+        assert!(matches!(
+            "foo.bar@baz".parse::<Destination>(),
+            Err(NameResolutionError::MalformedAddress { .. })
+        ));
+        assert!(matches!(
+            "Alice".parse::<Destination>(),
+            Err(NameResolutionError::InvalidName { .. })
+        ));
+    }
+
+    #[test]
+    fn cache_lookup_respects_registration_expiry() {
+        let now = OffsetDateTime::now_utc();
+        let now_secs = now.unix_timestamp() as u64;
+
+        let mut cache = NameCache::default();
+        cache.rebuild(
+            vec![
+                entry(1, "alice", ADDRESS, now_secs + 100),
+                entry(2, "bob", ADDRESS, now_secs - 100),
+                entry(3, "carol", "foomp", now_secs + 100),
+            ],
+            now,
+        );
+
+        assert_eq!(
+            cache
+                .lookup(&NymName::new("alice").unwrap(), now)
+                .unwrap()
+                .to_string(),
+            ADDRESS
+        );
+        assert!(matches!(
+            cache.lookup(&NymName::new("bob").unwrap(), now),
+            Err(NameResolutionError::NameExpired { .. })
+        ));
+        assert!(matches!(
+            cache.lookup(&NymName::new("carol").unwrap(), now),
+            Err(NameResolutionError::MalformedRegisteredAddress { .. })
+        ));
+        assert!(matches!(
+            cache.lookup(&NymName::new("dave").unwrap(), now),
+            Err(NameResolutionError::NameNotFound { .. })
+        ));
+
+        // the registration expires while the entry is still cached
+        assert!(matches!(
+            cache.lookup(
+                &NymName::new("alice").unwrap(),
+                now + Duration::from_secs(200)
+            ),
+            Err(NameResolutionError::NameExpired { .. })
+        ));
+    }
+
+    #[test]
+    fn reregistered_name_uses_latest_registration() {
+        let now = OffsetDateTime::now_utc();
+        let now_secs = now.unix_timestamp() as u64;
+
+        let mut cache = NameCache::default();
+        cache.rebuild(
+            vec![
+                entry(1, "alice", "foomp", now_secs - 100),
+                entry(2, "alice", ADDRESS, now_secs + 100),
+            ],
+            now,
+        );
+
+        assert!(cache.lookup(&NymName::new("alice").unwrap(), now).is_ok());
+    }
+
+    #[test]
+    fn cache_staleness() {
+        let now = OffsetDateTime::now_utc();
+        let mut cache = NameCache::default();
+        assert!(cache.is_stale(now, DEFAULT_NAME_CACHE_TTL));
+
+        cache.rebuild(Vec::new(), now);
+        assert!(!cache.is_stale(now, DEFAULT_NAME_CACHE_TTL));
+        assert!(cache.is_stale(now + DEFAULT_NAME_CACHE_TTL, DEFAULT_NAME_CACHE_TTL));
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use thiserror::Error;
+
+#[cfg(target_arch = "wasm32")]
+use wasm_utils::storage::error::StorageError as WasmStorageError;
+
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[cfg(target_arch = "wasm32")]
+    #[error("failed to use the underlying browser storage: {source}")]
+    InternalStorageError {
+        #[from]
+        source: WasmStorageError,
+    },
+
+    #[error("The loaded data is inconsistent - it seems that on the last shutdown the client hasn't finished the data flush")]
+    IncompleteDataFlush,
+
+    #[error("the storage does not contain any reply surb metadata")]
+    MissingReplySurbStorageMetadata,
+
+    #[error("data retrieved from the underlying storage is corrupted: {details}")]
+    CorruptedData { details: String },
+}
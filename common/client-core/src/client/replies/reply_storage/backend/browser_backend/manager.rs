@@ -0,0 +1,193 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::replies::reply_storage::backend::browser_backend::error::StorageError;
+use crate::client::replies::reply_storage::backend::browser_backend::models::{
+    ReplySurbStorageMetadata, StoredReplyKey, StoredSenderTag, StoredSurbSender,
+};
+use crate::client::replies::reply_storage::backend::browser_backend::schema::{
+    stores_to_create, v1, STORAGE_VERSION,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use wasm_bindgen::JsValue;
+use wasm_utils::storage::{IdbVersionChangeEvent, WasmStorage};
+
+const STORAGE_NAME_PREFIX: &str = "wasm-client-reply-storage";
+
+pub(crate) struct StorageManager {
+    storage: WasmStorage,
+}
+
+impl StorageManager {
+    fn db_name(client_id: &str) -> String {
+        format!("{STORAGE_NAME_PREFIX}-{client_id}")
+    }
+
+    pub(crate) async fn init(
+        client_id: &str,
+        passphrase: Option<&[u8]>,
+    ) -> Result<Self, StorageError> {
+        let migrate_fn = Some(|evt: &IdbVersionChangeEvent| -> Result<(), JsValue> {
+            // Even if the web-sys bindings expose the version as a f64, the IndexedDB API
+            // works with an unsigned integer.
+            // See <https://github.com/rustwasm/wasm-bindgen/issues/1149>
+            let old_version = evt.old_version() as u32;
+
+            let db = evt.db();
+            for store in stores_to_create(old_version) {
+                db.create_object_store(store)?;
+            }
+
+            Ok(())
+        });
+
+        let storage = WasmStorage::new(
+            &Self::db_name(client_id),
+            STORAGE_VERSION,
+            migrate_fn,
+            passphrase,
+        )
+        .await?;
+
+        Ok(StorageManager { storage })
+    }
+
+    async fn read_status<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, StorageError> {
+        self.storage
+            .read_value(v1::STATUS_STORE, JsValue::from_str(key))
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn store_status<T: Serialize>(&self, key: &str, value: &T) -> Result<(), StorageError> {
+        self.storage
+            .store_value(v1::STATUS_STORE, JsValue::from_str(key), value)
+            .await
+            .map_err(Into::into)
+    }
+
+    // note: the entries are keyed by their position rather than by any of their content
+    // as the keys themselves are never encrypted
+    async fn store_all<T: Serialize>(&self, store: &str, values: &[T]) -> Result<(), StorageError> {
+        for (i, value) in values.iter().enumerate() {
+            self.storage
+                .store_value(store, JsValue::from(i as u32), value)
+                .await?;
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn get_flush_status(&self) -> Result<bool, StorageError> {
+        Ok(self
+            .read_status(v1::FLUSH_IN_PROGRESS)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn set_flush_status(&self, in_progress: bool) -> Result<(), StorageError> {
+        self.store_status(v1::FLUSH_IN_PROGRESS, &in_progress).await
+    }
+
+    pub(crate) async fn get_previous_flush_timestamp(&self) -> Result<i64, StorageError> {
+        Ok(self
+            .read_status(v1::PREVIOUS_FLUSH_TIMESTAMP)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn set_previous_flush_timestamp(
+        &self,
+        timestamp: i64,
+    ) -> Result<(), StorageError> {
+        self.store_status(v1::PREVIOUS_FLUSH_TIMESTAMP, &timestamp)
+            .await
+    }
+
+    pub(crate) async fn get_client_in_use_status(&self) -> Result<bool, StorageError> {
+        Ok(self
+            .read_status(v1::CLIENT_IN_USE)
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn set_client_in_use_status(&self, in_use: bool) -> Result<(), StorageError> {
+        self.store_status(v1::CLIENT_IN_USE, &in_use).await
+    }
+
+    pub(crate) async fn get_reply_surb_storage_metadata(
+        &self,
+    ) -> Result<ReplySurbStorageMetadata, StorageError> {
+        self.read_status(v1::REPLY_SURB_STORAGE_METADATA)
+            .await?
+            .ok_or(StorageError::MissingReplySurbStorageMetadata)
+    }
+
+    pub(crate) async fn insert_reply_surb_storage_metadata(
+        &self,
+        metadata: ReplySurbStorageMetadata,
+    ) -> Result<(), StorageError> {
+        self.store_status(v1::REPLY_SURB_STORAGE_METADATA, &metadata)
+            .await
+    }
+
+    pub(crate) async fn get_tags(&self) -> Result<Vec<StoredSenderTag>, StorageError> {
+        self.storage
+            .read_all_values(v1::SENDER_TAGS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn insert_tags(&self, tags: &[StoredSenderTag]) -> Result<(), StorageError> {
+        self.store_all(v1::SENDER_TAGS_STORE, tags).await
+    }
+
+    pub(crate) async fn delete_all_tags(&self) -> Result<(), StorageError> {
+        self.storage
+            .clear_store(v1::SENDER_TAGS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn get_reply_keys(&self) -> Result<Vec<StoredReplyKey>, StorageError> {
+        self.storage
+            .read_all_values(v1::REPLY_KEYS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn insert_reply_keys(
+        &self,
+        reply_keys: &[StoredReplyKey],
+    ) -> Result<(), StorageError> {
+        self.store_all(v1::REPLY_KEYS_STORE, reply_keys).await
+    }
+
+    pub(crate) async fn delete_all_reply_keys(&self) -> Result<(), StorageError> {
+        self.storage
+            .clear_store(v1::REPLY_KEYS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn get_surb_senders(&self) -> Result<Vec<StoredSurbSender>, StorageError> {
+        self.storage
+            .read_all_values(v1::REPLY_SURBS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+
+    pub(crate) async fn insert_surb_senders(
+        &self,
+        senders: &[StoredSurbSender],
+    ) -> Result<(), StorageError> {
+        self.store_all(v1::REPLY_SURBS_STORE, senders).await
+    }
+
+    pub(crate) async fn delete_all_reply_surb_data(&self) -> Result<(), StorageError> {
+        self.storage
+            .clear_store(v1::REPLY_SURBS_STORE)
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,376 @@
+// Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(target_arch = "wasm32")]
+use crate::client::replies::reply_storage::backend::browser_backend::manager::StorageManager;
+#[cfg(target_arch = "wasm32")]
+use crate::client::replies::reply_storage::backend::browser_backend::models::{
+    ReplySurbStorageMetadata, StoredReplyKey, StoredSenderTag, StoredSurbSender,
+};
+#[cfg(target_arch = "wasm32")]
+use crate::client::replies::reply_storage::{
+    CombinedReplyStorage, ReceivedReplySurbsMap, ReplyStorageBackend, SentReplyKeys, UsedSenderTags,
+};
+#[cfg(target_arch = "wasm32")]
+use async_trait::async_trait;
+#[cfg(target_arch = "wasm32")]
+use log::{error, info};
+#[cfg(target_arch = "wasm32")]
+use std::time::Duration;
+#[cfg(target_arch = "wasm32")]
+use time::OffsetDateTime;
+
+pub use self::error::StorageError;
+
+mod error;
+#[cfg(target_arch = "wasm32")]
+mod manager;
+mod models;
+mod schema;
+
+// the browser tab might get closed at any point without the client going through a graceful shutdown,
+// so the data has to be regularly flushed in order to be able to restore it afterwards
+#[cfg(target_arch = "wasm32")]
+const FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Reply storage backed by the browser's IndexedDB, optionally encrypted with a passphrase.
+#[cfg(target_arch = "wasm32")]
+pub struct Backend {
+    manager: StorageManager,
+}
+
+// Safety: when compiled to wasm32 everything is going to be running on a single thread,
+// so the underlying `IdbDatabase` handle is never actually shared between threads.
+// Same caveat as with the `JSWebsocket` applies: this will have to be revisited once
+// wasm becomes properly multi-threaded.
+#[cfg(target_arch = "wasm32")]
+unsafe impl Send for Backend {}
+#[cfg(target_arch = "wasm32")]
+unsafe impl Sync for Backend {}
+
+#[cfg(target_arch = "wasm32")]
+impl Backend {
+    /// Opens the storage of the provided client and removes any data it might have contained.
+    pub async fn init(client_id: &str, passphrase: Option<&[u8]>) -> Result<Self, StorageError> {
+        let manager = StorageManager::init(client_id, passphrase).await?;
+
+        manager.delete_all_reply_surb_data().await?;
+        manager.delete_all_reply_keys().await?;
+        manager.delete_all_tags().await?;
+        manager.set_flush_status(false).await?;
+        manager.set_previous_flush_timestamp(0).await?;
+        manager.set_client_in_use_status(false).await?;
+
+        Ok(Backend { manager })
+    }
+
+    /// Opens the storage of the provided client making sure its data can still be used.
+    pub async fn try_load(
+        client_id: &str,
+        passphrase: Option<&[u8]>,
+    ) -> Result<Self, StorageError> {
+        let manager = StorageManager::init(client_id, passphrase).await?;
+
+        // either this is a brand new storage or the previous `init_fresh` has never completed,
+        // in either case we don't know the correct starting metadata
+        manager.get_reply_surb_storage_metadata().await?;
+
+        // the data flush wasn't fully finished and thus the data is in inconsistent state
+        // (we don't really know what's properly saved or what's not)
+        if manager.get_flush_status().await? {
+            return Err(StorageError::IncompleteDataFlush);
+        }
+
+        let last_flush_timestamp = manager.get_previous_flush_timestamp().await?;
+        if last_flush_timestamp == 0 {
+            // either this client has been running since 1970 or the flush failed
+            return Err(StorageError::IncompleteDataFlush);
+        }
+
+        // the client has gone down before it managed to flush any data in this session,
+        // meaning the storage might contain reply surbs and keys that have already been used
+        // so we have to purge it
+        if manager.get_client_in_use_status().await? {
+            error!("the client has gone down without flushing its data the last time it was running - we can't trust its reply surbs or stored encryption keys. They shall get purged");
+            manager.delete_all_reply_surb_data().await?;
+            manager.delete_all_reply_keys().await?;
+        }
+
+        let last_flush = match OffsetDateTime::from_unix_timestamp(last_flush_timestamp) {
+            Ok(last_flush) => last_flush,
+            Err(err) => {
+                return Err(StorageError::CorruptedData {
+                    details: format!("failed to parse stored timestamp - {err}"),
+                });
+            }
+        };
+
+        // same retention rules as for the on-disk storage
+        let since_last_flush = OffsetDateTime::now_utc() - last_flush;
+        let days = since_last_flush.whole_days();
+        let hours = since_last_flush.whole_hours() % 24;
+
+        if days > 0 {
+            info!("it's been over {days} days and {hours} hours since we last used our data store. our reply surbs are already outdated - we're going to purge them now.");
+            manager.delete_all_reply_surb_data().await?;
+        }
+
+        if days > 1 {
+            info!("it's been over {days} days and {hours} hours since we last used our data store. our reply keys are already outdated - we're going to purge them now.");
+            manager.delete_all_reply_keys().await?;
+        }
+
+        if days > 2 {
+            info!("it's been over {days} days and {hours} hours since we last used our data store. our used sender tags are already outdated - we're going to purge them now.");
+            manager.delete_all_tags().await?;
+        }
+
+        Ok(Backend { manager })
+    }
+
+    async fn start_storage_flush(&self) -> Result<(), StorageError> {
+        self.manager.set_flush_status(true).await
+    }
+
+    async fn end_storage_flush(&self) -> Result<(), StorageError> {
+        self.manager
+            .set_previous_flush_timestamp(OffsetDateTime::now_utc().unix_timestamp())
+            .await?;
+        self.manager.set_flush_status(false).await
+    }
+
+    async fn start_client_use(&self) -> Result<(), StorageError> {
+        self.manager.set_client_in_use_status(true).await
+    }
+
+    async fn stop_client_use(&self) -> Result<(), StorageError> {
+        self.manager.set_client_in_use_status(false).await
+    }
+
+    // there's no file to rotate here, so instead the old entries are removed
+    // after the flush has been marked as started
+    async fn purge_old_data(&self) -> Result<(), StorageError> {
+        self.manager.delete_all_tags().await?;
+        self.manager.delete_all_reply_keys().await?;
+        self.manager.delete_all_reply_surb_data().await
+    }
+
+    async fn get_stored_tags(&self) -> Result<UsedSenderTags, StorageError> {
+        let stored = self.manager.get_tags().await?;
+
+        // stop at the first instance of corruption. if even a single entry is malformed,
+        // something weird has happened and we can't trust the rest of the data
+        let raw = stored
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(UsedSenderTags::from_raw(raw))
+    }
+
+    async fn dump_sender_tags(&self, tags: &UsedSenderTags) -> Result<(), StorageError> {
+        let stored = tags
+            .as_raw_iter()
+            .map(|map_ref| {
+                let (recipient, tag) = map_ref.pair();
+                StoredSenderTag::new(*recipient, *tag)
+            })
+            .collect::<Vec<_>>();
+
+        self.manager.insert_tags(&stored).await
+    }
+
+    async fn get_stored_reply_keys(&self) -> Result<SentReplyKeys, StorageError> {
+        let stored = self.manager.get_reply_keys().await?;
+
+        // stop at the first instance of corruption. if even a single entry is malformed,
+        // something weird has happened and we can't trust the rest of the data
+        let raw = stored
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(SentReplyKeys::from_raw(raw))
+    }
+
+    async fn dump_sender_reply_keys(&self, reply_keys: &SentReplyKeys) -> Result<(), StorageError> {
+        let stored = reply_keys
+            .as_raw_iter()
+            .map(|map_ref| {
+                let (digest, key) = map_ref.pair();
+                StoredReplyKey::new(*digest, *key)
+            })
+            .collect::<Vec<_>>();
+
+        self.manager.insert_reply_keys(&stored).await
+    }
+
+    async fn get_stored_reply_surbs(&self) -> Result<ReceivedReplySurbsMap, StorageError> {
+        let metadata = self.manager.get_reply_surb_storage_metadata().await?;
+        let received_surbs = self
+            .manager
+            .get_surb_senders()
+            .await?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        Ok(ReceivedReplySurbsMap::from_raw(
+            metadata.min_reply_surb_threshold as usize,
+            metadata.max_reply_surb_threshold as usize,
+            received_surbs,
+        ))
+    }
+
+    async fn dump_reply_surbs(
+        &self,
+        reply_surbs: &ReceivedReplySurbsMap,
+    ) -> Result<(), StorageError> {
+        let stored = reply_surbs
+            .as_raw_iter()
+            .map(|map_ref| {
+                let (tag, received_surbs) = map_ref.pair();
+                StoredSurbSender::new(*tag, received_surbs)
+            })
+            .collect::<Vec<_>>();
+
+        self.manager.insert_surb_senders(&stored).await
+    }
+
+    async fn dump_reply_surb_storage_metadata(
+        &self,
+        reply_surbs: &ReceivedReplySurbsMap,
+    ) -> Result<(), StorageError> {
+        self.manager
+            .insert_reply_surb_storage_metadata(ReplySurbStorageMetadata::new(
+                reply_surbs.min_surb_threshold(),
+                reply_surbs.max_surb_threshold(),
+            ))
+            .await
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+#[async_trait(?Send)]
+impl ReplyStorageBackend for Backend {
+    type StorageError = StorageError;
+
+    async fn start_storage_session(&self) -> Result<(), Self::StorageError> {
+        self.start_client_use().await
+    }
+
+    fn flush_interval(&self) -> Option<Duration> {
+        Some(FLUSH_INTERVAL)
+    }
+
+    async fn flush_surb_storage(
+        &mut self,
+        storage: &CombinedReplyStorage,
+    ) -> Result<(), Self::StorageError> {
+        self.start_storage_flush().await?;
+        self.purge_old_data().await?;
+
+        self.dump_sender_tags(storage.tags_storage_ref()).await?;
+        self.dump_sender_reply_keys(storage.key_storage_ref())
+            .await?;
+        let surbs_ref = storage.surbs_storage_ref();
+        self.dump_reply_surb_storage_metadata(surbs_ref).await?;
+        self.dump_reply_surbs(surbs_ref).await?;
+
+        self.end_storage_flush().await?;
+
+        // the flushed data is consistent on its own, so if the tab gets closed before the next flush,
+        // it can still be restored (at worst containing surbs that have been used since)
+        self.stop_client_use().await
+    }
+
+    async fn init_fresh(&mut self, fresh: &CombinedReplyStorage) -> Result<(), Self::StorageError> {
+        // for now nothing more to do apart from dumping the metadata
+        self.dump_reply_surb_storage_metadata(fresh.surbs_storage_ref())
+            .await
+    }
+
+    async fn load_surb_storage(&self) -> Result<CombinedReplyStorage, Self::StorageError> {
+        let reply_keys = self.get_stored_reply_keys().await?;
+        let tags = self.get_stored_tags().await?;
+        let reply_surbs = self.get_stored_reply_surbs().await?;
+
+        Ok(CombinedReplyStorage::load(reply_keys, reply_surbs, tags))
+    }
+
+    async fn stop_storage_session(self) -> Result<(), Self::StorageError> {
+        self.stop_client_use().await
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use crate::client::replies::reply_storage::backend::browser_backend::models::tests::{
+        test_recipient, test_topology,
+    };
+    use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
+    use nym_sphinx::anonymous_replies::{ReplySurb, SurbEncryptionKey};
+    use rand::rngs::OsRng;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    async fn flushed_data_is_restored_after_reload_without_shutdown() {
+        let client_id = "reload-without-shutdown";
+
+        let mut backend = Backend::init(client_id, None).await.unwrap();
+        let mem_state = CombinedReplyStorage::new(10, 100);
+        backend.init_fresh(&mem_state).await.unwrap();
+        backend.start_storage_session().await.unwrap();
+
+        let reply_key = SurbEncryptionKey::new(&mut OsRng);
+        let digest = reply_key.compute_digest();
+        mem_state.key_storage_ref().insert_multiple(vec![reply_key]);
+
+        let recipient = test_recipient();
+        let topology = test_topology();
+        let reply_surbs = (0..3).map(|_| {
+            ReplySurb::construct(&mut OsRng, &recipient, Duration::from_millis(50), &topology)
+                .unwrap()
+        });
+        let tag = AnonymousSenderTag::new_random(&mut OsRng);
+        mem_state
+            .surbs_storage_ref()
+            .insert_surbs(&tag, reply_surbs);
+
+        // what the periodic flush does while the client is running
+        backend.flush_surb_storage(&mem_state).await.unwrap();
+
+        // the tab gets closed, so the storage session is never stopped
+        drop(backend);
+
+        let reloaded = Backend::try_load(client_id, None).await.unwrap();
+        let restored = reloaded.load_surb_storage().await.unwrap();
+        assert!(restored.key_storage_ref().try_pop(digest).is_some());
+        assert_eq!(restored.surbs_storage_ref().available_surbs(&tag), 3);
+    }
+
+    #[wasm_bindgen_test]
+    async fn unflushed_session_is_purged_after_reload_without_shutdown() {
+        let client_id = "reload-without-flush";
+
+        let mut backend = Backend::init(client_id, None).await.unwrap();
+        let mem_state = CombinedReplyStorage::new(10, 100);
+        backend.init_fresh(&mem_state).await.unwrap();
+
+        let reply_key = SurbEncryptionKey::new(&mut OsRng);
+        let digest = reply_key.compute_digest();
+        mem_state.key_storage_ref().insert_multiple(vec![reply_key]);
+        backend.flush_surb_storage(&mem_state).await.unwrap();
+
+        // the data gets loaded again, but the tab is closed before anything is flushed
+        backend.start_storage_session().await.unwrap();
+        drop(backend);
+
+        let reloaded = Backend::try_load(client_id, None).await.unwrap();
+        let restored = reloaded.load_surb_storage().await.unwrap();
+        assert!(restored.key_storage_ref().try_pop(digest).is_none());
+    }
+}
@@ -0,0 +1,371 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::replies::reply_storage::backend::browser_backend::error::StorageError;
+use crate::client::replies::reply_storage::key_storage::UsedReplyKey;
+use crate::client::replies::reply_storage::surb_storage::ReceivedReplySurbs;
+use nym_crypto::generic_array::typenum::Unsigned;
+use nym_crypto::Digest;
+use nym_sphinx::addressing::clients::{Recipient, RecipientBytes};
+use nym_sphinx::anonymous_replies::encryption_key::EncryptionKeyDigest;
+use nym_sphinx::anonymous_replies::requests::{AnonymousSenderTag, SENDER_TAG_SIZE};
+use nym_sphinx::anonymous_replies::{ReplySurb, SurbEncryptionKey, SurbEncryptionKeySize};
+use nym_sphinx::params::ReplySurbKeyDigestAlgorithm;
+use serde::{Deserialize, Serialize};
+
+fn corrupted_sender_tag(tag_len: usize) -> StorageError {
+    StorageError::CorruptedData {
+        details: format!(
+            "the retrieved sender tag has length of {tag_len} while {SENDER_TAG_SIZE} was expected",
+        ),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct StoredSenderTag {
+    pub(crate) recipient: Vec<u8>,
+    pub(crate) tag: Vec<u8>,
+}
+
+impl StoredSenderTag {
+    pub(crate) fn new(recipient: RecipientBytes, tag: AnonymousSenderTag) -> StoredSenderTag {
+        StoredSenderTag {
+            recipient: recipient.to_vec(),
+            tag: tag.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<StoredSenderTag> for (RecipientBytes, AnonymousSenderTag) {
+    type Error = StorageError;
+
+    fn try_from(value: StoredSenderTag) -> Result<Self, Self::Error> {
+        let recipient_len = value.recipient.len();
+        let Ok(recipient_bytes) = value.recipient.try_into() else {
+            return Err(StorageError::CorruptedData {
+                details: format!(
+                    "the retrieved recipient has length of {recipient_len} while {} was expected",
+                    Recipient::LEN
+                ),
+            });
+        };
+
+        let tag_len = value.tag.len();
+        let sender_tag_bytes = value
+            .tag
+            .try_into()
+            .map_err(|_| corrupted_sender_tag(tag_len))?;
+
+        Ok((
+            recipient_bytes,
+            AnonymousSenderTag::from_bytes(sender_tag_bytes),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct StoredReplyKey {
+    pub(crate) key_digest: Vec<u8>,
+    pub(crate) reply_key: Vec<u8>,
+    pub(crate) sent_at_timestamp: i64,
+}
+
+impl StoredReplyKey {
+    pub(crate) fn new(key_digest: EncryptionKeyDigest, reply_key: UsedReplyKey) -> StoredReplyKey {
+        StoredReplyKey {
+            key_digest: key_digest.to_vec(),
+            reply_key: (*reply_key).to_bytes(),
+            sent_at_timestamp: reply_key.sent_at_timestamp,
+        }
+    }
+}
+
+impl TryFrom<StoredReplyKey> for (EncryptionKeyDigest, UsedReplyKey) {
+    type Error = StorageError;
+
+    fn try_from(value: StoredReplyKey) -> Result<Self, Self::Error> {
+        let expected_reply_key_digest_size = ReplySurbKeyDigestAlgorithm::output_size();
+        let reply_key_digest_size = value.key_digest.len();
+
+        let digest = EncryptionKeyDigest::from_exact_iter(value.key_digest).ok_or_else(|| {
+            StorageError::CorruptedData {
+                details: format!(
+                    "the reply surb digest has length of {reply_key_digest_size} while {expected_reply_key_digest_size} was expected",
+                ),
+            }
+        })?;
+
+        let reply_key_len = value.reply_key.len();
+        let reply_key = SurbEncryptionKey::try_from_bytes(&value.reply_key).map_err(|_| {
+            StorageError::CorruptedData {
+                details: format!(
+                    "the reply key has length of {reply_key_len} while {} was expected",
+                    SurbEncryptionKeySize::USIZE
+                ),
+            }
+        })?;
+
+        Ok((
+            digest,
+            UsedReplyKey::new(reply_key, value.sent_at_timestamp),
+        ))
+    }
+}
+
+// unlike the sqlite schema, the surbs are kept together with their sender so that they could be
+// retrieved (and written) in a single indexeddb request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct StoredSurbSender {
+    pub(crate) tag: Vec<u8>,
+    pub(crate) last_sent_timestamp: i64,
+    pub(crate) reply_surbs: Vec<Vec<u8>>,
+}
+
+impl StoredSurbSender {
+    pub(crate) fn new(tag: AnonymousSenderTag, received_surbs: &ReceivedReplySurbs) -> Self {
+        StoredSurbSender {
+            tag: tag.to_bytes().to_vec(),
+            last_sent_timestamp: received_surbs.surbs_last_received_at(),
+            reply_surbs: received_surbs
+                .surbs_ref()
+                .iter()
+                .map(|reply_surb| reply_surb.to_bytes())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<StoredSurbSender> for (AnonymousSenderTag, ReceivedReplySurbs) {
+    type Error = StorageError;
+
+    fn try_from(value: StoredSurbSender) -> Result<Self, Self::Error> {
+        let tag_len = value.tag.len();
+        let sender_tag_bytes = value
+            .tag
+            .try_into()
+            .map_err(|_| corrupted_sender_tag(tag_len))?;
+
+        let reply_surbs = value
+            .reply_surbs
+            .iter()
+            .map(|raw| {
+                ReplySurb::from_bytes(raw).map_err(|err| StorageError::CorruptedData {
+                    details: format!("failed to recover the reply surb: {err}"),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((
+            AnonymousSenderTag::from_bytes(sender_tag_bytes),
+            ReceivedReplySurbs::new_retrieved(reply_surbs, value.last_sent_timestamp),
+        ))
+    }
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub(crate) struct ReplySurbStorageMetadata {
+    pub(crate) min_reply_surb_threshold: u32,
+    pub(crate) max_reply_surb_threshold: u32,
+}
+
+impl ReplySurbStorageMetadata {
+    pub(crate) fn new(min_reply_surb_threshold: usize, max_reply_surb_threshold: usize) -> Self {
+        Self {
+            min_reply_surb_threshold: min_reply_surb_threshold as u32,
+            max_reply_surb_threshold: max_reply_surb_threshold as u32,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_store_cipher::{EncryptedData, KdfInfo, StoreCipher};
+    use nym_topology::mix::Layer;
+    use nym_topology::{gateway, mix, NymTopology};
+    use rand::rngs::OsRng;
+    use serde::de::DeserializeOwned;
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    const GATEWAY_IDENTITY: &str = "FioFa8nMmPpQnYi7JyojoTuwGLeyNS8BF4ChPr29zUML";
+    const GATEWAY_SPHINX: &str = "EB42xvMFMD5rUCstE2CDazgQQJ22zLv8SPm1Luxni44c";
+    const MIX_KEYS: [(&str, &str); 3] = [
+        (
+            "3ebjp1Fb9hdcS1AR6AZihgeJiMHkB5jjJUsvqNnfQwU7",
+            "B3GzG62aXAZNg14RoMCp3BhELNBrySLr2JqrwyfYFzRc",
+        ),
+        (
+            "D6YaMzLSY7mANtSQRKXsmMZpqgqiVkeiagKM4V4oFPFr",
+            "5Z1VqYwM2xeKxd8H7fJpGWasNiDFijYBAee7MErkZ5QT",
+        ),
+        (
+            "GkWDysw4AjESv1KiAiVn7JzzCMJeksxNSXVfr1PpX8wD",
+            "9EyjhCggr2QEA2nakR88YHmXgpy92DWxoe2draDRkYof",
+        ),
+    ];
+
+    fn test_cipher() -> StoreCipher {
+        StoreCipher::new_aes256gcm(
+            b"my-secret-passphrase",
+            KdfInfo::new_with_default_settings().unwrap(),
+        )
+        .unwrap()
+    }
+
+    // natively we don't have the browser serializer, but json is what the cipher operates on anyway
+    fn store_and_load<T>(value: &T, cipher: Option<&StoreCipher>) -> T
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        match cipher {
+            Some(cipher) => {
+                let encrypted = cipher.encrypt_json_value(value).unwrap();
+                let stored = serde_json::to_string(&encrypted).unwrap();
+                let retrieved: EncryptedData = serde_json::from_str(&stored).unwrap();
+                cipher.decrypt_json_value(retrieved).unwrap()
+            }
+            None => serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap(),
+        }
+    }
+
+    pub(crate) fn test_recipient() -> Recipient {
+        Recipient::new(
+            identity::PublicKey::from_base58_string(MIX_KEYS[0].0).unwrap(),
+            encryption::PublicKey::from_base58_string(MIX_KEYS[0].1).unwrap(),
+            identity::PublicKey::from_base58_string(GATEWAY_IDENTITY).unwrap(),
+        )
+    }
+
+    pub(crate) fn test_topology() -> NymTopology {
+        let layers = [Layer::One, Layer::Two, Layer::Three];
+        let mut mixes = BTreeMap::new();
+        for (i, (layer, (identity_key, sphinx_key))) in layers.into_iter().zip(MIX_KEYS).enumerate()
+        {
+            mixes.insert(
+                layer.into(),
+                vec![mix::Node {
+                    mix_id: i as u32 + 1,
+                    owner: format!("owner{i}"),
+                    host: "10.20.30.40".parse().unwrap(),
+                    mix_host: "10.20.30.40:1789".parse().unwrap(),
+                    identity_key: identity::PublicKey::from_base58_string(identity_key).unwrap(),
+                    sphinx_key: encryption::PublicKey::from_base58_string(sphinx_key).unwrap(),
+                    layer,
+                    version: "1.1.14".to_string(),
+                }],
+            );
+        }
+
+        NymTopology::new(
+            mixes,
+            vec![gateway::Node {
+                owner: "gateway-owner".to_string(),
+                host: "1.2.3.4".parse().unwrap(),
+                mix_host: "1.2.3.4:1789".parse().unwrap(),
+                clients_port: 9000,
+                identity_key: identity::PublicKey::from_base58_string(GATEWAY_IDENTITY).unwrap(),
+                sphinx_key: encryption::PublicKey::from_base58_string(GATEWAY_SPHINX).unwrap(),
+                version: "1.1.14".to_string(),
+            }],
+        )
+    }
+
+    fn check_sender_tag_round_trip(cipher: Option<&StoreCipher>) {
+        let recipient = test_recipient().to_bytes();
+        let tag = AnonymousSenderTag::new_random(&mut OsRng);
+
+        let stored = store_and_load(&StoredSenderTag::new(recipient, tag), cipher);
+        let (retrieved_recipient, retrieved_tag) = stored.try_into().unwrap();
+        assert_eq!(retrieved_recipient, recipient);
+        assert_eq!(retrieved_tag, tag);
+    }
+
+    fn check_reply_key_round_trip(cipher: Option<&StoreCipher>) {
+        let key = UsedReplyKey::new(SurbEncryptionKey::new(&mut OsRng), 1234567);
+        let digest = key.compute_digest();
+
+        let stored = store_and_load(&StoredReplyKey::new(digest, key), cipher);
+        let (retrieved_digest, retrieved_key): (EncryptionKeyDigest, UsedReplyKey) =
+            stored.try_into().unwrap();
+        assert_eq!(retrieved_digest, digest);
+        assert_eq!(retrieved_key.to_bytes(), key.to_bytes());
+        assert_eq!(retrieved_key.sent_at_timestamp, key.sent_at_timestamp);
+    }
+
+    fn check_surb_sender_round_trip(cipher: Option<&StoreCipher>) {
+        let recipient = test_recipient();
+        let topology = test_topology();
+        let reply_surbs = (0..3)
+            .map(|_| {
+                ReplySurb::construct(&mut OsRng, &recipient, Duration::from_millis(50), &topology)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let tag = AnonymousSenderTag::new_random(&mut OsRng);
+        let received = ReceivedReplySurbs::new_retrieved(reply_surbs, 1234567);
+
+        let stored = store_and_load(&StoredSurbSender::new(tag, &received), cipher);
+        let (retrieved_tag, retrieved_surbs): (AnonymousSenderTag, ReceivedReplySurbs) =
+            stored.try_into().unwrap();
+        assert_eq!(retrieved_tag, tag);
+        assert_eq!(retrieved_surbs.surbs_last_received_at(), 1234567);
+
+        let original = received
+            .surbs_ref()
+            .iter()
+            .map(ReplySurb::to_bytes)
+            .collect::<Vec<_>>();
+        let retrieved = retrieved_surbs
+            .surbs_ref()
+            .iter()
+            .map(ReplySurb::to_bytes)
+            .collect::<Vec<_>>();
+        assert_eq!(original, retrieved);
+    }
+
+    fn check_metadata_round_trip(cipher: Option<&StoreCipher>) {
+        let stored = store_and_load(&ReplySurbStorageMetadata::new(10, 100), cipher);
+        assert_eq!(stored.min_reply_surb_threshold, 10);
+        assert_eq!(stored.max_reply_surb_threshold, 100);
+    }
+
+    #[test]
+    fn models_survive_plaintext_storage() {
+        check_sender_tag_round_trip(None);
+        check_reply_key_round_trip(None);
+        check_surb_sender_round_trip(None);
+        check_metadata_round_trip(None);
+    }
+
+    #[test]
+    fn models_survive_encrypted_storage() {
+        let cipher = test_cipher();
+        check_sender_tag_round_trip(Some(&cipher));
+        check_reply_key_round_trip(Some(&cipher));
+        check_surb_sender_round_trip(Some(&cipher));
+        check_metadata_round_trip(Some(&cipher));
+    }
+
+    #[test]
+    fn corrupted_models_are_rejected() {
+        let tag = AnonymousSenderTag::new_random(&mut OsRng);
+        let mut stored_tag = StoredSenderTag::new(test_recipient().to_bytes(), tag);
+        stored_tag.recipient.pop();
+        let res: Result<(RecipientBytes, AnonymousSenderTag), _> = stored_tag.try_into();
+        assert!(matches!(res, Err(StorageError::CorruptedData { .. })));
+
+        let key = UsedReplyKey::new(SurbEncryptionKey::new(&mut OsRng), 1234567);
+        let mut stored_key = StoredReplyKey::new(key.compute_digest(), key);
+        stored_key.reply_key.push(42);
+        let res: Result<(EncryptionKeyDigest, UsedReplyKey), _> = stored_key.try_into();
+        assert!(matches!(res, Err(StorageError::CorruptedData { .. })));
+
+        let received = ReceivedReplySurbs::new_retrieved(Vec::new(), 1234567);
+        let mut stored_sender = StoredSurbSender::new(tag, &received);
+        stored_sender.reply_surbs.push(vec![1, 2, 3]);
+        let res: Result<(AnonymousSenderTag, ReceivedReplySurbs), _> = stored_sender.try_into();
+        assert!(matches!(res, Err(StorageError::CorruptedData { .. })));
+    }
+}
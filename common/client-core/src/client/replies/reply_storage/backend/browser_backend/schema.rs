@@ -0,0 +1,60 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+pub(crate) const STORAGE_VERSION: u32 = 1;
+
+// v1 tables
+pub(crate) mod v1 {
+    // stores
+    pub const STATUS_STORE: &str = "status";
+    pub const SENDER_TAGS_STORE: &str = "sender_tags";
+    pub const REPLY_KEYS_STORE: &str = "reply_keys";
+    pub const REPLY_SURBS_STORE: &str = "reply_surbs";
+
+    // keys
+    pub const FLUSH_IN_PROGRESS: &str = "flush_in_progress";
+    pub const PREVIOUS_FLUSH_TIMESTAMP: &str = "previous_flush_timestamp";
+    pub const CLIENT_IN_USE: &str = "client_in_use";
+    pub const REPLY_SURB_STORAGE_METADATA: &str = "reply_surb_storage_metadata";
+}
+
+/// Returns the object stores that have to be created when upgrading the database from the provided version.
+pub(crate) fn stores_to_create(old_version: u32) -> Vec<&'static str> {
+    let mut stores = Vec::new();
+
+    if old_version < 1 {
+        // migrating to version 1
+        stores.extend([
+            v1::STATUS_STORE,
+            v1::SENDER_TAGS_STORE,
+            v1::REPLY_KEYS_STORE,
+            v1::REPLY_SURBS_STORE,
+        ]);
+    }
+
+    stores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_database_gets_all_v1_stores() {
+        assert_eq!(
+            stores_to_create(0),
+            vec![
+                v1::STATUS_STORE,
+                v1::SENDER_TAGS_STORE,
+                v1::REPLY_KEYS_STORE,
+                v1::REPLY_SURBS_STORE,
+            ]
+        );
+    }
+
+    #[test]
+    fn v1_database_is_left_untouched() {
+        assert!(stores_to_create(1).is_empty());
+        assert!(stores_to_create(STORAGE_VERSION).is_empty());
+    }
+}
@@ -4,9 +4,12 @@
 use crate::client::replies::reply_storage::CombinedReplyStorage;
 use async_trait::async_trait;
 use std::error::Error;
+use std::time::Duration;
 use thiserror::Error;
 
-#[cfg(target_arch = "wasm32")]
+// natively only the stored models and the schema get compiled, so that they could be tested
+#[cfg(any(target_arch = "wasm32", test))]
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
 pub mod browser_backend;
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "fs-surb-storage"))]
@@ -35,7 +38,8 @@ impl Default for Empty {
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 impl ReplyStorageBackend for Empty {
     type StorageError = UndefinedError;
 
@@ -61,7 +65,8 @@ impl ReplyStorageBackend for Empty {
     }
 }
 
-#[async_trait]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait ReplyStorageBackend: Sized {
     type StorageError: Error + 'static;
 
@@ -69,6 +74,12 @@ pub trait ReplyStorageBackend: Sized {
         Ok(())
     }
 
+    /// If specified, the in-memory data is also going to be flushed with this interval
+    /// rather than only on shutdown, for backends that can't rely on the client going through it.
+    fn flush_interval(&self) -> Option<Duration> {
+        None
+    }
+
     // reply keys and surbs would need additional field set when data is loaded
     // so if there's some failure, we'd trash it all
     async fn flush_surb_storage(
@@ -1,11 +1,13 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::helpers::new_interval_stream;
 pub use crate::client::replies::reply_storage::combined::CombinedReplyStorage;
 pub use crate::client::replies::reply_storage::key_storage::SentReplyKeys;
 pub use crate::client::replies::reply_storage::surb_storage::ReceivedReplySurbsMap;
 pub use crate::client::replies::reply_storage::tag_storage::UsedSenderTags;
 pub use backend::*;
+use futures::StreamExt;
 
 mod backend;
 mod combined;
@@ -39,7 +41,7 @@ where
         mem_state: CombinedReplyStorage,
         mut shutdown: nym_task::TaskClient,
     ) {
-        use log::{debug, error, info, warn};
+        use log::{debug, error, info, trace, warn};
 
         debug!("Started PersistentReplyStorage");
         if let Err(err) = self.backend.start_storage_session().await {
@@ -47,7 +49,27 @@ where
             return;
         }
 
-        shutdown.recv().await;
+        match self.backend.flush_interval() {
+            Some(flush_interval) => {
+                let mut flush_timer = new_interval_stream(flush_interval);
+
+                while !shutdown.is_shutdown() {
+                    tokio::select! {
+                        biased;
+                        _ = shutdown.recv() => {
+                            trace!("PersistentReplyStorage: Received shutdown");
+                        }
+                        _ = flush_timer.next() => {
+                            debug!("PersistentReplyStorage is flushing all reply-related data to underlying storage");
+                            if let Err(err) = self.backend.flush_surb_storage(&mem_state).await {
+                                error!("failed to flush our reply-related data to the persistent storage: {err}")
+                            }
+                        }
+                    }
+                }
+            }
+            None => shutdown.recv().await,
+        }
 
         info!("PersistentReplyStorage is flushing all reply-related data to underlying storage");
         warn!("you MUST NOT forcefully shutdown now or you risk data corruption!");
@@ -2,44 +2,50 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
-use log::{error, warn};
+use log::{debug, error, warn};
 use nym_topology::provider_trait::TopologyProvider;
-use nym_topology::{nym_topology_from_detailed, NymTopology, NymTopologyError};
+use nym_topology::{
+    nym_topology_from_bonds, nym_topology_from_detailed, NymTopology, NymTopologyError,
+};
+use nym_validator_client::topology::CompactTopology;
+use nym_validator_client::{EndpointPool, ValidatorClientError};
 use rand::prelude::SliceRandom;
 use rand::thread_rng;
 use url::Url;
 
 pub(crate) struct NymApiTopologyProvider {
     validator_client: nym_validator_client::client::NymApiClient,
-    nym_api_urls: Vec<Url>,
-
     client_version: String,
-    currently_used_api: usize,
+
+    /// Compact topology obtained from one of the nym APIs (alongside its url), kept around so that
+    /// only the changes would have to be downloaded on subsequent refreshes.
+    cached_topology: Option<(Url, CompactTopology)>,
 }
 
 impl NymApiTopologyProvider {
     pub(crate) fn new(mut nym_api_urls: Vec<Url>, client_version: String) -> Self {
+        // so that all the clients wouldn't start with the same nym API
         nym_api_urls.shuffle(&mut thread_rng());
+        let endpoints = EndpointPool::new(nym_api_urls).expect("no nym API urls were provided");
 
         NymApiTopologyProvider {
-            validator_client: nym_validator_client::client::NymApiClient::new(
-                nym_api_urls[0].clone(),
+            validator_client: nym_validator_client::client::NymApiClient::new_with_endpoints(
+                endpoints,
             ),
-            nym_api_urls,
             client_version,
-            currently_used_api: 0,
+            cached_topology: None,
         }
     }
 
-    fn use_next_nym_api(&mut self) {
-        if self.nym_api_urls.len() == 1 {
+    fn avoid_nym_api(&mut self, nym_api: &Url) {
+        let endpoints = self.validator_client.endpoints();
+        if endpoints.urls().len() == 1 {
             warn!("There's only a single nym API available - it won't be possible to use a different one");
             return;
         }
 
-        self.currently_used_api = (self.currently_used_api + 1) % self.nym_api_urls.len();
-        self.validator_client
-            .change_nym_api(self.nym_api_urls[self.currently_used_api].clone())
+        endpoints.mark_unhealthy(nym_api);
+        self.cached_topology = None;
     }
 
     /// Verifies whether nodes a reasonably distributed among all mix layers.
@@ -60,8 +66,46 @@ impl NymApiTopologyProvider {
         active_topology.ensure_even_layer_distribution(lower_threshold, upper_threshold)
     }
 
-    async fn get_current_compatible_topology(&mut self) -> Option<NymTopology> {
-        let mixnodes = match self.validator_client.get_cached_active_mixnodes().await {
+    /// Brings the cached compact topology up to date, downloading only the changes if possible.
+    async fn update_cached_topology(&mut self) -> Result<(), ValidatorClientError> {
+        // topology versions are local to given nym API, so all the requests have to go to the same one
+        let nym_api = self.validator_client.pinned_to_current();
+        let source = nym_api.current_nym_api();
+
+        if matches!(&self.cached_topology, Some((cached_source, _)) if cached_source != &source) {
+            debug!("switched to {source}. The cached topology has to be retrieved again");
+            self.cached_topology = None;
+        }
+
+        let Some((_, cached)) = &mut self.cached_topology else {
+            self.cached_topology = nym_api
+                .get_cached_topology(None)
+                .await?
+                .map(|topology| (source, topology));
+            return Ok(());
+        };
+
+        let Some(changes) = nym_api.get_cached_topology_changes(cached.version).await? else {
+            // nothing has changed since the last time
+            return Ok(());
+        };
+
+        if let Err(err) = cached.apply_changes(changes) {
+            warn!("failed to apply topology changes: {err}. Getting the full topology instead");
+            self.cached_topology = nym_api
+                .get_cached_topology(None)
+                .await?
+                .map(|topology| (source, topology));
+        }
+        Ok(())
+    }
+
+    // fallback for nym APIs that do not expose the compact topology
+    async fn get_full_topology(&mut self) -> Option<(Url, NymTopology)> {
+        // make sure both of the node lists come from the same nym API
+        let nym_api = self.validator_client.pinned_to_current();
+
+        let mixnodes = match nym_api.get_cached_active_mixnodes().await {
             Err(err) => {
                 error!("failed to get network mixnodes - {err}");
                 return None;
@@ -69,7 +113,7 @@ impl NymApiTopologyProvider {
             Ok(mixes) => mixes,
         };
 
-        let gateways = match self.validator_client.get_cached_gateways().await {
+        let gateways = match nym_api.get_cached_gateways().await {
             Err(err) => {
                 error!("failed to get network gateways - {err}");
                 return None;
@@ -77,12 +121,35 @@ impl NymApiTopologyProvider {
             Ok(gateways) => gateways,
         };
 
-        let topology = nym_topology_from_detailed(mixnodes, gateways)
-            .filter_system_version(&self.client_version);
+        Some((
+            nym_api.current_nym_api(),
+            nym_topology_from_detailed(mixnodes, gateways),
+        ))
+    }
+
+    async fn get_topology(&mut self) -> Option<(Url, NymTopology)> {
+        if let Err(err) = self.update_cached_topology().await {
+            debug!(
+                "failed to get the compact topology - {err}. Falling back to the full node lists"
+            );
+            self.cached_topology = None;
+            return self.get_full_topology().await;
+        }
+
+        let (source, topology) = self.cached_topology.as_ref()?;
+        Some((
+            source.clone(),
+            nym_topology_from_bonds(topology.mixnodes.iter().cloned(), topology.gateways.clone()),
+        ))
+    }
+
+    async fn get_current_compatible_topology(&mut self) -> Option<NymTopology> {
+        let (source, topology) = self.get_topology().await?;
+        let topology = topology.filter_system_version(&self.client_version);
 
         if let Err(err) = self.check_layer_distribution(&topology) {
-            warn!("The current filtered active topology has extremely skewed layer distribution. It cannot be used: {err}");
-            self.use_next_nym_api();
+            warn!("The current filtered active topology obtained from {source} has extremely skewed layer distribution. It cannot be used: {err}");
+            self.avoid_nym_api(&source);
             None
         } else {
             Some(topology)
@@ -1,7 +1,7 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use nym_config::defaults::NymNetworkDetails;
+use nym_config::defaults::{NymNetworkDetails, REMAINING_BANDWIDTH_THRESHOLD};
 use nym_crypto::asymmetric::identity;
 use nym_sphinx::params::{PacketSize, PacketType};
 use serde::{Deserialize, Serialize};
@@ -29,6 +29,7 @@ const DEFAULT_TOPOLOGY_RESOLUTION_TIMEOUT: Duration = Duration::from_millis(5_00
 // bought bandwidth tokens to not have time to be spent; Once we remove the gateway from the
 // bandwidth bridging protocol, we can come back to a smaller timeout value
 const DEFAULT_GATEWAY_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_BANDWIDTH_LOW_WATER_MARK: i64 = REMAINING_BANDWIDTH_THRESHOLD;
 
 const DEFAULT_COVER_TRAFFIC_PRIMARY_SIZE_RATIO: f64 = 0.70;
 
@@ -392,12 +393,18 @@ pub struct GatewayConnection {
     /// before giving up on it.
     #[serde(with = "humantime_serde")]
     pub gateway_response_timeout: Duration,
+
+    /// Amount of remaining bandwidth (in bytes) below which the client is going to automatically
+    /// claim more of it using the next stored credential (or the free testnet bandwidth
+    /// if running in the disabled credentials mode). Set to 0 to disable the automatic top-up.
+    pub bandwidth_low_water_mark: i64,
 }
 
 impl Default for GatewayConnection {
     fn default() -> Self {
         GatewayConnection {
             gateway_response_timeout: DEFAULT_GATEWAY_RESPONSE_TIMEOUT,
+            bandwidth_low_water_mark: DEFAULT_BANDWIDTH_LOW_WATER_MARK,
         }
     }
 }
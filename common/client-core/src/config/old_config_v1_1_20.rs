@@ -189,6 +189,7 @@ impl From<GatewayConnectionV1_1_20> for GatewayConnection {
     fn from(value: GatewayConnectionV1_1_20) -> Self {
         GatewayConnection {
             gateway_response_timeout: value.gateway_response_timeout,
+            ..Default::default()
         }
     }
 }
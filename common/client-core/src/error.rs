@@ -1,6 +1,7 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::name_resolver::NameResolutionError;
 use nym_crypto::asymmetric::identity::Ed25519RecoveryError;
 use nym_gateway_client::error::GatewayClientError;
 use nym_topology::gateway::GatewayConversionError;
@@ -37,6 +38,9 @@ pub enum ClientCoreError {
     #[error("Could not load existing gateway configuration: {0}")]
     CouldNotLoadExistingGatewayConfiguration(std::io::Error),
 
+    #[error("failed to resolve the destination address: {0}")]
+    NameResolutionFailure(#[from] NameResolutionError),
+
     #[error("The current network topology seem to be insufficient to route any packets through")]
     InsufficientNetworkTopology(#[from] NymTopologyError),
 
@@ -108,4 +112,6 @@ pub enum ClientCoreStatusMessage {
     // NOTE: The nym-connect frontend listens for these strings, so don't change them until we have a more robust mechanism in place
     #[error("The connected gateway is very slow, or the connection to it is very slow")]
     GatewayIsVerySlow,
+    #[error("The client is running low on bandwidth and there are no more stored credentials to claim more of it")]
+    OutOfBandwidthCredentials,
 }
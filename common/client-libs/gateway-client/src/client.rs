@@ -47,6 +47,9 @@ pub struct GatewayClient<C, St> {
     authenticated: bool,
     disabled_credentials_mode: bool,
     bandwidth_remaining: i64,
+    /// Amount of remaining bandwidth below which the client should attempt to claim more of it.
+    /// Set to 0 to disable the automatic top-up.
+    bandwidth_low_water_mark: i64,
     gateway_address: String,
     gateway_identity: identity::PublicKey,
     local_identity: Arc<identity::KeyPair>,
@@ -54,7 +57,7 @@ pub struct GatewayClient<C, St> {
     connection: SocketState,
     packet_router: PacketRouter,
     response_timeout_duration: Duration,
-    bandwidth_controller: Option<BandwidthController<C, St>>,
+    bandwidth_controller: Option<Arc<BandwidthController<C, St>>>,
 
     // reconnection related variables
     /// Specifies whether client should try to reconnect to gateway on connection failure.
@@ -88,6 +91,7 @@ impl<C, St> GatewayClient<C, St> {
             authenticated: false,
             disabled_credentials_mode: true,
             bandwidth_remaining: 0,
+            bandwidth_low_water_mark: 0,
             gateway_address,
             gateway_identity,
             local_identity,
@@ -95,7 +99,7 @@ impl<C, St> GatewayClient<C, St> {
             connection: SocketState::NotConnected,
             packet_router: PacketRouter::new(ack_sender, mixnet_message_sender, shutdown.clone()),
             response_timeout_duration,
-            bandwidth_controller,
+            bandwidth_controller: bandwidth_controller.map(Arc::new),
             should_reconnect_on_failure: true,
             reconnection_attempts: DEFAULT_RECONNECTION_ATTEMPTS,
             reconnection_backoff: DEFAULT_RECONNECTION_BACKOFF,
@@ -120,6 +124,10 @@ impl<C, St> GatewayClient<C, St> {
         self.reconnection_backoff = backoff
     }
 
+    pub fn with_bandwidth_low_water_mark(&mut self, bandwidth_low_water_mark: i64) {
+        self.bandwidth_low_water_mark = bandwidth_low_water_mark
+    }
+
     pub fn gateway_identity(&self) -> identity::PublicKey {
         self.gateway_identity
     }
@@ -128,6 +136,29 @@ impl<C, St> GatewayClient<C, St> {
         self.bandwidth_remaining
     }
 
+    /// Checks whether there's enough bandwidth remaining to send all the provided packets.
+    pub fn has_enough_bandwidth(&self, packets: &[MixPacket]) -> bool {
+        self.estimate_required_bandwidth(packets) <= self.bandwidth_remaining
+    }
+
+    /// Returns a handle to the bandwidth controller so that a new credential could be prepared,
+    /// which might take a while, without blocking the client.
+    /// `None` is returned if the client is running in the disabled credentials mode.
+    pub fn shared_bandwidth_controller(&self) -> Option<Arc<BandwidthController<C, St>>> {
+        if self.disabled_credentials_mode {
+            None
+        } else {
+            self.bandwidth_controller.clone()
+        }
+    }
+
+    /// Checks whether the remaining bandwidth has dropped below the configured low-water mark
+    /// and thus more of it should be claimed.
+    pub fn requires_bandwidth_top_up(&self) -> bool {
+        self.bandwidth_low_water_mark > 0
+            && self.bandwidth_remaining < self.bandwidth_low_water_mark
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     async fn _close_connection(&mut self) -> Result<(), GatewayClientError> {
         match std::mem::replace(&mut self.connection, SocketState::NotConnected) {
@@ -556,12 +587,42 @@ impl<C, St> GatewayClient<C, St> {
             .as_ref()
             .unwrap()
             .prepare_coconut_credential()
-            .await?;
+            .await
+            .map_err(|err| {
+                if err.is_out_of_credentials() {
+                    GatewayClientError::NoMoreBandwidthCredentials
+                } else {
+                    err.into()
+                }
+            })?;
+
+        self.claim_bandwidth_with_credential(credential, credential_id)
+            .await
+    }
+
+    /// Claims bandwidth using an already prepared credential (for example obtained via
+    /// the `shared_bandwidth_controller`) and marks it as consumed in the storage.
+    pub async fn claim_bandwidth_with_credential(
+        &mut self,
+        credential: Credential,
+        credential_id: i64,
+    ) -> Result<(), GatewayClientError>
+    where
+        St: CredentialStorage,
+        <St as CredentialStorage>::StorageError: Send + Sync + 'static,
+    {
+        if !self.authenticated {
+            return Err(GatewayClientError::NotAuthenticated);
+        }
+        if self.shared_key.is_none() {
+            return Err(GatewayClientError::NoSharedKeyAvailable);
+        }
+        let Some(bandwidth_controller) = self.bandwidth_controller.clone() else {
+            return Err(GatewayClientError::NoBandwidthControllerAvailable);
+        };
 
         self.claim_coconut_bandwidth(credential).await?;
-        self.bandwidth_controller
-            .as_ref()
-            .unwrap()
+        bandwidth_controller
             .consume_credential(credential_id)
             .await?;
 
@@ -584,9 +645,10 @@ impl<C, St> GatewayClient<C, St> {
         if !self.authenticated {
             return Err(GatewayClientError::NotAuthenticated);
         }
-        if self.estimate_required_bandwidth(&packets) > self.bandwidth_remaining {
+        let required_bandwidth = self.estimate_required_bandwidth(&packets);
+        if required_bandwidth > self.bandwidth_remaining {
             return Err(GatewayClientError::NotEnoughBandwidth(
-                self.estimate_required_bandwidth(&packets),
+                required_bandwidth,
                 self.bandwidth_remaining,
             ));
         }
@@ -615,6 +677,9 @@ impl<C, St> GatewayClient<C, St> {
                 Err(err)
             }
         } else {
+            // keep track of our bandwidth locally so that we'd know when to claim more of it.
+            // the gateway will send us the precise value next time we claim it or reconnect
+            self.bandwidth_remaining -= required_bandwidth;
             Ok(())
         }
     }
@@ -655,9 +720,10 @@ impl<C, St> GatewayClient<C, St> {
         if !self.authenticated {
             return Err(GatewayClientError::NotAuthenticated);
         }
-        if (mix_packet.packet().len() as i64) > self.bandwidth_remaining {
+        let required_bandwidth = mix_packet.packet().len() as i64;
+        if required_bandwidth > self.bandwidth_remaining {
             return Err(GatewayClientError::NotEnoughBandwidth(
-                mix_packet.packet().len() as i64,
+                required_bandwidth,
                 self.bandwidth_remaining,
             ));
         }
@@ -671,7 +737,9 @@ impl<C, St> GatewayClient<C, St> {
                 .as_ref()
                 .expect("no shared key present even though we're authenticated!"),
         );
-        self.send_with_reconnection_on_failure(msg).await
+        self.send_with_reconnection_on_failure(msg).await?;
+        self.bandwidth_remaining -= required_bandwidth;
+        Ok(())
     }
 
     async fn recover_socket_connection(&mut self) -> Result<(), GatewayClientError> {
@@ -783,6 +851,7 @@ impl<C> GatewayClient<C, EphemeralCredentialStorage> {
             authenticated: false,
             disabled_credentials_mode: true,
             bandwidth_remaining: 0,
+            bandwidth_low_water_mark: 0,
             gateway_address,
             gateway_identity,
             local_identity,
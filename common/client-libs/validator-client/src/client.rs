@@ -1,21 +1,34 @@
 // Copyright 2021-2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::endpoint_pool::EndpointPool;
 use crate::{nym_api, ValidatorClientError};
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchVerifyCredentialsBody, BatchVerifyCredentialsResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_api_requests::models::{
-    GatewayCoreStatusResponse, MixnodeCoreStatusResponse, MixnodeStatusResponse,
-    RewardEstimationResponse, StakeSaturationResponse,
+    GatewayCoreStatusResponse, GatewayTimingResponse, LatencyMatrixResponse,
+    MixnodeCoreStatusResponse, MixnodeLatencyResponse, MixnodeStatusResponse,
+    MixnodeTimingResponse, MixnodesLatencyResponse, RewardEstimationResponse,
+    StakeSaturationResponse,
 };
+use nym_api_requests::topology::{CompactTopology, TopologyChangesResponse};
 use nym_coconut_dkg_common::types::NodeIndex;
 use nym_coconut_interface::VerificationKey;
 pub use nym_mixnet_contract_common::{
     mixnode::MixNodeDetails, GatewayBond, IdentityKey, IdentityKeyRef, MixId,
 };
+use nym_name_service_common::NameEntry;
 use url::Url;
 
+#[cfg(not(target_arch = "wasm32"))]
+use crate::nym_api::events::NetworkEventsSubscription;
+#[cfg(not(target_arch = "wasm32"))]
+use nym_api_requests::events::NetworkEventFilter;
+
+#[cfg(feature = "nyxd-client")]
+use crate::endpoint_pool::EndpointPoolConfig;
 #[cfg(feature = "nyxd-client")]
 use crate::nyxd::traits::{DkgQueryClient, MixnetQueryClient};
 #[cfg(feature = "nyxd-client")]
@@ -44,8 +57,9 @@ use std::str::FromStr;
 #[must_use]
 #[derive(Debug, Clone)]
 pub struct Config {
-    api_url: Url,
-    nyxd_url: Url,
+    api_urls: Vec<Url>,
+    nyxd_urls: Vec<Url>,
+    endpoint_pool_config: EndpointPoolConfig,
 
     nyxd_config: nyxd::Config,
 
@@ -60,23 +74,27 @@ impl Config {
     pub fn try_from_nym_network_details(
         details: &NymNetworkDetails,
     ) -> Result<Self, ValidatorClientError> {
-        let mut api_url = details
+        let api_urls = details
             .endpoints
             .iter()
             .filter_map(|d| d.api_url.as_ref())
             .map(|url| Url::parse(url))
             .collect::<Result<Vec<_>, _>>()?;
 
-        if api_url.is_empty() {
+        if api_urls.is_empty() {
             return Err(ValidatorClientError::NoAPIUrlAvailable);
         }
 
+        let nyxd_urls = details
+            .endpoints
+            .iter()
+            .map(|d| d.nyxd_url.parse())
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(Config {
-            api_url: api_url.pop().unwrap(),
-            nyxd_url: details.endpoints[0]
-                .nyxd_url
-                .parse()
-                .map_err(ValidatorClientError::MalformedUrlProvided)?,
+            api_urls,
+            nyxd_urls,
+            endpoint_pool_config: Default::default(),
             nyxd_config: nyxd::Config::try_from_nym_network_details(details)?,
             mixnode_page_limit: None,
             gateway_page_limit: None,
@@ -88,16 +106,36 @@ impl Config {
     // TODO: this method shouldn't really exist as all information should be included immediately
     // via `from_nym_network_details`, but it's here for, you guessed it, legacy compatibility
     pub fn with_urls(mut self, nyxd_url: Url, api_url: Url) -> Self {
-        self.nyxd_url = nyxd_url;
-        self.api_url = api_url;
+        self.nyxd_urls = vec![nyxd_url];
+        self.api_urls = vec![api_url];
         self
     }
 
     pub fn with_nyxd_url(mut self, nyxd_url: Url) -> Self {
-        self.nyxd_url = nyxd_url;
+        self.nyxd_urls = vec![nyxd_url];
+        self
+    }
+
+    /// Sets all endpoints the client is going to fail over between.
+    pub fn with_endpoints(mut self, nyxd_urls: Vec<Url>, api_urls: Vec<Url>) -> Self {
+        self.nyxd_urls = nyxd_urls;
+        self.api_urls = api_urls;
         self
     }
 
+    pub fn with_endpoint_pool_config(mut self, endpoint_pool_config: EndpointPoolConfig) -> Self {
+        self.endpoint_pool_config = endpoint_pool_config;
+        self
+    }
+
+    fn nyxd_endpoints(&self) -> Result<EndpointPool, ValidatorClientError> {
+        EndpointPool::new_with_config(self.nyxd_urls.clone(), self.endpoint_pool_config)
+    }
+
+    fn api_endpoints(&self) -> Result<EndpointPool, ValidatorClientError> {
+        EndpointPool::new_with_config(self.api_urls.clone(), self.endpoint_pool_config)
+    }
+
     pub fn with_mixnode_page_limit(mut self, limit: Option<u32>) -> Config {
         self.mixnode_page_limit = limit;
         self
@@ -137,10 +175,10 @@ impl Client<SigningNyxdClient<DirectSecp256k1HdWallet>> {
         config: Config,
         mnemonic: bip39::Mnemonic,
     ) -> Result<Client<SigningNyxdClient<DirectSecp256k1HdWallet>>, ValidatorClientError> {
-        let nym_api_client = nym_api::Client::new(config.api_url.clone());
-        let nyxd_client = NyxdClient::connect_with_mnemonic(
+        let nym_api_client = nym_api::Client::new_with_endpoints(config.api_endpoints()?);
+        let nyxd_client = NyxdClient::connect_pooled_with_mnemonic(
             config.nyxd_config.clone(),
-            config.nyxd_url.as_str(),
+            config.nyxd_endpoints()?,
             mnemonic,
             None,
         )?;
@@ -168,9 +206,9 @@ impl Client<SigningNyxdClient<DirectSecp256k1HdWallet>> {
 #[cfg(feature = "nyxd-client")]
 impl Client<QueryNyxdClient> {
     pub fn new_query(config: Config) -> Result<Client<QueryNyxdClient>, ValidatorClientError> {
-        let nym_api_client = nym_api::Client::new(config.api_url.clone());
+        let nym_api_client = nym_api::Client::new_with_endpoints(config.api_endpoints()?);
         let nyxd_client =
-            NyxdClient::connect(config.nyxd_config.clone(), config.nyxd_url.as_str())?;
+            NyxdClient::connect_pooled(config.nyxd_config.clone(), config.nyxd_endpoints()?)?;
 
         Ok(Client {
             mixnode_page_limit: config.mixnode_page_limit,
@@ -689,10 +727,31 @@ impl NymApiClient {
         NymApiClient { nym_api_client }
     }
 
+    pub fn new_with_endpoints(endpoints: EndpointPool) -> Self {
+        let nym_api_client = nym_api::Client::new_with_endpoints(endpoints);
+
+        NymApiClient { nym_api_client }
+    }
+
     pub fn change_nym_api(&mut self, new_endpoint: Url) {
         self.nym_api_client.change_url(new_endpoint);
     }
 
+    pub fn current_nym_api(&self) -> Url {
+        self.nym_api_client.current_url()
+    }
+
+    pub fn endpoints(&self) -> &EndpointPool {
+        self.nym_api_client.endpoints()
+    }
+
+    /// Returns a client that's going to send all of its requests to the currently preferred nym API.
+    pub fn pinned_to_current(&self) -> Self {
+        NymApiClient {
+            nym_api_client: self.nym_api_client.pinned_to_current(),
+        }
+    }
+
     pub async fn get_cached_active_mixnodes(
         &self,
     ) -> Result<Vec<MixNodeDetails>, ValidatorClientError> {
@@ -713,6 +772,36 @@ impl NymApiClient {
         Ok(self.nym_api_client.get_gateways().await?)
     }
 
+    /// Gets the compact network topology. Returns `None` if it's still at the `known_version`.
+    pub async fn get_cached_topology(
+        &self,
+        known_version: Option<u64>,
+    ) -> Result<Option<CompactTopology>, ValidatorClientError> {
+        Ok(self.nym_api_client.get_topology(known_version).await?)
+    }
+
+    /// Gets the changes to the network topology since the provided version.
+    /// Returns `None` if nothing has changed.
+    pub async fn get_cached_topology_changes(
+        &self,
+        since: u64,
+    ) -> Result<Option<TopologyChangesResponse>, ValidatorClientError> {
+        Ok(self.nym_api_client.get_topology_changes(since).await?)
+    }
+
+    /// Subscribes to the stream of network events (such as epoch transitions or node status changes)
+    /// matching the provided filter.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn subscribe_to_network_events(
+        &self,
+        filter: &NetworkEventFilter,
+    ) -> Result<NetworkEventsSubscription, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .subscribe_to_network_events(filter)
+            .await?)
+    }
+
     pub async fn get_gateway_core_status_count(
         &self,
         identity: IdentityKeyRef<'_>,
@@ -735,6 +824,28 @@ impl NymApiClient {
             .await?)
     }
 
+    pub async fn get_gateway_timing(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+    ) -> Result<GatewayTimingResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_gateway_timing(identity, since)
+            .await?)
+    }
+
+    pub async fn get_mixnode_timing(
+        &self,
+        mix_id: MixId,
+        since: Option<i64>,
+    ) -> Result<MixnodeTimingResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_mixnode_timing(mix_id, since)
+            .await?)
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
@@ -762,6 +873,27 @@ impl NymApiClient {
             .await?)
     }
 
+    pub async fn get_latency_matrix(&self) -> Result<LatencyMatrixResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_latency_matrix().await?)
+    }
+
+    pub async fn get_mixnodes_latency(
+        &self,
+    ) -> Result<MixnodesLatencyResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_mixnodes_latency().await?)
+    }
+
+    pub async fn get_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<MixnodeLatencyResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_mixnode_latency(mix_id).await?)
+    }
+
+    pub async fn get_registered_names(&self) -> Result<Vec<NameEntry>, ValidatorClientError> {
+        Ok(self.nym_api_client.get_registered_names().await?.names)
+    }
+
     pub async fn blind_sign(
         &self,
         request_body: &BlindSignRequestBody,
@@ -778,4 +910,14 @@ impl NymApiClient {
             .verify_bandwidth_credential(request_body)
             .await?)
     }
+
+    pub async fn batch_verify_bandwidth_credentials(
+        &self,
+        request_body: &BatchVerifyCredentialsBody,
+    ) -> Result<BatchVerifyCredentialsResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .batch_verify_bandwidth_credentials(request_body)
+            .await?)
+    }
 }
@@ -0,0 +1,339 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::ValidatorClientError;
+use log::{info, warn};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use url::Url;
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use wasm_timer::Instant;
+
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_RETEST_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy)]
+pub struct EndpointPoolConfig {
+    /// Number of consecutive failed requests after which the endpoint is considered unhealthy
+    /// and is only going to be used as the last resort.
+    pub failure_threshold: u32,
+
+    /// Minimum amount of time that has to pass before an unhealthy endpoint is given another chance.
+    pub retest_interval: Duration,
+
+    /// Maximum duration of a single request before it's considered failed.
+    /// Note that it's not enforced in the browser.
+    pub request_timeout: Duration,
+}
+
+impl Default for EndpointPoolConfig {
+    fn default() -> Self {
+        EndpointPoolConfig {
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            retest_interval: DEFAULT_RETEST_INTERVAL,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+/// Health information of a single endpoint as observed by the pool.
+#[derive(Debug, Clone)]
+pub struct EndpointStatus {
+    pub url: Url,
+
+    /// Exponentially weighted average latency of the successful requests.
+    pub average_latency: Option<Duration>,
+
+    pub consecutive_failures: u32,
+    pub total_failures: u64,
+
+    pub healthy: bool,
+
+    last_failure: Option<Instant>,
+}
+
+impl EndpointStatus {
+    fn new(url: Url) -> Self {
+        EndpointStatus {
+            url,
+            average_latency: None,
+            consecutive_failures: 0,
+            total_failures: 0,
+            healthy: true,
+            last_failure: None,
+        }
+    }
+
+    fn due_for_retest(&self, retest_interval: Duration) -> bool {
+        !self.healthy
+            && self
+                .last_failure
+                .map(|last_failure| last_failure.elapsed() >= retest_interval)
+                .unwrap_or(true)
+    }
+}
+
+/// A set of equivalent endpoints (for example all nym APIs or all nyxd validators of the network)
+/// alongside the health information of each of them.
+///
+/// The pool is cheaply cloneable and all of its clones share the same state,
+/// so it can be used by multiple clients at once.
+#[derive(Debug, Clone)]
+pub struct EndpointPool {
+    config: EndpointPoolConfig,
+    endpoints: Arc<Mutex<Vec<EndpointStatus>>>,
+}
+
+impl EndpointPool {
+    pub fn new(urls: Vec<Url>) -> Result<Self, ValidatorClientError> {
+        Self::new_with_config(urls, Default::default())
+    }
+
+    pub fn new_with_config(
+        urls: Vec<Url>,
+        config: EndpointPoolConfig,
+    ) -> Result<Self, ValidatorClientError> {
+        if urls.is_empty() {
+            return Err(ValidatorClientError::NoEndpointsProvided);
+        }
+
+        let mut endpoints: Vec<EndpointStatus> = Vec::with_capacity(urls.len());
+        for url in urls {
+            if !endpoints.iter().any(|endpoint| endpoint.url == url) {
+                endpoints.push(EndpointStatus::new(url))
+            }
+        }
+
+        Ok(EndpointPool {
+            config,
+            endpoints: Arc::new(Mutex::new(endpoints)),
+        })
+    }
+
+    pub fn new_single(url: Url) -> Self {
+        Self::new_single_with_config(url, Default::default())
+    }
+
+    pub fn new_single_with_config(url: Url, config: EndpointPoolConfig) -> Self {
+        EndpointPool {
+            config,
+            endpoints: Arc::new(Mutex::new(vec![EndpointStatus::new(url)])),
+        }
+    }
+
+    pub fn config(&self) -> &EndpointPoolConfig {
+        &self.config
+    }
+
+    fn lock_endpoints(&self) -> MutexGuard<'_, Vec<EndpointStatus>> {
+        match self.endpoints.lock() {
+            Ok(guard) => guard,
+            // the statuses are updated in place, so they can't be left in an inconsistent state
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Returns all endpoints of this pool in the order they were provided.
+    pub fn urls(&self) -> Vec<Url> {
+        self.lock_endpoints()
+            .iter()
+            .map(|endpoint| endpoint.url.clone())
+            .collect()
+    }
+
+    /// Returns the current health information of all endpoints of this pool.
+    pub fn status(&self) -> Vec<EndpointStatus> {
+        self.lock_endpoints().clone()
+    }
+
+    /// Returns the endpoint that's currently the most preferable to use.
+    pub fn current(&self) -> Url {
+        // the pool is never empty
+        self.ordered(false).swap_remove(0)
+    }
+
+    /// Returns all endpoints in the order they should be attempted in for the next request.
+    ///
+    /// If any unhealthy endpoint hasn't been tried for at least the retest interval,
+    /// it's going to be attempted first to check whether it has recovered.
+    /// Only a single request is going to be used for such test.
+    pub fn candidates(&self) -> Vec<Url> {
+        self.ordered(true)
+    }
+
+    fn ordered(&self, reserve_retests: bool) -> Vec<Url> {
+        let retest_interval = self.config.retest_interval;
+        let mut endpoints = self.lock_endpoints();
+
+        let mut retests = Vec::new();
+        if reserve_retests {
+            for endpoint in endpoints
+                .iter_mut()
+                .filter(|endpoint| endpoint.due_for_retest(retest_interval))
+            {
+                // make sure no other request is going to pick it up in the meantime
+                endpoint.last_failure = Some(Instant::now());
+                retests.push(endpoint.url.clone());
+            }
+        }
+
+        let (mut healthy, mut unhealthy): (Vec<_>, Vec<_>) = endpoints
+            .iter()
+            .filter(|endpoint| !retests.contains(&endpoint.url))
+            .partition(|endpoint| endpoint.healthy);
+
+        // endpoints we haven't heard back from yet retain their original ordering
+        // and are placed after the ones that are known to work
+        healthy.sort_by_key(|endpoint| endpoint.average_latency.unwrap_or(Duration::MAX));
+        unhealthy.sort_by_key(|endpoint| endpoint.consecutive_failures);
+
+        let remaining = healthy
+            .into_iter()
+            .chain(unhealthy)
+            .map(|endpoint| endpoint.url.clone());
+        retests.into_iter().chain(remaining).collect()
+    }
+
+    fn with_endpoint<F: FnOnce(&mut EndpointStatus)>(&self, url: &Url, f: F) {
+        if let Some(endpoint) = self
+            .lock_endpoints()
+            .iter_mut()
+            .find(|endpoint| &endpoint.url == url)
+        {
+            f(endpoint)
+        }
+    }
+
+    pub fn record_success(&self, url: &Url, latency: Duration) {
+        self.with_endpoint(url, |endpoint| {
+            if !endpoint.healthy {
+                info!("{url} seems to have recovered and is going to be used again");
+            }
+
+            endpoint.average_latency = Some(match endpoint.average_latency {
+                Some(average) => (average * 3 + latency) / 4,
+                None => latency,
+            });
+            endpoint.consecutive_failures = 0;
+            endpoint.healthy = true;
+            endpoint.last_failure = None;
+        })
+    }
+
+    pub fn record_failure(&self, url: &Url) {
+        let failure_threshold = self.config.failure_threshold;
+        self.with_endpoint(url, |endpoint| {
+            endpoint.consecutive_failures += 1;
+            endpoint.total_failures += 1;
+            endpoint.last_failure = Some(Instant::now());
+
+            if endpoint.healthy && endpoint.consecutive_failures >= failure_threshold {
+                warn!(
+                    "{url} has failed {} consecutive requests. It's going to be avoided for now",
+                    endpoint.consecutive_failures
+                );
+                endpoint.healthy = false;
+            }
+        })
+    }
+
+    /// Explicitly marks the endpoint as unhealthy, for example if it has returned invalid data.
+    pub fn mark_unhealthy(&self, url: &Url) {
+        self.with_endpoint(url, |endpoint| {
+            endpoint.last_failure = Some(Instant::now());
+            endpoint.healthy = false;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(raw: &[&str]) -> Vec<Url> {
+        raw.iter().map(|url| url.parse().unwrap()).collect()
+    }
+
+    fn test_pool(raw: &[&str]) -> EndpointPool {
+        EndpointPool::new_with_config(
+            urls(raw),
+            EndpointPoolConfig {
+                failure_threshold: 2,
+                retest_interval: Duration::from_secs(3600),
+                request_timeout: Duration::from_secs(1),
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_pool_is_rejected() {
+        assert!(EndpointPool::new(Vec::new()).is_err())
+    }
+
+    #[test]
+    fn prefers_endpoints_with_lower_latency() {
+        let pool = test_pool(&["http://a.com", "http://b.com", "http://c.com"]);
+        assert_eq!(
+            pool.candidates(),
+            urls(&["http://a.com", "http://b.com", "http://c.com"])
+        );
+
+        pool.record_success(&"http://c.com".parse().unwrap(), Duration::from_millis(100));
+        pool.record_success(&"http://b.com".parse().unwrap(), Duration::from_millis(200));
+        assert_eq!(
+            pool.candidates(),
+            urls(&["http://c.com", "http://b.com", "http://a.com"])
+        );
+        assert_eq!(pool.current(), "http://c.com".parse().unwrap());
+    }
+
+    #[test]
+    fn unhealthy_endpoints_are_used_last() {
+        let pool = test_pool(&["http://a.com", "http://b.com", "http://c.com"]);
+        let a: Url = "http://a.com".parse().unwrap();
+
+        // a single failure is not enough
+        pool.record_failure(&a);
+        assert_eq!(pool.current(), a);
+
+        pool.record_failure(&a);
+        assert_eq!(
+            pool.candidates(),
+            urls(&["http://b.com", "http://c.com", "http://a.com"])
+        );
+        assert!(!pool.status()[0].healthy);
+
+        pool.record_success(&a, Duration::from_millis(100));
+        assert_eq!(pool.current(), a);
+        assert!(pool.status()[0].healthy);
+    }
+
+    #[test]
+    fn unhealthy_endpoints_get_retested() {
+        let pool = EndpointPool::new_with_config(
+            urls(&["http://a.com", "http://b.com"]),
+            EndpointPoolConfig {
+                failure_threshold: 1,
+                retest_interval: Duration::ZERO,
+                request_timeout: Duration::from_secs(1),
+            },
+        )
+        .unwrap();
+        let a: Url = "http://a.com".parse().unwrap();
+
+        pool.record_failure(&a);
+        assert_eq!(pool.current(), "http://b.com".parse().unwrap());
+        assert_eq!(pool.candidates(), urls(&["http://a.com", "http://b.com"]));
+
+        let pool = test_pool(&["http://a.com", "http://b.com"]);
+        pool.record_failure(&a);
+        pool.record_failure(&a);
+        // the retest interval hasn't elapsed yet
+        assert_eq!(pool.candidates(), urls(&["http://b.com", "http://a.com"]));
+    }
+}
@@ -21,4 +21,7 @@ pub enum ValidatorClientError {
 
     #[error("No validator API url has been provided")]
     NoAPIUrlAvailable,
+
+    #[error("No endpoints have been provided for the endpoint pool")]
+    NoEndpointsProvided,
 }
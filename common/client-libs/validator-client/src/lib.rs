@@ -4,6 +4,7 @@
 pub mod client;
 #[cfg(feature = "nyxd-client")]
 pub mod connection_tester;
+pub mod endpoint_pool;
 pub mod error;
 pub mod nym_api;
 #[cfg(feature = "nyxd-client")]
@@ -12,6 +13,7 @@ pub mod nyxd;
 #[cfg(feature = "signing")]
 pub mod signing;
 
+pub use crate::endpoint_pool::{EndpointPool, EndpointPoolConfig};
 pub use crate::error::ValidatorClientError;
 pub use client::NymApiClient;
 pub use nym_api_requests::*;
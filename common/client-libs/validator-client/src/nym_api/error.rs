@@ -17,4 +17,10 @@ pub enum NymAPIError {
 
     #[error("The nym API has failed to resolve our request. It returned status code {status} and additional error message: {}", error.message())]
     ApiRequestFailure { status: u16, error: RequestError },
+
+    #[error("Received malformed network event - {source}")]
+    MalformedEvent {
+        #[from]
+        source: serde_json::Error,
+    },
 }
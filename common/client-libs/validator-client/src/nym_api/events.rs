@@ -0,0 +1,111 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nym_api::error::NymAPIError;
+use nym_api_requests::events::NetworkEvent;
+use reqwest::Response;
+use std::collections::VecDeque;
+
+/// Minimal parser of the `text/event-stream` format, as emitted by the nym-api.
+///
+/// It only cares about the `data` fields as the event type is also embedded in the payload itself.
+#[derive(Debug, Default)]
+struct EventStreamParser {
+    // bytes of the not yet complete line
+    line: Vec<u8>,
+
+    // data of the event that's currently being received
+    data: Option<String>,
+}
+
+impl EventStreamParser {
+    /// Consumes the received bytes returning payloads of all events that got completed.
+    fn feed(&mut self, bytes: &[u8]) -> Vec<String> {
+        let mut completed = Vec::new();
+        for byte in bytes {
+            if *byte != b'\n' {
+                self.line.push(*byte);
+                continue;
+            }
+
+            let line = std::mem::take(&mut self.line);
+            let line = String::from_utf8_lossy(&line);
+            let line = line.strip_suffix('\r').unwrap_or(&line);
+
+            if line.is_empty() {
+                // empty line dispatches the event
+                if let Some(data) = self.data.take() {
+                    completed.push(data)
+                }
+                continue;
+            }
+
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+
+            // we ignore comments (empty field), `event`, `id` and `retry` fields
+            if field == "data" {
+                match &mut self.data {
+                    Some(data) => {
+                        data.push('\n');
+                        data.push_str(value)
+                    }
+                    None => self.data = Some(value.to_string()),
+                }
+            }
+        }
+        completed
+    }
+}
+
+/// Stream of the network events received from the nym-api.
+pub struct NetworkEventsSubscription {
+    response: Response,
+    parser: EventStreamParser,
+    pending: VecDeque<String>,
+}
+
+impl NetworkEventsSubscription {
+    pub(super) fn new(response: Response) -> Self {
+        NetworkEventsSubscription {
+            response,
+            parser: Default::default(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Waits for the next network event.
+    /// Returns `None` once the nym-api has closed the stream.
+    pub async fn next_event(&mut self) -> Option<Result<NetworkEvent, NymAPIError>> {
+        loop {
+            if let Some(data) = self.pending.pop_front() {
+                return Some(serde_json::from_str(&data).map_err(Into::into));
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(chunk)) => self.pending.extend(self.parser.feed(&chunk)),
+                Ok(None) => return None,
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_event_stream() {
+        let mut parser = EventStreamParser::default();
+
+        assert!(parser.feed(b":comment\n\n").is_empty());
+        assert!(parser.feed(b"event: foo\ndata: {\"a\"").is_empty());
+        assert_eq!(parser.feed(b": 1}\n\n"), vec!["{\"a\": 1}".to_string()]);
+
+        assert_eq!(
+            parser.feed(b"data:first\r\ndata: second\r\n\r\ndata: third\n\n"),
+            vec!["first\nsecond".to_string(), "third".to_string()]
+        );
+    }
+}
@@ -1,27 +1,44 @@
 // Copyright 2021-2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::endpoint_pool::EndpointPool;
 use crate::nym_api::error::NymAPIError;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::nym_api::events::NetworkEventsSubscription;
 use crate::nym_api::routes::{CORE_STATUS_COUNT, SINCE_ARG};
+use log::warn;
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchVerifyCredentialsBody, BatchVerifyCredentialsResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
 };
+#[cfg(not(target_arch = "wasm32"))]
+use nym_api_requests::events::NetworkEventFilter;
 use nym_api_requests::models::{
     ComputeRewardEstParam, GatewayCoreStatusResponse, GatewayStatusReportResponse,
-    GatewayUptimeHistoryResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RequestError, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayTimingResponse, GatewayUptimeHistoryResponse, InclusionProbabilityResponse,
+    LatencyMatrixResponse, MixNodeBondAnnotated, MixnodeCoreStatusResponse, MixnodeLatencyResponse,
+    MixnodeStatusReportResponse, MixnodeStatusResponse, MixnodeTimingResponse,
+    MixnodeUptimeHistoryResponse, MixnodesLatencyResponse, RequestError, RewardEstimationResponse,
+    StakeSaturationResponse, UptimeResponse,
 };
+use nym_api_requests::topology::{CompactTopology, TopologyChangesResponse};
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId};
 use nym_name_service_common::response::NamesListResponse;
 use nym_service_provider_directory_common::response::ServicesListResponse;
-use reqwest::{Response, StatusCode};
+use reqwest::header::IF_NONE_MATCH;
+use reqwest::{RequestBuilder, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+use wasm_timer::Instant;
+
 pub mod error;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod events;
 pub mod routes;
 
 type PathSegments<'a> = &'a [&'a str];
@@ -29,27 +46,110 @@ type Params<'a, K, V> = &'a [(K, V)];
 
 const NO_PARAMS: Params<'_, &'_ str, &'_ str> = &[];
 
+// responses indicating that the nym API itself (or something in front of it) is currently
+// unable to handle requests, as opposed to rejecting this particular one
+fn is_unavailable(status: StatusCode) -> bool {
+    status == StatusCode::BAD_GATEWAY
+        || status == StatusCode::SERVICE_UNAVAILABLE
+        || status == StatusCode::GATEWAY_TIMEOUT
+        || status == StatusCode::TOO_MANY_REQUESTS
+}
+
 #[derive(Clone)]
 pub struct Client {
-    url: Url,
+    endpoints: EndpointPool,
+
+    // if set, all requests are going to be sent to this endpoint without failing over to others
+    pinned: Option<Url>,
+
     reqwest_client: reqwest::Client,
 }
 
 impl Client {
     pub fn new(url: Url) -> Self {
+        Self::new_with_endpoints(EndpointPool::new_single(url))
+    }
+
+    /// Creates a client that's going to fail over to other endpoints of the pool
+    /// whenever the request to the currently used one fails.
+    pub fn new_with_endpoints(endpoints: EndpointPool) -> Self {
         let reqwest_client = reqwest::Client::new();
         Self {
-            url,
+            endpoints,
+            pinned: None,
             reqwest_client,
         }
     }
 
     pub fn change_url(&mut self, new_url: Url) {
-        self.url = new_url
+        self.endpoints = EndpointPool::new_single_with_config(new_url, *self.endpoints.config());
+        self.pinned = None;
+    }
+
+    pub fn current_url(&self) -> Url {
+        match &self.pinned {
+            Some(pinned) => pinned.clone(),
+            None => self.endpoints.current(),
+        }
+    }
+
+    pub fn endpoints(&self) -> &EndpointPool {
+        &self.endpoints
+    }
+
+    /// Returns a client that's going to send all of its requests to the currently preferred
+    /// endpoint. It's useful whenever the subsequent requests rely on the state of a particular nym API.
+    pub fn pinned_to_current(&self) -> Self {
+        Client {
+            endpoints: self.endpoints.clone(),
+            pinned: Some(self.current_url()),
+            reqwest_client: self.reqwest_client.clone(),
+        }
+    }
+
+    async fn send_with_failover<F>(&self, build_request: F) -> Result<Response, NymAPIError>
+    where
+        F: Fn(&Url) -> RequestBuilder,
+    {
+        let candidates = match &self.pinned {
+            Some(pinned) => vec![pinned.clone()],
+            None => self.endpoints.candidates(),
+        };
+
+        let mut last_attempt = None;
+        for base_url in candidates {
+            let start = Instant::now();
+            match build_request(&base_url).send().await {
+                Ok(res) if is_unavailable(res.status()) => {
+                    warn!("{base_url} is currently unavailable ({})", res.status());
+                    self.endpoints.record_failure(&base_url);
+                    last_attempt = Some(Ok(res));
+                }
+                Ok(res) => {
+                    self.endpoints.record_success(&base_url, start.elapsed());
+                    return Ok(res);
+                }
+                Err(err) => {
+                    warn!("the request to {base_url} has failed: {err}");
+                    self.endpoints.record_failure(&base_url);
+                    last_attempt = Some(Err(err.into()));
+                }
+            }
+        }
+
+        // there's always at least a single endpoint to try
+        last_attempt.expect("the endpoint pool is empty")
     }
 
-    pub fn current_url(&self) -> &Url {
-        &self.url
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with_request_timeout(&self, request: RequestBuilder) -> RequestBuilder {
+        request.timeout(self.endpoints.config().request_timeout)
+    }
+
+    // reqwest does not support timeouts in the browser
+    #[cfg(target_arch = "wasm32")]
+    fn with_request_timeout(&self, request: RequestBuilder) -> RequestBuilder {
+        request
     }
 
     async fn send_get_request<K, V>(
@@ -61,8 +161,13 @@ impl Client {
         K: AsRef<str>,
         V: AsRef<str>,
     {
-        let url = create_api_url(&self.url, path, params);
-        Ok(self.reqwest_client.get(url).send().await?)
+        self.send_with_failover(|base_url| {
+            self.with_request_timeout(
+                self.reqwest_client
+                    .get(create_api_url(base_url, path, params)),
+            )
+        })
+        .await
     }
 
     async fn query_nym_api<T, K, V>(
@@ -109,6 +214,42 @@ impl Client {
         }
     }
 
+    // Sends the request with `If-None-Match` set to the provided version (if any).
+    // Returns `None` if the data has not been modified since.
+    async fn query_nym_api_conditional<T, K, V>(
+        &self,
+        path: PathSegments<'_>,
+        params: Params<'_, K, V>,
+        known_version: Option<u64>,
+    ) -> Result<Option<T>, NymAPIError>
+    where
+        for<'a> T: Deserialize<'a>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let res = self
+            .send_with_failover(|base_url| {
+                let request = self
+                    .reqwest_client
+                    .get(create_api_url(base_url, path, params));
+                let request = match known_version {
+                    Some(version) => request.header(IF_NONE_MATCH, format!("\"{version}\"")),
+                    None => request,
+                };
+                self.with_request_timeout(request)
+            })
+            .await?;
+        if res.status() == StatusCode::NOT_MODIFIED {
+            Ok(None)
+        } else if res.status().is_success() {
+            Ok(Some(res.json().await?))
+        } else if res.status() == StatusCode::NOT_FOUND {
+            Err(NymAPIError::NotFound)
+        } else {
+            Err(NymAPIError::GenericRequestFailure(res.text().await?))
+        }
+    }
+
     async fn post_nym_api<B, T, K, V>(
         &self,
         path: PathSegments<'_>,
@@ -121,8 +262,15 @@ impl Client {
         K: AsRef<str>,
         V: AsRef<str>,
     {
-        let url = create_api_url(&self.url, path, params);
-        let response = self.reqwest_client.post(url).json(json_body).send().await?;
+        let response = self
+            .send_with_failover(|base_url| {
+                self.with_request_timeout(
+                    self.reqwest_client
+                        .post(create_api_url(base_url, path, params))
+                        .json(json_body),
+                )
+            })
+            .await?;
         if response.status().is_success() {
             Ok(response.json().await?)
         } else {
@@ -168,6 +316,76 @@ impl Client {
             .await
     }
 
+    /// Gets the compact network topology, unless it's still at the provided version.
+    pub async fn get_topology(
+        &self,
+        known_version: Option<u64>,
+    ) -> Result<Option<CompactTopology>, NymAPIError> {
+        self.query_nym_api_conditional(
+            &[routes::API_VERSION, routes::TOPOLOGY],
+            NO_PARAMS,
+            known_version,
+        )
+        .await
+    }
+
+    /// Gets the changes to the network topology since the provided version,
+    /// unless nothing has changed.
+    pub async fn get_topology_changes(
+        &self,
+        since: u64,
+    ) -> Result<Option<TopologyChangesResponse>, NymAPIError> {
+        self.query_nym_api_conditional(
+            &[routes::API_VERSION, routes::TOPOLOGY, routes::CHANGES],
+            &[(routes::SINCE_ARG, since.to_string())],
+            Some(since),
+        )
+        .await
+    }
+
+    /// Subscribes to the stream of network events matching the provided filter.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn subscribe_to_network_events(
+        &self,
+        filter: &NetworkEventFilter,
+    ) -> Result<NetworkEventsSubscription, NymAPIError> {
+        let params = filter
+            .mixnodes
+            .iter()
+            .map(|mix_id| (routes::MIX_ID_ARG, mix_id.to_string()))
+            .chain(
+                filter
+                    .gateways
+                    .iter()
+                    .map(|identity| (routes::GATEWAY_ARG, identity.clone())),
+            )
+            .chain(
+                filter
+                    .kinds
+                    .iter()
+                    .map(|kind| (routes::KIND_ARG, kind.to_string())),
+            )
+            .collect::<Vec<_>>();
+
+        // the subscription is long-lived, so the usual request timeout does not apply to it
+        let res = self
+            .send_with_failover(|base_url| {
+                self.reqwest_client.get(create_api_url(
+                    base_url,
+                    &[routes::API_VERSION, routes::EVENTS],
+                    &params,
+                ))
+            })
+            .await?;
+        if res.status().is_success() {
+            Ok(NetworkEventsSubscription::new(res))
+        } else if res.status() == StatusCode::NOT_FOUND {
+            Err(NymAPIError::NotFound)
+        } else {
+            Err(NymAPIError::GenericRequestFailure(res.text().await?))
+        }
+    }
+
     pub async fn get_active_mixnodes(&self) -> Result<Vec<MixNodeDetails>, NymAPIError> {
         self.query_nym_api(
             &[routes::API_VERSION, routes::MIXNODES, routes::ACTIVE],
@@ -315,6 +533,28 @@ impl Client {
         }
     }
 
+    pub async fn get_gateway_timing(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+    ) -> Result<GatewayTimingResponse, NymAPIError> {
+        let params = since
+            .map(|since| vec![(SINCE_ARG, since.to_string())])
+            .unwrap_or_default();
+
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::GATEWAY,
+                identity,
+                routes::TIMING,
+            ],
+            &params,
+        )
+        .await
+    }
+
     pub async fn get_mixnode_core_status_count(
         &self,
         mix_id: MixId,
@@ -347,6 +587,28 @@ impl Client {
         }
     }
 
+    pub async fn get_mixnode_timing(
+        &self,
+        mix_id: MixId,
+        since: Option<i64>,
+    ) -> Result<MixnodeTimingResponse, NymAPIError> {
+        let params = since
+            .map(|since| vec![(SINCE_ARG, since.to_string())])
+            .unwrap_or_default();
+
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::MIXNODE,
+                &mix_id.to_string(),
+                routes::TIMING,
+            ],
+            &params,
+        )
+        .await
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
@@ -434,6 +696,38 @@ impl Client {
         .await
     }
 
+    pub async fn get_latency_matrix(&self) -> Result<LatencyMatrixResponse, NymAPIError> {
+        self.query_nym_api_fallible(
+            &[routes::API_VERSION, routes::LATENCY, routes::MATRIX],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_mixnodes_latency(&self) -> Result<MixnodesLatencyResponse, NymAPIError> {
+        self.query_nym_api_fallible(
+            &[routes::API_VERSION, routes::LATENCY, routes::MIXNODES],
+            NO_PARAMS,
+        )
+        .await
+    }
+
+    pub async fn get_mixnode_latency(
+        &self,
+        mix_id: MixId,
+    ) -> Result<MixnodeLatencyResponse, NymAPIError> {
+        self.query_nym_api_fallible(
+            &[
+                routes::API_VERSION,
+                routes::LATENCY,
+                routes::MIXNODE,
+                &mix_id.to_string(),
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
     pub async fn get_mixnode_avg_uptime(
         &self,
         mix_id: MixId,
@@ -485,6 +779,23 @@ impl Client {
         .await
     }
 
+    pub async fn batch_verify_bandwidth_credentials(
+        &self,
+        request_body: &BatchVerifyCredentialsBody,
+    ) -> Result<BatchVerifyCredentialsResponse, NymAPIError> {
+        self.post_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::COCONUT_ROUTES,
+                routes::BANDWIDTH,
+                routes::COCONUT_BATCH_VERIFY_BANDWIDTH_CREDENTIALS,
+            ],
+            NO_PARAMS,
+            request_body,
+        )
+        .await
+    }
+
     pub async fn get_service_providers(&self) -> Result<ServicesListResponse, NymAPIError> {
         log::trace!("Getting service providers");
         self.query_nym_api(&[routes::API_VERSION, routes::SERVICE_PROVIDERS], NO_PARAMS)
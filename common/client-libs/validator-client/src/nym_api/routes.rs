@@ -13,9 +13,11 @@ pub const ACTIVE: &str = "active";
 pub const REWARDED: &str = "rewarded";
 pub const COCONUT_ROUTES: &str = "coconut";
 pub const BANDWIDTH: &str = "bandwidth";
+pub const DKG: &str = "dkg";
 
 pub const COCONUT_BLIND_SIGN: &str = "blind-sign";
 pub const COCONUT_VERIFY_BANDWIDTH_CREDENTIAL: &str = "verify-bandwidth-credential";
+pub const COCONUT_BATCH_VERIFY_BANDWIDTH_CREDENTIALS: &str = "verify-bandwidth-credentials";
 
 pub const STATUS_ROUTES: &str = "status";
 pub const MIXNODE: &str = "mixnode";
@@ -32,6 +34,18 @@ pub const COMPUTE_REWARD_ESTIMATION: &str = "compute-reward-estimation";
 pub const AVG_UPTIME: &str = "avg_uptime";
 pub const STAKE_SATURATION: &str = "stake-saturation";
 pub const INCLUSION_CHANCE: &str = "inclusion-probability";
+pub const TIMING: &str = "timing";
+
+pub const TOPOLOGY: &str = "topology";
+pub const CHANGES: &str = "changes";
+
+pub const EVENTS: &str = "events";
+pub const MIX_ID_ARG: &str = "mix_id";
+pub const GATEWAY_ARG: &str = "gateway";
+pub const KIND_ARG: &str = "kind";
+
+pub const LATENCY: &str = "latency";
+pub const MATRIX: &str = "matrix";
 
 pub const SERVICE_PROVIDERS: &str = "services";
 pub const REGISTERED_NAMES: &str = "names";
@@ -256,7 +256,14 @@ pub trait CosmWasmClient: rpc::Client {
 
     async fn broadcast_tx(&self, tx: Transaction) -> Result<TxResponse, NyxdError> {
         let broadcasted = CosmWasmClient::broadcast_tx_sync(self, tx).await?;
+        self.wait_for_tx_inclusion(broadcasted).await
+    }
 
+    /// Waits for the already broadcast transaction to get included in a block.
+    async fn wait_for_tx_inclusion(
+        &self,
+        broadcasted: broadcast::tx_sync::Response,
+    ) -> Result<TxResponse, NyxdError> {
         if broadcasted.code.is_err() {
             let code_val = broadcasted.code.value();
             return Err(NyxdError::BroadcastTxErrorDeliverTx {
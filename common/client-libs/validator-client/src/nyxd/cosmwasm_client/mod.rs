@@ -9,7 +9,9 @@ use std::convert::TryInto;
 pub mod client;
 mod helpers;
 pub mod logs;
+pub mod pooled_client;
 pub mod signing_client;
+pub mod tx_queue;
 pub mod types;
 
 pub fn connect<U>(endpoint: U) -> Result<HttpClient, NyxdError>
@@ -0,0 +1,144 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::endpoint_pool::EndpointPool;
+use crate::nyxd::cosmwasm_client::client::CosmWasmClient;
+use crate::nyxd::error::{NyxdError, TendermintRpcErrorDetail};
+use async_trait::async_trait;
+use cosmrs::rpc::{self, Error as TendermintRpcError, HttpClient, HttpClientUrl, SimpleRequest};
+use log::warn;
+use std::convert::TryInto;
+use std::time::{Duration, Instant};
+use url::Url;
+
+#[derive(Debug)]
+enum Transport {
+    Single(HttpClient),
+    Pooled {
+        endpoints: EndpointPool,
+        clients: Vec<(Url, HttpClient)>,
+    },
+}
+
+/// Tendermint RPC client that either talks to a single endpoint or fails over between
+/// all endpoints of the provided pool whenever any of them becomes unreachable.
+#[derive(Debug)]
+pub struct PooledHttpClient {
+    transport: Transport,
+}
+
+impl PooledHttpClient {
+    pub fn new<U>(endpoint: U) -> Result<Self, NyxdError>
+    where
+        U: TryInto<HttpClientUrl, Error = TendermintRpcError>,
+    {
+        Ok(PooledHttpClient {
+            transport: Transport::Single(HttpClient::new(endpoint)?),
+        })
+    }
+
+    pub fn new_pooled(endpoints: EndpointPool) -> Result<Self, NyxdError> {
+        let clients = endpoints
+            .urls()
+            .into_iter()
+            .map(|url| {
+                let client = HttpClient::new(url.as_str())?;
+                Ok((url, client))
+            })
+            .collect::<Result<_, NyxdError>>()?;
+
+        Ok(PooledHttpClient {
+            transport: Transport::Pooled { endpoints, clients },
+        })
+    }
+
+    /// Returns the pool of endpoints used by this client, if it's using more than a single one.
+    pub fn endpoints(&self) -> Option<&EndpointPool> {
+        match &self.transport {
+            Transport::Single(_) => None,
+            Transport::Pooled { endpoints, .. } => Some(endpoints),
+        }
+    }
+}
+
+async fn perform_with_failover<R>(
+    endpoints: &EndpointPool,
+    clients: &[(Url, HttpClient)],
+    request: R,
+) -> Result<R::Response, TendermintRpcError>
+where
+    R: SimpleRequest,
+{
+    let timeout = endpoints.config().request_timeout;
+
+    // the request is consumed when it's sent, so keep its serialized form around
+    // in case it has to be resent to another endpoint
+    let serialized = serde_json::to_value(&request).ok();
+    let mut request = Some(request);
+
+    let mut last_error = None;
+    for url in endpoints.candidates() {
+        let Some((_, client)) = clients.iter().find(|(client_url, _)| client_url == &url) else {
+            continue;
+        };
+        let Some(attempt) = request.take().or_else(|| {
+            serialized
+                .clone()
+                .and_then(|raw| serde_json::from_value(raw).ok())
+        }) else {
+            break;
+        };
+
+        let start = Instant::now();
+        match tokio::time::timeout(timeout, client.perform(attempt)).await {
+            Ok(Ok(response)) => {
+                endpoints.record_success(&url, start.elapsed());
+                return Ok(response);
+            }
+            // the node is perfectly fine, it just didn't like our request
+            Ok(Err(err @ TendermintRpcError(TendermintRpcErrorDetail::Response(_), _))) => {
+                endpoints.record_success(&url, start.elapsed());
+                return Err(err);
+            }
+            Ok(Err(err)) => {
+                warn!("nyxd request to {url} has failed: {err}");
+                endpoints.record_failure(&url);
+                last_error = Some(err);
+            }
+            Err(_) => {
+                warn!("nyxd request to {url} has timed out");
+                endpoints.record_failure(&url);
+                last_error = Some(TendermintRpcError::timeout(timeout));
+            }
+        }
+    }
+
+    // the first attempt is always made as all endpoints of the pool have an associated client
+    Err(last_error.expect("the endpoint pool is empty"))
+}
+
+#[async_trait]
+impl rpc::Client for PooledHttpClient {
+    async fn perform<R>(&self, request: R) -> Result<R::Response, rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        match &self.transport {
+            Transport::Single(client) => client.perform(request).await,
+            Transport::Pooled { endpoints, clients } => {
+                perform_with_failover(endpoints, clients, request).await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CosmWasmClient for PooledHttpClient {
+    fn broadcast_polling_rate(&self) -> Duration {
+        Duration::from_secs(4)
+    }
+
+    fn broadcast_timeout(&self) -> Duration {
+        Duration::from_secs(60)
+    }
+}
@@ -1,9 +1,14 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::endpoint_pool::EndpointPool;
 use crate::nyxd::cosmwasm_client::client::CosmWasmClient;
 use crate::nyxd::cosmwasm_client::helpers::{compress_wasm_code, CheckResponse};
 use crate::nyxd::cosmwasm_client::logs::{self, parse_raw_logs};
+use crate::nyxd::cosmwasm_client::pooled_client::PooledHttpClient;
+use crate::nyxd::cosmwasm_client::tx_queue::{
+    simulation_sequence_mismatch, CheckTxOutcome, CheckTxResult, TxQueue, TxQueueConfig,
+};
 use crate::nyxd::cosmwasm_client::types::*;
 use crate::nyxd::error::NyxdError;
 use crate::nyxd::fee::{Fee, DEFAULT_SIMULATED_GAS_MULTIPLIER};
@@ -19,15 +24,17 @@ use cosmrs::feegrant::{
 };
 use cosmrs::proto::cosmos::tx::signing::v1beta1::SignMode;
 use cosmrs::rpc::endpoint::broadcast;
-use cosmrs::rpc::{Error as TendermintRpcError, HttpClient, HttpClientUrl, SimpleRequest};
+use cosmrs::rpc::{Error as TendermintRpcError, HttpClientUrl, SimpleRequest};
 use cosmrs::staking::{MsgDelegate, MsgUndelegate};
+use cosmrs::tendermint::abci::Transaction;
 use cosmrs::tx::{self, Msg, Raw};
 use cosmrs::{cosmwasm, rpc, AccountId, Any, Tx};
-use log::debug;
+use log::{debug, warn};
 use serde::Serialize;
 use sha2::Digest;
 use sha2::Sha256;
 use std::convert::TryInto;
+use std::future::Future;
 use std::time::{Duration, SystemTime};
 
 const DEFAULT_BROADCAST_POLLING_RATE: Duration = Duration::from_secs(4);
@@ -75,12 +82,25 @@ pub trait SigningCosmWasmClient: CosmWasmClient {
         messages: Vec<Any>,
         memo: impl Into<String> + Send + 'static,
     ) -> Result<SimulateResponse, NyxdError> {
-        let public_key = self.signer_public_key(signer_address);
         let sequence_response = self.get_sequence(signer_address).await?;
+        self.simulate_with_sequence(signer_address, messages, memo, sequence_response.sequence)
+            .await
+    }
+
+    /// Simulates the transaction as if it was signed with the provided sequence number
+    /// rather than the one currently committed on chain.
+    async fn simulate_with_sequence(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        memo: impl Into<String> + Send + 'static,
+        sequence: tx::SequenceNumber,
+    ) -> Result<SimulateResponse, NyxdError> {
+        let public_key = self.signer_public_key(signer_address);
 
         let partial_tx = Tx {
             body: tx::Body::new(messages, memo, 0u32),
-            auth_info: single_unspecified_signer_auth(public_key, sequence_response.sequence),
+            auth_info: single_unspecified_signer_auth(public_key, sequence),
             signatures: vec![Vec::new()],
         };
         self.query_simulate(Some(partial_tx), Vec::new()).await
@@ -549,14 +569,42 @@ pub trait SigningCosmWasmClient: CosmWasmClient {
         messages: &[Any],
         fee: Fee,
         memo: &String,
+    ) -> Result<tx::Fee, NyxdError> {
+        self.determine_transaction_fee_with_sequence(signer_address, messages, fee, memo, None)
+            .await
+    }
+
+    /// Determines the transaction fee. If it has to be simulated, the provided sequence number
+    /// is used, or the one currently committed on chain if none was specified.
+    #[allow(clippy::ptr_arg)]
+    async fn determine_transaction_fee_with_sequence(
+        &self,
+        signer_address: &AccountId,
+        messages: &[Any],
+        fee: Fee,
+        memo: &String,
+        sequence: Option<tx::SequenceNumber>,
     ) -> Result<tx::Fee, NyxdError> {
         let auto_fee = |multiplier: Option<f32>| async move {
             debug!("Trying to simulate gas costs...");
+            let simulated = match sequence {
+                Some(sequence) => {
+                    self.simulate_with_sequence(
+                        signer_address,
+                        messages.to_vec(),
+                        memo.clone(),
+                        sequence,
+                    )
+                    .await?
+                }
+                None => {
+                    self.simulate(signer_address, messages.to_vec(), memo.clone())
+                        .await?
+                }
+            };
             // from what I've seen in manual testing, gas estimation does not exist if transaction
             // fails to get executed (for example if you send 'BondMixnode" with invalid signature)
-            let gas_estimation = self
-                .simulate(signer_address, messages.to_vec(), memo.clone())
-                .await?
+            let gas_estimation = simulated
                 .gas_info
                 .ok_or(NyxdError::GasEstimationFailure)?
                 .gas_used;
@@ -678,8 +726,9 @@ pub trait SigningCosmWasmClient: CosmWasmClient {
         let signer_data = match explicit_signer_data {
             Some(signer_data) => signer_data,
             None => {
-                // TODO: Future optimisation: rather than grabbing current account_number and sequence
-                // on every sign request -> just keep them cached on the struct and increment as required
+                // note: signing on its own can't know whether the transaction is ever going to be
+                // broadcast, so the sequence is always retrieved from the chain. `Client` keeps track
+                // of the sequence numbers of the transactions it broadcasts itself
                 let sequence_response = self.get_sequence(signer_address).await?;
                 let chain_id = self.get_chain_id().await?;
 
@@ -714,12 +763,14 @@ pub struct Client<S> {
     // TODO: somehow nicely hide this guy if we decide to use our client in offline mode,
     // maybe just convert it into an option?
     // or maybe we need another level of indirection. tbd.
-    rpc_client: HttpClient,
+    rpc_client: PooledHttpClient,
     tx_signer: TxSigner<S>,
     gas_price: GasPrice,
 
     broadcast_polling_rate: Duration,
     broadcast_timeout: Duration,
+
+    tx_queue: TxQueue,
 }
 
 impl<S> Client<S> {
@@ -731,13 +782,30 @@ impl<S> Client<S> {
     where
         U: TryInto<HttpClientUrl, Error = TendermintRpcError>,
     {
-        let rpc_client = HttpClient::new(endpoint)?;
+        let rpc_client = PooledHttpClient::new(endpoint)?;
         Ok(Client {
             rpc_client,
             tx_signer: TxSigner::new(signer),
             gas_price,
             broadcast_polling_rate: DEFAULT_BROADCAST_POLLING_RATE,
             broadcast_timeout: DEFAULT_BROADCAST_TIMEOUT,
+            tx_queue: Default::default(),
+        })
+    }
+
+    /// Creates a client that's going to fail over between all endpoints of the pool.
+    pub fn connect_pooled_with_signer(
+        endpoints: EndpointPool,
+        signer: S,
+        gas_price: GasPrice,
+    ) -> Result<Self, NyxdError> {
+        Ok(Client {
+            rpc_client: PooledHttpClient::new_pooled(endpoints)?,
+            tx_signer: TxSigner::new(signer),
+            gas_price,
+            broadcast_polling_rate: DEFAULT_BROADCAST_POLLING_RATE,
+            broadcast_timeout: DEFAULT_BROADCAST_TIMEOUT,
+            tx_queue: Default::default(),
         })
     }
 
@@ -752,7 +820,7 @@ impl<S> Client<S> {
     where
         U: TryInto<HttpClientUrl, Error = TendermintRpcError>,
     {
-        let new_rpc_client = HttpClient::new(new_endpoint)?;
+        let new_rpc_client = PooledHttpClient::new(new_endpoint)?;
         self.rpc_client = new_rpc_client;
         Ok(())
     }
@@ -768,6 +836,143 @@ impl<S> Client<S> {
     pub fn set_broadcast_timeout(&mut self, broadcast_timeout: Duration) {
         self.broadcast_timeout = broadcast_timeout
     }
+
+    pub fn set_tx_queue_config(&mut self, config: TxQueueConfig) {
+        self.tx_queue.set_config(config)
+    }
+}
+
+impl<S> Client<S>
+where
+    S: OfflineSigner + Send + Sync,
+    NyxdError: From<S::Error>,
+{
+    async fn fetch_signer_data(&self, signer_address: &AccountId) -> Result<SignerData, NyxdError> {
+        let sequence_response = self.get_sequence(signer_address).await?;
+        let chain_id = self.get_chain_id().await?;
+
+        Ok(SignerData::new_from_sequence_response(
+            sequence_response,
+            chain_id,
+        ))
+    }
+
+    // Signs the messages with the next sequence number of the signer and submits them with the
+    // provided broadcast method. Transactions of the same signer are simulated (if needed) and
+    // submitted one at a time using the cached sequence number. If any of them gets rejected due
+    // to a sequence mismatch (for example because some other client is using the same account),
+    // it gets re-simulated and re-signed with the resynced sequence and resubmitted.
+    async fn sign_and_submit<F, Fut, R>(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        fee: Fee,
+        memo: String,
+        broadcast: F,
+    ) -> Result<R, NyxdError>
+    where
+        F: Fn(Transaction) -> Fut,
+        Fut: Future<Output = Result<R, NyxdError>>,
+        R: CheckTxResult,
+    {
+        let config = *self.tx_queue.config();
+        let signer_slot = self.tx_queue.signer_slot(signer_address);
+        let mut cached_signer_data = signer_slot.lock().await;
+
+        let mut attempt = 0;
+        loop {
+            let signer_data = match cached_signer_data.take() {
+                Some(signer_data) => signer_data,
+                None => self.fetch_signer_data(signer_address).await?,
+            };
+
+            // the simulation has to use the same sequence as the transaction itself,
+            // as the committed one doesn't account for the transactions still in the mempool
+            let tx_fee = match self
+                .determine_transaction_fee_with_sequence(
+                    signer_address,
+                    &messages,
+                    fee.clone(),
+                    &memo,
+                    Some(signer_data.sequence),
+                )
+                .await
+            {
+                Ok(tx_fee) => tx_fee,
+                Err(err) => match simulation_sequence_mismatch(&err) {
+                    Some(expected) if attempt < config.max_sequence_retries => {
+                        attempt += 1;
+                        warn!(
+                            "simulation of transaction with sequence {} has failed due to sequence mismatch (expected: {expected:?}). Retrying it (attempt {attempt}/{})",
+                            signer_data.sequence, config.max_sequence_retries
+                        );
+                        *cached_signer_data = expected.map(|sequence| SignerData {
+                            sequence,
+                            ..signer_data
+                        });
+                        tokio::time::sleep(config.retry_backoff(attempt)).await;
+                        continue;
+                    }
+                    // give up and let the next transaction resync with the chain
+                    Some(_) => return Err(err),
+                    None => {
+                        // nothing got submitted, so the sequence is still unused
+                        *cached_signer_data = Some(signer_data);
+                        return Err(err);
+                    }
+                },
+            };
+
+            let tx_bytes = self
+                .sign_direct(
+                    signer_address,
+                    messages.clone(),
+                    tx_fee,
+                    memo.clone(),
+                    signer_data.clone(),
+                )?
+                .to_bytes()
+                .map_err(|_| NyxdError::SerializationError("Tx".to_owned()))?;
+
+            // if the broadcast itself has failed we don't know whether the sequence got used,
+            // so it will have to be retrieved from the chain again for the next transaction
+            let response = broadcast(tx_bytes.into()).await?;
+
+            match response.check_tx_outcome() {
+                CheckTxOutcome::Accepted => {
+                    *cached_signer_data = Some(SignerData {
+                        sequence: signer_data.sequence + 1,
+                        ..signer_data
+                    });
+                    return Ok(response);
+                }
+                CheckTxOutcome::Rejected => {
+                    // the transaction didn't make it to the mempool, so its sequence is still unused
+                    *cached_signer_data = Some(signer_data);
+                    return Ok(response);
+                }
+                CheckTxOutcome::SequenceMismatch { expected }
+                    if attempt < config.max_sequence_retries =>
+                {
+                    attempt += 1;
+                    warn!(
+                        "transaction signed with sequence {} got rejected due to sequence mismatch (expected: {expected:?}). Resubmitting it (attempt {attempt}/{})",
+                        signer_data.sequence, config.max_sequence_retries
+                    );
+                    // if we couldn't parse the expected value, get it from the chain instead
+                    *cached_signer_data = expected.map(|sequence| SignerData {
+                        sequence,
+                        ..signer_data
+                    });
+                    tokio::time::sleep(config.retry_backoff(attempt)).await;
+                }
+                CheckTxOutcome::SequenceMismatch { .. } => {
+                    // give up and let the next transaction resync with the chain
+                    return Ok(response);
+                }
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -813,6 +1018,68 @@ where
         &self.gas_price
     }
 
+    async fn sign_and_broadcast_async(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<broadcast::tx_async::Response, NyxdError> {
+        let memo = memo.into();
+        self.sign_and_submit(signer_address, messages, fee, memo, |tx| {
+            CosmWasmClient::broadcast_tx_async(self, tx)
+        })
+        .await
+    }
+
+    async fn sign_and_broadcast_sync(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<broadcast::tx_sync::Response, NyxdError> {
+        let memo = memo.into();
+        self.sign_and_submit(signer_address, messages, fee, memo, |tx| {
+            CosmWasmClient::broadcast_tx_sync(self, tx)
+        })
+        .await
+    }
+
+    async fn sign_and_broadcast_commit(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<broadcast::tx_commit::Response, NyxdError> {
+        let memo = memo.into();
+        self.sign_and_submit(signer_address, messages, fee, memo, |tx| {
+            CosmWasmClient::broadcast_tx_commit(self, tx)
+        })
+        .await
+    }
+
+    async fn sign_and_broadcast(
+        &self,
+        signer_address: &AccountId,
+        messages: Vec<Any>,
+        fee: Fee,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<TxResponse, NyxdError> {
+        let memo = memo.into();
+
+        // only the submission goes through the queue, waiting for the inclusion doesn't block
+        // other transactions of this signer
+        let broadcasted = self
+            .sign_and_submit(signer_address, messages, fee, memo, |tx| {
+                CosmWasmClient::broadcast_tx_sync(self, tx)
+            })
+            .await?;
+
+        self.wait_for_tx_inclusion(broadcasted).await
+    }
+
     fn sign_amino(
         &self,
         signer_address: &AccountId,
@@ -839,3 +1106,190 @@ where
             .sign_direct(signer_address, messages, fee, memo, signer_data)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signing::direct_wallet::DirectSecp256k1HdWallet;
+    use cosmrs::tendermint::{abci, chain};
+    use std::sync::Mutex;
+
+    const MNEMONIC: &str = "crush minute paddle tobacco message debate cabin peace bar jacket execute twenty winner view sure mask popular couch penalty fragile demise fresh pizza stove";
+
+    struct MockCheckTx {
+        code: abci::Code,
+        log: String,
+    }
+
+    impl MockCheckTx {
+        fn accepted() -> Self {
+            MockCheckTx {
+                code: abci::Code::Ok,
+                log: String::new(),
+            }
+        }
+
+        fn sequence_mismatch(expected: tx::SequenceNumber, got: tx::SequenceNumber) -> Self {
+            MockCheckTx {
+                code: abci::Code::from(32),
+                log: format!("account sequence mismatch, expected {expected}, got {got}: incorrect account sequence"),
+            }
+        }
+    }
+
+    impl CheckTxResult for MockCheckTx {
+        fn check_tx_code(&self) -> abci::Code {
+            self.code
+        }
+
+        fn check_tx_log(&self) -> &str {
+            &self.log
+        }
+    }
+
+    fn test_client() -> (Client<DirectSecp256k1HdWallet>, AccountId) {
+        let wallet = DirectSecp256k1HdWallet::from_mnemonic("n", MNEMONIC.parse().unwrap());
+        let signer_address = wallet.try_derive_accounts().unwrap()[0].address.clone();
+
+        // note: nothing is ever sent to this endpoint
+        let mut client = Client::connect_with_signer(
+            "http://localhost:26657",
+            wallet,
+            "0.025unym".parse().unwrap(),
+        )
+        .unwrap();
+        client.set_tx_queue_config(TxQueueConfig {
+            max_sequence_retries: 2,
+            initial_retry_backoff: Duration::ZERO,
+            max_retry_backoff: Duration::ZERO,
+        });
+
+        (client, signer_address)
+    }
+
+    // use a manual fee so that nothing has to be simulated
+    fn manual_fee(client: &Client<DirectSecp256k1HdWallet>) -> Fee {
+        let gas = tx::Gas::from(100_000u64);
+        Fee::Manual(tx::Fee::from_amount_and_gas(client.gas_price() * gas, gas))
+    }
+
+    async fn cache_sequence(
+        client: &Client<DirectSecp256k1HdWallet>,
+        signer_address: &AccountId,
+        sequence: tx::SequenceNumber,
+    ) {
+        let chain_id: chain::Id = "nyx".parse().unwrap();
+        *client.tx_queue.signer_slot(signer_address).lock().await =
+            Some(SignerData::new(1, sequence, chain_id));
+    }
+
+    async fn cached_sequence(
+        client: &Client<DirectSecp256k1HdWallet>,
+        signer_address: &AccountId,
+    ) -> Option<tx::SequenceNumber> {
+        client
+            .tx_queue
+            .signer_slot(signer_address)
+            .lock()
+            .await
+            .as_ref()
+            .map(|signer_data| signer_data.sequence)
+    }
+
+    fn submitted_sequence(tx: &Transaction) -> tx::SequenceNumber {
+        Tx::from_bytes(tx.as_bytes())
+            .unwrap()
+            .auth_info
+            .signer_infos[0]
+            .sequence
+    }
+
+    #[tokio::test]
+    async fn transaction_is_resubmitted_after_sequence_mismatch() {
+        let (client, signer_address) = test_client();
+        cache_sequence(&client, &signer_address, 10).await;
+
+        // some other client has used sequences 10 and 11 in the meantime
+        let submitted = Mutex::new(Vec::new());
+        let broadcast = |tx: Transaction| {
+            let sequence = submitted_sequence(&tx);
+            submitted.lock().unwrap().push(sequence);
+            async move {
+                if sequence < 12 {
+                    Ok(MockCheckTx::sequence_mismatch(12, sequence))
+                } else {
+                    Ok(MockCheckTx::accepted())
+                }
+            }
+        };
+
+        let fee = manual_fee(&client);
+        let res = client
+            .sign_and_submit(
+                &signer_address,
+                Vec::new(),
+                fee.clone(),
+                String::new(),
+                &broadcast,
+            )
+            .await
+            .unwrap();
+        assert!(res.code.is_ok());
+        assert_eq!(*submitted.lock().unwrap(), vec![10, 12]);
+        assert_eq!(cached_sequence(&client, &signer_address).await, Some(13));
+
+        // the next transaction uses the following sequence straight away
+        client
+            .sign_and_submit(&signer_address, Vec::new(), fee, String::new(), &broadcast)
+            .await
+            .unwrap();
+        assert_eq!(*submitted.lock().unwrap(), vec![10, 12, 13]);
+        assert_eq!(cached_sequence(&client, &signer_address).await, Some(14));
+    }
+
+    #[tokio::test]
+    async fn sequence_is_resynced_after_running_out_of_retries() {
+        let (client, signer_address) = test_client();
+        cache_sequence(&client, &signer_address, 10).await;
+
+        let submitted = Mutex::new(Vec::new());
+        let broadcast = |tx: Transaction| {
+            let sequence = submitted_sequence(&tx);
+            submitted.lock().unwrap().push(sequence);
+            // the sequence keeps on getting used by someone else
+            async move { Ok(MockCheckTx::sequence_mismatch(sequence + 1, sequence)) }
+        };
+
+        let fee = manual_fee(&client);
+        let res = client
+            .sign_and_submit(&signer_address, Vec::new(), fee, String::new(), &broadcast)
+            .await
+            .unwrap();
+        assert!(res.code.is_err());
+        assert_eq!(*submitted.lock().unwrap(), vec![10, 11, 12]);
+
+        // the next transaction will have to get the sequence from the chain
+        assert!(cached_sequence(&client, &signer_address).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn sequence_of_rejected_transaction_is_reused() {
+        let (client, signer_address) = test_client();
+        cache_sequence(&client, &signer_address, 10).await;
+
+        let broadcast = |_| async move {
+            Ok(MockCheckTx {
+                code: abci::Code::from(5),
+                log: "insufficient funds".to_string(),
+            })
+        };
+
+        let fee = manual_fee(&client);
+        let res = client
+            .sign_and_submit(&signer_address, Vec::new(), fee, String::new(), broadcast)
+            .await
+            .unwrap();
+        assert!(res.code.is_err());
+        assert_eq!(cached_sequence(&client, &signer_address).await, Some(10));
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nyxd::error::NyxdError;
+use crate::signing::SignerData;
+use cosmrs::rpc::endpoint::broadcast;
+use cosmrs::tendermint::abci;
+use cosmrs::tx::SequenceNumber;
+use cosmrs::AccountId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Mutex as AsyncMutex;
+
+const DEFAULT_MAX_SEQUENCE_RETRIES: u32 = 5;
+const DEFAULT_INITIAL_RETRY_BACKOFF: Duration = Duration::from_millis(500);
+const DEFAULT_MAX_RETRY_BACKOFF: Duration = Duration::from_secs(8);
+
+// the error returned by the cosmos-sdk ante handler (`ErrWrongSequence`), e.g.
+// "account sequence mismatch, expected 42, got 41: incorrect account sequence"
+const SEQUENCE_MISMATCH_ERROR: &str = "incorrect account sequence";
+const EXPECTED_SEQUENCE_PREFIX: &str = "expected ";
+
+#[derive(Debug, Clone, Copy)]
+pub struct TxQueueConfig {
+    /// Maximum number of times a transaction rejected because of a sequence mismatch
+    /// is going to get re-signed and resubmitted.
+    pub max_sequence_retries: u32,
+
+    /// Delay before the first resubmission. It's doubled with every subsequent attempt.
+    pub initial_retry_backoff: Duration,
+
+    /// Upper bound on the delay between resubmissions.
+    pub max_retry_backoff: Duration,
+}
+
+impl Default for TxQueueConfig {
+    fn default() -> Self {
+        TxQueueConfig {
+            max_sequence_retries: DEFAULT_MAX_SEQUENCE_RETRIES,
+            initial_retry_backoff: DEFAULT_INITIAL_RETRY_BACKOFF,
+            max_retry_backoff: DEFAULT_MAX_RETRY_BACKOFF,
+        }
+    }
+}
+
+impl TxQueueConfig {
+    pub(crate) fn retry_backoff(&self, attempt: u32) -> Duration {
+        let multiplier = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_retry_backoff
+            .saturating_mul(multiplier)
+            .min(self.max_retry_backoff)
+    }
+}
+
+/// Signing information of a single account. The lock is held for the entire duration of signing
+/// and submitting a transaction, so that transactions of the same signer are processed one at
+/// a time and in order, while the cached sequence number saves a chain query for each of them.
+pub(crate) type SignerSlot = Arc<AsyncMutex<Option<SignerData>>>;
+
+#[derive(Debug, Default)]
+pub(crate) struct TxQueue {
+    config: TxQueueConfig,
+    signers: Mutex<HashMap<AccountId, SignerSlot>>,
+}
+
+impl TxQueue {
+    pub(crate) fn config(&self) -> &TxQueueConfig {
+        &self.config
+    }
+
+    pub(crate) fn set_config(&mut self, config: TxQueueConfig) {
+        self.config = config
+    }
+
+    pub(crate) fn signer_slot(&self, address: &AccountId) -> SignerSlot {
+        let mut signers = match self.signers.lock() {
+            Ok(guard) => guard,
+            // the map itself can't be left in an inconsistent state
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        Arc::clone(signers.entry(address.clone()).or_default())
+    }
+}
+
+pub(crate) enum CheckTxOutcome {
+    Accepted,
+    SequenceMismatch { expected: Option<SequenceNumber> },
+    Rejected,
+}
+
+/// Allows inspecting the result of `CheckTx` for all of the broadcast modes.
+pub(crate) trait CheckTxResult {
+    fn check_tx_code(&self) -> abci::Code;
+
+    fn check_tx_log(&self) -> &str;
+
+    fn check_tx_outcome(&self) -> CheckTxOutcome {
+        if self.check_tx_code().is_ok() {
+            return CheckTxOutcome::Accepted;
+        }
+
+        let log = self.check_tx_log();
+        if log.contains(SEQUENCE_MISMATCH_ERROR) {
+            CheckTxOutcome::SequenceMismatch {
+                expected: parse_expected_sequence(log),
+            }
+        } else {
+            CheckTxOutcome::Rejected
+        }
+    }
+}
+
+// note: with async broadcast the `CheckTx` result is not known yet, so the code will always be 0
+impl CheckTxResult for broadcast::tx_async::Response {
+    fn check_tx_code(&self) -> abci::Code {
+        self.code
+    }
+
+    fn check_tx_log(&self) -> &str {
+        self.log.value()
+    }
+}
+
+impl CheckTxResult for broadcast::tx_sync::Response {
+    fn check_tx_code(&self) -> abci::Code {
+        self.code
+    }
+
+    fn check_tx_log(&self) -> &str {
+        self.log.value()
+    }
+}
+
+impl CheckTxResult for broadcast::tx_commit::Response {
+    fn check_tx_code(&self) -> abci::Code {
+        self.check_tx.code
+    }
+
+    fn check_tx_log(&self) -> &str {
+        self.check_tx.log.value()
+    }
+}
+
+/// Checks whether the transaction simulation has failed because it used a stale sequence number.
+/// If so, returns the sequence expected by the chain, if it could be parsed.
+pub(crate) fn simulation_sequence_mismatch(err: &NyxdError) -> Option<Option<SequenceNumber>> {
+    match err {
+        NyxdError::AbciError { log, .. } if log.value().contains(SEQUENCE_MISMATCH_ERROR) => {
+            Some(parse_expected_sequence(log.value()))
+        }
+        _ => None,
+    }
+}
+
+fn parse_expected_sequence(log: &str) -> Option<SequenceNumber> {
+    let (_, remaining) = log.split_once(EXPECTED_SEQUENCE_PREFIX)?;
+    remaining
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_expected_sequence() {
+        assert_eq!(
+            parse_expected_sequence(
+                "account sequence mismatch, expected 42, got 41: incorrect account sequence"
+            ),
+            Some(42)
+        );
+        assert_eq!(
+            parse_expected_sequence("account sequence mismatch, expected 7: incorrect"),
+            Some(7)
+        );
+        assert!(parse_expected_sequence("incorrect account sequence").is_none());
+        assert!(parse_expected_sequence("expected foo, got 41").is_none());
+    }
+
+    #[test]
+    fn retry_backoff_is_bounded() {
+        let config = TxQueueConfig {
+            max_sequence_retries: 10,
+            initial_retry_backoff: Duration::from_millis(100),
+            max_retry_backoff: Duration::from_secs(1),
+        };
+
+        assert_eq!(config.retry_backoff(1), Duration::from_millis(100));
+        assert_eq!(config.retry_backoff(2), Duration::from_millis(200));
+        assert_eq!(config.retry_backoff(4), Duration::from_millis(800));
+        assert_eq!(config.retry_backoff(5), Duration::from_secs(1));
+        assert_eq!(config.retry_backoff(100), Duration::from_secs(1));
+    }
+}
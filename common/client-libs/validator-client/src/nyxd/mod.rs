@@ -1,6 +1,7 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::endpoint_pool::EndpointPool;
 use crate::nyxd::cosmwasm_client::signing_client;
 use crate::nyxd::cosmwasm_client::types::{
     Account, ChangeAdminResult, ContractCodeId, ExecuteResult, InstantiateOptions,
@@ -19,7 +20,9 @@ use cosmrs::tx::Msg;
 use log::{debug, trace};
 use nym_network_defaults::{ChainDetails, NymNetworkDetails};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::convert::TryInto;
+use std::sync::{Mutex, MutexGuard};
 use std::time::SystemTime;
 
 pub use crate::nyxd::cosmwasm_client::client::CosmWasmClient;
@@ -29,7 +32,6 @@ pub use coin::Coin;
 pub use cosmrs::bank::MsgSend;
 pub use cosmrs::rpc::endpoint::tx::Response as TxResponse;
 pub use cosmrs::rpc::endpoint::validators::Response as ValidatorResponse;
-pub use cosmrs::rpc::HttpClient as QueryNyxdClient;
 pub use cosmrs::rpc::Paging;
 pub use cosmrs::tendermint::abci::responses::{DeliverTx, Event};
 pub use cosmrs::tendermint::abci::tag::Tag;
@@ -40,6 +42,8 @@ pub use cosmrs::tendermint::Time as TendermintTime;
 pub use cosmrs::tx::{self, Gas};
 pub use cosmrs::Coin as CosmosCoin;
 pub use cosmrs::{bip32, AccountId, Decimal, Denom};
+pub use cosmwasm_client::pooled_client::PooledHttpClient as QueryNyxdClient;
+pub use cosmwasm_client::tx_queue::TxQueueConfig;
 use cosmwasm_std::Addr;
 pub use cosmwasm_std::Coin as CosmWasmCoin;
 pub use fee::{gas_price::GasPrice, GasAdjustable, GasAdjustment};
@@ -148,12 +152,17 @@ impl Config {
     }
 }
 
+type PendingExecutions = HashMap<AccountId, Vec<(serde_json::Value, Vec<Coin>)>>;
+
 #[derive(Debug)]
 pub struct NyxdClient<C> {
     client: C,
     config: Config,
     client_address: Option<Vec<AccountId>>,
     simulated_gas_multiplier: f32,
+
+    // contract executions waiting to be sent together in a single transaction
+    pending_executions: Mutex<PendingExecutions>,
 }
 
 impl NyxdClient<QueryNyxdClient> {
@@ -166,6 +175,21 @@ impl NyxdClient<QueryNyxdClient> {
             config,
             client_address: None,
             simulated_gas_multiplier: DEFAULT_SIMULATED_GAS_MULTIPLIER,
+            pending_executions: Default::default(),
+        })
+    }
+
+    /// Connects to the nyxd endpoints of the pool, failing over between them as required.
+    pub fn connect_pooled(
+        config: Config,
+        endpoints: EndpointPool,
+    ) -> Result<NyxdClient<QueryNyxdClient>, NyxdError> {
+        Ok(NyxdClient {
+            client: QueryNyxdClient::new_pooled(endpoints)?,
+            config,
+            client_address: None,
+            simulated_gas_multiplier: DEFAULT_SIMULATED_GAS_MULTIPLIER,
+            pending_executions: Default::default(),
         })
     }
 }
@@ -185,6 +209,17 @@ impl NyxdClient<SigningNyxdClient<DirectSecp256k1HdWallet>> {
         let wallet = DirectSecp256k1HdWallet::from_mnemonic(prefix, mnemonic);
         Self::connect_with_signer(config, endpoint, wallet, gas_price)
     }
+
+    pub fn connect_pooled_with_mnemonic(
+        config: Config,
+        endpoints: EndpointPool,
+        mnemonic: bip39::Mnemonic,
+        gas_price: Option<GasPrice>,
+    ) -> Result<NyxdClient<SigningNyxdClient<DirectSecp256k1HdWallet>>, NyxdError> {
+        let prefix = &config.chain_details.bech32_account_prefix;
+        let wallet = DirectSecp256k1HdWallet::from_mnemonic(prefix, mnemonic);
+        Self::connect_pooled_with_signer(config, endpoints, wallet, gas_price)
+    }
 }
 
 impl<S> NyxdClient<SigningNyxdClient<S>>
@@ -215,6 +250,31 @@ where
             config,
             client_address: Some(client_address),
             simulated_gas_multiplier: DEFAULT_SIMULATED_GAS_MULTIPLIER,
+            pending_executions: Default::default(),
+        })
+    }
+
+    /// Connects to the nyxd endpoints of the pool, failing over between them as required.
+    pub fn connect_pooled_with_signer(
+        config: Config,
+        endpoints: EndpointPool,
+        signer: S,
+        gas_price: Option<GasPrice>,
+    ) -> Result<NyxdClient<SigningNyxdClient<S>>, NyxdError> {
+        let denom = &config.chain_details.mix_denom.base;
+        let client_address = signer
+            .get_accounts()?
+            .into_iter()
+            .map(|account| account.address)
+            .collect();
+        let gas_price = gas_price.unwrap_or(GasPrice::new_with_default_price(denom)?);
+
+        Ok(NyxdClient {
+            client: SigningNyxdClient::connect_pooled_with_signer(endpoints, signer, gas_price)?,
+            config,
+            client_address: Some(client_address),
+            simulated_gas_multiplier: DEFAULT_SIMULATED_GAS_MULTIPLIER,
+            pending_executions: Default::default(),
         })
     }
 
@@ -228,6 +288,10 @@ where
     pub fn into_signer(self) -> S {
         self.client.into_signer()
     }
+
+    pub fn set_tx_queue_config(&mut self, config: TxQueueConfig) {
+        self.client.set_tx_queue_config(config)
+    }
 }
 
 impl<C> NyxdClient<C> {
@@ -679,6 +743,71 @@ impl<C> NyxdClient<C> {
             .await
     }
 
+    /// Queues up the contract execution so that it could later be sent alongside other queued
+    /// executions of the same contract in a single transaction with [`Self::execute_queued`].
+    pub fn queue_execute<M>(
+        &self,
+        contract_address: &AccountId,
+        msg: &M,
+        funds: Vec<Coin>,
+    ) -> Result<(), NyxdError>
+    where
+        M: ?Sized + Serialize,
+    {
+        let msg = serde_json::to_value(msg)?;
+        self.lock_pending_executions()
+            .entry(contract_address.clone())
+            .or_default()
+            .push((msg, funds));
+        Ok(())
+    }
+
+    /// Returns the number of executions of the particular contract that are waiting to be sent.
+    pub fn queued_executions(&self, contract_address: &AccountId) -> usize {
+        self.lock_pending_executions()
+            .get(contract_address)
+            .map(Vec::len)
+            .unwrap_or_default()
+    }
+
+    /// Sends all queued executions of the particular contract in a single transaction.
+    /// Note that the queue is drained regardless of the outcome, so the messages would have to be
+    /// queued again if the transaction has failed.
+    /// Returns `None` if there was nothing to send.
+    pub async fn execute_queued(
+        &self,
+        contract_address: &AccountId,
+        fee: Option<Fee>,
+        memo: impl Into<String> + Send + 'static,
+    ) -> Result<Option<ExecuteResult>, NyxdError>
+    where
+        C: SigningCosmWasmClient + Sync,
+    {
+        let pending = self
+            .lock_pending_executions()
+            .remove(contract_address)
+            .unwrap_or_default();
+        if pending.is_empty() {
+            return Ok(None);
+        }
+
+        debug!(
+            "sending {} queued executions of {contract_address}",
+            pending.len()
+        );
+        self.execute_multiple(contract_address, pending, fee, memo)
+            .await
+            .map(Some)
+    }
+
+    fn lock_pending_executions(&self) -> MutexGuard<'_, PendingExecutions> {
+        match self.pending_executions.lock() {
+            Ok(guard) => guard,
+            // the queue itself can't be left in an inconsistent state
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
     pub async fn upload(
         &self,
         wasm_code: Vec<u8>,
@@ -7,6 +7,9 @@ use crate::nyxd::NyxdClient;
 use async_trait::async_trait;
 use cosmrs::AccountId;
 use nym_contracts_common::signing::Nonce;
+use nym_mixnet_contract_common::bond_transfer::{
+    PendingGatewayTransferResponse, PendingMixnodeTransferResponse,
+};
 use nym_mixnet_contract_common::delegation::{MixNodeDelegationResponse, OwnerProxySubKey};
 use nym_mixnet_contract_common::families::Family;
 use nym_mixnet_contract_common::mixnode::{
@@ -15,7 +18,7 @@ use nym_mixnet_contract_common::mixnode::{
 };
 use nym_mixnet_contract_common::reward_params::{Performance, RewardingParams};
 use nym_mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    CompoundingSettingsResponse, EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
 };
 use nym_mixnet_contract_common::{
     delegation, ContractBuildInformation, ContractState, ContractStateParams,
@@ -26,7 +29,7 @@ use nym_mixnet_contract_common::{
     PagedGatewayResponse, PagedMembersResponse, PagedMixNodeDelegationsResponse,
     PagedMixnodeBondsResponse, PagedRewardedSetResponse, PendingEpochEventResponse,
     PendingEpochEventsResponse, PendingIntervalEventResponse, PendingIntervalEventsResponse,
-    QueryMsg as MixnetQueryMsg,
+    QueryMsg as MixnetQueryMsg, RewardedSetSelectionResponse, RewardedSetSelectionSeedResponse,
 };
 use serde::Deserialize;
 
@@ -82,6 +85,18 @@ pub trait MixnetQueryClient {
             .await
     }
 
+    async fn get_rewarded_set_selection(&self) -> Result<RewardedSetSelectionResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetRewardedSetSelection {})
+            .await
+    }
+
+    async fn get_rewarded_set_selection_seed(
+        &self,
+    ) -> Result<RewardedSetSelectionSeedResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetRewardedSetSelectionSeed {})
+            .await
+    }
+
     async fn get_all_node_families_paged(
         &self,
         start_after: Option<String>,
@@ -214,6 +229,14 @@ pub trait MixnetQueryClient {
             .await
     }
 
+    async fn get_pending_mixnode_transfer(
+        &self,
+        mix_id: MixId,
+    ) -> Result<PendingMixnodeTransferResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingMixnodeTransfer { mix_id })
+            .await
+    }
+
     // gateway-related:
 
     async fn get_gateways_paged(
@@ -245,6 +268,14 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    async fn get_pending_gateway_transfer(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<PendingGatewayTransferResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingGatewayTransfer { identity })
+            .await
+    }
+
     // delegation-related:
 
     /// Gets list of all delegations towards particular mixnode on particular page.
@@ -335,6 +366,28 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    async fn get_delegation_compounding(
+        &self,
+        delegator: &AccountId,
+        mix_id: MixId,
+        proxy: Option<String>,
+    ) -> Result<CompoundingSettingsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetDelegationCompounding {
+            address: delegator.to_string(),
+            mix_id,
+            proxy,
+        })
+        .await
+    }
+
+    async fn get_operator_compounding(
+        &self,
+        mix_id: MixId,
+    ) -> Result<CompoundingSettingsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetOperatorCompounding { mix_id })
+            .await
+    }
+
     // given the provided performance, estimate the reward at the end of the current epoch
     async fn get_estimated_current_epoch_operator_reward(
         &self,
@@ -13,8 +13,10 @@ use nym_mixnet_contract_common::families::FamilyHead;
 use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
 use nym_mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use nym_mixnet_contract_common::reward_params::{IntervalRewardingParamsUpdate, Performance};
+use nym_mixnet_contract_common::rewarding::CompoundingFrequency;
 use nym_mixnet_contract_common::{
-    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, LayerAssignment, MixId, MixNode,
+    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, IdentityKey, LayerAssignment,
+    MixId, MixNode, RewardedSetSelection, SphinxKey,
 };
 
 #[async_trait]
@@ -118,6 +120,7 @@ pub trait MixnetSigningClient {
         &self,
         new_rewarded_set: Vec<LayerAssignment>,
         expected_active_set_size: u32,
+        selection: Option<RewardedSetSelection>,
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError> {
         self.execute_mixnet_contract(
@@ -125,6 +128,7 @@ pub trait MixnetSigningClient {
             MixnetExecuteMsg::AdvanceCurrentEpoch {
                 new_rewarded_set,
                 expected_active_set_size,
+                selection,
             },
             vec![],
         )
@@ -443,6 +447,47 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn propose_mixnode_transfer(
+        &self,
+        new_owner: AccountId,
+        new_identity_key: Option<IdentityKey>,
+        new_sphinx_key: Option<SphinxKey>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::ProposeMixnodeTransfer {
+                new_owner: new_owner.to_string(),
+                new_identity_key,
+                new_sphinx_key,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn accept_mixnode_transfer(
+        &self,
+        mix_id: MixId,
+        identity_signature: MessageSignature,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::AcceptMixnodeTransfer {
+                mix_id,
+                identity_signature,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn cancel_mixnode_transfer(&self, fee: Option<Fee>) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(fee, MixnetExecuteMsg::CancelMixnodeTransfer {}, vec![])
+            .await
+    }
+
     // gateway-related:
 
     async fn bond_gateway(
@@ -533,6 +578,47 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn propose_gateway_transfer(
+        &self,
+        new_owner: AccountId,
+        new_identity_key: Option<IdentityKey>,
+        new_sphinx_key: Option<SphinxKey>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::ProposeGatewayTransfer {
+                new_owner: new_owner.to_string(),
+                new_identity_key,
+                new_sphinx_key,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn accept_gateway_transfer(
+        &self,
+        identity: IdentityKey,
+        identity_signature: MessageSignature,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::AcceptGatewayTransfer {
+                identity,
+                identity_signature,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn cancel_gateway_transfer(&self, fee: Option<Fee>) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(fee, MixnetExecuteMsg::CancelGatewayTransfer {}, vec![])
+            .await
+    }
+
     // delegation-related:
 
     async fn delegate_to_mixnode(
@@ -597,6 +683,42 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn redelegate(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::Redelegate {
+                from_mix_id,
+                to_mix_id,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn redelegate_on_behalf(
+        &self,
+        delegate: AccountId,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RedelegateOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                delegate: delegate.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     // reward-related
 
     async fn reward_mixnode(
@@ -665,6 +787,33 @@ pub trait MixnetSigningClient {
         )
         .await
     }
+
+    async fn update_operator_compounding(
+        &self,
+        frequency: Option<CompoundingFrequency>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::UpdateOperatorCompounding { frequency },
+            vec![],
+        )
+        .await
+    }
+
+    async fn update_delegation_compounding(
+        &self,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::UpdateDelegationCompounding { mix_id, frequency },
+            vec![],
+        )
+        .await
+    }
 }
 
 #[async_trait]
@@ -1,10 +1,12 @@
 use async_trait::async_trait;
 use cosmrs::AccountId;
-use nym_contracts_common::ContractBuildInformation;
+use nym_contracts_common::{signing::Nonce, ContractBuildInformation};
 use nym_name_service_common::{
     msg::QueryMsg as NameQueryMsg,
-    response::{ConfigResponse, NamesListResponse, PagedNamesListResponse},
-    Address, NameEntry, NameId,
+    response::{
+        ConfigResponse, NamesListResponse, PagedNamesListResponse, PendingTransferResponse,
+    },
+    Address, NameEntry, NameId, NymName,
 };
 use serde::Deserialize;
 
@@ -47,6 +49,26 @@ pub trait NameServiceQueryClient {
             .await
     }
 
+    async fn get_name_entry_by_name(&self, name: NymName) -> Result<NameEntry, NyxdError> {
+        self.query_name_service_contract(NameQueryMsg::ByName { name })
+            .await
+    }
+
+    async fn get_pending_name_transfer(
+        &self,
+        name: NymName,
+    ) -> Result<PendingTransferResponse, NyxdError> {
+        self.query_name_service_contract(NameQueryMsg::PendingTransfer { name })
+            .await
+    }
+
+    async fn get_name_signing_nonce(&self, address: &AccountId) -> Result<Nonce, NyxdError> {
+        self.query_name_service_contract(NameQueryMsg::SigningNonce {
+            address: address.to_string(),
+        })
+        .await
+    }
+
     async fn get_name_service_contract_version(
         &self,
     ) -> Result<ContractBuildInformation, NyxdError> {
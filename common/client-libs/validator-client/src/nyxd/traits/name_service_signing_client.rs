@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
+use nym_contracts_common::signing::MessageSignature;
 use nym_name_service_common::{msg::ExecuteMsg as NameExecuteMsg, Address, NameId, NymName};
 
 use crate::nyxd::{
@@ -22,17 +23,87 @@ pub trait NameServiceSigningClient {
         &self,
         name: NymName,
         address: Address,
+        owner_signature: MessageSignature,
         deposit: Coin,
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError> {
         self.execute_name_service_contract(
             fee,
-            NameExecuteMsg::Register { name, address },
+            NameExecuteMsg::Register {
+                name,
+                address,
+                owner_signature,
+            },
             vec![deposit],
         )
         .await
     }
 
+    async fn renew_name(
+        &self,
+        name: NymName,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(fee, NameExecuteMsg::RenewName { name }, vec![])
+            .await
+    }
+
+    async fn update_name_address(
+        &self,
+        name: NymName,
+        address: Address,
+        owner_signature: MessageSignature,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::UpdateAddress {
+                name,
+                address,
+                owner_signature,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn transfer_name(
+        &self,
+        name: NymName,
+        new_owner: String,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::TransferName { name, new_owner },
+            vec![],
+        )
+        .await
+    }
+
+    async fn accept_name_transfer(
+        &self,
+        name: NymName,
+        deposit: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::AcceptNameTransfer { name },
+            vec![deposit],
+        )
+        .await
+    }
+
+    async fn cancel_name_transfer(
+        &self,
+        name: NymName,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(fee, NameExecuteMsg::CancelNameTransfer { name }, vec![])
+            .await
+    }
+
     async fn delete_name_by_id(
         &self,
         name_id: NameId,
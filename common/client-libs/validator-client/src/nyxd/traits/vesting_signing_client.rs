@@ -11,6 +11,7 @@ use nym_contracts_common::signing::MessageSignature;
 use nym_mixnet_contract_common::families::FamilyHead;
 use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
 use nym_mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
+use nym_mixnet_contract_common::rewarding::CompoundingFrequency;
 use nym_mixnet_contract_common::{Gateway, MixId, MixNode};
 use nym_vesting_contract_common::messages::{
     ExecuteMsg as VestingExecuteMsg, VestingSpecification,
@@ -144,6 +145,22 @@ pub trait VestingSigningClient {
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError>;
 
+    async fn vesting_redelegate(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vesting_track_redelegation(
+        &self,
+        address: &str,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -175,6 +192,38 @@ pub trait VestingSigningClient {
         .await
     }
 
+    async fn vesting_update_operator_compounding(
+        &self,
+        frequency: Option<CompoundingFrequency>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::UpdateOperatorCompounding { frequency },
+            Vec::new(),
+        )
+        .await
+    }
+
+    async fn vesting_update_delegation_compounding(
+        &self,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::UpdateDelegationCompounding {
+                mix_id,
+                frequency,
+                on_behalf_of,
+            },
+            Vec::new(),
+        )
+        .await
+    }
+
     async fn update_locked_pledge_cap(
         &self,
         address: AccountId,
@@ -523,6 +572,44 @@ impl<C: SigningCosmWasmClient + Sync + Send> VestingSigningClient for NyxdClient
         .await
     }
 
+    async fn vesting_redelegate(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::Redelegate {
+                from_mix_id,
+                to_mix_id,
+                on_behalf_of,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn vesting_track_redelegation(
+        &self,
+        address: &str,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::TrackRedelegation {
+                owner: address.to_string(),
+                from_mix_id,
+                to_mix_id,
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
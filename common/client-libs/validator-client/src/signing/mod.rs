@@ -43,7 +43,7 @@ impl AccountData {
 }
 
 /// Signing information for a single signer that is not included in the transaction.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct SignerData {
     pub account_number: AccountNumber,
     pub sequence: SequenceNumber,
@@ -55,18 +55,49 @@ impl Credential {
         self.voucher_value
     }
 
-    pub fn verify(&self, verification_key: &VerificationKey) -> bool {
-        let params = Parameters::new(self.n_params).unwrap();
-        let public_attributes = vec![
+    fn public_attributes(&self) -> Vec<Attribute> {
+        vec![
             self.voucher_value.to_string().as_bytes(),
             self.voucher_info.as_bytes(),
         ]
         .iter()
         .map(hash_to_scalar)
-        .collect::<Vec<Attribute>>();
+        .collect()
+    }
+
+    pub fn verify(&self, verification_key: &VerificationKey) -> bool {
+        let params = Parameters::new(self.n_params).unwrap();
+        let public_attributes = self.public_attributes();
         nym_coconut::verify_credential(&params, verification_key, &self.theta, &public_attributes)
     }
 
+    /// Verifies all of the provided credentials, each against its associated verification key,
+    /// as a single batch. Returns the indices of the credentials that failed the verification.
+    pub fn find_invalid(credentials: &[(&Credential, &VerificationKey)]) -> Vec<usize> {
+        let n_params = match credentials.iter().map(|(c, _)| c.n_params).max() {
+            Some(n_params) => n_params,
+            None => return Vec::new(),
+        };
+        let params = match Parameters::new(n_params) {
+            Ok(params) => params,
+            Err(_) => return (0..credentials.len()).collect(),
+        };
+
+        let public_attributes = credentials
+            .iter()
+            .map(|(credential, _)| credential.public_attributes())
+            .collect::<Vec<_>>();
+        let batch = credentials
+            .iter()
+            .zip(public_attributes.iter())
+            .map(|((credential, verification_key), public_attributes)| {
+                BatchedCredential::new(verification_key, &credential.theta, public_attributes)
+            })
+            .collect::<Vec<_>>();
+
+        nym_coconut::find_invalid_credentials(&params, &batch)
+    }
+
     pub fn as_bytes(&self) -> Vec<u8> {
         let n_params_bytes = self.n_params.to_be_bytes();
         let theta_bytes = self.theta.to_bytes();
@@ -11,7 +11,9 @@ pub use nym_validator_client::nym_api::Client as NymApiClient;
 use nym_validator_client::nyxd::{
     self, AccountId, DirectSigningNyxdClient, NyxdClient, QueryNyxdClient,
 };
+use nym_validator_client::{EndpointPool, ValidatorClientError};
 use tap::prelude::*;
+use url::Url;
 
 pub mod errors;
 
@@ -55,6 +57,18 @@ pub fn get_network_details(args: &ClientArgs) -> Result<NymNetworkDetails, Conte
     Ok(NymNetworkDetails::new_from_env())
 }
 
+// all the validators of the network, so that the client could fail over between them
+fn nyxd_endpoints(network_details: &NymNetworkDetails) -> Result<EndpointPool, ContextError> {
+    let urls = network_details
+        .endpoints
+        .iter()
+        .map(|details| details.nyxd_url.parse::<Url>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ValidatorClientError::from)?;
+
+    Ok(EndpointPool::new(urls)?)
+}
+
 pub fn create_signing_client(
     args: ClientArgs,
     network_details: &NymNetworkDetails,
@@ -72,14 +86,9 @@ pub fn create_signing_client(
         },
     };
 
-    let nyxd_url = network_details
-        .endpoints
-        .first()
-        .expect("network details are not defined")
-        .nyxd_url
-        .as_str();
+    let endpoints = nyxd_endpoints(network_details)?;
 
-    match NyxdClient::connect_with_mnemonic(client_config, nyxd_url, mnemonic, None) {
+    match NyxdClient::connect_pooled_with_mnemonic(client_config, endpoints, mnemonic, None) {
         Ok(client) => Ok(client),
         Err(e) => Err(ContextError::NyxdError(format!("{e}"))),
     }
@@ -91,14 +100,9 @@ pub fn create_query_client(
     let client_config = nyxd::Config::try_from_nym_network_details(network_details)
         .tap_err(|err| log::error!("Failed to get client config - {err}"))?;
 
-    let nyxd_url = network_details
-        .endpoints
-        .first()
-        .expect("network details are not defined")
-        .nyxd_url
-        .as_str();
+    let endpoints = nyxd_endpoints(network_details)?;
 
-    match NyxdClient::connect(client_config, nyxd_url) {
+    match NyxdClient::connect_pooled(client_config, endpoints) {
         Ok(client) => Ok(client),
         Err(e) => Err(ContextError::NyxdError(format!("{e}"))),
     }
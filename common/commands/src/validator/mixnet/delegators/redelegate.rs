@@ -0,0 +1,61 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::nyxd::traits::{MixnetQueryClient, MixnetSigningClient};
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Id of the mixnode the stake is currently delegated to
+    #[clap(long)]
+    pub from_mix_id: Option<MixId>,
+
+    /// Identity of the mixnode the stake is currently delegated to
+    #[clap(long)]
+    pub from_identity_key: Option<String>,
+
+    /// Id of the mixnode the stake should be moved to
+    #[clap(long)]
+    pub to_mix_id: Option<MixId>,
+
+    /// Identity of the mixnode the stake should be moved to
+    #[clap(long)]
+    pub to_identity_key: Option<String>,
+}
+
+pub(crate) async fn resolve_mix_id(
+    client: &SigningClient,
+    mix_id: Option<MixId>,
+    identity_key: Option<String>,
+) -> MixId {
+    match mix_id {
+        Some(mix_id) => mix_id,
+        None => {
+            let identity_key =
+                identity_key.expect("either mix_id or mix_identity has to be specified");
+            let node_details = client
+                .get_mixnode_details_by_identity(identity_key)
+                .await
+                .expect("contract query failed")
+                .expect("mixnode with the specified identity doesnt exist");
+            node_details.mix_id()
+        }
+    }
+}
+
+pub async fn redelegate(args: Args, client: SigningClient) {
+    info!("moving stake between mix-nodes");
+
+    let from_mix_id = resolve_mix_id(&client, args.from_mix_id, args.from_identity_key).await;
+    let to_mix_id = resolve_mix_id(&client, args.to_mix_id, args.to_identity_key).await;
+
+    let res = client
+        .redelegate(from_mix_id, to_mix_id, None)
+        .await
+        .expect("failed to move stake between mixnodes!");
+
+    info!("moving stake between mixnodes: {:?}", res)
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::nyxd::VestingSigningClient;
+
+use super::redelegate::resolve_mix_id;
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Id of the mixnode the stake is currently delegated to
+    #[clap(long)]
+    pub from_mix_id: Option<MixId>,
+
+    /// Identity of the mixnode the stake is currently delegated to
+    #[clap(long)]
+    pub from_identity_key: Option<String>,
+
+    /// Id of the mixnode the stake should be moved to
+    #[clap(long)]
+    pub to_mix_id: Option<MixId>,
+
+    /// Identity of the mixnode the stake should be moved to
+    #[clap(long)]
+    pub to_identity_key: Option<String>,
+
+    #[clap(long)]
+    pub on_behalf_of: Option<String>,
+}
+
+pub async fn vesting_redelegate(args: Args, client: SigningClient) {
+    info!("moving vesting stake between mix-nodes");
+
+    let from_mix_id = resolve_mix_id(&client, args.from_mix_id, args.from_identity_key).await;
+    let to_mix_id = resolve_mix_id(&client, args.to_mix_id, args.to_identity_key).await;
+
+    let res = client
+        .vesting_redelegate(from_mix_id, to_mix_id, args.on_behalf_of, None)
+        .await
+        .expect("failed to move stake of vesting account between mixnodes!");
+
+    info!("moving vesting stake between mixnodes: {:?}", res)
+}
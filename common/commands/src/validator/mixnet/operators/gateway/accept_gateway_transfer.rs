@@ -0,0 +1,31 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_contracts_common::signing::MessageSignature;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Current identity key of the gateway that is being transferred
+    #[clap(long)]
+    pub identity: String,
+
+    /// Signature of the transfer payload made with the new identity key of the gateway
+    #[clap(long)]
+    pub signature: MessageSignature,
+}
+
+pub async fn accept_gateway_transfer(args: Args, client: SigningClient) {
+    info!("Accepting transfer of gateway {}", args.identity);
+
+    let res = client
+        .accept_gateway_transfer(args.identity, args.signature, None)
+        .await
+        .expect("failed to accept gateway transfer!");
+
+    info!("Transfer result: {:?}", res)
+}
@@ -0,0 +1,55 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use crate::utils::{account_id_to_cw_addr, DataWrapper};
+use clap::Parser;
+use nym_bin_common::output_format::OutputFormat;
+use nym_mixnet_contract_common::construct_gateway_transfer_sign_payload;
+use nym_validator_client::nyxd::traits::MixnetQueryClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Current identity key of the gateway that is being transferred
+    #[clap(long)]
+    pub identity: String,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+// note: this has to be run by the NEW owner of the gateway
+pub async fn create_payload(args: Args, client: SigningClient) {
+    let proposal = match client
+        .get_pending_gateway_transfer(args.identity.clone())
+        .await
+    {
+        Ok(res) => match res.proposal {
+            Some(proposal) => proposal,
+            None => {
+                eprintln!("there is no pending transfer of gateway {}", args.identity);
+                return;
+            }
+        },
+        Err(err) => {
+            eprintln!("failed to query for the pending gateway transfer: {err}");
+            return;
+        }
+    };
+
+    let nonce = match client.get_signing_nonce(client.address()).await {
+        Ok(nonce) => nonce,
+        Err(err) => {
+            eprintln!(
+                "failed to query for the signing nonce of {}: {err}",
+                client.address()
+            );
+            return;
+        }
+    };
+
+    let address = account_id_to_cw_addr(client.address());
+    let payload = construct_gateway_transfer_sign_payload(nonce, address, args.identity, proposal);
+    let wrapper = DataWrapper::new(payload.to_base58_string().unwrap());
+    println!("{}", args.output.format(&wrapper))
+}
@@ -3,8 +3,12 @@
 
 use clap::{Args, Subcommand};
 
+pub mod accept_gateway_transfer;
 pub mod bond_gateway;
+pub mod cancel_gateway_transfer;
 pub mod gateway_bonding_sign_payload;
+pub mod gateway_transfer_sign_payload;
+pub mod propose_gateway_transfer;
 pub mod settings;
 pub mod unbond_gateway;
 pub mod vesting_bond_gateway;
@@ -31,4 +35,12 @@ pub enum MixnetOperatorsGatewayCommands {
     VestingUnbond(vesting_unbond_gateway::Args),
     /// Create base58-encoded payload required for producing valid bonding signature.
     CreateGatewayBondingSignPayload(gateway_bonding_sign_payload::Args),
+    /// Propose transferring your gateway to a new owner and/or new identity
+    ProposeTransfer(propose_gateway_transfer::Args),
+    /// Accept a gateway transfer proposed to you
+    AcceptTransfer(accept_gateway_transfer::Args),
+    /// Cancel the pending transfer of your gateway
+    CancelTransfer(cancel_gateway_transfer::Args),
+    /// Create base58-encoded payload required for producing valid transfer signature.
+    CreateGatewayTransferSignPayload(gateway_transfer_sign_payload::Args),
 }
@@ -0,0 +1,35 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use cosmrs::AccountId;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Address of the account that is going to own the gateway once the transfer is accepted
+    #[clap(long)]
+    pub new_owner: AccountId,
+
+    /// New identity key of the gateway. If not provided, the current one is going to be kept
+    #[clap(long)]
+    pub identity_key: Option<String>,
+
+    /// New sphinx key of the gateway. If not provided, the current one is going to be kept
+    #[clap(long)]
+    pub sphinx_key: Option<String>,
+}
+
+pub async fn propose_gateway_transfer(args: Args, client: SigningClient) {
+    info!("Proposing gateway transfer to {}", args.new_owner);
+
+    let res = client
+        .propose_gateway_transfer(args.new_owner, args.identity_key, args.sphinx_key, None)
+        .await
+        .expect("failed to propose gateway transfer!");
+
+    info!("Transfer proposal result: {:?}", res)
+}
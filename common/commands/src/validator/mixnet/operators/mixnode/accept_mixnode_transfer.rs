@@ -0,0 +1,31 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_contracts_common::signing::MessageSignature;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub mix_id: MixId,
+
+    /// Signature of the transfer payload made with the new identity key of the mixnode
+    #[clap(long)]
+    pub signature: MessageSignature,
+}
+
+pub async fn accept_mixnode_transfer(args: Args, client: SigningClient) {
+    info!("Accepting transfer of mixnode {}", args.mix_id);
+
+    let res = client
+        .accept_mixnode_transfer(args.mix_id, args.signature, None)
+        .await
+        .expect("failed to accept mixnode transfer!");
+
+    info!("Transfer result: {:?}", res)
+}
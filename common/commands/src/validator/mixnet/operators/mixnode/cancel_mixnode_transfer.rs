@@ -0,0 +1,22 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+pub async fn cancel_mixnode_transfer(_args: Args, client: SigningClient) {
+    info!("Cancelling mixnode transfer");
+
+    let res = client
+        .cancel_mixnode_transfer(None)
+        .await
+        .expect("failed to cancel mixnode transfer!");
+
+    info!("Transfer cancellation result: {:?}", res)
+}
@@ -0,0 +1,51 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use crate::utils::{account_id_to_cw_addr, DataWrapper};
+use clap::Parser;
+use nym_bin_common::output_format::OutputFormat;
+use nym_mixnet_contract_common::{construct_mixnode_transfer_sign_payload, MixId};
+use nym_validator_client::nyxd::traits::MixnetQueryClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub mix_id: MixId,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+// note: this has to be run by the NEW owner of the mixnode
+pub async fn create_payload(args: Args, client: SigningClient) {
+    let proposal = match client.get_pending_mixnode_transfer(args.mix_id).await {
+        Ok(res) => match res.proposal {
+            Some(proposal) => proposal,
+            None => {
+                eprintln!("there is no pending transfer of mixnode {}", args.mix_id);
+                return;
+            }
+        },
+        Err(err) => {
+            eprintln!("failed to query for the pending mixnode transfer: {err}");
+            return;
+        }
+    };
+
+    let nonce = match client.get_signing_nonce(client.address()).await {
+        Ok(nonce) => nonce,
+        Err(err) => {
+            eprintln!(
+                "failed to query for the signing nonce of {}: {err}",
+                client.address()
+            );
+            return;
+        }
+    };
+
+    let address = account_id_to_cw_addr(client.address());
+    let payload = construct_mixnode_transfer_sign_payload(nonce, address, args.mix_id, proposal);
+    let wrapper = DataWrapper::new(payload.to_base58_string().unwrap());
+    println!("{}", args.output.format(&wrapper))
+}
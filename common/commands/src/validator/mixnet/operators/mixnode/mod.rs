@@ -3,12 +3,16 @@
 
 use clap::{Args, Subcommand};
 
+pub mod accept_mixnode_transfer;
 pub mod bond_mixnode;
+pub mod cancel_mixnode_transfer;
 pub mod decrease_pledge;
 pub mod families;
 pub mod keys;
 pub mod mixnode_bonding_sign_payload;
+pub mod mixnode_transfer_sign_payload;
 pub mod pledge_more;
+pub mod propose_mixnode_transfer;
 pub mod rewards;
 pub mod settings;
 pub mod unbond_mixnode;
@@ -52,4 +56,12 @@ pub enum MixnetOperatorsMixnodeCommands {
     DecreasePledge(decrease_pledge::Args),
     /// Decrease pledge with locked tokens
     DecreasePledgeVesting(vesting_decrease_pledge::Args),
+    /// Propose transferring your mixnode to a new owner and/or new identity
+    ProposeTransfer(propose_mixnode_transfer::Args),
+    /// Accept a mixnode transfer proposed to you
+    AcceptTransfer(accept_mixnode_transfer::Args),
+    /// Cancel the pending transfer of your mixnode
+    CancelTransfer(cancel_mixnode_transfer::Args),
+    /// Create base58-encoded payload required for producing valid transfer signature.
+    CreateMixnodeTransferSignPayload(mixnode_transfer_sign_payload::Args),
 }
@@ -0,0 +1,36 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::{Coin, NymName};
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+
+    /// Deposit to be made to the name service, in curent DENOMINATION (e.g. 'unym'). The deposit
+    /// of the previous owner is returned to them.
+    #[clap(long)]
+    pub deposit: u128,
+}
+
+pub async fn accept_transfer(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!("Accepting transfer of name alias '{}'", args.name);
+
+    let name = NymName::new(&args.name).expect("invalid name");
+
+    let denom = client.current_chain_details().mix_denom.base.as_str();
+    let deposit = Coin::new(args.deposit, denom);
+
+    let res = client
+        .accept_name_transfer(name, deposit.into(), None)
+        .await
+        .tap_err(|err| error!("Failed to accept name transfer: {err:#?}"))?;
+
+    info!("Accepted name transfer: {res:?}");
+    Ok(())
+}
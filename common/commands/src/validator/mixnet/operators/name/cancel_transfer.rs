@@ -0,0 +1,28 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::NymName;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+}
+
+pub async fn cancel_transfer(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!("Cancelling transfer of name alias '{}'", args.name);
+
+    let name = NymName::new(&args.name).expect("invalid name");
+
+    let res = client
+        .cancel_name_transfer(name, None)
+        .await
+        .tap_err(|err| error!("Failed to cancel name transfer: {err:#?}"))?;
+
+    info!("Cancelled name transfer: {res:?}");
+    Ok(())
+}
@@ -1,7 +1,14 @@
 use clap::{Args, Subcommand};
 
+pub mod accept_transfer;
+pub mod cancel_transfer;
 pub mod delete;
 pub mod register;
+pub mod register_sign_payload;
+pub mod renew;
+pub mod transfer;
+pub mod update_address;
+pub mod update_address_sign_payload;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -16,4 +23,18 @@ pub enum MixnetOperatorsNameCommands {
     Register(register::Args),
     /// Delete name alias for a nym address
     Delete(delete::Args),
+    /// Renew the registration of a name alias
+    Renew(renew::Args),
+    /// Point a name alias to a different nym address
+    UpdateAddress(update_address::Args),
+    /// Propose transferring a name alias to another account
+    Transfer(transfer::Args),
+    /// Accept a name alias transferred to you
+    AcceptTransfer(accept_transfer::Args),
+    /// Cancel a pending transfer of a name alias
+    CancelTransfer(cancel_transfer::Args),
+    /// Create base58-encoded payload required for producing valid register signature.
+    CreateNameRegisterSignPayload(register_sign_payload::Args),
+    /// Create base58-encoded payload required for producing valid update address signature.
+    CreateNameUpdateAddressSignPayload(update_address_sign_payload::Args),
 }
@@ -1,5 +1,6 @@
 use clap::Parser;
 use log::{error, info};
+use nym_contracts_common::signing::MessageSignature;
 use nym_name_service_common::{Address, Coin, NymName};
 use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
 use tap::TapFallible;
@@ -16,6 +17,11 @@ pub struct Args {
     #[clap(long)]
     pub nym_address: String,
 
+    /// Signature of the register payload, produced with the identity key of the client the nym
+    /// address belongs to
+    #[clap(long)]
+    pub signature: MessageSignature,
+
     /// Deposit to be made to the service provider directory, in curent DENOMINATION (e.g. 'unym')
     #[clap(long)]
     pub deposit: u128,
@@ -34,7 +40,7 @@ pub async fn register(args: Args, client: SigningClient) -> Result<(), NyxdError
     let deposit = Coin::new(args.deposit, denom);
 
     let res = client
-        .register_name(name, address, deposit.into(), None)
+        .register_name(name, address, args.signature, deposit.into(), None)
         .await
         .tap_err(|err| error!("Failed to register name: {err:#?}"))?;
 
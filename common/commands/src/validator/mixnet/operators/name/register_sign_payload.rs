@@ -0,0 +1,65 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    context::SigningClient,
+    utils::{account_id_to_cw_addr, DataWrapper},
+};
+
+use clap::Parser;
+use cosmwasm_std::Coin;
+
+use nym_bin_common::output_format::OutputFormat;
+use nym_name_service_common::{
+    signing_types::construct_name_register_sign_payload, Address, NymName,
+};
+use nym_sphinx::addressing::clients::Recipient;
+use nym_validator_client::nyxd::traits::NameServiceQueryClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+
+    /// Nym address that the alias is pointing to
+    #[clap(long)]
+    pub nym_address: Recipient,
+
+    /// Deposit to be made to the name service, in curent DENOMINATION (e.g. 'unym')
+    #[clap(long)]
+    pub deposit: u128,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+pub async fn create_payload(args: Args, client: SigningClient) {
+    let name = match NymName::new(&args.name) {
+        Ok(name) => name,
+        Err(_) => {
+            eprintln!("'{}' is not a valid name", args.name);
+            return;
+        }
+    };
+    let address = Address::new(&args.nym_address.to_string());
+
+    let denom = client.current_chain_details().mix_denom.base.as_str();
+    let deposit = Coin::new(args.deposit, denom);
+
+    let nonce = match client.get_name_signing_nonce(client.address()).await {
+        Ok(nonce) => nonce,
+        Err(err) => {
+            eprintln!(
+                "failed to query for the signing nonce of {}: {err}",
+                client.address()
+            );
+            return;
+        }
+    };
+
+    let sender = account_id_to_cw_addr(client.address());
+    let payload = construct_name_register_sign_payload(nonce, sender, deposit, name, address);
+    let wrapper = DataWrapper::new(payload.to_base58_string().unwrap());
+    println!("{}", args.output.format(&wrapper))
+}
@@ -0,0 +1,28 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::NymName;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+}
+
+pub async fn renew(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!("Renewing name alias '{}'", args.name);
+
+    let name = NymName::new(&args.name).expect("invalid name");
+
+    let res = client
+        .renew_name(name, None)
+        .await
+        .tap_err(|err| error!("Failed to renew name: {err:#?}"))?;
+
+    info!("Renewed name: {res:?}");
+    Ok(())
+}
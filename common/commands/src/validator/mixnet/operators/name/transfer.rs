@@ -0,0 +1,35 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::NymName;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+
+    /// Account that should become the new owner of the name, once it accepts the transfer
+    #[clap(long)]
+    pub new_owner: String,
+}
+
+pub async fn transfer(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!(
+        "Proposing transfer of name alias '{}' to {}",
+        args.name, args.new_owner
+    );
+
+    let name = NymName::new(&args.name).expect("invalid name");
+
+    let res = client
+        .transfer_name(name, args.new_owner, None)
+        .await
+        .tap_err(|err| error!("Failed to propose name transfer: {err:#?}"))?;
+
+    info!("Proposed name transfer: {res:?}");
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use clap::Parser;
+use log::{error, info};
+use nym_contracts_common::signing::MessageSignature;
+use nym_name_service_common::{Address, NymName};
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Name alias
+    #[clap(long)]
+    pub name: String,
+
+    /// Nym address that the alias should point to from now on
+    #[clap(long)]
+    pub nym_address: String,
+
+    /// Signature of the update payload, produced with the identity key of the client the new nym
+    /// address belongs to
+    #[clap(long)]
+    pub signature: MessageSignature,
+}
+
+pub async fn update_address(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!(
+        "Pointing name alias '{}' to nym address '{}'",
+        args.name, args.nym_address
+    );
+
+    let name = NymName::new(&args.name).expect("invalid name");
+    let address = Address::new(&args.nym_address);
+
+    let res = client
+        .update_name_address(name, address, args.signature, None)
+        .await
+        .tap_err(|err| error!("Failed to update the address of the name: {err:#?}"))?;
+
+    info!("Updated name: {res:?}");
+    Ok(())
+}
@@ -65,6 +65,10 @@ impl SpendCredential {
         &self.blinded_serial_number
     }
 
+    pub fn gateway_cosmos_address(&self) -> &Addr {
+        &self.gateway_cosmos_address
+    }
+
     pub fn status(&self) -> SpendCredentialStatus {
         self.status
     }
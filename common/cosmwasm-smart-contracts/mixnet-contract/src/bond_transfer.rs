@@ -0,0 +1,44 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{IdentityKey, MixId, SphinxKey};
+use cosmwasm_std::Addr;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Details of a proposed transfer of a mixnode or a gateway bond onto a new owner and/or a new set of node keys.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, JsonSchema)]
+pub struct BondTransferProposal {
+    /// Address of the owner of the bond at the time of proposing the transfer.
+    pub current_owner: Addr,
+
+    /// Address of the account that is going to own the bond once the transfer is accepted.
+    pub new_owner: Addr,
+
+    /// Identity key the node is going to use once the transfer is accepted.
+    pub new_identity_key: IdentityKey,
+
+    /// Sphinx key the node is going to use once the transfer is accepted.
+    pub new_sphinx_key: SphinxKey,
+
+    /// Block height at which the transfer has been proposed.
+    pub proposal_height: u64,
+}
+
+impl BondTransferProposal {
+    pub fn changes_owner(&self) -> bool {
+        self.current_owner != self.new_owner
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, JsonSchema)]
+pub struct PendingMixnodeTransferResponse {
+    pub mix_id: MixId,
+    pub proposal: Option<BondTransferProposal>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq, JsonSchema)]
+pub struct PendingGatewayTransferResponse {
+    pub identity: IdentityKey,
+    pub proposal: Option<BondTransferProposal>,
+}
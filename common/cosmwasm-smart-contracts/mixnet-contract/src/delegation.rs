@@ -50,7 +50,8 @@ pub struct Delegation {
     /// Value of the "unit delegation" associated with the mixnode at the time of delegation.
     pub cumulative_reward_ratio: Decimal,
 
-    /// Original delegation amount. Note that it is never mutated as delegation accumulates rewards.
+    /// Original delegation amount. Note that it is never mutated as delegation accumulates rewards,
+    /// unless those rewards get explicitly compounded.
     pub amount: Coin,
 
     /// Block height where this delegation occurred.
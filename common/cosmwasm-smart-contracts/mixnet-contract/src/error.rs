@@ -1,7 +1,7 @@
 // Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EpochEventId, EpochState, IdentityKey, MixId};
+use crate::{EpochEventId, EpochId, EpochState, IdentityKey, MixId};
 use contracts_common::signing::verifier::ApiVerifierError;
 use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 use thiserror::Error;
@@ -138,6 +138,9 @@ pub enum MixnetContractError {
         proxy: Option<String>,
     },
 
+    #[error("Attempted to redelegate stake from mixnode {mix_id} back onto itself")]
+    RedelegationToSameMixnode { mix_id: MixId },
+
     #[error("Provided message to update rewarding params did not contain any updates")]
     EmptyParamsChangeMsg,
 
@@ -242,6 +245,32 @@ pub enum MixnetContractError {
         #[from]
         source: ApiVerifierError,
     },
+
+    #[error("There is no pending transfer of this bond")]
+    NoPendingBondTransfer,
+
+    #[error("The pending bond transfer can only be accepted by {expected}. It was attempted to be accepted by {received} instead")]
+    BondTransferRecipientMismatch { expected: Addr, received: Addr },
+
+    #[error("The proposed bond transfer would change neither the owner nor the keys of the node")]
+    EmptyBondTransfer,
+
+    #[error("Bonds created via the vesting contract can't be transferred")]
+    VestingBondTransfer,
+
+    #[error("Node {identity} belongs to a family and thus its identity can't be changed")]
+    FamilyNodeIdentityChange { identity: String },
+
+    #[error("there is no rewarded set selection seed fixed for epoch {absolute_epoch_id}")]
+    MissingRewardedSetSelectionSeed { absolute_epoch_id: EpochId },
+
+    #[error("the provided rewarded set selection seed ({provided}) from height {provided_height} does not match the one fixed at the beginning of the epoch transition ({expected}) from height {expected_height}")]
+    RewardedSetSelectionSeedMismatch {
+        expected: String,
+        expected_height: u64,
+        provided: String,
+        provided_height: u64,
+    },
 }
 
 impl MixnetContractError {
@@ -1,11 +1,14 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::bond_transfer::BondTransferProposal;
 use crate::gateway::GatewayConfigUpdate;
 use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{IntervalRewardParams, IntervalRewardingParamsUpdate};
-use crate::rewarding::RewardDistribution;
-use crate::{BlockHeight, ContractStateParams, IdentityKeyRef, Interval, Layer, MixId};
+use crate::rewarding::{CompoundingSettings, RewardDistribution};
+use crate::{
+    BlockHeight, ContractStateParams, IdentityKeyRef, Interval, Layer, MixId, RewardedSetSelection,
+};
 pub use contracts_common::events::*;
 use cosmwasm_std::{Addr, Coin, Decimal, Event};
 
@@ -27,6 +30,10 @@ pub enum MixnetEventType {
     MixnodeRewarding,
     WithdrawDelegatorReward,
     WithdrawOperatorReward,
+    DelegationCompoundingUpdate,
+    OperatorCompoundingUpdate,
+    CompoundDelegatorReward,
+    CompoundOperatorReward,
     PendingActiveSetUpdate,
     ActiveSetUpdate,
     PendingIntervalRewardingParamsUpdate,
@@ -36,16 +43,25 @@ pub enum MixnetEventType {
     Delegation,
     DelegationOnUnbonding,
     Undelegation,
+    PendingRedelegation,
+    Redelegation,
     ContractSettingsUpdate,
     RewardingValidatorUpdate,
     BeginEpochTransition,
     AdvanceEpoch,
     ExecutePendingEpochEvents,
     ExecutePendingIntervalEvents,
+    ExecutePendingCompounding,
     ReconcilePendingEvents,
     PendingIntervalConfigUpdate,
     IntervalConfigUpdate,
     GatewayConfigUpdate,
+    MixnodeTransferProposal,
+    MixnodeTransferCancellation,
+    MixnodeTransfer,
+    GatewayTransferProposal,
+    GatewayTransferCancellation,
+    GatewayTransfer,
 }
 
 impl From<MixnetEventType> for String {
@@ -72,6 +88,10 @@ impl ToString for MixnetEventType {
             MixnetEventType::MixnodeRewarding => "mix_rewarding",
             MixnetEventType::WithdrawDelegatorReward => "withdraw_delegator_reward",
             MixnetEventType::WithdrawOperatorReward => "withdraw_operator_reward",
+            MixnetEventType::DelegationCompoundingUpdate => "delegation_compounding_update",
+            MixnetEventType::OperatorCompoundingUpdate => "operator_compounding_update",
+            MixnetEventType::CompoundDelegatorReward => "compound_delegator_reward",
+            MixnetEventType::CompoundOperatorReward => "compound_operator_reward",
             MixnetEventType::PendingActiveSetUpdate => "pending_active_set_update",
             MixnetEventType::ActiveSetUpdate => "active_set_update",
             MixnetEventType::PendingIntervalRewardingParamsUpdate => {
@@ -82,17 +102,26 @@ impl ToString for MixnetEventType {
             MixnetEventType::PendingUndelegation => "pending_undelegation",
             MixnetEventType::Delegation => "delegation",
             MixnetEventType::Undelegation => "undelegation",
+            MixnetEventType::PendingRedelegation => "pending_redelegation",
+            MixnetEventType::Redelegation => "redelegation",
             MixnetEventType::ContractSettingsUpdate => "settings_update",
             MixnetEventType::RewardingValidatorUpdate => "rewarding_validator_address_update",
             MixnetEventType::BeginEpochTransition => "beginning_epoch_transition",
             MixnetEventType::AdvanceEpoch => "advance_epoch",
             MixnetEventType::ExecutePendingEpochEvents => "execute_pending_epoch_events",
             MixnetEventType::ExecutePendingIntervalEvents => "execute_pending_interval_events",
+            MixnetEventType::ExecutePendingCompounding => "execute_pending_compounding",
             MixnetEventType::ReconcilePendingEvents => "reconcile_pending_events",
             MixnetEventType::PendingIntervalConfigUpdate => "pending_interval_config_update",
             MixnetEventType::IntervalConfigUpdate => "interval_config_update",
             MixnetEventType::DelegationOnUnbonding => "delegation_on_unbonding_node",
             MixnetEventType::GatewayConfigUpdate => "gateway_config_update",
+            MixnetEventType::MixnodeTransferProposal => "mixnode_transfer_proposal",
+            MixnetEventType::MixnodeTransferCancellation => "mixnode_transfer_cancellation",
+            MixnetEventType::MixnodeTransfer => "mixnode_transfer",
+            MixnetEventType::GatewayTransferProposal => "gateway_transfer_proposal",
+            MixnetEventType::GatewayTransferCancellation => "gateway_transfer_cancellation",
+            MixnetEventType::GatewayTransfer => "gateway_transfer",
         };
 
         format!("{EVENT_VERSION_PREFIX}{event_name}")
@@ -110,12 +139,17 @@ pub const PROXY_KEY: &str = "proxy";
 pub const DELEGATOR_KEY: &str = "delegator";
 pub const DELEGATION_TARGET_KEY: &str = "delegation_target";
 pub const UNIT_REWARD_KEY: &str = "unit_reward";
+pub const REDELEGATION_SOURCE_KEY: &str = "redelegation_source";
 
 // bonding/unbonding
 pub const MIX_ID_KEY: &str = "mix_id";
 pub const NODE_IDENTITY_KEY: &str = "identity";
 pub const ASSIGNED_LAYER_KEY: &str = "assigned_layer";
 
+// bond transfers
+pub const NEW_OWNER_KEY: &str = "new_owner";
+pub const NEW_NODE_IDENTITY_KEY: &str = "new_identity";
+
 // settings change
 pub const OLD_MINIMUM_MIXNODE_PLEDGE_KEY: &str = "old_minimum_mixnode_pledge";
 pub const OLD_MINIMUM_GATEWAY_PLEDGE_KEY: &str = "old_minimum_gateway_pledge";
@@ -141,6 +175,8 @@ pub const INTERVAL_REWARDING_PARAMS_UPDATE_KEY: &str = "interval_rewarding_param
 pub const UPDATED_INTERVAL_REWARDING_PARAMS_KEY: &str = "updated_interval_rewarding_params";
 pub const PRIOR_DELEGATES_KEY: &str = "prior_delegates";
 pub const PRIOR_UNIT_REWARD_KEY: &str = "prior_unit_reward";
+pub const COMPOUNDING_FREQUENCY_KEY: &str = "compounding_frequency";
+pub const NEXT_COMPOUNDING_EPOCH_KEY: &str = "next_compounding_epoch";
 
 pub const NO_REWARD_REASON_KEY: &str = "no_reward_reason";
 pub const BOND_NOT_FOUND_VALUE: &str = "bond_not_found";
@@ -156,6 +192,10 @@ pub const NEW_CURRENT_EPOCH_KEY: &str = "new_current_epoch";
 pub const EVENTS_EXECUTED_KEY: &str = "number_of_events_executed";
 pub const EVENT_CREATION_HEIGHT_KEY: &str = "created_at";
 pub const REWARDED_SET_NODES_KEY: &str = "rewarded_set_nodes";
+pub const SELECTION_ALGORITHM_VERSION_KEY: &str = "selection_algorithm_version";
+pub const SELECTION_SEED_BLOCK_HEIGHT_KEY: &str = "selection_seed_block_height";
+pub const SELECTION_SEED_KEY: &str = "selection_seed";
+pub const SELECTION_CANDIDATES_COMMITMENT_KEY: &str = "selection_candidates_commitment";
 pub const NEW_EPOCHS_DURATION_SECS_KEY: &str = "new_epoch_durations_secs";
 pub const NEW_EPOCHS_IN_INTERVAL: &str = "new_epochs_in_interval";
 
@@ -226,6 +266,72 @@ pub fn new_withdraw_delegator_reward_event(
         .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
 }
 
+pub fn new_delegation_compounding_update_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    mix_id: MixId,
+    settings: Option<CompoundingSettings>,
+) -> Event {
+    Event::new(MixnetEventType::DelegationCompoundingUpdate)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
+        .add_optional_attribute(
+            COMPOUNDING_FREQUENCY_KEY,
+            settings.map(|s| s.frequency.to_string()),
+        )
+        .add_optional_attribute(
+            NEXT_COMPOUNDING_EPOCH_KEY,
+            settings.map(|s| s.next_compounding_epoch.to_string()),
+        )
+}
+
+pub fn new_operator_compounding_update_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    mix_id: MixId,
+    settings: Option<CompoundingSettings>,
+) -> Event {
+    Event::new(MixnetEventType::OperatorCompoundingUpdate)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_optional_attribute(
+            COMPOUNDING_FREQUENCY_KEY,
+            settings.map(|s| s.frequency.to_string()),
+        )
+        .add_optional_attribute(
+            NEXT_COMPOUNDING_EPOCH_KEY,
+            settings.map(|s| s.next_compounding_epoch.to_string()),
+        )
+}
+
+pub fn new_compound_delegator_reward_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::CompoundDelegatorReward)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
+}
+
+pub fn new_compound_operator_reward_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::CompoundOperatorReward)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+}
+
 pub fn new_active_set_update_event(created_at: BlockHeight, new_size: u32) -> Event {
     Event::new(MixnetEventType::ActiveSetUpdate)
         .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
@@ -301,6 +407,55 @@ pub fn new_pending_undelegation_event(
         .add_attribute(MIX_ID_KEY, mix_id.to_string())
 }
 
+pub fn new_redelegation_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    unit_reward: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::Redelegation)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+        .add_attribute(UNIT_REWARD_KEY, unit_reward.to_string())
+}
+
+pub fn new_redelegation_on_unbonded_node_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: &Coin,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::DelegationOnUnbonding)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+}
+
+pub fn new_pending_redelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::PendingRedelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+}
+
 pub fn new_gateway_bonding_event(
     owner: &Addr,
     proxy: &Option<Addr>,
@@ -345,6 +500,69 @@ pub fn new_mixnode_bonding_event(
         .add_attribute(AMOUNT_KEY, amount.to_string())
 }
 
+pub fn new_mixnode_transfer_proposal_event(
+    mix_id: MixId,
+    owner: &Addr,
+    proposal: &BondTransferProposal,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeTransferProposal)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, &proposal.new_owner)
+        .add_attribute(NEW_NODE_IDENTITY_KEY, &proposal.new_identity_key)
+}
+
+pub fn new_mixnode_transfer_cancellation_event(mix_id: MixId, owner: &Addr) -> Event {
+    Event::new(MixnetEventType::MixnodeTransferCancellation)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(OWNER_KEY, owner)
+}
+
+pub fn new_mixnode_transfer_event(
+    mix_id: MixId,
+    previous_identity: IdentityKeyRef<'_>,
+    proposal: &BondTransferProposal,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeTransfer)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(OWNER_KEY, &proposal.current_owner)
+        .add_attribute(NODE_IDENTITY_KEY, previous_identity)
+        .add_attribute(NEW_OWNER_KEY, &proposal.new_owner)
+        .add_attribute(NEW_NODE_IDENTITY_KEY, &proposal.new_identity_key)
+}
+
+pub fn new_gateway_transfer_proposal_event(
+    identity: IdentityKeyRef<'_>,
+    owner: &Addr,
+    proposal: &BondTransferProposal,
+) -> Event {
+    Event::new(MixnetEventType::GatewayTransferProposal)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, &proposal.new_owner)
+        .add_attribute(NEW_NODE_IDENTITY_KEY, &proposal.new_identity_key)
+}
+
+pub fn new_gateway_transfer_cancellation_event(
+    identity: IdentityKeyRef<'_>,
+    owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::GatewayTransferCancellation)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(OWNER_KEY, owner)
+}
+
+pub fn new_gateway_transfer_event(
+    previous_identity: IdentityKeyRef<'_>,
+    proposal: &BondTransferProposal,
+) -> Event {
+    Event::new(MixnetEventType::GatewayTransfer)
+        .add_attribute(OWNER_KEY, &proposal.current_owner)
+        .add_attribute(NODE_IDENTITY_KEY, previous_identity)
+        .add_attribute(NEW_OWNER_KEY, &proposal.new_owner)
+        .add_attribute(NEW_NODE_IDENTITY_KEY, &proposal.new_identity_key)
+}
+
 pub fn new_pending_pledge_increase_event(mix_id: MixId, amount: &Coin) -> Event {
     Event::new(MixnetEventType::PendingPledgeIncrease)
         .add_attribute(MIX_ID_KEY, mix_id.to_string())
@@ -542,13 +760,36 @@ pub fn new_epoch_transition_start_event(current_interval: Interval) -> Event {
     )
 }
 
-pub fn new_advance_epoch_event(interval: Interval, rewarded_nodes: u32) -> Event {
-    Event::new(MixnetEventType::AdvanceEpoch)
+pub fn new_advance_epoch_event(
+    interval: Interval,
+    rewarded_nodes: u32,
+    selection: Option<&RewardedSetSelection>,
+) -> Event {
+    let event = Event::new(MixnetEventType::AdvanceEpoch)
         .add_attribute(
             NEW_CURRENT_EPOCH_KEY,
             interval.current_epoch_absolute_id().to_string(),
         )
-        .add_attribute(REWARDED_SET_NODES_KEY, rewarded_nodes.to_string())
+        .add_attribute(REWARDED_SET_NODES_KEY, rewarded_nodes.to_string());
+
+    if let Some(selection) = selection {
+        event
+            .add_attribute(
+                SELECTION_ALGORITHM_VERSION_KEY,
+                selection.algorithm_version.to_string(),
+            )
+            .add_attribute(
+                SELECTION_SEED_BLOCK_HEIGHT_KEY,
+                selection.seed_block_height.to_string(),
+            )
+            .add_attribute(SELECTION_SEED_KEY, &selection.seed)
+            .add_attribute(
+                SELECTION_CANDIDATES_COMMITMENT_KEY,
+                &selection.candidates_commitment,
+            )
+    } else {
+        event
+    }
 }
 
 pub fn new_pending_epoch_events_execution_event(executed: u32) -> Event {
@@ -561,6 +802,11 @@ pub fn new_pending_interval_events_execution_event(executed: u32) -> Event {
         .add_attribute(EVENTS_EXECUTED_KEY, executed.to_string())
 }
 
+pub fn new_pending_compounding_execution_event(executed: u32) -> Event {
+    Event::new(MixnetEventType::ExecutePendingCompounding)
+        .add_attribute(EVENTS_EXECUTED_KEY, executed.to_string())
+}
+
 pub fn new_reconcile_pending_events() -> Event {
     Event::new(MixnetEventType::ReconcilePendingEvents)
 }
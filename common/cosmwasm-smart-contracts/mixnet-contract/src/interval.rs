@@ -12,6 +12,7 @@ use schemars::gen::SchemaGenerator;
 use schemars::schema::{InstanceType, Schema, SchemaObject};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fmt::{Display, Formatter};
 use std::time::Duration;
 use time::OffsetDateTime;
@@ -489,6 +490,94 @@ impl CurrentIntervalResponse {
     }
 }
 
+/// Public information allowing anyone to recompute the rewarded set selection and thus verify
+/// it has been performed fairly.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RewardedSetSelection {
+    /// Version of the deterministic selection algorithm that has been used.
+    pub algorithm_version: u32,
+
+    /// Height of the block at which the epoch transition has begun and the selection seed got fixed.
+    pub seed_block_height: u64,
+
+    /// Hex-encoded seed used for the selection.
+    pub seed: String,
+
+    /// Hex-encoded hash of all the selection candidates alongside their weights.
+    pub candidates_commitment: String,
+}
+
+/// Seed for the rewarded set selection of the upcoming epoch. It is derived by the contract
+/// at the beginning of the epoch transition so that the rewarding validator could not influence it.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RewardedSetSelectionSeed {
+    /// Absolute id of the epoch for which the rewarded set is going to be selected.
+    pub absolute_epoch_id: EpochId,
+
+    /// Height of the block at which the epoch transition has begun.
+    pub block_height: u64,
+
+    /// Hex-encoded seed to be used for the selection.
+    pub seed: String,
+}
+
+impl RewardedSetSelectionSeed {
+    pub const DOMAIN: &'static [u8] = b"NYM_REWARDED_SET_SELECTION_V1";
+
+    pub fn new(
+        chain_id: &str,
+        block_height: u64,
+        block_time_nanos: u64,
+        absolute_epoch_id: EpochId,
+    ) -> Self {
+        let seed = Self::derive(chain_id, block_height, block_time_nanos, absolute_epoch_id);
+
+        RewardedSetSelectionSeed {
+            absolute_epoch_id,
+            block_height,
+            seed: seed.iter().map(|b| format!("{b:02x}")).collect(),
+        }
+    }
+
+    /// Derives the selection seed as
+    /// `sha256(DOMAIN || chain_id || block_height || block_time_nanos || absolute_epoch_id)`,
+    /// with all the integers being encoded as big endian.
+    pub fn derive(
+        chain_id: &str,
+        block_height: u64,
+        block_time_nanos: u64,
+        absolute_epoch_id: EpochId,
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(Self::DOMAIN);
+        hasher.update(chain_id.as_bytes());
+        hasher.update(block_height.to_be_bytes());
+        hasher.update(block_time_nanos.to_be_bytes());
+        hasher.update(absolute_epoch_id.to_be_bytes());
+        hasher.finalize().into()
+    }
+
+    pub fn matches(&self, selection: &RewardedSetSelection) -> bool {
+        self.block_height == selection.seed_block_height && self.seed == selection.seed
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RewardedSetSelectionSeedResponse {
+    /// Seed fixed during the most recent epoch transition.
+    pub seed: Option<RewardedSetSelectionSeed>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct RewardedSetSelectionResponse {
+    /// Absolute id of the epoch for which the current rewarded set has been selected.
+    pub epoch_id: EpochId,
+
+    /// Information about the selection of the current rewarded set,
+    /// if it has been performed using a verifiable algorithm.
+    pub selection: Option<RewardedSetSelection>,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct PendingEpochEventsResponse {
     pub seconds_until_executable: i64,
@@ -559,13 +648,18 @@ impl PendingIntervalEventResponse {
 pub struct NumberOfPendingEventsResponse {
     pub epoch_events: u32,
     pub interval_events: u32,
+
+    /// Number of reward compoundings that are due to be performed at the end of the current epoch.
+    #[serde(default)]
+    pub due_compoundings: u32,
 }
 
 impl NumberOfPendingEventsResponse {
-    pub fn new(epoch_events: u32, interval_events: u32) -> Self {
+    pub fn new(epoch_events: u32, interval_events: u32, due_compoundings: u32) -> Self {
         Self {
             epoch_events,
             interval_events,
+            due_compoundings,
         }
     }
 }
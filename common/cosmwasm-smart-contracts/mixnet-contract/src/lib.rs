@@ -4,6 +4,7 @@
 #![warn(clippy::expect_used)]
 #![warn(clippy::unwrap_used)]
 
+pub mod bond_transfer;
 mod constants;
 pub mod delegation;
 pub mod error;
@@ -33,7 +34,8 @@ pub use gateway::{
 pub use interval::{
     CurrentIntervalResponse, EpochState, EpochStatus, Interval, NumberOfPendingEventsResponse,
     PendingEpochEventResponse, PendingEpochEventsResponse, PendingIntervalEventResponse,
-    PendingIntervalEventsResponse,
+    PendingIntervalEventsResponse, RewardedSetSelection, RewardedSetSelectionResponse,
+    RewardedSetSelectionSeed, RewardedSetSelectionSeedResponse,
 };
 pub use mixnode::{
     Layer, MixNode, MixNodeBond, MixNodeConfigUpdate, MixNodeCostParams, MixNodeDetails,
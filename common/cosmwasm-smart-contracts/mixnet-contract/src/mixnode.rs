@@ -197,6 +197,30 @@ impl MixNodeRewarding {
         Ok(truncate_reward(reward, &delegation.amount.denom))
     }
 
+    /// Converts the reward accrued by the operator into its pledge.
+    /// It returns the compounded amount so that the original pledge could be adjusted accordingly.
+    pub fn compound_operator_reward(
+        &mut self,
+        original_pledge: &Coin,
+    ) -> Result<Coin, MixnetContractError> {
+        // exactly the same truncation happens as if the reward was withdrawn
+        let reward = self.withdraw_operator_reward(original_pledge)?;
+        self.increase_operator_uint128(reward.amount)?;
+        Ok(reward)
+    }
+
+    /// Converts the reward accrued by the delegation into its principal.
+    pub fn compound_delegator_reward(
+        &mut self,
+        delegation: &mut Delegation,
+    ) -> Result<Coin, MixnetContractError> {
+        // exactly the same truncation happens as if the reward was withdrawn
+        let reward = self.withdraw_delegator_reward(delegation)?;
+        self.increase_delegates_uint128(reward.amount)?;
+        delegation.amount.amount += reward.amount;
+        Ok(reward)
+    }
+
     pub fn node_bond(&self) -> Decimal {
         self.operator + self.delegates
     }
@@ -10,11 +10,12 @@ use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{
     IntervalRewardParams, IntervalRewardingParamsUpdate, Performance, RewardingParams,
 };
+use crate::rewarding::CompoundingFrequency;
 use crate::{
     delegation, ContractStateParams, EpochEventId, IntervalEventId, Layer, LayerAssignment, MixId,
-    Percent,
+    Percent, RewardedSetSelection,
 };
-use crate::{Gateway, IdentityKey, MixNode};
+use crate::{Gateway, IdentityKey, MixNode, SphinxKey};
 use contracts_common::signing::MessageSignature;
 use cosmwasm_std::{Coin, Decimal};
 use schemars::JsonSchema;
@@ -143,6 +144,9 @@ pub enum ExecuteMsg {
         new_rewarded_set: Vec<LayerAssignment>,
         // families_in_layer: HashMap<String, Layer>,
         expected_active_set_size: u32,
+        /// Information allowing anyone to verify the selection of the new rewarded set.
+        #[serde(default)]
+        selection: Option<RewardedSetSelection>,
     },
     ReconcileEpochEvents {
         limit: Option<u32>,
@@ -189,6 +193,20 @@ pub enum ExecuteMsg {
         new_config: MixNodeConfigUpdate,
         owner: String,
     },
+    /// Proposes transferring the mixnode bond onto a new owner and/or a new set of node keys.
+    /// If the keys are not provided, the existing ones are going to be retained.
+    ProposeMixnodeTransfer {
+        new_owner: String,
+        new_identity_key: Option<IdentityKey>,
+        new_sphinx_key: Option<SphinxKey>,
+    },
+    /// Accepts the pending mixnode transfer. It has to be sent by the new owner
+    /// and signed with the new identity key of the node.
+    AcceptMixnodeTransfer {
+        mix_id: MixId,
+        identity_signature: MessageSignature,
+    },
+    CancelMixnodeTransfer {},
 
     // gateway-related:
     BondGateway {
@@ -211,6 +229,20 @@ pub enum ExecuteMsg {
         new_config: GatewayConfigUpdate,
         owner: String,
     },
+    /// Proposes transferring the gateway bond onto a new owner and/or a new set of node keys.
+    /// If the keys are not provided, the existing ones are going to be retained.
+    ProposeGatewayTransfer {
+        new_owner: String,
+        new_identity_key: Option<IdentityKey>,
+        new_sphinx_key: Option<SphinxKey>,
+    },
+    /// Accepts the pending gateway transfer. It has to be sent by the new owner
+    /// and signed with the new identity key of the gateway.
+    AcceptGatewayTransfer {
+        identity: IdentityKey,
+        identity_signature: MessageSignature,
+    },
+    CancelGatewayTransfer {},
 
     // delegation-related:
     DelegateToMixnode {
@@ -227,6 +259,15 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         delegate: String,
     },
+    Redelegate {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+    },
+    RedelegateOnBehalf {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        delegate: String,
+    },
 
     // reward-related
     RewardMixnode {
@@ -244,6 +285,26 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         owner: String,
     },
+    /// Opts into (or out of, if `frequency` is not set) having the delegator rewards automatically
+    /// compounded into the delegation.
+    UpdateDelegationCompounding {
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+    },
+    UpdateDelegationCompoundingOnBehalf {
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        owner: String,
+    },
+    /// Opts into (or out of, if `frequency` is not set) having the operator rewards automatically
+    /// compounded into the pledge.
+    UpdateOperatorCompounding {
+        frequency: Option<CompoundingFrequency>,
+    },
+    UpdateOperatorCompoundingOnBehalf {
+        frequency: Option<CompoundingFrequency>,
+        owner: String,
+    },
 
     // testing-only
     #[cfg(feature = "contract-testing")]
@@ -321,6 +382,13 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateMixnodeConfigOnBehalf { .. } => {
                 "updating mixnode configuration on behalf".into()
             }
+            ExecuteMsg::ProposeMixnodeTransfer { new_owner, .. } => {
+                format!("proposing mixnode transfer to {new_owner}")
+            }
+            ExecuteMsg::AcceptMixnodeTransfer { mix_id, .. } => {
+                format!("accepting transfer of mixnode {mix_id}")
+            }
+            ExecuteMsg::CancelMixnodeTransfer { .. } => "cancelling mixnode transfer".into(),
             ExecuteMsg::BondGateway { gateway, .. } => {
                 format!("bonding gateway {}", gateway.identity_key)
             }
@@ -333,6 +401,13 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateGatewayConfigOnBehalf { .. } => {
                 "updating gateway configuration on behalf".into()
             }
+            ExecuteMsg::ProposeGatewayTransfer { new_owner, .. } => {
+                format!("proposing gateway transfer to {new_owner}")
+            }
+            ExecuteMsg::AcceptGatewayTransfer { identity, .. } => {
+                format!("accepting transfer of gateway {identity}")
+            }
+            ExecuteMsg::CancelGatewayTransfer { .. } => "cancelling gateway transfer".into(),
             ExecuteMsg::DelegateToMixnode { mix_id } => format!("delegating to mixnode {mix_id}"),
             ExecuteMsg::DelegateToMixnodeOnBehalf { mix_id, .. } => {
                 format!("delegating to mixnode {mix_id} on behalf")
@@ -343,6 +418,17 @@ impl ExecuteMsg {
             ExecuteMsg::UndelegateFromMixnodeOnBehalf { mix_id, .. } => {
                 format!("removing delegation from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::Redelegate {
+                from_mix_id,
+                to_mix_id,
+            } => format!("redelegating from mixnode {from_mix_id} to mixnode {to_mix_id}"),
+            ExecuteMsg::RedelegateOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                ..
+            } => {
+                format!("redelegating from mixnode {from_mix_id} to mixnode {to_mix_id} on behalf")
+            }
             ExecuteMsg::RewardMixnode {
                 mix_id,
                 performance,
@@ -357,6 +443,20 @@ impl ExecuteMsg {
             ExecuteMsg::WithdrawDelegatorRewardOnBehalf { mix_id, .. } => {
                 format!("withdrawing delegator reward from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::UpdateDelegationCompounding { mix_id, .. } => {
+                format!("updating reward compounding of delegation towards mixnode {mix_id}")
+            }
+            ExecuteMsg::UpdateDelegationCompoundingOnBehalf { mix_id, .. } => {
+                format!(
+                    "updating reward compounding of delegation towards mixnode {mix_id} on behalf"
+                )
+            }
+            ExecuteMsg::UpdateOperatorCompounding { .. } => {
+                "updating operator reward compounding".into()
+            }
+            ExecuteMsg::UpdateOperatorCompoundingOnBehalf { .. } => {
+                "updating operator reward compounding on behalf".into()
+            }
             #[cfg(feature = "contract-testing")]
             ExecuteMsg::TestingResolveAllPendingEvents { .. } => {
                 "resolving all pending events".into()
@@ -403,6 +503,8 @@ pub enum QueryMsg {
         limit: Option<u32>,
         start_after: Option<MixId>,
     },
+    GetRewardedSetSelection {},
+    GetRewardedSetSelectionSeed {},
 
     // mixnode-related:
     GetMixNodeBonds {
@@ -446,6 +548,9 @@ pub enum QueryMsg {
         mix_identity: IdentityKey,
     },
     GetLayerDistribution {},
+    GetPendingMixnodeTransfer {
+        mix_id: MixId,
+    },
     // gateway-related:
     GetGateways {
         start_after: Option<IdentityKey>,
@@ -457,6 +562,9 @@ pub enum QueryMsg {
     GetOwnedGateway {
         address: String,
     },
+    GetPendingGatewayTransfer {
+        identity: IdentityKey,
+    },
 
     // delegation-related:
     // gets all [paged] delegations associated with particular mixnode
@@ -510,6 +618,14 @@ pub enum QueryMsg {
         proxy: Option<String>,
         estimated_performance: Performance,
     },
+    GetDelegationCompounding {
+        address: String,
+        mix_id: MixId,
+        proxy: Option<String>,
+    },
+    GetOperatorCompounding {
+        mix_id: MixId,
+    },
 
     // interval-related
     GetPendingEpochEvents {
@@ -34,6 +34,14 @@ pub enum PendingEpochEventKind {
         mix_id: MixId,
         proxy: Option<Addr>,
     },
+    // the amount is not known ahead of time as the delegation keeps accumulating rewards
+    // until the event gets executed
+    Redelegate {
+        owner: Addr,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        proxy: Option<Addr>,
+    },
     PledgeMore {
         mix_id: MixId,
         amount: Coin,
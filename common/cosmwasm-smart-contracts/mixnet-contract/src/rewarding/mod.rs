@@ -1,13 +1,73 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::{EpochId, Interval};
 use cosmwasm_std::{Coin, Decimal};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 pub mod helpers;
 pub mod simulator;
 
+/// Specifies how often the rewards accrued by a delegation (or by a node operator)
+/// should get compounded into the underlying stake.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompoundingFrequency {
+    /// The rewards are compounded at the end of every epoch.
+    Epoch,
+
+    /// The rewards are compounded at the end of every interval.
+    Interval,
+}
+
+impl CompoundingFrequency {
+    /// Determines the absolute id of the epoch at the end of which the rewards should get compounded
+    /// if the compounding was to be scheduled during the current epoch of the provided interval.
+    pub fn compounding_epoch(&self, interval: &Interval) -> EpochId {
+        match self {
+            CompoundingFrequency::Epoch => interval.current_epoch_absolute_id(),
+            CompoundingFrequency::Interval => {
+                interval.current_epoch_absolute_id()
+                    + interval.epochs_until_interval_end().saturating_sub(1)
+            }
+        }
+    }
+}
+
+impl Display for CompoundingFrequency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompoundingFrequency::Epoch => write!(f, "epoch"),
+            CompoundingFrequency::Interval => write!(f, "interval"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct CompoundingSettings {
+    /// How often the rewards should get compounded.
+    pub frequency: CompoundingFrequency,
+
+    /// Absolute id of the epoch at the end of which the rewards are going to get compounded next.
+    pub next_compounding_epoch: EpochId,
+}
+
+impl CompoundingSettings {
+    pub fn new(frequency: CompoundingFrequency, interval: &Interval) -> Self {
+        CompoundingSettings {
+            frequency,
+            next_compounding_epoch: frequency.compounding_epoch(interval),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct CompoundingSettingsResponse {
+    pub settings: Option<CompoundingSettings>,
+}
+
 #[cfg_attr(feature = "generate-ts", derive(ts_rs::TS))]
 #[cfg_attr(
     feature = "generate-ts",
@@ -5,7 +5,7 @@ use crate::error::MixnetContractError;
 use crate::helpers::IntoBaseDecimal;
 use crate::reward_params::NodeRewardParams;
 use crate::rewarding::simulator::simulated_node::SimulatedNode;
-use crate::rewarding::RewardDistribution;
+use crate::rewarding::{CompoundingFrequency, RewardDistribution};
 use crate::{
     Delegation, Interval, IntervalRewardParams, MixId, MixNodeCostParams, RewardingParams,
 };
@@ -128,6 +128,46 @@ impl Simulator {
         node.undelegate(delegator)
     }
 
+    pub fn set_operator_compounding(
+        &mut self,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+    ) -> Result<(), MixnetContractError> {
+        let node = self
+            .nodes
+            .get_mut(&mix_id)
+            .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+        node.operator_compounding = frequency;
+        Ok(())
+    }
+
+    pub fn set_delegation_compounding<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+    ) -> Result<(), MixnetContractError> {
+        let node = self
+            .nodes
+            .get_mut(&mix_id)
+            .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+
+        let delegator = delegator.into();
+        if !node.delegations.contains_key(&delegator) {
+            return Err(MixnetContractError::NoMixnodeDelegationFound {
+                mix_id,
+                address: delegator,
+                proxy: None,
+            });
+        }
+
+        match frequency {
+            Some(frequency) => node.delegations_compounding.insert(delegator, frequency),
+            None => node.delegations_compounding.remove(&delegator),
+        };
+        Ok(())
+    }
+
     pub fn simulate_epoch_single_node(
         &mut self,
         params: NodeRewardParams,
@@ -160,6 +200,7 @@ impl Simulator {
         }
 
         let mut dist = BTreeMap::new();
+        let interval_end = self.interval.epochs_until_interval_end() == 1;
 
         for (mix_id, node) in self.nodes.iter_mut() {
             let reward_distribution = node.rewarding_details.calculate_epoch_reward(
@@ -174,6 +215,8 @@ impl Simulator {
             self.pending_reward_pool_emission += reward_distribution.operator;
             self.pending_reward_pool_emission += reward_distribution.delegates;
 
+            node.compound_scheduled_rewards(interval_end)?;
+
             dist.insert(*mix_id, reward_distribution);
         }
 
@@ -596,6 +639,96 @@ mod tests {
             let node = &simulator.nodes[&0];
             assert_eq!(Decimal::zero(), node.rewarding_details.delegates);
         }
+
+        #[test]
+        fn compounding_delegator_reward() {
+            let mut simulator = base_simulator(10000_000000);
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+
+            // two identical delegations, only one of them gets compounded
+            simulator
+                .delegate("alice", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .delegate("bob", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .set_delegation_compounding("alice", 0, Some(CompoundingFrequency::Epoch))
+                .unwrap();
+
+            for _ in 0..10 {
+                simulator.simulate_epoch_single_node(node_params).unwrap();
+                check_rewarding_invariant(&simulator);
+            }
+
+            let node = &simulator.nodes[&0];
+            let alice = &node.delegations["alice"];
+            let bob = &node.delegations["bob"];
+
+            // all of alice's rewards got moved into the principal
+            assert!(alice.amount.amount > bob.amount.amount);
+            assert_eq!(
+                node.rewarding_details
+                    .determine_delegation_reward(alice)
+                    .unwrap(),
+                Decimal::zero()
+            );
+
+            // but the stake is worth the same (minus the truncated dust)
+            let alice_value = alice.dec_amount().unwrap();
+            let bob_value = bob.dec_amount().unwrap()
+                + node
+                    .rewarding_details
+                    .determine_delegation_reward(bob)
+                    .unwrap();
+            compare_decimals(
+                alice_value,
+                bob_value,
+                Some(Decimal::from_ratio(10u32, 1u32)),
+            );
+        }
+
+        #[test]
+        fn compounding_with_interval_frequency() {
+            let mut simulator = base_simulator(10000_000000);
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+
+            let original_pledge = simulator.nodes[&0].original_pledge.clone();
+            simulator
+                .delegate("alice", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .set_delegation_compounding("alice", 0, Some(CompoundingFrequency::Interval))
+                .unwrap();
+            simulator
+                .set_operator_compounding(0, Some(CompoundingFrequency::Interval))
+                .unwrap();
+
+            // nothing gets compounded mid-interval
+            simulator.simulate_epoch_single_node(node_params).unwrap();
+            let node = &simulator.nodes[&0];
+            assert_eq!(node.original_pledge, original_pledge);
+            assert_eq!(node.delegations["alice"].amount.amount.u128(), 18000_000000);
+
+            let epochs_left = simulator.interval.epochs_until_interval_end();
+            for _ in 0..epochs_left {
+                simulator.simulate_epoch_single_node(node_params).unwrap();
+            }
+            check_rewarding_invariant(&simulator);
+
+            let node = &simulator.nodes[&0];
+            assert!(node.original_pledge.amount > original_pledge.amount);
+            assert!(node.delegations["alice"].amount.amount.u128() > 18000_000000);
+            assert_eq!(
+                node.rewarding_details
+                    .pending_operator_reward(&node.original_pledge)
+                    .amount
+                    .u128(),
+                0
+            );
+        }
     }
 
     #[test]
@@ -7,11 +7,16 @@ use std::collections::HashMap;
 
 use crate::error::MixnetContractError;
 use crate::rewarding::helpers::truncate_reward;
+use crate::rewarding::CompoundingFrequency;
 
 pub struct SimulatedNode {
     pub mix_id: MixId,
+    pub original_pledge: Coin,
     pub rewarding_details: MixNodeRewarding,
     pub delegations: HashMap<String, Delegation>,
+
+    pub operator_compounding: Option<CompoundingFrequency>,
+    pub delegations_compounding: HashMap<String, CompoundingFrequency>,
 }
 
 impl SimulatedNode {
@@ -23,12 +28,15 @@ impl SimulatedNode {
     ) -> Result<Self, MixnetContractError> {
         Ok(SimulatedNode {
             mix_id,
+            original_pledge: initial_pledge.clone(),
             rewarding_details: MixNodeRewarding::initialise_new(
                 cost_params,
                 initial_pledge,
                 current_epoch,
             )?,
             delegations: HashMap::new(),
+            operator_compounding: None,
+            delegations_compounding: HashMap::new(),
         })
     }
 
@@ -59,6 +67,7 @@ impl SimulatedNode {
         delegator: S,
     ) -> Result<(Coin, Coin), MixnetContractError> {
         let delegator = delegator.into();
+        self.delegations_compounding.remove(&delegator);
         let delegation = self.delegations.remove(&delegator).ok_or(
             MixnetContractError::NoMixnodeDelegationFound {
                 mix_id: MixId::MAX,
@@ -78,4 +87,56 @@ impl SimulatedNode {
 
         Ok((delegation.amount, truncated_reward))
     }
+
+    pub fn compound_operator_reward(&mut self) -> Result<Coin, MixnetContractError> {
+        let reward = self
+            .rewarding_details
+            .compound_operator_reward(&self.original_pledge)?;
+        self.original_pledge.amount += reward.amount;
+        Ok(reward)
+    }
+
+    pub fn compound_delegator_reward<S: Into<String>>(
+        &mut self,
+        delegator: S,
+    ) -> Result<Coin, MixnetContractError> {
+        let delegator = delegator.into();
+        let delegation = self.delegations.get_mut(&delegator).ok_or(
+            MixnetContractError::NoMixnodeDelegationFound {
+                mix_id: self.mix_id,
+                address: delegator,
+                proxy: None,
+            },
+        )?;
+
+        self.rewarding_details.compound_delegator_reward(delegation)
+    }
+
+    /// Compounds rewards of the operator and all delegators that opted into it
+    /// with the frequency matching the boundary that has just been reached.
+    pub fn compound_scheduled_rewards(
+        &mut self,
+        interval_end: bool,
+    ) -> Result<(), MixnetContractError> {
+        let is_due = |frequency: &CompoundingFrequency| match frequency {
+            CompoundingFrequency::Epoch => true,
+            CompoundingFrequency::Interval => interval_end,
+        };
+
+        if self.operator_compounding.as_ref().map(is_due) == Some(true) {
+            self.compound_operator_reward()?;
+        }
+
+        let due = self
+            .delegations_compounding
+            .iter()
+            .filter(|(_, frequency)| is_due(frequency))
+            .map(|(delegator, _)| delegator.clone())
+            .collect::<Vec<_>>();
+
+        for delegator in due {
+            self.compound_delegator_reward(delegator)?;
+        }
+        Ok(())
+    }
 }
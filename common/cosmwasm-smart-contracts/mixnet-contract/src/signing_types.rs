@@ -1,8 +1,9 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::bond_transfer::BondTransferProposal;
 use crate::families::FamilyHead;
-use crate::{Gateway, IdentityKey, MixNode, MixNodeCostParams};
+use crate::{Gateway, IdentityKey, MixId, MixNode, MixNodeCostParams, SphinxKey};
 use contracts_common::signing::{
     ContractMessageContent, MessageType, Nonce, SignableMessage, SigningPurpose,
 };
@@ -12,6 +13,10 @@ use serde::Serialize;
 pub type SignableMixNodeBondingMsg = SignableMessage<ContractMessageContent<MixnodeBondingPayload>>;
 pub type SignableGatewayBondingMsg = SignableMessage<ContractMessageContent<GatewayBondingPayload>>;
 pub type SignableFamilyJoinPermitMsg = SignableMessage<FamilyJoinPermit>;
+pub type SignableMixNodeTransferMsg =
+    SignableMessage<ContractMessageContent<MixnodeTransferPayload>>;
+pub type SignableGatewayTransferMsg =
+    SignableMessage<ContractMessageContent<GatewayTransferPayload>>;
 
 #[derive(Serialize)]
 pub struct MixnodeBondingPayload {
@@ -78,6 +83,84 @@ pub fn construct_gateway_bonding_sign_payload(
     SignableMessage::new(nonce, content)
 }
 
+#[derive(Serialize)]
+pub struct MixnodeTransferPayload {
+    mix_id: MixId,
+    previous_owner: Addr,
+    new_identity_key: IdentityKey,
+    new_sphinx_key: SphinxKey,
+}
+
+impl MixnodeTransferPayload {
+    pub fn new(mix_id: MixId, proposal: BondTransferProposal) -> Self {
+        Self {
+            mix_id,
+            previous_owner: proposal.current_owner,
+            new_identity_key: proposal.new_identity_key,
+            new_sphinx_key: proposal.new_sphinx_key,
+        }
+    }
+}
+
+impl SigningPurpose for MixnodeTransferPayload {
+    fn message_type() -> MessageType {
+        MessageType::new("mixnode-transfer")
+    }
+}
+
+// note: the message has to be signed with the NEW identity key of the node
+// and the sender has to be the new owner of the bond
+pub fn construct_mixnode_transfer_sign_payload(
+    nonce: Nonce,
+    new_owner: Addr,
+    mix_id: MixId,
+    proposal: BondTransferProposal,
+) -> SignableMixNodeTransferMsg {
+    let payload = MixnodeTransferPayload::new(mix_id, proposal);
+    let content = ContractMessageContent::new(new_owner, None, vec![], payload);
+
+    SignableMessage::new(nonce, content)
+}
+
+#[derive(Serialize)]
+pub struct GatewayTransferPayload {
+    previous_identity_key: IdentityKey,
+    previous_owner: Addr,
+    new_identity_key: IdentityKey,
+    new_sphinx_key: SphinxKey,
+}
+
+impl GatewayTransferPayload {
+    pub fn new(previous_identity_key: IdentityKey, proposal: BondTransferProposal) -> Self {
+        Self {
+            previous_identity_key,
+            previous_owner: proposal.current_owner,
+            new_identity_key: proposal.new_identity_key,
+            new_sphinx_key: proposal.new_sphinx_key,
+        }
+    }
+}
+
+impl SigningPurpose for GatewayTransferPayload {
+    fn message_type() -> MessageType {
+        MessageType::new("gateway-transfer")
+    }
+}
+
+// note: the message has to be signed with the NEW identity key of the gateway
+// and the sender has to be the new owner of the bond
+pub fn construct_gateway_transfer_sign_payload(
+    nonce: Nonce,
+    new_owner: Addr,
+    previous_identity_key: IdentityKey,
+    proposal: BondTransferProposal,
+) -> SignableGatewayTransferMsg {
+    let payload = GatewayTransferPayload::new(previous_identity_key, proposal);
+    let content = ContractMessageContent::new(new_owner, None, vec![], payload);
+
+    SignableMessage::new(nonce, content)
+}
+
 #[derive(Serialize)]
 pub struct FamilyJoinPermit {
     // the granter of this permit
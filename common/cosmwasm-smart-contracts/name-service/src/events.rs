@@ -1,9 +1,14 @@
-use cosmwasm_std::{Coin, Event};
+use cosmwasm_std::{Addr, Coin, Event};
 
 use crate::{NameId, RegisteredName};
 
 pub enum NameEventType {
     Register,
+    RenewName,
+    UpdateAddress,
+    TransferName,
+    AcceptNameTransfer,
+    CancelNameTransfer,
     DeleteId,
     DeleteName,
     UpdateDepositRequired,
@@ -13,6 +18,11 @@ impl std::fmt::Display for NameEventType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             NameEventType::Register => write!(f, "register"),
+            NameEventType::RenewName => write!(f, "renew_name"),
+            NameEventType::UpdateAddress => write!(f, "update_address"),
+            NameEventType::TransferName => write!(f, "transfer_name"),
+            NameEventType::AcceptNameTransfer => write!(f, "accept_name_transfer"),
+            NameEventType::CancelNameTransfer => write!(f, "cancel_name_transfer"),
             NameEventType::DeleteId => write!(f, "delete_id"),
             NameEventType::DeleteName => write!(f, "delete_name"),
             NameEventType::UpdateDepositRequired => write!(f, "update_deposit_required"),
@@ -31,6 +41,9 @@ pub const ACTION: &str = "action";
 pub const NAME_ID: &str = "name_id";
 pub const NAME: &str = "name";
 pub const OWNER: &str = "owner";
+pub const PREVIOUS_OWNER: &str = "previous_owner";
+pub const NEW_OWNER: &str = "new_owner";
+pub const EXPIRES_AT: &str = "expires_at";
 
 pub const DEPOSIT_REQUIRED: &str = "deposit_required";
 
@@ -41,6 +54,52 @@ pub fn new_register_event(name_id: NameId, name: RegisteredName) -> Event {
         .add_attribute(NAME, name.name.to_string())
         .add_attribute(name.address.event_tag(), name.address.to_string())
         .add_attribute(OWNER, name.owner.to_string())
+        .add_attribute(EXPIRES_AT, name.expires_at.to_string())
+}
+
+pub fn new_renew_name_event(name_id: NameId, name: RegisteredName) -> Event {
+    Event::new(NameEventType::RenewName)
+        .add_attribute(ACTION, NameEventType::RenewName)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(EXPIRES_AT, name.expires_at.to_string())
+}
+
+pub fn new_update_address_event(name_id: NameId, name: RegisteredName) -> Event {
+    Event::new(NameEventType::UpdateAddress)
+        .add_attribute(ACTION, NameEventType::UpdateAddress)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(name.address.event_tag(), name.address.to_string())
+}
+
+pub fn new_transfer_name_event(name_id: NameId, name: RegisteredName, new_owner: &Addr) -> Event {
+    Event::new(NameEventType::TransferName)
+        .add_attribute(ACTION, NameEventType::TransferName)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(OWNER, name.owner.to_string())
+        .add_attribute(NEW_OWNER, new_owner.to_string())
+}
+
+pub fn new_accept_name_transfer_event(
+    name_id: NameId,
+    name: RegisteredName,
+    previous_owner: &Addr,
+) -> Event {
+    Event::new(NameEventType::AcceptNameTransfer)
+        .add_attribute(ACTION, NameEventType::AcceptNameTransfer)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(PREVIOUS_OWNER, previous_owner.to_string())
+        .add_attribute(OWNER, name.owner.to_string())
+}
+
+pub fn new_cancel_name_transfer_event(name_id: NameId, name: RegisteredName) -> Event {
+    Event::new(NameEventType::CancelNameTransfer)
+        .add_attribute(ACTION, NameEventType::CancelNameTransfer)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
 }
 
 pub fn new_delete_id_event(name_id: NameId, name: RegisteredName) -> Event {
@@ -1,6 +1,7 @@
 pub mod events;
 pub mod msg;
 pub mod response;
+pub mod signing_types;
 pub mod types;
 
 // Re-export all types at the top-level
@@ -1,28 +1,58 @@
 use crate::{Address, NameId, NymName};
 use cosmwasm_std::Coin;
+use nym_contracts_common::signing::MessageSignature;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct InstantiateMsg {
     pub deposit_required: Coin,
+    /// How long, in seconds, a registration is valid for before it has to be renewed.
+    pub registration_period: u64,
 }
 
 impl InstantiateMsg {
-    pub fn new(deposit_required: Coin) -> Self {
-        Self { deposit_required }
+    pub fn new(deposit_required: Coin, registration_period: u64) -> Self {
+        Self {
+            deposit_required,
+            registration_period,
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
-pub struct MigrateMsg {}
+pub struct MigrateMsg {
+    /// The registration period (in seconds) to use when migrating from a contract that predates
+    /// name expiry. It is also used to set the expiry of all the already registered names.
+    pub registration_period: Option<u64>,
+}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    /// Announcing a name pointing to a nym-address
-    Register { name: NymName, address: Address },
+    /// Announcing a name pointing to a nym-address. The signature has to be produced by the
+    /// identity key of the client the address belongs to.
+    Register {
+        name: NymName,
+        address: Address,
+        owner_signature: MessageSignature,
+    },
+    /// Extend the registration of a name by a full registration period, starting from now
+    RenewName { name: NymName },
+    /// Point an existing name to a new nym-address. The signature has to be produced by the
+    /// identity key of the client the new address belongs to.
+    UpdateAddress {
+        name: NymName,
+        address: Address,
+        owner_signature: MessageSignature,
+    },
+    /// Propose transferring the ownership of a name to another account
+    TransferName { name: NymName, new_owner: String },
+    /// Accept a pending name transfer, taking over the ownership of the name
+    AcceptNameTransfer { name: NymName },
+    /// Cancel a pending name transfer
+    CancelNameTransfer { name: NymName },
     /// Delete a name entry by id
     DeleteId { name_id: NameId },
     /// Delete a name entry by name
@@ -38,9 +68,24 @@ impl ExecuteMsg {
 
     pub fn default_memo(&self) -> String {
         match self {
-            ExecuteMsg::Register { name, address } => {
+            ExecuteMsg::Register { name, address, .. } => {
                 format!("registering {address} as name: {name}")
             }
+            ExecuteMsg::RenewName { name } => {
+                format!("renewing name: {name}")
+            }
+            ExecuteMsg::UpdateAddress { name, address, .. } => {
+                format!("pointing name {name} to {address}")
+            }
+            ExecuteMsg::TransferName { name, new_owner } => {
+                format!("proposing transfer of name {name} to {new_owner}")
+            }
+            ExecuteMsg::AcceptNameTransfer { name } => {
+                format!("accepting transfer of name: {name}")
+            }
+            ExecuteMsg::CancelNameTransfer { name } => {
+                format!("cancelling transfer of name: {name}")
+            }
             ExecuteMsg::DeleteId { name_id } => {
                 format!("deleting name with id {name_id}")
             }
@@ -75,6 +120,13 @@ pub enum QueryMsg {
         limit: Option<u32>,
         start_after: Option<NameId>,
     },
+    /// Query the pending ownership transfer of a name, if any
+    PendingTransfer {
+        name: NymName,
+    },
+    SigningNonce {
+        address: String,
+    },
     Config {},
     GetContractVersion {},
     #[serde(rename = "get_cw2_contract_version")]
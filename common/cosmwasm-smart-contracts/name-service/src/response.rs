@@ -1,5 +1,5 @@
-use crate::{msg::ExecuteMsg, NameEntry, NameId, RegisteredName};
-use cosmwasm_std::Coin;
+use crate::{NameEntry, NameId, RegisteredName};
+use cosmwasm_std::{Addr, Coin};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -67,13 +67,13 @@ impl PagedNamesListResponse {
 #[serde(rename_all = "snake_case")]
 pub struct ConfigResponse {
     pub deposit_required: Coin,
+    pub registration_period: u64,
 }
 
-impl From<RegisteredName> for ExecuteMsg {
-    fn from(name: RegisteredName) -> Self {
-        ExecuteMsg::Register {
-            name: name.name,
-            address: name.address,
-        }
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "snake_case")]
+pub struct PendingTransferResponse {
+    pub name_id: NameId,
+    /// The account the name is being transferred to, if there is a pending transfer.
+    pub new_owner: Option<Addr>,
 }
@@ -0,0 +1,59 @@
+use cosmwasm_std::{Addr, Coin};
+use nym_contracts_common::signing::{
+    ContractMessageContent, MessageType, Nonce, SignableMessage, SigningPurpose,
+};
+use serde::Serialize;
+
+use crate::{Address, NymName};
+
+pub type SignableNameRegisterMsg = SignableMessage<ContractMessageContent<NameRegister>>;
+pub type SignableNameUpdateAddressMsg = SignableMessage<ContractMessageContent<NameUpdateAddress>>;
+
+#[derive(Serialize)]
+pub struct NameRegister {
+    name: NymName,
+    address: Address,
+}
+
+impl SigningPurpose for NameRegister {
+    fn message_type() -> MessageType {
+        MessageType::new("name-register")
+    }
+}
+
+#[derive(Serialize)]
+pub struct NameUpdateAddress {
+    name: NymName,
+    address: Address,
+}
+
+impl SigningPurpose for NameUpdateAddress {
+    fn message_type() -> MessageType {
+        MessageType::new("name-update-address")
+    }
+}
+
+pub fn construct_name_register_sign_payload(
+    nonce: Nonce,
+    sender: Addr,
+    deposit: Coin,
+    name: NymName,
+    address: Address,
+) -> SignableNameRegisterMsg {
+    let payload = NameRegister { name, address };
+    let proxy = None;
+    let content = ContractMessageContent::new(sender, proxy, vec![deposit], payload);
+    SignableMessage::new(nonce, content)
+}
+
+pub fn construct_name_update_address_sign_payload(
+    nonce: Nonce,
+    sender: Addr,
+    name: NymName,
+    address: Address,
+) -> SignableNameUpdateAddressMsg {
+    let payload = NameUpdateAddress { name, address };
+    let proxy = None;
+    let content = ContractMessageContent::new(sender, proxy, vec![], payload);
+    SignableMessage::new(nonce, content)
+}
@@ -17,10 +17,20 @@ pub struct RegisteredName {
     pub owner: Addr,
     /// Block height at which the service was added.
     pub block_height: u64,
+    /// Unix timestamp (in seconds) at which the registration expires, unless renewed.
+    pub expires_at: u64,
     /// The deposit used to announce the service.
     pub deposit: Coin,
 }
 
+impl RegisteredName {
+    /// An expired name is still stored, but it can be renewed by its owner or claimed by anyone
+    /// else through a new registration.
+    pub fn is_expired(&self, current_time: u64) -> bool {
+        self.expires_at <= current_time
+    }
+}
+
 /// String representation of a nym address, which is of the form
 /// client_id.client_enc@gateway_id.
 /// NOTE: entirely unvalidated.
@@ -44,6 +54,16 @@ impl Address {
         }
     }
 
+    /// The client id part of the address, which is the base58 encoded identity key of the client.
+    pub fn client_id(&self) -> &str {
+        match self {
+            Address::NymAddress(address) => address
+                .split_once('.')
+                .map(|(client_id, _)| client_id)
+                .unwrap_or(address),
+        }
+    }
+
     pub fn event_tag(&self) -> &str {
         match self {
             Address::NymAddress(_) => "nym_address",
@@ -114,7 +134,16 @@ impl NameEntry {
 
 #[cfg(test)]
 mod tests {
-    use super::NymName;
+    use super::{Address, NymName};
+
+    #[test]
+    fn address_client_id() {
+        assert_eq!(
+            Address::new("client_id.client_key@gateway_id").client_id(),
+            "client_id"
+        );
+        assert_eq!(Address::new("client_id").client_id(), "client_id");
+    }
 
     #[test]
     fn parse_nym_name() {
@@ -11,6 +11,7 @@ pub const NEW_PERIODIC_VESTING_ACCOUNT_EVENT_TYPE: &str = "new_periodic_vesting_
 
 pub const VESTING_DELEGATION_EVENT_TYPE: &str = "vesting_delegation";
 pub const VESTING_UNDELEGATION_EVENT_TYPE: &str = "vesting_undelegation";
+pub const VESTING_REDELEGATION_EVENT_TYPE: &str = "vesting_redelegation";
 pub const VESTING_GATEWAY_BONDING_EVENT_TYPE: &str = "vesting_gateway_bonding";
 pub const VESTING_GATEWAY_UNBONDING_EVENT_TYPE: &str = "vesting_gateway_unbonding";
 pub const VESTING_MIXNODE_BONDING_EVENT_TYPE: &str = "vesting_mixnode_bonding";
@@ -21,12 +22,19 @@ pub const VESTING_UPDATE_MIXNODE_CONFIG_EVENT_TYPE: &str = "vesting_update_mixno
 pub const VESTING_UPDATE_GATEWAY_CONFIG_EVENT_TYPE: &str = "vesting_update_gateway_config";
 pub const VESTING_UPDATE_MIXNODE_COST_PARAMS_EVENT_TYPE: &str =
     "vesting_update_mixnode_cost_params";
+pub const VESTING_UPDATE_DELEGATION_COMPOUNDING_EVENT_TYPE: &str =
+    "vesting_update_delegation_compounding";
+pub const VESTING_UPDATE_OPERATOR_COMPOUNDING_EVENT_TYPE: &str =
+    "vesting_update_operator_compounding";
 
 pub const TRACK_MIXNODE_UNBOND_EVENT_TYPE: &str = "track_mixnode_unbond";
 pub const TRACK_MIXNODE_PLEDGE_DECREASE_EVENT_TYPE: &str = "track_mixnode_pledge_decrease";
 pub const TRACK_GATEWAY_UNBOND_EVENT_TYPE: &str = "track_gateway_unbond";
 pub const TRACK_UNDELEGATION_EVENT_TYPE: &str = "track_undelegation";
+pub const TRACK_REDELEGATION_EVENT_TYPE: &str = "track_redelegation";
 pub const TRACK_REWARD_EVENT_TYPE: &str = "track_reaward";
+pub const TRACK_COMPOUNDED_DELEGATOR_REWARD_EVENT_TYPE: &str = "track_compounded_delegator_reward";
+pub const TRACK_COMPOUNDED_OPERATOR_REWARD_EVENT_TYPE: &str = "track_compounded_operator_reward";
 
 // attributes that are used in multiple places
 pub const OWNER_KEY: &str = "owner";
@@ -148,6 +156,10 @@ pub fn new_vesting_undelegation_event() -> Event {
     Event::new(VESTING_UNDELEGATION_EVENT_TYPE)
 }
 
+pub fn new_vesting_redelegation_event() -> Event {
+    Event::new(VESTING_REDELEGATION_EVENT_TYPE)
+}
+
 pub fn new_track_mixnode_unbond_event() -> Event {
     Event::new(TRACK_MIXNODE_UNBOND_EVENT_TYPE)
 }
@@ -164,6 +176,26 @@ pub fn new_track_undelegation_event() -> Event {
     Event::new(TRACK_UNDELEGATION_EVENT_TYPE)
 }
 
+pub fn new_track_redelegation_event() -> Event {
+    Event::new(TRACK_REDELEGATION_EVENT_TYPE)
+}
+
 pub fn new_track_reward_event() -> Event {
     Event::new(TRACK_REWARD_EVENT_TYPE)
 }
+
+pub fn new_track_compounded_delegator_reward_event() -> Event {
+    Event::new(TRACK_COMPOUNDED_DELEGATOR_REWARD_EVENT_TYPE)
+}
+
+pub fn new_track_compounded_operator_reward_event() -> Event {
+    Event::new(TRACK_COMPOUNDED_OPERATOR_REWARD_EVENT_TYPE)
+}
+
+pub fn new_vesting_update_delegation_compounding_event() -> Event {
+    Event::new(VESTING_UPDATE_DELEGATION_COMPOUNDING_EVENT_TYPE)
+}
+
+pub fn new_vesting_update_operator_compounding_event() -> Event {
+    Event::new(VESTING_UPDATE_OPERATOR_COMPOUNDING_EVENT_TYPE)
+}
@@ -1,6 +1,7 @@
 use contracts_common::signing::MessageSignature;
 use cosmwasm_std::{Coin, Timestamp};
 use mixnet_contract_common::families::FamilyHead;
+use mixnet_contract_common::rewarding::CompoundingFrequency;
 use mixnet_contract_common::{
     gateway::GatewayConfigUpdate,
     mixnode::{MixNodeConfigUpdate, MixNodeCostParams},
@@ -82,6 +83,23 @@ pub enum ExecuteMsg {
     ClaimDelegatorReward {
         mix_id: MixId,
     },
+    UpdateOperatorCompounding {
+        frequency: Option<CompoundingFrequency>,
+    },
+    UpdateDelegationCompounding {
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        on_behalf_of: Option<String>,
+    },
+    TrackCompoundedOperatorReward {
+        owner: String,
+        amount: Coin,
+    },
+    TrackCompoundedDelegatorReward {
+        owner: String,
+        mix_id: MixId,
+        amount: Coin,
+    },
     UpdateMixnodeCostParams {
         new_costs: MixNodeCostParams,
     },
@@ -100,6 +118,11 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         on_behalf_of: Option<String>,
     },
+    Redelegate {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        on_behalf_of: Option<String>,
+    },
     CreateAccount {
         owner_address: String,
         staking_address: Option<String>,
@@ -114,6 +137,11 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         amount: Coin,
     },
+    TrackRedelegation {
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+    },
     BondMixnode {
         mix_node: MixNode,
         cost_params: MixNodeCostParams,
@@ -170,6 +198,18 @@ impl ExecuteMsg {
             ExecuteMsg::TrackReward { .. } => "VestingExecuteMsg::TrackReward",
             ExecuteMsg::ClaimOperatorReward { .. } => "VestingExecuteMsg::ClaimOperatorReward",
             ExecuteMsg::ClaimDelegatorReward { .. } => "VestingExecuteMsg::ClaimDelegatorReward",
+            ExecuteMsg::UpdateOperatorCompounding { .. } => {
+                "VestingExecuteMsg::UpdateOperatorCompounding"
+            }
+            ExecuteMsg::UpdateDelegationCompounding { .. } => {
+                "VestingExecuteMsg::UpdateDelegationCompounding"
+            }
+            ExecuteMsg::TrackCompoundedOperatorReward { .. } => {
+                "VestingExecuteMsg::TrackCompoundedOperatorReward"
+            }
+            ExecuteMsg::TrackCompoundedDelegatorReward { .. } => {
+                "VestingExecuteMsg::TrackCompoundedDelegatorReward"
+            }
             ExecuteMsg::UpdateMixnodeConfig { .. } => "VestingExecuteMsg::UpdateMixnodeConfig",
             ExecuteMsg::UpdateMixnodeCostParams { .. } => {
                 "VestingExecuteMsg::UpdateMixnodeCostParams"
@@ -177,9 +217,11 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateMixnetAddress { .. } => "VestingExecuteMsg::UpdateMixnetAddress",
             ExecuteMsg::DelegateToMixnode { .. } => "VestingExecuteMsg::DelegateToMixnode",
             ExecuteMsg::UndelegateFromMixnode { .. } => "VestingExecuteMsg::UndelegateFromMixnode",
+            ExecuteMsg::Redelegate { .. } => "VestingExecuteMsg::Redelegate",
             ExecuteMsg::CreateAccount { .. } => "VestingExecuteMsg::CreateAccount",
             ExecuteMsg::WithdrawVestedCoins { .. } => "VestingExecuteMsg::WithdrawVestedCoins",
             ExecuteMsg::TrackUndelegation { .. } => "VestingExecuteMsg::TrackUndelegation",
+            ExecuteMsg::TrackRedelegation { .. } => "VestingExecuteMsg::TrackRedelegation",
             ExecuteMsg::BondMixnode { .. } => "VestingExecuteMsg::BondMixnode",
             ExecuteMsg::PledgeMore { .. } => "VestingExecuteMsg::PledgeMore",
             ExecuteMsg::DecreasePledge { .. } => "VestingExecuteMsg::DecreasePledge",
@@ -37,7 +37,8 @@ impl CoconutCredentialManager {
         epoch_id: String,
     ) {
         let mut creds = self.inner.write().await;
-        let id = creds.len() as i64;
+        // ids are never reused, even if some credentials got removed
+        let id = creds.last().map(|c| c.id + 1).unwrap_or_default();
         creds.push(CoconutCredential {
             id,
             voucher_value,
@@ -63,8 +64,23 @@ impl CoconutCredentialManager {
     /// * `id`: Database id.
     pub async fn consume_coconut_credential(&self, id: i64) {
         let mut creds = self.inner.write().await;
-        if let Some(cred) = creds.get_mut(id as usize) {
+        if let Some(cred) = creds.iter_mut().find(|c| c.id == id) {
             cred.consumed = true;
         }
     }
+
+    /// Retrieves all stored credentials, including the consumed ones.
+    pub async fn get_all_coconut_credentials(&self) -> Vec<CoconutCredential> {
+        self.inner.read().await.clone()
+    }
+
+    /// Removes from the database the specified credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    pub async fn remove_coconut_credential(&self, id: i64) {
+        let mut creds = self.inner.write().await;
+        creds.retain(|c| c.id != id);
+    }
 }
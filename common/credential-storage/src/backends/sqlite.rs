@@ -71,4 +71,26 @@ impl CoconutCredentialManager {
         .await?;
         Ok(())
     }
+
+    /// Retrieves all stored credentials, including the consumed ones.
+    pub async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, sqlx::Error> {
+        sqlx::query_as!(
+            CoconutCredential,
+            "SELECT * FROM coconut_credentials ORDER BY id"
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Removes from the database the specified credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Database id.
+    pub async fn remove_coconut_credential(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM coconut_credentials WHERE id = ?", id)
+            .execute(&self.connection_pool)
+            .await?;
+        Ok(())
+    }
 }
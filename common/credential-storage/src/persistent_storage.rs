@@ -98,4 +98,19 @@ impl Storage for PersistentStorage {
 
         Ok(())
     }
+
+    async fn get_all_coconut_credentials(&self) -> Result<Vec<CoconutCredential>, StorageError> {
+        Ok(self
+            .coconut_credential_manager
+            .get_all_coconut_credentials()
+            .await?)
+    }
+
+    async fn remove_coconut_credential(&self, id: i64) -> Result<(), StorageError> {
+        self.coconut_credential_manager
+            .remove_coconut_credential(id)
+            .await?;
+
+        Ok(())
+    }
 }
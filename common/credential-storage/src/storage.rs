@@ -38,4 +38,16 @@ pub trait Storage: Send + Sync {
     ///
     /// * `id`: Id of the credential to be consumed.
     async fn consume_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError>;
+
+    /// Retrieves all stored credentials, including the consumed ones.
+    async fn get_all_coconut_credentials(
+        &self,
+    ) -> Result<Vec<CoconutCredential>, Self::StorageError>;
+
+    /// Removes the specified credential from the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: Id of the credential to be removed.
+    async fn remove_coconut_credential(&self, id: i64) -> Result<(), Self::StorageError>;
 }
@@ -55,6 +55,31 @@ impl BandwidthVoucher {
     ) -> Self {
         let serial_number = params.random_scalar();
         let binding_number = params.random_scalar();
+        Self::new_with_private_attributes(
+            params,
+            serial_number,
+            binding_number,
+            voucher_value,
+            voucher_info,
+            tx_hash,
+            signing_key,
+            encryption_key,
+        )
+    }
+
+    /// Creates a voucher with the provided, rather than random, private attributes,
+    /// such as the ones deterministically derived for tickets of a [TicketBook](super::ticketbook::TicketBook).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_private_attributes(
+        params: &Parameters,
+        serial_number: PrivateAttribute,
+        binding_number: PrivateAttribute,
+        voucher_value: String,
+        voucher_info: String,
+        tx_hash: Hash,
+        signing_key: identity::PrivateKey,
+        encryption_key: encryption::PrivateKey,
+    ) -> Self {
         let voucher_value_plain = voucher_value.clone();
         let voucher_info_plain = voucher_info.clone();
         let voucher_value = hash_to_scalar(voucher_value.as_bytes());
@@ -254,6 +279,19 @@ impl BandwidthVoucher {
         message.extend_from_slice(self.tx_hash.to_string().as_bytes());
         self.signing_key.sign(&message)
     }
+
+    /// Signs the request for the particular ticket of a ticket book. The index is included in
+    /// the signed message so that nobody could replay the request for a different ticket.
+    pub fn sign_ticket(
+        &self,
+        request: &BlindSignRequest,
+        ticket_index: u32,
+    ) -> identity::Signature {
+        let mut message = request.to_bytes();
+        message.extend_from_slice(self.tx_hash.to_string().as_bytes());
+        message.extend_from_slice(&ticket_index.to_be_bytes());
+        self.signing_key.sign(&message)
+    }
 }
 
 pub fn prepare_for_spending(
@@ -3,4 +3,5 @@
 
 pub mod bandwidth;
 pub mod params;
+pub mod ticketbook;
 pub mod utils;
@@ -0,0 +1,296 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// A ticket book splits a single deposit into multiple, independently spendable bandwidth tickets.
+// Each ticket is a regular bandwidth credential worth the equal share of the deposit, whose private
+// attributes are deterministically derived from a secret known only to the wallet. Since every
+// ticket is issued with a fresh blind signature and shown with a fresh zero-knowledge proof, gateways
+// can't link tickets coming out of the same book (apart from them sharing the same value)
+// and the client can spend them one by one at different gateways.
+
+use nym_coconut_interface::{hash_to_scalar, Parameters, PrivateAttribute};
+use nym_crypto::asymmetric::{encryption, identity};
+use nym_network_defaults::{MAX_TICKETS_PER_TICKETBOOK, TICKETBOOK_INFO_PREFIX, TICKET_INFO};
+
+use cosmrs::tx::Hash;
+
+use super::bandwidth::BandwidthVoucher;
+use crate::error::Error;
+
+const SERIAL_NUMBER_DOMAIN: &[u8] = b"NYM_TICKETBOOK_SERIAL_NUMBER";
+const BINDING_NUMBER_DOMAIN: &[u8] = b"NYM_TICKETBOOK_BINDING_NUMBER";
+
+/// Deposit info that has to be attached to the deposit in order for it to be issued as a ticket book
+/// of the specified number of tickets.
+pub fn ticketbook_deposit_info(tickets: u32) -> String {
+    format!("{TICKETBOOK_INFO_PREFIX}/{tickets}")
+}
+
+/// Attempts to recover the number of tickets from the deposit info. Returns `None` if the deposit
+/// is not meant to be issued as a ticket book or if the number of tickets is invalid.
+pub fn parse_ticketbook_deposit_info(deposit_info: &str) -> Option<u32> {
+    deposit_info
+        .strip_prefix(TICKETBOOK_INFO_PREFIX)?
+        .strip_prefix('/')?
+        .parse()
+        .ok()
+        .filter(|tickets| *tickets > 0 && *tickets <= MAX_TICKETS_PER_TICKETBOOK)
+}
+
+/// Computes the value of a single ticket of a ticket book created out of the deposit of the specified value.
+/// Only deposits that can be split evenly are accepted so that the sum of all tickets is always equal
+/// to the deposited amount.
+pub fn ticket_value(deposit_value: &str, tickets: u32) -> Result<String, Error> {
+    let indivisible = || Error::IndivisibleTicketBookValue {
+        value: deposit_value.to_string(),
+        tickets,
+    };
+
+    let value: u128 = deposit_value.parse().map_err(|_| indivisible())?;
+    if tickets == 0 || value == 0 || value % tickets as u128 != 0 {
+        return Err(indivisible());
+    }
+    Ok((value / tickets as u128).to_string())
+}
+
+pub struct TicketBook {
+    // secret value generated by the client out of which the private attributes of all tickets are derived
+    wallet_secret: [u8; 32],
+    // the number of tickets the deposit is split into
+    tickets: u32,
+    // the plain text value of the whole deposit
+    deposit_value_plain: String,
+    // the hash of the deposit transaction
+    tx_hash: Hash,
+    // private key ensuring the depositer requested the tickets
+    signing_key: identity::PrivateKey,
+    // private key ensuring only this client receives the signature shares
+    encryption_key: encryption::PrivateKey,
+}
+
+impl TicketBook {
+    pub fn new(
+        params: &Parameters,
+        deposit_value: String,
+        tickets: u32,
+        tx_hash: Hash,
+        signing_key: identity::PrivateKey,
+        encryption_key: encryption::PrivateKey,
+    ) -> Result<Self, Error> {
+        // make sure the deposit can actually be split
+        ticket_value(&deposit_value, tickets)?;
+
+        Ok(TicketBook {
+            wallet_secret: params.random_scalar().to_bytes(),
+            tickets,
+            deposit_value_plain: deposit_value,
+            tx_hash,
+            signing_key,
+            encryption_key,
+        })
+    }
+
+    pub fn tickets(&self) -> u32 {
+        self.tickets
+    }
+
+    pub fn tx_hash(&self) -> &Hash {
+        &self.tx_hash
+    }
+
+    pub fn ticket_value(&self) -> String {
+        // the value has been checked upon construction
+        ticket_value(&self.deposit_value_plain, self.tickets).unwrap_or_default()
+    }
+
+    fn derive_private_attributes(&self, index: u32) -> (PrivateAttribute, PrivateAttribute) {
+        let mut serial_number_seed = SERIAL_NUMBER_DOMAIN.to_vec();
+        serial_number_seed.extend_from_slice(&self.wallet_secret);
+        serial_number_seed.extend_from_slice(&index.to_be_bytes());
+
+        let mut binding_number_seed = BINDING_NUMBER_DOMAIN.to_vec();
+        binding_number_seed.extend_from_slice(&self.wallet_secret);
+        binding_number_seed.extend_from_slice(&index.to_be_bytes());
+
+        (
+            hash_to_scalar(serial_number_seed),
+            hash_to_scalar(binding_number_seed),
+        )
+    }
+
+    /// Creates the voucher for the ticket with the specified index. The same ticket is always
+    /// derived for the same index, so issuance can be safely resumed after an interruption.
+    pub fn ticket(&self, params: &Parameters, index: u32) -> Result<BandwidthVoucher, Error> {
+        if index >= self.tickets {
+            return Err(Error::InvalidTicketIndex {
+                index,
+                tickets: self.tickets,
+            });
+        }
+
+        let (serial_number, binding_number) = self.derive_private_attributes(index);
+        Ok(BandwidthVoucher::new_with_private_attributes(
+            params,
+            serial_number,
+            binding_number,
+            self.ticket_value(),
+            TICKET_INFO.to_string(),
+            self.tx_hash,
+            identity::PrivateKey::from_bytes(&self.signing_key.to_bytes())
+                .expect("failed to copy the signing key"),
+            encryption::PrivateKey::from_bytes(&self.encryption_key.to_bytes())
+                .expect("failed to copy the encryption key"),
+        ))
+    }
+
+    // wallet secret || tx hash || signing key || encryption key || tickets || deposit value
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut ret = Vec::with_capacity(4 * 32 + 4 + self.deposit_value_plain.len());
+        ret.extend_from_slice(&self.wallet_secret);
+        ret.extend_from_slice(self.tx_hash.as_bytes());
+        ret.extend_from_slice(&self.signing_key.to_bytes());
+        ret.extend_from_slice(&self.encryption_key.to_bytes());
+        ret.extend_from_slice(&self.tickets.to_be_bytes());
+        ret.extend_from_slice(self.deposit_value_plain.as_bytes());
+        ret
+    }
+
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < 4 * 32 + 4 {
+            return Err(Error::TicketBookDeserializationError(format!(
+                "Less then {} bytes needed",
+                4 * 32 + 4
+            )));
+        }
+
+        let mut buff = [0u8; 32];
+        let mut wallet_secret = [0u8; 32];
+        wallet_secret.copy_from_slice(&bytes[..32]);
+        buff.copy_from_slice(&bytes[32..2 * 32]);
+        let tx_hash = Hash::new(buff);
+        let signing_key = identity::PrivateKey::from_bytes(&bytes[2 * 32..3 * 32])
+            .map_err(|_| Error::TicketBookDeserializationError(String::from("Invalid key")))?;
+        let encryption_key = encryption::PrivateKey::from_bytes(&bytes[3 * 32..4 * 32])
+            .map_err(|_| Error::TicketBookDeserializationError(String::from("Invalid key")))?;
+        let mut tickets_bytes = [0u8; 4];
+        tickets_bytes.copy_from_slice(&bytes[4 * 32..4 * 32 + 4]);
+        let tickets = u32::from_be_bytes(tickets_bytes);
+        let deposit_value_plain =
+            String::from_utf8(bytes[4 * 32 + 4..].to_vec()).map_err(|_| {
+                Error::TicketBookDeserializationError(String::from("Invalid UTF8 string"))
+            })?;
+        ticket_value(&deposit_value_plain, tickets)?;
+
+        Ok(TicketBook {
+            wallet_secret,
+            tickets,
+            deposit_value_plain,
+            tx_hash,
+            signing_key,
+            encryption_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nym_coconut_interface::Base58;
+    use rand::rngs::OsRng;
+
+    fn ticketbook_fixture(tickets: u32) -> TicketBook {
+        let params = Parameters::new(4).unwrap();
+        let mut rng = OsRng;
+        TicketBook::new(
+            &params,
+            "1000".to_string(),
+            tickets,
+            Hash::new([0; 32]),
+            identity::PrivateKey::from_bytes(
+                &identity::KeyPair::new(&mut rng).private_key().to_bytes(),
+            )
+            .unwrap(),
+            encryption::PrivateKey::from_bytes(
+                &encryption::KeyPair::new(&mut rng).private_key().to_bytes(),
+            )
+            .unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn deposit_info_roundtrip() {
+        assert_eq!(
+            parse_ticketbook_deposit_info(&ticketbook_deposit_info(8)),
+            Some(8)
+        );
+        assert_eq!(parse_ticketbook_deposit_info(TICKETBOOK_INFO_PREFIX), None);
+        assert_eq!(
+            parse_ticketbook_deposit_info(&ticketbook_deposit_info(0)),
+            None
+        );
+        assert_eq!(
+            parse_ticketbook_deposit_info(&ticketbook_deposit_info(MAX_TICKETS_PER_TICKETBOOK + 1)),
+            None
+        );
+        assert_eq!(parse_ticketbook_deposit_info("BandwidthVoucher"), None);
+    }
+
+    #[test]
+    fn ticket_values() {
+        assert_eq!(ticket_value("1000", 4).unwrap(), "250");
+        assert_eq!(ticket_value("1000", 1).unwrap(), "1000");
+        assert!(ticket_value("1000", 3).is_err());
+        assert!(ticket_value("1000", 0).is_err());
+        assert!(ticket_value("0", 4).is_err());
+        assert!(ticket_value("foo", 4).is_err());
+    }
+
+    #[test]
+    fn tickets_are_deterministic_and_distinct() {
+        let params = Parameters::new(4).unwrap();
+        let ticketbook = ticketbook_fixture(4);
+
+        let first = ticketbook.ticket(&params, 0).unwrap();
+        let first_again = ticketbook.ticket(&params, 0).unwrap();
+        let second = ticketbook.ticket(&params, 1).unwrap();
+
+        assert_eq!(
+            first.get_private_attributes(),
+            first_again.get_private_attributes()
+        );
+        assert_ne!(
+            first.get_private_attributes()[0],
+            second.get_private_attributes()[0]
+        );
+        assert_eq!(first.get_voucher_value(), "250");
+        assert_eq!(first.get_public_attributes_plain()[1], TICKET_INFO);
+        assert!(ticketbook.ticket(&params, 4).is_err());
+    }
+
+    #[test]
+    fn serde_ticketbook() {
+        let params = Parameters::new(4).unwrap();
+        let ticketbook = ticketbook_fixture(10);
+        let deserialized = TicketBook::try_from_bytes(&ticketbook.to_bytes()).unwrap();
+
+        assert_eq!(ticketbook.tickets, deserialized.tickets);
+        assert_eq!(
+            ticketbook.deposit_value_plain,
+            deserialized.deposit_value_plain
+        );
+        assert_eq!(ticketbook.tx_hash, deserialized.tx_hash);
+        assert_eq!(
+            ticketbook
+                .ticket(&params, 3)
+                .unwrap()
+                .get_private_attributes()[0]
+                .to_bs58(),
+            deserialized
+                .ticket(&params, 3)
+                .unwrap()
+                .get_private_attributes()[0]
+                .to_bs58()
+        );
+    }
+}
@@ -37,6 +37,7 @@ pub async fn obtain_aggregate_verification_key(
 async fn obtain_partial_credential(
     params: &Parameters,
     attributes: &BandwidthVoucher,
+    ticket_index: Option<u32>,
     client: &nym_validator_client::client::NymApiClient,
     validator_vk: &VerificationKey,
 ) -> Result<Signature, Error> {
@@ -45,14 +46,21 @@ async fn obtain_partial_credential(
     let private_attributes = attributes.get_private_attributes();
     let blind_sign_request = attributes.blind_sign_request();
 
-    let blind_sign_request_body = BlindSignRequestBody::new(
+    let request_signature = match ticket_index {
+        Some(index) => attributes.sign_ticket(blind_sign_request, index),
+        None => attributes.sign(blind_sign_request),
+    };
+    let mut blind_sign_request_body = BlindSignRequestBody::new(
         blind_sign_request,
         attributes.tx_hash().to_string(),
-        attributes.sign(blind_sign_request).to_base58_string(),
+        request_signature.to_base58_string(),
         &public_attributes,
         public_attributes_plain,
         (public_attributes.len() + private_attributes.len()) as u32,
     );
+    if let Some(index) = ticket_index {
+        blind_sign_request_body = blind_sign_request_body.with_ticket_index(index);
+    }
     let response = client.blind_sign(&blind_sign_request_body).await?;
     let encrypted_signature = response.encrypted_signature;
     let remote_key = PublicKey::from_bytes(&response.remote_key)?;
@@ -87,6 +95,34 @@ pub async fn obtain_aggregate_signature(
     attributes: &BandwidthVoucher,
     coconut_api_clients: &[CoconutApiClient],
     threshold: u64,
+) -> Result<Signature, Error> {
+    aggregate_signature(params, attributes, None, coconut_api_clients, threshold).await
+}
+
+/// Obtains the aggregate signature on the particular ticket of a ticket book.
+pub async fn obtain_aggregate_ticket_signature(
+    params: &Parameters,
+    ticket: &BandwidthVoucher,
+    ticket_index: u32,
+    coconut_api_clients: &[CoconutApiClient],
+    threshold: u64,
+) -> Result<Signature, Error> {
+    aggregate_signature(
+        params,
+        ticket,
+        Some(ticket_index),
+        coconut_api_clients,
+        threshold,
+    )
+    .await
+}
+
+async fn aggregate_signature(
+    params: &Parameters,
+    attributes: &BandwidthVoucher,
+    ticket_index: Option<u32>,
+    coconut_api_clients: &[CoconutApiClient],
+    threshold: u64,
 ) -> Result<Signature, Error> {
     if coconut_api_clients.is_empty() {
         return Err(Error::NoValidatorsAvailable);
@@ -110,6 +146,7 @@ pub async fn obtain_aggregate_signature(
         if let Ok(signature) = obtain_partial_credential(
             params,
             attributes,
+            ticket_index,
             &coconut_api_client.api_client,
             &coconut_api_client.verification_key,
         )
@@ -41,4 +41,13 @@ pub enum Error {
 
     #[error("Could not deserialize bandwidth voucher - {0}")]
     BandwidthVoucherDeserializationError(String),
+
+    #[error("Could not deserialize ticket book - {0}")]
+    TicketBookDeserializationError(String),
+
+    #[error("Ticket {index} does not exist in a ticket book of {tickets} tickets")]
+    InvalidTicketIndex { index: u32, tickets: u32 },
+
+    #[error("Value of {value} can't be evenly split into {tickets} tickets")]
+    IndivisibleTicketBookValue { value: String, tickets: u32 },
 }
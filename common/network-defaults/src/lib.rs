@@ -405,6 +405,12 @@ pub const UTOKENS_TO_BURN: u64 = TOKENS_TO_BURN * 1000000;
 pub const BANDWIDTH_VALUE: u64 = UTOKENS_TO_BURN * BYTES_PER_UTOKEN;
 
 pub const VOUCHER_INFO: &str = "BandwidthVoucher";
+/// Prefix of the deposit info of deposits that are to be split into multiple bandwidth tickets.
+pub const TICKETBOOK_INFO_PREFIX: &str = "BandwidthTicketBook";
+/// Public information attached to each of the bandwidth tickets issued out of a ticket book.
+pub const TICKET_INFO: &str = "BandwidthTicket";
+/// Maximum number of tickets a single deposit can be split into
+pub const MAX_TICKETS_PER_TICKETBOOK: u32 = 256;
 
 pub const ETH_MIN_BLOCK_DEPTH: usize = 7;
 
@@ -6,9 +6,10 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use ff::Field;
 use group::{Curve, Group};
 use nym_coconut::{
-    aggregate_signature_shares, aggregate_verification_keys, blind_sign, elgamal_keygen,
-    prepare_blind_sign, prove_bandwidth_credential, setup, ttp_keygen, verify_credential,
-    Attribute, BlindedSignature, Parameters, Signature, SignatureShare, VerificationKey,
+    aggregate_signature_shares, aggregate_verification_keys, batch_verify_credentials, blind_sign,
+    elgamal_keygen, prepare_blind_sign, prove_bandwidth_credential, setup, ttp_keygen,
+    verify_credential, Attribute, BatchedCredential, BlindedSignature, Parameters, Signature,
+    SignatureShare, VerificationKey,
 };
 use rand::seq::SliceRandom;
 use std::ops::Neg;
@@ -333,6 +334,56 @@ fn bench_coconut(c: &mut Criterion) {
             })
         },
     );
+
+    // BATCH VERIFICATION BENCHMARK
+    // compare verifying credentials one by one against verifying them as a single batch
+    for batch_size in [10, 50, 100] {
+        let thetas = (0..batch_size)
+            .map(|_| {
+                prove_bandwidth_credential(
+                    &params,
+                    &aggr_verification_key,
+                    &aggregated_signature,
+                    serial_number,
+                    binding_number,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let batch = thetas
+            .iter()
+            .map(|theta| BatchedCredential::new(&aggr_verification_key, theta, &public_attributes))
+            .collect::<Vec<_>>();
+
+        group.bench_function(
+            &format!(
+                "[Verifier] verify_{}_credentials_individually_{}_attributes",
+                batch_size,
+                case.num_attrs(),
+            ),
+            |b| {
+                b.iter(|| {
+                    thetas.iter().all(|theta| {
+                        verify_credential(
+                            &params,
+                            &aggr_verification_key,
+                            theta,
+                            &public_attributes,
+                        )
+                    })
+                })
+            },
+        );
+
+        group.bench_function(
+            &format!(
+                "[Verifier] batch_verify_{}_credentials_{}_attributes",
+                batch_size,
+                case.num_attrs(),
+            ),
+            |b| b.iter(|| batch_verify_credentials(&params, &batch)),
+        );
+    }
 }
 criterion_group!(benches, bench_coconut);
 criterion_main!(benches);
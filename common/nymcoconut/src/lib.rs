@@ -21,9 +21,12 @@ pub use scheme::keygen::SecretKey;
 pub use scheme::keygen::VerificationKey;
 pub use scheme::setup::setup;
 pub use scheme::setup::Parameters;
+pub use scheme::verification::batch_verify_credentials;
 pub use scheme::verification::check_vk_pairing;
+pub use scheme::verification::find_invalid_credentials;
 pub use scheme::verification::prove_bandwidth_credential;
 pub use scheme::verification::verify_credential;
+pub use scheme::verification::BatchedCredential;
 pub use scheme::verification::Theta;
 pub use scheme::BlindedSignature;
 pub use scheme::Signature;
@@ -5,7 +5,7 @@ use core::ops::Neg;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 
-use bls12_381::{multi_miller_loop, G1Affine, G2Prepared, G2Projective, Scalar};
+use bls12_381::{multi_miller_loop, G1Affine, G1Projective, G2Prepared, G2Projective, Scalar};
 use group::{Curve, Group};
 
 use crate::error::{CoconutError, Result};
@@ -245,24 +245,21 @@ pub fn check_vk_pairing(
     true
 }
 
-pub fn verify_credential(
-    params: &Parameters,
+// computes the kappa the credential signature is checked against, i.e. the blinded message
+// with all the public attributes included
+fn credential_kappa(
     verification_key: &VerificationKey,
     theta: &Theta,
     public_attributes: &[Attribute],
-) -> bool {
+) -> Option<G2Projective> {
     if public_attributes.len() + theta.pi_v.private_attributes_len()
         > verification_key.beta_g2.len()
     {
-        return false;
+        return None;
     }
 
-    if !theta.verify_proof(params, verification_key) {
-        return false;
-    }
-
-    let kappa = if public_attributes.is_empty() {
-        theta.blinded_message
+    if public_attributes.is_empty() {
+        Some(theta.blinded_message)
     } else {
         let signed_public_attributes = public_attributes
             .iter()
@@ -275,9 +272,25 @@ pub fn verify_credential(
             .map(|(pub_attr, beta_i)| beta_i * pub_attr)
             .sum::<G2Projective>();
 
-        theta.blinded_message + signed_public_attributes
+        Some(theta.blinded_message + signed_public_attributes)
+    }
+}
+
+pub fn verify_credential(
+    params: &Parameters,
+    verification_key: &VerificationKey,
+    theta: &Theta,
+    public_attributes: &[Attribute],
+) -> bool {
+    let kappa = match credential_kappa(verification_key, theta, public_attributes) {
+        Some(kappa) => kappa,
+        None => return false,
     };
 
+    if !theta.verify_proof(params, verification_key) {
+        return false;
+    }
+
     check_bilinear_pairing(
         &theta.credential.0.to_affine(),
         &G2Prepared::from(kappa.to_affine()),
@@ -286,6 +299,157 @@ pub fn verify_credential(
     ) && !bool::from(theta.credential.0.is_identity())
 }
 
+/// A single credential to be verified as part of a batch.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchedCredential<'a> {
+    pub verification_key: &'a VerificationKey,
+    pub theta: &'a Theta,
+    pub public_attributes: &'a [Attribute],
+}
+
+impl<'a> BatchedCredential<'a> {
+    pub fn new(
+        verification_key: &'a VerificationKey,
+        theta: &'a Theta,
+        public_attributes: &'a [Attribute],
+    ) -> Self {
+        BatchedCredential {
+            verification_key,
+            theta,
+            public_attributes,
+        }
+    }
+}
+
+/// Verifies all of the provided credentials at once. Rather than checking e(h_i, kappa_i) == e(s_i, g2)
+/// for each credential separately, the pairing equations are combined using random scalars r_i into
+/// prod e(r_i * h_i, kappa_i) * e(-sum(r_i * s_i), g2) == id, which requires only a single final
+/// exponentiation for the whole batch. The proofs of knowledge are still checked individually.
+///
+/// Returns true only if every single credential in the batch is valid. Note that an empty batch is
+/// trivially valid.
+pub fn batch_verify_credentials(params: &Parameters, credentials: &[BatchedCredential]) -> bool {
+    let mut prepared = Vec::with_capacity(credentials.len());
+    for credential in credentials {
+        match PreparedCredential::new(params, credential) {
+            Some(credential) => prepared.push(credential),
+            None => return false,
+        }
+    }
+
+    batch_check_pairings(params, &prepared)
+}
+
+/// Attempts to verify all of the provided credentials as a single batch and, if that fails,
+/// recursively bisects the batch in order to find the invalid ones.
+///
+/// The proofs of knowledge are checked exactly once for each credential and only the combined
+/// pairing equation is re-evaluated when bisecting.
+///
+/// Returns the indices (within the provided slice) of all invalid credentials, so an empty result
+/// means the whole batch is valid.
+pub fn find_invalid_credentials(
+    params: &Parameters,
+    credentials: &[BatchedCredential],
+) -> Vec<usize> {
+    fn bisect(
+        params: &Parameters,
+        credentials: &[(usize, PreparedCredential)],
+        invalid: &mut Vec<usize>,
+    ) {
+        if batch_check_pairings(params, credentials.iter().map(|(_, c)| c)) {
+            return;
+        }
+
+        if credentials.len() == 1 {
+            invalid.push(credentials[0].0);
+            return;
+        }
+
+        let mid = credentials.len() / 2;
+        bisect(params, &credentials[..mid], invalid);
+        bisect(params, &credentials[mid..], invalid);
+    }
+
+    let mut invalid = Vec::new();
+    let mut prepared = Vec::with_capacity(credentials.len());
+    for (index, credential) in credentials.iter().enumerate() {
+        match PreparedCredential::new(params, credential) {
+            Some(credential) => prepared.push((index, credential)),
+            None => invalid.push(index),
+        }
+    }
+
+    bisect(params, &prepared, &mut invalid);
+    invalid.sort_unstable();
+    invalid
+}
+
+/// Credential that has passed all the checks apart from the final pairing equation.
+struct PreparedCredential<'a> {
+    theta: &'a Theta,
+    kappa: G2Prepared,
+}
+
+impl<'a> PreparedCredential<'a> {
+    fn new(params: &Parameters, credential: &BatchedCredential<'a>) -> Option<Self> {
+        let kappa = credential_kappa(
+            credential.verification_key,
+            credential.theta,
+            credential.public_attributes,
+        )?;
+
+        if bool::from(credential.theta.credential.0.is_identity()) {
+            return None;
+        }
+
+        if !credential
+            .theta
+            .verify_proof(params, credential.verification_key)
+        {
+            return None;
+        }
+
+        Some(PreparedCredential {
+            theta: credential.theta,
+            kappa: G2Prepared::from(kappa.to_affine()),
+        })
+    }
+}
+
+fn batch_check_pairings<'a, 'b: 'a, I>(params: &Parameters, credentials: I) -> bool
+where
+    I: IntoIterator<Item = &'a PreparedCredential<'b>>,
+{
+    let mut miller_terms = Vec::new();
+    let mut combined_s = G1Projective::identity();
+
+    for credential in credentials {
+        let r = params.random_scalar();
+        combined_s += credential.theta.credential.1 * r;
+        miller_terms.push((
+            (credential.theta.credential.0 * r).to_affine(),
+            &credential.kappa,
+        ));
+    }
+
+    if miller_terms.is_empty() {
+        return true;
+    }
+
+    let combined_s = combined_s.neg().to_affine();
+    let terms = miller_terms
+        .iter()
+        .map(|(g1, g2)| (g1, *g2))
+        .chain(std::iter::once((&combined_s, params.prepared_miller_g2())))
+        .collect::<Vec<_>>();
+
+    multi_miller_loop(&terms)
+        .final_exponentiation()
+        .is_identity()
+        .into()
+}
+
 // Used in tests only
 #[cfg(test)]
 pub fn verify(
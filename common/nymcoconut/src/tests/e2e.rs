@@ -3,8 +3,9 @@
 
 use crate::tests::helpers::tests::generate_dkg_keys;
 use crate::{
-    aggregate_verification_keys, setup, tests::helpers::*, ttp_keygen, verify_credential,
-    CoconutError, VerificationKey,
+    aggregate_verification_keys, batch_verify_credentials, find_invalid_credentials, setup,
+    tests::helpers::*, ttp_keygen, verify_credential, BatchedCredential, CoconutError,
+    VerificationKey,
 };
 
 #[test]
@@ -81,3 +82,59 @@ fn dkg() -> Result<(), CoconutError> {
 
     Ok(())
 }
+
+#[test]
+fn batch_verification() -> Result<(), CoconutError> {
+    let params = setup(4)?;
+    let node_indices = vec![1u64, 2, 3];
+
+    let coconut_keypairs = ttp_keygen(&params, 2, 3)?;
+    let verification_keys: Vec<VerificationKey> = coconut_keypairs
+        .iter()
+        .map(|keypair| keypair.verification_key())
+        .collect();
+    let verification_key = aggregate_verification_keys(&verification_keys, Some(&node_indices))?;
+
+    let public_attributes = (0..4)
+        .map(|_| params.n_random_scalars(2))
+        .collect::<Vec<_>>();
+    let thetas = public_attributes
+        .iter()
+        .map(|attributes| {
+            theta_from_keys_and_attributes(&params, &coconut_keypairs, &node_indices, attributes)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let batch = thetas
+        .iter()
+        .zip(public_attributes.iter())
+        .map(|(theta, attributes)| BatchedCredential::new(&verification_key, theta, attributes))
+        .collect::<Vec<_>>();
+
+    assert!(batch_verify_credentials(&params, &batch));
+    assert!(batch_verify_credentials(&params, &[]));
+    assert!(find_invalid_credentials(&params, &batch).is_empty());
+
+    // swap public attributes of two credentials so that both of them become invalid
+    let mut invalid_batch = batch.clone();
+    invalid_batch[1].public_attributes = &public_attributes[3][..];
+    invalid_batch[3].public_attributes = &public_attributes[1][..];
+
+    assert!(!batch_verify_credentials(&params, &invalid_batch));
+    assert_eq!(
+        find_invalid_credentials(&params, &invalid_batch),
+        vec![1, 3]
+    );
+
+    // a credential with an invalid proof of knowledge is rejected before any pairing is checked
+    let mut mixed_batch = invalid_batch.clone();
+    mixed_batch[0].verification_key = &verification_keys[0];
+
+    assert!(!batch_verify_credentials(&params, &mixed_batch));
+    assert_eq!(
+        find_invalid_credentials(&params, &mixed_batch),
+        vec![0, 1, 3]
+    );
+
+    Ok(())
+}
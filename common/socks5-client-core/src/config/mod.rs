@@ -1,6 +1,7 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use nym_client_core::client::name_resolver::{Destination, NameResolutionError};
 pub use nym_client_core::config::Config as BaseClientConfig;
 use nym_config::defaults::DEFAULT_SOCKS5_LISTENING_PORT;
 use nym_config::OptionalSet;
@@ -8,6 +9,7 @@ use nym_sphinx::addressing::clients::Recipient;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::time::Duration;
 
 pub use nym_service_providers_common::interface::ProviderInterfaceVersion;
 pub use nym_socks5_requests::Socks5ProtocolVersion;
@@ -15,6 +17,12 @@ pub use nym_socks5_requests::Socks5ProtocolVersion;
 const DEFAULT_CONNECTION_START_SURBS: u32 = 20;
 const DEFAULT_PER_REQUEST_SURBS: u32 = 3;
 
+const DEFAULT_HTTP_PROXY_LISTENING_PORT: u16 = 1081;
+
+const DEFAULT_PROVIDER_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_PROVIDER_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_MAX_FAILED_HEALTH_CHECKS: u32 = 2;
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -51,6 +59,16 @@ impl Config {
         self
     }
 
+    pub fn with_http_proxy(mut self, enabled: bool) -> Self {
+        self.socks5.http_proxy.enabled = enabled;
+        self
+    }
+
+    pub fn with_http_proxy_port(mut self, port: u16) -> Self {
+        self.socks5.http_proxy.listening_port = port;
+        self
+    }
+
     // poor man's 'builder' method
     pub fn with_base<F, T>(mut self, f: F, val: T) -> Self
     where
@@ -103,6 +121,7 @@ pub struct Socks5 {
     pub listening_port: u16,
 
     /// The mix address of the provider to which all requests are going to be sent.
+    /// It can also be a name registered in the name service, which is resolved on startup.
     pub provider_mix_address: String,
 
     /// The version of the 'service provider' this client is going to use in its communication with the
@@ -122,6 +141,24 @@ pub struct Socks5 {
     #[serde(default)]
     pub send_anonymously: bool,
 
+    /// Mix addresses of additional providers that can be used for new connections alongside,
+    /// or instead of, the provider specified by `provider_mix_address`.
+    #[serde(default)]
+    pub additional_providers: Vec<String>,
+
+    /// Specifies whether the network requesters announced in the service provider directory contract
+    /// should also be considered for new connections.
+    #[serde(default)]
+    pub use_provider_directory: bool,
+
+    /// Strategy used for choosing the provider for each new connection.
+    #[serde(default)]
+    pub provider_selection: ProviderSelectionStrategy,
+
+    /// Settings of the optional HTTP proxy listener running alongside the socks listener.
+    #[serde(default)]
+    pub http_proxy: HttpProxy,
+
     #[serde(default)]
     pub socks5_debug: Socks5Debug,
 }
@@ -134,24 +171,150 @@ impl Socks5 {
             provider_interface_version: ProviderInterfaceVersion::Legacy,
             socks5_protocol_version: Socks5ProtocolVersion::Legacy,
             send_anonymously: false,
+            additional_providers: Vec::new(),
+            use_provider_directory: false,
+            provider_selection: Default::default(),
+            http_proxy: Default::default(),
             socks5_debug: Default::default(),
         }
     }
 
+    pub fn with_additional_providers<S: Into<String>>(mut self, providers: Vec<S>) -> Self {
+        self.additional_providers = providers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn with_provider_directory(mut self, use_provider_directory: bool) -> Self {
+        self.use_provider_directory = use_provider_directory;
+        self
+    }
+
+    pub fn with_provider_selection(
+        mut self,
+        provider_selection: ProviderSelectionStrategy,
+    ) -> Self {
+        self.provider_selection = provider_selection;
+        self
+    }
+
+    /// Returns the address of the primary provider.
+    /// Note: this panics if the provider has been specified with a registered name rather than
+    /// an explicit address. Use `get_provider_destinations` alongside a `NameResolver` instead.
     pub fn get_provider_mix_address(&self) -> Recipient {
         Recipient::try_from_base58_string(&self.provider_mix_address)
             .expect("malformed provider address")
     }
+
+    /// Returns addresses of all configured providers, starting with the primary one.
+    /// Note: this panics if any provider has been specified with a registered name rather than
+    /// an explicit address. Use `get_provider_destinations` alongside a `NameResolver` instead.
+    pub fn get_all_provider_mix_addresses(&self) -> Vec<Recipient> {
+        let mut providers = vec![self.get_provider_mix_address()];
+        for raw in &self.additional_providers {
+            let provider =
+                Recipient::try_from_base58_string(raw).expect("malformed provider address");
+            if !providers.contains(&provider) {
+                providers.push(provider)
+            }
+        }
+        providers
+    }
+
+    /// Returns all configured providers, starting with the primary one, which might either be
+    /// explicit nym addresses or names registered in the name service.
+    pub fn get_provider_destinations(&self) -> Result<Vec<Destination>, NameResolutionError> {
+        let mut providers = vec![self.provider_mix_address.parse::<Destination>()?];
+        for raw in &self.additional_providers {
+            let provider = raw.parse()?;
+            if !providers.contains(&provider) {
+                providers.push(provider)
+            }
+        }
+        Ok(providers)
+    }
+}
+
+/// Strategy used by the client for choosing the provider for each new connection.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderSelectionStrategy {
+    /// Always use the first healthy provider, in the order they were specified in.
+    /// The remaining ones are only used if it becomes unavailable.
+    #[default]
+    Failover,
+
+    /// Distribute new connections evenly across all healthy providers.
+    RoundRobin,
+
+    /// Choose a random healthy provider for each new connection.
+    Random,
+}
+
+impl std::fmt::Display for ProviderSelectionStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProviderSelectionStrategy::Failover => write!(f, "failover"),
+            ProviderSelectionStrategy::RoundRobin => write!(f, "round_robin"),
+            ProviderSelectionStrategy::Random => write!(f, "random"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpProxy {
+    /// Specifies whether the client should also accept HTTP proxy connections, i.e. `CONNECT` tunnels
+    /// and plain HTTP requests, for applications that don't support socks.
+    pub enabled: bool,
+
+    /// The port on which the client will be listening for incoming HTTP proxy requests.
+    pub listening_port: u16,
+
+    /// Username the HTTP proxy clients have to present using basic authentication.
+    /// If empty, no authentication is required.
+    pub username: String,
+
+    /// Password the HTTP proxy clients have to present alongside the `username`.
+    pub password: String,
+}
+
+impl HttpProxy {
+    pub fn requires_authentication(&self) -> bool {
+        !self.username.is_empty()
+    }
+}
+
+impl Default for HttpProxy {
+    fn default() -> Self {
+        HttpProxy {
+            enabled: false,
+            listening_port: DEFAULT_HTTP_PROXY_LISTENING_PORT,
+            username: String::new(),
+            password: String::new(),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
-#[serde(deny_unknown_fields)]
+#[serde(default, deny_unknown_fields)]
 pub struct Socks5Debug {
     /// Number of reply SURBs attached to each `Request::Connect` message.
     pub connection_start_surbs: u32,
 
     /// Number of reply SURBs attached to each `Request::Send` message.
     pub per_request_surbs: u32,
+
+    /// Defines how often the providers are going to be probed with health control requests.
+    #[serde(with = "humantime_serde")]
+    pub provider_health_check_interval: Duration,
+
+    /// Maximum amount of time to wait for the response to the health control request.
+    #[serde(with = "humantime_serde")]
+    pub provider_health_check_timeout: Duration,
+
+    /// Number of consecutive failed health checks after which the provider is considered unhealthy
+    /// and gets excluded from handling new connections.
+    pub max_failed_health_checks: u32,
 }
 
 impl Default for Socks5Debug {
@@ -159,6 +322,9 @@ impl Default for Socks5Debug {
         Socks5Debug {
             connection_start_surbs: DEFAULT_CONNECTION_START_SURBS,
             per_request_surbs: DEFAULT_PER_REQUEST_SURBS,
+            provider_health_check_interval: DEFAULT_PROVIDER_HEALTH_CHECK_INTERVAL,
+            provider_health_check_timeout: DEFAULT_PROVIDER_HEALTH_CHECK_TIMEOUT,
+            max_failed_health_checks: DEFAULT_MAX_FAILED_HEALTH_CHECKS,
         }
     }
 }
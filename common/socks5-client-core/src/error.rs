@@ -1,4 +1,5 @@
 use crate::socks::types::SocksProxyError;
+use nym_client_core::client::name_resolver::NameResolutionError;
 use nym_client_core::error::ClientCoreError;
 use nym_socks5_requests::{ConnectionError, ConnectionId};
 
@@ -18,6 +19,35 @@ pub enum Socks5ClientCoreError {
         connection_id: ConnectionId,
         error: String,
     },
+
+    #[error("the service provider did not respond to the control request within {timeout:?}")]
+    ControlRequestTimeout { timeout: std::time::Duration },
+
+    #[error("received an unexpected response to the control request: {response}")]
+    UnexpectedControlResponse { response: String },
+
+    #[error("the service provider failed to handle the control request: {message}")]
+    ProviderControlError { message: String },
+
+    #[error("the provider control task is not running")]
+    ProviderControlUnavailable,
+
+    #[error("the policy of the service provider has changed while it was being retrieved")]
+    ProviderPolicyChanged,
+
+    #[error("failed to resolve the service provider address: {source}")]
+    ProviderResolutionFailure {
+        #[from]
+        source: NameResolutionError,
+    },
+
+    #[error("validator client error: {0}")]
+    ValidatorClientError(#[from] nym_validator_client::ValidatorClientError),
+
+    #[error("failed to query the service provider directory: {source}")]
+    ProviderDirectoryQueryFailure {
+        source: nym_validator_client::nyxd::error::NyxdError,
+    },
 }
 
 impl From<ConnectionError> for Socks5ClientCoreError {
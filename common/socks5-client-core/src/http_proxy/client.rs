@@ -0,0 +1,255 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::request::{read_request_head, ProxyRequest, RequestHead};
+use super::HttpProxyError;
+use crate::socks::authentication::User;
+use crate::socks::client;
+use crate::socks::provider_pool::ProviderPool;
+use futures::channel::mpsc;
+use log::*;
+use nym_client_core::client::inbound_messages::InputMessageSender;
+use nym_socks5_proxy_helpers::connection_controller::{
+    ConnectionReceiver, ControllerCommand, ControllerSender,
+};
+use nym_socks5_proxy_helpers::proxy_runner::ProxyRunner;
+use nym_socks5_requests::{ConnectionId, RemoteAddress};
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::params::PacketType;
+use nym_task::connections::LaneQueueLengths;
+use nym_task::TaskClient;
+use rand::RngCore;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+
+const CONNECTION_ESTABLISHED: &[u8] = b"HTTP/1.1 200 Connection established\r\n\r\n";
+
+/// An application connecting to the HTTP proxy server, because it wants to make
+/// a Nym-protected outbound request.
+pub(crate) struct HttpProxyClient {
+    config: client::Config,
+    controller_sender: ControllerSender,
+    // it's only `None` while the proxy is running
+    stream: Option<TcpStream>,
+    credentials: Option<User>,
+    input_sender: InputMessageSender,
+    connection_id: ConnectionId,
+    service_provider: Recipient,
+    provider_pool: ProviderPool,
+    self_address: Recipient,
+    started_proxy: bool,
+    lane_queue_lengths: LaneQueueLengths,
+    shutdown_listener: TaskClient,
+    packet_type: Option<PacketType>,
+}
+
+impl Drop for HttpProxyClient {
+    fn drop(&mut self) {
+        debug!(
+            "HTTP proxy connection {} is getting closed",
+            self.connection_id
+        );
+        // if we never managed to start a proxy, the entry will not exist in the controller
+        if self.started_proxy {
+            self.provider_pool
+                .release_connection(self.service_provider, self.connection_id);
+            self.controller_sender
+                .unbounded_send(ControllerCommand::Remove {
+                    connection_id: self.connection_id,
+                })
+                .unwrap();
+        }
+    }
+}
+
+impl HttpProxyClient {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        config: client::Config,
+        stream: TcpStream,
+        credentials: Option<User>,
+        input_sender: InputMessageSender,
+        provider_pool: ProviderPool,
+        controller_sender: ControllerSender,
+        self_address: Recipient,
+        lane_queue_lengths: LaneQueueLengths,
+        mut shutdown_listener: TaskClient,
+        packet_type: Option<PacketType>,
+    ) -> Self {
+        // If this task fails and exits, we don't want to send shutdown signal
+        shutdown_listener.mark_as_success();
+
+        HttpProxyClient {
+            config,
+            controller_sender,
+            stream: Some(stream),
+            credentials,
+            input_sender,
+            connection_id: rand::rngs::OsRng.next_u64(),
+            service_provider: provider_pool.select(),
+            provider_pool,
+            self_address,
+            started_proxy: false,
+            lane_queue_lengths,
+            shutdown_listener,
+            packet_type,
+        }
+    }
+
+    fn stream(&mut self) -> &mut TcpStream {
+        self.stream
+            .as_mut()
+            .expect("the stream is being used to run the proxy")
+    }
+
+    /// Sends the error response back to the application.
+    pub(crate) async fn send_error(&mut self, err: &HttpProxyError) -> Result<(), HttpProxyError> {
+        let (code, reason) = err.status();
+        let mut response =
+            format!("HTTP/1.1 {code} {reason}\r\nContent-Length: 0\r\nConnection: close\r\n");
+        if matches!(err, HttpProxyError::AuthenticationRequired) {
+            response.push_str("Proxy-Authenticate: Basic realm=\"nym\"\r\n");
+        }
+        response.push_str("\r\n");
+
+        self.stream()
+            .write_all(response.as_bytes())
+            .await
+            .map_err(|source| HttpProxyError::SocketWriteError { source })
+    }
+
+    /// Shutdown the `TcpStream` to the application and end the session
+    pub(crate) async fn shutdown(&mut self) -> Result<(), HttpProxyError> {
+        self.stream()
+            .shutdown()
+            .await
+            .map_err(|source| HttpProxyError::SocketShutdownFailure { source })?;
+        self.shutdown_listener.mark_as_success();
+        Ok(())
+    }
+
+    fn authenticate(&self, head: &RequestHead) -> Result<(), HttpProxyError> {
+        let Some(expected) = &self.credentials else {
+            return Ok(());
+        };
+        match head.proxy_credentials() {
+            Some(user) if &user == expected => Ok(()),
+            _ => Err(HttpProxyError::AuthenticationRequired),
+        }
+    }
+
+    /// Reads the request of the application and, if it's valid, starts proxying the data
+    /// between the application and the remote via the service provider.
+    pub(crate) async fn run(&mut self) -> Result<(), HttpProxyError> {
+        debug!(
+            "New HTTP proxy connection from: {}",
+            self.stream()
+                .peer_addr()
+                .map_err(|source| HttpProxyError::PeerAddrExtractionFailure { source })?
+                .ip()
+        );
+
+        let (raw_head, remaining) = read_request_head(self.stream()).await?;
+        let head = RequestHead::parse(&raw_head)?;
+        self.authenticate(&head)?;
+
+        let (remote_address, initial_data) = match head.into_proxy_request()? {
+            ProxyRequest::Connect { target } => {
+                trace!("Establishing tunnel to: {target}");
+                self.stream()
+                    .write_all(CONNECTION_ESTABLISHED)
+                    .await
+                    .map_err(|source| HttpProxyError::SocketWriteError { source })?;
+                (target, remaining)
+            }
+            ProxyRequest::Forward {
+                target,
+                mut request_head,
+            } => {
+                trace!("Forwarding plain HTTP request to: {target}");
+                request_head.extend_from_slice(&remaining);
+                (target, request_head)
+            }
+        };
+
+        // setup for receiving from the mixnet
+        let (mix_sender, mix_receiver) = mpsc::unbounded();
+
+        self.started_proxy = true;
+        self.provider_pool
+            .register_connection(self.service_provider, self.connection_id);
+        self.controller_sender
+            .unbounded_send(ControllerCommand::Insert {
+                connection_id: self.connection_id,
+                connection_sender: mix_sender,
+            })
+            .unwrap();
+
+        info!(
+            "Starting HTTP proxy for {} (id: {})",
+            remote_address, self.connection_id
+        );
+        self.run_proxy(mix_receiver, remote_address.clone(), initial_data)
+            .await;
+        info!(
+            "HTTP proxy for {} is finished (id: {})",
+            remote_address, self.connection_id
+        );
+
+        Ok(())
+    }
+
+    async fn run_proxy(
+        &mut self,
+        conn_receiver: ConnectionReceiver,
+        remote_address: RemoteAddress,
+        initial_data: Vec<u8>,
+    ) {
+        let input_message = self.config.connect_message(
+            self.service_provider,
+            self.connection_id,
+            remote_address.clone(),
+            self.self_address,
+            self.packet_type,
+        );
+        self.input_sender
+            .send(input_message)
+            .await
+            .expect("InputMessageReceiver has stopped receiving!");
+
+        let stream = self.stream.take().unwrap();
+        let peer_addr = match stream.peer_addr() {
+            Ok(peer_addr) => peer_addr,
+            Err(err) => {
+                log::error!("Unable to extract the remote peer address: {err}");
+                self.stream = Some(stream);
+                return;
+            }
+        };
+
+        let adapter_fn = self
+            .config
+            .send_message_adapter(self.service_provider, self.packet_type);
+
+        let mut proxy_runner = ProxyRunner::new(
+            stream,
+            peer_addr.to_string(),
+            remote_address,
+            conn_receiver,
+            self.input_sender.clone(),
+            // FIXME: this does NOT include overhead due to acks or chunking
+            // (so actual true plaintext is smaller)
+            self.config.biggest_packet_size().plaintext_size(),
+            self.connection_id,
+            Some(self.lane_queue_lengths.clone()),
+            self.shutdown_listener.clone(),
+        );
+        if !initial_data.is_empty() {
+            proxy_runner = proxy_runner.with_initial_data(initial_data);
+        }
+
+        let (stream, _) = proxy_runner.run(adapter_fn).await.into_inner();
+        // recover stream from the proxy
+        self.stream = Some(stream)
+    }
+}
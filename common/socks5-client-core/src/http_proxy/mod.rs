@@ -0,0 +1,74 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! HTTP proxy front-end for applications that don't speak socks. It supports both `CONNECT` tunnels
+//! and plain HTTP requests using the absolute-form URIs and sends the traffic through the same
+//! service providers as the socks listener.
+
+#![forbid(unsafe_code)]
+
+use thiserror::Error;
+
+pub(crate) mod client;
+mod request;
+pub mod server;
+
+#[derive(Error, Debug)]
+pub enum HttpProxyError {
+    #[error("failed to write to the socket: {source}")]
+    SocketWriteError {
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to read from the socket: {source}")]
+    SocketReadError {
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to shutdown underlying socket stream: {source}")]
+    SocketShutdownFailure {
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("failed to extract ip address of the connected peer: {source}")]
+    PeerAddrExtractionFailure {
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("the connection got closed before the full request was received")]
+    IncompleteRequest,
+
+    #[error("the request head exceeds the maximum allowed size of {max} bytes")]
+    RequestHeadTooLarge { max: usize },
+
+    #[error("the received request is malformed: {reason}")]
+    MalformedRequest { reason: String },
+
+    #[error("'{scheme}' requests can only be proxied using CONNECT tunnels")]
+    UnsupportedScheme { scheme: String },
+
+    #[error("the client has not provided valid proxy credentials")]
+    AuthenticationRequired,
+}
+
+impl HttpProxyError {
+    pub(crate) fn malformed<S: Into<String>>(reason: S) -> Self {
+        HttpProxyError::MalformedRequest {
+            reason: reason.into(),
+        }
+    }
+
+    /// Status code and the reason phrase that should be sent back to the client upon this error.
+    pub(crate) fn status(&self) -> (u16, &'static str) {
+        match self {
+            HttpProxyError::RequestHeadTooLarge { .. } => (431, "Request Header Fields Too Large"),
+            HttpProxyError::UnsupportedScheme { .. } => (501, "Not Implemented"),
+            HttpProxyError::AuthenticationRequired => (407, "Proxy Authentication Required"),
+            _ => (400, "Bad Request"),
+        }
+    }
+}
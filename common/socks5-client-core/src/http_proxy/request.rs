@@ -0,0 +1,363 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::HttpProxyError;
+use crate::socks::authentication::User;
+use nym_socks5_requests::RemoteAddress;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Maximum size of the request line alongside all the headers we're willing to buffer.
+pub(crate) const MAX_REQUEST_HEAD_SIZE: usize = 16 * 1024;
+
+const HEAD_TERMINATOR: &[u8] = b"\r\n\r\n";
+const DEFAULT_HTTP_PORT: u16 = 80;
+
+// headers that are only meaningful for the hop between the application and this proxy
+const HOP_BY_HOP_HEADERS: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authorization",
+    "proxy-connection",
+];
+
+/// Reads the request line and the headers from the stream. Returns the raw request head
+/// alongside any additional bytes, i.e. the beginning of the body, that were read in the process.
+pub(crate) async fn read_request_head<R>(
+    reader: &mut R,
+) -> Result<(Vec<u8>, Vec<u8>), HttpProxyError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+    loop {
+        let read = reader
+            .read(&mut chunk)
+            .await
+            .map_err(|source| HttpProxyError::SocketReadError { source })?;
+        if read == 0 {
+            return Err(HttpProxyError::IncompleteRequest);
+        }
+
+        // the terminator might have been split between the reads
+        let search_start = buf.len().saturating_sub(HEAD_TERMINATOR.len() - 1);
+        buf.extend_from_slice(&chunk[..read]);
+
+        if let Some(pos) = buf[search_start..]
+            .windows(HEAD_TERMINATOR.len())
+            .position(|w| w == HEAD_TERMINATOR)
+        {
+            let head_end = search_start + pos + HEAD_TERMINATOR.len();
+            if head_end > MAX_REQUEST_HEAD_SIZE {
+                break;
+            }
+            let remaining = buf.split_off(head_end);
+            return Ok((buf, remaining));
+        }
+
+        if buf.len() > MAX_REQUEST_HEAD_SIZE {
+            break;
+        }
+    }
+
+    Err(HttpProxyError::RequestHeadTooLarge {
+        max: MAX_REQUEST_HEAD_SIZE,
+    })
+}
+
+/// The action requested by the application connected to the proxy.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ProxyRequest {
+    /// Open a raw tunnel to the target, i.e. `CONNECT host:port`.
+    Connect { target: RemoteAddress },
+
+    /// Forward a plain HTTP request to the target. The request head has already been rewritten
+    /// into the origin-form that should be sent to the remote server.
+    Forward {
+        target: RemoteAddress,
+        request_head: Vec<u8>,
+    },
+}
+
+#[derive(Debug)]
+pub(crate) struct RequestHead {
+    method: String,
+    uri: String,
+    version: String,
+    headers: Vec<(String, String)>,
+}
+
+impl RequestHead {
+    pub(crate) fn parse(raw: &[u8]) -> Result<Self, HttpProxyError> {
+        let raw = std::str::from_utf8(raw)
+            .map_err(|_| HttpProxyError::malformed("the request head is not valid utf8"))?;
+        let mut lines = raw.split("\r\n");
+
+        let request_line = lines
+            .next()
+            .ok_or_else(|| HttpProxyError::malformed("missing request line"))?;
+        let mut parts = request_line.split(' ');
+        let (Some(method), Some(uri), Some(version), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(HttpProxyError::malformed(format!(
+                "invalid request line '{request_line}'"
+            )));
+        };
+        if method.is_empty() || uri.is_empty() || !version.starts_with("HTTP/") {
+            return Err(HttpProxyError::malformed(format!(
+                "invalid request line '{request_line}'"
+            )));
+        }
+
+        let mut headers = Vec::new();
+        for line in lines.take_while(|line| !line.is_empty()) {
+            let (name, value) = line
+                .split_once(':')
+                .ok_or_else(|| HttpProxyError::malformed(format!("invalid header '{line}'")))?;
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+
+        Ok(RequestHead {
+            method: method.to_string(),
+            uri: uri.to_string(),
+            version: version.to_string(),
+            headers,
+        })
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Extracts the credentials sent using the basic authentication scheme, if any.
+    pub(crate) fn proxy_credentials(&self) -> Option<User> {
+        let value = self.header("proxy-authorization")?;
+        let (scheme, encoded) = value.split_once(' ')?;
+        if !scheme.eq_ignore_ascii_case("basic") {
+            return None;
+        }
+        let decoded = String::from_utf8(base64::decode(encoded.trim()).ok()?).ok()?;
+        let (username, password) = decoded.split_once(':')?;
+        Some(User {
+            username: username.to_string(),
+            password: password.to_string(),
+        })
+    }
+
+    pub(crate) fn into_proxy_request(self) -> Result<ProxyRequest, HttpProxyError> {
+        if self.method.eq_ignore_ascii_case("CONNECT") {
+            let (host, port) = split_authority(&self.uri)?;
+            let port = port
+                .ok_or_else(|| HttpProxyError::malformed("CONNECT target must specify the port"))?;
+            return Ok(ProxyRequest::Connect {
+                target: format!("{host}:{port}"),
+            });
+        }
+
+        let (scheme, rest) = self.uri.split_once("://").ok_or_else(|| {
+            HttpProxyError::malformed("requests sent to a proxy must use the absolute uri")
+        })?;
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(HttpProxyError::UnsupportedScheme {
+                scheme: scheme.to_string(),
+            });
+        }
+
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(idx) if rest.as_bytes()[idx] == b'?' => {
+                (&rest[..idx], format!("/{}", &rest[idx..]))
+            }
+            Some(idx) => (&rest[..idx], rest[idx..].to_string()),
+            None => (rest, "/".to_string()),
+        };
+        // we don't want to forward any credentials embedded in the uri
+        let authority = authority.rsplit('@').next().unwrap_or(authority);
+        let (host, port) = split_authority(authority)?;
+        let port = port.unwrap_or(DEFAULT_HTTP_PORT);
+
+        let mut request_head = format!("{} {} {}\r\n", self.method, path, self.version);
+        if self.header("host").is_none() {
+            request_head.push_str(&format!("Host: {authority}\r\n"));
+        }
+        for (name, value) in &self.headers {
+            if !HOP_BY_HOP_HEADERS.contains(&name.to_ascii_lowercase().as_str()) {
+                request_head.push_str(&format!("{name}: {value}\r\n"));
+            }
+        }
+        // each proxied connection is bound to a single remote, so we can't reuse it for subsequent
+        // requests that could be sent to completely different hosts
+        request_head.push_str("Connection: close\r\n\r\n");
+
+        Ok(ProxyRequest::Forward {
+            target: format!("{host}:{port}"),
+            request_head: request_head.into_bytes(),
+        })
+    }
+}
+
+fn split_authority(authority: &str) -> Result<(&str, Option<u16>), HttpProxyError> {
+    let invalid = || HttpProxyError::malformed(format!("invalid target '{authority}'"));
+
+    let (host, port) = if authority.starts_with('[') {
+        // ipv6 literal
+        let end = authority.find(']').ok_or_else(invalid)?;
+        let port = match &authority[end + 1..] {
+            "" => None,
+            port => Some(port.strip_prefix(':').ok_or_else(invalid)?),
+        };
+        (&authority[..=end], port)
+    } else {
+        match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        }
+    };
+
+    if host.is_empty() {
+        return Err(invalid());
+    }
+    let port = port
+        .map(|port| port.parse().map_err(|_| invalid()))
+        .transpose()?;
+    Ok((host, port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(raw: &str) -> ProxyRequest {
+        RequestHead::parse(raw.as_bytes())
+            .unwrap()
+            .into_proxy_request()
+            .unwrap()
+    }
+
+    #[test]
+    fn parsing_connect_request() {
+        let req = parse("CONNECT example.com:443 HTTP/1.1\r\nHost: example.com:443\r\n\r\n");
+        assert_eq!(
+            req,
+            ProxyRequest::Connect {
+                target: "example.com:443".to_string()
+            }
+        );
+
+        let req = parse("CONNECT [::1]:8443 HTTP/1.1\r\n\r\n");
+        assert_eq!(
+            req,
+            ProxyRequest::Connect {
+                target: "[::1]:8443".to_string()
+            }
+        );
+
+        let err = RequestHead::parse(b"CONNECT example.com HTTP/1.1\r\n\r\n")
+            .unwrap()
+            .into_proxy_request()
+            .unwrap_err();
+        assert!(matches!(err, HttpProxyError::MalformedRequest { .. }))
+    }
+
+    #[test]
+    fn absolute_uri_request_gets_rewritten() {
+        let req = parse(
+            "GET http://example.com/foo?bar=baz HTTP/1.1\r\nHost: example.com\r\nProxy-Connection: keep-alive\r\nProxy-Authorization: Basic Zm9vOmJhcg==\r\nAccept: */*\r\n\r\n",
+        );
+        assert_eq!(
+            req,
+            ProxyRequest::Forward {
+                target: "example.com:80".to_string(),
+                request_head: b"GET /foo?bar=baz HTTP/1.1\r\nHost: example.com\r\nAccept: */*\r\nConnection: close\r\n\r\n".to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn missing_path_and_host_are_filled_in() {
+        let req = parse("GET http://example.com:8080 HTTP/1.0\r\n\r\n");
+        assert_eq!(
+            req,
+            ProxyRequest::Forward {
+                target: "example.com:8080".to_string(),
+                request_head:
+                    b"GET / HTTP/1.0\r\nHost: example.com:8080\r\nConnection: close\r\n\r\n"
+                        .to_vec(),
+            }
+        );
+
+        let req = parse("GET http://example.com?a=b HTTP/1.1\r\nHost: example.com\r\n\r\n");
+        assert_eq!(
+            req,
+            ProxyRequest::Forward {
+                target: "example.com:80".to_string(),
+                request_head:
+                    b"GET /?a=b HTTP/1.1\r\nHost: example.com\r\nConnection: close\r\n\r\n".to_vec(),
+            }
+        );
+    }
+
+    #[test]
+    fn non_http_and_relative_requests_are_rejected() {
+        let err = RequestHead::parse(b"GET https://example.com/ HTTP/1.1\r\n\r\n")
+            .unwrap()
+            .into_proxy_request()
+            .unwrap_err();
+        assert!(matches!(err, HttpProxyError::UnsupportedScheme { .. }));
+
+        let err = RequestHead::parse(b"GET /foo HTTP/1.1\r\n\r\n")
+            .unwrap()
+            .into_proxy_request()
+            .unwrap_err();
+        assert!(matches!(err, HttpProxyError::MalformedRequest { .. }));
+
+        assert!(RequestHead::parse(b"GET http://example.com/\r\n\r\n").is_err());
+    }
+
+    #[test]
+    fn extracting_basic_credentials() {
+        let head = RequestHead::parse(
+            b"CONNECT example.com:443 HTTP/1.1\r\nproxy-authorization: Basic Zm9vOmJhcjpiYXo=\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            head.proxy_credentials(),
+            Some(User {
+                username: "foo".to_string(),
+                password: "bar:baz".to_string(),
+            })
+        );
+
+        let head = RequestHead::parse(
+            b"CONNECT example.com:443 HTTP/1.1\r\nProxy-Authorization: Bearer foo\r\n\r\n",
+        )
+        .unwrap();
+        assert!(head.proxy_credentials().is_none());
+    }
+
+    #[tokio::test]
+    async fn reading_request_head_keeps_the_remaining_data() {
+        let raw = b"POST http://example.com/ HTTP/1.1\r\nContent-Length: 4\r\n\r\nbody".to_vec();
+        let (head, remaining) = read_request_head(&mut raw.as_slice()).await.unwrap();
+        assert_eq!(
+            head,
+            b"POST http://example.com/ HTTP/1.1\r\nContent-Length: 4\r\n\r\n"
+        );
+        assert_eq!(remaining, b"body");
+
+        let incomplete = b"GET http://example.com/ HTTP/1.1\r\n".to_vec();
+        assert!(matches!(
+            read_request_head(&mut incomplete.as_slice()).await,
+            Err(HttpProxyError::IncompleteRequest)
+        ));
+
+        let too_large = vec![b'a'; MAX_REQUEST_HEAD_SIZE + 10];
+        assert!(matches!(
+            read_request_head(&mut too_large.as_slice()).await,
+            Err(HttpProxyError::RequestHeadTooLarge { .. })
+        ));
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::client::HttpProxyClient;
+use crate::config;
+use crate::error::Socks5ClientCoreError;
+use crate::socks::authentication::User;
+use crate::socks::client;
+use crate::socks::provider_pool::ProviderPool;
+use log::*;
+use nym_client_core::client::inbound_messages::InputMessageSender;
+use nym_socks5_proxy_helpers::connection_controller::ControllerSender;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::params::PacketType;
+use nym_task::connections::LaneQueueLengths;
+use nym_task::TaskClient;
+use std::net::SocketAddr;
+use tap::TapFallible;
+use tokio::net::TcpListener;
+
+/// An HTTP proxy server that listens for connections. It shares the mixnet connection plumbing,
+/// i.e. the connection controller and the providers, with the socks server.
+pub struct NymHttpProxyServer {
+    listening_address: SocketAddr,
+    credentials: Option<User>,
+    provider_pool: ProviderPool,
+    self_address: Recipient,
+    client_config: client::Config,
+    lane_queue_lengths: LaneQueueLengths,
+    shutdown: TaskClient,
+    packet_type: PacketType,
+}
+
+impl NymHttpProxyServer {
+    pub(crate) fn new(
+        http_proxy_config: &config::HttpProxy,
+        provider_pool: ProviderPool,
+        self_address: Recipient,
+        lane_queue_lengths: LaneQueueLengths,
+        client_config: client::Config,
+        shutdown: TaskClient,
+        packet_type: PacketType,
+    ) -> Self {
+        // same as with the socks listener, we (presumably) ONLY want to listen locally
+        let ip = "127.0.0.1";
+        let port = http_proxy_config.listening_port;
+        info!("HTTP proxy listening on {}:{}", ip, port);
+
+        let credentials = http_proxy_config.requires_authentication().then(|| User {
+            username: http_proxy_config.username.clone(),
+            password: http_proxy_config.password.clone(),
+        });
+
+        NymHttpProxyServer {
+            listening_address: format!("{ip}:{port}").parse().unwrap(),
+            credentials,
+            provider_pool,
+            self_address,
+            client_config,
+            lane_queue_lengths,
+            shutdown,
+            packet_type,
+        }
+    }
+
+    /// Set up the listener and initiate connection handling when something
+    /// connects to the server.
+    pub(crate) async fn serve(
+        mut self,
+        input_sender: InputMessageSender,
+        controller_sender: ControllerSender,
+    ) -> Result<(), Socks5ClientCoreError> {
+        let listener = TcpListener::bind(self.listening_address)
+            .await
+            .tap_err(|err| log::error!("Failed to bind HTTP proxy to address: {err}"))?;
+        info!("Serving HTTP proxy connections...");
+
+        loop {
+            tokio::select! {
+                Ok((stream, _remote)) = listener.accept() => {
+                    let mut client = HttpProxyClient::new(
+                        self.client_config,
+                        stream,
+                        self.credentials.clone(),
+                        input_sender.clone(),
+                        self.provider_pool.clone(),
+                        controller_sender.clone(),
+                        self.self_address,
+                        self.lane_queue_lengths.clone(),
+                        self.shutdown.clone(),
+                        Some(self.packet_type)
+                    );
+
+                    tokio::spawn(async move {
+                        if let Err(err) = client.run().await {
+                            error!("HTTP proxy error: {err}");
+                            if client.send_error(&err).await.is_err() {
+                                warn!("Failed to send error response");
+                            };
+                            if client.shutdown().await.is_err() {
+                                warn!("Failed to shutdown TcpStream");
+                            };
+                        }
+                    });
+                },
+                _ = self.shutdown.recv() => {
+                    log::trace!("NymHttpProxyServer: Received shutdown");
+                    log::debug!("NymHttpProxyServer: Exiting");
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
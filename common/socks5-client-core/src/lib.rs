@@ -5,6 +5,8 @@ use crate::config::Config;
 use crate::error::Socks5ClientCoreError;
 use crate::socks::{
     authentication::{AuthenticationMethods, Authenticator, User},
+    provider_control::{ProviderControl, ProviderControlHandle},
+    provider_pool::ProviderPool,
     server::NymSocksServer,
 };
 use futures::channel::mpsc;
@@ -15,9 +17,11 @@ use nym_client_core::client::base_client::{
     non_wasm_helpers, BaseClientBuilder, ClientInput, ClientOutput, ClientState,
 };
 use nym_client_core::client::key_manager::persistence::KeyStore;
+use nym_client_core::client::name_resolver::{Destination, NameResolutionError, NameResolver};
 use nym_client_core::client::replies::reply_storage::ReplyStorageBackend;
 use nym_client_core::config::DebugConfig;
 use nym_credential_storage::storage::Storage as CredentialStorage;
+use nym_network_defaults::NymNetworkDetails;
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::params::PacketType;
 use nym_task::{TaskClient, TaskManager};
@@ -25,6 +29,7 @@ use std::error::Error;
 
 pub mod config;
 pub mod error;
+pub mod http_proxy;
 pub mod socks;
 
 // Channels used to control the main task from outside
@@ -43,6 +48,13 @@ pub struct StartedSocks5Client {
 
     /// Address of the started client
     pub address: Recipient,
+
+    /// Handle for sending control requests, such as capability or allow list queries,
+    /// to the service provider.
+    pub provider_control: ProviderControlHandle,
+
+    /// Service providers used by this client alongside their health status.
+    pub provider_pool: ProviderPool,
 }
 
 pub struct NymClient<S> {
@@ -65,9 +77,31 @@ where
         NymClient { config, storage }
     }
 
+    /// Resolves the addresses of all configured providers, starting with the primary one,
+    /// looking up any of them that were specified with a registered name.
+    pub async fn resolve_providers(
+        socks5_config: &config::Socks5,
+        name_resolver: &NameResolver,
+    ) -> Result<Vec<Recipient>, NameResolutionError> {
+        let mut providers = Vec::new();
+        for destination in socks5_config.get_provider_destinations()? {
+            let provider = name_resolver.resolve(&destination).await?;
+            if let Destination::Name(name) = &destination {
+                info!("resolved service provider '{name}' into {provider}");
+            }
+            if !providers.contains(&provider) {
+                providers.push(provider)
+            }
+        }
+        Ok(providers)
+    }
+
+    /// Starts the socks5 listener. `providers` must contain at least a single address and the
+    /// first one is going to be used as the primary provider.
     #[allow(clippy::too_many_arguments)]
     pub fn start_socks5_listener(
         socks5_config: &config::Socks5,
+        providers: Vec<Recipient>,
         base_debug: DebugConfig,
         client_input: ClientInput,
         client_output: ClientOutput,
@@ -75,7 +109,7 @@ where
         self_address: Recipient,
         shutdown: TaskClient,
         packet_type: PacketType,
-    ) {
+    ) -> (ProviderControlHandle, ProviderPool) {
         info!("Starting socks5 listener...");
         let auth_methods = vec![AuthenticationMethods::NoAuth as u8];
         let allowed_users: Vec<User> = Vec::new();
@@ -99,11 +133,34 @@ where
             .secondary_packet_size
             .unwrap_or(base_debug.traffic.primary_packet_size);
 
+        let (control_response_sender, control_response_receiver) = mpsc::unbounded();
+        let (mut provider_control, provider_control_handle) = ProviderControl::new(
+            input_sender.clone(),
+            providers[0],
+            socks5_config.provider_interface_version,
+            socks5_config.socks5_debug.connection_start_surbs,
+            Some(packet_type),
+            control_response_receiver,
+            shutdown.clone(),
+        );
+        tokio::spawn(async move {
+            provider_control.run().await;
+        });
+
+        // there's no point in probing the provider if we have nowhere else to go
+        let health_check = if providers.len() > 1 || socks5_config.use_provider_directory {
+            Some((&socks5_config.socks5_debug).into())
+        } else {
+            None
+        };
+        let provider_pool = ProviderPool::new(providers, socks5_config.provider_selection);
+
         let authenticator = Authenticator::new(auth_methods, allowed_users);
         let mut sphinx_socks = NymSocksServer::new(
             socks5_config.listening_port,
             authenticator,
-            socks5_config.get_provider_mix_address(),
+            provider_pool.clone(),
+            health_check,
             self_address,
             shared_lane_queue_lengths,
             socks::client::Config::new(
@@ -116,6 +173,9 @@ where
             shutdown.clone(),
             packet_type,
         );
+        if socks5_config.http_proxy.enabled {
+            sphinx_socks = sphinx_socks.with_http_proxy(&socks5_config.http_proxy);
+        }
         nym_task::spawn_with_report_error(
             async move {
                 sphinx_socks
@@ -123,11 +183,15 @@ where
                         input_sender,
                         received_buffer_request_sender,
                         connection_command_sender,
+                        control_response_sender,
+                        provider_control_handle.clone(),
                     )
                     .await
             },
             shutdown,
         );
+
+        (provider_control_handle, provider_pool)
     }
 
     /// blocking version of `start` method. Will run forever (or until SIGINT is sent)
@@ -186,6 +250,30 @@ where
         res
     }
 
+    async fn add_directory_providers(
+        config: &Config,
+        provider_pool: &ProviderPool,
+    ) -> Result<(), Socks5ClientCoreError> {
+        let details = NymNetworkDetails::new_from_env();
+        let mut client_config =
+            nym_validator_client::Config::try_from_nym_network_details(&details)?;
+        // overwrite env configuration with config URLs
+        if let (Some(nyxd_url), Some(nym_api_url)) = (
+            config.base.get_validator_endpoints().pop(),
+            config.base.get_nym_api_endpoints().pop(),
+        ) {
+            client_config = client_config.with_urls(nyxd_url, nym_api_url);
+        }
+
+        let providers = socks::provider_directory::query_network_requesters(client_config).await?;
+        info!(
+            "retrieved {} network requesters from the service provider directory",
+            providers.len()
+        );
+        provider_pool.extend(providers);
+        Ok(())
+    }
+
     pub async fn start(self) -> Result<StartedSocks5Client, Socks5ClientCoreError> {
         let (key_store, reply_storage_backend, credential_store) = self.storage.into_split();
 
@@ -213,10 +301,14 @@ where
         let client_output = started_client.client_output.register_consumer();
         let client_state = started_client.client_state;
 
+        let providers =
+            Self::resolve_providers(&self.config.socks5, &client_state.name_resolver).await?;
+
         info!("Running with {packet_type} packets",);
 
-        Self::start_socks5_listener(
+        let (provider_control, provider_pool) = Self::start_socks5_listener(
             &self.config.socks5,
+            providers,
             self.config.base.debug,
             client_input,
             client_output,
@@ -226,12 +318,20 @@ where
             packet_type,
         );
 
+        if self.config.socks5.use_provider_directory {
+            if let Err(err) = Self::add_directory_providers(&self.config, &provider_pool).await {
+                warn!("failed to retrieve providers from the service provider directory: {err}")
+            }
+        }
+
         info!("Client startup finished!");
         info!("The address of this client is: {self_address}");
 
         Ok(StartedSocks5Client {
             shutdown_handle: started_client.task_manager,
             address: self_address,
+            provider_control,
+            provider_pool,
         })
     }
 }
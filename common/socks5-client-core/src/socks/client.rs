@@ -1,6 +1,7 @@
 #![forbid(unsafe_code)]
 
 use super::authentication::{AuthenticationMethods, Authenticator, User};
+use super::provider_pool::ProviderPool;
 use super::request::{SocksCommand, SocksRequest};
 use super::types::{ResponseCodeV4, ResponseCodeV5, SocksProxyError};
 use super::{SocksVersion, RESERVED, SOCKS4_VERSION, SOCKS5_VERSION};
@@ -166,6 +167,91 @@ impl Config {
             provider_protocol: self.socks5_protocol_version,
         }
     }
+
+    pub(crate) fn biggest_packet_size(&self) -> PacketSize {
+        self.biggest_packet_size
+    }
+
+    /// Creates the message asking the service provider to open a new connection to the remote address.
+    pub(crate) fn connect_message(
+        &self,
+        provider: Recipient,
+        connection_id: ConnectionId,
+        remote_address: RemoteAddress,
+        self_address: Recipient,
+        packet_type: Option<PacketType>,
+    ) -> InputMessage {
+        let lane = TransmissionLane::ConnectionId(connection_id);
+        if self.use_surbs_for_responses {
+            let req = Socks5Request::new_connect(
+                self.socks5_protocol_version,
+                connection_id,
+                remote_address,
+                None,
+            );
+            let msg =
+                Socks5ProviderRequest::new_provider_data(self.provider_interface_version, req);
+            InputMessage::new_anonymous(
+                provider,
+                msg.into_bytes(),
+                self.connection_start_surbs,
+                lane,
+                packet_type,
+            )
+        } else {
+            let req = Socks5Request::new_connect(
+                self.socks5_protocol_version,
+                connection_id,
+                remote_address,
+                Some(self_address),
+            );
+            let msg =
+                Socks5ProviderRequest::new_provider_data(self.provider_interface_version, req);
+            InputMessage::new_regular(provider, msg.into_bytes(), lane, packet_type)
+        }
+    }
+
+    /// Creates the function used by the `ProxyRunner` for wrapping the data read from the local socket
+    /// into messages for the service provider.
+    pub(crate) fn send_message_adapter(
+        &self,
+        provider: Recipient,
+        packet_type: Option<PacketType>,
+    ) -> impl Fn(ConnectionId, Vec<u8>, bool) -> InputMessage + Send + Sync + 'static {
+        let anonymous = self.use_surbs_for_responses;
+        let per_request_surbs = self.per_request_surbs;
+        let request_version = self.request_version();
+
+        move |conn_id, read_data, socket_closed| {
+            let provider_request = Socks5Request::new_send(
+                request_version.provider_protocol,
+                conn_id,
+                read_data,
+                socket_closed,
+            );
+            let provider_message = Socks5ProviderRequest::new_provider_data(
+                request_version.provider_interface,
+                provider_request,
+            );
+            let lane = TransmissionLane::ConnectionId(conn_id);
+            if anonymous {
+                InputMessage::new_anonymous(
+                    provider,
+                    provider_message.into_bytes(),
+                    per_request_surbs,
+                    lane,
+                    packet_type,
+                )
+            } else {
+                InputMessage::new_regular(
+                    provider,
+                    provider_message.into_bytes(),
+                    lane,
+                    packet_type,
+                )
+            }
+        }
+    }
 }
 
 /// A client connecting to the Socks proxy server, because
@@ -182,6 +268,7 @@ pub(crate) struct SocksClient {
     input_sender: InputMessageSender,
     connection_id: ConnectionId,
     service_provider: Recipient,
+    provider_pool: ProviderPool,
     self_address: Recipient,
     started_proxy: bool,
     lane_queue_lengths: LaneQueueLengths,
@@ -194,6 +281,8 @@ impl Drop for SocksClient {
         debug!("Connection {} is getting closed", self.connection_id);
         // if we never managed to start a proxy, the entry will not exist in the controller
         if self.started_proxy {
+            self.provider_pool
+                .release_connection(self.service_provider, self.connection_id);
             self.controller_sender
                 .unbounded_send(ControllerCommand::Remove {
                     connection_id: self.connection_id,
@@ -210,7 +299,7 @@ impl SocksClient {
         stream: TcpStream,
         authenticator: Authenticator,
         input_sender: InputMessageSender,
-        service_provider: &Recipient,
+        provider_pool: ProviderPool,
         controller_sender: ControllerSender,
         self_address: &Recipient,
         lane_queue_lengths: LaneQueueLengths,
@@ -231,7 +320,8 @@ impl SocksClient {
             socks_version: None,
             authenticator,
             input_sender,
-            service_provider: *service_provider,
+            service_provider: provider_pool.select(),
+            provider_pool,
             self_address: *self_address,
             started_proxy: false,
             lane_queue_lengths,
@@ -337,45 +427,12 @@ impl SocksClient {
         self.handle_request().await
     }
 
-    async fn send_anonymous_connect_to_mixnet(&mut self, remote_address: RemoteAddress) {
-        // TODO: simplify by using `request_version`
-        let req = Socks5Request::new_connect(
-            self.config.socks5_protocol_version,
-            self.connection_id,
-            remote_address,
-            None,
-        );
-        let msg =
-            Socks5ProviderRequest::new_provider_data(self.config.provider_interface_version, req);
-
-        let input_message = InputMessage::new_anonymous(
+    async fn send_connect_to_mixnet(&mut self, remote_address: RemoteAddress) {
+        let input_message = self.config.connect_message(
             self.service_provider,
-            msg.into_bytes(),
-            self.config.connection_start_surbs,
-            TransmissionLane::ConnectionId(self.connection_id),
-            self.packet_type,
-        );
-        self.input_sender
-            .send(input_message)
-            .await
-            .expect("InputMessageReceiver has stopped receiving!");
-    }
-
-    async fn send_connect_to_mixnet_with_return_address(&mut self, remote_address: RemoteAddress) {
-        // TODO: simplify by using `request_version`
-        let req = Socks5Request::new_connect(
-            self.config.socks5_protocol_version,
             self.connection_id,
             remote_address,
-            Some(self.self_address),
-        );
-        let msg =
-            Socks5ProviderRequest::new_provider_data(self.config.provider_interface_version, req);
-
-        let input_message = InputMessage::new_regular(
-            self.service_provider,
-            msg.into_bytes(),
-            TransmissionLane::ConnectionId(self.connection_id),
+            self.self_address,
             self.packet_type,
         );
         self.input_sender
@@ -384,15 +441,6 @@ impl SocksClient {
             .expect("InputMessageReceiver has stopped receiving!");
     }
 
-    async fn send_connect_to_mixnet(&mut self, remote_address: RemoteAddress) {
-        if self.config.use_surbs_for_responses {
-            self.send_anonymous_connect_to_mixnet(remote_address).await
-        } else {
-            self.send_connect_to_mixnet_with_return_address(remote_address)
-                .await
-        }
-    }
-
     async fn run_proxy(&mut self, conn_receiver: ConnectionReceiver, remote_proxy_target: String) {
         self.send_connect_to_mixnet(remote_proxy_target.clone())
             .await;
@@ -409,12 +457,10 @@ impl SocksClient {
 
         let connection_id = self.connection_id;
         let input_sender = self.input_sender.clone();
-        let anonymous = self.config.use_surbs_for_responses;
-        let per_request_surbs = self.config.per_request_surbs;
-        let request_version = self.config.request_version();
+        let adapter_fn = self
+            .config
+            .send_message_adapter(self.service_provider, self.packet_type);
 
-        let recipient = self.service_provider;
-        let packet_type = self.packet_type;
         let (stream, _) = ProxyRunner::new(
             stream,
             local_stream_remote,
@@ -428,35 +474,7 @@ impl SocksClient {
             Some(self.lane_queue_lengths.clone()),
             self.shutdown_listener.clone(),
         )
-        .run(move |conn_id, read_data, socket_closed| {
-            let provider_request = Socks5Request::new_send(
-                request_version.provider_protocol,
-                conn_id,
-                read_data,
-                socket_closed,
-            );
-            let provider_message = Socks5ProviderRequest::new_provider_data(
-                request_version.provider_interface,
-                provider_request,
-            );
-            let lane = TransmissionLane::ConnectionId(conn_id);
-            if anonymous {
-                InputMessage::new_anonymous(
-                    recipient,
-                    provider_message.into_bytes(),
-                    per_request_surbs,
-                    lane,
-                    packet_type,
-                )
-            } else {
-                InputMessage::new_regular(
-                    recipient,
-                    provider_message.into_bytes(),
-                    lane,
-                    packet_type,
-                )
-            }
-        })
+        .run(adapter_fn)
         .await
         .into_inner();
         // recover stream from the proxy
@@ -492,6 +510,8 @@ impl SocksClient {
                 }
 
                 self.started_proxy = true;
+                self.provider_pool
+                    .register_connection(self.service_provider, self.connection_id);
                 self.controller_sender
                     .unbounded_send(ControllerCommand::Insert {
                         connection_id: self.connection_id,
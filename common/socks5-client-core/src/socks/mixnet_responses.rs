@@ -6,18 +6,20 @@ use nym_client_core::client::received_buffer::ReconstructedMessagesReceiver;
 use nym_client_core::client::received_buffer::{
     ReceivedBufferMessage, ReceivedBufferRequestSender,
 };
-use nym_service_providers_common::interface::{ControlResponse, ResponseContent};
+use nym_service_providers_common::interface::{ControlRequestId, ControlResponse, ResponseContent};
 use nym_socks5_proxy_helpers::connection_controller::ControllerSender;
 use nym_socks5_requests::{Socks5ProviderResponse, Socks5Response, Socks5ResponseContent};
 use nym_sphinx::receiver::ReconstructedMessage;
 use nym_task::TaskClient;
 
 use crate::error::Socks5ClientCoreError;
+use crate::socks::provider_control::ControlResponseSender;
 
 pub(crate) struct MixnetResponseListener {
     buffer_requester: ReceivedBufferRequestSender,
     mix_response_receiver: ReconstructedMessagesReceiver,
     controller_sender: ControllerSender,
+    control_response_sender: ControlResponseSender,
     shutdown: TaskClient,
 }
 
@@ -40,6 +42,7 @@ impl MixnetResponseListener {
     pub(crate) fn new(
         buffer_requester: ReceivedBufferRequestSender,
         controller_sender: ControllerSender,
+        control_response_sender: ControlResponseSender,
         shutdown: TaskClient,
     ) -> Self {
         let (mix_response_sender, mix_response_receiver) = mpsc::unbounded();
@@ -51,18 +54,25 @@ impl MixnetResponseListener {
             buffer_requester,
             mix_response_receiver,
             controller_sender,
+            control_response_sender,
             shutdown,
         }
     }
 
     fn on_control_response(
         &self,
+        request_id: Option<ControlRequestId>,
         control_response: ControlResponse,
     ) -> Result<(), Socks5ClientCoreError> {
-        error!("received a control response which we don't know how to handle yet!");
-        error!("got: {:?}", control_response);
-
-        // I guess we'd need another channel here to forward those to where they need to go
+        if let Err(err) = self
+            .control_response_sender
+            .unbounded_send((request_id, control_response))
+        {
+            warn!(
+                "received a control response but the provider control task has gone away: {:?}",
+                err.into_inner()
+            );
+        }
 
         Ok(())
     }
@@ -109,9 +119,10 @@ impl MixnetResponseListener {
                     response.interface_version
                 );
                 match response.content {
-                    ResponseContent::Control(control_response) => {
-                        self.on_control_response(control_response)
-                    }
+                    ResponseContent::Control {
+                        request_id,
+                        response,
+                    } => self.on_control_response(request_id, response),
                     ResponseContent::ProviderData(provider_response) => {
                         self.on_provider_data_response(provider_response)
                     }
@@ -7,6 +7,10 @@ use self::types::SocksProxyError;
 pub mod authentication;
 pub(crate) mod client;
 pub(crate) mod mixnet_responses;
+pub mod provider_control;
+pub mod provider_directory;
+mod provider_health;
+pub mod provider_pool;
 mod request;
 pub mod server;
 pub mod types;
@@ -0,0 +1,365 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::Socks5ClientCoreError;
+use futures::channel::{mpsc, oneshot};
+use futures::StreamExt;
+use log::*;
+use nym_client_core::client::inbound_messages::{InputMessage, InputMessageSender};
+use nym_service_providers_common::interface::{
+    ControlRequest, ControlRequestId, ControlResponse, PolicyPage, ProviderCapabilities,
+    ProviderInterfaceVersion, MAX_POLICY_PAGE_SIZE,
+};
+use nym_socks5_requests::Socks5ProviderRequest;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::params::PacketType;
+use nym_task::connections::TransmissionLane;
+use nym_task::TaskClient;
+use std::time::Duration;
+
+/// Maximum amount of time we're willing to wait for the service provider to respond to a control request.
+const CONTROL_REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Control response alongside the id of the request it was sent for, if the provider has included it.
+pub(crate) type IdentifiedControlResponse = (Option<ControlRequestId>, ControlResponse);
+
+pub(crate) type ControlResponseSender = mpsc::UnboundedSender<IdentifiedControlResponse>;
+pub(crate) type ControlResponseReceiver = mpsc::UnboundedReceiver<IdentifiedControlResponse>;
+
+type ProviderControlRequestSender = mpsc::UnboundedSender<ProviderControlRequest>;
+type ProviderControlRequestReceiver = mpsc::UnboundedReceiver<ProviderControlRequest>;
+
+struct ProviderControlRequest {
+    provider: Recipient,
+    request: ControlRequest,
+    timeout: Duration,
+    response_tx: oneshot::Sender<Result<ControlResponse, Socks5ClientCoreError>>,
+}
+
+/// Handle allowing sending control requests, such as capability or allow list queries,
+/// to the service providers used by this socks5 client.
+#[derive(Clone)]
+pub struct ProviderControlHandle {
+    primary_provider: Recipient,
+    request_sender: ProviderControlRequestSender,
+}
+
+impl ProviderControlHandle {
+    /// Sends the provided control request to the primary service provider and waits for its response.
+    pub async fn send_control_request(
+        &self,
+        request: ControlRequest,
+    ) -> Result<ControlResponse, Socks5ClientCoreError> {
+        self.send_control_request_to(self.primary_provider, request, CONTROL_REQUEST_TIMEOUT)
+            .await
+    }
+
+    /// Sends the provided control request to the specified service provider and waits
+    /// up to `timeout` for its response.
+    pub async fn send_control_request_to(
+        &self,
+        provider: Recipient,
+        request: ControlRequest,
+        timeout: Duration,
+    ) -> Result<ControlResponse, Socks5ClientCoreError> {
+        let (response_tx, response_rx) = oneshot::channel();
+        self.request_sender
+            .unbounded_send(ProviderControlRequest {
+                provider,
+                request,
+                timeout,
+                response_tx,
+            })
+            .map_err(|_| Socks5ClientCoreError::ProviderControlUnavailable)?;
+
+        response_rx
+            .await
+            .map_err(|_| Socks5ClientCoreError::ProviderControlUnavailable)?
+    }
+
+    /// Probes the specified service provider with the health control request.
+    pub async fn check_health(
+        &self,
+        provider: Recipient,
+        timeout: Duration,
+    ) -> Result<(), Socks5ClientCoreError> {
+        match self
+            .send_control_request_to(provider, ControlRequest::Health, timeout)
+            .await?
+        {
+            ControlResponse::Health => Ok(()),
+            other => Err(unexpected_response(other)),
+        }
+    }
+
+    /// Queries the service provider for the optional features it supports and the summary of its outbound policy.
+    pub async fn get_capabilities(&self) -> Result<ProviderCapabilities, Socks5ClientCoreError> {
+        match self
+            .send_control_request(ControlRequest::Capabilities)
+            .await?
+        {
+            ControlResponse::Capabilities(capabilities) => Ok(capabilities),
+            other => Err(unexpected_response(other)),
+        }
+    }
+
+    /// Queries the service provider for a single page of its allow list.
+    pub async fn get_policy_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<PolicyPage, Socks5ClientCoreError> {
+        match self
+            .send_control_request(ControlRequest::PolicyPage { offset, limit })
+            .await?
+        {
+            ControlResponse::PolicyPage(page) => Ok(page),
+            other => Err(unexpected_response(other)),
+        }
+    }
+
+    /// Retrieves the full allow list of the service provider, page by page.
+    /// It makes sure the policy has not changed in between the queries.
+    pub async fn get_full_policy(&self) -> Result<Vec<String>, Socks5ClientCoreError> {
+        let first_page = self.get_policy_page(0, MAX_POLICY_PAGE_SIZE).await?;
+        let expected_hash = first_page.hash.clone();
+
+        let mut next_offset = first_page.next_offset();
+        let mut entries = first_page.entries;
+
+        while let Some(offset) = next_offset {
+            let page = self.get_policy_page(offset, MAX_POLICY_PAGE_SIZE).await?;
+            if page.hash != expected_hash {
+                return Err(Socks5ClientCoreError::ProviderPolicyChanged);
+            }
+            next_offset = page.next_offset();
+            entries.extend(page.entries);
+        }
+
+        Ok(entries)
+    }
+}
+
+fn unexpected_response(response: ControlResponse) -> Socks5ClientCoreError {
+    match response {
+        ControlResponse::Error(err) => Socks5ClientCoreError::ProviderControlError {
+            message: err.message().to_string(),
+        },
+        other => Socks5ClientCoreError::UnexpectedControlResponse {
+            response: format!("{other:?}"),
+        },
+    }
+}
+
+/// Task responsible for forwarding control requests into the mixnet and matching them
+/// with the responses received by the `MixnetResponseListener`.
+// Note: each request gets a unique id that's echoed back by the provider, so a late response
+// to a request that has already timed out can't be mistaken for the response to the current one.
+pub(crate) struct ProviderControl {
+    input_sender: InputMessageSender,
+    interface_version: ProviderInterfaceVersion,
+    reply_surbs: u32,
+    packet_type: Option<PacketType>,
+    next_request_id: ControlRequestId,
+
+    request_receiver: ProviderControlRequestReceiver,
+    response_receiver: ControlResponseReceiver,
+    shutdown: TaskClient,
+}
+
+impl ProviderControl {
+    pub(crate) fn new(
+        input_sender: InputMessageSender,
+        primary_provider: Recipient,
+        interface_version: ProviderInterfaceVersion,
+        reply_surbs: u32,
+        packet_type: Option<PacketType>,
+        response_receiver: ControlResponseReceiver,
+        shutdown: TaskClient,
+    ) -> (Self, ProviderControlHandle) {
+        let (request_sender, request_receiver) = mpsc::unbounded();
+        (
+            ProviderControl {
+                input_sender,
+                interface_version,
+                reply_surbs,
+                packet_type,
+                next_request_id: 0,
+                request_receiver,
+                response_receiver,
+                shutdown,
+            },
+            ProviderControlHandle {
+                primary_provider,
+                request_sender,
+            },
+        )
+    }
+
+    async fn handle_request(
+        &mut self,
+        provider: Recipient,
+        request: ControlRequest,
+        timeout: Duration,
+    ) -> Result<ControlResponse, Socks5ClientCoreError> {
+        // make sure to use an interface version that understands the request and attaches its id,
+        // even if the data is still being sent using the legacy interface
+        let interface_version = self
+            .interface_version
+            .max(request.min_interface_version())
+            .max(ProviderInterfaceVersion::new_current());
+        let request_id = self.next_request_id;
+        self.next_request_id = self.next_request_id.wrapping_add(1);
+        let msg =
+            Socks5ProviderRequest::new_identified_control(interface_version, request_id, request);
+
+        // the service provider can currently only respond to control requests using reply SURBs
+        let input_message = InputMessage::new_anonymous(
+            provider,
+            msg.into_bytes(),
+            self.reply_surbs,
+            TransmissionLane::General,
+            self.packet_type,
+        );
+        self.input_sender
+            .send(input_message)
+            .await
+            .expect("InputMessageReceiver has stopped receiving!");
+
+        match tokio::time::timeout(timeout, self.wait_for_response(request_id)).await {
+            Ok(Some(response)) => Ok(response),
+            Ok(None) => Err(Socks5ClientCoreError::ProviderControlUnavailable),
+            Err(_timeout) => Err(Socks5ClientCoreError::ControlRequestTimeout { timeout }),
+        }
+    }
+
+    async fn wait_for_response(&mut self, request_id: ControlRequestId) -> Option<ControlResponse> {
+        while let Some((received_id, response)) = self.response_receiver.next().await {
+            if received_id == Some(request_id) {
+                return Some(response);
+            }
+            // it must be a late response to a request that has previously timed out
+            // (or the provider doesn't support request ids, in which case we can't trust it either)
+            debug!("discarding stale control response to request {received_id:?}: {response:?}")
+        }
+        None
+    }
+
+    pub(crate) async fn run(&mut self) {
+        while !self.shutdown.is_shutdown() {
+            tokio::select! {
+                request = self.request_receiver.next() => {
+                    let Some(request) = request else {
+                        log::trace!("ProviderControl: Stopping since channel closed");
+                        break;
+                    };
+                    let response = self
+                        .handle_request(request.provider, request.request, request.timeout)
+                        .await;
+                    if request.response_tx.send(response).is_err() {
+                        debug!("the requester of the control response has gone away");
+                    }
+                },
+                _ = self.shutdown.recv() => {
+                    log::trace!("ProviderControl: Received shutdown");
+                }
+            }
+        }
+        self.shutdown.recv_timeout().await;
+        log::debug!("ProviderControl: Exiting");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_client_core::client::inbound_messages::InputMessageReceiver;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_service_providers_common::interface::RequestContent;
+
+    fn test_recipient() -> Recipient {
+        let mut rng = rand::thread_rng();
+        let client_id = identity::KeyPair::new(&mut rng);
+        let client_enc = encryption::KeyPair::new(&mut rng);
+        let gateway_id = identity::KeyPair::new(&mut rng);
+
+        Recipient::new(
+            *client_id.public_key(),
+            *client_enc.public_key(),
+            *gateway_id.public_key(),
+        )
+    }
+
+    async fn next_request_id(
+        input_receiver: &mut InputMessageReceiver,
+    ) -> Option<ControlRequestId> {
+        let Some(InputMessage::Anonymous { data, .. }) = input_receiver.recv().await else {
+            panic!("control request was not sent as an anonymous message")
+        };
+        match Socks5ProviderRequest::try_from_bytes(&data)
+            .unwrap()
+            .content
+        {
+            RequestContent::Control { request_id, .. } => request_id,
+            RequestContent::ProviderData(_) => {
+                panic!("sent provider data instead of control request")
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn late_response_is_not_matched_with_subsequent_request() {
+        let provider = test_recipient();
+        let (input_sender, mut input_receiver) = tokio::sync::mpsc::channel(10);
+        let (response_sender, response_receiver) = mpsc::unbounded();
+
+        // note: legacy interface is the default one, but it mustn't prevent us from sending control requests
+        let (mut provider_control, handle) = ProviderControl::new(
+            input_sender,
+            provider,
+            ProviderInterfaceVersion::new_legacy(),
+            10,
+            None,
+            response_receiver,
+            TaskClient::dummy(),
+        );
+        tokio::spawn(async move { provider_control.run().await });
+
+        let res = handle
+            .send_control_request_to(provider, ControlRequest::Health, Duration::from_millis(10))
+            .await;
+        assert!(matches!(
+            res,
+            Err(Socks5ClientCoreError::ControlRequestTimeout { .. })
+        ));
+        let timed_out_id = next_request_id(&mut input_receiver).await.unwrap();
+
+        // the response to the first request only arrives once we've sent the next one
+        let capabilities = ProviderCapabilities {
+            features: vec!["tcp".to_string()],
+            policy: None,
+        };
+        let expected = capabilities.clone();
+        let responder = tokio::spawn(async move {
+            let request_id = next_request_id(&mut input_receiver).await.unwrap();
+            assert_ne!(request_id, timed_out_id);
+
+            response_sender
+                .unbounded_send((Some(timed_out_id), ControlResponse::Health))
+                .unwrap();
+            response_sender
+                .unbounded_send((None, ControlResponse::Health))
+                .unwrap();
+            response_sender
+                .unbounded_send((
+                    Some(request_id),
+                    ControlResponse::Capabilities(capabilities),
+                ))
+                .unwrap();
+        });
+
+        let received = handle.get_capabilities().await.unwrap();
+        assert_eq!(received, expected);
+        responder.await.unwrap();
+    }
+}
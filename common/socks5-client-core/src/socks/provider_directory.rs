@@ -0,0 +1,37 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::Socks5ClientCoreError;
+use log::*;
+use nym_service_provider_directory_common::ServiceType;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_validator_client::nyxd::traits::SpDirectoryQueryClient;
+
+/// Retrieves addresses of all network requesters announced in the service provider directory contract.
+pub async fn query_network_requesters(
+    client_config: nym_validator_client::Config,
+) -> Result<Vec<Recipient>, Socks5ClientCoreError> {
+    let client = nym_validator_client::Client::new_query(client_config)?;
+
+    let services = client
+        .nyxd
+        .get_all_services()
+        .await
+        .map_err(|source| Socks5ClientCoreError::ProviderDirectoryQueryFailure { source })?;
+
+    Ok(services
+        .into_iter()
+        .filter(|service| service.service.service_type == ServiceType::NetworkRequester)
+        .filter_map(|service| {
+            let raw_address = service.service.nym_address.as_str();
+            Recipient::try_from_base58_string(raw_address)
+                .map_err(|err| {
+                    warn!(
+                        "service {} has announced malformed address '{raw_address}': {err}",
+                        service.service_id
+                    )
+                })
+                .ok()
+        })
+        .collect())
+}
@@ -0,0 +1,140 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::provider_control::ProviderControlHandle;
+use super::provider_pool::ProviderPool;
+use crate::config;
+use log::*;
+use nym_socks5_proxy_helpers::connection_controller::{ControllerCommand, ControllerSender};
+use nym_sphinx::addressing::clients::Recipient;
+use nym_task::TaskClient;
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HealthCheckConfig {
+    interval: Duration,
+    timeout: Duration,
+    max_failed_checks: u32,
+}
+
+impl From<&config::Socks5Debug> for HealthCheckConfig {
+    fn from(debug: &config::Socks5Debug) -> Self {
+        HealthCheckConfig {
+            interval: debug.provider_health_check_interval,
+            timeout: debug.provider_health_check_timeout,
+            max_failed_checks: debug.max_failed_health_checks,
+        }
+    }
+}
+
+struct HealthProbe {
+    config: HealthCheckConfig,
+    provider_pool: ProviderPool,
+    provider_control: ProviderControlHandle,
+    controller_sender: ControllerSender,
+
+    // providers that have responded to at least a single health check.
+    // Note: older providers don't understand control requests at all, so we shouldn't
+    // penalise them for not responding
+    responsive: HashSet<Recipient>,
+}
+
+impl HealthProbe {
+    async fn check_provider(&mut self, provider: Recipient) {
+        match self
+            .provider_control
+            .check_health(provider, self.config.timeout)
+            .await
+        {
+            Ok(_) => {
+                trace!("service provider {provider} is healthy");
+                self.responsive.insert(provider);
+                self.provider_pool.mark_healthy(provider)
+            }
+            Err(err) => {
+                debug!("health check of service provider {provider} has failed: {err}");
+                if !self.responsive.contains(&provider) {
+                    trace!("service provider {provider} has never responded to a health check - it might not support control requests");
+                    return;
+                }
+
+                let Some(to_close) = self
+                    .provider_pool
+                    .mark_failed_check(provider, self.config.max_failed_checks)
+                else {
+                    return;
+                };
+
+                if !to_close.is_empty() {
+                    info!(
+                        "closing {} connections going through unhealthy service provider {provider}",
+                        to_close.len()
+                    );
+                }
+                for connection_id in to_close {
+                    self.controller_sender
+                        .unbounded_send(ControllerCommand::Remove { connection_id })
+                        .unwrap();
+                }
+            }
+        }
+    }
+
+    async fn check_all_providers(&mut self) {
+        for provider in self.provider_pool.providers() {
+            self.check_provider(provider).await
+        }
+    }
+}
+
+/// Task periodically probing all known service providers with health control requests.
+/// Providers failing enough consecutive checks are excluded from handling new connections
+/// and their existing connections get closed so that the clients could reconnect via the healthy ones.
+pub(crate) struct ProviderHealthChecker {
+    probe: HealthProbe,
+    shutdown: TaskClient,
+}
+
+impl ProviderHealthChecker {
+    pub(crate) fn new(
+        config: HealthCheckConfig,
+        provider_pool: ProviderPool,
+        provider_control: ProviderControlHandle,
+        controller_sender: ControllerSender,
+        shutdown: TaskClient,
+    ) -> Self {
+        ProviderHealthChecker {
+            probe: HealthProbe {
+                config,
+                provider_pool,
+                provider_control,
+                controller_sender,
+                responsive: HashSet::new(),
+            },
+            shutdown,
+        }
+    }
+
+    pub(crate) async fn run(&mut self) {
+        let mut interval = tokio::time::interval(self.probe.config.interval);
+
+        while !self.shutdown.is_shutdown() {
+            tokio::select! {
+                _ = interval.tick() => {
+                    tokio::select! {
+                        _ = self.probe.check_all_providers() => (),
+                        _ = self.shutdown.recv() => {
+                            log::trace!("ProviderHealthChecker: Received shutdown");
+                        }
+                    }
+                },
+                _ = self.shutdown.recv() => {
+                    log::trace!("ProviderHealthChecker: Received shutdown");
+                }
+            }
+        }
+        self.shutdown.recv_timeout().await;
+        log::debug!("ProviderHealthChecker: Exiting");
+    }
+}
@@ -0,0 +1,286 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::ProviderSelectionStrategy;
+use log::*;
+use nym_socks5_requests::ConnectionId;
+use nym_sphinx::addressing::clients::Recipient;
+use rand::seq::SliceRandom;
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+struct ProviderState {
+    address: Recipient,
+    healthy: bool,
+    consecutive_failures: u32,
+    active_connections: HashSet<ConnectionId>,
+}
+
+impl ProviderState {
+    fn new(address: Recipient) -> Self {
+        ProviderState {
+            address,
+            // assume the provider is fine until proven otherwise
+            healthy: true,
+            consecutive_failures: 0,
+            active_connections: HashSet::new(),
+        }
+    }
+}
+
+struct ProviderPoolInner {
+    providers: Vec<ProviderState>,
+    next_index: usize,
+}
+
+/// Snapshot of the current state of a provider known to the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderStatus {
+    pub address: Recipient,
+    pub healthy: bool,
+    pub active_connections: usize,
+}
+
+/// Set of service providers the socks5 client can send its requests to
+/// alongside their current health state.
+#[derive(Clone)]
+pub struct ProviderPool {
+    strategy: ProviderSelectionStrategy,
+    inner: Arc<RwLock<ProviderPoolInner>>,
+}
+
+impl ProviderPool {
+    pub fn new(providers: Vec<Recipient>, strategy: ProviderSelectionStrategy) -> Self {
+        assert!(
+            !providers.is_empty(),
+            "attempted to create an empty provider pool"
+        );
+
+        ProviderPool {
+            strategy,
+            inner: Arc::new(RwLock::new(ProviderPoolInner {
+                providers: providers.into_iter().map(ProviderState::new).collect(),
+                next_index: 0,
+            })),
+        }
+    }
+
+    pub fn strategy(&self) -> ProviderSelectionStrategy {
+        self.strategy
+    }
+
+    /// Adds new providers, such as the ones discovered from the service provider directory, to the pool.
+    pub fn extend(&self, providers: Vec<Recipient>) {
+        let mut guard = self.inner.write().unwrap();
+        for provider in providers {
+            if !guard.providers.iter().any(|p| p.address == provider) {
+                guard.providers.push(ProviderState::new(provider))
+            }
+        }
+    }
+
+    pub fn providers(&self) -> Vec<Recipient> {
+        let guard = self.inner.read().unwrap();
+        guard.providers.iter().map(|p| p.address).collect()
+    }
+
+    pub fn status(&self) -> Vec<ProviderStatus> {
+        let guard = self.inner.read().unwrap();
+        guard
+            .providers
+            .iter()
+            .map(|p| ProviderStatus {
+                address: p.address,
+                healthy: p.healthy,
+                active_connections: p.active_connections.len(),
+            })
+            .collect()
+    }
+
+    /// Chooses provider for a new connection according to the selection strategy.
+    /// If none of the providers are healthy, all of them are considered, so that the
+    /// client would still attempt to do something rather than failing immediately.
+    pub fn select(&self) -> Recipient {
+        let mut guard = self.inner.write().unwrap();
+
+        let mut candidates: Vec<_> = guard
+            .providers
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.healthy)
+            .map(|(i, _)| i)
+            .collect();
+        if candidates.is_empty() {
+            warn!("none of the service providers are currently healthy");
+            candidates = (0..guard.providers.len()).collect();
+        }
+
+        let chosen = match self.strategy {
+            ProviderSelectionStrategy::Failover => candidates[0],
+            ProviderSelectionStrategy::RoundRobin => {
+                let next = guard.next_index;
+                // first healthy provider at or after our cursor, wrapping around if needed
+                let chosen = candidates
+                    .iter()
+                    .copied()
+                    .find(|&i| i >= next)
+                    .unwrap_or(candidates[0]);
+                guard.next_index = chosen + 1;
+                chosen
+            }
+            ProviderSelectionStrategy::Random => {
+                *candidates.choose(&mut rand::thread_rng()).unwrap()
+            }
+        };
+
+        guard.providers[chosen].address
+    }
+
+    pub(crate) fn register_connection(&self, provider: Recipient, connection_id: ConnectionId) {
+        let mut guard = self.inner.write().unwrap();
+        if let Some(state) = guard.providers.iter_mut().find(|p| p.address == provider) {
+            state.active_connections.insert(connection_id);
+        }
+    }
+
+    pub(crate) fn release_connection(&self, provider: Recipient, connection_id: ConnectionId) {
+        let mut guard = self.inner.write().unwrap();
+        if let Some(state) = guard.providers.iter_mut().find(|p| p.address == provider) {
+            state.active_connections.remove(&connection_id);
+        }
+    }
+
+    pub(crate) fn mark_healthy(&self, provider: Recipient) {
+        let mut guard = self.inner.write().unwrap();
+        if let Some(state) = guard.providers.iter_mut().find(|p| p.address == provider) {
+            if !state.healthy {
+                info!("service provider {provider} is healthy again");
+            }
+            state.healthy = true;
+            state.consecutive_failures = 0;
+        }
+    }
+
+    /// Records a failed health check of the provider. If it resulted in the provider becoming unhealthy,
+    /// ids of all of its currently active connections are returned so that they could be closed.
+    pub(crate) fn mark_failed_check(
+        &self,
+        provider: Recipient,
+        max_failures: u32,
+    ) -> Option<Vec<ConnectionId>> {
+        let mut guard = self.inner.write().unwrap();
+        let state = guard.providers.iter_mut().find(|p| p.address == provider)?;
+
+        state.consecutive_failures += 1;
+        if state.healthy && state.consecutive_failures >= max_failures {
+            warn!(
+                "service provider {provider} has failed {} consecutive health checks - marking it as unhealthy",
+                state.consecutive_failures
+            );
+            state.healthy = false;
+            Some(state.active_connections.drain().collect())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+
+    fn test_recipient() -> Recipient {
+        let mut rng = rand::thread_rng();
+        let client_id = identity::KeyPair::new(&mut rng);
+        let client_enc = encryption::KeyPair::new(&mut rng);
+        let gateway_id = identity::KeyPair::new(&mut rng);
+
+        Recipient::new(
+            *client_id.public_key(),
+            *client_enc.public_key(),
+            *gateway_id.public_key(),
+        )
+    }
+
+    fn test_pool(n: usize, strategy: ProviderSelectionStrategy) -> (ProviderPool, Vec<Recipient>) {
+        let providers: Vec<_> = (0..n).map(|_| test_recipient()).collect();
+        (ProviderPool::new(providers.clone(), strategy), providers)
+    }
+
+    #[test]
+    fn failover_uses_first_healthy_provider() {
+        let (pool, providers) = test_pool(3, ProviderSelectionStrategy::Failover);
+        assert_eq!(pool.select(), providers[0]);
+        assert_eq!(pool.select(), providers[0]);
+
+        pool.mark_failed_check(providers[0], 1);
+        assert_eq!(pool.select(), providers[1]);
+
+        pool.mark_healthy(providers[0]);
+        assert_eq!(pool.select(), providers[0]);
+    }
+
+    #[test]
+    fn round_robin_skips_unhealthy_providers() {
+        let (pool, providers) = test_pool(3, ProviderSelectionStrategy::RoundRobin);
+        assert_eq!(pool.select(), providers[0]);
+        assert_eq!(pool.select(), providers[1]);
+        assert_eq!(pool.select(), providers[2]);
+        assert_eq!(pool.select(), providers[0]);
+
+        pool.mark_failed_check(providers[1], 1);
+        assert_eq!(pool.select(), providers[2]);
+        assert_eq!(pool.select(), providers[0]);
+        assert_eq!(pool.select(), providers[2]);
+    }
+
+    #[test]
+    fn random_only_chooses_healthy_providers() {
+        let (pool, providers) = test_pool(3, ProviderSelectionStrategy::Random);
+        pool.mark_failed_check(providers[0], 1);
+        pool.mark_failed_check(providers[2], 1);
+        for _ in 0..20 {
+            assert_eq!(pool.select(), providers[1]);
+        }
+    }
+
+    #[test]
+    fn all_providers_are_considered_if_none_are_healthy() {
+        let (pool, providers) = test_pool(2, ProviderSelectionStrategy::Failover);
+        pool.mark_failed_check(providers[0], 1);
+        pool.mark_failed_check(providers[1], 1);
+        assert_eq!(pool.select(), providers[0]);
+    }
+
+    #[test]
+    fn provider_becomes_unhealthy_after_enough_failures() {
+        let (pool, providers) = test_pool(2, ProviderSelectionStrategy::Failover);
+        pool.register_connection(providers[0], 1);
+        pool.register_connection(providers[0], 2);
+        pool.register_connection(providers[1], 3);
+
+        assert!(pool.mark_failed_check(providers[0], 2).is_none());
+        let mut to_close = pool.mark_failed_check(providers[0], 2).unwrap();
+        to_close.sort();
+        assert_eq!(to_close, vec![1, 2]);
+
+        // it's already unhealthy, so there's nothing else to close
+        assert!(pool.mark_failed_check(providers[0], 2).is_none());
+
+        let status = pool.status();
+        assert!(!status[0].healthy);
+        assert_eq!(status[0].active_connections, 0);
+        assert!(status[1].healthy);
+        assert_eq!(status[1].active_connections, 1);
+    }
+
+    #[test]
+    fn successful_check_resets_failure_count() {
+        let (pool, providers) = test_pool(1, ProviderSelectionStrategy::Failover);
+        assert!(pool.mark_failed_check(providers[0], 2).is_none());
+        pool.mark_healthy(providers[0]);
+        assert!(pool.mark_failed_check(providers[0], 2).is_none());
+        assert!(pool.status()[0].healthy);
+    }
+}
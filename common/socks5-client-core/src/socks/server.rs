@@ -1,7 +1,14 @@
+use crate::config;
 use crate::error::Socks5ClientCoreError;
+use crate::http_proxy::server::NymHttpProxyServer;
 
 use super::{
-    authentication::Authenticator, client::SocksClient, mixnet_responses::MixnetResponseListener,
+    authentication::Authenticator,
+    client::SocksClient,
+    mixnet_responses::MixnetResponseListener,
+    provider_control::{ControlResponseSender, ProviderControlHandle},
+    provider_health::{HealthCheckConfig, ProviderHealthChecker},
+    provider_pool::ProviderPool,
 };
 use crate::socks::client;
 use log::*;
@@ -21,7 +28,9 @@ use tokio::net::TcpListener;
 pub struct NymSocksServer {
     authenticator: Authenticator,
     listening_address: SocketAddr,
-    service_provider: Recipient,
+    provider_pool: ProviderPool,
+    health_check: Option<HealthCheckConfig>,
+    http_proxy: Option<NymHttpProxyServer>,
     self_address: Recipient,
     client_config: client::Config,
     lane_queue_lengths: LaneQueueLengths,
@@ -35,7 +44,8 @@ impl NymSocksServer {
     pub(crate) fn new(
         port: u16,
         authenticator: Authenticator,
-        service_provider: Recipient,
+        provider_pool: ProviderPool,
+        health_check: Option<HealthCheckConfig>,
         self_address: Recipient,
         lane_queue_lengths: LaneQueueLengths,
         client_config: client::Config,
@@ -49,7 +59,9 @@ impl NymSocksServer {
         NymSocksServer {
             authenticator,
             listening_address: format!("{ip}:{port}").parse().unwrap(),
-            service_provider,
+            provider_pool,
+            health_check,
+            http_proxy: None,
             self_address,
             client_config,
             lane_queue_lengths,
@@ -58,6 +70,20 @@ impl NymSocksServer {
         }
     }
 
+    /// Additionally accept HTTP proxy connections, sending them through the same providers.
+    pub(crate) fn with_http_proxy(mut self, http_proxy_config: &config::HttpProxy) -> Self {
+        self.http_proxy = Some(NymHttpProxyServer::new(
+            http_proxy_config,
+            self.provider_pool.clone(),
+            self.self_address,
+            self.lane_queue_lengths.clone(),
+            self.client_config,
+            self.shutdown.clone(),
+            self.packet_type,
+        ));
+        self
+    }
+
     /// Set up the listener and initiate connection handling when something
     /// connects to the server.
     pub(crate) async fn serve(
@@ -65,6 +91,8 @@ impl NymSocksServer {
         input_sender: InputMessageSender,
         buffer_requester: ReceivedBufferRequestSender,
         client_connection_tx: ConnectionCommandSender,
+        control_response_sender: ControlResponseSender,
+        provider_control: ProviderControlHandle,
     ) -> Result<(), Socks5ClientCoreError> {
         let listener = TcpListener::bind(self.listening_address)
             .await
@@ -85,16 +113,33 @@ impl NymSocksServer {
         let mut mixnet_response_listener = MixnetResponseListener::new(
             buffer_requester,
             controller_sender.clone(),
+            control_response_sender,
             self.shutdown.clone(),
         );
         tokio::spawn(async move {
             mixnet_response_listener.run().await;
         });
 
-        // TODO:, if required, there should be another task here responsible for control requests.
-        // it should get `input_sender` to send actual requests into the mixnet
-        // and some channel that connects it from `MixnetResponseListener` to receive
-        // any control responses
+        // monitor the providers so that new connections wouldn't go through the unhealthy ones
+        if let Some(health_check) = self.health_check {
+            let mut health_checker = ProviderHealthChecker::new(
+                health_check,
+                self.provider_pool.clone(),
+                provider_control,
+                controller_sender.clone(),
+                self.shutdown.clone(),
+            );
+            tokio::spawn(async move {
+                health_checker.run().await;
+            });
+        }
+
+        if let Some(http_proxy) = self.http_proxy.take() {
+            nym_task::spawn_with_report_error(
+                http_proxy.serve(input_sender.clone(), controller_sender.clone()),
+                self.shutdown.clone(),
+            );
+        }
 
         loop {
             tokio::select! {
@@ -104,7 +149,7 @@ impl NymSocksServer {
                         stream,
                         self.authenticator.clone(),
                         input_sender.clone(),
-                        &self.service_provider,
+                        self.provider_pool.clone(),
                         controller_sender.clone(),
                         &self.self_address,
                         self.lane_queue_lengths.clone(),
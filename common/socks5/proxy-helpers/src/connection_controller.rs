@@ -162,6 +162,13 @@ impl Controller {
     }
 
     fn remove_connection(&mut self, conn_id: ConnectionId) {
+        // the connection might have been forcefully closed before, for example if it was going
+        // through a service provider that has become unhealthy
+        if self.recently_closed.contains(&conn_id) {
+            debug!("{} has already been removed from controller", conn_id);
+            return;
+        }
+
         debug!("Removing {} from controller", conn_id);
         if self.active_connections.remove(&conn_id).is_none() {
             error!(
@@ -261,3 +268,36 @@ impl Controller {
         log::debug!("SOCKS5 Controller: Exiting");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removing_already_closed_connection_is_a_noop() {
+        let (client_connection_tx, mut client_connection_rx) = mpsc::unbounded();
+        let (mut controller, _sender) = Controller::new(client_connection_tx, TaskClient::dummy());
+
+        let (connection_sender, _connection_receiver) = mpsc::unbounded();
+        controller.insert_connection(42, connection_sender);
+
+        // the connection might get forcefully removed, for example if its provider has become unhealthy,
+        // and then removed again once the local socket gets closed
+        controller.remove_connection(42);
+        controller.remove_connection(42);
+
+        assert!(controller.active_connections.is_empty());
+        assert!(controller.recently_closed.contains(&42));
+
+        // the closure is only announced once
+        assert!(matches!(
+            client_connection_rx.try_next(),
+            Ok(Some(ConnectionCommand::Close(42)))
+        ));
+        assert!(client_connection_rx.try_next().is_err());
+
+        // and any data that arrives afterwards isn't buffered for a future connection
+        controller.send_to_connection(42, vec![1, 2, 3], false);
+        assert!(controller.pending_messages.is_empty());
+    }
+}
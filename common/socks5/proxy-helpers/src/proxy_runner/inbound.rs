@@ -167,6 +167,7 @@ pub(super) async fn run_inbound<F, S>(
     mix_sender: MixProxySender<S>,
     available_plaintext_per_mix_packet: usize,
     adapter_fn: F,
+    initial_data: Option<Vec<u8>>,
     shutdown_notify: Arc<Notify>,
     lane_queue_lengths: Option<LaneQueueLengths>,
     mut shutdown_listener: TaskClient,
@@ -180,6 +181,15 @@ where
         AvailableReader::new(&mut reader, Some(available_plaintext_per_mix_packet * 4));
     let mut message_sender = OrderedMessageSender::new();
 
+    // whatever was consumed from the socket before the proxy got started has to go out first
+    if let Some(initial_data) = initial_data {
+        let ordered_msg = message_sender.wrap_message(initial_data).into_bytes();
+        mix_sender
+            .send(adapter_fn(connection_id, ordered_msg, false))
+            .await
+            .expect("InputMessageReceiver has stopped receiving!");
+    }
+
     // Shutdown if outbound signled to shutdown
     let shutdown_future = shutdown_notify.notified().then(|_| sleep(SHUTDOWN_TIMEOUT));
     tokio::pin!(shutdown_future);
@@ -55,6 +55,9 @@ pub struct ProxyRunner<S> {
 
     available_plaintext_per_mix_packet: usize,
 
+    /// data that should be sent into the mix network before anything read from the socket
+    initial_data: Option<Vec<u8>>,
+
     // Listens to shutdown commands from higher up
     shutdown_listener: TaskClient,
 }
@@ -84,10 +87,18 @@ where
             connection_id,
             lane_queue_lengths,
             available_plaintext_per_mix_packet,
+            initial_data: None,
             shutdown_listener,
         }
     }
 
+    /// Specifies data that has already been read from the socket (or was derived from it),
+    /// that should be sent to the remote before anything else.
+    pub fn with_initial_data(mut self, initial_data: Vec<u8>) -> Self {
+        self.initial_data = Some(initial_data);
+        self
+    }
+
     // The `adapter_fn` is used to transform whatever was read into appropriate
     // request/response as required by entity running particular side of the proxy.
     pub async fn run<F>(mut self, adapter_fn: F) -> Self
@@ -106,6 +117,7 @@ where
             self.mix_sender.clone(),
             self.available_plaintext_per_mix_packet,
             adapter_fn,
+            self.initial_data.take(),
             Arc::clone(&shutdown_notify),
             self.lane_queue_lengths.clone(),
             self.shutdown_listener.clone(),
@@ -30,6 +30,7 @@ impl StatsMessage {
 pub enum StatsData {
     Service(StatsServiceData),
     Gateway(StatsGatewayData),
+    RateLimit(StatsRateLimitData),
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -63,3 +64,15 @@ impl StatsServiceData {
         }
     }
 }
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StatsRateLimitData {
+    pub limit: String,
+    pub events: u32,
+}
+
+impl StatsRateLimitData {
+    pub fn new(limit: String, events: u32) -> Self {
+        StatsRateLimitData { limit, events }
+    }
+}
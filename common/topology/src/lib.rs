@@ -4,7 +4,7 @@
 use crate::filter::VersionFilterable;
 use log::warn;
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
-use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId};
+use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId, MixNodeBond};
 use nym_sphinx_addressing::nodes::NodeIdentity;
 use nym_sphinx_types::Node as SphinxNode;
 use rand::prelude::SliceRandom;
@@ -321,12 +321,21 @@ impl NymTopology {
 pub fn nym_topology_from_detailed(
     mix_details: Vec<MixNodeDetails>,
     gateway_bonds: Vec<GatewayBond>,
+) -> NymTopology {
+    nym_topology_from_bonds(
+        mix_details
+            .into_iter()
+            .map(|details| details.bond_information),
+        gateway_bonds,
+    )
+}
+
+pub fn nym_topology_from_bonds(
+    mix_bonds: impl IntoIterator<Item = MixNodeBond>,
+    gateway_bonds: Vec<GatewayBond>,
 ) -> NymTopology {
     let mut mixes = BTreeMap::new();
-    for bond in mix_details
-        .into_iter()
-        .map(|details| details.bond_information)
-    {
+    for bond in mix_bonds {
         let layer = bond.layer as MixLayer;
         if layer == 0 || layer > 3 {
             warn!(
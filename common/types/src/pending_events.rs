@@ -57,6 +57,12 @@ pub enum PendingEpochEventData {
         mix_id: MixId,
         proxy: Option<String>,
     },
+    Redelegate {
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        proxy: Option<String>,
+    },
     PledgeMore {
         mix_id: MixId,
         amount: DecCoin,
@@ -99,6 +105,17 @@ impl PendingEpochEventData {
                 mix_id,
                 proxy: proxy.map(|p| p.into_string()),
             }),
+            MixnetContractPendingEpochEventKind::Redelegate {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                proxy,
+            } => Ok(PendingEpochEventData::Redelegate {
+                owner: owner.into_string(),
+                from_mix_id,
+                to_mix_id,
+                proxy: proxy.map(|p| p.into_string()),
+            }),
             MixnetContractPendingEpochEventKind::PledgeMore { mix_id, amount } => {
                 Ok(PendingEpochEventData::PledgeMore {
                     mix_id,
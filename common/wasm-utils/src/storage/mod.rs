@@ -134,6 +134,22 @@ impl WasmStorage {
             .store_value_raw(store, key, &self.serialize_value(&value)?)
             .await
     }
+
+    pub async fn read_all_values<T>(&self, store: &str) -> Result<Vec<T>, StorageError>
+    where
+        T: DeserializeOwned,
+    {
+        self.inner
+            .read_all_values_raw(store)
+            .await?
+            .into_iter()
+            .map(|raw| self.deserialize_value(raw))
+            .collect()
+    }
+
+    pub async fn clear_store(&self, store: &str) -> Result<(), StorageError> {
+        self.inner.clear_store(store).await
+    }
 }
 
 struct IdbWrapper(IdbDatabase);
@@ -169,6 +185,27 @@ impl IdbWrapper {
             .map_err(Into::into)
     }
 
+    async fn read_all_values_raw(&self, store: &str) -> Result<Vec<JsValue>, StorageError> {
+        let values = self
+            .0
+            .transaction_on_one_with_mode(store, IdbTransactionMode::Readonly)?
+            .object_store(store)?
+            .get_all()?
+            .await?;
+
+        Ok(values.iter().collect())
+    }
+
+    async fn clear_store(&self, store: &str) -> Result<(), StorageError> {
+        self.0
+            .transaction_on_one_with_mode(store, IdbTransactionMode::Readwrite)?
+            .object_store(store)?
+            .clear()?
+            .into_future()
+            .await
+            .map_err(Into::into)
+    }
+
     async fn read_exported_cipher_store(
         &self,
     ) -> Result<Option<StoredExportedStoreCipher>, StorageError> {
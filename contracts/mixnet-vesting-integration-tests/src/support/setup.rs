@@ -195,6 +195,7 @@ impl TestSetup {
                 &MixnetExecuteMsg::AdvanceCurrentEpoch {
                     new_rewarded_set,
                     expected_active_set_size: current_params.active_set_size,
+                    selection: None,
                 },
                 &[],
             )
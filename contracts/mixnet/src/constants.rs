@@ -45,8 +45,11 @@ pub const DELEGATION_MIXNODE_IDX_NAMESPACE: &str = "dlm";
 
 pub const GATEWAYS_PK_NAMESPACE: &str = "gt";
 pub const GATEWAYS_OWNER_IDX_NAMESPACE: &str = "gto";
+pub const PENDING_GATEWAY_TRANSFERS_NAMESPACE: &str = "pgt";
 
 pub const REWARDED_SET_KEY: &str = "rs";
+pub const REWARDED_SET_SELECTION_KEY: &str = "rss";
+pub const REWARDED_SET_SELECTION_SEED_KEY: &str = "rsss";
 pub const CURRENT_EPOCH_STATUS_KEY: &str = "ces";
 pub const CURRENT_INTERVAL_KEY: &str = "ci";
 pub const EPOCH_EVENT_ID_COUNTER_KEY: &str = "eic";
@@ -62,6 +65,7 @@ pub const CONTRACT_STATE_KEY: &str = "state";
 pub const LAYER_DISTRIBUTION_KEY: &str = "layers";
 pub const NODE_ID_COUNTER_KEY: &str = "nic";
 pub const PENDING_MIXNODE_CHANGES_NAMESPACE: &str = "pmc";
+pub const PENDING_MIXNODE_TRANSFERS_NAMESPACE: &str = "pmt";
 pub const MIXNODES_PK_NAMESPACE: &str = "mnn";
 pub const MIXNODES_OWNER_IDX_NAMESPACE: &str = "mno";
 pub const MIXNODES_IDENTITY_IDX_NAMESPACE: &str = "mni";
@@ -74,6 +78,10 @@ pub const UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE: &str = "umi";
 pub const REWARDING_PARAMS_KEY: &str = "rparams";
 pub const PENDING_REWARD_POOL_KEY: &str = "prp";
 pub const MIXNODES_REWARDING_PK_NAMESPACE: &str = "mnr";
+pub const DELEGATIONS_COMPOUNDING_NAMESPACE: &str = "dcmp";
+pub const OPERATORS_COMPOUNDING_NAMESPACE: &str = "ocmp";
+pub const PENDING_DELEGATIONS_COMPOUNDING_NAMESPACE: &str = "pdcmp";
+pub const PENDING_OPERATORS_COMPOUNDING_NAMESPACE: &str = "pocmp";
 
 pub const FAMILIES_INDEX_NAMESPACE: &str = "faml2";
 pub const FAMILIES_MAP_NAMESPACE: &str = "fam2";
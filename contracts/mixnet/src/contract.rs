@@ -207,12 +207,14 @@ pub fn execute(
             new_rewarded_set,
             // families_in_layer,
             expected_active_set_size,
+            selection,
         } => crate::interval::transactions::try_advance_epoch(
             deps,
             env,
             info,
             new_rewarded_set,
             expected_active_set_size,
+            selection,
         ),
         ExecuteMsg::ReconcileEpochEvents { limit } => {
             crate::interval::transactions::try_reconcile_epoch_events(deps, env, info, limit)
@@ -287,6 +289,30 @@ pub fn execute(
                 deps, info, new_config, owner,
             )
         }
+        ExecuteMsg::ProposeMixnodeTransfer {
+            new_owner,
+            new_identity_key,
+            new_sphinx_key,
+        } => crate::mixnodes::transactions::try_propose_mixnode_transfer(
+            deps,
+            env,
+            info,
+            new_owner,
+            new_identity_key,
+            new_sphinx_key,
+        ),
+        ExecuteMsg::AcceptMixnodeTransfer {
+            mix_id,
+            identity_signature,
+        } => crate::mixnodes::transactions::try_accept_mixnode_transfer(
+            deps,
+            info,
+            mix_id,
+            identity_signature,
+        ),
+        ExecuteMsg::CancelMixnodeTransfer {} => {
+            crate::mixnodes::transactions::try_cancel_mixnode_transfer(deps, info)
+        }
 
         // gateway-related:
         ExecuteMsg::BondGateway {
@@ -325,6 +351,30 @@ pub fn execute(
                 deps, info, new_config, owner,
             )
         }
+        ExecuteMsg::ProposeGatewayTransfer {
+            new_owner,
+            new_identity_key,
+            new_sphinx_key,
+        } => crate::gateways::transactions::try_propose_gateway_transfer(
+            deps,
+            env,
+            info,
+            new_owner,
+            new_identity_key,
+            new_sphinx_key,
+        ),
+        ExecuteMsg::AcceptGatewayTransfer {
+            identity,
+            identity_signature,
+        } => crate::gateways::transactions::try_accept_gateway_transfer(
+            deps,
+            info,
+            identity,
+            identity_signature,
+        ),
+        ExecuteMsg::CancelGatewayTransfer {} => {
+            crate::gateways::transactions::try_cancel_gateway_transfer(deps, info)
+        }
 
         // delegation-related:
         ExecuteMsg::DelegateToMixnode { mix_id } => {
@@ -345,6 +395,28 @@ pub fn execute(
                 deps, env, info, mix_id, delegate,
             )
         }
+        ExecuteMsg::Redelegate {
+            from_mix_id,
+            to_mix_id,
+        } => crate::delegations::transactions::try_redelegate(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+        ),
+        ExecuteMsg::RedelegateOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            delegate,
+        } => crate::delegations::transactions::try_redelegate_on_behalf(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+            delegate,
+        ),
 
         // reward-related
         ExecuteMsg::RewardMixnode {
@@ -366,6 +438,26 @@ pub fn execute(
                 deps, info, mix_id, owner,
             )
         }
+        ExecuteMsg::UpdateDelegationCompounding { mix_id, frequency } => {
+            crate::rewards::transactions::try_update_delegation_compounding(
+                deps, info, mix_id, frequency,
+            )
+        }
+        ExecuteMsg::UpdateDelegationCompoundingOnBehalf {
+            mix_id,
+            frequency,
+            owner,
+        } => crate::rewards::transactions::try_update_delegation_compounding_on_behalf(
+            deps, info, mix_id, frequency, owner,
+        ),
+        ExecuteMsg::UpdateOperatorCompounding { frequency } => {
+            crate::rewards::transactions::try_update_operator_compounding(deps, info, frequency)
+        }
+        ExecuteMsg::UpdateOperatorCompoundingOnBehalf { frequency, owner } => {
+            crate::rewards::transactions::try_update_operator_compounding_on_behalf(
+                deps, info, frequency, owner,
+            )
+        }
 
         // testing-only
         #[cfg(feature = "contract-testing")]
@@ -425,6 +517,12 @@ pub fn query(
         QueryMsg::GetRewardedSet { limit, start_after } => to_binary(
             &crate::interval::queries::query_rewarded_set_paged(deps, start_after, limit)?,
         ),
+        QueryMsg::GetRewardedSetSelection {} => to_binary(
+            &crate::interval::queries::query_rewarded_set_selection(deps)?,
+        ),
+        QueryMsg::GetRewardedSetSelectionSeed {} => {
+            to_binary(&crate::interval::queries::query_rewarded_set_selection_seed(deps)?)
+        }
 
         // mixnode-related:
         QueryMsg::GetMixNodeBonds { start_after, limit } => to_binary(
@@ -481,6 +579,9 @@ pub fn query(
         QueryMsg::GetLayerDistribution {} => {
             to_binary(&crate::mixnodes::queries::query_layer_distribution(deps)?)
         }
+        QueryMsg::GetPendingMixnodeTransfer { mix_id } => to_binary(
+            &crate::mixnodes::queries::query_pending_mixnode_transfer(deps, mix_id)?,
+        ),
 
         // gateway-related:
         QueryMsg::GetGateways { limit, start_after } => to_binary(
@@ -492,6 +593,9 @@ pub fn query(
         QueryMsg::GetOwnedGateway { address } => to_binary(
             &crate::gateways::queries::query_owned_gateway(deps, address)?,
         ),
+        QueryMsg::GetPendingGatewayTransfer { identity } => to_binary(
+            &crate::gateways::queries::query_pending_gateway_transfer(deps, identity)?,
+        ),
 
         // delegation-related:
         QueryMsg::GetMixnodeDelegations {
@@ -543,6 +647,16 @@ pub fn query(
         } => to_binary(&crate::rewards::queries::query_pending_delegator_reward(
             deps, address, mix_id, proxy,
         )?),
+        QueryMsg::GetDelegationCompounding {
+            address,
+            mix_id,
+            proxy,
+        } => to_binary(&crate::rewards::queries::query_delegation_compounding(
+            deps, address, mix_id, proxy,
+        )?),
+        QueryMsg::GetOperatorCompounding { mix_id } => to_binary(
+            &crate::rewards::queries::query_operator_compounding(deps, mix_id)?,
+        ),
         QueryMsg::GetEstimatedCurrentEpochOperatorReward {
             mix_id,
             estimated_performance,
@@ -17,6 +17,7 @@ pub(crate) fn undelegate(
 
     rewards_storage::MIXNODE_REWARDING.save(store, delegation.mix_id, &mix_rewarding)?;
     storage::delegations().replace(store, delegation.storage_key(), None, Some(&delegation))?;
+    rewards_storage::remove_delegation_compounding(store, delegation.storage_key())?;
 
     Ok(tokens)
 }
@@ -11,7 +11,7 @@ use crate::support::helpers::{
 use cosmwasm_std::{Addr, Coin, DepsMut, Env, MessageInfo, Response};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_pending_delegation_event, new_pending_undelegation_event,
+    new_pending_delegation_event, new_pending_redelegation_event, new_pending_undelegation_event,
 };
 use mixnet_contract_common::pending_events::PendingEpochEventKind;
 use mixnet_contract_common::{Delegation, MixId};
@@ -139,6 +139,92 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
     Ok(Response::new().add_event(cosmos_event))
 }
 
+pub(crate) fn try_redelegate(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Result<Response, MixnetContractError> {
+    _try_redelegate(deps, env, from_mix_id, to_mix_id, info.sender, None)
+}
+
+pub(crate) fn try_redelegate_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    delegate: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let delegate = deps.api.addr_validate(&delegate)?;
+    _try_redelegate(
+        deps,
+        env,
+        from_mix_id,
+        to_mix_id,
+        delegate,
+        Some(info.sender),
+    )
+}
+
+pub(crate) fn _try_redelegate(
+    deps: DepsMut<'_>,
+    env: Env,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    delegate: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // redelegation is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    if from_mix_id == to_mix_id {
+        return Err(MixnetContractError::RedelegationToSameMixnode {
+            mix_id: from_mix_id,
+        });
+    }
+
+    // see if the delegation even exists. note that we don't care about the state of the source node,
+    // it's perfectly fine to move the stake away from a node that is unbonding (or has already unbonded)
+    let storage_key = Delegation::generate_storage_key(from_mix_id, &delegate, proxy.as_ref());
+    if storage::delegations()
+        .may_load(deps.storage, storage_key)?
+        .is_none()
+    {
+        return Err(MixnetContractError::NoMixnodeDelegationFound {
+            mix_id: from_mix_id,
+            address: delegate.into_string(),
+            proxy: proxy.map(Addr::into_string),
+        });
+    }
+
+    // check if the target node actually exists and is still bonded
+    match mixnodes_storage::mixnode_bonds().may_load(deps.storage, to_mix_id)? {
+        None => return Err(MixnetContractError::MixNodeBondNotFound { mix_id: to_mix_id }),
+        Some(bond) if bond.is_unbonding => {
+            return Err(MixnetContractError::MixnodeIsUnbonding { mix_id: to_mix_id })
+        }
+        _ => (),
+    }
+
+    // push the event onto the queue and wait for it to be picked up at the end of the epoch
+    // (the stake stays delegated to the source node until then)
+    let cosmos_event = new_pending_redelegation_event(&delegate, &proxy, from_mix_id, to_mix_id);
+
+    let epoch_event = PendingEpochEventKind::Redelegate {
+        owner: delegate,
+        from_mix_id,
+        to_mix_id,
+        proxy,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -609,4 +695,287 @@ mod tests {
             )
         }
     }
+
+    #[cfg(test)]
+    mod redelegating {
+        use super::*;
+        use crate::mixnodes::transactions::try_remove_mixnode;
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::TestSetup;
+        use cosmwasm_std::coin;
+        use cosmwasm_std::testing::mock_info;
+        use mixnet_contract_common::{EpochState, EpochStatus};
+
+        #[test]
+        fn cant_be_performed_if_epoch_transition_is_in_progress() {
+            let bad_states = vec![
+                EpochState::Rewarding {
+                    last_rewarded: 0,
+                    final_node_id: 0,
+                },
+                EpochState::ReconcilingEvents,
+                EpochState::AdvancingEpoch,
+            ];
+
+            for bad_state in bad_states {
+                let mut test = TestSetup::new();
+                let env = test.env();
+                let owner = "delegator";
+                let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+                let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+                test.add_immediate_delegation(owner, 10000u32, from_mix_id);
+
+                let mut status = EpochStatus::new(test.rewarding_validator().sender);
+                status.state = bad_state;
+                interval_storage::save_current_epoch_status(test.deps_mut().storage, &status)
+                    .unwrap();
+
+                let res = try_redelegate(
+                    test.deps_mut(),
+                    env,
+                    mock_info(owner, &[]),
+                    from_mix_id,
+                    to_mix_id,
+                );
+                assert!(matches!(
+                    res,
+                    Err(MixnetContractError::EpochAdvancementInProgress { .. })
+                ));
+            }
+        }
+
+        #[test]
+        fn cannot_be_performed_onto_the_same_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation(owner, 10000u32, mix_id);
+
+            let res = try_redelegate(test.deps_mut(), env, mock_info(owner, &[]), mix_id, mix_id);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationToSameMixnode { mix_id })
+            )
+        }
+
+        #[test]
+        fn cannot_be_performed_if_delegation_never_existed() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let res = try_redelegate(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id: from_mix_id,
+                    address: owner.to_string(),
+                    proxy: None
+                })
+            )
+        }
+
+        #[test]
+        fn can_only_be_done_towards_fully_bonded_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let unbonding_mix_id = test.add_dummy_mixnode("mix-owner-unbonding", None);
+            let unbonded_mix_id = test.add_dummy_mixnode("mix-owner-unbonded", None);
+            test.add_immediate_delegation(owner, 10000u32, from_mix_id);
+
+            try_remove_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("mix-owner-unbonded", &[]),
+            )
+            .unwrap();
+            test.execute_all_pending_events();
+            try_remove_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("mix-owner-unbonding", &[]),
+            )
+            .unwrap();
+
+            let res = try_redelegate(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                unbonding_mix_id,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixnodeIsUnbonding {
+                    mix_id: unbonding_mix_id
+                })
+            );
+
+            let res = try_redelegate(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                unbonded_mix_id,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixNodeBondNotFound {
+                    mix_id: unbonded_mix_id
+                })
+            );
+
+            let res = try_redelegate(test.deps_mut(), env, mock_info(owner, &[]), from_mix_id, 42);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixNodeBondNotFound { mix_id: 42 })
+            );
+        }
+
+        #[test]
+        fn can_be_performed_from_unbonding_or_unbonded_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "delegator";
+            let to_mix_id = test.add_dummy_mixnode("mix-owner", None);
+            let mix_id_unbonding = test.add_dummy_mixnode("mix-owner-unbonding", None);
+            let mix_id_unbonded_leftover =
+                test.add_dummy_mixnode("mix-owner-unbonded-leftover", None);
+
+            test.add_immediate_delegation(owner, 10000u32, mix_id_unbonding);
+            test.add_immediate_delegation(owner, 10000u32, mix_id_unbonded_leftover);
+
+            try_remove_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("mix-owner-unbonded-leftover", &[]),
+            )
+            .unwrap();
+            test.execute_all_pending_events();
+            try_remove_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("mix-owner-unbonding", &[]),
+            )
+            .unwrap();
+
+            let res = try_redelegate(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                mix_id_unbonding,
+                to_mix_id,
+            );
+            assert!(res.is_ok());
+
+            let res = try_redelegate(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                mix_id_unbonded_leftover,
+                to_mix_id,
+            );
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn correctly_pushes_appropriate_epoch_event() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 10000u32, from_mix_id);
+            test.add_immediate_delegation_with_legal_proxy(owner, 10000u32, from_mix_id);
+
+            try_redelegate(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from_mix_id,
+                to_mix_id,
+            )
+            .unwrap();
+            try_redelegate_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(test.vesting_contract().as_str(), &[]),
+                from_mix_id,
+                to_mix_id,
+                owner.into(),
+            )
+            .unwrap();
+
+            let events = test.pending_epoch_events();
+
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id,
+                    to_mix_id,
+                    proxy: None
+                }
+            );
+
+            assert_eq!(
+                events[1].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id,
+                    to_mix_id,
+                    proxy: Some(test.vesting_contract())
+                }
+            );
+        }
+
+        #[test]
+        fn fails_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let illegal_proxy = Addr::unchecked("not-vesting-contract");
+            let vesting_contract = test.vesting_contract();
+
+            let owner = "delegator";
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation_with_illegal_proxy(
+                owner,
+                10000u32,
+                from_mix_id,
+                illegal_proxy.clone(),
+            );
+
+            let res = try_redelegate_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(illegal_proxy.as_ref(), &[coin(123, TEST_COIN_DENOM)]),
+                from_mix_id,
+                to_mix_id,
+                owner.into(),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                res,
+                MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy,
+                    vesting_contract
+                }
+            )
+        }
+    }
 }
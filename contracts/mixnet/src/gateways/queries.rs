@@ -5,6 +5,7 @@ use super::storage;
 use crate::constants::{GATEWAY_BOND_DEFAULT_RETRIEVAL_LIMIT, GATEWAY_BOND_MAX_RETRIEVAL_LIMIT}; // Keeps gateway and mixnode retrieval in sync by re-using the constant. Could be split into its own constant.
 use cosmwasm_std::{Deps, Order, StdResult};
 use cw_storage_plus::Bound;
+use mixnet_contract_common::bond_transfer::PendingGatewayTransferResponse;
 use mixnet_contract_common::{
     GatewayBond, GatewayBondResponse, GatewayOwnershipResponse, IdentityKey, PagedGatewayResponse,
 };
@@ -56,6 +57,16 @@ pub fn query_gateway_bond(deps: Deps<'_>, identity: IdentityKey) -> StdResult<Ga
     })
 }
 
+pub(crate) fn query_pending_gateway_transfer(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+) -> StdResult<PendingGatewayTransferResponse> {
+    Ok(PendingGatewayTransferResponse {
+        proposal: storage::PENDING_GATEWAY_TRANSFERS.may_load(deps.storage, &identity)?,
+        identity,
+    })
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
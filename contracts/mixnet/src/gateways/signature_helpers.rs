@@ -4,8 +4,12 @@
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::decode_ed25519_identity_key;
 use cosmwasm_std::{Addr, Coin, Deps};
+use mixnet_contract_common::bond_transfer::BondTransferProposal;
 use mixnet_contract_common::error::MixnetContractError;
-use mixnet_contract_common::{construct_gateway_bonding_sign_payload, Gateway};
+use mixnet_contract_common::{
+    construct_gateway_bonding_sign_payload, construct_gateway_transfer_sign_payload, Gateway,
+    IdentityKey,
+};
 use nym_contracts_common::signing::MessageSignature;
 use nym_contracts_common::signing::Verifier;
 
@@ -30,3 +34,25 @@ pub(crate) fn verify_gateway_bonding_signature(
         Err(MixnetContractError::InvalidEd25519Signature)
     }
 }
+
+pub(crate) fn verify_gateway_transfer_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    previous_identity_key: IdentityKey,
+    proposal: BondTransferProposal,
+    signature: MessageSignature,
+) -> Result<(), MixnetContractError> {
+    // recover the public key of the NEW identity
+    let public_key = decode_ed25519_identity_key(&proposal.new_identity_key)?;
+
+    // reconstruct the payload
+    let nonce = signing_storage::get_signing_nonce(deps.storage, sender.clone())?;
+    let msg =
+        construct_gateway_transfer_sign_payload(nonce, sender, previous_identity_key, proposal);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(MixnetContractError::InvalidEd25519Signature)
+    }
+}
@@ -3,19 +3,24 @@
 
 use super::helpers::must_get_gateway_bond_by_owner;
 use super::storage;
-use crate::gateways::signature_helpers::verify_gateway_bonding_signature;
+use crate::gateways::signature_helpers::{
+    verify_gateway_bonding_signature, verify_gateway_transfer_signature,
+};
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
     ensure_no_existing_bond, ensure_proxy_match, ensure_sent_by_vesting_contract, validate_pledge,
 };
 use cosmwasm_std::{wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response};
+use mixnet_contract_common::bond_transfer::BondTransferProposal;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_gateway_bonding_event, new_gateway_config_update_event, new_gateway_unbonding_event,
+    new_gateway_bonding_event, new_gateway_config_update_event,
+    new_gateway_transfer_cancellation_event, new_gateway_transfer_event,
+    new_gateway_transfer_proposal_event, new_gateway_unbonding_event,
 };
 use mixnet_contract_common::gateway::GatewayConfigUpdate;
-use mixnet_contract_common::{Gateway, GatewayBond};
+use mixnet_contract_common::{Gateway, GatewayBond, IdentityKey, SphinxKey};
 use nym_contracts_common::signing::MessageSignature;
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
@@ -170,8 +175,9 @@ pub(crate) fn _try_remove_gateway(
         amount: vec![gateway_bond.pledge_amount()],
     };
 
-    // remove the bond
+    // remove the bond alongside any transfer that might have been proposed
     storage::gateways().remove(deps.storage, gateway_bond.identity())?;
+    storage::PENDING_GATEWAY_TRANSFERS.remove(deps.storage, gateway_bond.identity());
 
     let mut response = Response::new().add_message(return_tokens);
 
@@ -245,6 +251,143 @@ pub(crate) fn _try_update_gateway_config(
     Ok(Response::new().add_event(cfg_update_event))
 }
 
+pub(crate) fn try_propose_gateway_transfer(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    new_owner: String,
+    new_identity_key: Option<IdentityKey>,
+    new_sphinx_key: Option<SphinxKey>,
+) -> Result<Response, MixnetContractError> {
+    let existing_bond = must_get_gateway_bond_by_owner(deps.storage, &info.sender)?;
+
+    // the vesting contract keeps track of the pledged tokens on per-account basis,
+    // so we can't just move such bond elsewhere
+    if existing_bond.proxy.is_some() {
+        return Err(MixnetContractError::VestingBondTransfer);
+    }
+
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let proposal = BondTransferProposal {
+        current_owner: info.sender,
+        new_owner,
+        new_identity_key: new_identity_key
+            .unwrap_or_else(|| existing_bond.gateway.identity_key.clone()),
+        new_sphinx_key: new_sphinx_key.unwrap_or_else(|| existing_bond.gateway.sphinx_key.clone()),
+        proposal_height: env.block.height,
+    };
+
+    if !proposal.changes_owner()
+        && proposal.new_identity_key == existing_bond.gateway.identity_key
+        && proposal.new_sphinx_key == existing_bond.gateway.sphinx_key
+    {
+        return Err(MixnetContractError::EmptyBondTransfer);
+    }
+
+    // this is going to get checked again upon accepting the transfer,
+    // but there's no point in allowing the proposal if it's already invalid
+    if proposal.changes_owner() {
+        ensure_no_existing_bond(&proposal.new_owner, deps.storage)?;
+    }
+
+    // note: this overwrites any previous proposal made for this gateway
+    storage::PENDING_GATEWAY_TRANSFERS.save(deps.storage, existing_bond.identity(), &proposal)?;
+
+    Ok(
+        Response::new().add_event(new_gateway_transfer_proposal_event(
+            existing_bond.identity(),
+            &proposal.current_owner,
+            &proposal,
+        )),
+    )
+}
+
+pub(crate) fn try_cancel_gateway_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    let existing_bond = must_get_gateway_bond_by_owner(deps.storage, &info.sender)?;
+
+    if !storage::PENDING_GATEWAY_TRANSFERS.has(deps.storage, existing_bond.identity()) {
+        return Err(MixnetContractError::NoPendingBondTransfer);
+    }
+    storage::PENDING_GATEWAY_TRANSFERS.remove(deps.storage, existing_bond.identity());
+
+    Ok(
+        Response::new().add_event(new_gateway_transfer_cancellation_event(
+            existing_bond.identity(),
+            &info.sender,
+        )),
+    )
+}
+
+pub(crate) fn try_accept_gateway_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    identity: IdentityKey,
+    identity_signature: MessageSignature,
+) -> Result<Response, MixnetContractError> {
+    let proposal = storage::PENDING_GATEWAY_TRANSFERS
+        .may_load(deps.storage, &identity)?
+        .ok_or(MixnetContractError::NoPendingBondTransfer)?;
+
+    if info.sender != proposal.new_owner {
+        return Err(MixnetContractError::BondTransferRecipientMismatch {
+            expected: proposal.new_owner,
+            received: info.sender,
+        });
+    }
+
+    // proposals are removed whenever the gateway unbonds, so the bond MUST still exist
+    let existing_bond = match storage::gateways().may_load(deps.storage, &identity)? {
+        Some(bond) if bond.owner == proposal.current_owner => bond,
+        _ => {
+            return Err(MixnetContractError::inconsistent_state(
+                "the gateway bond has changed since the transfer has been proposed",
+            ))
+        }
+    };
+
+    if proposal.changes_owner() {
+        ensure_no_existing_bond(&proposal.new_owner, deps.storage)?;
+    }
+
+    // check if somebody else has already bonded a gateway with the new identity
+    if proposal.new_identity_key != identity {
+        if let Some(other_bond) =
+            storage::gateways().may_load(deps.storage, &proposal.new_identity_key)?
+        {
+            return Err(MixnetContractError::DuplicateGateway {
+                owner: other_bond.owner,
+            });
+        }
+    }
+
+    // check if the new owner actually controls the (new) gateway identity by checking the signature
+    verify_gateway_transfer_signature(
+        deps.as_ref(),
+        info.sender.clone(),
+        identity.clone(),
+        proposal.clone(),
+        identity_signature,
+    )?;
+
+    // update the signing nonce associated with this sender so that the future signature would be made on the new value
+    signing_storage::increment_signing_nonce(deps.storage, info.sender)?;
+
+    let mut updated_bond = existing_bond.clone();
+    updated_bond.owner = proposal.new_owner.clone();
+    updated_bond.gateway.identity_key = proposal.new_identity_key.clone();
+    updated_bond.gateway.sphinx_key = proposal.new_sphinx_key.clone();
+
+    // gateways are keyed by their identities, so the old entry has to be removed explicitly
+    storage::gateways().replace(deps.storage, &identity, None, Some(&existing_bond))?;
+    storage::gateways().save(deps.storage, updated_bond.identity(), &updated_bond)?;
+    storage::PENDING_GATEWAY_TRANSFERS.remove(deps.storage, &identity);
+
+    Ok(Response::new().add_event(new_gateway_transfer_event(&identity, &proposal)))
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -633,4 +776,149 @@ pub mod tests {
             }
         )
     }
+
+    mod transferring_gateway {
+        use super::*;
+        use crate::support::tests::test_helpers::ed25519_sign_message;
+        use mixnet_contract_common::construct_gateway_transfer_sign_payload;
+        use nym_crypto::asymmetric::identity::{KeyPair, PrivateKey};
+
+        fn transfer_signature(
+            test: &TestSetup,
+            new_owner: &str,
+            identity: &str,
+            key: &PrivateKey,
+        ) -> MessageSignature {
+            let new_owner = Addr::unchecked(new_owner);
+            let proposal = storage::PENDING_GATEWAY_TRANSFERS
+                .load(test.deps().storage, identity)
+                .unwrap();
+            let nonce =
+                signing_storage::get_signing_nonce(test.deps().storage, new_owner.clone()).unwrap();
+            let msg = construct_gateway_transfer_sign_payload(
+                nonce,
+                new_owner,
+                identity.to_string(),
+                proposal,
+            );
+            ed25519_sign_message(msg, key)
+        }
+
+        #[test]
+        fn is_not_allowed_for_bonds_made_with_vesting_tokens() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "alice";
+            test.add_dummy_gateway_with_legal_proxy(owner, None);
+
+            let res = try_propose_gateway_transfer(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                "bob".to_string(),
+                None,
+                None,
+            );
+            assert_eq!(res, Err(MixnetContractError::VestingBondTransfer));
+        }
+
+        #[test]
+        fn moves_the_bond_under_new_identity() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("alice", None);
+
+            let new_keypair = KeyPair::new(&mut test.rng);
+            let new_identity = new_keypair.public_key().to_base58_string();
+
+            try_propose_gateway_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                Some(new_identity.clone()),
+                None,
+            )
+            .unwrap();
+
+            let signature =
+                transfer_signature(&test, "charlie", &identity, new_keypair.private_key());
+            let res = try_accept_gateway_transfer(
+                test.deps_mut(),
+                mock_info("charlie", &[]),
+                identity.clone(),
+                signature,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::BondTransferRecipientMismatch {
+                    expected: Addr::unchecked("bob"),
+                    received: Addr::unchecked("charlie"),
+                })
+            );
+
+            let signature = transfer_signature(&test, "bob", &identity, new_keypair.private_key());
+            try_accept_gateway_transfer(
+                test.deps_mut(),
+                mock_info("bob", &[]),
+                identity.clone(),
+                signature,
+            )
+            .unwrap();
+
+            assert!(storage::gateways()
+                .may_load(test.deps().storage, &identity)
+                .unwrap()
+                .is_none());
+            let bond = storage::gateways()
+                .load(test.deps().storage, &new_identity)
+                .unwrap();
+            assert_eq!(bond.owner, Addr::unchecked("bob"));
+            assert_eq!(bond.gateway.identity_key, new_identity);
+
+            let owned = queries::query_owned_gateway(test.deps(), "bob".to_string()).unwrap();
+            assert_eq!(owned.gateway, Some(bond));
+            let owned = queries::query_owned_gateway(test.deps(), "alice".to_string()).unwrap();
+            assert!(owned.gateway.is_none());
+
+            assert!(storage::PENDING_GATEWAY_TRANSFERS
+                .may_load(test.deps().storage, &identity)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn is_cleared_upon_unbonding_or_cancellation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let identity = test.add_dummy_gateway("alice", None);
+
+            let res = try_cancel_gateway_transfer(test.deps_mut(), mock_info("alice", &[]));
+            assert_eq!(res, Err(MixnetContractError::NoPendingBondTransfer));
+
+            try_propose_gateway_transfer(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+            try_cancel_gateway_transfer(test.deps_mut(), mock_info("alice", &[])).unwrap();
+            assert!(!storage::PENDING_GATEWAY_TRANSFERS.has(test.deps().storage, &identity));
+
+            try_propose_gateway_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+            try_remove_gateway(test.deps_mut(), mock_info("alice", &[])).unwrap();
+            assert!(!storage::PENDING_GATEWAY_TRANSFERS.has(test.deps().storage, &identity));
+        }
+    }
 }
@@ -1,15 +1,16 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{Addr, Coin, DepsMut, Env, Response};
+use cosmwasm_std::{Addr, Coin, Decimal, DepsMut, Env, Response, Storage};
 
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_active_set_update_event, new_delegation_event, new_delegation_on_unbonded_node_event,
     new_mixnode_cost_params_update_event, new_mixnode_unbonding_event, new_pledge_decrease_event,
-    new_pledge_increase_event, new_rewarding_params_update_event, new_undelegation_event,
+    new_pledge_increase_event, new_redelegation_event, new_redelegation_on_unbonded_node_event,
+    new_rewarding_params_update_event, new_undelegation_event,
 };
-use mixnet_contract_common::mixnode::MixNodeCostParams;
+use mixnet_contract_common::mixnode::{MixNodeCostParams, MixNodeRewarding};
 use mixnet_contract_common::pending_events::{
     PendingEpochEventData, PendingEpochEventKind, PendingIntervalEventData,
     PendingIntervalEventKind,
@@ -75,9 +76,39 @@ pub(crate) fn delegate(
         }
     };
 
-    let new_delegation_amount = amount.clone();
-    let mut mix_rewarding = mixnode_details.rewarding_details;
+    let cosmos_event_amount = amount.clone();
+    let unit_reward = add_delegation(
+        deps.storage,
+        env,
+        owner.clone(),
+        mix_id,
+        mixnode_details.rewarding_details,
+        amount,
+        proxy.clone(),
+    )?;
+
+    let cosmos_event = new_delegation_event(
+        created_at,
+        &owner,
+        &proxy,
+        &cosmos_event_amount,
+        mix_id,
+        unit_reward,
+    );
 
+    Ok(Response::new().add_event(cosmos_event))
+}
+
+// adds the specified amount to the delegation on the (bonded) mixnode and returns the current unit reward
+fn add_delegation(
+    storage: &mut dyn Storage,
+    env: &Env,
+    owner: Addr,
+    mix_id: MixId,
+    mut mix_rewarding: MixNodeRewarding,
+    amount: Coin,
+    proxy: Option<Addr>,
+) -> Result<Decimal, MixnetContractError> {
     // the delegation_amount might get increased if there's already a pre-existing delegation on this mixnode
     // (in that case we just create a fresh delegation with the sum of both)
     let mut stored_delegation_amount = amount;
@@ -86,7 +117,7 @@ pub(crate) fn delegate(
     // with the sum of both
     let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
     let old_delegation = if let Some(existing_delegation) =
-        delegations_storage::delegations().may_load(deps.storage, storage_key.clone())?
+        delegations_storage::delegations().may_load(storage, storage_key.clone())?
     {
         // completely remove the delegation from the node
         let og_with_reward = mix_rewarding.undelegate(&existing_delegation)?;
@@ -103,15 +134,6 @@ pub(crate) fn delegate(
     // add the amount we're intending to delegate (whether it's fresh or we're adding to the existing one)
     mix_rewarding.add_base_delegation(stored_delegation_amount.amount)?;
 
-    let cosmos_event = new_delegation_event(
-        created_at,
-        &owner,
-        &proxy,
-        &new_delegation_amount,
-        mix_id,
-        mix_rewarding.total_unit_reward,
-    );
-
     let delegation = Delegation::new(
         owner,
         mix_id,
@@ -123,14 +145,14 @@ pub(crate) fn delegate(
 
     // save on reading since `.save()` would have attempted to read old data that we already have on hand
     delegations_storage::delegations().replace(
-        deps.storage,
+        storage,
         storage_key,
         Some(&delegation),
         old_delegation.as_ref(),
     )?;
-    rewards_storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
+    rewards_storage::MIXNODE_REWARDING.save(storage, mix_id, &mix_rewarding)?;
 
-    Ok(Response::new().add_event(cosmos_event))
+    Ok(mix_rewarding.total_unit_reward)
 }
 
 pub(crate) fn undelegate(
@@ -171,6 +193,100 @@ pub(crate) fn undelegate(
     Ok(response)
 }
 
+pub(crate) fn redelegate(
+    deps: DepsMut<'_>,
+    env: &Env,
+    created_at: BlockHeight,
+    owner: Addr,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // see if the delegation still exists (the user might have undelegated in the meantime)
+    let storage_key = Delegation::generate_storage_key(from_mix_id, &owner, proxy.as_ref());
+    let delegation = match delegations_storage::delegations().may_load(deps.storage, storage_key)? {
+        None => return Ok(Response::default()),
+        Some(delegation) => delegation,
+    };
+
+    // note: the source node might be unbonding or might have even unbonded already,
+    // but its rewarding information is kept for as long as there are any delegations left
+    let source_rewarding =
+        rewards_storage::MIXNODE_REWARDING.may_load(deps.storage, from_mix_id)?.ok_or(MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
+        ))?;
+
+    // remove the delegation (alongside all the earned rewards) from the source node
+    // note: this also appropriately adjusts the storage
+    let tokens = delegations::helpers::undelegate(deps.storage, delegation, source_rewarding)?;
+
+    // check if the target node still exists, similarly to a regular delegation
+    let target_details = match get_mixnode_details_by_id(deps.storage, to_mix_id)? {
+        Some(details)
+            if details.rewarding_details.still_bonded()
+                && !details.bond_information.is_unbonding =>
+        {
+            details
+        }
+        _ => {
+            // if the target is no longer bonded or in the process of unbonding, treat it as an undelegation
+            // and return the tokens back to the delegator
+            // (read the notes regarding possible epoch progressiong halting behaviour in `maybe_add_track_undelegation_message`)
+            let return_tokens = send_to_proxy_or_owner(&proxy, &owner, vec![tokens.clone()]);
+            let response = Response::new()
+                .add_message(return_tokens)
+                .add_event(new_redelegation_on_unbonded_node_event(
+                    created_at,
+                    &owner,
+                    &proxy,
+                    &tokens,
+                    from_mix_id,
+                    to_mix_id,
+                ))
+                .maybe_add_track_vesting_undelegation_message(
+                    deps.storage,
+                    proxy,
+                    owner.to_string(),
+                    from_mix_id,
+                    tokens,
+                )?;
+
+            return Ok(response);
+        }
+    };
+
+    // the tokens never leave the contract, they're just moved onto the target node
+    let unit_reward = add_delegation(
+        deps.storage,
+        env,
+        owner.clone(),
+        to_mix_id,
+        target_details.rewarding_details,
+        tokens.clone(),
+        proxy.clone(),
+    )?;
+
+    let response = Response::new()
+        .add_event(new_redelegation_event(
+            created_at,
+            &owner,
+            &proxy,
+            &tokens,
+            from_mix_id,
+            to_mix_id,
+            unit_reward,
+        ))
+        .maybe_add_track_vesting_redelegation_message(
+            deps.storage,
+            proxy,
+            owner.into_string(),
+            from_mix_id,
+            to_mix_id,
+        )?;
+
+    Ok(response)
+}
+
 pub(crate) fn unbond_mixnode(
     deps: DepsMut<'_>,
     env: &Env,
@@ -356,6 +472,20 @@ impl ContractExecutableEvent for PendingEpochEventData {
                 mix_id,
                 proxy,
             } => undelegate(deps, self.created_at, owner, mix_id, proxy),
+            PendingEpochEventKind::Redelegate {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                proxy,
+            } => redelegate(
+                deps,
+                env,
+                self.created_at,
+                owner,
+                from_mix_id,
+                to_mix_id,
+                proxy,
+            ),
             PendingEpochEventKind::PledgeMore { mix_id, amount } => {
                 increase_pledge(deps, self.created_at, mix_id, amount)
             }
@@ -1112,6 +1242,239 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod redelegating {
+        use cosmwasm_std::{coin, to_binary, CosmosMsg, WasmMsg};
+
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::get_bank_send_msg;
+
+        use super::*;
+
+        fn delegation_exists(
+            test: &TestSetup,
+            mix_id: MixId,
+            owner: &str,
+            proxy: Option<&Addr>,
+        ) -> bool {
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(owner), proxy);
+            delegations_storage::delegations()
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_some()
+        }
+
+        #[test]
+        fn doesnt_do_anything_if_delegation_doesnt_exist() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let env = test.env();
+            let owner = Addr::unchecked("delegator");
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                owner,
+                from_mix_id,
+                to_mix_id,
+                None,
+            )
+            .unwrap();
+            assert_eq!(res, Response::default());
+            assert!(!delegation_exists(&test, to_mix_id, "delegator", None));
+        }
+
+        #[test]
+        fn moves_delegation_alongside_earned_rewards_without_returning_tokens() {
+            let mut test = TestSetup::new();
+            let from_mix_id =
+                test.add_dummy_mixnode("mix-owner1", Some(100_000_000_000u128.into()));
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+
+            test.force_change_rewarded_set(vec![from_mix_id]);
+            test.skip_to_next_epoch_end();
+            let dist = test.reward_with_distribution_with_state_bypass(
+                from_mix_id,
+                test_helpers::performance(100.0),
+            );
+            let expected_amount = delegation + truncate_reward_amount(dist.delegates).u128();
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                None,
+            )
+            .unwrap();
+
+            // the tokens never left the contract
+            assert!(get_bank_send_msg(&res).is_none());
+            assert!(res.messages.is_empty());
+
+            assert!(!delegation_exists(&test, from_mix_id, owner, None));
+            let source_rewarding = test.mix_rewarding(from_mix_id);
+            assert!(source_rewarding.delegates.is_zero());
+            assert_eq!(source_rewarding.unique_delegations, 0);
+
+            let new_delegation = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(new_delegation.amount.amount.u128(), expected_amount);
+            let target_rewarding = test.mix_rewarding(to_mix_id);
+            assert_eq!(target_rewarding.unique_delegations, 1);
+            assert_decimals(
+                target_rewarding.delegates,
+                Decimal::from_atomics(expected_amount, 0).unwrap(),
+            );
+        }
+
+        #[test]
+        fn is_merged_with_existing_delegation_on_target_mixnode() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            test.add_immediate_delegation(owner, 120_000_000u128, from_mix_id);
+            test.add_immediate_delegation(owner, 80_000_000u128, to_mix_id);
+
+            let env = test.env();
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                None,
+            )
+            .unwrap();
+
+            let new_delegation = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(new_delegation.amount, coin(200_000_000, TEST_COIN_DENOM));
+            assert_eq!(test.mix_rewarding(to_mix_id).unique_delegations, 1);
+        }
+
+        #[test]
+        fn works_if_source_mixnode_has_unbonded() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+            test.immediately_unbond_mixnode(from_mix_id);
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                None,
+            )
+            .unwrap();
+            assert!(get_bank_send_msg(&res).is_none());
+
+            assert!(!delegation_exists(&test, from_mix_id, owner, None));
+            let new_delegation = test.delegation(to_mix_id, owner, &None);
+            assert_eq!(new_delegation.amount, coin(delegation, TEST_COIN_DENOM));
+        }
+
+        #[test]
+        fn returns_the_tokens_if_target_mixnode_has_unbonded() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from_mix_id);
+            test.immediately_unbond_mixnode(to_mix_id);
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                None,
+            )
+            .unwrap();
+
+            assert!(!delegation_exists(&test, from_mix_id, owner, None));
+            assert!(!delegation_exists(&test, to_mix_id, owner, None));
+
+            let (receiver, sent_amount) = get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, owner);
+            assert_eq!(sent_amount[0], coin(delegation, TEST_COIN_DENOM));
+        }
+
+        #[test]
+        fn attaches_vesting_contract_track_message() {
+            let mut test = TestSetup::new();
+            let from_mix_id = test.add_dummy_mixnode("mix-owner1", None);
+            let to_mix_id = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let vesting_contract = test.vesting_contract();
+            test.add_immediate_delegation_with_legal_proxy(owner, 120_000_000u128, from_mix_id);
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from_mix_id,
+                to_mix_id,
+                Some(vesting_contract.clone()),
+            )
+            .unwrap();
+            assert!(delegation_exists(
+                &test,
+                to_mix_id,
+                owner,
+                Some(&vesting_contract)
+            ));
+
+            assert_eq!(res.messages.len(), 1);
+            let CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) = &res.messages[0].msg
+            else {
+                panic!("expected wasm execute message")
+            };
+            assert_eq!(contract_addr, vesting_contract.as_str());
+            let expected_msg = to_binary(&VestingContractExecuteMsg::TrackRedelegation {
+                owner: owner.to_string(),
+                from_mix_id,
+                to_mix_id,
+            })
+            .unwrap();
+            assert_eq!(&expected_msg, msg);
+            assert!(funds.is_empty())
+        }
+    }
+
     #[cfg(test)]
     mod mixnode_unbonding {
         use cosmwasm_std::{coin, to_binary, CosmosMsg, Uint128, WasmMsg};
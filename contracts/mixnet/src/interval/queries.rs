@@ -7,6 +7,7 @@ use crate::constants::{
     REWARDED_SET_DEFAULT_RETRIEVAL_LIMIT, REWARDED_SET_MAX_RETRIEVAL_LIMIT,
 };
 use crate::interval::storage;
+use crate::rewards::storage as rewards_storage;
 use cosmwasm_std::{Deps, Env, Order, StdResult};
 use cw_storage_plus::Bound;
 use mixnet_contract_common::error::MixnetContractError;
@@ -15,12 +16,30 @@ use mixnet_contract_common::{
     CurrentIntervalResponse, EpochEventId, EpochStatus, IntervalEventId, MixId,
     NumberOfPendingEventsResponse, PagedRewardedSetResponse, PendingEpochEventResponse,
     PendingEpochEventsResponse, PendingIntervalEventResponse, PendingIntervalEventsResponse,
+    RewardedSetSelectionResponse, RewardedSetSelectionSeedResponse,
 };
 
 pub fn query_epoch_status(deps: Deps<'_>) -> StdResult<EpochStatus> {
     storage::current_epoch_status(deps.storage)
 }
 
+pub fn query_rewarded_set_selection(deps: Deps<'_>) -> StdResult<RewardedSetSelectionResponse> {
+    let interval = storage::current_interval(deps.storage)?;
+
+    Ok(RewardedSetSelectionResponse {
+        epoch_id: interval.current_epoch_absolute_id(),
+        selection: storage::rewarded_set_selection(deps.storage)?,
+    })
+}
+
+pub fn query_rewarded_set_selection_seed(
+    deps: Deps<'_>,
+) -> StdResult<RewardedSetSelectionSeedResponse> {
+    Ok(RewardedSetSelectionSeedResponse {
+        seed: storage::rewarded_set_selection_seed(deps.storage)?,
+    })
+}
+
 pub fn query_current_interval_details(
     deps: Deps<'_>,
     env: Env,
@@ -137,9 +156,12 @@ pub fn query_number_of_pending_events(
     let last_executed_interval_id = storage::LAST_PROCESSED_INTERVAL_EVENT.load(deps.storage)?;
     let last_inserted_interval_id = storage::INTERVAL_EVENT_ID_COUNTER.load(deps.storage)?;
 
+    let current_epoch = storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+
     Ok(NumberOfPendingEventsResponse {
         epoch_events: last_inserted_epoch_id - last_executed_epoch_id,
         interval_events: last_inserted_interval_id - last_executed_interval_id,
+        due_compoundings: rewards_storage::count_due_compoundings(deps.storage, current_epoch),
     })
 }
 
@@ -646,7 +668,8 @@ mod tests {
         assert_eq!(
             Ok(NumberOfPendingEventsResponse {
                 epoch_events: 0,
-                interval_events: 0
+                interval_events: 0,
+                due_compoundings: 0,
             }),
             query_number_of_pending_events(test.deps())
         );
@@ -656,7 +679,8 @@ mod tests {
         assert_eq!(
             Ok(NumberOfPendingEventsResponse {
                 epoch_events: 1,
-                interval_events: 0
+                interval_events: 0,
+                due_compoundings: 0,
             }),
             query_number_of_pending_events(test.deps())
         );
@@ -666,7 +690,8 @@ mod tests {
         assert_eq!(
             Ok(NumberOfPendingEventsResponse {
                 epoch_events: 43,
-                interval_events: 0
+                interval_events: 0,
+                due_compoundings: 0,
             }),
             query_number_of_pending_events(test.deps())
         );
@@ -677,7 +702,8 @@ mod tests {
         assert_eq!(
             Ok(NumberOfPendingEventsResponse {
                 epoch_events: 43,
-                interval_events: 1
+                interval_events: 1,
+                due_compoundings: 0,
             }),
             query_number_of_pending_events(test.deps())
         );
@@ -687,7 +713,8 @@ mod tests {
         assert_eq!(
             Ok(NumberOfPendingEventsResponse {
                 epoch_events: 43,
-                interval_events: 43
+                interval_events: 43,
+                due_compoundings: 0,
             }),
             query_number_of_pending_events(test.deps())
         );
@@ -5,6 +5,7 @@ use crate::constants::{
     CURRENT_EPOCH_STATUS_KEY, CURRENT_INTERVAL_KEY, EPOCH_EVENT_ID_COUNTER_KEY,
     INTERVAL_EVENT_ID_COUNTER_KEY, LAST_EPOCH_EVENT_ID_KEY, LAST_INTERVAL_EVENT_ID_KEY,
     PENDING_EPOCH_EVENTS_NAMESPACE, PENDING_INTERVAL_EVENTS_NAMESPACE, REWARDED_SET_KEY,
+    REWARDED_SET_SELECTION_KEY, REWARDED_SET_SELECTION_SEED_KEY,
 };
 use cosmwasm_std::{Addr, Env, Order, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
@@ -13,7 +14,7 @@ use mixnet_contract_common::pending_events::{
 };
 use mixnet_contract_common::{
     EpochEventId, EpochStatus, Interval, IntervalEventId, MixId, PendingIntervalEventKind,
-    RewardedSetNodeStatus,
+    RewardedSetNodeStatus, RewardedSetSelection, RewardedSetSelectionSeed,
 };
 use std::collections::HashMap;
 
@@ -21,6 +22,14 @@ pub(crate) const CURRENT_EPOCH_STATUS: Item<'_, EpochStatus> = Item::new(CURRENT
 pub(crate) const CURRENT_INTERVAL: Item<'_, Interval> = Item::new(CURRENT_INTERVAL_KEY);
 pub(crate) const REWARDED_SET: Map<MixId, RewardedSetNodeStatus> = Map::new(REWARDED_SET_KEY);
 
+/// Information about the selection of the current rewarded set, if it was performed using a verifiable algorithm.
+pub(crate) const REWARDED_SET_SELECTION: Item<'_, RewardedSetSelection> =
+    Item::new(REWARDED_SET_SELECTION_KEY);
+
+/// Seed for the rewarded set selection fixed at the beginning of the most recent epoch transition.
+pub(crate) const REWARDED_SET_SELECTION_SEED: Item<'_, RewardedSetSelectionSeed> =
+    Item::new(REWARDED_SET_SELECTION_SEED_KEY);
+
 pub(crate) const EPOCH_EVENT_ID_COUNTER: Item<EpochEventId> = Item::new(EPOCH_EVENT_ID_COUNTER_KEY);
 pub(crate) const INTERVAL_EVENT_ID_COUNTER: Item<IntervalEventId> =
     Item::new(INTERVAL_EVENT_ID_COUNTER_KEY);
@@ -52,6 +61,38 @@ pub(crate) fn save_current_epoch_status(
     CURRENT_EPOCH_STATUS.save(storage, status)
 }
 
+pub(crate) fn rewarded_set_selection(
+    storage: &dyn Storage,
+) -> StdResult<Option<RewardedSetSelection>> {
+    REWARDED_SET_SELECTION.may_load(storage)
+}
+
+pub(crate) fn save_rewarded_set_selection(
+    storage: &mut dyn Storage,
+    selection: Option<&RewardedSetSelection>,
+) -> StdResult<()> {
+    match selection {
+        Some(selection) => REWARDED_SET_SELECTION.save(storage, selection),
+        None => {
+            REWARDED_SET_SELECTION.remove(storage);
+            Ok(())
+        }
+    }
+}
+
+pub(crate) fn rewarded_set_selection_seed(
+    storage: &dyn Storage,
+) -> StdResult<Option<RewardedSetSelectionSeed>> {
+    REWARDED_SET_SELECTION_SEED.may_load(storage)
+}
+
+pub(crate) fn save_rewarded_set_selection_seed(
+    storage: &mut dyn Storage,
+    seed: &RewardedSetSelectionSeed,
+) -> StdResult<()> {
+    REWARDED_SET_SELECTION_SEED.save(storage, seed)
+}
+
 pub(crate) fn current_interval(storage: &dyn Storage) -> StdResult<Interval> {
     CURRENT_INTERVAL.load(storage)
 }
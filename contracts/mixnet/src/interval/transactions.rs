@@ -15,11 +15,15 @@ use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_advance_epoch_event, new_epoch_transition_start_event,
-    new_pending_epoch_events_execution_event, new_pending_interval_config_update_event,
-    new_pending_interval_events_execution_event, new_reconcile_pending_events,
+    new_pending_compounding_execution_event, new_pending_epoch_events_execution_event,
+    new_pending_interval_config_update_event, new_pending_interval_events_execution_event,
+    new_reconcile_pending_events,
 };
 use mixnet_contract_common::pending_events::PendingIntervalEventKind;
-use mixnet_contract_common::{EpochState, EpochStatus, LayerAssignment, MixId};
+use mixnet_contract_common::{
+    EpochId, EpochState, EpochStatus, LayerAssignment, MixId, RewardedSetSelection,
+    RewardedSetSelectionSeed,
+};
 use std::collections::BTreeSet;
 
 // those two should be called in separate tx (from advancing epoch),
@@ -159,14 +163,35 @@ pub fn try_reconcile_epoch_events(
         response
             .events
             .push(new_pending_interval_events_execution_event(executed));
+
+        limit = limit.map(|l| l - executed)
     }
 
+    // and finally, once all the events got cleared, compound all the rewards that are due
+    // (so that they'd include the changes from the events)
+    let (mut sub_response, executed) =
+        rewards::transactions::perform_pending_compounding(deps.branch(), limit)?;
+    response.messages.append(&mut sub_response.messages);
+    response.attributes.append(&mut sub_response.attributes);
+    response.events.append(&mut sub_response.events);
+    response
+        .events
+        .push(new_pending_compounding_execution_event(executed));
+
     // if there are no more events to clear, go into the next state
-    let pending_events = super::queries::query_number_of_pending_events(deps.as_ref())?;
+    // (this is executed on every call, so the compoundings are not counted, only checked for existence)
+    let epoch_events_cleared = storage::LAST_PROCESSED_EPOCH_EVENT.load(deps.storage)?
+        == storage::EPOCH_EVENT_ID_COUNTER.load(deps.storage)?;
+    let interval_events_cleared = storage::LAST_PROCESSED_INTERVAL_EVENT.load(deps.storage)?
+        == storage::INTERVAL_EVENT_ID_COUNTER.load(deps.storage)?;
+    let compoundings_cleared =
+        !rewards_storage::any_due_compoundings(deps.storage, interval.current_epoch_absolute_id());
+
     // we can only progress if there are no epoch events AND if the interval has finished, that there are no interval events
-    let progress = if pending_events.epoch_events == 0 {
+    // (and, in either case, if there are no more rewards to compound)
+    let progress = if epoch_events_cleared && compoundings_cleared {
         if interval.is_current_interval_over(&env) {
-            pending_events.interval_events == 0
+            interval_events_cleared
         } else {
             true
         }
@@ -266,16 +291,48 @@ pub fn try_begin_epoch_transition(
         state: new_epoch_state,
     };
 
+    // fix the seed for the rewarded set selection now, so that it wouldn't be up to the rewarding validator
+    // to choose it (and potentially keep on retrying until it found one it liked)
+    let selection_seed = RewardedSetSelectionSeed::new(
+        &env.block.chain_id,
+        env.block.height,
+        env.block.time.nanos(),
+        current_interval.current_epoch_absolute_id() + 1,
+    );
+
     storage::save_current_epoch_status(deps.storage, &new_epoch_status)?;
+    storage::save_rewarded_set_selection_seed(deps.storage, &selection_seed)?;
     Ok(Response::new().add_event(new_epoch_transition_start_event(current_interval)))
 }
 
+fn ensure_valid_selection_seed(
+    storage: &dyn Storage,
+    selection: &RewardedSetSelection,
+    absolute_epoch_id: EpochId,
+) -> Result<(), MixnetContractError> {
+    let expected = storage::rewarded_set_selection_seed(storage)?
+        .filter(|seed| seed.absolute_epoch_id == absolute_epoch_id)
+        .ok_or(MixnetContractError::MissingRewardedSetSelectionSeed { absolute_epoch_id })?;
+
+    if !expected.matches(selection) {
+        return Err(MixnetContractError::RewardedSetSelectionSeedMismatch {
+            expected: expected.seed,
+            expected_height: expected.block_height,
+            provided: selection.seed.clone(),
+            provided_height: selection.seed_block_height,
+        });
+    }
+
+    Ok(())
+}
+
 pub fn try_advance_epoch(
     deps: DepsMut<'_>,
     env: Env,
     info: MessageInfo,
     layer_assignments: Vec<LayerAssignment>,
     expected_active_set_size: u32,
+    selection: Option<RewardedSetSelection>,
 ) -> Result<Response, MixnetContractError> {
     // Only rewarding validator can attempt to advance epoch
     let mut current_epoch_status = ensure_can_advance_epoch(&info.sender, deps.storage)?;
@@ -301,12 +358,22 @@ pub fn try_advance_epoch(
     let updated_interval = current_interval.advance_epoch();
     let num_nodes = layer_assignments.len();
 
+    if let Some(selection) = &selection {
+        ensure_valid_selection_seed(
+            deps.storage,
+            selection,
+            updated_interval.current_epoch_absolute_id(),
+        )?;
+    }
+
     let new_rewarded_set = layer_assignments.iter().map(|l| l.mix_id()).collect();
 
     // finally save updated interval and the rewarded set
     storage::save_interval(deps.storage, &updated_interval)?;
     update_rewarded_set(deps.storage, new_rewarded_set, expected_active_set_size)?;
 
+    storage::save_rewarded_set_selection(deps.storage, selection.as_ref())?;
+
     for a in layer_assignments {
         update_mixnode_layer(a.mix_id(), a.layer(), deps.storage)?;
     }
@@ -314,7 +381,11 @@ pub fn try_advance_epoch(
     current_epoch_status.state = EpochState::InProgress;
     storage::save_current_epoch_status(deps.storage, &current_epoch_status)?;
 
-    Ok(Response::new().add_event(new_advance_epoch_event(updated_interval, num_nodes as u32)))
+    Ok(Response::new().add_event(new_advance_epoch_event(
+        updated_interval,
+        num_nodes as u32,
+        selection.as_ref(),
+    )))
 }
 
 pub(crate) fn try_update_interval_config(
@@ -955,6 +1026,29 @@ mod tests {
             ));
         }
 
+        #[test]
+        fn fixes_the_rewarded_set_selection_seed() {
+            let mut test = TestSetup::new();
+            let rewarding_validator = test.rewarding_validator();
+
+            test.skip_to_current_epoch_end();
+            let env = test.env();
+            let next_epoch_id = test.current_interval().current_epoch_absolute_id() + 1;
+
+            try_begin_epoch_transition(test.deps_mut(), env.clone(), rewarding_validator).unwrap();
+
+            let expected = RewardedSetSelectionSeed::new(
+                &env.block.chain_id,
+                env.block.height,
+                env.block.time.nanos(),
+                next_epoch_id,
+            );
+            assert_eq!(
+                Some(expected),
+                storage::rewarded_set_selection_seed(test.deps().storage).unwrap()
+            )
+        }
+
         #[test]
         fn epoch_state_is_correctly_updated_for_empty_rewarded_set() {
             let mut test = TestSetup::new();
@@ -1011,6 +1105,7 @@ mod tests {
         };
         use mixnet_contract_common::pending_events::PendingEpochEventKind;
         use mixnet_contract_common::reward_params::IntervalRewardingParamsUpdate;
+        use mixnet_contract_common::rewarding::CompoundingFrequency;
 
         #[test]
         fn can_only_be_performed_if_in_reconciling_state() {
@@ -1090,6 +1185,55 @@ mod tests {
             )
         }
 
+        #[test]
+        fn epoch_state_is_not_updated_if_there_are_leftover_compoundings() {
+            let mut test = TestSetup::new();
+            let rewarding_validator = test.rewarding_validator();
+
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            for i in 0..5 {
+                let delegator = format!("delegator{i}");
+                test.add_immediate_delegation(&delegator, 100_000_000u128, mix_id);
+                rewards::transactions::try_update_delegation_compounding(
+                    test.deps_mut(),
+                    mock_info(&delegator, &[]),
+                    mix_id,
+                    Some(CompoundingFrequency::Epoch),
+                )
+                .unwrap();
+            }
+
+            test.skip_to_current_epoch_end();
+            push_n_dummy_epoch_actions(&mut test, 3);
+            test.set_epoch_reconciliation_state();
+
+            // 3 epoch events and 2 out of 5 compoundings
+            let env = test.env();
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator.clone(), Some(5))
+                .unwrap();
+
+            let expected = EpochStatus {
+                being_advanced_by: test.rewarding_validator().sender,
+                state: EpochState::ReconcilingEvents,
+            };
+            assert_eq!(
+                expected,
+                storage::current_epoch_status(test.deps().storage).unwrap()
+            );
+
+            let env = test.env();
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator, Some(5)).unwrap();
+
+            let expected = EpochStatus {
+                being_advanced_by: test.rewarding_validator().sender,
+                state: EpochState::AdvancingEpoch,
+            };
+            assert_eq!(
+                expected,
+                storage::current_epoch_status(test.deps().storage).unwrap()
+            )
+        }
+
         #[test]
         fn epoch_state_is_correctly_updated_if_even_with_leftover_interval_events_if_interval_is_not_over(
         ) {
@@ -1370,6 +1514,9 @@ mod tests {
             ));
             expected_events.push(new_pending_interval_events_execution_event(1));
 
+            // there were no compoundings scheduled
+            expected_events.push(new_pending_compounding_execution_event(0));
+
             test.skip_to_current_interval_end();
             test.set_epoch_reconciliation_state();
             let env = test.env();
@@ -1517,6 +1664,7 @@ mod tests {
                     sender,
                     layer_assignments,
                     current_active_set,
+                    None,
                 );
                 assert_eq!(
                     res,
@@ -1552,6 +1700,7 @@ mod tests {
                 sender,
                 layer_assignments,
                 current_active_set,
+                None,
             )
             .unwrap();
 
@@ -1565,6 +1714,124 @@ mod tests {
             )
         }
 
+        #[test]
+        fn rewarded_set_selection_is_updated() {
+            let mut test = TestSetup::new();
+            test.add_dummy_mixnode("1", Some(Uint128::new(100000000)));
+            test.add_dummy_mixnode("2", Some(Uint128::new(100000000)));
+            test.add_dummy_mixnode("3", Some(Uint128::new(100000000)));
+            let current_active_set = test.rewarding_params().active_set_size;
+
+            test.skip_to_current_epoch_end();
+            let selection = test.begin_transition_with_selection_seed();
+            test.set_epoch_advancement_state();
+
+            let layer_assignments = vec![
+                LayerAssignment::new(1, Layer::One),
+                LayerAssignment::new(2, Layer::Two),
+                LayerAssignment::new(3, Layer::Three),
+            ];
+
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender,
+                layer_assignments.clone(),
+                current_active_set,
+                Some(selection.clone()),
+            )
+            .unwrap();
+            assert_eq!(
+                Some(selection),
+                storage::rewarded_set_selection(test.deps().storage).unwrap()
+            );
+
+            // if the next rewarded set comes without any selection information, the old one is purged
+            test.skip_to_current_epoch_end();
+            test.set_epoch_advancement_state();
+
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender,
+                layer_assignments,
+                current_active_set,
+                None,
+            )
+            .unwrap();
+            assert!(storage::rewarded_set_selection(test.deps().storage)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn rejects_selection_with_seed_not_fixed_by_the_contract() {
+            let mut test = TestSetup::new();
+            test.add_dummy_mixnode("1", Some(Uint128::new(100000000)));
+            let current_active_set = test.rewarding_params().active_set_size;
+            let layer_assignments = vec![LayerAssignment::new(1, Layer::One)];
+
+            test.skip_to_current_epoch_end();
+            let selection = test.begin_transition_with_selection_seed();
+            test.set_epoch_advancement_state();
+
+            let mut different_seed = selection.clone();
+            different_seed.seed = "deadbeef".to_string();
+            let mut different_height = selection.clone();
+            different_height.seed_block_height += 1;
+
+            for bad_selection in [different_seed, different_height] {
+                let env = test.env();
+                let sender = test.rewarding_validator();
+                let res = try_advance_epoch(
+                    test.deps_mut(),
+                    env,
+                    sender,
+                    layer_assignments.clone(),
+                    current_active_set,
+                    Some(bad_selection),
+                );
+                assert!(matches!(
+                    res,
+                    Err(MixnetContractError::RewardedSetSelectionSeedMismatch { .. })
+                ));
+            }
+
+            // the seed fixed for the previous transition can't be reused either
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender,
+                layer_assignments.clone(),
+                current_active_set,
+                Some(selection.clone()),
+            )
+            .unwrap();
+
+            test.skip_to_current_epoch_end();
+            test.set_epoch_advancement_state();
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            let res = try_advance_epoch(
+                test.deps_mut(),
+                env,
+                sender,
+                layer_assignments,
+                current_active_set,
+                Some(selection),
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::MissingRewardedSetSelectionSeed { .. })
+            ));
+        }
+
         #[test]
         fn can_only_be_performed_by_specified_rewarding_validator() {
             let mut test = TestSetup::new();
@@ -1590,6 +1857,7 @@ mod tests {
                 some_sender,
                 layer_assignments.clone(),
                 current_active_set,
+                None,
             );
             assert_eq!(res, Err(MixnetContractError::Unauthorized));
 
@@ -1602,6 +1870,7 @@ mod tests {
                 sender,
                 layer_assignments,
                 current_active_set,
+                None,
             );
             assert!(res.is_ok())
         }
@@ -1631,6 +1900,7 @@ mod tests {
                 sender.clone(),
                 layer_assignments.clone(),
                 current_active_set,
+                None,
             );
             assert!(matches!(
                 res,
@@ -1665,6 +1935,7 @@ mod tests {
                 sender,
                 layer_assignments,
                 current_active_set,
+                None,
             );
             assert!(res.is_ok())
         }
@@ -1711,6 +1982,7 @@ mod tests {
                 sender,
                 layer_assignments.clone(),
                 current_active_set,
+                None,
             )
             .unwrap();
 
@@ -1732,6 +2004,7 @@ mod tests {
                 sender,
                 layer_assignments,
                 current_active_set,
+                None,
             )
             .unwrap();
 
@@ -1784,6 +2057,7 @@ mod tests {
                 sender,
                 layer_assignments,
                 current_active_set,
+                None,
             )
             .unwrap();
 
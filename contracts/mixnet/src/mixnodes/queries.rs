@@ -14,6 +14,7 @@ use crate::mixnodes::helpers::{
 use crate::rewards::storage as rewards_storage;
 use cosmwasm_std::{Deps, Order, StdResult, Storage};
 use cw_storage_plus::Bound;
+use mixnet_contract_common::bond_transfer::PendingMixnodeTransferResponse;
 use mixnet_contract_common::mixnode::{
     MixNodeBond, MixNodeDetails, MixnodeRewardingDetailsResponse, PagedMixnodesDetailsResponse,
     PagedUnbondedMixnodesResponse, StakeSaturationResponse, UnbondedMixnodeResponse,
@@ -243,6 +244,16 @@ pub(crate) fn query_layer_distribution(deps: Deps<'_>) -> StdResult<LayerDistrib
     storage::LAYERS.load(deps.storage)
 }
 
+pub(crate) fn query_pending_mixnode_transfer(
+    deps: Deps<'_>,
+    mix_id: MixId,
+) -> StdResult<PendingMixnodeTransferResponse> {
+    Ok(PendingMixnodeTransferResponse {
+        mix_id,
+        proposal: storage::PENDING_MIXNODE_TRANSFERS.may_load(deps.storage, mix_id)?,
+    })
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -4,8 +4,12 @@
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::decode_ed25519_identity_key;
 use cosmwasm_std::{Addr, Coin, Deps};
+use mixnet_contract_common::bond_transfer::BondTransferProposal;
 use mixnet_contract_common::error::MixnetContractError;
-use mixnet_contract_common::{construct_mixnode_bonding_sign_payload, MixNode, MixNodeCostParams};
+use mixnet_contract_common::{
+    construct_mixnode_bonding_sign_payload, construct_mixnode_transfer_sign_payload, MixId,
+    MixNode, MixNodeCostParams,
+};
 use nym_contracts_common::signing::MessageSignature;
 use nym_contracts_common::signing::Verifier;
 
@@ -32,3 +36,24 @@ pub(crate) fn verify_mixnode_bonding_signature(
         Err(MixnetContractError::InvalidEd25519Signature)
     }
 }
+
+pub(crate) fn verify_mixnode_transfer_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    mix_id: MixId,
+    proposal: BondTransferProposal,
+    signature: MessageSignature,
+) -> Result<(), MixnetContractError> {
+    // recover the public key of the NEW identity
+    let public_key = decode_ed25519_identity_key(&proposal.new_identity_key)?;
+
+    // reconstruct the payload
+    let nonce = signing_storage::get_signing_nonce(deps.storage, sender.clone())?;
+    let msg = construct_mixnode_transfer_sign_payload(nonce, sender, mix_id, proposal);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(MixnetContractError::InvalidEd25519Signature)
+    }
+}
@@ -3,25 +3,33 @@
 
 use cosmwasm_std::{coin, Addr, Coin, DepsMut, Env, MessageInfo, Response, Storage};
 
+use mixnet_contract_common::bond_transfer::BondTransferProposal;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_mixnode_bonding_event, new_mixnode_config_update_event,
-    new_mixnode_pending_cost_params_update_event, new_pending_mixnode_unbonding_event,
-    new_pending_pledge_decrease_event, new_pending_pledge_increase_event,
+    new_mixnode_pending_cost_params_update_event, new_mixnode_transfer_cancellation_event,
+    new_mixnode_transfer_event, new_mixnode_transfer_proposal_event,
+    new_pending_mixnode_unbonding_event, new_pending_pledge_decrease_event,
+    new_pending_pledge_increase_event,
 };
 use mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use mixnet_contract_common::pending_events::{PendingEpochEventKind, PendingIntervalEventKind};
-use mixnet_contract_common::{Layer, MixId, MixNode};
+use mixnet_contract_common::{IdentityKey, Layer, MixId, MixNode, SphinxKey};
 use nym_contracts_common::signing::MessageSignature;
 
+use crate::families::storage as families_storage;
 use crate::interval::storage as interval_storage;
 use crate::interval::storage::push_new_interval_event;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnet_contract_settings::storage::rewarding_denom;
 use crate::mixnodes::helpers::{
-    get_mixnode_details_by_owner, must_get_mixnode_bond_by_owner, save_new_mixnode,
+    get_mixnode_details_by_id, get_mixnode_details_by_owner, must_get_mixnode_bond_by_owner,
+    save_new_mixnode,
 };
-use crate::mixnodes::signature_helpers::verify_mixnode_bonding_signature;
+use crate::mixnodes::signature_helpers::{
+    verify_mixnode_bonding_signature, verify_mixnode_transfer_signature,
+};
+use crate::rewards::storage as rewards_storage;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
     ensure_bonded, ensure_epoch_in_progress_state, ensure_is_authorized, ensure_no_existing_bond,
@@ -372,6 +380,9 @@ pub(crate) fn _try_remove_mixnode(
     };
     interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
 
+    // the node is leaving, so there's nothing to transfer anymore
+    storage::PENDING_MIXNODE_TRANSFERS.remove(deps.storage, existing_bond.mix_id);
+
     Ok(
         Response::new().add_event(new_pending_mixnode_unbonding_event(
             &existing_bond.owner,
@@ -494,6 +505,171 @@ pub(crate) fn _try_update_mixnode_cost_params(
     Ok(Response::new().add_event(cosmos_event))
 }
 
+pub(crate) fn try_propose_mixnode_transfer(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    new_owner: String,
+    new_identity_key: Option<IdentityKey>,
+    new_sphinx_key: Option<SphinxKey>,
+) -> Result<Response, MixnetContractError> {
+    let existing_bond = must_get_mixnode_bond_by_owner(deps.storage, &info.sender)?;
+
+    // the vesting contract keeps track of the pledged tokens on per-account basis,
+    // so we can't just move such bond elsewhere
+    if existing_bond.proxy.is_some() {
+        return Err(MixnetContractError::VestingBondTransfer);
+    }
+    ensure_bonded(&existing_bond)?;
+
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let proposal = BondTransferProposal {
+        current_owner: info.sender,
+        new_owner,
+        new_identity_key: new_identity_key
+            .unwrap_or_else(|| existing_bond.mix_node.identity_key.clone()),
+        new_sphinx_key: new_sphinx_key.unwrap_or_else(|| existing_bond.mix_node.sphinx_key.clone()),
+        proposal_height: env.block.height,
+    };
+
+    if !proposal.changes_owner()
+        && proposal.new_identity_key == existing_bond.mix_node.identity_key
+        && proposal.new_sphinx_key == existing_bond.mix_node.sphinx_key
+    {
+        return Err(MixnetContractError::EmptyBondTransfer);
+    }
+
+    // this is going to get checked again upon accepting the transfer,
+    // but there's no point in allowing the proposal if it's already invalid
+    if proposal.changes_owner() {
+        ensure_no_existing_bond(&proposal.new_owner, deps.storage)?;
+    }
+
+    // note: this overwrites any previous proposal made for this node
+    storage::PENDING_MIXNODE_TRANSFERS.save(deps.storage, existing_bond.mix_id, &proposal)?;
+
+    Ok(
+        Response::new().add_event(new_mixnode_transfer_proposal_event(
+            existing_bond.mix_id,
+            &proposal.current_owner,
+            &proposal,
+        )),
+    )
+}
+
+pub(crate) fn try_cancel_mixnode_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    let existing_bond = must_get_mixnode_bond_by_owner(deps.storage, &info.sender)?;
+    let mix_id = existing_bond.mix_id;
+
+    if !storage::PENDING_MIXNODE_TRANSFERS.has(deps.storage, mix_id) {
+        return Err(MixnetContractError::NoPendingBondTransfer);
+    }
+    storage::PENDING_MIXNODE_TRANSFERS.remove(deps.storage, mix_id);
+
+    Ok(
+        Response::new().add_event(new_mixnode_transfer_cancellation_event(
+            mix_id,
+            &info.sender,
+        )),
+    )
+}
+
+pub(crate) fn try_accept_mixnode_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    identity_signature: MessageSignature,
+) -> Result<Response, MixnetContractError> {
+    let proposal = storage::PENDING_MIXNODE_TRANSFERS
+        .may_load(deps.storage, mix_id)?
+        .ok_or(MixnetContractError::NoPendingBondTransfer)?;
+
+    if info.sender != proposal.new_owner {
+        return Err(MixnetContractError::BondTransferRecipientMismatch {
+            expected: proposal.new_owner,
+            received: info.sender,
+        });
+    }
+
+    let mix_details = get_mixnode_details_by_id(deps.storage, mix_id)?
+        .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+    let existing_bond = mix_details.bond_information;
+
+    // proposals are removed whenever the node unbonds, so the owner MUST still be the same
+    if existing_bond.owner != proposal.current_owner {
+        return Err(MixnetContractError::inconsistent_state(
+            "the owner of the mixnode has changed since the transfer has been proposed",
+        ));
+    }
+    ensure_bonded(&existing_bond)?;
+
+    // if there are any pending requests to change the pledge, wait for them to resolve
+    // so that the tokens would not end up being returned to the wrong account
+    ensure_no_pending_pledge_changes(&mix_details.pending_changes)?;
+
+    if proposal.changes_owner() {
+        ensure_no_existing_bond(&proposal.new_owner, deps.storage)?;
+    }
+
+    // families are identified by the identity keys of their members,
+    // so changing it would break the existing relationships
+    let previous_identity = existing_bond.mix_node.identity_key.clone();
+    if proposal.new_identity_key != previous_identity
+        && (families_storage::families()
+            .may_load(deps.storage, previous_identity.clone())?
+            .is_some()
+            || families_storage::is_any_member(deps.storage, &previous_identity)?.is_some())
+    {
+        return Err(MixnetContractError::FamilyNodeIdentityChange {
+            identity: previous_identity,
+        });
+    }
+
+    // there's no need to explicitly check whether there already exists mixnode with the same
+    // identity or sphinx keys as this is going to be done implicitly when attempting to save
+    // the bond information due to `UniqueIndex` constraint defined on those fields.
+
+    // check if the new owner actually controls the (new) node identity by checking the signature
+    verify_mixnode_transfer_signature(
+        deps.as_ref(),
+        info.sender.clone(),
+        mix_id,
+        proposal.clone(),
+        identity_signature,
+    )?;
+
+    // update the signing nonce associated with this sender so that the future signature would be made on the new value
+    signing_storage::increment_signing_nonce(deps.storage, info.sender)?;
+
+    // note: delegations and rewarding information are keyed by the mix_id, so they're carried over
+    let mut updated_bond = existing_bond.clone();
+    updated_bond.owner = proposal.new_owner.clone();
+    updated_bond.mix_node.identity_key = proposal.new_identity_key.clone();
+    updated_bond.mix_node.sphinx_key = proposal.new_sphinx_key.clone();
+
+    storage::mixnode_bonds().replace(
+        deps.storage,
+        mix_id,
+        Some(&updated_bond),
+        Some(&existing_bond),
+    )?;
+    storage::PENDING_MIXNODE_TRANSFERS.remove(deps.storage, mix_id);
+
+    // compounding settings were chosen by the previous operator and must not carry over to the new one
+    if proposal.changes_owner() {
+        rewards_storage::remove_operator_compounding(deps.storage, mix_id)?;
+    }
+
+    Ok(Response::new().add_event(new_mixnode_transfer_event(
+        mix_id,
+        &previous_identity,
+        &proposal,
+    )))
+}
+
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_std::testing::mock_info;
@@ -1853,4 +2029,367 @@ pub mod tests {
             )
         }
     }
+
+    mod transferring_mixnode {
+        use super::*;
+        use crate::support::tests::test_helpers::ed25519_sign_message;
+        use mixnet_contract_common::construct_mixnode_transfer_sign_payload;
+        use mixnet_contract_common::rewarding::CompoundingFrequency;
+        use nym_crypto::asymmetric::{encryption, identity};
+
+        fn transfer_signature(
+            test: &TestSetup,
+            new_owner: &str,
+            mix_id: MixId,
+            key: &identity::PrivateKey,
+        ) -> MessageSignature {
+            let new_owner = Addr::unchecked(new_owner);
+            let proposal = storage::PENDING_MIXNODE_TRANSFERS
+                .load(test.deps().storage, mix_id)
+                .unwrap();
+            let nonce =
+                signing_storage::get_signing_nonce(test.deps().storage, new_owner.clone()).unwrap();
+            let msg = construct_mixnode_transfer_sign_payload(nonce, new_owner, mix_id, proposal);
+            ed25519_sign_message(msg, key)
+        }
+
+        #[test]
+        fn is_not_allowed_for_bonds_made_with_vesting_tokens() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "alice";
+            test.add_dummy_mixnode_with_legal_proxy(owner, None);
+
+            let res = try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                "bob".to_string(),
+                None,
+                None,
+            );
+            assert_eq!(res, Err(MixnetContractError::VestingBondTransfer));
+        }
+
+        #[test]
+        fn must_change_either_owner_or_keys() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "alice";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+            let bond = test.mix_bond(mix_id);
+
+            let res = try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                owner.to_string(),
+                None,
+                Some(bond.mix_node.sphinx_key),
+            );
+            assert_eq!(res, Err(MixnetContractError::EmptyBondTransfer));
+
+            let res = try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                owner.to_string(),
+                None,
+                Some("new-sphinx-key".to_string()),
+            );
+            assert!(res.is_ok())
+        }
+
+        #[test]
+        fn is_not_allowed_if_new_owner_already_has_a_bond() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            test.add_dummy_mixnode("alice", None);
+            test.add_dummy_gateway("bob", None);
+
+            let res = try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            );
+            assert_eq!(res, Err(MixnetContractError::AlreadyOwnsGateway));
+        }
+
+        #[test]
+        fn can_only_be_accepted_by_the_new_owner_with_valid_signature() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let (mix_id, keypair) = test.add_dummy_mixnode_with_keypair("alice", None);
+
+            let res = try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("bob", &[]),
+                mix_id,
+                MessageSignature::from(vec![1, 2, 3]),
+            );
+            assert_eq!(res, Err(MixnetContractError::NoPendingBondTransfer));
+
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+
+            let signature = transfer_signature(&test, "bob", mix_id, keypair.private_key());
+            let res = try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("charlie", &[]),
+                mix_id,
+                signature,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::BondTransferRecipientMismatch {
+                    expected: Addr::unchecked("bob"),
+                    received: Addr::unchecked("charlie"),
+                })
+            );
+
+            // signed with the wrong key
+            let other_keypair = identity::KeyPair::new(&mut test.rng);
+            let signature = transfer_signature(&test, "bob", mix_id, other_keypair.private_key());
+            let res = try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("bob", &[]),
+                mix_id,
+                signature,
+            );
+            assert_eq!(res, Err(MixnetContractError::InvalidEd25519Signature));
+
+            let signature = transfer_signature(&test, "bob", mix_id, keypair.private_key());
+            let res = try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("bob", &[]),
+                mix_id,
+                signature.clone(),
+            );
+            assert!(res.is_ok());
+
+            // the nonce got incremented so the same signature can't be replayed
+            let nonce =
+                signing_storage::get_signing_nonce(test.deps().storage, Addr::unchecked("bob"))
+                    .unwrap();
+            assert_eq!(nonce, 1);
+        }
+
+        #[test]
+        fn moves_the_bond_alongside_delegations() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let mix_id = test.add_dummy_mixnode("alice", None);
+            test.add_immediate_delegation("delegator", 100_000_000u32, mix_id);
+            let rewarding_before = test.mix_rewarding(mix_id);
+
+            let new_keypair = identity::KeyPair::new(&mut test.rng);
+            let new_identity = new_keypair.public_key().to_base58_string();
+            let new_sphinx = encryption::KeyPair::new(&mut test.rng)
+                .public_key()
+                .to_base58_string();
+
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                Some(new_identity.clone()),
+                Some(new_sphinx.clone()),
+            )
+            .unwrap();
+
+            let signature = transfer_signature(&test, "bob", mix_id, new_keypair.private_key());
+            try_accept_mixnode_transfer(test.deps_mut(), mock_info("bob", &[]), mix_id, signature)
+                .unwrap();
+
+            let bond = test.mix_bond(mix_id);
+            assert_eq!(bond.owner, Addr::unchecked("bob"));
+            assert_eq!(bond.mix_node.identity_key, new_identity);
+            assert_eq!(bond.mix_node.sphinx_key, new_sphinx);
+            assert_eq!(test.mix_rewarding(mix_id), rewarding_before);
+            assert_eq!(
+                test.delegation(mix_id, "delegator", &None).amount.amount,
+                Uint128::new(100_000_000)
+            );
+
+            assert!(storage::PENDING_MIXNODE_TRANSFERS
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+            assert!(
+                get_mixnode_details_by_owner(test.deps().storage, Addr::unchecked("alice"))
+                    .unwrap()
+                    .is_none()
+            );
+        }
+
+        #[test]
+        fn clears_operator_compounding_of_the_previous_owner() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let (mix_id, keypair) = test.add_dummy_mixnode_with_keypair("alice", None);
+
+            crate::rewards::transactions::try_update_operator_compounding(
+                test.deps_mut(),
+                mock_info("alice", &[]),
+                Some(CompoundingFrequency::Interval),
+            )
+            .unwrap();
+            let settings = rewards_storage::OPERATORS_COMPOUNDING
+                .load(test.deps().storage, mix_id)
+                .unwrap();
+            let pending_key = (settings.next_compounding_epoch, mix_id);
+
+            // rotating the keys of the node keeps the settings of its (unchanged) operator
+            let new_sphinx = encryption::KeyPair::new(&mut test.rng)
+                .public_key()
+                .to_base58_string();
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("alice", &[]),
+                "alice".to_string(),
+                None,
+                Some(new_sphinx),
+            )
+            .unwrap();
+            let signature = transfer_signature(&test, "alice", mix_id, keypair.private_key());
+            try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("alice", &[]),
+                mix_id,
+                signature,
+            )
+            .unwrap();
+
+            assert_eq!(
+                rewards_storage::OPERATORS_COMPOUNDING
+                    .may_load(test.deps().storage, mix_id)
+                    .unwrap(),
+                Some(settings)
+            );
+            assert!(rewards_storage::PENDING_OPERATORS_COMPOUNDING
+                .may_load(test.deps().storage, pending_key)
+                .unwrap()
+                .is_some());
+
+            // but changing the owner removes them alongside the scheduled compounding
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+            let signature = transfer_signature(&test, "bob", mix_id, keypair.private_key());
+            try_accept_mixnode_transfer(test.deps_mut(), mock_info("bob", &[]), mix_id, signature)
+                .unwrap();
+
+            assert!(rewards_storage::OPERATORS_COMPOUNDING
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+            assert!(rewards_storage::PENDING_OPERATORS_COMPOUNDING
+                .may_load(test.deps().storage, pending_key)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn identity_of_family_members_cant_be_changed() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let mix_id = test.add_dummy_mixnode("alice", None);
+            crate::families::transactions::try_create_family(
+                test.deps_mut(),
+                mock_info("alice", &[]),
+                "family".to_string(),
+            )
+            .unwrap();
+
+            let new_keypair = identity::KeyPair::new(&mut test.rng);
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                Some(new_keypair.public_key().to_base58_string()),
+                None,
+            )
+            .unwrap();
+
+            let signature = transfer_signature(&test, "bob", mix_id, new_keypair.private_key());
+            let res = try_accept_mixnode_transfer(
+                test.deps_mut(),
+                mock_info("bob", &[]),
+                mix_id,
+                signature,
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::FamilyNodeIdentityChange { .. })
+            ));
+        }
+
+        #[test]
+        fn can_be_cancelled_by_the_owner() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let mix_id = test.add_dummy_mixnode("alice", None);
+
+            let res = try_cancel_mixnode_transfer(test.deps_mut(), mock_info("alice", &[]));
+            assert_eq!(res, Err(MixnetContractError::NoPendingBondTransfer));
+
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env,
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+
+            try_cancel_mixnode_transfer(test.deps_mut(), mock_info("alice", &[])).unwrap();
+            assert!(storage::PENDING_MIXNODE_TRANSFERS
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn is_cleared_upon_unbonding() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let mix_id = test.add_dummy_mixnode("alice", None);
+
+            try_propose_mixnode_transfer(
+                test.deps_mut(),
+                env.clone(),
+                mock_info("alice", &[]),
+                "bob".to_string(),
+                None,
+                None,
+            )
+            .unwrap();
+
+            try_remove_mixnode(test.deps_mut(), env, mock_info("alice", &[])).unwrap();
+            assert!(storage::PENDING_MIXNODE_TRANSFERS
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+        }
+    }
 }
@@ -12,7 +12,7 @@ use mixnet_contract_common::mixnode::MixNodeDetails;
 use mixnet_contract_common::reward_params::{NodeRewardParams, Performance, RewardingParams};
 use mixnet_contract_common::rewarding::helpers::truncate_reward;
 use mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    CompoundingSettingsResponse, EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
 };
 use mixnet_contract_common::{Delegation, MixId};
 
@@ -91,6 +91,32 @@ pub fn query_pending_delegator_reward(
     })
 }
 
+pub fn query_delegation_compounding(
+    deps: Deps,
+    owner: String,
+    mix_id: MixId,
+    proxy: Option<String>,
+) -> StdResult<CompoundingSettingsResponse> {
+    let owner_address = deps.api.addr_validate(&owner)?;
+    let proxy = proxy
+        .map(|proxy| deps.api.addr_validate(&proxy))
+        .transpose()?;
+
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner_address, proxy.as_ref());
+    Ok(CompoundingSettingsResponse {
+        settings: storage::DELEGATIONS_COMPOUNDING.may_load(deps.storage, storage_key)?,
+    })
+}
+
+pub fn query_operator_compounding(
+    deps: Deps,
+    mix_id: MixId,
+) -> StdResult<CompoundingSettingsResponse> {
+    Ok(CompoundingSettingsResponse {
+        settings: storage::OPERATORS_COMPOUNDING.may_load(deps.storage, mix_id)?,
+    })
+}
+
 fn zero_reward(
     original_stake: Coin,
     current_value: Decimal,
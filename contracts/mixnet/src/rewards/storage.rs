@@ -2,15 +2,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::constants::{
-    MIXNODES_REWARDING_PK_NAMESPACE, PENDING_REWARD_POOL_KEY, REWARDING_PARAMS_KEY,
+    DELEGATIONS_COMPOUNDING_NAMESPACE, MIXNODES_REWARDING_PK_NAMESPACE,
+    OPERATORS_COMPOUNDING_NAMESPACE, PENDING_DELEGATIONS_COMPOUNDING_NAMESPACE,
+    PENDING_OPERATORS_COMPOUNDING_NAMESPACE, PENDING_REWARD_POOL_KEY, REWARDING_PARAMS_KEY,
 };
 use crate::rewards::models::RewardPoolChange;
-use cosmwasm_std::{Decimal, StdResult, Storage};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Decimal, Order, StdResult, Storage};
+use cw_storage_plus::{Item, Map, PrefixBound};
+use mixnet_contract_common::delegation::StorageKey;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::MixNodeRewarding;
 use mixnet_contract_common::reward_params::RewardingParams;
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::rewarding::CompoundingSettings;
+use mixnet_contract_common::{EpochId, MixId};
 
 // current parameters used for rewarding purposes
 pub(crate) const REWARDING_PARAMS: Item<'_, RewardingParams> = Item::new(REWARDING_PARAMS_KEY);
@@ -20,6 +24,18 @@ pub(crate) const PENDING_REWARD_POOL_CHANGE: Item<'_, RewardPoolChange> =
 pub const MIXNODE_REWARDING: Map<MixId, MixNodeRewarding> =
     Map::new(MIXNODES_REWARDING_PK_NAMESPACE);
 
+// delegations and operators that opted into having their rewards automatically compounded
+pub(crate) const DELEGATIONS_COMPOUNDING: Map<StorageKey, CompoundingSettings> =
+    Map::new(DELEGATIONS_COMPOUNDING_NAMESPACE);
+pub(crate) const OPERATORS_COMPOUNDING: Map<MixId, CompoundingSettings> =
+    Map::new(OPERATORS_COMPOUNDING_NAMESPACE);
+
+// compoundings scheduled to be performed at the end of the particular (absolute) epoch
+pub(crate) const PENDING_DELEGATIONS_COMPOUNDING: Map<(EpochId, StorageKey), ()> =
+    Map::new(PENDING_DELEGATIONS_COMPOUNDING_NAMESPACE);
+pub(crate) const PENDING_OPERATORS_COMPOUNDING: Map<(EpochId, MixId), ()> =
+    Map::new(PENDING_OPERATORS_COMPOUNDING_NAMESPACE);
+
 pub fn reward_accounting(
     storage: &mut dyn Storage,
     amount: Decimal,
@@ -30,6 +46,138 @@ pub fn reward_accounting(
     Ok(PENDING_REWARD_POOL_CHANGE.save(storage, &pending_changes)?)
 }
 
+/// Sets (or replaces) the compounding settings of the delegation alongside scheduling its next compounding.
+pub(crate) fn save_delegation_compounding(
+    storage: &mut dyn Storage,
+    key: StorageKey,
+    settings: &CompoundingSettings,
+) -> StdResult<()> {
+    remove_delegation_compounding(storage, key.clone())?;
+    PENDING_DELEGATIONS_COMPOUNDING.save(
+        storage,
+        (settings.next_compounding_epoch, key.clone()),
+        &(),
+    )?;
+    DELEGATIONS_COMPOUNDING.save(storage, key, settings)
+}
+
+/// Removes the compounding settings of the delegation alongside its scheduled compounding (if any).
+pub(crate) fn remove_delegation_compounding(
+    storage: &mut dyn Storage,
+    key: StorageKey,
+) -> StdResult<()> {
+    if let Some(existing) = DELEGATIONS_COMPOUNDING.may_load(storage, key.clone())? {
+        PENDING_DELEGATIONS_COMPOUNDING
+            .remove(storage, (existing.next_compounding_epoch, key.clone()));
+        DELEGATIONS_COMPOUNDING.remove(storage, key);
+    }
+    Ok(())
+}
+
+/// Sets (or replaces) the compounding settings of the operator alongside scheduling its next compounding.
+pub(crate) fn save_operator_compounding(
+    storage: &mut dyn Storage,
+    mix_id: MixId,
+    settings: &CompoundingSettings,
+) -> StdResult<()> {
+    remove_operator_compounding(storage, mix_id)?;
+    PENDING_OPERATORS_COMPOUNDING.save(storage, (settings.next_compounding_epoch, mix_id), &())?;
+    OPERATORS_COMPOUNDING.save(storage, mix_id, settings)
+}
+
+/// Removes the compounding settings of the operator alongside its scheduled compounding (if any).
+pub(crate) fn remove_operator_compounding(
+    storage: &mut dyn Storage,
+    mix_id: MixId,
+) -> StdResult<()> {
+    if let Some(existing) = OPERATORS_COMPOUNDING.may_load(storage, mix_id)? {
+        PENDING_OPERATORS_COMPOUNDING.remove(storage, (existing.next_compounding_epoch, mix_id));
+        OPERATORS_COMPOUNDING.remove(storage, mix_id);
+    }
+    Ok(())
+}
+
+/// Returns up to `limit` operator compoundings that were scheduled for the provided epoch (or any prior one).
+pub(crate) fn due_operator_compoundings(
+    storage: &dyn Storage,
+    epoch_id: EpochId,
+    limit: usize,
+) -> StdResult<Vec<(EpochId, MixId)>> {
+    PENDING_OPERATORS_COMPOUNDING
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(epoch_id)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(key, _)| key))
+        .collect()
+}
+
+/// Returns up to `limit` delegation compoundings that were scheduled for the provided epoch (or any prior one).
+pub(crate) fn due_delegation_compoundings(
+    storage: &dyn Storage,
+    epoch_id: EpochId,
+    limit: usize,
+) -> StdResult<Vec<(EpochId, StorageKey)>> {
+    PENDING_DELEGATIONS_COMPOUNDING
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(epoch_id)),
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|res| res.map(|(key, _)| key))
+        .collect()
+}
+
+/// Checks whether any compounding was scheduled for the provided epoch (or any prior one).
+/// Unlike [`count_due_compoundings`], it only ever looks at a single entry of each queue.
+pub(crate) fn any_due_compoundings(storage: &dyn Storage, epoch_id: EpochId) -> bool {
+    PENDING_OPERATORS_COMPOUNDING
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(epoch_id)),
+            Order::Ascending,
+        )
+        .next()
+        .is_some()
+        || PENDING_DELEGATIONS_COMPOUNDING
+            .prefix_range(
+                storage,
+                None,
+                Some(PrefixBound::inclusive(epoch_id)),
+                Order::Ascending,
+            )
+            .next()
+            .is_some()
+}
+
+/// Returns the total number of compoundings that were scheduled for the provided epoch (or any prior one).
+/// Note that it iterates over all of them, so it should only be used by queries.
+pub(crate) fn count_due_compoundings(storage: &dyn Storage, epoch_id: EpochId) -> u32 {
+    let operators = PENDING_OPERATORS_COMPOUNDING
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(epoch_id)),
+            Order::Ascending,
+        )
+        .count();
+    let delegations = PENDING_DELEGATIONS_COMPOUNDING
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(epoch_id)),
+            Order::Ascending,
+        )
+        .count();
+    (operators + delegations) as u32
+}
+
 pub(crate) fn initialise_storage(
     storage: &mut dyn Storage,
     reward_params: RewardingParams,
@@ -1,12 +1,15 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, MessageInfo, Response, Storage};
 
+use mixnet_contract_common::delegation::StorageKey;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_active_set_update_event, new_mix_rewarding_event,
-    new_not_found_mix_operator_rewarding_event, new_pending_active_set_update_event,
+    new_active_set_update_event, new_compound_delegator_reward_event,
+    new_compound_operator_reward_event, new_delegation_compounding_update_event,
+    new_mix_rewarding_event, new_not_found_mix_operator_rewarding_event,
+    new_operator_compounding_update_event, new_pending_active_set_update_event,
     new_pending_rewarding_params_update_event, new_rewarding_params_update_event,
     new_withdraw_delegator_reward_event, new_withdraw_operator_reward_event,
     new_zero_uptime_mix_operator_rewarding_event,
@@ -15,20 +18,21 @@ use mixnet_contract_common::pending_events::{PendingEpochEventKind, PendingInter
 use mixnet_contract_common::reward_params::{
     IntervalRewardingParamsUpdate, NodeRewardParams, Performance,
 };
-use mixnet_contract_common::{Delegation, EpochState, MixId};
+use mixnet_contract_common::rewarding::{CompoundingFrequency, CompoundingSettings};
+use mixnet_contract_common::{Delegation, EpochId, EpochState, Interval, MixId};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
 use crate::delegations::storage as delegations_storage;
 use crate::interval::storage as interval_storage;
 use crate::interval::storage::{push_new_epoch_event, push_new_interval_event};
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
-use crate::mixnodes::helpers::get_mixnode_details_by_owner;
+use crate::mixnodes::helpers::{get_mixnode_details_by_id, get_mixnode_details_by_owner};
 use crate::mixnodes::storage as mixnodes_storage;
 use crate::rewards::helpers;
 use crate::rewards::helpers::update_and_save_last_rewarded;
 use crate::support::helpers::{
     ensure_bonded, ensure_can_advance_epoch, ensure_epoch_in_progress_state, ensure_is_owner,
-    ensure_proxy_match, ensure_sent_by_vesting_contract, send_to_proxy_or_owner,
+    ensure_proxy_match, ensure_sent_by_vesting_contract, send_to_proxy_or_owner, VestingTracking,
 };
 
 use super::storage;
@@ -287,6 +291,305 @@ pub(crate) fn _try_withdraw_delegator_reward(
     )))
 }
 
+pub(crate) fn try_update_operator_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    frequency: Option<CompoundingFrequency>,
+) -> Result<Response, MixnetContractError> {
+    _try_update_operator_compounding(deps, info.sender, None, frequency)
+}
+
+pub(crate) fn try_update_operator_compounding_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    frequency: Option<CompoundingFrequency>,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let proxy = info.sender;
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_update_operator_compounding(deps, owner, Some(proxy), frequency)
+}
+
+pub(crate) fn _try_update_operator_compounding(
+    deps: DepsMut<'_>,
+    owner: Addr,
+    proxy: Option<Addr>,
+    frequency: Option<CompoundingFrequency>,
+) -> Result<Response, MixnetContractError> {
+    // we don't want to be messing with the compounding queue whilst it's being processed
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    let mix_details = get_mixnode_details_by_owner(deps.storage, owner.clone())?.ok_or(
+        MixnetContractError::NoAssociatedMixNodeBond {
+            owner: owner.clone(),
+        },
+    )?;
+    let mix_id = mix_details.mix_id();
+
+    ensure_proxy_match(&proxy, &mix_details.bond_information.proxy)?;
+
+    let settings = match frequency {
+        Some(frequency) => {
+            // there's no point in compounding rewards of a node that's about to unbond
+            ensure_bonded(&mix_details.bond_information)?;
+
+            let interval = interval_storage::current_interval(deps.storage)?;
+            let settings = CompoundingSettings::new(frequency, &interval);
+            storage::save_operator_compounding(deps.storage, mix_id, &settings)?;
+            Some(settings)
+        }
+        None => {
+            storage::remove_operator_compounding(deps.storage, mix_id)?;
+            None
+        }
+    };
+
+    Ok(
+        Response::new().add_event(new_operator_compounding_update_event(
+            &owner, &proxy, mix_id, settings,
+        )),
+    )
+}
+
+pub(crate) fn try_update_delegation_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    frequency: Option<CompoundingFrequency>,
+) -> Result<Response, MixnetContractError> {
+    _try_update_delegation_compounding(deps, mix_id, info.sender, None, frequency)
+}
+
+pub(crate) fn try_update_delegation_compounding_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    frequency: Option<CompoundingFrequency>,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let proxy = info.sender;
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_update_delegation_compounding(deps, mix_id, owner, Some(proxy), frequency)
+}
+
+pub(crate) fn _try_update_delegation_compounding(
+    deps: DepsMut<'_>,
+    mix_id: MixId,
+    owner: Addr,
+    proxy: Option<Addr>,
+    frequency: Option<CompoundingFrequency>,
+) -> Result<Response, MixnetContractError> {
+    // we don't want to be messing with the compounding queue whilst it's being processed
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    // see if the delegation even exists
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
+    if delegations_storage::delegations()
+        .may_load(deps.storage, storage_key.clone())?
+        .is_none()
+    {
+        return Err(MixnetContractError::NoMixnodeDelegationFound {
+            mix_id,
+            address: owner.into_string(),
+            proxy: proxy.map(Addr::into_string),
+        });
+    }
+
+    let settings = match frequency {
+        Some(frequency) => {
+            // there's no point in compounding rewards of a node that's about to unbond
+            match mixnodes_storage::mixnode_bonds().may_load(deps.storage, mix_id)? {
+                Some(mix_bond) if mix_bond.is_unbonding => {
+                    return Err(MixnetContractError::MixnodeIsUnbonding { mix_id });
+                }
+                None => return Err(MixnetContractError::MixnodeHasUnbonded { mix_id }),
+                _ => (),
+            };
+
+            let interval = interval_storage::current_interval(deps.storage)?;
+            let settings = CompoundingSettings::new(frequency, &interval);
+            storage::save_delegation_compounding(deps.storage, storage_key, &settings)?;
+            Some(settings)
+        }
+        None => {
+            storage::remove_delegation_compounding(deps.storage, storage_key)?;
+            None
+        }
+    };
+
+    Ok(
+        Response::new().add_event(new_delegation_compounding_update_event(
+            &owner, &proxy, mix_id, settings,
+        )),
+    )
+}
+
+/// Compounds the rewards of all operators and delegations that were scheduled to get compounded
+/// by the end of the current epoch. Each of them gets rescheduled according to its frequency.
+pub(crate) fn perform_pending_compounding(
+    deps: DepsMut<'_>,
+    limit: Option<u32>,
+) -> Result<(Response, u32), MixnetContractError> {
+    let interval = interval_storage::current_interval(deps.storage)?;
+    let current_epoch = interval.current_epoch_absolute_id();
+
+    // this is executed at the very end of the epoch, so any further compounding
+    // must be scheduled relatively to the upcoming one
+    let upcoming_interval = interval.advance_epoch();
+
+    let limit = limit.map(|l| l as usize).unwrap_or(usize::MAX);
+    let operators = storage::due_operator_compoundings(deps.storage, current_epoch, limit)?;
+    let delegations =
+        storage::due_delegation_compoundings(deps.storage, current_epoch, limit - operators.len())?;
+    let executed = (operators.len() + delegations.len()) as u32;
+
+    let mut response = Response::new();
+    for (scheduled_epoch, mix_id) in operators {
+        let mut sub_response = compound_scheduled_operator_reward(
+            deps.storage,
+            scheduled_epoch,
+            mix_id,
+            &upcoming_interval,
+        )?;
+        response.messages.append(&mut sub_response.messages);
+        response.events.append(&mut sub_response.events);
+    }
+
+    for (scheduled_epoch, storage_key) in delegations {
+        let mut sub_response = compound_scheduled_delegator_reward(
+            deps.storage,
+            scheduled_epoch,
+            storage_key,
+            &upcoming_interval,
+        )?;
+        response.messages.append(&mut sub_response.messages);
+        response.events.append(&mut sub_response.events);
+    }
+
+    Ok((response, executed))
+}
+
+fn compound_scheduled_operator_reward(
+    store: &mut dyn Storage,
+    scheduled_epoch: EpochId,
+    mix_id: MixId,
+    upcoming_interval: &Interval,
+) -> Result<Response, MixnetContractError> {
+    storage::PENDING_OPERATORS_COMPOUNDING.remove(store, (scheduled_epoch, mix_id));
+    let settings = match storage::OPERATORS_COMPOUNDING.may_load(store, mix_id)? {
+        Some(settings) => settings,
+        None => {
+            return Err(MixnetContractError::inconsistent_state(
+                "operator compounding got scheduled without the associated settings",
+            ))
+        }
+    };
+
+    // if the node is gone (or is about to be gone), there's nothing to compound anymore
+    let mix_details = match get_mixnode_details_by_id(store, mix_id)? {
+        Some(mix_details) if !mix_details.is_unbonding() => mix_details,
+        _ => {
+            storage::OPERATORS_COMPOUNDING.remove(store, mix_id);
+            return Ok(Response::new());
+        }
+    };
+
+    let owner = mix_details.bond_information.owner.clone();
+    let proxy = mix_details.bond_information.proxy.clone();
+    let reward = helpers::compound_operator_reward(store, mix_details)?;
+
+    let rescheduled = CompoundingSettings::new(settings.frequency, upcoming_interval);
+    storage::save_operator_compounding(store, mix_id, &rescheduled)?;
+
+    let mut response = Response::new().add_event(new_compound_operator_reward_event(
+        &owner,
+        &proxy,
+        reward.clone(),
+        mix_id,
+    ));
+
+    // if the reward is zero, don't track anything - there's no point
+    if !reward.amount.is_zero() {
+        response = response.maybe_add_track_vesting_compounded_operator_reward_message(
+            store,
+            proxy,
+            owner.into_string(),
+            reward,
+        )?;
+    }
+    Ok(response)
+}
+
+fn compound_scheduled_delegator_reward(
+    store: &mut dyn Storage,
+    scheduled_epoch: EpochId,
+    storage_key: StorageKey,
+    upcoming_interval: &Interval,
+) -> Result<Response, MixnetContractError> {
+    storage::PENDING_DELEGATIONS_COMPOUNDING.remove(store, (scheduled_epoch, storage_key.clone()));
+    let settings = match storage::DELEGATIONS_COMPOUNDING.may_load(store, storage_key.clone())? {
+        Some(settings) => settings,
+        None => {
+            return Err(MixnetContractError::inconsistent_state(
+                "delegation compounding got scheduled without the associated settings",
+            ))
+        }
+    };
+
+    // the settings are removed alongside the delegation so it must still exist
+    let delegation = delegations_storage::delegations()
+        .may_load(store, storage_key.clone())?
+        .ok_or(MixnetContractError::inconsistent_state(
+            "delegation got removed whilst its rewards were still set to be compounded",
+        ))?;
+    let mix_id = delegation.mix_id;
+
+    // if the node is gone (or is about to be gone), there's nothing to compound anymore
+    match mixnodes_storage::mixnode_bonds().may_load(store, mix_id)? {
+        Some(mix_bond) if !mix_bond.is_unbonding => (),
+        _ => {
+            storage::DELEGATIONS_COMPOUNDING.remove(store, storage_key);
+            return Ok(Response::new());
+        }
+    }
+
+    let mix_rewarding = storage::MIXNODE_REWARDING.may_load(store, mix_id)?.ok_or(
+        MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
+        ),
+    )?;
+
+    let owner = delegation.owner.clone();
+    let proxy = delegation.proxy.clone();
+    let reward = helpers::compound_delegator_reward(store, delegation, mix_rewarding)?;
+
+    let rescheduled = CompoundingSettings::new(settings.frequency, upcoming_interval);
+    storage::save_delegation_compounding(store, storage_key, &rescheduled)?;
+
+    let mut response = Response::new().add_event(new_compound_delegator_reward_event(
+        &owner,
+        &proxy,
+        reward.clone(),
+        mix_id,
+    ));
+
+    // if the reward is zero, don't track anything - there's no point
+    if !reward.amount.is_zero() {
+        response = response.maybe_add_track_vesting_compounded_delegator_reward_message(
+            store,
+            proxy,
+            owner.into_string(),
+            mix_id,
+            reward,
+        )?;
+    }
+    Ok(response)
+}
+
 pub(crate) fn try_update_active_set_size(
     deps: DepsMut<'_>,
     env: Env,
@@ -1948,6 +2251,342 @@ pub mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod compounding_rewards {
+        use cosmwasm_std::{Decimal, Uint128};
+
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+
+        use crate::support::tests::test_helpers::TestSetup;
+
+        use super::*;
+
+        #[test]
+        fn delegation_compounding_can_only_be_set_for_existing_delegations() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+            let delegator = "delegator";
+            let sender = mock_info(delegator, &[]);
+
+            let res = try_update_delegation_compounding(
+                test.deps_mut(),
+                sender.clone(),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id,
+                    address: delegator.to_string(),
+                    proxy: None,
+                })
+            );
+
+            test.add_immediate_delegation(delegator, 100_000_000u128, mix_id);
+            let res = try_update_delegation_compounding(
+                test.deps_mut(),
+                sender.clone(),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+            );
+            assert!(res.is_ok());
+
+            let storage_key = Delegation::generate_storage_key(mix_id, &sender.sender, None);
+            let expected =
+                CompoundingSettings::new(CompoundingFrequency::Epoch, &test.current_interval());
+            assert_eq!(
+                expected,
+                storage::DELEGATIONS_COMPOUNDING
+                    .load(test.deps().storage, storage_key.clone())
+                    .unwrap()
+            );
+
+            // and it can be disabled again
+            try_update_delegation_compounding(test.deps_mut(), sender, mix_id, None).unwrap();
+            assert!(storage::DELEGATIONS_COMPOUNDING
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_none());
+            assert_eq!(
+                0,
+                storage::count_due_compoundings(
+                    test.deps().storage,
+                    test.current_interval().current_epoch_absolute_id()
+                )
+            );
+        }
+
+        #[test]
+        fn delegation_compounding_cant_be_enabled_for_unbonding_nodes() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+            let delegator = "delegator";
+            let sender = mock_info(delegator, &[]);
+            test.add_immediate_delegation(delegator, 100_000_000u128, mix_id);
+            test.start_unbonding_mixnode(mix_id);
+
+            let res = try_update_delegation_compounding(
+                test.deps_mut(),
+                sender.clone(),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+            );
+            assert_eq!(res, Err(MixnetContractError::MixnodeIsUnbonding { mix_id }));
+
+            // but disabling it is always fine
+            let res = try_update_delegation_compounding(test.deps_mut(), sender, mix_id, None);
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn operator_compounding_can_only_be_set_if_bond_exists() {
+            let mut test = TestSetup::new();
+
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+            let sender = mock_info("random-guy", &[]);
+
+            let res = try_update_operator_compounding(
+                test.deps_mut(),
+                sender.clone(),
+                Some(CompoundingFrequency::Interval),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoAssociatedMixNodeBond {
+                    owner: sender.sender
+                })
+            );
+
+            let res = try_update_operator_compounding(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                Some(CompoundingFrequency::Interval),
+            );
+            assert!(res.is_ok());
+
+            let expected =
+                CompoundingSettings::new(CompoundingFrequency::Interval, &test.current_interval());
+            assert_eq!(
+                expected,
+                storage::OPERATORS_COMPOUNDING
+                    .load(test.deps().storage, mix_id)
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn settings_cant_be_changed_outside_epoch_in_progress_state() {
+            let mut test = TestSetup::new();
+
+            let owner = "mix-owner";
+            test.add_dummy_mixnode(owner, None);
+            test.set_epoch_reconciliation_state();
+
+            let res = try_update_operator_compounding(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                Some(CompoundingFrequency::Epoch),
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::EpochAdvancementInProgress { .. })
+            ));
+        }
+
+        #[test]
+        fn due_rewards_are_compounded_and_rescheduled() {
+            let mut test = TestSetup::new();
+
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, Some(Uint128::new(1_000_000_000_000)));
+            let delegator = "delegator";
+            test.add_immediate_delegation(delegator, 100_000_000_000u128, mix_id);
+
+            try_update_operator_compounding(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                Some(CompoundingFrequency::Epoch),
+            )
+            .unwrap();
+            try_update_delegation_compounding(
+                test.deps_mut(),
+                mock_info(delegator, &[]),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+            )
+            .unwrap();
+
+            test.skip_to_current_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.start_epoch_transition();
+            test.reward_with_distribution(mix_id, test_helpers::performance(100.0));
+
+            let operator_reward = test.pending_operator_reward(mix_id);
+            let delegator_reward = test.pending_delegator_reward(delegator, mix_id);
+            assert!(!operator_reward.is_zero());
+            assert!(!delegator_reward.is_zero());
+
+            let pledge_before = test.mix_bond(mix_id).original_pledge.amount;
+            let delegation_before = test.delegation(mix_id, delegator, &None).amount.amount;
+
+            let (res, executed) = perform_pending_compounding(test.deps_mut(), None).unwrap();
+            assert_eq!(2, executed);
+            // no vesting contract involved
+            assert!(res.messages.is_empty());
+
+            // the rewards are now part of the stake
+            let pledge_after = test.mix_bond(mix_id).original_pledge.amount;
+            let delegation_after = test.delegation(mix_id, delegator, &None).amount.amount;
+            assert_eq!(
+                pledge_before + truncate_reward_amount(operator_reward),
+                pledge_after
+            );
+            assert_eq!(
+                delegation_before + truncate_reward_amount(delegator_reward),
+                delegation_after
+            );
+            assert_eq!(
+                test.mix_rewarding(mix_id).operator,
+                Decimal::from_atomics(pledge_after, 0).unwrap()
+            );
+            assert!(test.pending_operator_reward(mix_id).is_zero());
+            assert!(test.pending_delegator_reward(delegator, mix_id).is_zero());
+
+            // and both got rescheduled for the following epoch
+            let current_epoch = test.current_interval().current_epoch_absolute_id();
+            assert_eq!(
+                0,
+                storage::count_due_compoundings(test.deps().storage, current_epoch)
+            );
+            assert_eq!(
+                2,
+                storage::count_due_compoundings(test.deps().storage, current_epoch + 1)
+            );
+            assert_eq!(
+                current_epoch + 1,
+                storage::OPERATORS_COMPOUNDING
+                    .load(test.deps().storage, mix_id)
+                    .unwrap()
+                    .next_compounding_epoch
+            );
+        }
+
+        #[test]
+        fn compounding_respects_the_limit() {
+            let mut test = TestSetup::new();
+
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+            for i in 0..5 {
+                let delegator = format!("delegator{i}");
+                test.add_immediate_delegation(&delegator, 100_000_000u128, mix_id);
+                try_update_delegation_compounding(
+                    test.deps_mut(),
+                    mock_info(&delegator, &[]),
+                    mix_id,
+                    Some(CompoundingFrequency::Epoch),
+                )
+                .unwrap();
+            }
+
+            let current_epoch = test.current_interval().current_epoch_absolute_id();
+            let (_, executed) = perform_pending_compounding(test.deps_mut(), Some(3)).unwrap();
+            assert_eq!(3, executed);
+            assert_eq!(
+                2,
+                storage::count_due_compoundings(test.deps().storage, current_epoch)
+            );
+
+            assert!(storage::any_due_compoundings(
+                test.deps().storage,
+                current_epoch
+            ));
+
+            let (_, executed) = perform_pending_compounding(test.deps_mut(), Some(3)).unwrap();
+            assert_eq!(2, executed);
+            assert_eq!(
+                0,
+                storage::count_due_compoundings(test.deps().storage, current_epoch)
+            );
+            assert!(!storage::any_due_compoundings(
+                test.deps().storage,
+                current_epoch
+            ));
+        }
+
+        #[test]
+        fn settings_are_dropped_if_node_is_unbonding() {
+            let mut test = TestSetup::new();
+
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, Some(Uint128::new(1_000_000_000_000)));
+            let delegator = "delegator";
+            test.add_immediate_delegation(delegator, 100_000_000u128, mix_id);
+
+            try_update_operator_compounding(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                Some(CompoundingFrequency::Epoch),
+            )
+            .unwrap();
+            try_update_delegation_compounding(
+                test.deps_mut(),
+                mock_info(delegator, &[]),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+            )
+            .unwrap();
+
+            test.start_unbonding_mixnode(mix_id);
+
+            let (res, executed) = perform_pending_compounding(test.deps_mut(), None).unwrap();
+            assert_eq!(2, executed);
+            assert!(res.messages.is_empty());
+
+            assert!(storage::OPERATORS_COMPOUNDING
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+                .is_none());
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(delegator), None);
+            assert!(storage::DELEGATIONS_COMPOUNDING
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn compounding_is_tracked_by_vesting_contract_for_proxied_delegations() {
+            let mut test = TestSetup::new();
+
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+            let delegator = "delegator";
+            let vesting_contract = test.vesting_contract();
+            test.add_immediate_delegation_with_legal_proxy(delegator, 100_000_000_000u128, mix_id);
+
+            try_update_delegation_compounding_on_behalf(
+                test.deps_mut(),
+                mock_info(vesting_contract.as_ref(), &[]),
+                mix_id,
+                Some(CompoundingFrequency::Epoch),
+                delegator.to_string(),
+            )
+            .unwrap();
+
+            test.skip_to_current_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.start_epoch_transition();
+            test.reward_with_distribution(mix_id, test_helpers::performance(100.0));
+
+            let (res, executed) = perform_pending_compounding(test.deps_mut(), None).unwrap();
+            assert_eq!(1, executed);
+            assert_eq!(1, res.messages.len());
+        }
+    }
+
     #[cfg(test)]
     mod updating_active_set {
         use mixnet_contract_common::{EpochState, EpochStatus};
@@ -776,6 +776,23 @@ pub mod test_helpers {
             .unwrap();
         }
 
+        // begins the epoch transition and returns the selection that uses the seed fixed by the contract
+        pub fn begin_transition_with_selection_seed(&mut self) -> RewardedSetSelection {
+            let env = self.env();
+            let sender = self.rewarding_validator();
+            try_begin_epoch_transition(self.deps_mut(), env, sender).unwrap();
+
+            let seed = interval_storage::rewarded_set_selection_seed(self.deps().storage)
+                .unwrap()
+                .unwrap();
+            RewardedSetSelection {
+                algorithm_version: 1,
+                seed_block_height: seed.block_height,
+                seed: seed.seed,
+                candidates_commitment: "cafebabe".to_string(),
+            }
+        }
+
         #[allow(unused)]
         pub fn pending_operator_reward(&mut self, mix: MixId) -> Decimal {
             query_pending_mixnode_operator_reward(self.deps(), mix)
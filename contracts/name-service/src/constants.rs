@@ -14,3 +14,6 @@ pub const NAMES_PK_NAMESPACE: &str = "nanames";
 pub const NAMES_OWNER_IDX_NAMESPACE: &str = "naowner";
 pub const NAMES_ADDRESS_IDX_NAMESPACE: &str = "naaddress";
 pub const NAMES_NAME_IDX_NAMESPACE: &str = "naname";
+pub const PENDING_TRANSFERS_NAMESPACE: &str = "napt";
+
+pub const SIGNING_NONCES_NAMESPACE: &str = "sn";
@@ -23,6 +23,7 @@ pub fn instantiate(
 
     let config = Config {
         deposit_required: msg.deposit_required,
+        registration_period: msg.registration_period,
     };
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     state::save_config(deps.storage, &config)?;
@@ -33,9 +34,9 @@ pub fn instantiate(
 }
 
 pub fn migrate(
-    deps: DepsMut<'_>,
-    _env: Env,
-    _msg: MigrateMsg,
+    mut deps: DepsMut<'_>,
+    env: Env,
+    msg: MigrateMsg,
 ) -> Result<Response, NameServiceError> {
     // Note: don't remove this particular bit of code as we have to ALWAYS check whether we have to
     // update the stored version
@@ -60,6 +61,11 @@ pub fn migrate(
 
         // If state structure changed in any contract version in the way migration is needed, it
         // should occur here, for example anything from `crate::queued_migrations::`
+        crate::queued_migrations::backfill_registration_expiry(
+            deps.branch(),
+            &env,
+            msg.registration_period,
+        )?;
     }
 
     Ok(Response::new())
@@ -72,7 +78,24 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, NameServiceError> {
     match msg {
-        ExecuteMsg::Register { name, address } => execute::register(deps, env, info, name, address),
+        ExecuteMsg::Register {
+            name,
+            address,
+            owner_signature,
+        } => execute::register(deps, env, info, name, address, owner_signature),
+        ExecuteMsg::RenewName { name } => execute::renew_name(deps, env, info, name),
+        ExecuteMsg::UpdateAddress {
+            name,
+            address,
+            owner_signature,
+        } => execute::update_address(deps, env, info, name, address, owner_signature),
+        ExecuteMsg::TransferName { name, new_owner } => {
+            execute::transfer_name(deps, env, info, name, new_owner)
+        }
+        ExecuteMsg::AcceptNameTransfer { name } => {
+            execute::accept_name_transfer(deps, env, info, name)
+        }
+        ExecuteMsg::CancelNameTransfer { name } => execute::cancel_name_transfer(deps, info, name),
         ExecuteMsg::DeleteId { name_id } => execute::delete_id(deps, info, name_id),
         ExecuteMsg::DeleteName { name } => execute::delete_name(deps, info, name),
         ExecuteMsg::UpdateDepositRequired { deposit_required } => {
@@ -90,6 +113,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary> {
         QueryMsg::All { limit, start_after } => {
             to_binary(&query::query_all_paged(deps, limit, start_after)?)
         }
+        QueryMsg::PendingTransfer { name } => {
+            to_binary(&query::query_pending_transfer(deps, name)?)
+        }
+        QueryMsg::SigningNonce { address } => {
+            to_binary(&query::query_current_signing_nonce(deps, address)?)
+        }
         QueryMsg::Config {} => to_binary(&query::query_config(deps)?),
         QueryMsg::GetContractVersion {} => to_binary(&query::query_contract_version()),
         QueryMsg::GetCW2ContractVersion {} => to_binary(&cw2::get_contract_version(deps.storage)?),
@@ -104,7 +133,7 @@ mod tests {
     use crate::test_helpers::{
         assert::{assert_config, assert_empty, assert_name, assert_names, assert_not_found},
         fixture::name_fixture,
-        helpers::{get_attribute, nyms},
+        helpers::{get_attribute, nyms, signed_register_msg, REGISTRATION_PERIOD},
     };
 
     use cosmwasm_std::{
@@ -120,6 +149,7 @@ mod tests {
         let mut deps = mock_dependencies();
         let msg = InstantiateMsg {
             deposit_required: Coin::new(100u128, DENOM),
+            registration_period: REGISTRATION_PERIOD,
         };
         let info = mock_info("creator", &[]);
         let admin = info.sender.clone();
@@ -137,14 +167,14 @@ mod tests {
     #[test]
     fn register_fails_incorrect_deposit() {
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg::new(nyms(100));
+        let msg = InstantiateMsg::new(nyms(100), REGISTRATION_PERIOD);
         let info = mock_info("creator", &[]);
         let admin = info.sender.clone();
         let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
         assert_eq!(res.messages.len(), 0);
 
         // Register
-        let msg: ExecuteMsg = name_fixture().into();
+        let msg = signed_register_msg(deps.as_ref(), &name_fixture());
         let owner = name_fixture().owner.to_string();
 
         assert_eq!(
@@ -182,13 +212,13 @@ mod tests {
     #[test]
     fn register_success() {
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg::new(nyms(100));
+        let msg = InstantiateMsg::new(nyms(100), REGISTRATION_PERIOD);
         let info = mock_info("creator", &[]);
         let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
         assert_eq!(res.messages.len(), 0);
 
         // Register
-        let msg: ExecuteMsg = name_fixture().into();
+        let msg = signed_register_msg(deps.as_ref(), &name_fixture());
         let info = mock_info("steve", &[nyms(100)]);
         let res = execute(deps.as_mut(), mock_env(), info, msg).unwrap();
 
@@ -202,7 +232,7 @@ mod tests {
         );
         assert_eq!(
             get_attribute(&res, "register", "nym_address"),
-            "client_id.client_key@gateway_id".to_string()
+            name_fixture().address.to_string()
         );
 
         // The expected registered name
@@ -217,13 +247,13 @@ mod tests {
     #[test]
     fn delete() {
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg::new(Coin::new(100, "unym"));
+        let msg = InstantiateMsg::new(Coin::new(100, "unym"), REGISTRATION_PERIOD);
         let info = mock_info("creator", &[]);
         let res = instantiate(deps.as_mut(), mock_env(), info, msg).unwrap();
         assert_eq!(res.messages.len(), 0);
 
         // Register
-        let msg: ExecuteMsg = name_fixture().into();
+        let msg = signed_register_msg(deps.as_ref(), &name_fixture());
         let info_steve = mock_info("steve", &[nyms(100)]);
         assert_eq!(info_steve.sender, name_fixture().owner);
         execute(deps.as_mut(), mock_env(), info_steve, msg).unwrap();
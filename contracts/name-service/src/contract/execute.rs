@@ -4,12 +4,17 @@ use crate::{
     state,
 };
 use cosmwasm_std::{Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use nym_contracts_common::signing::{MessageSignature, Verifier};
 use nym_name_service_common::{
     events::{
-        new_delete_id_event, new_delete_name_event, new_register_event,
-        new_update_deposit_required_event,
+        new_accept_name_transfer_event, new_cancel_name_transfer_event, new_delete_id_event,
+        new_delete_name_event, new_register_event, new_renew_name_event, new_transfer_name_event,
+        new_update_address_event, new_update_deposit_required_event,
     },
-    Address, NameId, NymName, RegisteredName,
+    signing_types::{
+        construct_name_register_sign_payload, construct_name_update_address_sign_payload,
+    },
+    Address, NameEntry, NameId, NymName, RegisteredName,
 };
 
 use super::query;
@@ -60,10 +65,11 @@ fn ensure_name_exists(deps: Deps, name_id: NameId) -> Result<()> {
     }
 }
 
-fn ensure_name_not_exists(deps: Deps, name: &NymName) -> Result<()> {
-    if state::names::has_name(deps.storage, name) {
-        println!("name already exists");
-        Err(NameServiceError::NameAlreadyRegistered { name: name.clone() })
+fn ensure_not_expired(env: &Env, name: &RegisteredName) -> Result<()> {
+    if name.is_expired(env.block.time.seconds()) {
+        Err(NameServiceError::NameExpired {
+            name: name.name.clone(),
+        })
     } else {
         Ok(())
     }
@@ -86,34 +92,288 @@ fn return_deposit(name_to_delete: &RegisteredName) -> BankMsg {
     }
 }
 
+fn take_deposit(deps: Deps, info: &MessageInfo) -> Result<Coin> {
+    let deposit_required = state::deposit_required(deps.storage)?;
+    let denom = deposit_required.denom.clone();
+    let will_deposit = cw_utils::must_pay(info, &denom)
+        .map_err(|err| NameServiceError::DepositRequired { source: err })?;
+    ensure_correct_deposit(will_deposit, deposit_required.amount)?;
+    Ok(Coin::new(will_deposit.u128(), denom))
+}
+
+fn expiry_from_now(deps: Deps, env: &Env) -> Result<u64> {
+    let registration_period = state::registration_period(deps.storage)?;
+    Ok(env.block.time.seconds() + registration_period)
+}
+
+/// Names that are still registered can't be taken. Expired names are removed, and their deposit
+/// returned to the previous owner, so that the name can be registered again.
+fn clear_expired_name(deps: DepsMut, env: &Env, name: &NymName) -> Result<Response> {
+    let (name_id, existing) = match state::names::load_name_entry(deps.storage, name) {
+        Ok(entry) => entry,
+        Err(NameServiceError::NameNotFound { .. }) => return Ok(Response::new()),
+        Err(err) => return Err(err),
+    };
+
+    if !existing.is_expired(env.block.time.seconds()) {
+        return Err(NameServiceError::NameAlreadyRegistered { name: name.clone() });
+    }
+
+    state::names::remove_id(deps.storage, name_id)?;
+    state::remove_pending_transfer(deps.storage, name_id);
+    let return_deposit_msg = return_deposit(&existing);
+
+    Ok(Response::new()
+        .add_message(return_deposit_msg)
+        .add_event(new_delete_id_event(name_id, existing)))
+}
+
+// The identity key of the client is the client id part of its nym address, so a valid signature
+// proves that whoever registers the name controls the address it points to.
+fn client_identity_key(address: &Address) -> Result<[u8; 32]> {
+    let mut public_key = [0u8; 32];
+    let used = bs58::decode(address.client_id())
+        .into(&mut public_key)
+        .map_err(|err| NameServiceError::MalformedEd25519IdentityKey(err.to_string()))?;
+
+    if used != 32 {
+        return Err(NameServiceError::MalformedEd25519IdentityKey(
+            "Too few bytes provided for the public key".into(),
+        ));
+    }
+
+    Ok(public_key)
+}
+
+fn verify_register_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    deposit: Coin,
+    name: NymName,
+    address: Address,
+    signature: MessageSignature,
+) -> Result<()> {
+    // recover the public key
+    let public_key = client_identity_key(&address)?;
+
+    // reconstruct the payload
+    let nonce = state::get_signing_nonce(deps.storage, sender.clone())?;
+
+    let msg = construct_name_register_sign_payload(nonce, sender, deposit, name, address);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(NameServiceError::InvalidEd25519Signature)
+    }
+}
+
+fn verify_update_address_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    name: NymName,
+    address: Address,
+    signature: MessageSignature,
+) -> Result<()> {
+    // recover the public key
+    let public_key = client_identity_key(&address)?;
+
+    // reconstruct the payload
+    let nonce = state::get_signing_nonce(deps.storage, sender.clone())?;
+
+    let msg = construct_name_update_address_sign_payload(nonce, sender, name, address);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(NameServiceError::InvalidEd25519Signature)
+    }
+}
+
 /// Register a new name. It will be assigned a new name id.
 pub fn register(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     name: NymName,
     address: Address,
+    owner_signature: MessageSignature,
 ) -> Result<Response> {
-    ensure_name_not_exists(deps.as_ref(), &name)?;
+    let response = clear_expired_name(deps.branch(), &env, &name)?;
     ensure_max_names_per_owner(deps.as_ref(), info.sender.clone())?;
     ensure_max_names_per_address(deps.as_ref(), address.clone())?;
 
-    let deposit_required = state::deposit_required(deps.storage)?;
-    let denom = deposit_required.denom.clone();
-    let will_deposit = cw_utils::must_pay(&info, &denom)
-        .map_err(|err| NameServiceError::DepositRequired { source: err })?;
-    ensure_correct_deposit(will_deposit, deposit_required.amount)?;
+    let deposit = take_deposit(deps.as_ref(), &info)?;
+
+    // Check that the sender actually controls the nym address by checking the signature
+    verify_register_signature(
+        deps.as_ref(),
+        info.sender.clone(),
+        deposit.clone(),
+        name.clone(),
+        address.clone(),
+        owner_signature,
+    )?;
+
+    state::increment_signing_nonce(deps.storage, info.sender.clone())?;
 
     let new_name = RegisteredName {
         address,
         name,
         owner: info.sender,
         block_height: env.block.height,
-        deposit: Coin::new(will_deposit.u128(), denom),
+        expires_at: expiry_from_now(deps.as_ref(), &env)?,
+        deposit,
     };
     let name_id = state::names::save(deps.storage, &new_name)?;
 
-    Ok(Response::new().add_event(new_register_event(name_id, new_name)))
+    Ok(response.add_event(new_register_event(name_id, new_name)))
+}
+
+/// Renew the registration of a name, so that it expires a full registration period from now.
+/// Expired names can still be renewed by their owner, as long as nobody else has registered them.
+pub(crate) fn renew_name(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: NymName,
+) -> Result<Response> {
+    let NameEntry {
+        name_id,
+        name: mut name_to_renew,
+    } = query::query_name(deps.as_ref(), name)?;
+    ensure_sender_authorized(info, &name_to_renew)?;
+
+    name_to_renew.expires_at = expiry_from_now(deps.as_ref(), &env)?;
+    state::names::update(deps.storage, name_id, &name_to_renew)?;
+
+    Ok(Response::new().add_event(new_renew_name_event(name_id, name_to_renew)))
+}
+
+/// Point an existing name to a new nym address.
+pub(crate) fn update_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: NymName,
+    address: Address,
+    owner_signature: MessageSignature,
+) -> Result<Response> {
+    let NameEntry {
+        name_id,
+        name: mut name_to_update,
+    } = query::query_name(deps.as_ref(), name)?;
+    ensure_sender_authorized(info.clone(), &name_to_update)?;
+    ensure_not_expired(&env, &name_to_update)?;
+    ensure_max_names_per_address(deps.as_ref(), address.clone())?;
+
+    // Same as when registering, the new address has to sign off on the name pointing to it
+    verify_update_address_signature(
+        deps.as_ref(),
+        info.sender.clone(),
+        name_to_update.name.clone(),
+        address.clone(),
+        owner_signature,
+    )?;
+
+    state::increment_signing_nonce(deps.storage, info.sender)?;
+
+    name_to_update.address = address;
+    state::names::update(deps.storage, name_id, &name_to_update)?;
+
+    Ok(Response::new().add_event(new_update_address_event(name_id, name_to_update)))
+}
+
+/// Propose transferring the ownership of a name. The transfer only happens once the new owner
+/// accepts it, which also replaces any previously proposed transfer.
+pub(crate) fn transfer_name(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: NymName,
+    new_owner: String,
+) -> Result<Response> {
+    let new_owner = deps.api.addr_validate(&new_owner)?;
+    let NameEntry {
+        name_id,
+        name: name_to_transfer,
+    } = query::query_name(deps.as_ref(), name)?;
+    ensure_sender_authorized(info, &name_to_transfer)?;
+    ensure_not_expired(&env, &name_to_transfer)?;
+
+    state::save_pending_transfer(deps.storage, name_id, &new_owner)?;
+
+    Ok(Response::new().add_event(new_transfer_name_event(
+        name_id,
+        name_to_transfer,
+        &new_owner,
+    )))
+}
+
+/// Accept a pending transfer of a name. The new owner puts down their own deposit and the deposit
+/// of the previous owner is returned to them.
+pub(crate) fn accept_name_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: NymName,
+) -> Result<Response> {
+    let NameEntry {
+        name_id,
+        name: mut name_to_transfer,
+    } = query::query_name(deps.as_ref(), name)?;
+
+    let new_owner = state::load_pending_transfer(deps.storage, name_id)?.ok_or(
+        NameServiceError::NoPendingTransfer {
+            name: name_to_transfer.name.clone(),
+        },
+    )?;
+    if info.sender != new_owner {
+        return Err(NameServiceError::Unauthorized {
+            sender: info.sender,
+        });
+    }
+    ensure_not_expired(&env, &name_to_transfer)?;
+    ensure_max_names_per_owner(deps.as_ref(), new_owner.clone())?;
+
+    let deposit = take_deposit(deps.as_ref(), &info)?;
+    let return_deposit_msg = return_deposit(&name_to_transfer);
+    let previous_owner = name_to_transfer.owner.clone();
+
+    name_to_transfer.owner = new_owner;
+    name_to_transfer.deposit = deposit;
+    state::names::update(deps.storage, name_id, &name_to_transfer)?;
+    state::remove_pending_transfer(deps.storage, name_id);
+
+    Ok(Response::new()
+        .add_message(return_deposit_msg)
+        .add_event(new_accept_name_transfer_event(
+            name_id,
+            name_to_transfer,
+            &previous_owner,
+        )))
+}
+
+/// Cancel a pending transfer of a name.
+pub(crate) fn cancel_name_transfer(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: NymName,
+) -> Result<Response> {
+    let NameEntry {
+        name_id,
+        name: name_to_keep,
+    } = query::query_name(deps.as_ref(), name)?;
+    ensure_sender_authorized(info, &name_to_keep)?;
+
+    if state::load_pending_transfer(deps.storage, name_id)?.is_none() {
+        return Err(NameServiceError::NoPendingTransfer {
+            name: name_to_keep.name,
+        });
+    }
+    state::remove_pending_transfer(deps.storage, name_id);
+
+    Ok(Response::new().add_event(new_cancel_name_transfer_event(name_id, name_to_keep)))
 }
 
 /// Delete an exsisting name.
@@ -123,6 +383,7 @@ pub fn delete_id(deps: DepsMut, info: MessageInfo, name_id: NameId) -> Result<Re
     ensure_sender_authorized(info, &name_to_delete)?;
 
     state::names::remove_id(deps.storage, name_id)?;
+    state::remove_pending_transfer(deps.storage, name_id);
     let return_deposit_msg = return_deposit(&name_to_delete);
 
     Ok(Response::new()
@@ -136,6 +397,7 @@ pub(crate) fn delete_name(deps: DepsMut, info: MessageInfo, name: NymName) -> Re
     ensure_sender_authorized(info, &name_to_delete.name)?;
 
     state::names::remove_id(deps.storage, name_to_delete.name_id)?;
+    state::remove_pending_transfer(deps.storage, name_to_delete.name_id);
     let return_deposit_msg = return_deposit(&name_to_delete.name);
 
     Ok(Response::new()
@@ -1,7 +1,9 @@
 use cosmwasm_std::Deps;
-use nym_contracts_common::ContractBuildInformation;
+use nym_contracts_common::{signing::Nonce, ContractBuildInformation};
 use nym_name_service_common::{
-    response::{ConfigResponse, NamesListResponse, PagedNamesListResponse},
+    response::{
+        ConfigResponse, NamesListResponse, PagedNamesListResponse, PendingTransferResponse,
+    },
     Address, NameEntry, NameId, NymName,
 };
 
@@ -44,6 +46,17 @@ pub fn query_all_paged(
     Ok(PagedNamesListResponse::new(names, limit, start_next_after))
 }
 
+pub fn query_pending_transfer(deps: Deps, name: NymName) -> Result<PendingTransferResponse> {
+    let (name_id, _) = state::names::load_name_entry(deps.storage, &name)?;
+    let new_owner = state::load_pending_transfer(deps.storage, name_id)?;
+    Ok(PendingTransferResponse { name_id, new_owner })
+}
+
+pub fn query_current_signing_nonce(deps: Deps<'_>, address: String) -> Result<Nonce> {
+    let address = deps.api.addr_validate(&address)?;
+    state::get_signing_nonce(deps.storage, address)
+}
+
 pub fn query_config(deps: Deps) -> Result<ConfigResponse> {
     let config = state::load_config(deps.storage)?;
     Ok(config.into())
@@ -1,5 +1,6 @@
 use cosmwasm_std::{Addr, StdError};
 use cw_controllers::AdminError;
+use nym_contracts_common::signing::verifier::ApiVerifierError;
 use nym_name_service_common::{Address, NameId, NymName};
 use thiserror::Error;
 
@@ -47,11 +48,32 @@ pub enum NameServiceError {
         error_message: String,
     },
 
+    #[error("a registration period has to be provided when migrating from a contract without one")]
+    MissingRegistrationPeriod,
+
     #[error("duplicate entries detected for name: {name}")]
     DuplicateNames { name: NymName },
 
     #[error("name already registered: {name}")]
     NameAlreadyRegistered { name: NymName },
+
+    #[error("the registration of name {name} has expired")]
+    NameExpired { name: NymName },
+
+    #[error("there is no pending transfer for name: {name}")]
+    NoPendingTransfer { name: NymName },
+
+    #[error("Failed to recover ed25519 public key from its base58 representation - {0}")]
+    MalformedEd25519IdentityKey(String),
+
+    #[error("Provided ed25519 signature did not verify correctly")]
+    InvalidEd25519Signature,
+
+    #[error("failed to verify message signature: {source}")]
+    SignatureVerificationFailure {
+        #[from]
+        source: ApiVerifierError,
+    },
 }
 
 pub(crate) type Result<T, E = NameServiceError> = std::result::Result<T, E>;
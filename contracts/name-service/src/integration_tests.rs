@@ -1,15 +1,19 @@
 //! Integration tests using cw-multi-test.
 
-use cosmwasm_std::Addr;
+use cosmwasm_std::{testing::mock_env, Addr};
 use nym_name_service_common::{
-    response::{ConfigResponse, PagedNamesListResponse},
+    response::{ConfigResponse, PagedNamesListResponse, PendingTransferResponse},
     Address, NameEntry, NymName, RegisteredName,
 };
 
 use crate::{
     constants::NAME_DEFAULT_RETRIEVAL_LIMIT,
     error::NameServiceError,
-    test_helpers::{fixture::name_entry, helpers::nyms, test_setup::TestSetup},
+    test_helpers::{
+        fixture::{name_entry, new_nym_address},
+        helpers::{nyms, test_rng, REGISTRATION_PERIOD},
+        test_setup::TestSetup,
+    },
 };
 
 #[test]
@@ -23,6 +27,7 @@ fn query_config() {
         TestSetup::new().query_config(),
         ConfigResponse {
             deposit_required: nyms(100),
+            registration_period: REGISTRATION_PERIOD,
         }
     );
 }
@@ -42,7 +47,7 @@ fn register_and_query_name() {
     // Register a first name
     let owner = Addr::unchecked("owner");
     let name = NymName::new("steves-server").unwrap();
-    let nym_address = Address::new("nym-address");
+    let nym_address = setup.new_address();
     assert_eq!(setup.contract_balance(), nyms(0));
     assert_eq!(setup.balance(&owner), nyms(250));
     setup.register(name.clone(), nym_address.clone(), owner.clone());
@@ -62,6 +67,7 @@ fn register_and_query_name() {
                     name: name.clone(),
                     owner: owner.clone(),
                     block_height: 12345,
+                    expires_at: mock_env().block.time.seconds() + REGISTRATION_PERIOD,
                     deposit: nyms(100),
                 },
             }],
@@ -80,6 +86,7 @@ fn register_and_query_name() {
                 name: name.clone(),
                 owner: owner.clone(),
                 block_height: 12345,
+                expires_at: mock_env().block.time.seconds() + REGISTRATION_PERIOD,
                 deposit: nyms(100),
             },
         }
@@ -88,7 +95,7 @@ fn register_and_query_name() {
     // Register a second name
     let owner2 = Addr::unchecked("owner2");
     let name2 = NymName::new("another_server").unwrap();
-    let nym_address2 = Address::new("nymAddress2");
+    let nym_address2 = setup.new_address();
     setup.register(name2.clone(), nym_address2.clone(), owner2.clone());
 
     assert_eq!(setup.contract_balance(), nyms(200));
@@ -108,18 +115,19 @@ fn register_and_query_name() {
 #[test]
 fn cant_register_a_name_without_funds() {
     let mut setup = TestSetup::new();
+    let nym_address = setup.new_address();
     assert_eq!(setup.contract_balance(), nyms(0));
     assert_eq!(setup.balance("owner"), nyms(250));
     setup.register(
         NymName::new("my_name").unwrap(),
-        Address::new("nymAddress"),
+        nym_address.clone(),
         Addr::unchecked("owner"),
     );
     assert_eq!(setup.contract_balance(), nyms(100));
     assert_eq!(setup.balance("owner"), nyms(150));
     setup.register(
         NymName::new("my_name2").unwrap(),
-        Address::new("nymAddress"),
+        nym_address.clone(),
         Addr::unchecked("owner"),
     );
     assert_eq!(setup.contract_balance(), nyms(200));
@@ -127,7 +135,7 @@ fn cant_register_a_name_without_funds() {
     let res = setup
         .try_register(
             NymName::new("my_name3").unwrap(),
-            Address::new("nymAddress"),
+            nym_address,
             Addr::unchecked("owner"),
         )
         .unwrap_err();
@@ -146,9 +154,10 @@ fn cant_register_a_name_without_funds() {
 #[test]
 fn delete_name() {
     let mut setup = TestSetup::new();
+    let nym_address = setup.new_address();
     setup.register(
         NymName::new("my_name").unwrap(),
-        Address::new("nymAddress"),
+        nym_address,
         Addr::unchecked("owner"),
     );
     assert_eq!(setup.contract_balance(), nyms(100));
@@ -165,10 +174,11 @@ fn delete_name() {
 #[test]
 fn only_owner_can_delete_name() {
     let mut setup = TestSetup::new();
+    let nym_address = setup.new_address();
     assert_eq!(setup.contract_balance(), nyms(0));
     setup.register(
         NymName::new("name").unwrap(),
-        Address::new("nymAddress"),
+        nym_address,
         Addr::unchecked("owner"),
     );
     assert_eq!(setup.contract_balance(), nyms(100));
@@ -190,9 +200,10 @@ fn only_owner_can_delete_name() {
 #[test]
 fn cant_delete_name_that_does_not_exist() {
     let mut setup = TestSetup::new();
+    let nym_address = setup.new_address();
     setup.register(
         NymName::new("foo").unwrap(),
-        Address::new("nymAddress"),
+        nym_address,
         Addr::unchecked("owner"),
     );
     assert_eq!(setup.contract_balance(), nyms(100));
@@ -221,16 +232,17 @@ fn cant_delete_name_that_does_not_exist() {
 #[test]
 fn cant_register_the_same_name_multiple_times() {
     let mut setup = TestSetup::new();
+    let nym_address = setup.new_address();
 
     setup.register(
         NymName::new("name").unwrap(),
-        Address::new("nymAddress"),
+        nym_address.clone(),
         Addr::unchecked("owner"),
     );
     let resp = setup
         .try_register(
             NymName::new("name").unwrap(),
-            Address::new("nymAddress"),
+            nym_address,
             Addr::unchecked("owner"),
         )
         .unwrap_err();
@@ -248,7 +260,7 @@ fn can_register_multiple_names_for_the_same_nym_address() {
     let mut setup = TestSetup::new();
     let name1 = NymName::new("name1").unwrap();
     let name2 = NymName::new("name2").unwrap();
-    let address = Address::new("nymaddress");
+    let address = setup.new_address();
     let owner = Addr::unchecked("owner");
 
     setup.register(name1.clone(), address.clone(), owner.clone());
@@ -268,8 +280,8 @@ fn register_multiple_names_and_deleting_by_name() {
     let mut setup = TestSetup::new();
     let owner1 = Addr::unchecked("wealthy_owner_1");
     let owner2 = Addr::unchecked("wealthy_owner_2");
-    let nym_address1 = Address::new("nymaddress1");
-    let nym_address2 = Address::new("nymaddress2");
+    let nym_address1 = setup.new_address();
+    let nym_address2 = setup.new_address();
     let name1 = NymName::new("name1").unwrap();
     let name2 = NymName::new("name2").unwrap();
     let name3 = NymName::new("name3").unwrap();
@@ -326,8 +338,8 @@ fn check_paging() {
     let mut setup = TestSetup::new();
     let owner1 = Addr::unchecked("wealthy_owner_1");
     let owner2 = Addr::unchecked("wealthy_owner_2");
-    let nym_address1 = Address::new("nymAddress1");
-    let nym_address2 = Address::new("nymAddress2");
+    let nym_address1 = setup.new_address();
+    let nym_address2 = setup.new_address();
     let name1 = NymName::new("name1").unwrap();
     let name2 = NymName::new("name2").unwrap();
     let name3 = NymName::new("name3").unwrap();
@@ -390,19 +402,23 @@ fn check_paging() {
 #[test]
 fn name_id_is_not_resused_when_deleting_and_then_adding_a_new_names() {
     let mut setup = TestSetup::new();
+    let nym_address1 = setup.new_address();
+    let nym_address2 = setup.new_address();
+    let nym_address3 = setup.new_address();
+    let nym_address4 = setup.new_address();
     setup.register(
         NymName::new("myname1").unwrap(),
-        Address::new("nymAddress1"),
+        nym_address1,
         Addr::unchecked("owner1"),
     );
     setup.register(
         NymName::new("myname2").unwrap(),
-        Address::new("nymAddress2"),
+        nym_address2.clone(),
         Addr::unchecked("owner2"),
     );
     setup.register(
         NymName::new("myname3").unwrap(),
-        Address::new("nymAddress3"),
+        nym_address3,
         Addr::unchecked("owner3"),
     );
 
@@ -414,14 +430,14 @@ fn name_id_is_not_resused_when_deleting_and_then_adding_a_new_names() {
         vec![name_entry(
             2,
             NymName::new("myname2").unwrap(),
-            Address::new("nymAddress2"),
+            nym_address2.clone(),
             Addr::unchecked("owner2")
         )]
     );
 
     setup.register(
         NymName::new("myname4").unwrap(),
-        Address::new("nymAddress4"),
+        nym_address4.clone(),
         Addr::unchecked("owner4"),
     );
 
@@ -431,15 +447,268 @@ fn name_id_is_not_resused_when_deleting_and_then_adding_a_new_names() {
             name_entry(
                 2,
                 NymName::new("myname2").unwrap(),
-                Address::new("nymAddress2"),
+                nym_address2,
                 Addr::unchecked("owner2")
             ),
             name_entry(
                 4,
                 NymName::new("myname4").unwrap(),
-                Address::new("nymAddress4"),
+                nym_address4,
                 Addr::unchecked("owner4")
             )
         ]
     );
 }
+
+#[test]
+fn register_requires_signature_from_the_client_identity_key() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let name = NymName::new("steves-server").unwrap();
+    let nym_address = setup.new_address();
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 0);
+
+    // Signing with any other key than the one of the client behind the address fails
+    let (_, other_keys) = new_nym_address(&mut test_rng());
+    let owner_signature = setup.sign_register(&name, &nym_address, &owner, &other_keys);
+    let resp = setup
+        .try_register_with_signature(
+            name.clone(),
+            nym_address.clone(),
+            owner.clone(),
+            owner_signature,
+        )
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::InvalidEd25519Signature
+    );
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 0);
+    assert!(setup.query_all().names.is_empty());
+
+    // An address that doesn't start with a valid identity key can't be signed for at all
+    let owner_signature = setup.sign_register(&name, &nym_address, &owner, &other_keys);
+    let resp = setup
+        .try_register_with_signature(
+            name.clone(),
+            Address::new("nymAddress"),
+            owner.clone(),
+            owner_signature,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::MalformedEd25519IdentityKey(..)
+    ));
+
+    // Registering with the correct signature bumps the signing nonce
+    setup.register(name, nym_address, owner.clone());
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 1);
+}
+
+#[test]
+fn expired_name_can_be_registered_by_someone_else() {
+    let mut setup = TestSetup::new();
+    let owner1 = Addr::unchecked("owner1");
+    let owner2 = Addr::unchecked("owner2");
+    let name = NymName::new("name").unwrap();
+    let nym_address1 = setup.new_address();
+    let nym_address2 = setup.new_address();
+
+    setup.register(name.clone(), nym_address1, owner1.clone());
+    assert_eq!(setup.balance(&owner1), nyms(150));
+
+    // Up until it expires, the name is taken
+    setup.advance_time(REGISTRATION_PERIOD - 1);
+    let resp = setup
+        .try_register(name.clone(), nym_address2.clone(), owner2.clone())
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NameAlreadyRegistered { name: name.clone() }
+    );
+
+    // Once expired, anyone can register it. The previous owner gets their deposit back.
+    setup.advance_time(1);
+    setup.register(name.clone(), nym_address2.clone(), owner2.clone());
+    assert_eq!(setup.balance(&owner1), nyms(250));
+    assert_eq!(setup.balance(&owner2), nyms(150));
+    assert_eq!(setup.contract_balance(), nyms(100));
+
+    let entries = setup.query_all().names;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].name_id, 2);
+    assert_eq!(entries[0].name.owner, owner2);
+    assert_eq!(entries[0].name.address, nym_address2);
+    assert_eq!(
+        entries[0].name.expires_at,
+        mock_env().block.time.seconds() + 2 * REGISTRATION_PERIOD
+    );
+}
+
+#[test]
+fn renewing_extends_the_registration() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let name = NymName::new("name").unwrap();
+    let nym_address = setup.new_address();
+    setup.register(name.clone(), nym_address, owner.clone());
+
+    // Only the owner can renew
+    let resp = setup
+        .try_renew(name.clone(), Addr::unchecked("user"))
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("user")
+        }
+    );
+
+    // The registration is extended to a full period from the time of renewal
+    setup.advance_time(REGISTRATION_PERIOD / 2);
+    setup.try_renew(name.clone(), owner.clone()).unwrap();
+    assert_eq!(
+        setup.query_id(1).name.expires_at,
+        mock_env().block.time.seconds() + REGISTRATION_PERIOD / 2 + REGISTRATION_PERIOD
+    );
+
+    // An expired name can still be renewed, as long as nobody else took it
+    setup.advance_time(2 * REGISTRATION_PERIOD);
+    setup.try_renew(name, owner).unwrap();
+    assert_eq!(
+        setup.query_id(1).name.expires_at,
+        mock_env().block.time.seconds() + REGISTRATION_PERIOD / 2 + 3 * REGISTRATION_PERIOD
+    );
+}
+
+#[test]
+fn owner_can_update_the_address() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let name = NymName::new("name").unwrap();
+    let nym_address1 = setup.new_address();
+    let nym_address2 = setup.new_address();
+    setup.register(name.clone(), nym_address1, owner.clone());
+
+    let resp = setup
+        .try_update_address(name.clone(), nym_address2.clone(), Addr::unchecked("user"))
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("user")
+        }
+    );
+
+    setup
+        .try_update_address(name.clone(), nym_address2.clone(), owner.clone())
+        .unwrap();
+    assert_eq!(setup.query_id(1).name.address, nym_address2);
+    assert_eq!(setup.query_signing_nonce(owner.to_string()), 2);
+
+    // Expired names have to be renewed first
+    setup.advance_time(REGISTRATION_PERIOD);
+    let nym_address3 = setup.new_address();
+    let resp = setup
+        .try_update_address(name.clone(), nym_address3, owner)
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NameExpired { name }
+    );
+}
+
+#[test]
+fn transfer_name_to_new_owner() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let new_owner = Addr::unchecked("owner2");
+    let name = NymName::new("name").unwrap();
+    let nym_address = setup.new_address();
+    setup.register(name.clone(), nym_address.clone(), owner.clone());
+
+    // Only the owner can propose a transfer
+    let resp = setup
+        .try_transfer(name.clone(), new_owner.clone(), new_owner.clone())
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: new_owner.clone()
+        }
+    );
+
+    setup
+        .try_transfer(name.clone(), owner.clone(), new_owner.clone())
+        .unwrap();
+    assert_eq!(
+        setup.query_pending_transfer(name.clone()),
+        PendingTransferResponse {
+            name_id: 1,
+            new_owner: Some(new_owner.clone()),
+        }
+    );
+
+    // Only the proposed owner can accept it
+    let resp = setup
+        .try_accept_transfer(name.clone(), Addr::unchecked("owner3"))
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("owner3")
+        }
+    );
+
+    // Accepting swaps the deposits of the previous and the new owner
+    setup
+        .try_accept_transfer(name.clone(), new_owner.clone())
+        .unwrap();
+    assert_eq!(setup.balance(&owner), nyms(250));
+    assert_eq!(setup.balance(&new_owner), nyms(150));
+    assert_eq!(setup.contract_balance(), nyms(100));
+    assert_eq!(
+        setup.query_all().names,
+        vec![name_entry(1, name.clone(), nym_address, new_owner)]
+    );
+    assert_eq!(
+        setup.query_pending_transfer(name),
+        PendingTransferResponse {
+            name_id: 1,
+            new_owner: None,
+        }
+    );
+}
+
+#[test]
+fn cancelled_transfer_cant_be_accepted() {
+    let mut setup = TestSetup::new();
+    let owner = Addr::unchecked("owner");
+    let new_owner = Addr::unchecked("owner2");
+    let name = NymName::new("name").unwrap();
+    let nym_address = setup.new_address();
+    setup.register(name.clone(), nym_address, owner.clone());
+
+    let resp = setup
+        .try_cancel_transfer(name.clone(), owner.clone())
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NoPendingTransfer { name: name.clone() }
+    );
+
+    setup
+        .try_transfer(name.clone(), owner.clone(), new_owner.clone())
+        .unwrap();
+    setup.try_cancel_transfer(name.clone(), owner).unwrap();
+
+    let resp = setup
+        .try_accept_transfer(name.clone(), new_owner.clone())
+        .unwrap_err();
+    assert_eq!(
+        resp.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NoPendingTransfer { name }
+    );
+    assert_eq!(setup.balance(&new_owner), nyms(250));
+}
@@ -13,6 +13,7 @@ use error::NameServiceError;
 
 mod contract;
 mod error;
+mod queued_migrations;
 mod state;
 
 pub mod constants;
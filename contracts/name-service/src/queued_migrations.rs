@@ -0,0 +1,233 @@
+use cosmwasm_std::{Addr, Coin, DepsMut, Env, Order, StdResult};
+use cw_storage_plus::{Item, Map};
+use nym_name_service_common::{Address, NameId, NymName, RegisteredName};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{CONFIG_KEY, NAMES_PK_NAMESPACE},
+    error::{NameServiceError, Result},
+    state::{self, Config},
+};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct LegacyConfig {
+    deposit_required: Coin,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+struct LegacyRegisteredName {
+    name: NymName,
+    address: Address,
+    owner: Addr,
+    block_height: u64,
+    deposit: Coin,
+}
+
+/// Contracts deployed before names could expire have neither a registration period in their
+/// config nor an expiry on the registered names. Set both, with every existing registration
+/// expiring one registration period from now.
+pub fn backfill_registration_expiry(
+    deps: DepsMut<'_>,
+    env: &Env,
+    registration_period: Option<u64>,
+) -> Result<()> {
+    // the config is already in the current layout, so there's nothing to backfill
+    if state::load_config(deps.storage).is_ok() {
+        return Ok(());
+    }
+
+    let registration_period =
+        registration_period.ok_or(NameServiceError::MissingRegistrationPeriod)?;
+
+    let legacy_config = Item::<LegacyConfig>::new(CONFIG_KEY).load(deps.storage)?;
+    state::save_config(
+        deps.storage,
+        &Config {
+            deposit_required: legacy_config.deposit_required,
+            registration_period,
+        },
+    )?;
+
+    let expires_at = env.block.time.seconds() + registration_period;
+    let legacy_names = Map::<NameId, LegacyRegisteredName>::new(NAMES_PK_NAMESPACE)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (name_id, legacy) in legacy_names {
+        let name = RegisteredName {
+            name: legacy.name,
+            address: legacy.address,
+            owner: legacy.owner,
+            block_height: legacy.block_height,
+            expires_at,
+            deposit: legacy.deposit,
+        };
+        state::names::overwrite_legacy(deps.storage, name_id, &name)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        constants::{
+            NAMES_ADDRESS_IDX_NAMESPACE, NAMES_NAME_IDX_NAMESPACE, NAMES_OWNER_IDX_NAMESPACE,
+        },
+        test_helpers::helpers::REGISTRATION_PERIOD,
+    };
+
+    use cosmwasm_std::{
+        testing::{mock_dependencies, mock_env},
+        Storage,
+    };
+    use cw_storage_plus::{Index, IndexList, IndexedMap, MultiIndex, UniqueIndex};
+    use nym_name_service_common::msg::MigrateMsg;
+
+    const DENOM: &str = "unym";
+
+    struct LegacyNameIndex<'a> {
+        name: UniqueIndex<'a, String, LegacyRegisteredName, NameId>,
+        address: MultiIndex<'a, String, LegacyRegisteredName, NameId>,
+        owner: MultiIndex<'a, Addr, LegacyRegisteredName, NameId>,
+    }
+
+    impl<'a> IndexList<LegacyRegisteredName> for LegacyNameIndex<'a> {
+        fn get_indexes(
+            &'_ self,
+        ) -> Box<dyn Iterator<Item = &'_ dyn Index<LegacyRegisteredName>> + '_> {
+            let v: Vec<&dyn Index<LegacyRegisteredName>> =
+                vec![&self.name, &self.address, &self.owner];
+            Box::new(v.into_iter())
+        }
+    }
+
+    fn legacy_names<'a>() -> IndexedMap<'a, NameId, LegacyRegisteredName, LegacyNameIndex<'a>> {
+        let indexes = LegacyNameIndex {
+            name: UniqueIndex::new(|d| d.name.to_string(), NAMES_NAME_IDX_NAMESPACE),
+            address: MultiIndex::new(
+                |d| d.address.to_string(),
+                NAMES_PK_NAMESPACE,
+                NAMES_ADDRESS_IDX_NAMESPACE,
+            ),
+            owner: MultiIndex::new(
+                |d| d.owner.clone(),
+                NAMES_PK_NAMESPACE,
+                NAMES_OWNER_IDX_NAMESPACE,
+            ),
+        };
+        IndexedMap::new(NAMES_PK_NAMESPACE, indexes)
+    }
+
+    fn legacy_name(name: &str, owner: &str) -> LegacyRegisteredName {
+        LegacyRegisteredName {
+            name: NymName::new(name).unwrap(),
+            address: Address::new(&format!("{name}.client_key@gateway_id")),
+            owner: Addr::unchecked(owner),
+            block_height: 12345,
+            deposit: Coin::new(100, DENOM),
+        }
+    }
+
+    fn setup_legacy_state(store: &mut dyn Storage) -> Vec<LegacyRegisteredName> {
+        cw2::set_contract_version(store, "crate:nym-name-service", "0.0.1").unwrap();
+        Item::new(CONFIG_KEY)
+            .save(
+                store,
+                &LegacyConfig {
+                    deposit_required: Coin::new(100, DENOM),
+                },
+            )
+            .unwrap();
+
+        let names = vec![
+            legacy_name("foo", "steve"),
+            legacy_name("bar", "steve"),
+            legacy_name("baz", "timmy"),
+        ];
+        for (name_id, name) in (1..).zip(&names) {
+            legacy_names().save(store, name_id, name).unwrap();
+        }
+        names
+    }
+
+    #[test]
+    fn migrating_from_the_legacy_layout_backfills_the_config_and_expiry() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let legacy = setup_legacy_state(deps.as_mut().storage);
+
+        // nothing in the old layout can be read with the current types
+        assert!(state::load_config(deps.as_ref().storage).is_err());
+        assert!(state::names::load_id(deps.as_ref().storage, 1).is_err());
+
+        crate::contract::migrate(
+            deps.as_mut(),
+            env.clone(),
+            MigrateMsg {
+                registration_period: Some(REGISTRATION_PERIOD),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            state::load_config(deps.as_ref().storage).unwrap(),
+            Config {
+                deposit_required: Coin::new(100, DENOM),
+                registration_period: REGISTRATION_PERIOD,
+            }
+        );
+
+        let expires_at = env.block.time.seconds() + REGISTRATION_PERIOD;
+        for (name_id, legacy) in (1..).zip(&legacy) {
+            let expected = RegisteredName {
+                name: legacy.name.clone(),
+                address: legacy.address.clone(),
+                owner: legacy.owner.clone(),
+                block_height: legacy.block_height,
+                expires_at,
+                deposit: legacy.deposit.clone(),
+            };
+            assert_eq!(
+                state::names::load_id(deps.as_ref().storage, name_id).unwrap(),
+                expected
+            );
+            // the indexes have to point to the migrated entries as well
+            assert_eq!(
+                state::names::load_name(deps.as_ref().storage, &legacy.name).unwrap(),
+                expected
+            );
+        }
+        assert_eq!(
+            state::names::load_owner(deps.as_ref().storage, Addr::unchecked("steve"))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            state::names::load_address(deps.as_ref().storage, &legacy[2].address)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn migrating_from_the_legacy_layout_requires_a_registration_period() {
+        let mut deps = mock_dependencies();
+        setup_legacy_state(deps.as_mut().storage);
+
+        assert_eq!(
+            crate::contract::migrate(
+                deps.as_mut(),
+                mock_env(),
+                MigrateMsg {
+                    registration_period: None,
+                },
+            ),
+            Err(NameServiceError::MissingRegistrationPeriod)
+        );
+    }
+}
@@ -10,12 +10,14 @@ const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub(crate) struct Config {
     pub deposit_required: Coin,
+    pub registration_period: u64,
 }
 
 impl From<Config> for ConfigResponse {
     fn from(config: Config) -> Self {
         ConfigResponse {
             deposit_required: config.deposit_required,
+            registration_period: config.registration_period,
         }
     }
 }
@@ -32,3 +34,10 @@ pub(crate) fn load_config(store: &dyn Storage) -> Result<Config> {
 pub(crate) fn deposit_required(store: &dyn Storage) -> Result<Coin> {
     Ok(CONFIG.load(store).map(|config| config.deposit_required)?)
 }
+
+/// Return how long, in seconds, a registration is valid for.
+pub(crate) fn registration_period(store: &dyn Storage) -> Result<u64> {
+    Ok(CONFIG
+        .load(store)
+        .map(|config| config.registration_period)?)
+}
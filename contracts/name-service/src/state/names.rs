@@ -1,5 +1,5 @@
-use cosmwasm_std::{Addr, Order, StdError, StdResult, Storage};
-use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, MultiIndex, UniqueIndex};
+use cosmwasm_std::{Addr, Empty, Order, StdError, StdResult, Storage};
+use cw_storage_plus::{Bound, Index, IndexList, IndexedMap, Map, MultiIndex, UniqueIndex};
 use nym_name_service_common::{Address, NameId, NymName, RegisteredName};
 
 use crate::{
@@ -49,6 +49,24 @@ pub fn save(store: &mut dyn Storage, new_name: &RegisteredName) -> Result<NameId
     Ok(name_id)
 }
 
+/// Overwrite an existing entry, such as when the owner or address of the name changes.
+pub fn update(store: &mut dyn Storage, name_id: NameId, name: &RegisteredName) -> Result<()> {
+    Ok(names().save(store, name_id, name)?)
+}
+
+/// Overwrite an entry stored in a layout that can no longer be deserialized, such as when
+/// migrating. The stale copy kept by the unique name index is dropped before re-indexing, while
+/// the multi indexes are keyed on unchanged fields and simply get overwritten.
+pub(crate) fn overwrite_legacy(
+    store: &mut dyn Storage,
+    name_id: NameId,
+    name: &RegisteredName,
+) -> Result<()> {
+    Map::<NameId, Empty>::new(NAMES_PK_NAMESPACE).remove(store, name_id);
+    Map::<String, Empty>::new(NAMES_NAME_IDX_NAMESPACE).remove(store, name.name.to_string());
+    Ok(names().save(store, name_id, name)?)
+}
+
 #[cfg(test)]
 pub fn save_all(state: &mut dyn Storage, names: &[RegisteredName]) -> Result<Vec<NameId>> {
     let mut ids = vec![];
@@ -397,6 +415,26 @@ mod tests {
         assert!(!has_name(&deps.storage, &name_fixture().name));
     }
 
+    #[rstest]
+    fn update_works(mut deps: TestDeps) {
+        save(deps.as_mut().storage, &name_fixture()).unwrap();
+        let updated = name_fixture_full("my-service", "new_address", "timmy");
+        update(deps.as_mut().storage, 1, &updated).unwrap();
+        assert_eq!(load_id(deps.as_ref().storage, 1).unwrap(), updated);
+        assert_eq!(
+            load_name(deps.as_ref().storage, &updated.name).unwrap(),
+            updated
+        );
+        assert_eq!(
+            load_address(deps.as_ref().storage, &name_fixture().address).unwrap(),
+            vec![]
+        );
+        assert_eq!(
+            load_owner(deps.as_ref().storage, Addr::unchecked("timmy")).unwrap(),
+            vec![(1, updated)]
+        );
+    }
+
     #[rstest]
     fn remove_name_works(mut deps: TestDeps) {
         save(deps.as_mut().storage, &name_fixture()).unwrap();
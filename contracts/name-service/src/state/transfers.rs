@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Map;
+use nym_name_service_common::NameId;
+
+use crate::{constants::PENDING_TRANSFERS_NAMESPACE, error::Result};
+
+/// The account each name is being transferred to, until the transfer is accepted or cancelled.
+const PENDING_TRANSFERS: Map<'_, NameId, Addr> = Map::new(PENDING_TRANSFERS_NAMESPACE);
+
+pub(crate) fn save_pending_transfer(
+    store: &mut dyn Storage,
+    name_id: NameId,
+    new_owner: &Addr,
+) -> Result<()> {
+    Ok(PENDING_TRANSFERS.save(store, name_id, new_owner)?)
+}
+
+pub(crate) fn load_pending_transfer(store: &dyn Storage, name_id: NameId) -> Result<Option<Addr>> {
+    Ok(PENDING_TRANSFERS.may_load(store, name_id)?)
+}
+
+pub(crate) fn remove_pending_transfer(store: &mut dyn Storage, name_id: NameId) {
+    PENDING_TRANSFERS.remove(store, name_id)
+}
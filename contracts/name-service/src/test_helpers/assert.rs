@@ -7,11 +7,19 @@ use nym_name_service_common::{
 
 use crate::{constants::NAME_DEFAULT_RETRIEVAL_LIMIT, error::NameServiceError};
 
+use super::helpers::REGISTRATION_PERIOD;
+
 pub fn assert_config(deps: Deps, admin: &Addr, deposit_required: Coin) {
     crate::state::assert_admin(deps, admin).unwrap();
     let res = crate::contract::query(deps, mock_env(), QueryMsg::Config {}).unwrap();
     let config: ConfigResponse = from_binary(&res).unwrap();
-    assert_eq!(config, ConfigResponse { deposit_required });
+    assert_eq!(
+        config,
+        ConfigResponse {
+            deposit_required,
+            registration_period: REGISTRATION_PERIOD,
+        }
+    );
 }
 
 pub fn assert_names(deps: Deps, expected_names: &[NameEntry]) {
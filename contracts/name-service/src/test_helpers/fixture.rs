@@ -1,7 +1,9 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{testing::mock_env, Addr};
+use nym_crypto::asymmetric::identity;
 use nym_name_service_common::{Address, NameEntry, NameId, NymName, RegisteredName};
+use rand_chacha::rand_core::{CryptoRng, RngCore};
 
-use super::helpers::nyms;
+use super::helpers::{nyms, test_rng, REGISTRATION_PERIOD};
 
 pub fn name_fixture_full(name: &str, nym_address: &str, owner: &str) -> RegisteredName {
     RegisteredName {
@@ -9,16 +11,43 @@ pub fn name_fixture_full(name: &str, nym_address: &str, owner: &str) -> Register
         address: Address::new(nym_address),
         owner: Addr::unchecked(owner),
         block_height: 12345,
+        expires_at: mock_env().block.time.seconds() + REGISTRATION_PERIOD,
         deposit: nyms(100),
     }
 }
 
+// Nym address of a client, where the client id is its identity key
+pub fn nym_address(identity_key: &identity::PublicKey) -> Address {
+    Address::new(&format!(
+        "{}.client_key@gateway_id",
+        identity_key.to_base58_string()
+    ))
+}
+
+// Create a new nym address, together with the keypair of the client it belongs to
+pub fn new_nym_address<R>(rng: &mut R) -> (Address, identity::KeyPair)
+where
+    R: RngCore + CryptoRng,
+{
+    let keypair = identity::KeyPair::new(rng);
+    (nym_address(keypair.public_key()), keypair)
+}
+
+// The keypair of the client behind the address used by the fixtures
+pub fn fixture_keypair() -> identity::KeyPair {
+    identity::KeyPair::new(&mut test_rng())
+}
+
+pub fn fixture_address() -> Address {
+    nym_address(fixture_keypair().public_key())
+}
+
 pub fn name_fixture() -> RegisteredName {
-    name_fixture_full("my-service", "client_id.client_key@gateway_id", "steve")
+    name_fixture_full("my-service", fixture_address().as_str(), "steve")
 }
 
 pub fn name_fixture_name(name: &str) -> RegisteredName {
-    name_fixture_full(name, "client_id.client_key@gateway_id", "steve")
+    name_fixture_full(name, fixture_address().as_str(), "steve")
 }
 
 pub fn name_entry(name_id: NameId, name: NymName, address: Address, owner: Addr) -> NameEntry {
@@ -1,7 +1,7 @@
 use cosmwasm_std::{
     coin, coins,
     testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier},
-    Coin, DepsMut, Event, MemoryStorage, OwnedDeps, Response,
+    Coin, Deps, DepsMut, Event, MemoryStorage, OwnedDeps, Response,
 };
 use cw_multi_test::AppResponse;
 use nym_name_service_common::{
@@ -9,11 +9,25 @@ use nym_name_service_common::{
     msg::{ExecuteMsg, InstantiateMsg},
     NameId, NymName, RegisteredName,
 };
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use super::{
+    fixture::fixture_keypair,
+    signing::{ed25519_sign_message, name_register_sign_payload},
+};
+
+// Registrations are valid for a year
+pub const REGISTRATION_PERIOD: u64 = 365 * 24 * 60 * 60;
 
 pub fn nyms(amount: u64) -> Coin {
     Coin::new(amount.into(), "unym")
 }
 
+pub fn test_rng() -> ChaCha20Rng {
+    let dummy_seed = [42u8; 32];
+    ChaCha20Rng::from_seed(dummy_seed)
+}
+
 pub fn get_event_types(response: &Response, event_type: &str) -> Vec<Event> {
     response
         .events
@@ -75,6 +89,7 @@ pub fn instantiate_test_contract() -> OwnedDeps<MemoryStorage, MockApi, MockQuer
     let mut deps = mock_dependencies();
     let msg = InstantiateMsg {
         deposit_required: coin(100, "unym"),
+        registration_period: REGISTRATION_PERIOD,
     };
     let env = mock_env();
     let info = mock_info("creator", &[]);
@@ -83,8 +98,24 @@ pub fn instantiate_test_contract() -> OwnedDeps<MemoryStorage, MockApi, MockQuer
     deps
 }
 
+// Sign and register the name, which is expected to point to the fixture address
+pub fn signed_register_msg(deps: Deps<'_>, name: &RegisteredName) -> ExecuteMsg {
+    let payload = name_register_sign_payload(
+        deps,
+        name.owner.as_str(),
+        name.name.clone(),
+        name.address.clone(),
+        name.deposit.clone(),
+    );
+    ExecuteMsg::Register {
+        name: name.name.clone(),
+        address: name.address.clone(),
+        owner_signature: ed25519_sign_message(payload, fixture_keypair().private_key()),
+    }
+}
+
 pub fn register_name(deps: DepsMut<'_>, name: &RegisteredName) -> NameId {
-    let msg: ExecuteMsg = name.clone().into();
+    let msg = signed_register_msg(deps.as_ref(), name);
     let info = mock_info(name.owner.as_str(), &coins(100, "unym"));
     let res = crate::execute(deps, mock_env(), info, msg).unwrap();
     let name_id: NameId = get_attribute(&res, &NameEventType::Register.to_string(), NAME_ID)
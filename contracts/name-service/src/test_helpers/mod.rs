@@ -1,4 +1,5 @@
 pub mod assert;
 pub mod fixture;
 pub mod helpers;
+pub mod signing;
 pub mod test_setup;
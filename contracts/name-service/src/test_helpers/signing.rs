@@ -0,0 +1,54 @@
+use cosmwasm_std::{Addr, Coin, Deps};
+use nym_contracts_common::signing::{
+    MessageSignature, SignableMessage, SigningAlgorithm, SigningPurpose,
+};
+use nym_crypto::asymmetric::identity;
+use nym_name_service_common::{
+    signing_types::{
+        construct_name_register_sign_payload, construct_name_update_address_sign_payload,
+        SignableNameRegisterMsg, SignableNameUpdateAddressMsg,
+    },
+    Address, NymName,
+};
+use serde::Serialize;
+
+use crate::state;
+
+pub fn name_register_sign_payload(
+    deps: Deps<'_>,
+    owner: &str,
+    name: NymName,
+    address: Address,
+    deposit: Coin,
+) -> SignableNameRegisterMsg {
+    let owner = Addr::unchecked(owner);
+    let nonce = state::get_signing_nonce(deps.storage, owner.clone()).unwrap();
+    construct_name_register_sign_payload(nonce, owner, deposit, name, address)
+}
+
+pub fn name_update_address_sign_payload(
+    deps: Deps<'_>,
+    owner: &str,
+    name: NymName,
+    address: Address,
+) -> SignableNameUpdateAddressMsg {
+    let owner = Addr::unchecked(owner);
+    let nonce = state::get_signing_nonce(deps.storage, owner.clone()).unwrap();
+    construct_name_update_address_sign_payload(nonce, owner, name, address)
+}
+
+pub fn ed25519_sign_message<T: Serialize + SigningPurpose>(
+    message: SignableMessage<T>,
+    private_key: &identity::PrivateKey,
+) -> MessageSignature {
+    match message.algorithm {
+        SigningAlgorithm::Ed25519 => {
+            let plaintext = message.to_plaintext().unwrap();
+            let signature = private_key.sign(&plaintext);
+            MessageSignature::from(signature.to_bytes().as_ref())
+        }
+        SigningAlgorithm::Secp256k1 => {
+            unimplemented!()
+        }
+    }
+}
@@ -1,13 +1,25 @@
+use std::collections::HashMap;
+
 use cosmwasm_std::{coins, Addr, Coin, Uint128};
 use cw_multi_test::{App, AppBuilder, AppResponse, ContractWrapper, Executor};
+use nym_contracts_common::signing::{MessageSignature, Nonce};
+use nym_crypto::asymmetric::identity;
 use nym_name_service_common::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
-    response::{ConfigResponse, PagedNamesListResponse},
+    response::{ConfigResponse, PagedNamesListResponse, PendingTransferResponse},
+    signing_types::{
+        construct_name_register_sign_payload, construct_name_update_address_sign_payload,
+    },
     Address, NameEntry, NameId, NymName,
 };
+use rand_chacha::ChaCha20Rng;
 use serde::de::DeserializeOwned;
 
-use crate::test_helpers::helpers::get_app_attribute;
+use crate::test_helpers::{
+    fixture::new_nym_address,
+    helpers::{get_app_attribute, test_rng, REGISTRATION_PERIOD},
+    signing::ed25519_sign_message,
+};
 
 const DENOM: &str = "unym";
 const ADDRESSES: &[&str] = &[
@@ -19,6 +31,9 @@ const WEALTHY_ADDRESSES: &[&str] = &["wealthy_owner_1", "wealthy_owner_2"];
 pub struct TestSetup {
     app: App,
     addr: Addr,
+    rng: ChaCha20Rng,
+    // The keys of the clients behind the nym addresses created for the tests
+    client_keys: HashMap<String, identity::KeyPair>,
 }
 
 impl Default for TestSetup {
@@ -44,7 +59,12 @@ impl TestSetup {
         let code = ContractWrapper::new(crate::execute, crate::instantiate, crate::query);
         let code_id = app.store_code(Box::new(code));
         let addr = Self::instantiate(&mut app, code_id);
-        TestSetup { app, addr }
+        TestSetup {
+            app,
+            addr,
+            rng: test_rng(),
+            client_keys: HashMap::new(),
+        }
     }
 
     fn instantiate(app: &mut App, code_id: u64) -> Addr {
@@ -53,6 +73,7 @@ impl TestSetup {
             Addr::unchecked("admin"),
             &InstantiateMsg {
                 deposit_required: Coin::new(100, DENOM),
+                registration_period: REGISTRATION_PERIOD,
             },
             &[],
             "contract_label",
@@ -92,16 +113,82 @@ impl TestSetup {
         self.query(&QueryMsg::All { limit, start_after })
     }
 
-    pub fn try_register(
+    pub fn query_pending_transfer(&self, name: NymName) -> PendingTransferResponse {
+        self.query(&QueryMsg::PendingTransfer { name })
+    }
+
+    pub fn query_signing_nonce(&self, address: String) -> Nonce {
+        self.query(&QueryMsg::SigningNonce { address })
+    }
+
+    // Create a new nym address, keeping the keys of its client around for signing
+    pub fn new_address(&mut self) -> Address {
+        let (address, keys) = new_nym_address(&mut self.rng);
+        self.client_keys.insert(address.to_string(), keys);
+        address
+    }
+
+    fn client_keys(&self, address: &Address) -> &identity::KeyPair {
+        self.client_keys
+            .get(address.as_str())
+            .expect("the address was not created by the test setup")
+    }
+
+    pub fn advance_time(&mut self, seconds: u64) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(seconds));
+    }
+
+    pub fn sign_register(
+        &self,
+        name: &NymName,
+        address: &Address,
+        owner: &Addr,
+        keys: &identity::KeyPair,
+    ) -> MessageSignature {
+        let nonce = self.query_signing_nonce(owner.to_string());
+        let payload = construct_name_register_sign_payload(
+            nonce,
+            owner.clone(),
+            Coin::new(100, DENOM),
+            name.clone(),
+            address.clone(),
+        );
+        ed25519_sign_message(payload, keys.private_key())
+    }
+
+    pub fn sign_update_address(
+        &self,
+        name: &NymName,
+        address: &Address,
+        owner: &Addr,
+        keys: &identity::KeyPair,
+    ) -> MessageSignature {
+        let nonce = self.query_signing_nonce(owner.to_string());
+        let payload = construct_name_update_address_sign_payload(
+            nonce,
+            owner.clone(),
+            name.clone(),
+            address.clone(),
+        );
+        ed25519_sign_message(payload, keys.private_key())
+    }
+
+    pub fn try_register_with_signature(
         &mut self,
         name: NymName,
         address: Address,
         owner: Addr,
+        owner_signature: MessageSignature,
     ) -> anyhow::Result<AppResponse> {
         self.app.execute_contract(
             owner,
             self.addr.clone(),
-            &ExecuteMsg::Register { name, address },
+            &ExecuteMsg::Register {
+                name,
+                address,
+                owner_signature,
+            },
             &[Coin {
                 denom: DENOM.to_string(),
                 amount: Uint128::new(100),
@@ -109,6 +196,17 @@ impl TestSetup {
         )
     }
 
+    pub fn try_register(
+        &mut self,
+        name: NymName,
+        address: Address,
+        owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        let keys = self.client_keys(&address);
+        let owner_signature = self.sign_register(&name, &address, &owner, keys);
+        self.try_register_with_signature(name, address, owner, owner_signature)
+    }
+
     pub fn register(&mut self, name: NymName, address: Address, owner: Addr) -> AppResponse {
         let resp = self.try_register(name, address, owner).unwrap();
         assert_eq!(
@@ -147,6 +245,81 @@ impl TestSetup {
             .unwrap()
     }
 
+    pub fn try_renew(&mut self, name: NymName, owner: Addr) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::RenewName { name },
+            &[],
+        )
+    }
+
+    pub fn try_update_address(
+        &mut self,
+        name: NymName,
+        address: Address,
+        owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        let keys = self.client_keys(&address);
+        let owner_signature = self.sign_update_address(&name, &address, &owner, keys);
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::UpdateAddress {
+                name,
+                address,
+                owner_signature,
+            },
+            &[],
+        )
+    }
+
+    pub fn try_transfer(
+        &mut self,
+        name: NymName,
+        owner: Addr,
+        new_owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::TransferName {
+                name,
+                new_owner: new_owner.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn try_accept_transfer(
+        &mut self,
+        name: NymName,
+        new_owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            new_owner,
+            self.addr.clone(),
+            &ExecuteMsg::AcceptNameTransfer { name },
+            &[Coin {
+                denom: DENOM.to_string(),
+                amount: Uint128::new(100),
+            }],
+        )
+    }
+
+    pub fn try_cancel_transfer(
+        &mut self,
+        name: NymName,
+        owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::CancelNameTransfer { name },
+            &[],
+        )
+    }
+
     pub fn balance(&self, address: impl Into<String>) -> Coin {
         self.app.wrap().query_balance(address, DENOM).unwrap()
     }
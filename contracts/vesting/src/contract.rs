@@ -123,6 +123,11 @@ pub fn execute(
             mix_id,
             on_behalf_of,
         } => try_undelegate_from_mixnode(mix_id, on_behalf_of, info, deps),
+        ExecuteMsg::Redelegate {
+            from_mix_id,
+            to_mix_id,
+            on_behalf_of,
+        } => try_redelegate(from_mix_id, to_mix_id, on_behalf_of, info, deps),
         ExecuteMsg::CreateAccount {
             owner_address,
             staking_address,
@@ -145,6 +150,27 @@ pub fn execute(
             mix_id,
             amount,
         } => try_track_undelegation(&owner, mix_id, amount, info, deps),
+        ExecuteMsg::TrackRedelegation {
+            owner,
+            from_mix_id,
+            to_mix_id,
+        } => try_track_redelegation(&owner, from_mix_id, to_mix_id, info, env, deps),
+        ExecuteMsg::UpdateOperatorCompounding { frequency } => {
+            try_update_operator_compounding(frequency, info, deps)
+        }
+        ExecuteMsg::UpdateDelegationCompounding {
+            mix_id,
+            frequency,
+            on_behalf_of,
+        } => try_update_delegation_compounding(mix_id, frequency, on_behalf_of, info, deps),
+        ExecuteMsg::TrackCompoundedOperatorReward { owner, amount } => {
+            try_track_compounded_operator_reward(&owner, amount, info, deps)
+        }
+        ExecuteMsg::TrackCompoundedDelegatorReward {
+            owner,
+            mix_id,
+            amount,
+        } => try_track_compounded_delegator_reward(&owner, mix_id, amount, info, env, deps),
         ExecuteMsg::BondMixnode {
             mix_node,
             cost_params,
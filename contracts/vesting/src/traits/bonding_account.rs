@@ -4,6 +4,7 @@ use cosmwasm_std::{Coin, Env, Response, Storage};
 use mixnet_contract_common::{
     gateway::GatewayConfigUpdate,
     mixnode::{MixNodeConfigUpdate, MixNodeCostParams},
+    rewarding::CompoundingFrequency,
     Gateway, MixNode,
 };
 
@@ -58,6 +59,18 @@ pub trait MixnodeBondingAccount {
         new_costs: MixNodeCostParams,
         storage: &mut dyn Storage,
     ) -> Result<Response, ContractError>;
+
+    fn try_update_operator_compounding(
+        &self,
+        frequency: Option<CompoundingFrequency>,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_track_compounded_operator_reward(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
 
 pub trait GatewayBondingAccount {
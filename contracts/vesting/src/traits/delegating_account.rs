@@ -1,5 +1,6 @@
 use crate::errors::ContractError;
 use cosmwasm_std::{Coin, Env, Response, Storage, Uint128};
+use mixnet_contract_common::rewarding::CompoundingFrequency;
 use mixnet_contract_common::MixId;
 
 pub trait DelegatingAccount {
@@ -23,6 +24,20 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    fn try_redelegate(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
+    fn try_update_delegation_compounding(
+        &self,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     // track_delegation performs internal vesting accounting necessary when
     // delegating from a vesting account. It accepts the current block height, the
     // delegation amount and balance of all coins whose denomination exists in
@@ -44,4 +59,24 @@ pub trait DelegatingAccount {
         amount: Coin,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+
+    // track_redelegation moves the internal vesting accounting of delegations from one mixnode
+    // to the other once the redelegation has been performed. Balance of the account is unaffected.
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
+
+    // track_compounded_delegator_reward increases the internal vesting accounting of the delegation
+    // by the reward that got compounded into it. Balance of the account is unaffected.
+    fn track_compounded_delegator_reward(
+        &self,
+        block_timestamp_secs: u64,
+        mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
@@ -13,14 +13,17 @@ use crate::vesting::{populate_vesting_periods, Account};
 use contracts_common::signing::MessageSignature;
 use cosmwasm_std::{coin, BankMsg, Coin, DepsMut, Env, MessageInfo, Response, Timestamp};
 use mixnet_contract_common::families::FamilyHead;
+use mixnet_contract_common::rewarding::CompoundingFrequency;
 use mixnet_contract_common::{
     Gateway, GatewayConfigUpdate, MixId, MixNode, MixNodeConfigUpdate, MixNodeCostParams,
 };
 use vesting_contract_common::events::{
     new_ownership_transfer_event, new_periodic_vesting_account_event,
-    new_staking_address_update_event, new_track_gateway_unbond_event,
+    new_staking_address_update_event, new_track_compounded_delegator_reward_event,
+    new_track_compounded_operator_reward_event, new_track_gateway_unbond_event,
     new_track_mixnode_pledge_decrease_event, new_track_mixnode_unbond_event,
-    new_track_reward_event, new_track_undelegation_event, new_vested_coins_withdraw_event,
+    new_track_redelegation_event, new_track_reward_event, new_track_undelegation_event,
+    new_vested_coins_withdraw_event,
 };
 use vesting_contract_common::messages::VestingSpecification;
 use vesting_contract_common::PledgeCap;
@@ -360,6 +363,67 @@ pub fn try_track_undelegation(
     Ok(Response::new().add_event(new_track_undelegation_event()))
 }
 
+pub fn try_track_redelegation(
+    address: &str,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+
+    account.track_redelegation(
+        env.block.time.seconds(),
+        from_mix_id,
+        to_mix_id,
+        deps.storage,
+    )?;
+    Ok(Response::new().add_event(new_track_redelegation_event()))
+}
+
+/// Track operator reward compounding, invoked by the mixnet contract after the reward got automatically compounded into the pledge.
+pub fn try_track_compounded_operator_reward(
+    owner: &str,
+    amount: Coin,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(owner, deps.storage, deps.api)?;
+
+    account.try_track_compounded_operator_reward(amount, deps.storage)?;
+    Ok(Response::new().add_event(new_track_compounded_operator_reward_event()))
+}
+
+/// Track delegator reward compounding, invoked by the mixnet contract after the reward got automatically compounded into the delegation.
+pub fn try_track_compounded_delegator_reward(
+    owner: &str,
+    mix_id: MixId,
+    amount: Coin,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(owner, deps.storage, deps.api)?;
+
+    account.track_compounded_delegator_reward(
+        env.block.time.seconds(),
+        mix_id,
+        amount,
+        deps.storage,
+    )?;
+    Ok(Response::new().add_event(new_track_compounded_delegator_reward_event()))
+}
+
 /// Delegate to mixnode, sends [mixnet_contract_common::ExecuteMsg::DelegateToMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS]..
 pub fn try_delegate_to_mixnode(
     mix_id: MixId,
@@ -433,6 +497,58 @@ pub fn try_undelegate_from_mixnode(
     account.try_undelegate_from_mixnode(mix_id, deps.storage)
 }
 
+/// Moves delegation between mixnodes, sends [mixnet_contract_common::ExecuteMsg::RedelegateOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_redelegate(
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            ensure_staking_permission(&info.sender, &account)?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_redelegate(from_mix_id, to_mix_id, deps.storage)
+}
+
+/// Updates compounding of the operator rewards, sends [mixnet_contract_common::ExecuteMsg::UpdateOperatorCompoundingOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_update_operator_compounding(
+    frequency: Option<CompoundingFrequency>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_update_operator_compounding(frequency, deps.storage)
+}
+
+/// Updates compounding of the delegator rewards, sends [mixnet_contract_common::ExecuteMsg::UpdateDelegationCompoundingOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_update_delegation_compounding(
+    mix_id: MixId,
+    frequency: Option<CompoundingFrequency>,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            ensure_staking_permission(&info.sender, &account)?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_update_delegation_compounding(mix_id, frequency, deps.storage)
+}
+
 /// Creates a new periodic vesting account, and deposits funds to vest into the contract.
 ///
 /// Callable by ADMIN only, see [instantiate].
@@ -4,10 +4,12 @@ use crate::storage::save_delegation;
 use crate::storage::MIXNET_CONTRACT_ADDRESS;
 use crate::traits::DelegatingAccount;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
+use mixnet_contract_common::rewarding::CompoundingFrequency;
 use mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
 use mixnet_contract_common::MixId;
 use vesting_contract_common::events::{
-    new_vesting_delegation_event, new_vesting_undelegation_event,
+    new_vesting_delegation_event, new_vesting_redelegation_event, new_vesting_undelegation_event,
+    new_vesting_update_delegation_compounding_event,
 };
 
 use super::Account;
@@ -95,6 +97,69 @@ impl DelegatingAccount for Account {
             .add_event(new_vesting_undelegation_event()))
     }
 
+    fn try_redelegate(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(from_mix_id, storage) {
+            return Err(ContractError::NoSuchDelegation(
+                self.owner_address(),
+                from_mix_id,
+            ));
+        }
+
+        // the redelegated stake is going to be tracked as a single new delegation towards the target
+        let num_subdelegations = self.num_subdelegations_for_mix(to_mix_id, storage);
+        if num_subdelegations >= MAX_PER_MIX_DELEGATIONS {
+            return Err(ContractError::TooManyDelegations {
+                address: self.owner_address.clone(),
+                acc_id: self.storage_key(),
+                mix_id: to_mix_id,
+                num: num_subdelegations,
+                cap: MAX_PER_MIX_DELEGATIONS,
+            });
+        }
+
+        let msg = MixnetExecuteMsg::RedelegateOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            delegate: self.owner_address().into_string(),
+        };
+        let redelegate = wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(redelegate)
+            .add_event(new_vesting_redelegation_event()))
+    }
+
+    fn try_update_delegation_compounding(
+        &self,
+        mix_id: MixId,
+        frequency: Option<CompoundingFrequency>,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(mix_id, storage) {
+            return Err(ContractError::NoSuchDelegation(
+                self.owner_address(),
+                mix_id,
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UpdateDelegationCompoundingOnBehalf {
+            mix_id,
+            frequency,
+            owner: self.owner_address().into_string(),
+        };
+        let update_compounding =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(update_compounding)
+            .add_event(new_vesting_update_delegation_compounding_event()))
+    }
+
     fn track_delegation(
         &self,
         block_timestamp_secs: u64,
@@ -124,4 +189,42 @@ impl DelegatingAccount for Account {
         self.save_balance(new_balance, storage)?;
         Ok(())
     }
+
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let delegated = self.total_delegations_for_mix(from_mix_id, storage)?;
+        self.remove_delegations_for_mix(from_mix_id, storage)?;
+        if !delegated.is_zero() {
+            save_delegation(
+                (self.storage_key(), to_mix_id, block_timestamp_secs),
+                delegated,
+                storage,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn track_compounded_delegator_reward(
+        &self,
+        block_timestamp_secs: u64,
+        mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        // merge all existing entries into a single one so that the compounding
+        // would never push us over the per-mix delegation limit
+        let delegated = self.total_delegations_for_mix(mix_id, storage)?;
+        self.remove_delegations_for_mix(mix_id, storage)?;
+        save_delegation(
+            (self.storage_key(), mix_id, block_timestamp_secs),
+            delegated + amount.amount,
+            storage,
+        )?;
+        Ok(())
+    }
 }
@@ -9,11 +9,13 @@ use contracts_common::signing::MessageSignature;
 use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
 use mixnet_contract_common::mixnode::MixNodeConfigUpdate;
 use mixnet_contract_common::mixnode::MixNodeCostParams;
+use mixnet_contract_common::rewarding::CompoundingFrequency;
 use mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, MixNode};
 use vesting_contract_common::events::{
     new_vesting_decrease_pledge_event, new_vesting_mixnode_bonding_event,
     new_vesting_mixnode_unbonding_event, new_vesting_pledge_more_event,
     new_vesting_update_mixnode_config_event, new_vesting_update_mixnode_cost_params_event,
+    new_vesting_update_operator_compounding_event,
 };
 use vesting_contract_common::PledgeData;
 
@@ -218,4 +220,40 @@ impl MixnodeBondingAccount for Account {
             .add_message(update_mixnode_costs_msg)
             .add_event(new_vesting_update_mixnode_cost_params_event()))
     }
+
+    fn try_update_operator_compounding(
+        &self,
+        frequency: Option<CompoundingFrequency>,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if self.load_mixnode_pledge(storage)?.is_none() {
+            return Err(ContractError::NoBondFound(
+                self.owner_address().as_str().to_string(),
+            ));
+        }
+
+        let msg = MixnetExecuteMsg::UpdateOperatorCompoundingOnBehalf {
+            frequency,
+            owner: self.owner_address().into_string(),
+        };
+
+        let update_compounding_msg =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(update_compounding_msg)
+            .add_event(new_vesting_update_operator_compounding_event()))
+    }
+
+    fn try_track_compounded_operator_reward(
+        &self,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        let mut pledge_data = self
+            .load_mixnode_pledge(storage)?
+            .ok_or_else(|| ContractError::NoBondFound(self.owner_address().as_str().to_string()))?;
+        pledge_data.amount.amount += amount.amount;
+        self.save_mixnode_pledge(pledge_data, storage)
+    }
 }
@@ -51,6 +51,7 @@ mod tests {
     use cosmwasm_std::testing::{mock_env, mock_info};
     use cosmwasm_std::{coin, coins, Addr, Coin, Timestamp, Uint128};
     use mixnet_contract_common::mixnode::MixNodeCostParams;
+    use mixnet_contract_common::rewarding::CompoundingFrequency;
     use mixnet_contract_common::{Gateway, MixNode, Percent};
     use vesting_contract_common::messages::{ExecuteMsg, VestingSpecification};
     use vesting_contract_common::Period;
@@ -647,6 +648,142 @@ mod tests {
         assert_eq!(Uint128::new(90_000_000_000), total_delegations);
     }
 
+    #[test]
+    fn test_redelegations() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+
+        let msg = ExecuteMsg::CreateAccount {
+            owner_address: "owner".to_string(),
+            staking_address: Some("staking".to_string()),
+            vesting_spec: None,
+            cap: Some(PledgeCap::Absolute(Uint128::from(100_000_000_000u128))),
+        };
+        let info = mock_info("admin", &coins(1_000_000_000_000, TEST_COIN_DENOM));
+
+        let _response = execute(deps.as_mut(), env.clone(), info, msg);
+        let account = load_account(Addr::unchecked("owner"), &deps.storage)
+            .unwrap()
+            .unwrap();
+
+        for amount in [10_000_000_000u128, 20_000_000_000] {
+            account
+                .try_delegate_to_mixnode(1, coin(amount, TEST_COIN_DENOM), &env, &mut deps.storage)
+                .unwrap();
+            env.block.time = env.block.time.plus_seconds(42);
+        }
+        let balance = account.load_balance(&deps.storage).unwrap();
+
+        // there's nothing to redelegate from mixnode 3
+        let err = account.try_redelegate(3, 2, &deps.storage).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoSuchDelegation(account.owner_address(), 3)
+        );
+
+        assert!(account.try_redelegate(1, 2, &deps.storage).is_ok());
+
+        // nothing is tracked until the mixnet contract actually moves the delegation
+        assert_eq!(account.num_subdelegations_for_mix(1, &deps.storage), 2);
+        assert_eq!(account.num_subdelegations_for_mix(2, &deps.storage), 0);
+
+        account
+            .track_redelegation(env.block.time.seconds(), 1, 2, &mut deps.storage)
+            .unwrap();
+
+        assert!(!account.any_delegation_for_mix(1, &deps.storage));
+        assert_eq!(account.num_subdelegations_for_mix(2, &deps.storage), 1);
+        assert_eq!(
+            account.total_delegations_for_mix(2, &deps.storage).unwrap(),
+            Uint128::new(30_000_000_000)
+        );
+
+        // the tokens have never left the mixnet contract
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+    }
+
+    #[test]
+    fn test_tracking_compounded_rewards() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+
+        let account = vesting_account_new_fixture(&mut deps.storage, &env);
+
+        for amount in [10_000_000_000u128, 20_000_000_000] {
+            account
+                .try_delegate_to_mixnode(1, coin(amount, TEST_COIN_DENOM), &env, &mut deps.storage)
+                .unwrap();
+            env.block.time = env.block.time.plus_seconds(42);
+        }
+
+        let mix_node = MixNode {
+            host: "mix.node.org".to_string(),
+            mix_port: 1789,
+            verloc_port: 1790,
+            http_api_port: 8000,
+            sphinx_key: "sphinx".to_string(),
+            identity_key: "identity".to_string(),
+            version: "0.10.0".to_string(),
+        };
+        let cost_params = MixNodeCostParams {
+            profit_margin_percent: Percent::from_percentage_value(10).unwrap(),
+            interval_operating_cost: coin(40, TEST_COIN_DENOM),
+        };
+        account
+            .try_bond_mixnode(
+                mix_node,
+                cost_params,
+                MessageSignature::from(vec![1, 2, 3]),
+                coin(60_000_000_000, TEST_COIN_DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        let balance = account.load_balance(&deps.storage).unwrap();
+
+        // compounding can only be updated for existing delegations
+        let err = account
+            .try_update_delegation_compounding(3, None, &deps.storage)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoSuchDelegation(account.owner_address(), 3)
+        );
+        assert!(account
+            .try_update_delegation_compounding(1, Some(CompoundingFrequency::Epoch), &deps.storage)
+            .is_ok());
+        assert!(account
+            .try_update_operator_compounding(Some(CompoundingFrequency::Interval), &deps.storage)
+            .is_ok());
+
+        account
+            .track_compounded_delegator_reward(
+                env.block.time.seconds(),
+                1,
+                coin(1_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+        account
+            .try_track_compounded_operator_reward(
+                coin(2_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        // all the delegations got merged into a single one
+        assert_eq!(account.num_subdelegations_for_mix(1, &deps.storage), 1);
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(30_001_000_000)
+        );
+        let pledge = account.load_mixnode_pledge(&deps.storage).unwrap().unwrap();
+        assert_eq!(Uint128::new(60_002_000_000), pledge.amount().amount);
+
+        // the rewards have never left the mixnet contract
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+    }
+
     #[test]
     fn test_mixnode_bonds() {
         let mut deps = init_contract();
@@ -20,7 +20,7 @@ impl ThreadsafeValidatorClient {
         new_validator_client()
     }
 
-    pub(crate) fn api_endpoint(&self) -> &Url {
+    pub(crate) fn api_endpoint(&self) -> Url {
         self.0.nym_api.current_url()
     }
 }
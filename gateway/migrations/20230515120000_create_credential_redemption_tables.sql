@@ -0,0 +1,22 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+-- blinded serial numbers of every credential ever accepted by this gateway,
+-- used for rejecting double spending attempts, including across restarts
+CREATE TABLE spent_credential
+(
+    blinded_serial_number_bs58 TEXT NOT NULL PRIMARY KEY UNIQUE
+);
+
+-- credentials that were accepted but whose funds have not yet been released on chain
+CREATE TABLE pending_credential_redemption
+(
+    id                         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    blinded_serial_number_bs58 TEXT    NOT NULL UNIQUE REFERENCES spent_credential (blinded_serial_number_bs58),
+    credential                 BLOB    NOT NULL,
+    proposal_id                INTEGER,
+    redemption_attempts        INTEGER NOT NULL DEFAULT 0,
+    last_error                 TEXT
+);
@@ -36,6 +36,10 @@ const DEFAULT_MAXIMUM_CONNECTION_BUFFER_SIZE: usize = 2000;
 const DEFAULT_STORED_MESSAGE_FILENAME_LENGTH: u16 = 16;
 const DEFAULT_MESSAGE_RETRIEVAL_LIMIT: i64 = 100;
 
+const DEFAULT_CREDENTIAL_REDEMPTION_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_CREDENTIAL_REDEMPTION_BATCH_SIZE: u32 = 20;
+const DEFAULT_MAXIMUM_CREDENTIAL_REDEMPTION_ATTEMPTS: u32 = 10;
+
 /// Derive default path to gateway's config directory.
 /// It should get resolved to `$HOME/.nym/gateways/<id>/config`
 pub fn default_config_directory<P: AsRef<Path>>(id: P) -> PathBuf {
@@ -280,6 +284,16 @@ pub struct Debug {
     /// Number of messages from offline client that can be pulled at once from the storage.
     pub message_retrieval_limit: i64,
 
+    /// Delay between subsequent attempts at redeeming the received bandwidth credentials.
+    #[serde(with = "humantime_serde")]
+    pub credential_redemption_interval: Duration,
+
+    /// Maximum number of bandwidth credentials redeemed in a single batch.
+    pub credential_redemption_batch_size: u32,
+
+    /// Maximum number of attempts at redeeming particular bandwidth credential before giving up on it.
+    pub maximum_credential_redemption_attempts: u32,
+
     /// Specifies whether the mixnode should be using the legacy framing for the sphinx packets.
     // it's set to true by default. The reason for that decision is to preserve compatibility with the
     // existing nodes whilst everyone else is upgrading and getting the code for handling the new field.
@@ -297,6 +311,9 @@ impl Default for Debug {
             maximum_connection_buffer_size: DEFAULT_MAXIMUM_CONNECTION_BUFFER_SIZE,
             stored_messages_filename_length: DEFAULT_STORED_MESSAGE_FILENAME_LENGTH,
             message_retrieval_limit: DEFAULT_MESSAGE_RETRIEVAL_LIMIT,
+            credential_redemption_interval: DEFAULT_CREDENTIAL_REDEMPTION_INTERVAL,
+            credential_redemption_batch_size: DEFAULT_CREDENTIAL_REDEMPTION_BATCH_SIZE,
+            maximum_credential_redemption_attempts: DEFAULT_MAXIMUM_CREDENTIAL_REDEMPTION_ATTEMPTS,
             // TODO: remember to change it in one of future releases!!
             use_legacy_framed_packet_version: true,
         }
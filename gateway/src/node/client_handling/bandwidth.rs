@@ -13,8 +13,8 @@ impl Bandwidth {
     }
 }
 
-impl From<Credential> for Bandwidth {
-    fn from(credential: Credential) -> Self {
+impl From<&Credential> for Bandwidth {
+    fn from(credential: &Credential) -> Self {
         let token_value = credential.voucher_value();
         let bandwidth_bytes = token_value * nym_network_defaults::BYTES_PER_UTOKEN;
         Bandwidth {
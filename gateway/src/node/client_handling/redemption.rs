@@ -0,0 +1,748 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
+use crate::node::client_handling::websocket::connection_handler::RequestHandlingError;
+use crate::node::storage::models::PendingRedemption;
+use crate::node::storage::Storage;
+use async_trait::async_trait;
+use log::*;
+use nym_coconut_interface::error::CoconutInterfaceError;
+use nym_coconut_interface::Credential;
+use nym_task::TaskClient;
+use nym_validator_client::CoconutApiClient;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// State of the credential as seen by the coconut bandwidth contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OnChainSpendStatus {
+    Unspent,
+
+    /// The credential has been spent by this very gateway, so the associated proposal must exist.
+    SpentByUs,
+
+    SpentByOther,
+}
+
+/// Chain (and signer) operations required for redeeming the credentials. It's implemented
+/// by the `CoconutVerifier`, but it's kept behind a trait so that the redemption logic
+/// could be exercised without a running chain.
+#[async_trait]
+pub(crate) trait RedemptionClient: Send + Sync {
+    async fn all_current_coconut_api_clients(
+        &self,
+    ) -> Result<Vec<CoconutApiClient>, RequestHandlingError>;
+
+    /// Verifies all of the provided credentials as a single batch. Returns indices of the invalid ones.
+    async fn find_invalid_credentials(
+        &self,
+        credentials: &[&Credential],
+    ) -> Result<Vec<usize>, RequestHandlingError>;
+
+    /// Checks whether the credential has already been spent in the coconut bandwidth contract
+    /// and if so, by whom.
+    async fn spend_status(
+        &self,
+        credential: &Credential,
+    ) -> Result<OnChainSpendStatus, RequestHandlingError>;
+
+    /// Spends the credential in the coconut bandwidth contract, which in turn creates
+    /// a multisig proposal for releasing the deposited funds. Returns id of the created proposal.
+    async fn create_spend_proposal(
+        &self,
+        credential: &Credential,
+    ) -> Result<u64, RequestHandlingError>;
+
+    /// Looks up the id of the spending proposal that has previously been created for the credential.
+    async fn find_spend_proposal(
+        &self,
+        credential: &Credential,
+    ) -> Result<Option<u64>, RequestHandlingError>;
+
+    /// Checks whether the particular proposal has already been executed,
+    /// which might happen if the gateway was interrupted before it finished processing the redemption.
+    async fn is_proposal_executed(&self, proposal_id: u64) -> Result<bool, RequestHandlingError>;
+
+    /// Grants the signers a temporary fee allowance so that they could vote on the spending proposals.
+    async fn grant_voting_allowances(
+        &self,
+        api_clients: &[CoconutApiClient],
+    ) -> Result<(), RequestHandlingError>;
+
+    /// Revokes the fee allowances previously granted with [Self::grant_voting_allowances].
+    async fn revoke_voting_allowances(
+        &self,
+        api_clients: &[CoconutApiClient],
+    ) -> Result<(), RequestHandlingError>;
+
+    /// Asks each of the signers to verify all of the provided credentials
+    /// and vote on the associated spending proposals.
+    async fn request_batch_verification(
+        &self,
+        api_clients: &[CoconutApiClient],
+        credentials: &[(&Credential, u64)],
+    ) -> Result<(), RequestHandlingError>;
+
+    async fn execute_proposal(&self, proposal_id: u64) -> Result<(), RequestHandlingError>;
+}
+
+/// Background task responsible for releasing, on chain, the funds associated with the credentials
+/// accepted by the gateway. The credentials are verified locally upon being received and the
+/// client is immediately granted the bandwidth, so the (slow) redemption does not affect
+/// the client handshake in any way.
+pub(crate) struct CredentialRedeemer<St, C = CoconutVerifier> {
+    coconut_verifier: Arc<C>,
+    storage: St,
+
+    /// Delay between subsequent attempts at redeeming the queued credentials.
+    redemption_interval: Duration,
+
+    /// Maximum number of credentials redeemed in a single batch.
+    batch_size: u32,
+
+    /// Maximum number of attempts at redeeming particular credential before giving up on it.
+    maximum_attempts: u32,
+}
+
+impl<St, C> CredentialRedeemer<St, C>
+where
+    St: Storage,
+    C: RedemptionClient,
+{
+    pub(crate) fn new(
+        coconut_verifier: Arc<C>,
+        storage: St,
+        redemption_interval: Duration,
+        batch_size: u32,
+        maximum_attempts: u32,
+    ) -> Self {
+        CredentialRedeemer {
+            coconut_verifier,
+            storage,
+            redemption_interval,
+            batch_size,
+            maximum_attempts,
+        }
+    }
+
+    // Makes sure the spending proposal for the credential exists.
+    // Returns `None` if the proposal has already been executed and thus no further action is required.
+    async fn prepare_redemption(
+        &self,
+        pending: &PendingRedemption,
+        credential: &Credential,
+    ) -> Result<Option<u64>, RequestHandlingError> {
+        // if we have already created the proposal during some previous attempt, don't try to do it again
+        let proposal_id = match pending.proposal_id {
+            Some(proposal_id) => proposal_id as u64,
+            None => {
+                let proposal_id = match self.coconut_verifier.spend_status(credential).await? {
+                    OnChainSpendStatus::Unspent => {
+                        self.coconut_verifier
+                            .create_spend_proposal(credential)
+                            .await?
+                    }
+                    OnChainSpendStatus::SpentByUs => {
+                        // we must have crashed after submitting the transaction,
+                        // but before persisting the proposal id
+                        info!(
+                            "credential {} has already been spent by us - recovering its proposal id",
+                            pending.blinded_serial_number_bs58
+                        );
+                        self.coconut_verifier
+                            .find_spend_proposal(credential)
+                            .await?
+                            .ok_or(RequestHandlingError::ProposalIdError {
+                                reason: String::from(
+                                    "could not find the proposal of a credential spent by this gateway",
+                                ),
+                            })?
+                    }
+                    OnChainSpendStatus::SpentByOther => {
+                        return Err(RequestHandlingError::BandwidthCredentialAlreadySpent)
+                    }
+                };
+                self.storage
+                    .set_redemption_proposal_id(pending.id, proposal_id)
+                    .await?;
+                proposal_id
+            }
+        };
+
+        if self
+            .coconut_verifier
+            .is_proposal_executed(proposal_id)
+            .await?
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(proposal_id))
+    }
+
+    async fn handle_redemption_result(
+        &self,
+        redemption: &PendingRedemption,
+        result: Result<(), RequestHandlingError>,
+    ) -> Result<(), RequestHandlingError> {
+        match result {
+            Ok(_) => {
+                debug!(
+                    "redeemed credential {}",
+                    redemption.blinded_serial_number_bs58
+                );
+                self.storage
+                    .remove_pending_redemption(redemption.id)
+                    .await?;
+            }
+            Err(RequestHandlingError::BandwidthCredentialAlreadySpent) => {
+                // there's no point in retrying it
+                warn!(
+                    "credential {} has already been spent on chain - it will not be redeemed",
+                    redemption.blinded_serial_number_bs58
+                );
+                self.storage
+                    .remove_pending_redemption(redemption.id)
+                    .await?;
+            }
+            Err(RequestHandlingError::InvalidBandwidthCredential(_)) => {
+                // nor is there any point in retrying an invalid credential
+                error!(
+                    "credential {} did not verify correctly - it will not be redeemed",
+                    redemption.blinded_serial_number_bs58
+                );
+                self.storage
+                    .remove_pending_redemption(redemption.id)
+                    .await?;
+            }
+            Err(err) => {
+                if redemption.redemption_attempts + 1 >= self.maximum_attempts as i64 {
+                    error!(
+                        "failed to redeem credential {} - {err}. This was the last attempt, it will not be retried",
+                        redemption.blinded_serial_number_bs58
+                    );
+                } else {
+                    warn!(
+                        "failed to redeem credential {} - {err}. It will be retried later",
+                        redemption.blinded_serial_number_bs58
+                    );
+                }
+                self.storage
+                    .record_failed_redemption(redemption.id, &err.to_string())
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn redeem_batch(
+        &self,
+        api_clients: &[CoconutApiClient],
+        pending: Vec<PendingRedemption>,
+    ) -> Result<(), RequestHandlingError> {
+        let mut decoded = Vec::with_capacity(pending.len());
+        for redemption in pending {
+            match Credential::from_bytes(&redemption.credential) {
+                Ok(credential) => decoded.push((redemption, credential)),
+                Err(err) => {
+                    let err = RequestHandlingError::from(CoconutInterfaceError::from(err));
+                    self.handle_redemption_result(&redemption, Err(err)).await?
+                }
+            }
+        }
+
+        // only the credentials that haven't been submitted on chain yet have to be re-verified
+        let unsubmitted = decoded
+            .iter()
+            .filter(|(redemption, _)| redemption.proposal_id.is_none())
+            .map(|(_, credential)| credential)
+            .collect::<Vec<_>>();
+        // re-verify, as a single batch, all credentials for which we're about to create spending proposals,
+        // so that we wouldn't pay the transaction fees for proposals the signers are going to reject anyway
+        let invalid = self
+            .coconut_verifier
+            .find_invalid_credentials(&unsubmitted)
+            .await?
+            .into_iter()
+            .map(|index| unsubmitted[index].blinded_serial_number())
+            .collect::<Vec<_>>();
+
+        let mut awaiting_votes = Vec::with_capacity(decoded.len());
+        for (redemption, credential) in decoded {
+            let result = if invalid.contains(&redemption.blinded_serial_number_bs58) {
+                Err(RequestHandlingError::InvalidBandwidthCredential(
+                    String::from("redemption"),
+                ))
+            } else {
+                match self.prepare_redemption(&redemption, &credential).await {
+                    Ok(Some(proposal_id)) => {
+                        awaiting_votes.push((redemption, credential, proposal_id));
+                        continue;
+                    }
+                    Ok(None) => Ok(()),
+                    Err(err) => Err(err),
+                }
+            };
+            self.handle_redemption_result(&redemption, result).await?;
+        }
+
+        if awaiting_votes.is_empty() {
+            return Ok(());
+        }
+
+        // each signer gets asked to verify all of the credentials at once
+        let to_verify = awaiting_votes
+            .iter()
+            .map(|(_, credential, proposal_id)| (credential, *proposal_id))
+            .collect::<Vec<_>>();
+        if let Err(err) = self
+            .coconut_verifier
+            .request_batch_verification(api_clients, &to_verify)
+            .await
+        {
+            for (redemption, _, _) in &awaiting_votes {
+                warn!(
+                    "failed to request verification of credential {} - {err}. It will be retried later",
+                    redemption.blinded_serial_number_bs58
+                );
+                self.storage
+                    .record_failed_redemption(redemption.id, &err.to_string())
+                    .await?;
+            }
+            return Ok(());
+        }
+
+        for (redemption, _, proposal_id) in awaiting_votes {
+            let result = self.coconut_verifier.execute_proposal(proposal_id).await;
+            self.handle_redemption_result(&redemption, result).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn redeem_pending_batch(&self) -> Result<(), RequestHandlingError> {
+        let pending = self
+            .storage
+            .get_pending_redemptions(self.maximum_attempts, self.batch_size)
+            .await?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let api_clients = self
+            .coconut_verifier
+            .all_current_coconut_api_clients()
+            .await?;
+        if api_clients.is_empty() {
+            return Err(RequestHandlingError::NotEnoughNymAPIs {
+                received: 0,
+                needed: 1,
+            });
+        }
+
+        info!("attempting to redeem {} pending credentials", pending.len());
+
+        // the signers only need the allowance once for the whole batch
+        self.coconut_verifier
+            .grant_voting_allowances(&api_clients)
+            .await?;
+
+        let result = self.redeem_batch(&api_clients, pending).await;
+
+        if let Err(err) = self
+            .coconut_verifier
+            .revoke_voting_allowances(&api_clients)
+            .await
+        {
+            warn!("failed to revoke the voting fee allowances - {err}")
+        }
+
+        result
+    }
+
+    pub(crate) async fn run(&self, mut shutdown: TaskClient) {
+        let mut interval = tokio::time::interval(self.redemption_interval);
+
+        while !shutdown.is_shutdown() {
+            tokio::select! {
+                biased;
+                _ = shutdown.recv() => {
+                    trace!("CredentialRedeemer: received shutdown");
+                }
+                _ = interval.tick() => {
+                    if let Err(err) = self.redeem_pending_batch().await {
+                        warn!("failed to redeem pending credentials - {err}")
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::storage::PersistentStorage;
+    use nym_coconut_interface::tests::helpers::theta_from_keys_and_attributes;
+    use nym_coconut_interface::{hash_to_scalar, ttp_keygen, Parameters};
+    use std::collections::{HashMap, HashSet};
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    const MAXIMUM_ATTEMPTS: u32 = 3;
+
+    struct MockProposal {
+        blinded_serial_number: String,
+        executed: bool,
+    }
+
+    #[derive(Default)]
+    struct MockChainState {
+        invalid: HashSet<String>,
+        spent_by_others: HashSet<String>,
+        proposals: HashMap<u64, MockProposal>,
+
+        // simulates the gateway crashing right after submitting the spending transaction
+        crash_after_spending: bool,
+        fail_verification_requests: bool,
+        fail_executions: bool,
+
+        created_proposals: usize,
+        executed_proposals: usize,
+    }
+
+    #[derive(Default)]
+    struct MockChain {
+        state: Mutex<MockChainState>,
+    }
+
+    impl MockChain {
+        fn state(&self) -> std::sync::MutexGuard<'_, MockChainState> {
+            self.state.lock().unwrap()
+        }
+
+        fn proposal_of(&self, credential: &Credential) -> Option<u64> {
+            let serial_number = credential.blinded_serial_number();
+            self.state()
+                .proposals
+                .iter()
+                .find(|(_, proposal)| proposal.blinded_serial_number == serial_number)
+                .map(|(id, _)| *id)
+        }
+    }
+
+    fn chain_error() -> RequestHandlingError {
+        RequestHandlingError::NotEnoughNymAPIs {
+            received: 0,
+            needed: 1,
+        }
+    }
+
+    #[async_trait]
+    impl RedemptionClient for MockChain {
+        async fn all_current_coconut_api_clients(
+            &self,
+        ) -> Result<Vec<CoconutApiClient>, RequestHandlingError> {
+            Ok(Vec::new())
+        }
+
+        async fn find_invalid_credentials(
+            &self,
+            credentials: &[&Credential],
+        ) -> Result<Vec<usize>, RequestHandlingError> {
+            let state = self.state();
+            Ok(credentials
+                .iter()
+                .enumerate()
+                .filter(|(_, credential)| {
+                    state.invalid.contains(&credential.blinded_serial_number())
+                })
+                .map(|(index, _)| index)
+                .collect())
+        }
+
+        async fn spend_status(
+            &self,
+            credential: &Credential,
+        ) -> Result<OnChainSpendStatus, RequestHandlingError> {
+            if self
+                .state()
+                .spent_by_others
+                .contains(&credential.blinded_serial_number())
+            {
+                return Ok(OnChainSpendStatus::SpentByOther);
+            }
+            if self.proposal_of(credential).is_some() {
+                return Ok(OnChainSpendStatus::SpentByUs);
+            }
+            Ok(OnChainSpendStatus::Unspent)
+        }
+
+        async fn create_spend_proposal(
+            &self,
+            credential: &Credential,
+        ) -> Result<u64, RequestHandlingError> {
+            let mut state = self.state();
+            state.created_proposals += 1;
+            let proposal_id = state.created_proposals as u64;
+            state.proposals.insert(
+                proposal_id,
+                MockProposal {
+                    blinded_serial_number: credential.blinded_serial_number(),
+                    executed: false,
+                },
+            );
+
+            if state.crash_after_spending {
+                return Err(chain_error());
+            }
+            Ok(proposal_id)
+        }
+
+        async fn find_spend_proposal(
+            &self,
+            credential: &Credential,
+        ) -> Result<Option<u64>, RequestHandlingError> {
+            Ok(self.proposal_of(credential))
+        }
+
+        async fn is_proposal_executed(
+            &self,
+            proposal_id: u64,
+        ) -> Result<bool, RequestHandlingError> {
+            Ok(self.state().proposals[&proposal_id].executed)
+        }
+
+        async fn grant_voting_allowances(
+            &self,
+            _api_clients: &[CoconutApiClient],
+        ) -> Result<(), RequestHandlingError> {
+            Ok(())
+        }
+
+        async fn revoke_voting_allowances(
+            &self,
+            _api_clients: &[CoconutApiClient],
+        ) -> Result<(), RequestHandlingError> {
+            Ok(())
+        }
+
+        async fn request_batch_verification(
+            &self,
+            _api_clients: &[CoconutApiClient],
+            _credentials: &[(&Credential, u64)],
+        ) -> Result<(), RequestHandlingError> {
+            if self.state().fail_verification_requests {
+                return Err(chain_error());
+            }
+            Ok(())
+        }
+
+        async fn execute_proposal(&self, proposal_id: u64) -> Result<(), RequestHandlingError> {
+            let mut state = self.state();
+            if state.fail_executions {
+                return Err(chain_error());
+            }
+            state.executed_proposals += 1;
+            state.proposals.get_mut(&proposal_id).unwrap().executed = true;
+            Ok(())
+        }
+    }
+
+    fn test_credentials(n: usize) -> Vec<Credential> {
+        let params = Parameters::new(4).unwrap();
+        let key_pairs = ttp_keygen(&params, 1, 1).unwrap();
+        let voucher_value = 1234u64;
+        let voucher_info = "voucher info";
+        let public_attributes = vec![
+            hash_to_scalar(voucher_value.to_string()),
+            hash_to_scalar(voucher_info),
+        ];
+
+        (0..n)
+            .map(|_| {
+                let theta =
+                    theta_from_keys_and_attributes(&params, &key_pairs, &[1], &public_attributes)
+                        .unwrap();
+                Credential::new(4, theta, voucher_value, voucher_info.to_string(), 0)
+            })
+            .collect()
+    }
+
+    struct TestSetup {
+        redeemer: CredentialRedeemer<PersistentStorage, MockChain>,
+        chain: Arc<MockChain>,
+        storage: PersistentStorage,
+        credentials: Vec<Credential>,
+        _dir: TempDir,
+    }
+
+    impl TestSetup {
+        async fn new(n: usize) -> Self {
+            let dir = tempfile::tempdir().unwrap();
+            let storage = PersistentStorage::init(dir.path().join("db.sqlite"), 100)
+                .await
+                .unwrap();
+            let credentials = test_credentials(n);
+            for credential in &credentials {
+                storage.insert_pending_redemption(credential).await.unwrap();
+            }
+
+            let chain = Arc::new(MockChain::default());
+            let redeemer = CredentialRedeemer::new(
+                Arc::clone(&chain),
+                storage.clone(),
+                Duration::from_secs(1),
+                100,
+                MAXIMUM_ATTEMPTS,
+            );
+
+            TestSetup {
+                redeemer,
+                chain,
+                storage,
+                credentials,
+                _dir: dir,
+            }
+        }
+
+        async fn redemption_round(&self) {
+            let pending = self.pending().await;
+            self.redeemer.redeem_batch(&[], pending).await.unwrap()
+        }
+
+        async fn pending(&self) -> Vec<PendingRedemption> {
+            self.storage
+                .get_pending_redemptions(MAXIMUM_ATTEMPTS, 100)
+                .await
+                .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn redeemed_credentials_are_removed_from_the_queue() {
+        let setup = TestSetup::new(3).await;
+        setup.redemption_round().await;
+
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 3);
+        assert_eq!(setup.chain.state().executed_proposals, 3);
+
+        // the credentials remain spent
+        for credential in &setup.credentials {
+            assert!(setup
+                .storage
+                .is_credential_spent(&credential.blinded_serial_number())
+                .await
+                .unwrap());
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_redemptions_are_retried_up_to_maximum_attempts() {
+        let setup = TestSetup::new(2).await;
+        setup.chain.state().fail_executions = true;
+
+        for attempt in 1..MAXIMUM_ATTEMPTS {
+            setup.redemption_round().await;
+            let pending = setup.pending().await;
+            assert_eq!(pending.len(), 2);
+            for redemption in pending {
+                assert_eq!(redemption.redemption_attempts, attempt as i64);
+                // the proposal is persisted and reused for subsequent attempts
+                assert!(redemption.proposal_id.is_some());
+            }
+        }
+
+        setup.redemption_round().await;
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 2);
+        assert_eq!(setup.chain.state().executed_proposals, 0);
+    }
+
+    #[tokio::test]
+    async fn failed_verification_requests_are_retried() {
+        let setup = TestSetup::new(2).await;
+        setup.chain.state().fail_verification_requests = true;
+
+        setup.redemption_round().await;
+        let pending = setup.pending().await;
+        assert_eq!(pending.len(), 2);
+        assert!(pending
+            .iter()
+            .all(|redemption| redemption.redemption_attempts == 1));
+
+        setup.chain.state().fail_verification_requests = false;
+        setup.redemption_round().await;
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 2);
+        assert_eq!(setup.chain.state().executed_proposals, 2);
+    }
+
+    #[tokio::test]
+    async fn credentials_spent_by_other_gateways_are_dropped() {
+        let setup = TestSetup::new(2).await;
+        let double_spent = setup.credentials[0].blinded_serial_number();
+        setup.chain.state().spent_by_others.insert(double_spent);
+
+        setup.redemption_round().await;
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 1);
+        assert!(setup.chain.proposal_of(&setup.credentials[0]).is_none());
+        assert!(setup.chain.proposal_of(&setup.credentials[1]).is_some());
+    }
+
+    #[tokio::test]
+    async fn invalid_credentials_are_dropped() {
+        let setup = TestSetup::new(2).await;
+        let invalid = setup.credentials[1].blinded_serial_number();
+        setup.chain.state().invalid.insert(invalid);
+
+        setup.redemption_round().await;
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 1);
+        assert!(setup.chain.proposal_of(&setup.credentials[1]).is_none());
+    }
+
+    #[tokio::test]
+    async fn already_executed_proposals_are_not_executed_again() {
+        let setup = TestSetup::new(1).await;
+        setup.chain.state().fail_executions = true;
+        setup.redemption_round().await;
+        assert_eq!(setup.pending().await.len(), 1);
+
+        // somebody else has executed the proposal in the meantime
+        let proposal_id = setup.chain.proposal_of(&setup.credentials[0]).unwrap();
+        setup
+            .chain
+            .state()
+            .proposals
+            .get_mut(&proposal_id)
+            .unwrap()
+            .executed = true;
+
+        setup.redemption_round().await;
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().executed_proposals, 0);
+    }
+
+    #[tokio::test]
+    async fn proposal_id_is_recovered_after_crash() {
+        let setup = TestSetup::new(1).await;
+        setup.chain.state().crash_after_spending = true;
+
+        setup.redemption_round().await;
+        let pending = setup.pending().await;
+        assert_eq!(pending.len(), 1);
+        assert!(pending[0].proposal_id.is_none());
+
+        setup.chain.state().crash_after_spending = false;
+        setup.redemption_round().await;
+
+        // rather than being dropped as spent, the existing proposal got executed
+        assert!(setup.pending().await.is_empty());
+        assert_eq!(setup.chain.state().created_proposals, 1);
+        assert_eq!(setup.chain.state().executed_proposals, 1);
+    }
+}
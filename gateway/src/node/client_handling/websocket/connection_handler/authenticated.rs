@@ -46,6 +46,9 @@ pub(crate) enum RequestHandlingError {
     #[error("Provided bandwidth credential did not verify correctly on {0}")]
     InvalidBandwidthCredential(String),
 
+    #[error("Provided bandwidth credential has already been spent")]
+    BandwidthCredentialAlreadySpent,
+
     #[error("This gateway is only accepting coconut credentials for bandwidth")]
     OnlyCoconutCredentials,
 
@@ -206,26 +209,31 @@ where
             iv,
         )?;
 
-        // Get the latest coconut signers and their VK
-        let credential_api_clients = self
+        if self
             .inner
-            .coconut_verifier
-            .all_coconut_api_clients(*credential.epoch_id())
-            .await?;
-        let current_api_clients = self
+            .storage
+            .is_credential_spent(&credential.blinded_serial_number())
+            .await?
+        {
+            return Err(RequestHandlingError::BandwidthCredentialAlreadySpent);
+        }
+
+        // the local check is not enough as the same credential could have been presented
+        // to (and already redeemed by) any other gateway
+        if self
             .inner
             .coconut_verifier
-            .all_current_coconut_api_clients()
-            .await?;
-        if credential_api_clients.is_empty() || current_api_clients.is_empty() {
-            return Err(RequestHandlingError::NotEnoughNymAPIs {
-                received: 0,
-                needed: 1,
-            });
+            .is_spent_on_chain(&credential)
+            .await?
+        {
+            return Err(RequestHandlingError::BandwidthCredentialAlreadySpent);
         }
 
-        let aggregated_verification_key =
-            nym_credentials::obtain_aggregate_verification_key(&credential_api_clients).await?;
+        let aggregated_verification_key = self
+            .inner
+            .coconut_verifier
+            .aggregated_verification_key(*credential.epoch_id())
+            .await?;
 
         if !credential.verify(&aggregated_verification_key) {
             return Err(RequestHandlingError::InvalidBandwidthCredential(
@@ -233,12 +241,7 @@ where
             ));
         }
 
-        self.inner
-            .coconut_verifier
-            .release_funds(current_api_clients, &credential)
-            .await?;
-
-        let bandwidth = Bandwidth::from(credential);
+        let bandwidth = Bandwidth::from(&credential);
         let bandwidth_value = bandwidth.value();
 
         if bandwidth_value > i64::MAX as u64 {
@@ -251,6 +254,12 @@ where
             ));
         }
 
+        // the funds are going to be released in the background by the `CredentialRedeemer`
+        self.inner
+            .storage
+            .insert_pending_redemption(&credential)
+            .await?;
+
         self.increase_bandwidth(bandwidth_value as i64).await?;
         let available_total = self.get_available_bandwidth().await?;
 
@@ -2,16 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::authenticated::RequestHandlingError;
+use crate::node::client_handling::redemption::{OnChainSpendStatus, RedemptionClient};
+use async_trait::async_trait;
+use dashmap::DashMap;
 use log::*;
-use nym_coconut_interface::Credential;
-use nym_validator_client::nyxd::traits::DkgQueryClient;
+use nym_api_requests::coconut::{
+    BatchVerifyCredentialsBody, VerifyCredentialBody, MAX_BATCH_VERIFICATION_SIZE,
+};
+use nym_coconut_interface::{Credential, VerificationKey};
+use nym_validator_client::nyxd::traits::{CoconutBandwidthQueryClient, DkgQueryClient};
 use nym_validator_client::{
     nyxd::{
         cosmwasm_client::logs::{find_attribute, BANDWIDTH_PROPOSAL_ID},
         traits::{CoconutBandwidthSigningClient, MultisigQueryClient, MultisigSigningClient},
         Coin, DirectSigningNyxdClient, Fee,
     },
-    Client, CoconutApiClient,
+    Client, CoconutApiClient, ValidatorClientError,
 };
 use std::time::{Duration, SystemTime};
 
@@ -21,6 +27,10 @@ const MAX_FEEGRANT_UNYM: u128 = 10000;
 pub(crate) struct CoconutVerifier {
     nyxd_client: Client<DirectSigningNyxdClient>,
     mix_denom_base: String,
+
+    // the aggregated verification key of a given epoch never changes,
+    // so there's no point in re-obtaining it for every received credential
+    verification_keys: DashMap<u64, VerificationKey>,
 }
 
 impl CoconutVerifier {
@@ -35,32 +45,109 @@ impl CoconutVerifier {
         CoconutVerifier {
             nyxd_client,
             mix_denom_base,
+            verification_keys: DashMap::new(),
         }
     }
 
-    pub async fn all_current_coconut_api_clients(
+    pub async fn all_coconut_api_clients(
         &self,
+        epoch_id: u64,
     ) -> Result<Vec<CoconutApiClient>, RequestHandlingError> {
-        let epoch_id = self.nyxd_client.nyxd.get_current_epoch().await?.epoch_id;
-        self.all_coconut_api_clients(epoch_id).await
+        Ok(CoconutApiClient::all_coconut_api_clients(&self.nyxd_client, epoch_id).await?)
     }
 
-    pub async fn all_coconut_api_clients(
+    /// Obtains the aggregated verification key of the signers of the particular epoch,
+    /// using the cached value if available.
+    pub async fn aggregated_verification_key(
         &self,
         epoch_id: u64,
+    ) -> Result<VerificationKey, RequestHandlingError> {
+        if let Some(cached) = self.verification_keys.get(&epoch_id) {
+            return Ok(cached.value().clone());
+        }
+
+        let api_clients = self.all_coconut_api_clients(epoch_id).await?;
+        if api_clients.is_empty() {
+            return Err(RequestHandlingError::NotEnoughNymAPIs {
+                received: 0,
+                needed: 1,
+            });
+        }
+
+        let verification_key =
+            nym_credentials::obtain_aggregate_verification_key(&api_clients).await?;
+        self.verification_keys
+            .insert(epoch_id, verification_key.clone());
+
+        Ok(verification_key)
+    }
+
+    /// Checks whether the coconut bandwidth contract already knows about the particular credential,
+    /// i.e. whether somebody (possibly another gateway) has already attempted to spend it.
+    pub async fn is_spent_on_chain(
+        &self,
+        credential: &Credential,
+    ) -> Result<bool, RequestHandlingError> {
+        Ok(self.spend_status(credential).await? != OnChainSpendStatus::Unspent)
+    }
+}
+
+#[async_trait]
+impl RedemptionClient for CoconutVerifier {
+    async fn all_current_coconut_api_clients(
+        &self,
     ) -> Result<Vec<CoconutApiClient>, RequestHandlingError> {
-        Ok(CoconutApiClient::all_coconut_api_clients(&self.nyxd_client, epoch_id).await?)
+        let epoch_id = self.nyxd_client.nyxd.get_current_epoch().await?.epoch_id;
+        self.all_coconut_api_clients(epoch_id).await
+    }
+
+    async fn find_invalid_credentials(
+        &self,
+        credentials: &[&Credential],
+    ) -> Result<Vec<usize>, RequestHandlingError> {
+        let mut verification_keys = Vec::with_capacity(credentials.len());
+        for credential in credentials {
+            verification_keys.push(
+                self.aggregated_verification_key(*credential.epoch_id())
+                    .await?,
+            );
+        }
+
+        let batch = credentials
+            .iter()
+            .zip(verification_keys.iter())
+            .map(|(credential, verification_key)| (*credential, verification_key))
+            .collect::<Vec<_>>();
+        Ok(Credential::find_invalid(&batch))
     }
 
-    pub async fn release_funds(
+    async fn spend_status(
         &self,
-        api_clients: Vec<CoconutApiClient>,
         credential: &Credential,
-    ) -> Result<(), RequestHandlingError> {
-        // Use a custom multiplier for revoke, as the default one (1.3)
-        // isn't enough
-        let revoke_fee = Some(Fee::Auto(Some(1.5)));
+    ) -> Result<OnChainSpendStatus, RequestHandlingError> {
+        let spend_credential = self
+            .nyxd_client
+            .nyxd
+            .get_spent_credential(credential.blinded_serial_number())
+            .await?
+            .spend_credential;
+
+        Ok(match spend_credential {
+            None => OnChainSpendStatus::Unspent,
+            Some(spent)
+                if spent.gateway_cosmos_address().as_str()
+                    == self.nyxd_client.nyxd.address().as_ref() =>
+            {
+                OnChainSpendStatus::SpentByUs
+            }
+            Some(_) => OnChainSpendStatus::SpentByOther,
+        })
+    }
 
+    async fn create_spend_proposal(
+        &self,
+        credential: &Credential,
+    ) -> Result<u64, RequestHandlingError> {
         let res = self
             .nyxd_client
             .nyxd
@@ -91,11 +178,35 @@ impl CoconutVerifier {
             });
         }
 
-        let req = nym_api_requests::coconut::VerifyCredentialBody::new(
-            credential.clone(),
-            proposal_id,
-            self.nyxd_client.nyxd.address().clone(),
-        );
+        Ok(proposal_id)
+    }
+
+    async fn find_spend_proposal(
+        &self,
+        credential: &Credential,
+    ) -> Result<Option<u64>, RequestHandlingError> {
+        // this is only ever needed when recovering from a crash, so going through all
+        // of the proposals is acceptable. the contract guarantees a single proposal per serial number
+        let blinded_serial_number = credential.blinded_serial_number();
+        Ok(self
+            .nyxd_client
+            .nyxd
+            .get_all_proposals()
+            .await?
+            .into_iter()
+            .find(|proposal| proposal.description == blinded_serial_number)
+            .map(|proposal| proposal.id))
+    }
+
+    async fn is_proposal_executed(&self, proposal_id: u64) -> Result<bool, RequestHandlingError> {
+        let proposal = self.nyxd_client.nyxd.get_proposal(proposal_id).await?;
+        Ok(proposal.status == cw3::Status::Executed)
+    }
+
+    async fn grant_voting_allowances(
+        &self,
+        api_clients: &[CoconutApiClient],
+    ) -> Result<(), RequestHandlingError> {
         for client in api_clients {
             self.nyxd_client
                 .nyxd
@@ -109,7 +220,19 @@ impl CoconutVerifier {
                     None,
                 )
                 .await?;
-            let ret = client.api_client.verify_bandwidth_credential(&req).await;
+        }
+        Ok(())
+    }
+
+    async fn revoke_voting_allowances(
+        &self,
+        api_clients: &[CoconutApiClient],
+    ) -> Result<(), RequestHandlingError> {
+        // Use a custom multiplier for revoke, as the default one (1.3)
+        // isn't enough
+        let revoke_fee = Some(Fee::Auto(Some(1.5)));
+
+        for client in api_clients {
             self.nyxd_client
                 .nyxd
                 .revoke_allowance(
@@ -118,16 +241,125 @@ impl CoconutVerifier {
                     revoke_fee.clone(),
                 )
                 .await?;
-            if !ret?.verification_result {
-                debug!("Validator {} didn't accept the credential. It will probably vote No on the spending proposal", client.api_client.nym_api_client.current_url());
+        }
+        Ok(())
+    }
+
+    async fn request_batch_verification(
+        &self,
+        api_clients: &[CoconutApiClient],
+        credentials: &[(&Credential, u64)],
+    ) -> Result<(), RequestHandlingError> {
+        // the signers refuse to verify too many credentials in a single request
+        let requests = credentials
+            .chunks(MAX_BATCH_VERIFICATION_SIZE)
+            .map(|chunk| {
+                BatchVerifyCredentialsBody::new(
+                    chunk
+                        .iter()
+                        .map(|(credential, proposal_id)| {
+                            VerifyCredentialBody::new(
+                                (*credential).clone(),
+                                *proposal_id,
+                                self.nyxd_client.nyxd.address().clone(),
+                            )
+                        })
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // a failure of any single signer shouldn't prevent us from contacting the remaining ones
+        let mut reached_signers = 0;
+        let mut last_error = None;
+        for client in api_clients {
+            let url = client.api_client.nym_api_client.current_url();
+            let verification_results = match request_verification(client, &requests).await {
+                Ok(verification_results) => verification_results,
+                Err(err) => {
+                    warn!("failed to request credential verification from {url} - {err}");
+                    last_error = Some(err);
+                    continue;
+                }
+            };
+
+            reached_signers += 1;
+            let rejected = verification_results
+                .iter()
+                .filter(|accepted| !**accepted)
+                .count();
+            if rejected > 0 {
+                debug!("Validator {url} didn't accept {rejected} out of {} credentials. It will probably vote No on the associated spending proposals", credentials.len());
             }
         }
 
+        match last_error {
+            Some(err) if reached_signers == 0 => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    async fn execute_proposal(&self, proposal_id: u64) -> Result<(), RequestHandlingError> {
         self.nyxd_client
             .nyxd
             .execute_proposal(proposal_id, None)
             .await?;
-
         Ok(())
     }
 }
+
+async fn request_verification(
+    client: &CoconutApiClient,
+    requests: &[BatchVerifyCredentialsBody],
+) -> Result<Vec<bool>, ValidatorClientError> {
+    let mut verification_results = Vec::new();
+    for req in requests {
+        match client
+            .api_client
+            .batch_verify_bandwidth_credentials(req)
+            .await
+        {
+            Ok(response) => verification_results.extend(response.verification_results),
+            Err(err) => {
+                // the signer might not support batch verification yet
+                debug!("batch verification request to {} failed ({err}). Falling back to verifying the credentials one by one", client.api_client.nym_api_client.current_url());
+                verification_results
+                    .extend(request_individual_verification(client, req.credentials()).await?)
+            }
+        }
+    }
+    Ok(verification_results)
+}
+
+// fallback for signers that don't support the batch verification route
+async fn request_individual_verification(
+    client: &CoconutApiClient,
+    credentials: &[VerifyCredentialBody],
+) -> Result<Vec<bool>, ValidatorClientError> {
+    let mut verification_results = Vec::with_capacity(credentials.len());
+    let mut responses = 0;
+    let mut last_error = None;
+    for credential in credentials {
+        match client
+            .api_client
+            .verify_bandwidth_credential(credential)
+            .await
+        {
+            Ok(response) => {
+                responses += 1;
+                verification_results.push(response.verification_result)
+            }
+            Err(err) => {
+                debug!("failed to request verification of a single credential - {err}");
+                verification_results.push(false);
+                last_error = Some(err);
+            }
+        }
+    }
+
+    // if none of the requests went through, the signer is most likely down
+    match last_error {
+        Some(err) if responses == 0 => Err(err),
+        _ => Ok(verification_results),
+    }
+}
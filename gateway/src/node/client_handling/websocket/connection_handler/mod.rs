@@ -12,7 +12,7 @@ use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_tungstenite::WebSocketStream;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
-pub(crate) use self::authenticated::AuthenticatedHandler;
+pub(crate) use self::authenticated::{AuthenticatedHandler, RequestHandlingError};
 pub(crate) use self::fresh::FreshHandler;
 
 mod authenticated;
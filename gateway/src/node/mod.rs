@@ -5,6 +5,7 @@ use self::storage::PersistentStorage;
 use crate::config::Config;
 use crate::error::GatewayError;
 use crate::node::client_handling::active_clients::ActiveClientsStore;
+use crate::node::client_handling::redemption::CredentialRedeemer;
 use crate::node::client_handling::websocket;
 use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
 use crate::node::mixnet_handling::receiver::connection_handler::ConnectionHandler;
@@ -17,7 +18,7 @@ use nym_mixnet_client::forwarder::{MixForwardingSender, PacketForwarder};
 use nym_network_defaults::NymNetworkDetails;
 use nym_statistics_common::collector::StatisticsSender;
 use nym_task::{TaskClient, TaskManager};
-use nym_validator_client::Client;
+use nym_validator_client::{Client, EndpointPool};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use std::error::Error;
@@ -194,6 +195,26 @@ impl<St> Gateway<St> {
         packet_sender
     }
 
+    fn start_credential_redeemer(
+        &self,
+        coconut_verifier: Arc<CoconutVerifier>,
+        shutdown: TaskClient,
+    ) where
+        St: Storage + Clone + 'static,
+    {
+        info!("Starting credential redeemer...");
+
+        let credential_redeemer = CredentialRedeemer::new(
+            coconut_verifier,
+            self.storage.clone(),
+            self.config.debug.credential_redemption_interval,
+            self.config.debug.credential_redemption_batch_size,
+            self.config.debug.maximum_credential_redemption_attempts,
+        );
+
+        tokio::spawn(async move { credential_redeemer.run(shutdown).await });
+    }
+
     async fn wait_for_interrupt(
         &self,
         shutdown: TaskManager,
@@ -203,41 +224,39 @@ impl<St> Gateway<St> {
         res
     }
 
-    fn random_api_client(&self) -> nym_validator_client::NymApiClient {
-        let endpoints = self.config.get_nym_api_endpoints();
-        let nym_api = endpoints
-            .choose(&mut thread_rng())
-            .expect("The list of validator apis is empty");
+    fn nym_api_client(&self) -> nym_validator_client::NymApiClient {
+        let mut endpoints = self.config.get_nym_api_endpoints();
+        // spread the initial load among all of the apis
+        endpoints.shuffle(&mut thread_rng());
 
-        nym_validator_client::NymApiClient::new(nym_api.clone())
+        let pool = EndpointPool::new(endpoints).expect("The list of validator apis is empty");
+        nym_validator_client::NymApiClient::new_with_endpoints(pool)
     }
 
-    fn random_nyxd_client(
+    fn nyxd_client(
         &self,
     ) -> nym_validator_client::Client<nym_validator_client::nyxd::DirectSigningNyxdClient> {
-        let endpoints = self.config.get_nyxd_urls();
-        let validator_nyxd = endpoints
-            .choose(&mut thread_rng())
-            .expect("The list of validators is empty");
+        let mut nyxd_urls = self.config.get_nyxd_urls();
+        nyxd_urls.shuffle(&mut thread_rng());
+        let mut api_urls = self.config.get_nym_api_endpoints();
+        api_urls.shuffle(&mut thread_rng());
 
         let network_details = NymNetworkDetails::new_from_env();
-        let client_config = nym_validator_client::Config::try_from_nym_network_details(
-            &network_details,
-        )
-        .expect("failed to construct valid validator client config with the provided network");
-
-        let mut client = Client::new_signing(client_config, self.config.get_cosmos_mnemonic())
-            .expect("Could not connect with mnemonic");
-        client
-            .change_nyxd(validator_nyxd.clone())
-            .expect("Could not use the random nyxd URL");
-        client
+        let client_config =
+            nym_validator_client::Config::try_from_nym_network_details(&network_details)
+                .expect(
+                    "failed to construct valid validator client config with the provided network",
+                )
+                .with_endpoints(nyxd_urls, api_urls);
+
+        Client::new_signing(client_config, self.config.get_cosmos_mnemonic())
+            .expect("Could not connect with mnemonic")
     }
 
     async fn check_if_bonded(&self) -> Result<bool, GatewayError> {
         // TODO: if anything, this should be getting data directly from the contract
         // as opposed to the validator API
-        let validator_client = self.random_api_client();
+        let validator_client = self.nym_api_client();
         let existing_nodes = match validator_client.get_cached_gateways().await {
             Ok(nodes) => nodes,
             Err(err) => {
@@ -264,8 +283,8 @@ impl<St> Gateway<St> {
         let shutdown = TaskManager::new(10);
 
         let coconut_verifier = {
-            let nyxd_client = self.random_nyxd_client();
-            CoconutVerifier::new(nyxd_client)
+            let nyxd_client = self.nyxd_client();
+            Arc::new(CoconutVerifier::new(nyxd_client))
         };
 
         let mix_forwarding_channel = self.start_packet_forwarder(shutdown.subscribe());
@@ -294,9 +313,11 @@ impl<St> Gateway<St> {
             mix_forwarding_channel,
             active_clients_store,
             shutdown.subscribe(),
-            Arc::new(coconut_verifier),
+            Arc::clone(&coconut_verifier),
         );
 
+        self.start_credential_redeemer(coconut_verifier, shutdown.subscribe());
+
         info!("Finished nym gateway startup procedure - it should now be able to receive mix and client traffic!");
 
         self.wait_for_interrupt(shutdown).await
@@ -0,0 +1,287 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node::storage::models::PendingRedemption;
+
+#[derive(Clone)]
+pub(crate) struct CredentialManager {
+    connection_pool: sqlx::SqlitePool,
+}
+
+impl CredentialManager {
+    /// Creates new instance of the `CredentialManager` with the provided sqlite connection pool.
+    ///
+    /// # Arguments
+    ///
+    /// * `connection_pool`: database connection pool to use.
+    pub(crate) fn new(connection_pool: sqlx::SqlitePool) -> Self {
+        CredentialManager { connection_pool }
+    }
+
+    /// Checks whether a credential with the provided blinded serial number has already been accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    pub(crate) async fn is_spent(
+        &self,
+        blinded_serial_number_bs58: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let count = sqlx::query!(
+            "SELECT COUNT(*) as count FROM spent_credential WHERE blinded_serial_number_bs58 = ?",
+            blinded_serial_number_bs58
+        )
+        .fetch_one(&self.connection_pool)
+        .await?
+        .count;
+
+        Ok(count != 0)
+    }
+
+    /// Marks the credential as spent and queues it for redemption.
+    /// Both operations are performed within a single transaction so that a credential
+    /// can never be marked as spent without also being queued for redemption (or vice versa).
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    /// * `credential`: serialized credential.
+    pub(crate) async fn insert_pending_redemption(
+        &self,
+        blinded_serial_number_bs58: &str,
+        credential: Vec<u8>,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        sqlx::query!(
+            "INSERT INTO spent_credential(blinded_serial_number_bs58) VALUES (?)",
+            blinded_serial_number_bs58
+        )
+        .execute(&mut tx)
+        .await?;
+        sqlx::query!(
+            "INSERT INTO pending_credential_redemption(blinded_serial_number_bs58, credential) VALUES (?, ?)",
+            blinded_serial_number_bs58,
+            credential
+        )
+        .execute(&mut tx)
+        .await?;
+        tx.commit().await
+    }
+
+    /// Retrieves the next batch of credentials awaiting redemption that have not yet exceeded
+    /// the maximum number of redemption attempts. Credentials that were attempted fewer times
+    /// are returned first so that a single misbehaving entry can't starve the rest of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts`: maximum number of redemption attempts of the returned entries.
+    /// * `limit`: maximum number of entries to return.
+    pub(crate) async fn get_pending_redemptions(
+        &self,
+        max_attempts: i64,
+        limit: i64,
+    ) -> Result<Vec<PendingRedemption>, sqlx::Error> {
+        sqlx::query_as!(
+            PendingRedemption,
+            r#"
+                SELECT * FROM pending_credential_redemption
+                WHERE redemption_attempts < ?
+                ORDER BY redemption_attempts ASC, id ASC
+                LIMIT ?
+            "#,
+            max_attempts,
+            limit
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Records id of the spending proposal created for the particular pending redemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    /// * `proposal_id`: id of the created multisig proposal.
+    pub(crate) async fn set_proposal_id(
+        &self,
+        id: i64,
+        proposal_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE pending_credential_redemption SET proposal_id = ? WHERE id = ?",
+            proposal_id,
+            id
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Increments the number of redemption attempts of the particular pending redemption
+    /// and records the cause of the failure.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    /// * `error`: description of the failure.
+    pub(crate) async fn record_failed_attempt(
+        &self,
+        id: i64,
+        error: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            r#"
+                UPDATE pending_credential_redemption
+                SET redemption_attempts = redemption_attempts + 1, last_error = ?
+                WHERE id = ?
+            "#,
+            error,
+            id
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Removes the pending redemption with the specified id. Note that the blinded serial number
+    /// remains recorded as spent.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    pub(crate) async fn remove_pending_redemption(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM pending_credential_redemption WHERE id = ?", id)
+            .execute(&self.connection_pool)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqliteConnectOptions;
+    use tempfile::TempDir;
+
+    // the directory has to be kept around for as long as the database is used
+    async fn test_manager() -> (CredentialManager, TempDir) {
+        let dir = tempfile::tempdir().unwrap();
+        let opts = SqliteConnectOptions::new()
+            .filename(dir.path().join("db.sqlite"))
+            .create_if_missing(true);
+        let connection_pool = sqlx::SqlitePool::connect_with(opts).await.unwrap();
+        sqlx::migrate!("./migrations")
+            .run(&connection_pool)
+            .await
+            .unwrap();
+
+        (CredentialManager::new(connection_pool), dir)
+    }
+
+    #[tokio::test]
+    async fn inserted_credentials_are_marked_as_spent() {
+        let (manager, _dir) = test_manager().await;
+        assert!(!manager.is_spent("foo").await.unwrap());
+
+        manager
+            .insert_pending_redemption("foo", vec![1, 2, 3])
+            .await
+            .unwrap();
+        assert!(manager.is_spent("foo").await.unwrap());
+        assert!(!manager.is_spent("bar").await.unwrap());
+
+        let pending = manager.get_pending_redemptions(10, 10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].blinded_serial_number_bs58, "foo");
+        assert_eq!(pending[0].credential, vec![1, 2, 3]);
+        assert_eq!(pending[0].proposal_id, None);
+        assert_eq!(pending[0].redemption_attempts, 0);
+    }
+
+    #[tokio::test]
+    async fn the_same_credential_cannot_be_inserted_twice() {
+        let (manager, _dir) = test_manager().await;
+
+        manager
+            .insert_pending_redemption("foo", vec![1, 2, 3])
+            .await
+            .unwrap();
+        assert!(manager
+            .insert_pending_redemption("foo", vec![4, 5, 6])
+            .await
+            .is_err());
+
+        // the original entry is left untouched
+        let pending = manager.get_pending_redemptions(10, 10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].credential, vec![1, 2, 3]);
+
+        // and after the redemption is removed, the credential still remains spent
+        manager
+            .remove_pending_redemption(pending[0].id)
+            .await
+            .unwrap();
+
+        assert!(manager
+            .get_pending_redemptions(10, 10)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(manager
+            .insert_pending_redemption("foo", vec![4, 5, 6])
+            .await
+            .is_err());
+        assert!(manager
+            .get_pending_redemptions(10, 10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn least_attempted_redemptions_are_returned_first() {
+        let (manager, _dir) = test_manager().await;
+
+        for serial_number in ["first", "second", "third"] {
+            manager
+                .insert_pending_redemption(serial_number, Vec::new())
+                .await
+                .unwrap();
+        }
+        let ids = manager
+            .get_pending_redemptions(10, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|pending| pending.id)
+            .collect::<Vec<_>>();
+
+        manager.record_failed_attempt(ids[0], "err").await.unwrap();
+        manager.record_failed_attempt(ids[0], "err").await.unwrap();
+        manager.record_failed_attempt(ids[1], "err").await.unwrap();
+        manager.set_proposal_id(ids[1], 42).await.unwrap();
+
+        let pending = manager.get_pending_redemptions(10, 10).await.unwrap();
+        let order = pending
+            .iter()
+            .map(|pending| pending.blinded_serial_number_bs58.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["third", "second", "first"]);
+        assert_eq!(pending[1].proposal_id, Some(42));
+        assert_eq!(pending[2].redemption_attempts, 2);
+        assert_eq!(pending[2].last_error.as_deref(), Some("err"));
+
+        // the limit is respected
+        let pending = manager.get_pending_redemptions(10, 1).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].blinded_serial_number_bs58, "third");
+
+        // and so is the maximum number of attempts
+        let pending = manager.get_pending_redemptions(2, 10).await.unwrap();
+        let order = pending
+            .iter()
+            .map(|pending| pending.blinded_serial_number_bs58.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec!["third", "second"]);
+    }
+}
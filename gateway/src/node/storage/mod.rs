@@ -2,21 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::node::storage::bandwidth::BandwidthManager;
+use crate::node::storage::credentials::CredentialManager;
 use crate::node::storage::error::StorageError;
 use crate::node::storage::inboxes::InboxManager;
-use crate::node::storage::models::{PersistedSharedKeys, StoredMessage};
+use crate::node::storage::models::{PendingRedemption, PersistedSharedKeys, StoredMessage};
 use crate::node::storage::shared_keys::SharedKeysManager;
 use async_trait::async_trait;
 use log::{debug, error};
+use nym_coconut_interface::Credential;
 use nym_gateway_requests::registration::handshake::SharedKeys;
 use nym_sphinx::DestinationAddressBytes;
 use sqlx::ConnectOptions;
 use std::path::Path;
 
 mod bandwidth;
+mod credentials;
 pub(crate) mod error;
 mod inboxes;
-mod models;
+pub(crate) mod models;
 mod shared_keys;
 
 #[async_trait]
@@ -134,6 +137,63 @@ pub(crate) trait Storage: Send + Sync {
         client_address: DestinationAddressBytes,
         amount: i64,
     ) -> Result<(), StorageError>;
+
+    /// Checks whether a credential with the provided blinded serial number has already been
+    /// accepted by this gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `blinded_serial_number_bs58`: base58-encoded blinded serial number of the credential.
+    async fn is_credential_spent(
+        &self,
+        blinded_serial_number_bs58: &str,
+    ) -> Result<bool, StorageError>;
+
+    /// Marks the provided credential as spent and queues it for (asynchronous) redemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `credential`: the verified bandwidth credential.
+    async fn insert_pending_redemption(&self, credential: &Credential) -> Result<(), StorageError>;
+
+    /// Retrieves the next batch of credentials awaiting redemption.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts`: credentials that failed to get redeemed this many times are not returned.
+    /// * `limit`: maximum number of credentials to retrieve.
+    async fn get_pending_redemptions(
+        &self,
+        max_attempts: u32,
+        limit: u32,
+    ) -> Result<Vec<PendingRedemption>, StorageError>;
+
+    /// Records id of the multisig proposal created for spending the particular credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    /// * `proposal_id`: id of the created proposal.
+    async fn set_redemption_proposal_id(
+        &self,
+        id: i64,
+        proposal_id: u64,
+    ) -> Result<(), StorageError>;
+
+    /// Records a failed attempt at redeeming the particular credential.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    /// * `error`: description of the failure.
+    async fn record_failed_redemption(&self, id: i64, error: &str) -> Result<(), StorageError>;
+
+    /// Removes the particular credential from the redemption queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `id`: id of the pending redemption.
+    async fn remove_pending_redemption(&self, id: i64) -> Result<(), StorageError>;
 }
 
 // note that clone here is fine as upon cloning the same underlying pool will be used
@@ -142,6 +202,7 @@ pub(crate) struct PersistentStorage {
     shared_key_manager: SharedKeysManager,
     inbox_manager: InboxManager,
     bandwidth_manager: BandwidthManager,
+    credential_manager: CredentialManager,
 }
 
 impl PersistentStorage {
@@ -187,7 +248,8 @@ impl PersistentStorage {
         Ok(PersistentStorage {
             shared_key_manager: SharedKeysManager::new(connection_pool.clone()),
             inbox_manager: InboxManager::new(connection_pool.clone(), message_retrieval_limit),
-            bandwidth_manager: BandwidthManager::new(connection_pool),
+            bandwidth_manager: BandwidthManager::new(connection_pool.clone()),
+            credential_manager: CredentialManager::new(connection_pool),
         })
     }
 }
@@ -304,6 +366,59 @@ impl Storage for PersistentStorage {
             .await?;
         Ok(())
     }
+
+    async fn is_credential_spent(
+        &self,
+        blinded_serial_number_bs58: &str,
+    ) -> Result<bool, StorageError> {
+        Ok(self
+            .credential_manager
+            .is_spent(blinded_serial_number_bs58)
+            .await?)
+    }
+
+    async fn insert_pending_redemption(&self, credential: &Credential) -> Result<(), StorageError> {
+        self.credential_manager
+            .insert_pending_redemption(&credential.blinded_serial_number(), credential.as_bytes())
+            .await?;
+        Ok(())
+    }
+
+    async fn get_pending_redemptions(
+        &self,
+        max_attempts: u32,
+        limit: u32,
+    ) -> Result<Vec<PendingRedemption>, StorageError> {
+        Ok(self
+            .credential_manager
+            .get_pending_redemptions(max_attempts as i64, limit as i64)
+            .await?)
+    }
+
+    async fn set_redemption_proposal_id(
+        &self,
+        id: i64,
+        proposal_id: u64,
+    ) -> Result<(), StorageError> {
+        self.credential_manager
+            .set_proposal_id(id, proposal_id as i64)
+            .await?;
+        Ok(())
+    }
+
+    async fn record_failed_redemption(&self, id: i64, error: &str) -> Result<(), StorageError> {
+        self.credential_manager
+            .record_failed_attempt(id, error)
+            .await?;
+        Ok(())
+    }
+
+    async fn remove_pending_redemption(&self, id: i64) -> Result<(), StorageError> {
+        self.credential_manager
+            .remove_pending_redemption(id)
+            .await?;
+        Ok(())
+    }
 }
 
 /// In-memory implementation of `Storage`. The intention is primarily in testing environments.
@@ -393,4 +508,42 @@ impl Storage for InMemStorage {
     ) -> Result<(), StorageError> {
         todo!()
     }
+
+    async fn is_credential_spent(
+        &self,
+        _blinded_serial_number_bs58: &str,
+    ) -> Result<bool, StorageError> {
+        todo!()
+    }
+
+    async fn insert_pending_redemption(
+        &self,
+        _credential: &Credential,
+    ) -> Result<(), StorageError> {
+        todo!()
+    }
+
+    async fn get_pending_redemptions(
+        &self,
+        _max_attempts: u32,
+        _limit: u32,
+    ) -> Result<Vec<PendingRedemption>, StorageError> {
+        todo!()
+    }
+
+    async fn set_redemption_proposal_id(
+        &self,
+        _id: i64,
+        _proposal_id: u64,
+    ) -> Result<(), StorageError> {
+        todo!()
+    }
+
+    async fn record_failed_redemption(&self, _id: i64, _error: &str) -> Result<(), StorageError> {
+        todo!()
+    }
+
+    async fn remove_pending_redemption(&self, _id: i64) -> Result<(), StorageError> {
+        todo!()
+    }
 }
@@ -18,3 +18,13 @@ pub(crate) struct PersistedBandwidth {
     pub(crate) client_address_bs58: String,
     pub(crate) available: i64,
 }
+
+pub(crate) struct PendingRedemption {
+    pub(crate) id: i64,
+    pub(crate) blinded_serial_number_bs58: String,
+    pub(crate) credential: Vec<u8>,
+    pub(crate) proposal_id: Option<i64>,
+    pub(crate) redemption_attempts: i64,
+    #[allow(dead_code)]
+    pub(crate) last_error: Option<String>,
+}
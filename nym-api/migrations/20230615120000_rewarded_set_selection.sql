@@ -0,0 +1,24 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE rewarded_set_selection
+(
+    absolute_epoch_id     INTEGER NOT NULL PRIMARY KEY,
+    algorithm_version     INTEGER NOT NULL,
+    seed_block_height     INTEGER NOT NULL,
+    seed                  VARCHAR NOT NULL,
+    candidates_commitment VARCHAR NOT NULL,
+    rewarded_set_size     INTEGER NOT NULL,
+    active_set_size       INTEGER NOT NULL
+);
+
+CREATE TABLE rewarded_set_selection_candidate
+(
+    absolute_epoch_id INTEGER NOT NULL REFERENCES rewarded_set_selection (absolute_epoch_id),
+    mix_id            INTEGER NOT NULL,
+    weight            INTEGER NOT NULL,
+
+    PRIMARY KEY (absolute_epoch_id, mix_id)
+);
@@ -0,0 +1,46 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE testing_route_timing
+(
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+    monitor_run_id     INTEGER NOT NULL,
+    timestamp          INTEGER NOT NULL,
+    gateway_identity   VARCHAR NOT NULL,
+    layer1_mix_id      INTEGER NOT NULL,
+    layer2_mix_id      INTEGER NOT NULL,
+    layer3_mix_id      INTEGER NOT NULL,
+    measured_packets   INTEGER NOT NULL,
+    median_delivery_ms REAL,
+    p90_delivery_ms    REAL
+);
+
+CREATE TABLE mixnode_timing
+(
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+    mixnode_details_id INTEGER NOT NULL,
+    monitor_run_id     INTEGER NOT NULL,
+    timestamp          INTEGER NOT NULL,
+    median_delivery_ms REAL,
+    excess_delay_ms    REAL,
+    estimated_loss     REAL    NOT NULL
+);
+
+CREATE TABLE gateway_timing
+(
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+    gateway_details_id INTEGER NOT NULL,
+    monitor_run_id     INTEGER NOT NULL,
+    timestamp          INTEGER NOT NULL,
+    median_delivery_ms REAL,
+    excess_delay_ms    REAL,
+    estimated_loss     REAL    NOT NULL
+);
+
+CREATE INDEX testing_route_timing_monitor_run_id ON testing_route_timing(`monitor_run_id`);
+CREATE INDEX testing_route_timing_timestamp ON testing_route_timing(`timestamp`);
+
+CREATE INDEX mixnode_timing_id_timestamp ON mixnode_timing(`mixnode_details_id`, `timestamp`);
+CREATE INDEX gateway_timing_id_timestamp ON gateway_timing(`gateway_details_id`, `timestamp`);
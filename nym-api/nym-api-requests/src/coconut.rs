@@ -46,6 +46,35 @@ impl VerifyCredentialResponse {
     }
 }
 
+/// Maximum number of credentials that can be verified in a single [`BatchVerifyCredentialsBody`].
+pub const MAX_BATCH_VERIFICATION_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize, Getters)]
+pub struct BatchVerifyCredentialsBody {
+    #[getset(get = "pub")]
+    credentials: Vec<VerifyCredentialBody>,
+}
+
+impl BatchVerifyCredentialsBody {
+    pub fn new(credentials: Vec<VerifyCredentialBody>) -> BatchVerifyCredentialsBody {
+        BatchVerifyCredentialsBody { credentials }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchVerifyCredentialsResponse {
+    // verification results in the same order as the credentials in the request
+    pub verification_results: Vec<bool>,
+}
+
+impl BatchVerifyCredentialsResponse {
+    pub fn new(verification_results: Vec<bool>) -> Self {
+        BatchVerifyCredentialsResponse {
+            verification_results,
+        }
+    }
+}
+
 //  All strings are base58 encoded representations of structs
 #[derive(Clone, Serialize, Deserialize, Debug, Getters, CopyGetters)]
 pub struct BlindSignRequestBody {
@@ -60,6 +89,10 @@ pub struct BlindSignRequestBody {
     public_attributes_plain: Vec<String>,
     #[getset(get = "pub")]
     total_params: u32,
+    // index of the requested ticket if the deposit is to be split into multiple tickets
+    #[serde(default)]
+    #[getset(get_copy = "pub")]
+    ticket_index: Option<u32>,
 }
 
 impl BlindSignRequestBody {
@@ -81,6 +114,22 @@ impl BlindSignRequestBody {
                 .collect(),
             public_attributes_plain,
             total_params,
+            ticket_index: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_ticket_index(mut self, ticket_index: u32) -> Self {
+        self.ticket_index = Some(ticket_index);
+        self
+    }
+
+    /// Key under which the issued signature is stored. Each ticket of a ticket book
+    /// gets a distinct entry, while a whole-deposit credential is keyed by the tx hash alone.
+    pub fn issuance_key(&self) -> String {
+        match self.ticket_index {
+            Some(index) => format!("{}/{index}", self.tx_hash),
+            None => self.tx_hash.clone(),
         }
     }
 
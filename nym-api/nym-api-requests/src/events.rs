@@ -0,0 +1,215 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::models::MixnodeStatus;
+use nym_mixnet_contract_common::{EpochId, IdentityKey, MixId};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NetworkEvent {
+    /// The current epoch has changed.
+    EpochAdvanced {
+        absolute_epoch_id: EpochId,
+        epoch_start: i64,
+        epoch_end: i64,
+    },
+
+    /// All the nodes in the rewarded set of the given epoch have been rewarded.
+    EpochRewarded {
+        absolute_epoch_id: EpochId,
+        rewarded_mixnodes: u32,
+    },
+
+    /// The rewarded set (and thus also possibly the active set) has changed.
+    RewardedSetChanged {
+        active_set: Vec<MixId>,
+        standby_set: Vec<MixId>,
+    },
+
+    MixnodeStatusChanged {
+        mix_id: MixId,
+        identity: IdentityKey,
+        previous: MixnodeStatus,
+        current: MixnodeStatus,
+    },
+
+    GatewayBonded {
+        identity: IdentityKey,
+    },
+
+    GatewayUnbonded {
+        identity: IdentityKey,
+    },
+
+    MixnodeBlacklistChanged {
+        mix_id: MixId,
+        blacklisted: bool,
+    },
+
+    GatewayBlacklistChanged {
+        identity: IdentityKey,
+        blacklisted: bool,
+    },
+
+    /// The historical daily uptimes of all monitored nodes have been recalculated.
+    HistoricalUptimesUpdated {
+        date: String,
+        mixnodes: u32,
+        gateways: u32,
+    },
+
+    /// The subscriber could not keep up and the specified number of events got dropped.
+    MissedEvents {
+        count: u64,
+    },
+}
+
+impl NetworkEvent {
+    pub fn kind(&self) -> NetworkEventKind {
+        match self {
+            NetworkEvent::EpochAdvanced { .. } => NetworkEventKind::EpochAdvanced,
+            NetworkEvent::EpochRewarded { .. } => NetworkEventKind::EpochRewarded,
+            NetworkEvent::RewardedSetChanged { .. } => NetworkEventKind::RewardedSetChanged,
+            NetworkEvent::MixnodeStatusChanged { .. } => NetworkEventKind::MixnodeStatusChanged,
+            NetworkEvent::GatewayBonded { .. } => NetworkEventKind::GatewayBonded,
+            NetworkEvent::GatewayUnbonded { .. } => NetworkEventKind::GatewayUnbonded,
+            NetworkEvent::MixnodeBlacklistChanged { .. } => {
+                NetworkEventKind::MixnodeBlacklistChanged
+            }
+            NetworkEvent::GatewayBlacklistChanged { .. } => {
+                NetworkEventKind::GatewayBlacklistChanged
+            }
+            NetworkEvent::HistoricalUptimesUpdated { .. } => {
+                NetworkEventKind::HistoricalUptimesUpdated
+            }
+            NetworkEvent::MissedEvents { .. } => NetworkEventKind::MissedEvents,
+        }
+    }
+
+    /// Mixnode this event is concerned with, if any.
+    pub fn mixnode(&self) -> Option<MixId> {
+        match self {
+            NetworkEvent::MixnodeStatusChanged { mix_id, .. }
+            | NetworkEvent::MixnodeBlacklistChanged { mix_id, .. } => Some(*mix_id),
+            _ => None,
+        }
+    }
+
+    /// Gateway this event is concerned with, if any.
+    pub fn gateway(&self) -> Option<&str> {
+        match self {
+            NetworkEvent::GatewayBonded { identity }
+            | NetworkEvent::GatewayUnbonded { identity }
+            | NetworkEvent::GatewayBlacklistChanged { identity, .. } => Some(identity),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkEventKind {
+    EpochAdvanced,
+    EpochRewarded,
+    RewardedSetChanged,
+    MixnodeStatusChanged,
+    GatewayBonded,
+    GatewayUnbonded,
+    MixnodeBlacklistChanged,
+    GatewayBlacklistChanged,
+    HistoricalUptimesUpdated,
+    MissedEvents,
+}
+
+impl NetworkEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkEventKind::EpochAdvanced => "epoch_advanced",
+            NetworkEventKind::EpochRewarded => "epoch_rewarded",
+            NetworkEventKind::RewardedSetChanged => "rewarded_set_changed",
+            NetworkEventKind::MixnodeStatusChanged => "mixnode_status_changed",
+            NetworkEventKind::GatewayBonded => "gateway_bonded",
+            NetworkEventKind::GatewayUnbonded => "gateway_unbonded",
+            NetworkEventKind::MixnodeBlacklistChanged => "mixnode_blacklist_changed",
+            NetworkEventKind::GatewayBlacklistChanged => "gateway_blacklist_changed",
+            NetworkEventKind::HistoricalUptimesUpdated => "historical_uptimes_updated",
+            NetworkEventKind::MissedEvents => "missed_events",
+        }
+    }
+}
+
+impl Display for NetworkEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownNetworkEventKind(pub String);
+
+impl Display for UnknownNetworkEventKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a known network event kind", self.0)
+    }
+}
+
+impl std::error::Error for UnknownNetworkEventKind {}
+
+impl FromStr for NetworkEventKind {
+    type Err = UnknownNetworkEventKind;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "epoch_advanced" => Ok(NetworkEventKind::EpochAdvanced),
+            "epoch_rewarded" => Ok(NetworkEventKind::EpochRewarded),
+            "rewarded_set_changed" => Ok(NetworkEventKind::RewardedSetChanged),
+            "mixnode_status_changed" => Ok(NetworkEventKind::MixnodeStatusChanged),
+            "gateway_bonded" => Ok(NetworkEventKind::GatewayBonded),
+            "gateway_unbonded" => Ok(NetworkEventKind::GatewayUnbonded),
+            "mixnode_blacklist_changed" => Ok(NetworkEventKind::MixnodeBlacklistChanged),
+            "gateway_blacklist_changed" => Ok(NetworkEventKind::GatewayBlacklistChanged),
+            "historical_uptimes_updated" => Ok(NetworkEventKind::HistoricalUptimesUpdated),
+            "missed_events" => Ok(NetworkEventKind::MissedEvents),
+            other => Err(UnknownNetworkEventKind(other.to_string())),
+        }
+    }
+}
+
+/// Subscription filter for the network events.
+///
+/// Empty lists do not restrict anything, i.e. the default filter lets through all the events.
+/// Events that are not concerned with any particular node are not affected by the node filters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NetworkEventFilter {
+    pub mixnodes: Vec<MixId>,
+    pub gateways: Vec<IdentityKey>,
+    pub kinds: Vec<NetworkEventKind>,
+}
+
+impl NetworkEventFilter {
+    pub fn matches(&self, event: &NetworkEvent) -> bool {
+        // always inform the subscriber about it missing events
+        if matches!(event, NetworkEvent::MissedEvents { .. }) {
+            return true;
+        }
+
+        if !self.kinds.is_empty() && !self.kinds.contains(&event.kind()) {
+            return false;
+        }
+
+        // if we only filter by one type of node, we're not interested in events for the other type
+        let filters_nodes = !self.mixnodes.is_empty() || !self.gateways.is_empty();
+        if let Some(mix_id) = event.mixnode() {
+            return !filters_nodes || self.mixnodes.contains(&mix_id);
+        }
+        if let Some(identity) = event.gateway() {
+            return !filters_nodes || self.gateways.iter().any(|gateway| gateway == identity);
+        }
+
+        true
+    }
+}
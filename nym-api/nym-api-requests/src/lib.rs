@@ -5,7 +5,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 pub mod coconut;
+pub mod events;
 pub mod models;
+pub mod topology;
 
 pub trait Deprecatable {
     fn deprecate(self) -> Deprecated<Self>
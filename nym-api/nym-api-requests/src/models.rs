@@ -7,7 +7,7 @@ use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::reward_params::{Performance, RewardingParams};
 use nym_mixnet_contract_common::rewarding::RewardEstimate;
 use nym_mixnet_contract_common::{
-    GatewayBond, IdentityKey, Interval, MixId, MixNode, Percent, RewardedSetNodeStatus,
+    EpochId, GatewayBond, IdentityKey, Interval, MixId, MixNode, Percent, RewardedSetNodeStatus,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -337,3 +337,121 @@ pub struct CirculatingSupplyResponse {
     pub vesting_tokens: Coin,
     pub circulating_supply: Coin,
 }
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RewardedSetSelectionCandidate {
+    pub mix_id: MixId,
+    pub weight: u64,
+}
+
+/// All the inputs that were used for selecting the rewarded set of particular epoch
+/// alongside the result of verifying them against the current rewarded set.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RewardedSetSelectionResponse {
+    pub absolute_epoch_id: EpochId,
+    pub algorithm_version: u32,
+    pub seed_block_height: u64,
+    pub seed: String,
+    pub candidates_commitment: String,
+    pub rewarded_set_size: u32,
+    pub active_set_size: u32,
+    pub candidates: Vec<RewardedSetSelectionCandidate>,
+
+    /// Set only if the selection is for the current epoch.
+    pub verification: Option<SelectionVerificationResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SelectionVerificationResult {
+    pub verified: bool,
+    pub error: Option<String>,
+}
+
+/// Round-trip time between two mixnodes, as reported by their verloc measurements.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct LatencyMatrixEntry {
+    pub mix_a: MixId,
+    pub mix_b: MixId,
+    pub mean_rtt_micros: u64,
+    pub minimum_rtt_micros: u64,
+
+    /// Indicates whether both nodes have measured each other and their measurements agreed.
+    /// Otherwise the entry is based on a single, unconfirmed, report.
+    pub confirmed: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LatencyMatrixResponse {
+    pub as_at: i64,
+    pub entries: Vec<LatencyMatrixEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+pub struct MixnodeLatencyResponse {
+    pub mix_id: MixId,
+    pub identity: IdentityKey,
+
+    /// Number of peers with an accepted latency entry.
+    pub measured_peers: usize,
+
+    /// Number of peers whose measurements contradicted the ones of this node.
+    pub inconsistent_peers: usize,
+
+    /// Number of measurements reported by this node that were rejected as implausible.
+    pub rejected_measurements: usize,
+
+    pub p50_rtt_micros: Option<u64>,
+    pub p90_rtt_micros: Option<u64>,
+    pub p99_rtt_micros: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MixnodesLatencyResponse {
+    pub as_at: i64,
+    pub mixnodes: Vec<MixnodeLatencyResponse>,
+}
+
+/// Delivery time and estimated delay and loss of a node obtained during a single network monitor run.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NodeTimingMeasurement {
+    pub timestamp: i64,
+    pub median_delivery_ms: Option<f32>,
+    /// Delay of the node relative to other nodes of the same kind tested through the same routes.
+    pub excess_delay_ms: Option<f32>,
+    /// Fraction of the node's packets lost on top of the typical loss of the routes used.
+    pub estimated_loss: f32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MixnodeTimingResponse {
+    pub mix_id: MixId,
+    pub average_excess_delay_ms: Option<f32>,
+    pub average_estimated_loss: Option<f32>,
+    pub measurements: Vec<NodeTimingMeasurement>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GatewayTimingResponse {
+    pub identity: String,
+    pub average_excess_delay_ms: Option<f32>,
+    pub average_estimated_loss: Option<f32>,
+    pub measurements: Vec<NodeTimingMeasurement>,
+}
+
+/// End-to-end delivery times of packets sent through a single network monitor test route.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct RouteTimingResponse {
+    pub gateway_identity: String,
+    pub layer1_mix_id: MixId,
+    pub layer2_mix_id: MixId,
+    pub layer3_mix_id: MixId,
+    pub measured_packets: u32,
+    pub median_delivery_ms: Option<f32>,
+    pub p90_delivery_ms: Option<f32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NetworkMonitorRoutesTimingResponse {
+    pub as_at: Option<i64>,
+    pub routes: Vec<RouteTimingResponse>,
+}
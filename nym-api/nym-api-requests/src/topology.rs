@@ -0,0 +1,132 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_mixnet_contract_common::{EpochId, GatewayBond, IdentityKey, MixId, MixNodeBond};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+/// Compact representation of the network topology, i.e. the active set mixnodes and all the
+/// gateways, alongside the version it corresponds to.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct CompactTopology {
+    /// Monotonically increasing version of the topology, bumped whenever the epoch advances
+    /// or any of the nodes changes.
+    pub version: u64,
+    pub epoch_id: EpochId,
+    pub mixnodes: Vec<MixNodeBond>,
+    pub gateways: Vec<GatewayBond>,
+}
+
+/// Changes that have to be applied to topology of version `from_version`
+/// in order to obtain the topology of version `version`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct TopologyDiff {
+    pub from_version: u64,
+    pub version: u64,
+    pub epoch_id: EpochId,
+    pub added_or_updated_mixnodes: Vec<MixNodeBond>,
+    pub removed_mixnodes: Vec<MixId>,
+    pub added_or_updated_gateways: Vec<GatewayBond>,
+    pub removed_gateways: Vec<IdentityKey>,
+}
+
+impl TopologyDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_or_updated_mixnodes.is_empty()
+            && self.removed_mixnodes.is_empty()
+            && self.added_or_updated_gateways.is_empty()
+            && self.removed_gateways.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TopologyChangesResponse {
+    /// Changes since the requested version.
+    Diff(TopologyDiff),
+
+    /// The full topology, returned if the requested version is unknown
+    /// or too old for the changes to still be available.
+    Full(CompactTopology),
+}
+
+impl TopologyChangesResponse {
+    pub fn version(&self) -> u64 {
+        match self {
+            TopologyChangesResponse::Diff(diff) => diff.version,
+            TopologyChangesResponse::Full(topology) => topology.version,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopologyVersionMismatch {
+    pub expected: u64,
+    pub received: u64,
+}
+
+impl Display for TopologyVersionMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "attempted to apply topology diff from version {} onto topology of version {}",
+            self.received, self.expected
+        )
+    }
+}
+
+impl std::error::Error for TopologyVersionMismatch {}
+
+impl CompactTopology {
+    /// Applies the provided diff onto this topology, bringing it up to `diff.version`.
+    pub fn apply_diff(&mut self, diff: TopologyDiff) -> Result<(), TopologyVersionMismatch> {
+        if diff.from_version != self.version {
+            return Err(TopologyVersionMismatch {
+                expected: self.version,
+                received: diff.from_version,
+            });
+        }
+
+        let removed_mixnodes = diff.removed_mixnodes.into_iter().collect::<HashSet<_>>();
+        let mut mixnodes = std::mem::take(&mut self.mixnodes)
+            .into_iter()
+            .filter(|mix| !removed_mixnodes.contains(&mix.mix_id))
+            .map(|mix| (mix.mix_id, mix))
+            .collect::<BTreeMap<_, _>>();
+        for mix in diff.added_or_updated_mixnodes {
+            mixnodes.insert(mix.mix_id, mix);
+        }
+
+        let removed_gateways = diff.removed_gateways.into_iter().collect::<HashSet<_>>();
+        let mut gateways = std::mem::take(&mut self.gateways)
+            .into_iter()
+            .filter(|gateway| !removed_gateways.contains(gateway.identity()))
+            .map(|gateway| (gateway.identity().clone(), gateway))
+            .collect::<BTreeMap<_, _>>();
+        for gateway in diff.added_or_updated_gateways {
+            gateways.insert(gateway.identity().clone(), gateway);
+        }
+
+        self.version = diff.version;
+        self.epoch_id = diff.epoch_id;
+        self.mixnodes = mixnodes.into_values().collect();
+        self.gateways = gateways.into_values().collect();
+        Ok(())
+    }
+
+    /// Updates this topology with the received changes.
+    pub fn apply_changes(
+        &mut self,
+        changes: TopologyChangesResponse,
+    ) -> Result<(), TopologyVersionMismatch> {
+        match changes {
+            TopologyChangesResponse::Diff(diff) => self.apply_diff(diff),
+            TopologyChangesResponse::Full(topology) => {
+                *self = topology;
+                Ok(())
+            }
+        }
+    }
+}
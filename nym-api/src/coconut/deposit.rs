@@ -6,7 +6,9 @@ use nym_coconut_bandwidth_contract_common::events::{
     DEPOSITED_FUNDS_EVENT_TYPE, DEPOSIT_ENCRYPTION_KEY, DEPOSIT_IDENTITY_KEY, DEPOSIT_INFO,
     DEPOSIT_VALUE,
 };
+use nym_config::defaults::TICKET_INFO;
 use nym_credentials::coconut::bandwidth::BandwidthVoucher;
+use nym_credentials::coconut::ticketbook::{parse_ticketbook_deposit_info, ticket_value};
 use nym_crypto::asymmetric::encryption;
 use nym_crypto::asymmetric::identity::{self, Signature};
 use nym_validator_client::nyxd::TxResponse;
@@ -28,6 +30,9 @@ pub async fn extract_encryption_key(
     let tx_hash_str = blind_sign_request_body.tx_hash();
     let mut message = blind_sign_request.to_bytes();
     message.extend_from_slice(tx_hash_str.as_bytes());
+    if let Some(ticket_index) = blind_sign_request_body.ticket_index() {
+        message.extend_from_slice(&ticket_index.to_be_bytes());
+    }
 
     let signature = Signature::from_base58_string(blind_sign_request_body.signature())?;
 
@@ -46,24 +51,47 @@ pub async fn extract_encryption_key(
         .ok_or(CoconutError::DepositValueNotFound)?
         .value
         .as_ref();
+    let deposit_info = attributes
+        .iter()
+        .find(|tag| tag.key.as_ref() == DEPOSIT_INFO)
+        .map(|tag| tag.value.as_ref());
+
+    // deposits made for a ticket book are issued as multiple tickets, each worth the equal share
+    // of the deposit, rather than as a single credential
+    let (deposit_value, deposit_info) = match (
+        deposit_info.and_then(parse_ticketbook_deposit_info),
+        blind_sign_request_body.ticket_index(),
+    ) {
+        (Some(tickets), Some(index)) => {
+            if index >= tickets {
+                return Err(CoconutError::InvalidTicketIndex { index, tickets });
+            }
+            (
+                ticket_value(deposit_value, tickets)?,
+                Some(TICKET_INFO.to_string()),
+            )
+        }
+        (Some(tickets), None) => return Err(CoconutError::MissingTicketIndex { tickets }),
+        (None, Some(_)) => return Err(CoconutError::UnexpectedTicketIndex),
+        (None, None) => (
+            deposit_value.to_string(),
+            deposit_info.map(ToString::to_string),
+        ),
+    };
+
     let deposit_value_plain = public_attributes_plain.get(0).cloned().unwrap_or_default();
     if deposit_value != deposit_value_plain {
         return Err(CoconutError::DifferentPublicAttributes(
-            deposit_value.to_string(),
+            deposit_value,
             deposit_value_plain,
         ));
     }
 
-    let deposit_info = attributes
-        .iter()
-        .find(|tag| tag.key.as_ref() == DEPOSIT_INFO)
-        .ok_or(CoconutError::DepositInfoNotFound)?
-        .value
-        .as_ref();
+    let deposit_info = deposit_info.ok_or(CoconutError::DepositInfoNotFound)?;
     let deposit_info_plain = public_attributes_plain.get(1).cloned().unwrap_or_default();
     if deposit_info != deposit_info_plain {
         return Err(CoconutError::DifferentPublicAttributes(
-            deposit_info.to_string(),
+            deposit_info,
             deposit_info_plain,
         ));
     }
@@ -97,6 +125,7 @@ mod test {
     use crate::coconut::tests::tx_entry_fixture;
     use nym_coconut::{prepare_blind_sign, BlindSignRequest, Parameters};
     use nym_config::defaults::VOUCHER_INFO;
+    use nym_credentials::coconut::ticketbook::{ticketbook_deposit_info, TicketBook};
     use nym_validator_client::nyxd::tx::Hash;
     use nym_validator_client::nyxd::{Event, Tag};
     use rand_07::rngs::OsRng;
@@ -431,4 +460,109 @@ mod test {
             .unwrap();
         assert_eq!(encryption_key.to_base58_string(), expected_encryption_key);
     }
+
+    #[tokio::test]
+    async fn extract_encryption_key_ticketbook_test() {
+        let tx_hash =
+            Hash::from_str("6B27412050B823E58BB38447D7870BBC8CBE3C51C905BEA89D459ACCDA80A00E")
+                .unwrap();
+        let mut tx_entry = tx_entry_fixture(&tx_hash.to_string());
+        let params = Parameters::new(4).unwrap();
+        let mut rng = OsRng;
+        let ticketbook = TicketBook::new(
+            &params,
+            "1000".to_string(),
+            4,
+            tx_hash,
+            identity::PrivateKey::from_bytes(
+                &identity::KeyPair::new(&mut rng).private_key().to_bytes(),
+            )
+            .unwrap(),
+            encryption::PrivateKey::from_bytes(
+                &encryption::KeyPair::new(&mut rng).private_key().to_bytes(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let ticket = ticketbook.ticket(&params, 1).unwrap();
+        let signature = "2DHbEZ6pzToGpsAXJrqJi7Wj1pAXeT18283q2YEEyNH5gTymwRozWBdja6SMAVt1dyYmUnM4ZNhsJ4wxZyGh4Z6J".to_string();
+        let request = || {
+            BlindSignRequestBody::new(
+                ticket.blind_sign_request(),
+                tx_hash.to_string(),
+                signature.clone(),
+                &ticket.get_public_attributes(),
+                ticket.get_public_attributes_plain(),
+                4,
+            )
+        };
+
+        tx_entry.tx_result.events.push(Event {
+            type_str: format!("wasm-{}", DEPOSITED_FUNDS_EVENT_TYPE),
+            attributes: vec![
+                Tag {
+                    key: DEPOSIT_VALUE.parse().unwrap(),
+                    value: "1000".parse().unwrap(),
+                },
+                Tag {
+                    key: DEPOSIT_INFO.parse().unwrap(),
+                    value: ticketbook_deposit_info(4).parse().unwrap(),
+                },
+            ],
+        });
+
+        let err = extract_encryption_key(&request(), tx_entry.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CoconutError::MissingTicketIndex { tickets: 4 }.to_string()
+        );
+
+        let err = extract_encryption_key(&request().with_ticket_index(4), tx_entry.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CoconutError::InvalidTicketIndex {
+                index: 4,
+                tickets: 4
+            }
+            .to_string()
+        );
+
+        // the value of a single ticket has to be the equal share of the deposit
+        tx_entry.tx_result.events.get_mut(0).unwrap().attributes[1].value =
+            ticketbook_deposit_info(2).parse().unwrap();
+        let err = extract_encryption_key(&request().with_ticket_index(1), tx_entry.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CoconutError::DifferentPublicAttributes("500".to_string(), "250".to_string())
+                .to_string()
+        );
+
+        // tickets can't be requested for regular deposits
+        tx_entry.tx_result.events.get_mut(0).unwrap().attributes[1].value =
+            VOUCHER_INFO.parse().unwrap();
+        let err = extract_encryption_key(&request().with_ticket_index(1), tx_entry.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CoconutError::UnexpectedTicketIndex.to_string()
+        );
+
+        // with the correct ticket book the checks proceed to the deposit keys
+        tx_entry.tx_result.events.get_mut(0).unwrap().attributes[1].value =
+            ticketbook_deposit_info(4).parse().unwrap();
+        let err = extract_encryption_key(&request().with_ticket_index(1), tx_entry.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CoconutError::DepositVerifKeyNotFound.to_string()
+        );
+    }
 }
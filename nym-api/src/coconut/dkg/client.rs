@@ -7,7 +7,7 @@ use cw3::ProposalResponse;
 use cw4::MemberResponse;
 use nym_coconut_dkg_common::dealer::{ContractDealing, DealerDetails, DealerDetailsResponse};
 use nym_coconut_dkg_common::types::{
-    EncodedBTEPublicKeyWithProof, Epoch, EpochId, InitialReplacementData, NodeIndex,
+    EncodedBTEPublicKeyWithProof, Epoch, EpochId, InitialReplacementData, NodeIndex, TOTAL_DEALINGS,
 };
 use nym_coconut_dkg_common::verification_key::{ContractVKShare, VerificationKeyShare};
 use nym_contracts_common::dealings::ContractSafeBytes;
@@ -91,6 +91,23 @@ impl DkgClient {
         ret
     }
 
+    // Indices of the dealings this dealer has submitted in the current epoch
+    pub(crate) async fn get_self_submitted_dealings(&self) -> Result<Vec<usize>, CoconutError> {
+        let own_address = self.get_address().await.to_string();
+        let mut submitted = vec![];
+        for idx in 0..TOTAL_DEALINGS {
+            if self
+                .get_dealings(idx)
+                .await?
+                .iter()
+                .any(|dealing| dealing.dealer.as_str() == own_address)
+            {
+                submitted.push(idx);
+            }
+        }
+        Ok(submitted)
+    }
+
     pub(crate) async fn get_verification_key_shares(
         &self,
         epoch_id: EpochId,
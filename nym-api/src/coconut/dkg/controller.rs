@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::coconut::dkg::client::DkgClient;
+use crate::coconut::dkg::recovery::{load_persistent_state, reconcile_with_chain};
 use crate::coconut::dkg::state::{ConsistentState, PersistentState, State};
 use crate::coconut::dkg::verification_key::{
     verification_key_finalization, verification_key_validation,
@@ -66,8 +67,7 @@ impl<R: RngCore + CryptoRng + Clone> DkgController<R> {
             coconut_keypair.set(Some(coconut_keypair_value)).await;
         }
         let persistent_state =
-            PersistentState::load_from_file(&config.storage_paths.dkg_persistent_state_path)
-                .unwrap_or_default();
+            load_persistent_state(&config.storage_paths.dkg_persistent_state_path);
 
         Ok(DkgController {
             dkg_client: DkgClient::new(nyxd_client),
@@ -111,6 +111,15 @@ impl<R: RngCore + CryptoRng + Clone> DkgController<R> {
                     debug!("Not a member of the group, DKG won't be run");
                     return;
                 }
+                let new_epoch = self.state.epoch_id() != Some(epoch.epoch_id);
+                if let Err(err) =
+                    reconcile_with_chain(&self.dkg_client, &mut self.state, &epoch).await
+                {
+                    warn!("Could not reconcile the DKG state with the chain: {err}");
+                }
+                if new_epoch {
+                    self.dump_persistent_state().await;
+                }
                 if let Err(err) = self.state.is_consistent(epoch.state).await {
                     debug!("Epoch state is corrupted - {err}. Awaiting for a DKG restart.");
                 } else {
@@ -6,5 +6,7 @@ pub(crate) mod complaints;
 pub(crate) mod controller;
 pub(crate) mod dealing;
 pub(crate) mod public_key;
+pub(crate) mod recovery;
 pub(crate) mod state;
+pub(crate) mod status;
 pub(crate) mod verification_key;
@@ -0,0 +1,333 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::coconut::dkg::client::DkgClient;
+use crate::coconut::dkg::state::{PersistentState, State};
+use crate::coconut::error::CoconutError;
+use log::debug;
+use nym_coconut_dkg_common::types::{Epoch, EpochId, EpochState};
+use std::path::{Path, PathBuf};
+
+fn corrupted_state_path(path: &Path) -> PathBuf {
+    path.with_extension("json.corrupted")
+}
+
+/// Loads the persisted DKG state. A state file that can't be parsed is moved aside, so that it
+/// can still be inspected, and a clean state is used instead, which is later reconciled with
+/// the contract.
+pub(crate) fn load_persistent_state<P: AsRef<Path>>(path: P) -> PersistentState {
+    let path = path.as_ref();
+    if !path.exists() {
+        return PersistentState::default();
+    }
+
+    match PersistentState::load_from_file(path) {
+        Ok(persistent_state) => persistent_state,
+        Err(err) => {
+            let backup_path = corrupted_state_path(path);
+            warn!(
+                "Could not load the persisted DKG state from {}: {err}. It will be moved to {} and the state will be recovered from the chain",
+                path.display(),
+                backup_path.display()
+            );
+            if let Err(err) = std::fs::rename(path, &backup_path) {
+                warn!("Could not move the corrupted DKG state: {err}");
+            }
+            PersistentState::default()
+        }
+    }
+}
+
+fn archive_state(state: &State, epoch_id: EpochId) {
+    let archive_path = PersistentState::archive_path(state.persistent_state_path(), epoch_id);
+    if let Err(err) = PersistentState::from(state).save_to_file(&archive_path) {
+        warn!("Could not archive the DKG state of epoch {epoch_id}: {err}");
+    }
+}
+
+// The node index is the only part of the state that can be safely restored from the contract, and
+// only as long as no dealings have been submitted yet, as everything derived afterwards depends
+// on data that was only ever kept locally.
+async fn recover_node_index(dkg_client: &DkgClient, state: &mut State) -> Result<(), CoconutError> {
+    let dealer_details = dkg_client.get_self_registered_dealer_details().await?;
+    let Some(details) = dealer_details.details else {
+        return Ok(());
+    };
+    if !dealer_details.dealer_type.is_current() {
+        return Ok(());
+    }
+
+    if dkg_client.get_self_submitted_dealings().await?.is_empty() {
+        info!(
+            "DKG: Recovered node index {} from the contract",
+            details.assigned_index
+        );
+        state.set_node_index(Some(details.assigned_index));
+    } else {
+        warn!("Dealings for this epoch were already submitted, but the local state they were derived from is gone. Awaiting for a DKG restart.");
+    }
+    Ok(())
+}
+
+/// Makes sure the local state describes the epoch the contract is currently in. The state of a
+/// previous epoch is archived and reset, including when that epoch never finished locally
+/// (for example because the api was offline when a new DKG round started), and whatever can be
+/// safely learned from the chain is restored.
+pub(crate) async fn reconcile_with_chain(
+    dkg_client: &DkgClient,
+    state: &mut State,
+    epoch: &Epoch,
+) -> Result<(), CoconutError> {
+    if let Some(epoch_id) = state.epoch_id() {
+        if epoch_id != epoch.epoch_id {
+            archive_state(state, epoch_id);
+            if state.was_in_progress() {
+                debug!(
+                    "DKG epoch {epoch_id} has finished, moving on to epoch {}",
+                    epoch.epoch_id
+                );
+            } else {
+                warn!(
+                    "The local DKG state refers to epoch {epoch_id}, which never finished, while the contract is at epoch {}. Resetting it",
+                    epoch.epoch_id
+                );
+            }
+            state.reset_persistent(false).await;
+            // let the public key submission decide whether the coconut keypair should be kept
+            state.set_was_in_progress();
+        }
+    }
+    state.set_epoch_id(epoch.epoch_id);
+
+    if matches!(epoch.state, EpochState::DealingExchange { .. }) && state.node_index().is_none() {
+        recover_node_index(dkg_client, state).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::coconut::tests::DummyClient;
+    use crate::coconut::KeyPair;
+    use cosmwasm_std::Addr;
+    use nym_coconut_dkg_common::dealer::DealerDetails;
+    use nym_coconut_dkg_common::types::TOTAL_DEALINGS;
+    use nym_contracts_common::dealings::ContractSafeBytes;
+    use nym_dkg::bte::keys::KeyPair as DkgKeyPair;
+    use nym_validator_client::nyxd::AccountId;
+    use rand::rngs::OsRng;
+    use rand::Rng;
+    use std::collections::HashMap;
+    use std::env::temp_dir;
+    use std::str::FromStr;
+    use std::sync::{Arc, RwLock};
+    use url::Url;
+
+    const TEST_VALIDATOR_ADDRESS: &str = "n19lc9u84cz0yz3fww5283nucc9yvr8gsjmgeul0";
+
+    fn random_state_path() -> PathBuf {
+        let random_file: usize = OsRng.gen();
+        temp_dir().join(format!("dkg_persistent_state{}.json", random_file))
+    }
+
+    fn new_state(path: PathBuf, persistent_state: PersistentState) -> State {
+        State::new(
+            path,
+            persistent_state,
+            Url::parse("localhost:8000").unwrap(),
+            DkgKeyPair::new(&nym_dkg::bte::setup(), OsRng),
+            KeyPair::new(),
+        )
+    }
+
+    fn epoch(epoch_id: EpochId, state: EpochState) -> Epoch {
+        Epoch {
+            state,
+            epoch_id,
+            ..Default::default()
+        }
+    }
+
+    fn registered_dealer_db(
+        assigned_index: u64,
+    ) -> Arc<RwLock<HashMap<String, (DealerDetails, bool)>>> {
+        let details = DealerDetails {
+            address: Addr::unchecked(TEST_VALIDATOR_ADDRESS),
+            bte_public_key_with_proof: String::new(),
+            announce_address: String::new(),
+            assigned_index,
+        };
+        Arc::new(RwLock::new(HashMap::from([(
+            TEST_VALIDATOR_ADDRESS.to_string(),
+            (details, true),
+        )])))
+    }
+
+    #[test]
+    fn missing_state_file_gives_clean_state() {
+        let path = random_state_path();
+        let persistent_state = load_persistent_state(&path);
+        assert!(persistent_state.epoch_id.is_none());
+        assert!(persistent_state.node_index.is_none());
+        assert!(!corrupted_state_path(&path).exists());
+    }
+
+    #[test]
+    fn corrupted_state_file_is_moved_aside() {
+        let path = random_state_path();
+        std::fs::write(&path, "{\"node_index\": 4, \"dealers\": ").unwrap();
+
+        let persistent_state = load_persistent_state(&path);
+        assert!(persistent_state.node_index.is_none());
+        assert!(!path.exists());
+
+        let backup_path = corrupted_state_path(&path);
+        assert_eq!(
+            std::fs::read_to_string(&backup_path).unwrap(),
+            "{\"node_index\": 4, \"dealers\": "
+        );
+        std::fs::remove_file(backup_path).unwrap();
+    }
+
+    #[test]
+    fn state_without_epoch_can_be_loaded() {
+        let path = random_state_path();
+        let mut persistent_state = serde_json::to_value(PersistentState {
+            node_index: Some(2),
+            ..Default::default()
+        })
+        .unwrap();
+        persistent_state.as_object_mut().unwrap().remove("epoch_id");
+        std::fs::write(&path, persistent_state.to_string()).unwrap();
+
+        let persistent_state = load_persistent_state(&path);
+        assert!(persistent_state.epoch_id.is_none());
+        assert_eq!(persistent_state.node_index, Some(2));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn state_without_epoch_adopts_the_current_one() {
+        let dkg_client = DkgClient::new(DummyClient::new(
+            AccountId::from_str(TEST_VALIDATOR_ADDRESS).unwrap(),
+        ));
+        let mut state = new_state(
+            random_state_path(),
+            PersistentState {
+                node_index: Some(2),
+                receiver_index: Some(1),
+                ..Default::default()
+            },
+        );
+
+        reconcile_with_chain(
+            &dkg_client,
+            &mut state,
+            &epoch(
+                3,
+                EpochState::VerificationKeySubmission { resharing: false },
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(state.epoch_id(), Some(3));
+        assert_eq!(state.node_index(), Some(2));
+        assert_eq!(state.receiver_index(), Some(1));
+        assert!(!state.was_in_progress());
+    }
+
+    #[tokio::test]
+    async fn outdated_state_is_archived_and_reset() {
+        let dkg_client = DkgClient::new(DummyClient::new(
+            AccountId::from_str(TEST_VALIDATOR_ADDRESS).unwrap(),
+        ));
+        let path = random_state_path();
+        let mut state = new_state(
+            path.clone(),
+            PersistentState {
+                epoch_id: Some(1),
+                node_index: Some(2),
+                receiver_index: Some(1),
+                proposal_id: Some(42),
+                ..Default::default()
+            },
+        );
+
+        reconcile_with_chain(
+            &dkg_client,
+            &mut state,
+            &epoch(
+                2,
+                EpochState::VerificationKeySubmission { resharing: false },
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(state.epoch_id(), Some(2));
+        assert!(state.node_index().is_none());
+        assert!(state.receiver_index().is_none());
+        assert!(state.was_in_progress());
+
+        let archive_path = PersistentState::archive_path(&path, 1);
+        let archived = PersistentState::load_from_file(&archive_path).unwrap();
+        assert_eq!(archived.epoch_id, Some(1));
+        assert_eq!(archived.node_index, Some(2));
+        assert_eq!(archived.proposal_id, Some(42));
+        std::fs::remove_file(archive_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn node_index_is_recovered_before_dealing() {
+        let dealer_details_db = registered_dealer_db(5);
+        let dkg_client = DkgClient::new(
+            DummyClient::new(AccountId::from_str(TEST_VALIDATOR_ADDRESS).unwrap())
+                .with_dealer_details(&dealer_details_db),
+        );
+        let mut state = new_state(random_state_path(), PersistentState::default());
+
+        // nothing gets recovered outside of the dealing exchange
+        reconcile_with_chain(
+            &dkg_client,
+            &mut state,
+            &epoch(1, EpochState::PublicKeySubmission { resharing: false }),
+        )
+        .await
+        .unwrap();
+        assert!(state.node_index().is_none());
+
+        reconcile_with_chain(
+            &dkg_client,
+            &mut state,
+            &epoch(1, EpochState::DealingExchange { resharing: false }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(state.node_index(), Some(5));
+    }
+
+    #[tokio::test]
+    async fn node_index_is_not_recovered_after_dealing() {
+        let dealer_details_db = registered_dealer_db(5);
+        let dealings_db = Arc::new(RwLock::new(HashMap::from([(
+            TEST_VALIDATOR_ADDRESS.to_string(),
+            vec![ContractSafeBytes(vec![]); TOTAL_DEALINGS],
+        )])));
+        let dkg_client = DkgClient::new(
+            DummyClient::new(AccountId::from_str(TEST_VALIDATOR_ADDRESS).unwrap())
+                .with_dealer_details(&dealer_details_db)
+                .with_dealings(&dealings_db),
+        );
+        let mut state = new_state(random_state_path(), PersistentState::default());
+
+        reconcile_with_chain(
+            &dkg_client,
+            &mut state,
+            &epoch(1, EpochState::DealingExchange { resharing: false }),
+        )
+        .await
+        .unwrap();
+        assert!(state.node_index().is_none());
+    }
+}
@@ -8,7 +8,7 @@ use cosmwasm_std::Addr;
 use log::debug;
 use nym_coconut::SecretKey;
 use nym_coconut_dkg_common::dealer::DealerDetails;
-use nym_coconut_dkg_common::types::EpochState;
+use nym_coconut_dkg_common::types::{EpochId, EpochState};
 use nym_dkg::bte::{keys::KeyPair as DkgKeyPair, PublicKey, PublicKeyWithProof};
 use nym_dkg::{NodeIndex, RecoveredVerificationKeys, Threshold};
 use serde::de::Error;
@@ -162,22 +162,26 @@ where
 
 #[derive(Default, Deserialize, Serialize)]
 pub(crate) struct PersistentState {
-    node_index: Option<NodeIndex>,
-    dealers: BTreeMap<Addr, Result<DkgParticipant, ComplaintReason>>,
-    receiver_index: Option<usize>,
-    threshold: Option<Threshold>,
+    // states persisted by older versions do not record the epoch they belong to
+    #[serde(default)]
+    pub(crate) epoch_id: Option<EpochId>,
+    pub(crate) node_index: Option<NodeIndex>,
+    pub(crate) dealers: BTreeMap<Addr, Result<DkgParticipant, ComplaintReason>>,
+    pub(crate) receiver_index: Option<usize>,
+    pub(crate) threshold: Option<Threshold>,
     #[serde(serialize_with = "vks_serialize")]
     #[serde(deserialize_with = "vks_deserialize")]
-    recovered_vks: Vec<RecoveredVerificationKeys>,
-    proposal_id: Option<u64>,
-    voted_vks: bool,
-    executed_proposal: bool,
-    was_in_progress: bool,
+    pub(crate) recovered_vks: Vec<RecoveredVerificationKeys>,
+    pub(crate) proposal_id: Option<u64>,
+    pub(crate) voted_vks: bool,
+    pub(crate) executed_proposal: bool,
+    pub(crate) was_in_progress: bool,
 }
 
 impl From<&State> for PersistentState {
     fn from(s: &State) -> Self {
         PersistentState {
+            epoch_id: s.epoch_id,
             node_index: s.node_index,
             dealers: s.dealers.clone(),
             receiver_index: s.receiver_index,
@@ -200,6 +204,13 @@ impl PersistentState {
     pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self, CoconutError> {
         Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
     }
+
+    /// Path under which the state of a finished (or abandoned) epoch is kept, next to the
+    /// state of the current one.
+    pub fn archive_path<P: AsRef<Path>>(path: P, epoch_id: EpochId) -> PathBuf {
+        path.as_ref()
+            .with_extension(format!("epoch_{epoch_id}.json"))
+    }
 }
 
 pub(crate) struct State {
@@ -207,6 +218,7 @@ pub(crate) struct State {
     announce_address: Url,
     dkg_keypair: DkgKeyPair,
     coconut_keypair: CoconutKeyPair,
+    epoch_id: Option<EpochId>,
     node_index: Option<NodeIndex>,
     dealers: BTreeMap<Addr, Result<DkgParticipant, ComplaintReason>>,
     receiver_index: Option<usize>,
@@ -231,6 +243,7 @@ impl State {
             announce_address,
             dkg_keypair,
             coconut_keypair,
+            epoch_id: persistent_state.epoch_id,
             node_index: persistent_state.node_index,
             dealers: persistent_state.dealers,
             receiver_index: persistent_state.receiver_index,
@@ -282,6 +295,10 @@ impl State {
             .map(|kp| kp.secret_key())
     }
 
+    pub fn epoch_id(&self) -> Option<EpochId> {
+        self.epoch_id
+    }
+
     pub fn node_index(&self) -> Option<NodeIndex> {
         self.node_index
     }
@@ -343,6 +360,10 @@ impl State {
         self.coconut_keypair.set(coconut_keypair).await
     }
 
+    pub fn set_epoch_id(&mut self, epoch_id: EpochId) {
+        self.epoch_id = Some(epoch_id);
+    }
+
     pub fn set_node_index(&mut self, node_index: Option<NodeIndex>) {
         self.node_index = node_index;
     }
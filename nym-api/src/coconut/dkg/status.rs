@@ -0,0 +1,595 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::coconut::client::Client as LocalClient;
+use crate::coconut::dkg::client::DkgClient;
+use crate::coconut::dkg::complaints::ComplaintReason;
+use crate::coconut::dkg::state::PersistentState;
+use crate::coconut::error::{CoconutError, Result};
+use cosmwasm_std::Addr;
+use nym_coconut_dkg_common::dealer::DealerType;
+use nym_coconut_dkg_common::types::{Epoch, EpochId, EpochState, NodeIndex, TOTAL_DEALINGS};
+use nym_coconut_dkg_common::verification_key::ContractVKShare;
+use nym_config::defaults::NYM_API_VERSION;
+use nym_dkg::Threshold;
+use nym_validator_client::nym_api::routes::{COCONUT_ROUTES, DKG};
+use rocket::fairing::AdHoc;
+use rocket::serde::json::Json;
+use rocket::State as RocketState;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DealerStatus {
+    address: Addr,
+    node_index: Option<NodeIndex>,
+    // reason for which this api excluded the dealer, if it did
+    complaint: Option<ComplaintReason>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct LocalDkgState {
+    epoch_id: Option<EpochId>,
+    node_index: Option<NodeIndex>,
+    receiver_index: Option<usize>,
+    threshold: Option<Threshold>,
+    proposal_id: Option<u64>,
+    voted_vks: bool,
+    executed_proposal: bool,
+    was_in_progress: bool,
+    dealers: Vec<DealerStatus>,
+}
+
+impl From<&PersistentState> for LocalDkgState {
+    fn from(state: &PersistentState) -> Self {
+        LocalDkgState {
+            epoch_id: state.epoch_id,
+            node_index: state.node_index,
+            receiver_index: state.receiver_index,
+            threshold: state.threshold,
+            proposal_id: state.proposal_id,
+            voted_vks: state.voted_vks,
+            executed_proposal: state.executed_proposal,
+            was_in_progress: state.was_in_progress,
+            dealers: state
+                .dealers
+                .iter()
+                .map(|(address, dealer)| DealerStatus {
+                    address: address.clone(),
+                    node_index: dealer.as_ref().ok().map(|p| p.assigned_index),
+                    complaint: dealer.as_ref().err().cloned(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ChainDkgState {
+    current_epoch: Epoch,
+    // the dealer registration and the dealings are only available for the current epoch
+    dealer_type: Option<DealerType>,
+    assigned_index: Option<NodeIndex>,
+    submitted_dealings: Option<Vec<usize>>,
+    verification_key_share: Option<ContractVKShare>,
+}
+
+impl ChainDkgState {
+    fn current_assigned_index(&self) -> Option<NodeIndex> {
+        match self.dealer_type {
+            Some(DealerType::Current) => self.assigned_index,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Inconsistency {
+    LocalStateUnavailable {
+        reason: String,
+    },
+    OutdatedLocalState {
+        local_epoch_id: EpochId,
+    },
+    NotRegistered {
+        local_index: NodeIndex,
+    },
+    NodeIndexMismatch {
+        local_index: NodeIndex,
+        chain_index: NodeIndex,
+    },
+    MissingNodeIndex {
+        chain_index: NodeIndex,
+    },
+    IncompleteDealings {
+        submitted: Vec<usize>,
+    },
+    MissingDealings,
+    MissingVerificationKeyShare {
+        proposal_id: u64,
+    },
+    UntrackedVerificationKeyShare,
+    UnverifiedVerificationKeyShare,
+}
+
+impl Display for Inconsistency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Inconsistency::LocalStateUnavailable { reason } => {
+                write!(f, "the local state could not be loaded: {reason}")
+            }
+            Inconsistency::OutdatedLocalState { local_epoch_id } => {
+                write!(f, "the local state belongs to epoch {local_epoch_id}")
+            }
+            Inconsistency::NotRegistered { local_index } => write!(
+                f,
+                "the local state uses node index {local_index}, but the contract has no registration for this epoch"
+            ),
+            Inconsistency::NodeIndexMismatch {
+                local_index,
+                chain_index,
+            } => write!(
+                f,
+                "the local state uses node index {local_index}, but the contract assigned {chain_index}"
+            ),
+            Inconsistency::MissingNodeIndex { chain_index } => write!(
+                f,
+                "the contract assigned node index {chain_index}, but it is missing from the local state"
+            ),
+            Inconsistency::IncompleteDealings { submitted } => write!(
+                f,
+                "only dealings {submitted:?} out of {TOTAL_DEALINGS} were submitted to the contract"
+            ),
+            Inconsistency::MissingDealings => write!(
+                f,
+                "the local state finished the dealing exchange, but no dealings were submitted to the contract"
+            ),
+            Inconsistency::MissingVerificationKeyShare { proposal_id } => write!(
+                f,
+                "the local state tracks proposal {proposal_id}, but the contract has no verification key share"
+            ),
+            Inconsistency::UntrackedVerificationKeyShare => write!(
+                f,
+                "the contract has a verification key share that the local state does not know about"
+            ),
+            Inconsistency::UnverifiedVerificationKeyShare => write!(
+                f,
+                "the local state executed the verification key proposal, but the share is not verified in the contract"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DkgStateReport {
+    epoch_id: EpochId,
+    address: String,
+    local: Option<LocalDkgState>,
+    chain: ChainDkgState,
+    inconsistencies: Vec<Inconsistency>,
+}
+
+impl Display for DkgStateReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "DKG epoch {} (the contract is at epoch {}: {})",
+            self.epoch_id, self.chain.current_epoch.epoch_id, self.chain.current_epoch.state
+        )?;
+        writeln!(f, "signer: {}", self.address)?;
+
+        writeln!(f, "local state:")?;
+        if let Some(local) = &self.local {
+            writeln!(f, "  node index: {:?}", local.node_index)?;
+            writeln!(f, "  receiver index: {:?}", local.receiver_index)?;
+            writeln!(f, "  threshold: {:?}", local.threshold)?;
+            writeln!(f, "  verification key proposal: {:?}", local.proposal_id)?;
+            writeln!(f, "  voted on verification keys: {}", local.voted_vks)?;
+            writeln!(f, "  executed own proposal: {}", local.executed_proposal)?;
+            for dealer in &local.dealers {
+                match (&dealer.complaint, dealer.node_index) {
+                    (Some(complaint), _) => {
+                        writeln!(f, "  dealer {}: excluded ({complaint:?})", dealer.address)?
+                    }
+                    (None, Some(index)) => {
+                        writeln!(f, "  dealer {}: index {index}", dealer.address)?
+                    }
+                    (None, None) => writeln!(f, "  dealer {}", dealer.address)?,
+                }
+            }
+        } else {
+            writeln!(f, "  unavailable")?;
+        }
+
+        writeln!(f, "contract state:")?;
+        if let Some(dealer_type) = self.chain.dealer_type {
+            writeln!(
+                f,
+                "  dealer registration: {dealer_type:?} with index {:?}",
+                self.chain.assigned_index
+            )?;
+        }
+        if let Some(submitted) = &self.chain.submitted_dealings {
+            writeln!(f, "  submitted dealings: {submitted:?}")?;
+        }
+        match &self.chain.verification_key_share {
+            Some(share) => writeln!(
+                f,
+                "  verification key share: index {}, verified: {}",
+                share.node_index, share.verified
+            )?,
+            None => writeln!(f, "  verification key share: none")?,
+        }
+
+        if self.inconsistencies.is_empty() {
+            write!(f, "no inconsistencies found")
+        } else {
+            write!(f, "inconsistencies:")?;
+            for inconsistency in &self.inconsistencies {
+                write!(f, "\n  - {inconsistency}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn load_local_state(
+    persistent_state_path: &Path,
+    epoch_id: EpochId,
+    current_epoch_id: EpochId,
+) -> Result<PersistentState> {
+    if epoch_id == current_epoch_id {
+        return PersistentState::load_from_file(persistent_state_path);
+    }
+
+    // the state of the previous epoch only gets archived once the controller notices the new one
+    match PersistentState::load_from_file(PersistentState::archive_path(
+        persistent_state_path,
+        epoch_id,
+    )) {
+        Ok(archived) => Ok(archived),
+        Err(err) => match PersistentState::load_from_file(persistent_state_path) {
+            Ok(state) if state.epoch_id == Some(epoch_id) => Ok(state),
+            _ => Err(err),
+        },
+    }
+}
+
+fn find_inconsistencies(
+    epoch_id: EpochId,
+    local: &PersistentState,
+    chain: &ChainDkgState,
+) -> Vec<Inconsistency> {
+    let mut inconsistencies = vec![];
+
+    if let Some(local_epoch_id) = local.epoch_id {
+        if local_epoch_id != epoch_id {
+            inconsistencies.push(Inconsistency::OutdatedLocalState { local_epoch_id });
+        }
+    }
+
+    if epoch_id == chain.current_epoch.epoch_id {
+        let phase = chain.current_epoch.state;
+        if !matches!(phase, EpochState::PublicKeySubmission { .. }) {
+            match (local.node_index, chain.current_assigned_index()) {
+                (Some(local_index), Some(chain_index)) if local_index != chain_index => {
+                    inconsistencies.push(Inconsistency::NodeIndexMismatch {
+                        local_index,
+                        chain_index,
+                    })
+                }
+                (Some(local_index), None) => {
+                    inconsistencies.push(Inconsistency::NotRegistered { local_index })
+                }
+                (None, Some(chain_index)) => {
+                    inconsistencies.push(Inconsistency::MissingNodeIndex { chain_index })
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(submitted) = &chain.submitted_dealings {
+            // in resharing mode only the initial dealers submit dealings, so an empty set
+            // is only suspicious outside of it
+            let dealings_expected = matches!(
+                phase,
+                EpochState::VerificationKeySubmission { resharing: false }
+                    | EpochState::VerificationKeyValidation { resharing: false }
+                    | EpochState::VerificationKeyFinalization { resharing: false }
+            );
+            if !submitted.is_empty() && submitted.len() < TOTAL_DEALINGS {
+                inconsistencies.push(Inconsistency::IncompleteDealings {
+                    submitted: submitted.clone(),
+                })
+            } else if submitted.is_empty() && local.receiver_index.is_some() && dealings_expected {
+                inconsistencies.push(Inconsistency::MissingDealings)
+            }
+        }
+    }
+
+    match (&chain.verification_key_share, local.proposal_id) {
+        (None, Some(proposal_id)) => {
+            inconsistencies.push(Inconsistency::MissingVerificationKeyShare { proposal_id })
+        }
+        (Some(_), None) => inconsistencies.push(Inconsistency::UntrackedVerificationKeyShare),
+        (Some(share), Some(_)) if local.executed_proposal && !share.verified => {
+            inconsistencies.push(Inconsistency::UnverifiedVerificationKeyShare)
+        }
+        _ => {}
+    }
+
+    inconsistencies
+}
+
+/// Compares the locally persisted DKG state of the given epoch (or of the current one) with
+/// what the coconut-dkg contract knows about this signer.
+pub(crate) async fn dkg_state_report(
+    dkg_client: &DkgClient,
+    persistent_state_path: &Path,
+    epoch_id: Option<EpochId>,
+) -> Result<DkgStateReport> {
+    let current_epoch = dkg_client.get_current_epoch().await?;
+    let epoch_id = epoch_id.unwrap_or(current_epoch.epoch_id);
+    if epoch_id > current_epoch.epoch_id {
+        return Err(CoconutError::DkgEpochNotStarted {
+            epoch_id,
+            current_epoch_id: current_epoch.epoch_id,
+        });
+    }
+    let address = dkg_client.get_address().await.to_string();
+
+    let (dealer_type, assigned_index, submitted_dealings) = if epoch_id == current_epoch.epoch_id {
+        let dealer_details = dkg_client.get_self_registered_dealer_details().await?;
+        (
+            Some(dealer_details.dealer_type),
+            dealer_details.details.map(|details| details.assigned_index),
+            Some(dkg_client.get_self_submitted_dealings().await?),
+        )
+    } else {
+        (None, None, None)
+    };
+    let verification_key_share = dkg_client
+        .get_verification_key_shares(epoch_id)
+        .await?
+        .into_iter()
+        .find(|share| share.owner.as_str() == address);
+    let chain = ChainDkgState {
+        current_epoch,
+        dealer_type,
+        assigned_index,
+        submitted_dealings,
+        verification_key_share,
+    };
+
+    let (local, inconsistencies) =
+        match load_local_state(persistent_state_path, epoch_id, current_epoch.epoch_id) {
+            Ok(local) => (
+                Some(LocalDkgState::from(&local)),
+                find_inconsistencies(epoch_id, &local, &chain),
+            ),
+            Err(err) => (
+                None,
+                vec![Inconsistency::LocalStateUnavailable {
+                    reason: err.to_string(),
+                }],
+            ),
+        };
+
+    Ok(DkgStateReport {
+        epoch_id,
+        address,
+        local,
+        chain,
+        inconsistencies,
+    })
+}
+
+pub(crate) struct DkgStatus {
+    dkg_client: DkgClient,
+    persistent_state_path: PathBuf,
+}
+
+impl DkgStatus {
+    pub(crate) fn stage<C>(client: C, persistent_state_path: PathBuf) -> AdHoc
+    where
+        C: LocalClient + Send + Sync + 'static,
+    {
+        let status = DkgStatus {
+            dkg_client: DkgClient::new(client),
+            persistent_state_path,
+        };
+        AdHoc::on_ignite("DKG Status Stage", |rocket| async {
+            rocket.manage(status).mount(
+                format!("/{}/{}/{}", NYM_API_VERSION, COCONUT_ROUTES, DKG),
+                routes![get_current_dkg_state, get_dkg_state],
+            )
+        })
+    }
+
+    async fn report(&self, epoch_id: Option<EpochId>) -> Result<DkgStateReport> {
+        dkg_state_report(&self.dkg_client, &self.persistent_state_path, epoch_id).await
+    }
+}
+
+#[get("/state")]
+pub(crate) async fn get_current_dkg_state(
+    status: &RocketState<DkgStatus>,
+) -> Result<Json<DkgStateReport>> {
+    Ok(Json(status.report(None).await?))
+}
+
+#[get("/state/<epoch_id>")]
+pub(crate) async fn get_dkg_state(
+    epoch_id: EpochId,
+    status: &RocketState<DkgStatus>,
+) -> Result<Json<DkgStateReport>> {
+    Ok(Json(status.report(Some(epoch_id)).await?))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    fn chain_state(epoch_id: EpochId, state: EpochState) -> ChainDkgState {
+        ChainDkgState {
+            current_epoch: Epoch {
+                state,
+                epoch_id,
+                ..Default::default()
+            },
+            dealer_type: Some(DealerType::Current),
+            assigned_index: Some(2),
+            submitted_dealings: Some((0..TOTAL_DEALINGS).collect()),
+            verification_key_share: None,
+        }
+    }
+
+    fn vk_share(node_index: NodeIndex, verified: bool) -> ContractVKShare {
+        ContractVKShare {
+            share: String::new(),
+            announce_address: String::new(),
+            node_index,
+            owner: Addr::unchecked("owner"),
+            epoch_id: 1,
+            verified,
+        }
+    }
+
+    #[test]
+    fn consistent_state() {
+        let mut chain = chain_state(1, EpochState::InProgress);
+        chain.verification_key_share = Some(vk_share(2, true));
+        let local = PersistentState {
+            epoch_id: Some(1),
+            node_index: Some(2),
+            receiver_index: Some(1),
+            proposal_id: Some(42),
+            voted_vks: true,
+            executed_proposal: true,
+            was_in_progress: true,
+            ..Default::default()
+        };
+        assert!(find_inconsistencies(1, &local, &chain).is_empty());
+    }
+
+    #[test]
+    fn outdated_local_state() {
+        let chain = chain_state(2, EpochState::PublicKeySubmission { resharing: false });
+        let local = PersistentState {
+            epoch_id: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            find_inconsistencies(2, &local, &chain),
+            vec![Inconsistency::OutdatedLocalState { local_epoch_id: 1 }]
+        );
+    }
+
+    #[test]
+    fn node_index_checks() {
+        let chain = chain_state(1, EpochState::DealingExchange { resharing: false });
+        let mut local = PersistentState {
+            epoch_id: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::MissingNodeIndex { chain_index: 2 }]
+        );
+
+        local.node_index = Some(3);
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::NodeIndexMismatch {
+                local_index: 3,
+                chain_index: 2
+            }]
+        );
+
+        let mut chain = chain;
+        chain.dealer_type = Some(DealerType::Past);
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::NotRegistered { local_index: 3 }]
+        );
+
+        // nobody has an index assigned yet while the keys are being submitted
+        chain.current_epoch.state = EpochState::PublicKeySubmission { resharing: false };
+        assert!(find_inconsistencies(1, &local, &chain).is_empty());
+    }
+
+    #[test]
+    fn dealing_checks() {
+        let mut chain = chain_state(
+            1,
+            EpochState::VerificationKeySubmission { resharing: false },
+        );
+        let local = PersistentState {
+            epoch_id: Some(1),
+            node_index: Some(2),
+            receiver_index: Some(0),
+            ..Default::default()
+        };
+        chain.submitted_dealings = Some(vec![0, 1]);
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::IncompleteDealings {
+                submitted: vec![0, 1]
+            }]
+        );
+
+        chain.submitted_dealings = Some(vec![]);
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::MissingDealings]
+        );
+
+        // not all the dealers submit dealings when resharing
+        chain.current_epoch.state = EpochState::VerificationKeySubmission { resharing: true };
+        assert!(find_inconsistencies(1, &local, &chain).is_empty());
+    }
+
+    #[test]
+    fn verification_key_share_checks() {
+        let mut chain = chain_state(1, EpochState::InProgress);
+        let mut local = PersistentState {
+            epoch_id: Some(1),
+            node_index: Some(2),
+            receiver_index: Some(0),
+            proposal_id: Some(42),
+            executed_proposal: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::MissingVerificationKeyShare { proposal_id: 42 }]
+        );
+
+        chain.verification_key_share = Some(vk_share(2, false));
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::UnverifiedVerificationKeyShare]
+        );
+
+        local.proposal_id = None;
+        assert_eq!(
+            find_inconsistencies(1, &local, &chain),
+            vec![Inconsistency::UntrackedVerificationKeyShare]
+        );
+    }
+
+    #[test]
+    fn past_epochs_only_check_verification_key_shares() {
+        let mut chain = chain_state(3, EpochState::DealingExchange { resharing: false });
+        chain.verification_key_share = Some(vk_share(2, true));
+        let local = PersistentState {
+            epoch_id: Some(2),
+            proposal_id: Some(42),
+            executed_proposal: true,
+            ..Default::default()
+        };
+        assert!(find_inconsistencies(2, &local, &chain).is_empty());
+    }
+}
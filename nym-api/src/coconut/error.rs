@@ -7,6 +7,7 @@ use rocket::{response, Request, Response};
 use std::io::Cursor;
 use thiserror::Error;
 
+use nym_coconut_dkg_common::types::EpochId;
 use nym_crypto::asymmetric::{
     encryption::KeyRecoveryError,
     identity::{Ed25519RecoveryError, SignatureError},
@@ -100,8 +101,28 @@ pub enum CoconutError {
     #[error("The coconut keypair is corrupted")]
     CorruptedCoconutKeyPair,
 
+    #[error("DKG epoch {epoch_id} has not started yet, the current epoch is {current_epoch_id}")]
+    DkgEpochNotStarted {
+        epoch_id: EpochId,
+        current_epoch_id: EpochId,
+    },
+
     #[error("There was a problem with the proposal id: {reason}")]
     ProposalIdError { reason: String },
+
+    #[error("The deposit was made for a ticket book of {tickets} tickets, but no ticket index was provided")]
+    MissingTicketIndex { tickets: u32 },
+
+    #[error("Ticket {index} does not exist in a ticket book of {tickets} tickets")]
+    InvalidTicketIndex { index: u32, tickets: u32 },
+
+    #[error("A ticket was requested for a deposit that was not made for a ticket book")]
+    UnexpectedTicketIndex,
+
+    #[error(
+        "Requested verification of {requested} credentials at once, but at most {max} are allowed"
+    )]
+    TooManyCredentials { requested: usize, max: usize },
 }
 
 impl<'r, 'o: 'r> Responder<'r, 'o> for CoconutError {
@@ -10,7 +10,9 @@ use crate::support::storage::NymApiStorage;
 use getset::{CopyGetters, Getters};
 use keypair::KeyPair;
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchVerifyCredentialsBody, BatchVerifyCredentialsResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    MAX_BATCH_VERIFICATION_SIZE,
 };
 use nym_coconut_bandwidth_contract_common::spend_credential::{
     funds_from_cosmos_msgs, SpendCredentialStatus,
@@ -18,7 +20,7 @@ use nym_coconut_bandwidth_contract_common::spend_credential::{
 use nym_coconut_dkg_common::types::EpochId;
 use nym_coconut_interface::KeyPair as CoconutKeyPair;
 use nym_coconut_interface::{
-    Attribute, BlindSignRequest, BlindedSignature, Parameters, VerificationKey,
+    Attribute, BlindSignRequest, BlindedSignature, Credential, Parameters, VerificationKey,
 };
 use nym_config::defaults::NYM_API_VERSION;
 use nym_credentials::coconut::params::{
@@ -182,7 +184,11 @@ impl InternalSignRequest {
             rocket.manage(state).mount(
                 // this format! is so ugly...
                 format!("/{}/{}/{}", NYM_API_VERSION, COCONUT_ROUTES, BANDWIDTH),
-                routes![post_blind_sign, verify_bandwidth_credential],
+                routes![
+                    post_blind_sign,
+                    verify_bandwidth_credential,
+                    batch_verify_bandwidth_credentials
+                ],
             )
         })
     }
@@ -205,10 +211,9 @@ pub async fn post_blind_sign(
     state: &RocketState<State>,
 ) -> Result<Json<BlindedSignatureResponse>> {
     debug!("{:?}", blind_sign_request_body);
-    if let Some(response) = state
-        .signed_before(blind_sign_request_body.tx_hash())
-        .await?
-    {
+    // each ticket of a ticket book is issued separately
+    let issuance_key = blind_sign_request_body.issuance_key();
+    if let Some(response) = state.signed_before(&issuance_key).await? {
         return Ok(Json(response));
     }
     let tx = state
@@ -228,21 +233,18 @@ pub async fn post_blind_sign(
     };
 
     let response = state
-        .encrypt_and_store(
-            blind_sign_request_body.tx_hash(),
-            &encryption_key,
-            &blinded_signature,
-        )
+        .encrypt_and_store(&issuance_key, &encryption_key, &blinded_signature)
         .await?;
 
     Ok(Json(response))
 }
 
-#[post("/verify-bandwidth-credential", data = "<verify_credential_body>")]
-pub async fn verify_bandwidth_credential(
-    verify_credential_body: Json<VerifyCredentialBody>,
-    state: &RocketState<State>,
-) -> Result<Json<VerifyCredentialResponse>> {
+// checks whether the proposal associated with the credential is valid and the credential
+// is in the process of being spent. Returns the amount of funds the proposal is meant to release.
+async fn check_spending_proposal(
+    state: &State,
+    verify_credential_body: &VerifyCredentialBody,
+) -> Result<Coin> {
     let proposal_id = *verify_credential_body.proposal_id();
     let proposal = state.client.get_proposal(proposal_id).await?;
     // Proposal description is the blinded serial number
@@ -273,24 +275,20 @@ pub async fn verify_bandwidth_credential(
             status: format!("{:?}", credential_status),
         });
     }
-    let verification_key = state
-        .verification_key(*verify_credential_body.credential().epoch_id())
-        .await?;
-    let mut vote_yes = verify_credential_body
-        .credential()
-        .verify(&verification_key);
 
-    vote_yes &= Coin::from(proposed_release_funds)
-        == Coin::new(
-            verify_credential_body.credential().voucher_value() as u128,
-            state.mix_denom.clone(),
-        );
+    Ok(Coin::from(proposed_release_funds))
+}
 
-    // Vote yes or no on the proposal based on the verification result
+// Vote yes or no on the proposal based on the verification result
+async fn vote_on_spending_proposal(
+    state: &State,
+    verify_credential_body: &VerifyCredentialBody,
+    vote_yes: bool,
+) -> Result<()> {
     let ret = state
         .client
         .vote_proposal(
-            proposal_id,
+            *verify_credential_body.proposal_id(),
             vote_yes,
             Some(Fee::new_payer_granter_auto(
                 None,
@@ -299,7 +297,93 @@ pub async fn verify_bandwidth_credential(
             )),
         )
         .await;
-    accepted_vote_err(ret)?;
+    accepted_vote_err(ret)
+}
+
+#[post("/verify-bandwidth-credential", data = "<verify_credential_body>")]
+pub async fn verify_bandwidth_credential(
+    verify_credential_body: Json<VerifyCredentialBody>,
+    state: &RocketState<State>,
+) -> Result<Json<VerifyCredentialResponse>> {
+    let proposed_release_funds = check_spending_proposal(state, &verify_credential_body).await?;
+    let verification_key = state
+        .verification_key(*verify_credential_body.credential().epoch_id())
+        .await?;
+    let mut vote_yes = verify_credential_body
+        .credential()
+        .verify(&verification_key);
+
+    vote_yes &= proposed_release_funds
+        == Coin::new(
+            verify_credential_body.credential().voucher_value() as u128,
+            state.mix_denom.clone(),
+        );
+
+    vote_on_spending_proposal(state, &verify_credential_body, vote_yes).await?;
 
     Ok(Json(VerifyCredentialResponse::new(vote_yes)))
 }
+
+#[post("/verify-bandwidth-credentials", data = "<verify_credentials_body>")]
+pub async fn batch_verify_bandwidth_credentials(
+    verify_credentials_body: Json<BatchVerifyCredentialsBody>,
+    state: &RocketState<State>,
+) -> Result<Json<BatchVerifyCredentialsResponse>> {
+    let requests = verify_credentials_body.credentials();
+    if requests.len() > MAX_BATCH_VERIFICATION_SIZE {
+        return Err(CoconutError::TooManyCredentials {
+            requested: requests.len(),
+            max: MAX_BATCH_VERIFICATION_SIZE,
+        });
+    }
+
+    let mut verification_results = vec![false; requests.len()];
+
+    // perform all the cheap checks first. Unlike the single credential endpoint, a failure of
+    // a particular credential does not fail the whole request, it's just reported as unverified
+    let mut checked = Vec::with_capacity(requests.len());
+    for (index, request) in requests.iter().enumerate() {
+        let proposed_release_funds = match check_spending_proposal(state, request).await {
+            Ok(funds) => funds,
+            Err(err) => {
+                warn!(
+                    "credential with proposal {} failed the preliminary checks - {err}",
+                    request.proposal_id()
+                );
+                continue;
+            }
+        };
+        let verification_key = state
+            .verification_key(*request.credential().epoch_id())
+            .await?;
+        let matching_funds = proposed_release_funds
+            == Coin::new(
+                request.credential().voucher_value() as u128,
+                state.mix_denom.clone(),
+            );
+        checked.push((index, verification_key, matching_funds));
+    }
+
+    let to_verify = checked
+        .iter()
+        .map(|(index, verification_key, _)| (requests[*index].credential(), verification_key))
+        .collect::<Vec<_>>();
+    let invalid = Credential::find_invalid(&to_verify);
+
+    for (position, (index, _, matching_funds)) in checked.iter().enumerate() {
+        let request = &requests[*index];
+        let vote_yes = *matching_funds && !invalid.contains(&position);
+        if let Err(err) = vote_on_spending_proposal(state, request, vote_yes).await {
+            warn!(
+                "failed to vote on proposal {} - {err}",
+                request.proposal_id()
+            );
+            continue;
+        }
+        verification_results[*index] = vote_yes;
+    }
+
+    Ok(Json(BatchVerifyCredentialsResponse::new(
+        verification_results,
+    )))
+}
@@ -5,7 +5,9 @@ use super::InternalSignRequest;
 use crate::coconut::error::{CoconutError, Result};
 use cosmwasm_std::{to_binary, Addr, CosmosMsg, Decimal, WasmMsg};
 use nym_api_requests::coconut::{
-    BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    BatchVerifyCredentialsBody, BatchVerifyCredentialsResponse, BlindSignRequestBody,
+    BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
+    MAX_BATCH_VERIFICATION_SIZE,
 };
 use nym_coconut::tests::helpers::theta_from_keys_and_attributes;
 use nym_coconut::{prepare_blind_sign, ttp_keygen, Base58, BlindedSignature, Parameters};
@@ -25,7 +27,8 @@ use nym_credentials::coconut::params::{
 use nym_crypto::shared_key::recompute_shared_key;
 use nym_crypto::symmetric::stream_cipher;
 use nym_validator_client::nym_api::routes::{
-    API_VERSION, BANDWIDTH, COCONUT_BLIND_SIGN, COCONUT_ROUTES, COCONUT_VERIFY_BANDWIDTH_CREDENTIAL,
+    API_VERSION, BANDWIDTH, COCONUT_BATCH_VERIFY_BANDWIDTH_CREDENTIALS, COCONUT_BLIND_SIGN,
+    COCONUT_ROUTES, COCONUT_VERIFY_BANDWIDTH_CREDENTIAL,
 };
 use nym_validator_client::nyxd::Coin;
 use nym_validator_client::nyxd::{tx::Hash, AccountId, DeliverTx, Event, Fee, Tag, TxResponse};
@@ -1146,3 +1149,161 @@ async fn verification_of_bandwidth_credential() {
         .to_string()
     );
 }
+
+#[tokio::test]
+async fn batch_verification_of_bandwidth_credentials() {
+    let validator_address = AccountId::from_str(TEST_REWARDING_VALIDATOR_ADDRESS).unwrap();
+    let proposal_db = Arc::new(RwLock::new(HashMap::new()));
+    let spent_credential_db = Arc::new(RwLock::new(HashMap::new()));
+    let nyxd_client = DummyClient::new(validator_address.clone())
+        .with_proposal_db(&proposal_db)
+        .with_spent_credential_db(&spent_credential_db);
+    let mut db_dir = std::env::temp_dir();
+    let params = Parameters::new(4).unwrap();
+    let mut key_pairs = ttp_keygen(&params, 1, 1).unwrap();
+    let voucher_value = 1234u64;
+    let voucher_info = "voucher info";
+    let public_attributes = vec![
+        hash_to_scalar(voucher_value.to_string()),
+        hash_to_scalar(voucher_info),
+    ];
+    let indices = vec![1u64];
+    let key_pair = key_pairs.remove(0);
+    db_dir.push(format!(
+        "batch-{}",
+        &key_pair.verification_key().to_bs58()[..8]
+    ));
+    let storage = NymApiStorage::init(db_dir).await.unwrap();
+    let comm_channel = DummyCommunicationChannel::new(key_pair.verification_key());
+    let mut signing_key_pairs = vec![key_pair];
+
+    let funds = Coin::new(voucher_value as u128, TEST_COIN_DENOM);
+    let release_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: String::new(),
+        msg: to_binary(
+            &nym_coconut_bandwidth_contract_common::msg::ExecuteMsg::ReleaseFunds {
+                funds: funds.clone().into(),
+            },
+        )
+        .unwrap(),
+        funds: vec![],
+    });
+
+    // three valid credentials with their spending proposals
+    let mut requests = Vec::new();
+    for proposal_id in 1..=3u64 {
+        let theta = theta_from_keys_and_attributes(
+            &params,
+            &signing_key_pairs,
+            &indices,
+            &public_attributes,
+        )
+        .unwrap();
+        let credential = Credential::new(4, theta, voucher_value, voucher_info.to_string(), 0);
+        proposal_db.write().unwrap().insert(
+            proposal_id,
+            ProposalResponse {
+                id: proposal_id,
+                title: String::new(),
+                description: credential.blinded_serial_number(),
+                msgs: vec![release_msg.clone()],
+                status: cw3::Status::Open,
+                expires: cw_utils::Expiration::Never {},
+                threshold: cw_utils::ThresholdResponse::AbsolutePercentage {
+                    percentage: Decimal::from_ratio(2u32, 3u32),
+                    total_weight: 100,
+                },
+            },
+        );
+        spent_credential_db.write().unwrap().insert(
+            credential.blinded_serial_number(),
+            SpendCredentialResponse::new(Some(SpendCredential::new(
+                funds.clone().into(),
+                credential.blinded_serial_number(),
+                Addr::unchecked("unimportant"),
+            ))),
+        );
+        requests.push((credential, proposal_id));
+    }
+
+    let staged_key_pair = crate::coconut::KeyPair::new();
+    staged_key_pair.set(Some(signing_key_pairs.remove(0))).await;
+    let rocket = rocket::build().attach(InternalSignRequest::stage(
+        nyxd_client.clone(),
+        TEST_COIN_DENOM.to_string(),
+        staged_key_pair,
+        comm_channel.clone(),
+        storage.clone(),
+    ));
+    let client = Client::tracked(rocket)
+        .await
+        .expect("valid rocket instance");
+
+    // the second credential gets tampered with, while the third one has no associated proposal
+    let (mut credentials, _): (Vec<_>, Vec<_>) = requests.iter().cloned().unzip();
+    credentials[1] = Credential::new(
+        4,
+        credentials[1].theta().clone(),
+        voucher_value,
+        String::from("bad voucher info"),
+        0,
+    );
+    proposal_db.write().unwrap().remove(&3);
+
+    let req = BatchVerifyCredentialsBody::new(
+        credentials
+            .into_iter()
+            .zip(requests.iter().map(|(_, proposal_id)| *proposal_id))
+            .map(|(credential, proposal_id)| {
+                VerifyCredentialBody::new(credential, proposal_id, validator_address.clone())
+            })
+            .collect(),
+    );
+
+    let response = client
+        .post(format!(
+            "/{}/{}/{}/{}",
+            API_VERSION, COCONUT_ROUTES, BANDWIDTH, COCONUT_BATCH_VERIFY_BANDWIDTH_CREDENTIALS
+        ))
+        .json(&req)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    let batch_response = serde_json::from_str::<BatchVerifyCredentialsResponse>(
+        &response.into_string().await.unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        batch_response.verification_results,
+        vec![true, false, false]
+    );
+
+    let proposals = proposal_db.read().unwrap();
+    assert_eq!(cw3::Status::Passed, proposals.get(&1).unwrap().status);
+    assert_eq!(cw3::Status::Rejected, proposals.get(&2).unwrap().status);
+    drop(proposals);
+
+    // oversized batches are rejected outright
+    let oversized = BatchVerifyCredentialsBody::new(
+        (0..=MAX_BATCH_VERIFICATION_SIZE)
+            .map(|_| VerifyCredentialBody::new(requests[0].0.clone(), 1, validator_address.clone()))
+            .collect(),
+    );
+    let response = client
+        .post(format!(
+            "/{}/{}/{}/{}",
+            API_VERSION, COCONUT_ROUTES, BANDWIDTH, COCONUT_BATCH_VERIFY_BANDWIDTH_CREDENTIALS
+        ))
+        .json(&oversized)
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::BadRequest);
+    assert_eq!(
+        response.into_string().await.unwrap(),
+        CoconutError::TooManyCredentials {
+            requested: MAX_BATCH_VERIFICATION_SIZE + 1,
+            max: MAX_BATCH_VERIFICATION_SIZE,
+        }
+        .to_string()
+    );
+}
@@ -42,13 +42,37 @@ pub enum RewardingError {
         #[from]
         source: std::num::TryFromIntError,
     },
-    #[error("{source}")]
-    WeightedError {
-        #[from]
-        source: rand::distributions::WeightedError,
+
+    #[error("the mixnet contract has not fixed the rewarded set selection seed for epoch {absolute_epoch_id}")]
+    UnavailableSelectionSeed { absolute_epoch_id: u32 },
+
+    #[error("the rewarded set selection seed for epoch {absolute_epoch_id} ({seed}) is malformed")]
+    MalformedSelectionSeed {
+        absolute_epoch_id: u32,
+        seed: String,
     },
 }
 
+#[derive(Debug, Error)]
+pub enum SelectionVerificationError {
+    #[error("the rewarded set has been selected with an unsupported algorithm version {version}")]
+    UnsupportedAlgorithmVersion { version: u32 },
+
+    #[error(
+        "the recorded selection seed ({recorded}) does not match the expected value of {expected}"
+    )]
+    SeedMismatch { expected: String, recorded: String },
+
+    #[error("the recorded candidates commitment ({recorded}) does not match the expected value of {expected}")]
+    CandidatesCommitmentMismatch { expected: String, recorded: String },
+
+    #[error("the active set does not match the one recomputed from the selection inputs")]
+    ActiveSetMismatch,
+
+    #[error("the standby set does not match the one recomputed from the selection inputs")]
+    StandbySetMismatch,
+}
+
 impl From<NyxdError> for RewardingError {
     fn from(err: NyxdError) -> Self {
         RewardingError::ContractExecutionFailure(err)
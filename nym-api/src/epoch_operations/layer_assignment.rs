@@ -0,0 +1,410 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Assignment of the nodes in the rewarded set into mix layers.
+//!
+//! Every provided node is always assigned to some layer. Members of the same family are always
+//! put on the same layer and, apart from that, layers are kept as balanced as possible: the
+//! difference between the biggest and the smallest layer never exceeds the size of the biggest family
+//! (or 1 if there are no families). Optionally, out of the least populated layers, nodes are put on the one
+//! with the fewest nodes sharing their ASN or country.
+
+use crate::support::config::LayerSpreading;
+use nym_mixnet_contract_common::{Layer, LayerAssignment, MixId};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display, Formatter};
+
+const LAYERS: [Layer; 3] = [Layer::One, Layer::Two, Layer::Three];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct NodeLocation {
+    pub(crate) asn: Option<u32>,
+    pub(crate) country: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct LayerCandidate {
+    pub(crate) mix_id: MixId,
+
+    /// Identity of the head of the family this node belongs to (if any).
+    pub(crate) family: Option<String>,
+
+    pub(crate) location: Option<NodeLocation>,
+}
+
+impl LayerCandidate {
+    fn spreading_group(&self, spreading: LayerSpreading) -> Option<String> {
+        let location = self.location.as_ref()?;
+        match spreading {
+            LayerSpreading::None => None,
+            LayerSpreading::Asn => location.asn.map(|asn| format!("AS{asn}")),
+            LayerSpreading::Country => location.country.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AssignmentReason {
+    /// The node belongs to a family whose all members got put on the least populated layer.
+    Family { head: String, members: usize },
+
+    /// The node got put on the least populated layer.
+    SmallestLayer,
+
+    /// Out of the least populated layers, the node got put on the one with the fewest nodes from the same group.
+    Spreading { group: String, shared: usize },
+}
+
+impl Display for AssignmentReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignmentReason::Family { head, members } => {
+                write!(f, "member of family {head} ({members} nodes in this set)")
+            }
+            AssignmentReason::SmallestLayer => write!(f, "least populated layer"),
+            AssignmentReason::Spreading { group, shared } => write!(
+                f,
+                "least populated layer with fewest nodes from {group} ({shared} already present)"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LayerDecision {
+    pub(crate) mix_id: MixId,
+    pub(crate) layer: Layer,
+    pub(crate) reason: AssignmentReason,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LayerAssignmentReport {
+    pub(crate) decisions: Vec<LayerDecision>,
+}
+
+impl LayerAssignmentReport {
+    pub(crate) fn assignments(&self) -> Vec<LayerAssignment> {
+        self.decisions
+            .iter()
+            .map(|decision| LayerAssignment::new(decision.mix_id, decision.layer))
+            .collect()
+    }
+
+    pub(crate) fn layer_sizes(&self) -> [usize; 3] {
+        let mut sizes = [0; 3];
+        for decision in &self.decisions {
+            sizes[decision.layer as usize - 1] += 1;
+        }
+        sizes
+    }
+
+    pub(crate) fn imbalance(&self) -> usize {
+        let sizes = self.layer_sizes();
+        // the unwraps are fine as the array is not empty
+        sizes.iter().max().unwrap() - sizes.iter().min().unwrap()
+    }
+}
+
+impl Display for LayerAssignmentReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [layer1, layer2, layer3] = self.layer_sizes();
+        let family_nodes = self
+            .decisions
+            .iter()
+            .filter(|d| matches!(d.reason, AssignmentReason::Family { .. }))
+            .count();
+        let spread_nodes = self
+            .decisions
+            .iter()
+            .filter(|d| matches!(d.reason, AssignmentReason::Spreading { .. }))
+            .count();
+
+        write!(
+            f,
+            "assigned {} nodes (layer 1: {layer1}, layer 2: {layer2}, layer 3: {layer3}, imbalance: {}); {family_nodes} placed with their families, {spread_nodes} spread by location",
+            self.decisions.len(),
+            self.imbalance(),
+        )
+    }
+}
+
+#[derive(Default)]
+struct LayersState {
+    sizes: [usize; 3],
+    groups: [HashMap<String, usize>; 3],
+}
+
+impl LayersState {
+    // out of the least populated layers choose the one with the fewest nodes sharing the provided groups
+    fn choose_layer(&self, groups: &[String]) -> (usize, usize) {
+        let smallest = self.sizes.iter().min().copied().unwrap_or_default();
+
+        (0..LAYERS.len())
+            .filter(|&i| self.sizes[i] == smallest)
+            .map(|i| {
+                let shared = groups
+                    .iter()
+                    .map(|group| self.groups[i].get(group).copied().unwrap_or_default())
+                    .sum::<usize>();
+                (i, shared)
+            })
+            .min_by_key(|&(i, shared)| (shared, i))
+            .expect("there's always at least a single smallest layer")
+    }
+
+    fn add(&mut self, layer_index: usize, group: Option<&String>) {
+        self.sizes[layer_index] += 1;
+        if let Some(group) = group {
+            *self.groups[layer_index].entry(group.clone()).or_default() += 1;
+        }
+    }
+}
+
+pub(crate) fn assign_layers(
+    nodes: &[LayerCandidate],
+    spreading: LayerSpreading,
+) -> LayerAssignmentReport {
+    let mut families: BTreeMap<&str, Vec<&LayerCandidate>> = BTreeMap::new();
+    let mut regular_nodes = Vec::new();
+    for node in nodes {
+        match &node.family {
+            Some(head) => families.entry(head.as_str()).or_default().push(node),
+            None => regular_nodes.push(node),
+        }
+    }
+
+    let mut state = LayersState::default();
+    let mut decisions = Vec::with_capacity(nodes.len());
+
+    // place the biggest families first so that the smaller ones and the regular nodes could even out the layers
+    let mut families = families.into_iter().collect::<Vec<_>>();
+    families.sort_by(|(head_a, members_a), (head_b, members_b)| {
+        members_b
+            .len()
+            .cmp(&members_a.len())
+            .then_with(|| head_a.cmp(head_b))
+    });
+
+    for (head, members) in families {
+        let groups = members
+            .iter()
+            .filter_map(|member| member.spreading_group(spreading))
+            .collect::<Vec<_>>();
+        let (layer_index, _) = state.choose_layer(&groups);
+
+        for member in &members {
+            state.add(layer_index, member.spreading_group(spreading).as_ref());
+            decisions.push(LayerDecision {
+                mix_id: member.mix_id,
+                layer: LAYERS[layer_index],
+                reason: AssignmentReason::Family {
+                    head: head.to_string(),
+                    members: members.len(),
+                },
+            })
+        }
+    }
+
+    // nodes sharing the same group are placed one after another so that they'd end up on different layers
+    let mut regular_nodes = regular_nodes
+        .into_iter()
+        .map(|node| (node.spreading_group(spreading), node.mix_id))
+        .collect::<Vec<_>>();
+    regular_nodes.sort();
+
+    for (group, mix_id) in regular_nodes {
+        let groups = group.iter().cloned().collect::<Vec<_>>();
+        let (layer_index, shared) = state.choose_layer(&groups);
+        state.add(layer_index, group.as_ref());
+
+        let reason = match group {
+            Some(group) => AssignmentReason::Spreading { group, shared },
+            None => AssignmentReason::SmallestLayer,
+        };
+        decisions.push(LayerDecision {
+            mix_id,
+            layer: LAYERS[layer_index],
+            reason,
+        })
+    }
+
+    LayerAssignmentReport { decisions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha20Rng;
+    use std::collections::HashSet;
+
+    fn random_nodes<R: Rng>(rng: &mut R, families: usize, groups: u32) -> Vec<LayerCandidate> {
+        let set_size = rng.gen_range(0..300);
+        (0..set_size)
+            .map(|mix_id| {
+                let family = if families > 0 && rng.gen_bool(0.3) {
+                    Some(format!("family{}", rng.gen_range(0..families)))
+                } else {
+                    None
+                };
+                let location = if rng.gen_bool(0.8) {
+                    Some(NodeLocation {
+                        asn: Some(rng.gen_range(0..groups)),
+                        country: Some(format!("C{}", rng.gen_range(0..groups))),
+                    })
+                } else {
+                    None
+                };
+                LayerCandidate {
+                    mix_id,
+                    family,
+                    location,
+                }
+            })
+            .collect()
+    }
+
+    fn layers_by_node(report: &LayerAssignmentReport) -> HashMap<MixId, Layer> {
+        report
+            .decisions
+            .iter()
+            .map(|decision| (decision.mix_id, decision.layer))
+            .collect()
+    }
+
+    #[test]
+    fn every_node_is_assigned_exactly_once() {
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        for spreading in [
+            LayerSpreading::None,
+            LayerSpreading::Asn,
+            LayerSpreading::Country,
+        ] {
+            for _ in 0..200 {
+                let families = rng.gen_range(0..10);
+                let groups = rng.gen_range(1..8);
+                let nodes = random_nodes(&mut rng, families, groups);
+
+                let report = assign_layers(&nodes, spreading);
+                assert_eq!(report.decisions.len(), nodes.len());
+
+                let assigned = report
+                    .decisions
+                    .iter()
+                    .map(|d| d.mix_id)
+                    .collect::<HashSet<_>>();
+                let expected = nodes.iter().map(|n| n.mix_id).collect::<HashSet<_>>();
+                assert_eq!(assigned, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn families_are_kept_on_single_layer() {
+        let mut rng = ChaCha20Rng::seed_from_u64(2);
+        for _ in 0..500 {
+            let families = rng.gen_range(1..10);
+            let nodes = random_nodes(&mut rng, families, 4);
+            let report = assign_layers(&nodes, LayerSpreading::Asn);
+            let layers = layers_by_node(&report);
+
+            let mut family_layers: HashMap<&str, Layer> = HashMap::new();
+            for node in &nodes {
+                if let Some(family) = &node.family {
+                    let layer = layers[&node.mix_id];
+                    assert_eq!(*family_layers.entry(family).or_insert(layer), layer);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn layers_are_balanced() {
+        let mut rng = ChaCha20Rng::seed_from_u64(3);
+        for _ in 0..500 {
+            let families = rng.gen_range(0..10);
+            let nodes = random_nodes(&mut rng, families, 4);
+
+            let mut family_sizes: HashMap<&str, usize> = HashMap::new();
+            for family in nodes.iter().filter_map(|n| n.family.as_deref()) {
+                *family_sizes.entry(family).or_default() += 1;
+            }
+            let biggest_family = family_sizes.values().copied().max().unwrap_or_default();
+
+            let report = assign_layers(&nodes, LayerSpreading::Country);
+            assert!(report.imbalance() <= biggest_family.max(1));
+        }
+    }
+
+    #[test]
+    fn nodes_from_same_group_are_spread_across_layers() {
+        let mut rng = ChaCha20Rng::seed_from_u64(4);
+        for _ in 0..500 {
+            let groups = rng.gen_range(1..8);
+            let nodes = random_nodes(&mut rng, 0, groups);
+            let report = assign_layers(&nodes, LayerSpreading::Asn);
+            let layers = layers_by_node(&report);
+
+            let mut per_group: HashMap<u32, [usize; 3]> = HashMap::new();
+            for node in &nodes {
+                if let Some(asn) = node.location.as_ref().and_then(|l| l.asn) {
+                    per_group.entry(asn).or_default()[layers[&node.mix_id] as usize - 1] += 1;
+                }
+            }
+
+            for counts in per_group.values() {
+                let max = counts.iter().max().unwrap();
+                let min = counts.iter().min().unwrap();
+                assert!(max - min <= 1, "{counts:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn report_explains_the_decisions() {
+        let nodes = vec![
+            LayerCandidate {
+                mix_id: 1,
+                family: Some("head".to_string()),
+                location: None,
+            },
+            LayerCandidate {
+                mix_id: 2,
+                family: Some("head".to_string()),
+                location: None,
+            },
+            LayerCandidate {
+                mix_id: 3,
+                family: None,
+                location: None,
+            },
+            LayerCandidate {
+                mix_id: 4,
+                family: None,
+                location: Some(NodeLocation {
+                    asn: Some(1234),
+                    country: None,
+                }),
+            },
+        ];
+
+        let report = assign_layers(&nodes, LayerSpreading::Asn);
+        assert_eq!(report.layer_sizes(), [2, 1, 1]);
+        assert_eq!(
+            report.decisions[0].reason,
+            AssignmentReason::Family {
+                head: "head".to_string(),
+                members: 2
+            }
+        );
+        assert_eq!(report.decisions[2].reason, AssignmentReason::SmallestLayer);
+        assert_eq!(
+            report.decisions[3].reason,
+            AssignmentReason::Spreading {
+                group: "AS1234".to_string(),
+                shared: 0
+            }
+        );
+    }
+}
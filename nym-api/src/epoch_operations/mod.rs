@@ -12,8 +12,10 @@
 // 3. Eventually this whole procedure is going to get expanded to allow for distribution of rewarded set generation
 //    and hence this might be a good place for it.
 
+use crate::network_events::NetworkEvents;
 use crate::node_status_api::ONE_DAY;
 use crate::nym_contract_cache::cache::NymContractCache;
+use crate::support::config;
 use crate::support::nyxd::Client;
 use crate::support::storage::NymApiStorage;
 use error::RewardingError;
@@ -27,7 +29,9 @@ use tokio::time::sleep;
 pub(crate) mod error;
 mod event_reconciliation;
 mod helpers;
+mod layer_assignment;
 mod rewarded_set_assignment;
+pub(crate) mod rewarded_set_selection;
 mod rewarding;
 mod transition_beginning;
 
@@ -35,6 +39,8 @@ pub struct RewardedSetUpdater {
     nyxd_client: Client,
     nym_contract_cache: NymContractCache,
     storage: NymApiStorage,
+    events: NetworkEvents,
+    layer_assignment: config::LayerAssignment,
 }
 
 impl RewardedSetUpdater {
@@ -48,11 +54,15 @@ impl RewardedSetUpdater {
         nyxd_client: Client,
         nym_contract_cache: NymContractCache,
         storage: NymApiStorage,
+        events: NetworkEvents,
+        layer_assignment: config::LayerAssignment,
     ) -> Self {
         RewardedSetUpdater {
             nyxd_client,
             nym_contract_cache,
             storage,
+            events,
+            layer_assignment,
         }
     }
 
@@ -78,10 +88,11 @@ impl RewardedSetUpdater {
     ///    `ReconcileEpochEvents` transaction until all of them are resolved.
     ///    At this point the mixnet contract automatically transitions the state to `AdvancingEpoch`.
     /// 5. it obtains the list of all nodes on the network and pseudorandomly (but weighted by total stake)
-    ///    determines the new rewarded set. It then assigns layers to the provided nodes taking
-    ///    family information into consideration. Finally it sends `AdvanceCurrentEpoch` message
-    ///    containing the set and layer information thus rolling over the epoch and changing the state
-    ///    to `InProgress`.
+    ///    determines the new rewarded set using a seed derived from the latest block hash, so that
+    ///    anyone could verify the selection afterwards. It then assigns layers to all of the provided nodes
+    ///    keeping families together (and optionally spreading nodes by their location). Finally it sends
+    ///    `AdvanceCurrentEpoch` message containing the set and layer information thus rolling over the epoch
+    ///    and changing the state to `InProgress`.
     /// 6. it purges old (older than 48h) measurement data
     /// 7. the whole process repeats once the new epoch finishes
     async fn perform_epoch_operations(&self, interval: Interval) -> Result<(), RewardingError> {
@@ -263,12 +274,16 @@ impl RewardedSetUpdater {
         nyxd_client: Client,
         nym_contract_cache: &NymContractCache,
         storage: &NymApiStorage,
+        events: &NetworkEvents,
+        layer_assignment: &config::LayerAssignment,
         shutdown: &TaskManager,
     ) {
         let mut rewarded_set_updater = RewardedSetUpdater::new(
             nyxd_client,
             nym_contract_cache.to_owned(),
             storage.to_owned(),
+            events.clone(),
+            layer_assignment.clone(),
         );
         let shutdown_listener = shutdown.subscribe();
         tokio::spawn(async move { rewarded_set_updater.run(shutdown_listener).await });
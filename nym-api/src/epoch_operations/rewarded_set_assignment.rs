@@ -2,17 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::epoch_operations::error::RewardingError;
-use crate::epoch_operations::helpers::stake_to_f64;
+use crate::epoch_operations::layer_assignment::{assign_layers, LayerCandidate, NodeLocation};
+use crate::epoch_operations::rewarded_set_selection::{
+    candidates_commitment, select_rewarded_set, SelectionCandidate, SelectionSeed,
+    SELECTION_ALGORITHM_VERSION,
+};
+use crate::support::config::LayerSpreading;
+use crate::support::storage::models::{
+    RewardedSetSelectionCandidateRecord, RewardedSetSelectionRecord,
+};
 use crate::RewardedSetUpdater;
 use cosmwasm_std::Decimal;
 use nym_mixnet_contract_common::families::FamilyHead;
 use nym_mixnet_contract_common::reward_params::Performance;
 use nym_mixnet_contract_common::{
-    EpochState, IdentityKey, Interval, Layer, LayerAssignment, MixId, MixNodeDetails,
+    EpochState, IdentityKey, Interval, LayerAssignment, MixId, MixNodeDetails, RewardedSetSelection,
 };
-use rand::prelude::SliceRandom;
-use rand::rngs::OsRng;
 use std::collections::HashMap;
+use std::convert::TryInto;
 
 #[derive(Debug, Clone)]
 struct MixnodeWithStakeAndPerformance {
@@ -23,111 +30,119 @@ struct MixnodeWithStakeAndPerformance {
 }
 
 impl MixnodeWithStakeAndPerformance {
-    fn to_selection_weight(&self) -> f64 {
+    // the weight is expressed as an integer so that anyone would be able to recompute the selection
+    // without having to worry about floating point inconsistencies
+    fn to_selection_candidate(&self) -> SelectionCandidate {
         let scaled_stake = self.total_stake * self.performance;
-        stake_to_f64(scaled_stake)
+        let weight = scaled_stake.numerator().u128() / scaled_stake.denominator().u128();
+        SelectionCandidate::new(self.mix_id, weight.try_into().unwrap_or(u64::MAX))
     }
 }
 
 impl RewardedSetUpdater {
-    // Needs to run for active and reserve sets separatley, as it does not preserve order
+    fn load_node_locations(&self) -> HashMap<IdentityKey, NodeLocation> {
+        if self.layer_assignment.spreading == LayerSpreading::None {
+            return HashMap::new();
+        }
+
+        let path = match &self.layer_assignment.node_locations_path {
+            Some(path) => path,
+            None => {
+                warn!("layer spreading is enabled, but no node locations file has been specified");
+                return HashMap::new();
+            }
+        };
+
+        match std::fs::read(path)
+            .map_err(|err| err.to_string())
+            .and_then(|content| serde_json::from_slice(&content).map_err(|err| err.to_string()))
+        {
+            Ok(locations) => locations,
+            Err(err) => {
+                warn!(
+                    "failed to load node locations from {} - {err}. Nodes are not going to be spread by their location",
+                    path.display()
+                );
+                HashMap::new()
+            }
+        }
+    }
+
+    // Needs to run for active and reserve sets separately, as it does not preserve order
     async fn determine_layers(
         &self,
         set: &[MixnodeWithStakeAndPerformance],
-    ) -> Result<Vec<LayerAssignment>, RewardingError> {
-        let mut assignments = Vec::with_capacity(set.len());
-        let target_layer_count = set.len() / 3;
-
+        node_locations: &HashMap<IdentityKey, NodeLocation>,
+    ) -> Vec<LayerAssignment> {
         let mix_to_family = self.nym_contract_cache.mix_to_family().await.to_vec();
 
         let mix_to_family = mix_to_family
             .into_iter()
             .collect::<HashMap<IdentityKey, FamilyHead>>();
 
-        let mut regular_nodes = Vec::with_capacity(set.len());
-
-        let mut families = HashMap::new();
-
-        for node in set.iter() {
-            if let Some(fh) = mix_to_family.get(&node.identity) {
-                let family: &mut Vec<u32> = families.entry(fh.identity()).or_default();
-                family.push(node.mix_id)
-            } else {
-                regular_nodes.push(node.mix_id)
-            }
-        }
-
-        let mut layers = HashMap::new();
-        layers.insert(Layer::One, Vec::with_capacity(target_layer_count));
-        layers.insert(Layer::Two, Vec::with_capacity(target_layer_count));
-        layers.insert(Layer::Three, Vec::with_capacity(target_layer_count));
-
-        // Assign all members of a family to same layer
-        for (_head, members) in families.iter_mut() {
-            let smallest_layer = layers
-                .iter()
-                .min_by_key(|(_layer, members)| members.len())
-                .map(|(layer, _members)| *layer)
-                .unwrap_or(Layer::One);
-
-            let entry = layers.entry(smallest_layer).or_default();
-            if entry.len() + members.len() <= target_layer_count {
-                entry.extend_from_slice(members)
-            }
-        }
+        let candidates = set
+            .iter()
+            .map(|node| LayerCandidate {
+                mix_id: node.mix_id,
+                family: mix_to_family
+                    .get(&node.identity)
+                    .map(|head| head.identity().to_owned()),
+                location: node_locations.get(&node.identity).cloned(),
+            })
+            .collect::<Vec<_>>();
 
-        // Assign nodes with no families into layers
-        for mix_id in regular_nodes.drain(..) {
-            let smallest_layer = layers
-                .iter()
-                .min_by_key(|(_layer, members)| members.len())
-                .map(|(layer, _members)| *layer)
-                .unwrap_or(Layer::One);
-
-            let entry = layers.entry(smallest_layer).or_default();
-            if entry.len() < target_layer_count {
-                entry.push(mix_id)
-            }
+        let report = assign_layers(&candidates, self.layer_assignment.spreading);
+        info!("Layer assignment: {report}");
+        for decision in &report.decisions {
+            debug!(
+                "mixnode {} assigned to layer {}: {}",
+                decision.mix_id, decision.layer as u8, decision.reason
+            );
         }
 
-        for (layer, members) in layers {
-            let layer_assignments = members
-                .into_iter()
-                .map(|mix_id| LayerAssignment::new(mix_id, layer));
-            assignments.extend(layer_assignments);
-        }
-        Ok(assignments)
+        report.assignments()
     }
 
     fn determine_rewarded_set(
         &self,
         mixnodes: Vec<MixnodeWithStakeAndPerformance>,
+        candidates: &[SelectionCandidate],
+        seed: SelectionSeed,
         nodes_to_select: u32,
-    ) -> Result<Vec<MixnodeWithStakeAndPerformance>, RewardingError> {
-        if mixnodes.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let mut rng = OsRng;
+    ) -> Vec<MixnodeWithStakeAndPerformance> {
+        let mut mixnodes = mixnodes
+            .into_iter()
+            .map(|mix| (mix.mix_id, mix))
+            .collect::<HashMap<_, _>>();
 
-        // generate list of mixnodes and their relatively weight (by total stake)
-        let choices = mixnodes
+        // note: the selection order is important as the first nodes are going to end up in the active set
+        select_rewarded_set(candidates, seed, nodes_to_select as usize)
             .into_iter()
-            .map(|mix| {
-                let weight = mix.to_selection_weight();
-                (mix, weight)
-            })
-            .collect::<Vec<_>>();
+            .filter_map(|mix_id| mixnodes.remove(&mix_id))
+            .collect()
+    }
+
+    // the seed has been fixed by the contract when the epoch transition began
+    async fn selection_seed(
+        &self,
+        absolute_epoch_id: u32,
+    ) -> Result<(u64, SelectionSeed), RewardingError> {
+        let seed = self
+            .nyxd_client
+            .get_rewarded_set_selection_seed()
+            .await?
+            .filter(|seed| seed.absolute_epoch_id == absolute_epoch_id)
+            .ok_or(RewardingError::UnavailableSelectionSeed { absolute_epoch_id })?;
+
+        let mut decoded = SelectionSeed::default();
+        hex::decode_to_slice(&seed.seed, &mut decoded).map_err(|_| {
+            RewardingError::MalformedSelectionSeed {
+                absolute_epoch_id,
+                seed: seed.seed.clone(),
+            }
+        })?;
 
-        // the unwrap here is fine as an error can only be thrown under one of the following conditions:
-        // - our mixnode list is empty - we have already checked for that
-        // - we have invalid weights, i.e. less than zero or NaNs - it shouldn't happen in our case as we safely cast down from u128
-        // - all weights are zero - it's impossible in our case as the list of nodes is not empty and weight is proportional to stake. You must have non-zero stake in order to bond
-        // - we have more than u32::MAX values (which is incredibly unrealistic to have 4B mixnodes bonded... literally every other person on the planet would need one)
-        Ok(choices
-            .choose_multiple_weighted(&mut rng, nodes_to_select as usize, |item| item.1)?
-            .map(|(mix, _weight)| mix.clone())
-            .collect())
+        Ok((seed.block_height, decoded))
     }
 
     async fn attach_performance(
@@ -164,7 +179,10 @@ impl RewardedSetUpdater {
                     .await;
 
                 if let Err(err) = self
-                    ._update_rewarded_set_and_advance_epoch(nodes_with_performance)
+                    ._update_rewarded_set_and_advance_epoch(
+                        current_interval,
+                        nodes_with_performance,
+                    )
                     .await
                 {
                     log::error!("FAILED to advance the current epoch... - {err}");
@@ -187,6 +205,7 @@ impl RewardedSetUpdater {
 
     async fn _update_rewarded_set_and_advance_epoch(
         &self,
+        current_interval: Interval,
         all_mixnodes: Vec<MixnodeWithStakeAndPerformance>,
     ) -> Result<(), RewardingError> {
         // we grab rewarding parameters here as they might have gotten updated when performing epoch actions
@@ -194,8 +213,52 @@ impl RewardedSetUpdater {
 
         debug!("Rewarding paremeters: {:?}", rewarding_parameters);
 
-        let new_rewarded_set =
-            self.determine_rewarded_set(all_mixnodes, rewarding_parameters.rewarded_set_size)?;
+        // the set is selected for the upcoming epoch
+        let absolute_epoch_id = current_interval.current_epoch_absolute_id() + 1;
+        let (seed_block_height, seed) = self.selection_seed(absolute_epoch_id).await?;
+
+        let candidates = all_mixnodes
+            .iter()
+            .map(|mix| mix.to_selection_candidate())
+            .collect::<Vec<_>>();
+
+        let selection = RewardedSetSelection {
+            algorithm_version: SELECTION_ALGORITHM_VERSION,
+            seed_block_height,
+            seed: hex::encode(seed),
+            candidates_commitment: hex::encode(candidates_commitment(&candidates)),
+        };
+        debug!("Rewarded set selection: {:?}", selection);
+
+        // persist all the inputs so that anyone could verify the selection afterwards
+        let selection_record = RewardedSetSelectionRecord {
+            absolute_epoch_id,
+            algorithm_version: selection.algorithm_version,
+            seed_block_height: seed_block_height.try_into()?,
+            seed: selection.seed.clone(),
+            candidates_commitment: selection.candidates_commitment.clone(),
+            rewarded_set_size: rewarding_parameters.rewarded_set_size,
+            active_set_size: rewarding_parameters.active_set_size,
+        };
+        let candidate_records = candidates
+            .iter()
+            .map(|candidate| {
+                Ok(RewardedSetSelectionCandidateRecord {
+                    mix_id: candidate.mix_id,
+                    weight: candidate.weight.try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, RewardingError>>()?;
+        self.storage
+            .insert_rewarded_set_selection(&selection_record, &candidate_records)
+            .await?;
+
+        let new_rewarded_set = self.determine_rewarded_set(
+            all_mixnodes,
+            &candidates,
+            seed,
+            rewarding_parameters.rewarded_set_size,
+        );
 
         debug!("New rewarded set: {:?}", new_rewarded_set);
 
@@ -210,12 +273,16 @@ impl RewardedSetUpdater {
             new_rewarded_set.split_at(rewarding_parameters.active_set_size as usize)
         };
 
-        let mut active_set_layer_assignments = self.determine_layers(active_set).await?;
+        let node_locations = self.load_node_locations();
+
+        let mut active_set_layer_assignments =
+            self.determine_layers(active_set, &node_locations).await;
         debug!(
             "Active set layer assignments: {:?}",
             active_set_layer_assignments
         );
-        let reserve_set_layer_assignments = self.determine_layers(reserve_set).await?;
+        let reserve_set_layer_assignments =
+            self.determine_layers(reserve_set, &node_locations).await;
         debug!(
             "Reserve set layer assignments: {:?}",
             reserve_set_layer_assignments
@@ -232,6 +299,7 @@ impl RewardedSetUpdater {
             .advance_current_epoch(
                 active_set_layer_assignments,
                 rewarding_parameters.active_set_size,
+                selection,
             )
             .await?;
 
@@ -0,0 +1,299 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deterministic, publicly verifiable selection of the rewarded set.
+//!
+//! Version 1 of the algorithm works as follows:
+//! 1. the selection seed is fixed by the mixnet contract when the epoch transition begins as
+//!    `sha256(DOMAIN || chain_id || block_height || block_time_nanos || absolute_epoch_id)` (see `RewardedSetSelectionSeed`),
+//!    where `block_height` and `block_time_nanos` describe the block that included the `BeginEpochTransition`
+//!    transaction and `absolute_epoch_id` is the id of the epoch the set is selected for (all integers are big endian).
+//!    The contract rejects any selection that does not use that seed, so it can't be chosen by the rewarding validator,
+//! 2. the candidates are sorted by their `mix_id` and all candidates with zero weight are discarded,
+//! 3. a `ChaCha20` rng is seeded with the selection seed and nodes are drawn one by one, without replacement,
+//!    with probability proportional to their weight. Each draw takes a 128-bit big endian integer made out of
+//!    two consecutive `next_u64` calls and uses rejection sampling to get a value `r` uniform in `[0, total_weight)`.
+//!    The node whose cumulative weight range contains `r` is selected,
+//! 4. the first `active_set_size` selected nodes form the active set and the remaining ones the standby set.
+//!
+//! The candidates commitment is `sha256` over the sorted list of `mix_id || weight` (both big endian),
+//! so that anybody who has obtained the list of candidates can check it's the one used for the selection.
+
+use crate::epoch_operations::error::SelectionVerificationError;
+use nym_mixnet_contract_common::{EpochId, MixId, RewardedSetSelection, RewardedSetSelectionSeed};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+pub(crate) const SELECTION_ALGORITHM_VERSION: u32 = 1;
+
+pub(crate) type SelectionSeed = [u8; 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SelectionCandidate {
+    pub(crate) mix_id: MixId,
+    pub(crate) weight: u64,
+}
+
+impl SelectionCandidate {
+    pub(crate) fn new(mix_id: MixId, weight: u64) -> Self {
+        SelectionCandidate { mix_id, weight }
+    }
+}
+
+fn sorted_candidates(candidates: &[SelectionCandidate]) -> Vec<SelectionCandidate> {
+    let mut sorted = candidates.to_vec();
+    sorted.sort_by_key(|candidate| candidate.mix_id);
+    sorted
+}
+
+pub(crate) fn derive_selection_seed(
+    chain_id: &str,
+    block_height: u64,
+    block_time_nanos: u64,
+    absolute_epoch_id: EpochId,
+) -> SelectionSeed {
+    RewardedSetSelectionSeed::derive(chain_id, block_height, block_time_nanos, absolute_epoch_id)
+}
+
+pub(crate) fn candidates_commitment(candidates: &[SelectionCandidate]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for candidate in sorted_candidates(candidates) {
+        hasher.update(candidate.mix_id.to_be_bytes());
+        hasher.update(candidate.weight.to_be_bytes());
+    }
+    hasher.finalize().into()
+}
+
+// returns value uniformly distributed in [0, bound)
+fn sample_below<R: RngCore>(rng: &mut R, bound: u128) -> u128 {
+    debug_assert_ne!(bound, 0);
+
+    // the largest multiple of `bound` that fits in u128 (well, almost, but that's good enough)
+    let zone = u128::MAX - (u128::MAX % bound);
+    loop {
+        let high = rng.next_u64() as u128;
+        let low = rng.next_u64() as u128;
+        let value = (high << 64) | low;
+        if value < zone {
+            return value % bound;
+        }
+    }
+}
+
+/// Selects up to `nodes_to_select` candidates, weighted by their weights, in the order they got drawn.
+pub(crate) fn select_rewarded_set(
+    candidates: &[SelectionCandidate],
+    seed: SelectionSeed,
+    nodes_to_select: usize,
+) -> Vec<MixId> {
+    let mut remaining = sorted_candidates(candidates)
+        .into_iter()
+        .filter(|candidate| candidate.weight > 0)
+        .collect::<Vec<_>>();
+    let mut total_weight: u128 = remaining.iter().map(|c| c.weight as u128).sum();
+
+    let mut rng = ChaCha20Rng::from_seed(seed);
+    let mut selected = Vec::with_capacity(nodes_to_select.min(remaining.len()));
+
+    while selected.len() < nodes_to_select && !remaining.is_empty() {
+        let mut target = sample_below(&mut rng, total_weight);
+
+        let mut chosen_index = remaining.len() - 1;
+        for (i, candidate) in remaining.iter().enumerate() {
+            let weight = candidate.weight as u128;
+            if target < weight {
+                chosen_index = i;
+                break;
+            }
+            target -= weight;
+        }
+
+        let chosen = remaining.remove(chosen_index);
+        total_weight -= chosen.weight as u128;
+        selected.push(chosen.mix_id)
+    }
+
+    selected
+}
+
+/// Recomputes the rewarded set out of the provided candidates and on-chain data and checks whether
+/// it matches the set recorded in the mixnet contract.
+/// `chain_id` and `block_time_nanos` must come from the header of the block at `selection.seed_block_height`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn verify_rewarded_set_selection(
+    selection: &RewardedSetSelection,
+    absolute_epoch_id: EpochId,
+    chain_id: &str,
+    block_time_nanos: u64,
+    candidates: &[SelectionCandidate],
+    rewarded_set_size: u32,
+    active_set_size: u32,
+    active_set: &HashSet<MixId>,
+    standby_set: &HashSet<MixId>,
+) -> Result<(), SelectionVerificationError> {
+    if selection.algorithm_version != SELECTION_ALGORITHM_VERSION {
+        return Err(SelectionVerificationError::UnsupportedAlgorithmVersion {
+            version: selection.algorithm_version,
+        });
+    }
+
+    let seed = derive_selection_seed(
+        chain_id,
+        selection.seed_block_height,
+        block_time_nanos,
+        absolute_epoch_id,
+    );
+    if hex::encode(seed) != selection.seed {
+        return Err(SelectionVerificationError::SeedMismatch {
+            expected: hex::encode(seed),
+            recorded: selection.seed.clone(),
+        });
+    }
+
+    let commitment = hex::encode(candidates_commitment(candidates));
+    if commitment != selection.candidates_commitment {
+        return Err(SelectionVerificationError::CandidatesCommitmentMismatch {
+            expected: commitment,
+            recorded: selection.candidates_commitment.clone(),
+        });
+    }
+
+    let selected = select_rewarded_set(candidates, seed, rewarded_set_size as usize);
+    let split = selected.len().min(active_set_size as usize);
+    let (expected_active, expected_standby) = selected.split_at(split);
+
+    if expected_active.iter().copied().collect::<HashSet<_>>() != *active_set {
+        return Err(SelectionVerificationError::ActiveSetMismatch);
+    }
+    if expected_standby.iter().copied().collect::<HashSet<_>>() != *standby_set {
+        return Err(SelectionVerificationError::StandbySetMismatch);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy_candidates() -> Vec<SelectionCandidate> {
+        (1..=100)
+            .map(|mix_id| SelectionCandidate::new(mix_id, mix_id as u64 * 1000))
+            .collect()
+    }
+
+    const CHAIN_ID: &str = "nyx";
+    const BLOCK_HEIGHT: u64 = 42;
+    const BLOCK_TIME: u64 = 1_686_830_400_000_000_000;
+
+    fn dummy_seed(epoch_id: EpochId) -> SelectionSeed {
+        derive_selection_seed(CHAIN_ID, BLOCK_HEIGHT, BLOCK_TIME, epoch_id)
+    }
+
+    fn dummy_selection(
+        epoch_id: EpochId,
+        candidates: &[SelectionCandidate],
+    ) -> RewardedSetSelection {
+        RewardedSetSelection {
+            algorithm_version: SELECTION_ALGORITHM_VERSION,
+            seed_block_height: BLOCK_HEIGHT,
+            seed: hex::encode(dummy_seed(epoch_id)),
+            candidates_commitment: hex::encode(candidates_commitment(candidates)),
+        }
+    }
+
+    #[test]
+    fn selection_is_deterministic_and_independent_of_candidates_order() {
+        let candidates = dummy_candidates();
+        let mut reversed = candidates.clone();
+        reversed.reverse();
+
+        let seed = dummy_seed(123);
+        let first = select_rewarded_set(&candidates, seed, 30);
+        let second = select_rewarded_set(&reversed, seed, 30);
+        assert_eq!(first.len(), 30);
+        assert_eq!(first, second);
+        assert_eq!(
+            candidates_commitment(&candidates),
+            candidates_commitment(&reversed)
+        );
+
+        let other_seed = dummy_seed(124);
+        assert_ne!(first, select_rewarded_set(&candidates, other_seed, 30));
+    }
+
+    #[test]
+    fn selection_never_picks_the_same_node_twice_nor_zero_weight_nodes() {
+        let mut candidates = dummy_candidates();
+        candidates.push(SelectionCandidate::new(1000, 0));
+
+        let seed = dummy_seed(1);
+        let selected = select_rewarded_set(&candidates, seed, 500);
+        assert_eq!(selected.len(), 100);
+        assert_eq!(selected.iter().collect::<HashSet<_>>().len(), 100);
+        assert!(!selected.contains(&1000));
+    }
+
+    #[test]
+    fn verification_detects_tampering() {
+        let epoch_id = 10;
+        let candidates = dummy_candidates();
+        let selection = dummy_selection(epoch_id, &candidates);
+
+        let seed = dummy_seed(epoch_id);
+        let selected = select_rewarded_set(&candidates, seed, 20);
+        let active = selected[..15].iter().copied().collect::<HashSet<_>>();
+        let standby = selected[15..].iter().copied().collect::<HashSet<_>>();
+
+        let verify = |selection: &RewardedSetSelection,
+                      candidates: &[SelectionCandidate],
+                      active: &HashSet<MixId>,
+                      standby: &HashSet<MixId>| {
+            verify_rewarded_set_selection(
+                selection, epoch_id, CHAIN_ID, BLOCK_TIME, candidates, 20, 15, active, standby,
+            )
+        };
+
+        assert!(verify(&selection, &candidates, &active, &standby).is_ok());
+
+        let mut bad_seed = selection.clone();
+        bad_seed.seed = hex::encode([0u8; 32]);
+        assert!(matches!(
+            verify(&bad_seed, &candidates, &active, &standby),
+            Err(SelectionVerificationError::SeedMismatch { .. })
+        ));
+
+        // the seed must have been derived from the block at the recorded height
+        let mut bad_height = selection.clone();
+        bad_height.seed_block_height += 1;
+        assert!(matches!(
+            verify(&bad_height, &candidates, &active, &standby),
+            Err(SelectionVerificationError::SeedMismatch { .. })
+        ));
+
+        let mut modified_candidates = candidates.clone();
+        modified_candidates[0].weight += 1;
+        assert!(matches!(
+            verify(&selection, &modified_candidates, &active, &standby),
+            Err(SelectionVerificationError::CandidatesCommitmentMismatch { .. })
+        ));
+
+        let unselected = (1..=100).find(|id| !selected.contains(id)).unwrap();
+        let mut bad_active = active.clone();
+        bad_active.remove(&selected[0]);
+        bad_active.insert(unselected);
+        assert!(matches!(
+            verify(&selection, &candidates, &bad_active, &standby),
+            Err(SelectionVerificationError::ActiveSetMismatch)
+        ));
+
+        let mut bad_standby = standby.clone();
+        bad_standby.insert(unselected);
+        assert!(matches!(
+            verify(&selection, &candidates, &active, &bad_standby),
+            Err(SelectionVerificationError::StandbySetMismatch)
+        ));
+    }
+}
@@ -4,6 +4,7 @@
 use crate::epoch_operations::error::RewardingError;
 use crate::epoch_operations::helpers::MixnodeWithPerformance;
 use crate::RewardedSetUpdater;
+use nym_api_requests::events::NetworkEvent;
 use nym_mixnet_contract_common::{EpochState, Interval, MixId};
 
 impl RewardedSetUpdater {
@@ -63,6 +64,10 @@ impl RewardedSetUpdater {
         }
 
         log::info!("rewarded {} mixnodes...", to_reward.len());
+        self.events.publish(NetworkEvent::EpochRewarded {
+            absolute_epoch_id: current_interval.current_epoch_absolute_id(),
+            rewarded_mixnodes: to_reward.len() as u32,
+        });
 
         Ok(())
     }
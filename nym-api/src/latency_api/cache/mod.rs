@@ -0,0 +1,84 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::latency_api::matrix::LatencyMatrix;
+use crate::support::caching::Cache;
+use nym_api_requests::models::{
+    LatencyMatrixResponse, MixnodeLatencyResponse, MixnodesLatencyResponse,
+};
+use nym_mixnet_contract_common::MixId;
+use rocket::fairing::AdHoc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{RwLock, RwLockReadGuard};
+use tokio::time;
+
+pub(crate) mod refresher;
+
+/// A cache for the network-wide latency matrix built out of the verloc measurements of bonded mixnodes.
+///
+/// Similarly to the circulating supply cache, it does not include an update listener.
+#[derive(Clone)]
+pub(crate) struct LatencyCache {
+    initialised: Arc<AtomicBool>,
+    data: Arc<RwLock<Cache<LatencyMatrix>>>,
+}
+
+impl LatencyCache {
+    fn new() -> LatencyCache {
+        LatencyCache {
+            initialised: Arc::new(AtomicBool::new(false)),
+            data: Arc::new(RwLock::new(Cache::default())),
+        }
+    }
+
+    pub(crate) fn stage() -> AdHoc {
+        AdHoc::on_ignite("Latency Cache Stage", |rocket| async {
+            rocket.manage(Self::new())
+        })
+    }
+
+    pub(crate) fn initialised(&self) -> bool {
+        self.initialised.load(Ordering::Relaxed)
+    }
+
+    async fn get(&self) -> Option<RwLockReadGuard<'_, Cache<LatencyMatrix>>> {
+        match time::timeout(Duration::from_millis(100), self.data.read()).await {
+            Ok(cache) => Some(cache),
+            Err(err) => {
+                error!("Failed to get latency matrix: {err}");
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn latency_matrix(&self) -> Option<LatencyMatrixResponse> {
+        self.get().await.map(|cache| LatencyMatrixResponse {
+            as_at: cache.timestamp(),
+            entries: cache.entries.clone(),
+        })
+    }
+
+    pub(crate) async fn mixnodes_latency(&self) -> Option<MixnodesLatencyResponse> {
+        self.get().await.map(|cache| MixnodesLatencyResponse {
+            as_at: cache.timestamp(),
+            mixnodes: cache.mixnodes.clone(),
+        })
+    }
+
+    pub(crate) async fn mixnode_latency(&self, mix_id: MixId) -> Option<MixnodeLatencyResponse> {
+        self.get().await.and_then(|cache| {
+            cache
+                .mixnodes
+                .iter()
+                .find(|node| node.mix_id == mix_id)
+                .cloned()
+        })
+    }
+
+    pub(crate) async fn update(&self, matrix: LatencyMatrix) {
+        self.data.write().await.update(matrix);
+        self.initialised.store(true, Ordering::Relaxed)
+    }
+}
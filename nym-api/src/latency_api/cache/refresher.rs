@@ -0,0 +1,155 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::LatencyCache;
+use crate::latency_api::matrix::{build_latency_matrix, RttMeasurement, VerlocReport};
+use crate::nym_contract_cache::cache::NymContractCache;
+use futures::{stream, StreamExt};
+use nym_mixnet_contract_common::mixnode::MixNodeDetails;
+use nym_mixnet_contract_common::{IdentityKey, MixId};
+use nym_task::TaskClient;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::time;
+
+// subset of the verloc results exposed by the mixnode http api that we care about
+#[derive(Deserialize)]
+struct VerlocResponse {
+    results: Vec<VerlocEntry>,
+}
+
+#[derive(Deserialize)]
+struct VerlocEntry {
+    identity: IdentityKey,
+    latest_measurement: Option<VerlocMeasurement>,
+}
+
+#[derive(Deserialize)]
+struct VerlocMeasurement {
+    #[serde(with = "humantime_serde")]
+    minimum: Duration,
+    #[serde(with = "humantime_serde")]
+    mean: Duration,
+    #[serde(with = "humantime_serde")]
+    maximum: Duration,
+}
+
+impl From<VerlocMeasurement> for RttMeasurement {
+    fn from(value: VerlocMeasurement) -> Self {
+        RttMeasurement {
+            minimum: value.minimum,
+            mean: value.mean,
+            maximum: value.maximum,
+        }
+    }
+}
+
+pub(crate) struct LatencyCacheRefresher {
+    contract_cache: NymContractCache,
+    cache: LatencyCache,
+    caching_interval: Duration,
+    max_concurrent_requests: usize,
+    http_client: reqwest::Client,
+}
+
+impl LatencyCacheRefresher {
+    pub(crate) fn new(
+        contract_cache: NymContractCache,
+        cache: LatencyCache,
+        caching_interval: Duration,
+        request_timeout: Duration,
+        max_concurrent_requests: usize,
+    ) -> Self {
+        LatencyCacheRefresher {
+            contract_cache,
+            cache,
+            caching_interval,
+            max_concurrent_requests,
+            http_client: reqwest::Client::builder()
+                .timeout(request_timeout)
+                .build()
+                .expect("failed to build the http client"),
+        }
+    }
+
+    pub(crate) async fn run(&self, mut shutdown: TaskClient) {
+        self.contract_cache.wait_for_initial_values().await;
+
+        let mut interval = time::interval(self.caching_interval);
+        while !shutdown.is_shutdown() {
+            tokio::select! {
+                _ = interval.tick() => {
+                    tokio::select! {
+                        biased;
+                        _ = shutdown.recv() => {
+                            trace!("LatencyCacheRefresher: Received shutdown");
+                        }
+                        _ = self.refresh() => (),
+                    }
+                }
+                _ = shutdown.recv() => {
+                    trace!("LatencyCacheRefresher: Received shutdown");
+                }
+            }
+        }
+    }
+
+    async fn query_verloc(&self, mixnode: &MixNodeDetails) -> Result<VerlocReport, reqwest::Error> {
+        let node = &mixnode.bond_information.mix_node;
+        let url = format!("http://{}:{}/verloc", node.host, node.http_api_port);
+
+        let response: VerlocResponse = self
+            .http_client
+            .get(url)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(VerlocReport {
+            reporter: mixnode.mix_id(),
+            measurements: response
+                .results
+                .into_iter()
+                .filter_map(|entry| Some((entry.identity, entry.latest_measurement?.into())))
+                .collect(),
+        })
+    }
+
+    async fn refresh(&self) {
+        let mixnodes = self.contract_cache.mixnodes_all().await;
+        let bonded = mixnodes
+            .iter()
+            .map(|node| (node.bond_information.identity().to_owned(), node.mix_id()))
+            .collect::<HashMap<IdentityKey, MixId>>();
+
+        let reports = stream::iter(&mixnodes)
+            .map(|mixnode| async move {
+                match self.query_verloc(mixnode).await {
+                    Ok(report) => Some(report),
+                    Err(err) => {
+                        debug!(
+                            "failed to obtain verloc results of mixnode {}: {err}",
+                            mixnode.mix_id()
+                        );
+                        None
+                    }
+                }
+            })
+            .buffer_unordered(self.max_concurrent_requests)
+            .filter_map(|report| async move { report })
+            .collect::<Vec<_>>()
+            .await;
+
+        info!(
+            "obtained verloc results from {} out of {} mixnodes",
+            reports.len(),
+            mixnodes.len()
+        );
+
+        let matrix = build_latency_matrix(&reports, &bonded);
+        self.cache.update(matrix).await
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Aggregation of the verloc measurements self-reported by mixnodes into a network-wide latency matrix.
+//!
+//! Since the reports can't be trusted blindly, they go through the following sanity checks:
+//! 1. measurements of nodes that are not bonded (or of the reporter itself) are ignored,
+//! 2. implausible measurements, i.e. with zero minimum, inconsistent minimum/mean/maximum or with
+//!    round-trip time above `MAX_PLAUSIBLE_RTT`, are rejected,
+//! 3. round-trip time is symmetric, so if both nodes have measured each other, their reported mean
+//!    values must agree within the tolerance. Agreeing measurements get averaged into a confirmed entry,
+//!    while contradicting ones are discarded altogether and counted against both nodes,
+//! 4. if only one of the nodes has measured the other, its measurement is kept as an unconfirmed entry.
+
+use nym_api_requests::models::{LatencyMatrixEntry, MixnodeLatencyResponse};
+use nym_mixnet_contract_common::{IdentityKey, MixId};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::time::Duration;
+
+const MAX_PLAUSIBLE_RTT: Duration = Duration::from_secs(10);
+
+// reports of the two nodes are considered consistent if they differ by at most
+// max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE_PERCENT% of the smaller value)
+const ABSOLUTE_TOLERANCE_MICROS: u64 = 10_000;
+const RELATIVE_TOLERANCE_PERCENT: u64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RttMeasurement {
+    pub(crate) minimum: Duration,
+    pub(crate) mean: Duration,
+    pub(crate) maximum: Duration,
+}
+
+impl RttMeasurement {
+    fn is_plausible(&self) -> bool {
+        !self.minimum.is_zero()
+            && self.minimum <= self.mean
+            && self.mean <= self.maximum
+            && self.maximum <= MAX_PLAUSIBLE_RTT
+    }
+}
+
+/// Verloc results as reported by a single mixnode.
+#[derive(Debug, Clone)]
+pub(crate) struct VerlocReport {
+    pub(crate) reporter: MixId,
+    pub(crate) measurements: Vec<(IdentityKey, RttMeasurement)>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LatencyMatrix {
+    pub(crate) entries: Vec<LatencyMatrixEntry>,
+    pub(crate) mixnodes: Vec<MixnodeLatencyResponse>,
+}
+
+#[derive(Default)]
+struct NodeCounters {
+    rtts: Vec<u64>,
+    inconsistent_peers: usize,
+    rejected_measurements: usize,
+}
+
+fn micros(duration: Duration) -> u64 {
+    // anything that could overflow has already been rejected as implausible
+    duration.as_micros() as u64
+}
+
+fn are_consistent(a: u64, b: u64) -> bool {
+    let smaller = a.min(b);
+    let tolerance = ABSOLUTE_TOLERANCE_MICROS.max(smaller * RELATIVE_TOLERANCE_PERCENT / 100);
+    a.abs_diff(b) <= tolerance
+}
+
+// nearest-rank percentile of sorted values
+fn percentile(sorted: &[u64], percent: usize) -> Option<u64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = (percent * sorted.len() + 99) / 100;
+    Some(sorted[rank.max(1) - 1])
+}
+
+/// Builds the latency matrix out of the received reports.
+/// `bonded` maps identities of all currently bonded mixnodes to their ids.
+pub(crate) fn build_latency_matrix(
+    reports: &[VerlocReport],
+    bonded: &HashMap<IdentityKey, MixId>,
+) -> LatencyMatrix {
+    let mut counters: BTreeMap<MixId, NodeCounters> = bonded
+        .values()
+        .map(|mix_id| (*mix_id, NodeCounters::default()))
+        .collect();
+
+    let mut directed: HashMap<(MixId, MixId), RttMeasurement> = HashMap::new();
+    let mut pairs = BTreeSet::new();
+
+    for report in reports {
+        if !counters.contains_key(&report.reporter) {
+            continue;
+        }
+        for (identity, measurement) in &report.measurements {
+            let Some(&target) = bonded.get(identity) else {
+                continue;
+            };
+            if target == report.reporter {
+                continue;
+            }
+            if !measurement.is_plausible() {
+                if let Some(node) = counters.get_mut(&report.reporter) {
+                    node.rejected_measurements += 1;
+                }
+                continue;
+            }
+            directed
+                .entry((report.reporter, target))
+                .or_insert(*measurement);
+            pairs.insert((report.reporter.min(target), report.reporter.max(target)));
+        }
+    }
+
+    let mut entries = Vec::with_capacity(pairs.len());
+    for (mix_a, mix_b) in pairs {
+        let entry = match (directed.get(&(mix_a, mix_b)), directed.get(&(mix_b, mix_a))) {
+            (Some(forward), Some(backward)) => {
+                let forward_mean = micros(forward.mean);
+                let backward_mean = micros(backward.mean);
+                if !are_consistent(forward_mean, backward_mean) {
+                    for mix_id in [mix_a, mix_b] {
+                        if let Some(node) = counters.get_mut(&mix_id) {
+                            node.inconsistent_peers += 1;
+                        }
+                    }
+                    continue;
+                }
+                LatencyMatrixEntry {
+                    mix_a,
+                    mix_b,
+                    mean_rtt_micros: (forward_mean + backward_mean) / 2,
+                    minimum_rtt_micros: micros(forward.minimum.min(backward.minimum)),
+                    confirmed: true,
+                }
+            }
+            (Some(measurement), None) | (None, Some(measurement)) => LatencyMatrixEntry {
+                mix_a,
+                mix_b,
+                mean_rtt_micros: micros(measurement.mean),
+                minimum_rtt_micros: micros(measurement.minimum),
+                confirmed: false,
+            },
+            (None, None) => continue,
+        };
+
+        for mix_id in [mix_a, mix_b] {
+            if let Some(node) = counters.get_mut(&mix_id) {
+                node.rtts.push(entry.mean_rtt_micros);
+            }
+        }
+        entries.push(entry)
+    }
+
+    let identities = bonded
+        .iter()
+        .map(|(identity, mix_id)| (*mix_id, identity))
+        .collect::<HashMap<_, _>>();
+
+    let mixnodes = counters
+        .into_iter()
+        .map(|(mix_id, mut node)| {
+            node.rtts.sort_unstable();
+            MixnodeLatencyResponse {
+                mix_id,
+                identity: identities
+                    .get(&mix_id)
+                    .map(|identity| identity.to_string())
+                    .unwrap_or_default(),
+                measured_peers: node.rtts.len(),
+                inconsistent_peers: node.inconsistent_peers,
+                rejected_measurements: node.rejected_measurements,
+                p50_rtt_micros: percentile(&node.rtts, 50),
+                p90_rtt_micros: percentile(&node.rtts, 90),
+                p99_rtt_micros: percentile(&node.rtts, 99),
+            }
+        })
+        .collect();
+
+    LatencyMatrix { entries, mixnodes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(mean_millis: u64) -> RttMeasurement {
+        RttMeasurement {
+            minimum: Duration::from_millis(mean_millis / 2),
+            mean: Duration::from_millis(mean_millis),
+            maximum: Duration::from_millis(mean_millis * 2),
+        }
+    }
+
+    fn identity(mix_id: MixId) -> IdentityKey {
+        format!("identity{mix_id}")
+    }
+
+    fn bonded(nodes: MixId) -> HashMap<IdentityKey, MixId> {
+        (1..=nodes)
+            .map(|mix_id| (identity(mix_id), mix_id))
+            .collect()
+    }
+
+    fn report(reporter: MixId, measurements: &[(MixId, RttMeasurement)]) -> VerlocReport {
+        VerlocReport {
+            reporter,
+            measurements: measurements
+                .iter()
+                .map(|(target, measurement)| (identity(*target), *measurement))
+                .collect(),
+        }
+    }
+
+    fn node(matrix: &LatencyMatrix, mix_id: MixId) -> &MixnodeLatencyResponse {
+        matrix
+            .mixnodes
+            .iter()
+            .find(|node| node.mix_id == mix_id)
+            .unwrap()
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank() {
+        let values = (1..=100).collect::<Vec<_>>();
+        assert_eq!(percentile(&values, 50), Some(50));
+        assert_eq!(percentile(&values, 90), Some(90));
+        assert_eq!(percentile(&values, 99), Some(99));
+        assert_eq!(percentile(&[42], 99), Some(42));
+        assert_eq!(percentile(&[], 50), None);
+    }
+
+    #[test]
+    fn consistent_measurements_are_averaged_and_confirmed() {
+        let reports = vec![
+            report(1, &[(2, measurement(100))]),
+            report(2, &[(1, measurement(120))]),
+        ];
+        let matrix = build_latency_matrix(&reports, &bonded(2));
+
+        assert_eq!(
+            matrix.entries,
+            vec![LatencyMatrixEntry {
+                mix_a: 1,
+                mix_b: 2,
+                mean_rtt_micros: 110_000,
+                minimum_rtt_micros: 50_000,
+                confirmed: true,
+            }]
+        );
+        assert_eq!(node(&matrix, 1).measured_peers, 1);
+        assert_eq!(node(&matrix, 2).p50_rtt_micros, Some(110_000));
+    }
+
+    #[test]
+    fn contradicting_measurements_are_discarded() {
+        let reports = vec![
+            report(1, &[(2, measurement(10)), (3, measurement(100))]),
+            report(2, &[(1, measurement(500))]),
+        ];
+        let matrix = build_latency_matrix(&reports, &bonded(3));
+
+        assert_eq!(matrix.entries.len(), 1);
+        let entry = matrix.entries[0];
+        assert_eq!((entry.mix_a, entry.mix_b), (1, 3));
+        assert!(!entry.confirmed);
+
+        assert_eq!(node(&matrix, 1).inconsistent_peers, 1);
+        assert_eq!(node(&matrix, 2).inconsistent_peers, 1);
+        assert_eq!(node(&matrix, 2).measured_peers, 0);
+        assert_eq!(node(&matrix, 2).p50_rtt_micros, None);
+        assert_eq!(node(&matrix, 3).measured_peers, 1);
+    }
+
+    #[test]
+    fn implausible_and_unknown_measurements_are_ignored() {
+        let zero_minimum = RttMeasurement {
+            minimum: Duration::ZERO,
+            ..measurement(100)
+        };
+        let inverted = RttMeasurement {
+            minimum: Duration::from_millis(200),
+            ..measurement(100)
+        };
+        let too_slow = measurement(20_000);
+
+        let reports = vec![
+            report(
+                1,
+                &[
+                    (2, zero_minimum),
+                    (3, inverted),
+                    (4, too_slow),
+                    (1, measurement(1)),
+                    (42, measurement(100)),
+                ],
+            ),
+            // not bonded
+            report(42, &[(1, measurement(100))]),
+        ];
+        let matrix = build_latency_matrix(&reports, &bonded(4));
+
+        assert!(matrix.entries.is_empty());
+        assert_eq!(matrix.mixnodes.len(), 4);
+        assert_eq!(node(&matrix, 1).rejected_measurements, 3);
+        assert_eq!(node(&matrix, 1).measured_peers, 0);
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_task::TaskManager;
+use okapi::openapi3::OpenApi;
+use rocket::Route;
+use rocket_okapi::{openapi_get_routes_spec, settings::OpenApiSettings};
+
+use crate::nym_contract_cache::cache::NymContractCache;
+use crate::support::config;
+
+use self::cache::refresher::LatencyCacheRefresher;
+
+pub(crate) mod cache;
+pub(crate) mod matrix;
+pub(crate) mod routes;
+
+/// Merges the routes with http information and returns it to Rocket for serving
+pub(crate) fn latency_routes(settings: &OpenApiSettings) -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![
+        settings: routes::get_latency_matrix,
+        routes::get_mixnodes_latency,
+        routes::get_mixnode_latency
+    ]
+}
+
+/// Spawn the latency cache refresher.
+pub(crate) fn start_cache_refresh(
+    config: &config::LatencyCacher,
+    nym_contract_cache: &NymContractCache,
+    latency_cache: &cache::LatencyCache,
+    shutdown: &TaskManager,
+) {
+    if config.enabled {
+        let refresher = LatencyCacheRefresher::new(
+            nym_contract_cache.to_owned(),
+            latency_cache.to_owned(),
+            config.debug.caching_interval,
+            config.debug.request_timeout,
+            config.debug.max_concurrent_requests,
+        );
+        let shutdown_listener = shutdown.subscribe();
+        tokio::spawn(async move { refresher.run(shutdown_listener).await });
+    }
+}
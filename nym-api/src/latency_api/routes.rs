@@ -0,0 +1,70 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::latency_api::cache::LatencyCache;
+use crate::node_status_api::models::ErrorResponse;
+use nym_api_requests::models::{
+    LatencyMatrixResponse, MixnodeLatencyResponse, MixnodesLatencyResponse,
+};
+use nym_mixnet_contract_common::MixId;
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use rocket_okapi::openapi;
+
+fn unavailable() -> ErrorResponse {
+    ErrorResponse::new("unavailable", Status::InternalServerError)
+}
+
+fn ensure_initialised(cache: &LatencyCache) -> Result<(), ErrorResponse> {
+    if cache.initialised() {
+        Ok(())
+    } else {
+        Err(ErrorResponse::new(
+            "the latency matrix has not been built yet",
+            Status::ServiceUnavailable,
+        ))
+    }
+}
+
+#[openapi(tag = "latency")]
+#[get("/latency/matrix")]
+pub(crate) async fn get_latency_matrix(
+    cache: &State<LatencyCache>,
+) -> Result<Json<LatencyMatrixResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    cache
+        .latency_matrix()
+        .await
+        .map(Json)
+        .ok_or_else(unavailable)
+}
+
+#[openapi(tag = "latency")]
+#[get("/latency/mixnodes")]
+pub(crate) async fn get_mixnodes_latency(
+    cache: &State<LatencyCache>,
+) -> Result<Json<MixnodesLatencyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    cache
+        .mixnodes_latency()
+        .await
+        .map(Json)
+        .ok_or_else(unavailable)
+}
+
+#[openapi(tag = "latency")]
+#[get("/latency/mixnode/<mix_id>")]
+pub(crate) async fn get_mixnode_latency(
+    cache: &State<LatencyCache>,
+    mix_id: MixId,
+) -> Result<Json<MixnodeLatencyResponse>, ErrorResponse> {
+    ensure_initialised(cache)?;
+    match cache.mixnode_latency(mix_id).await {
+        Some(latency) => Ok(Json(latency)),
+        None => Err(ErrorResponse::new(
+            format!("mixnode {mix_id} is not bonded or its latency data is unavailable"),
+            Status::NotFound,
+        )),
+    }
+}
@@ -5,9 +5,10 @@
 extern crate rocket;
 
 use crate::epoch_operations::RewardedSetUpdater;
+use crate::network_events::NetworkEvents;
 use crate::node_status_api::uptime_updater::HistoricalUptimeUpdater;
 use crate::support::cli;
-use crate::support::cli::CliArgs;
+use crate::support::cli::{CliArgs, Commands};
 use crate::support::config::Config;
 use crate::support::storage;
 use crate::support::storage::NymApiStorage;
@@ -16,6 +17,7 @@ use anyhow::Result;
 use circulating_supply_api::cache::CirculatingSupplyCache;
 use clap::Parser;
 use coconut::dkg::controller::DkgController;
+use latency_api::cache::LatencyCache;
 use log::info;
 use node_status_api::NodeStatusCache;
 use nym_bin_common::logging::setup_logging;
@@ -29,6 +31,8 @@ use support::{http, nyxd};
 mod circulating_supply_api;
 mod coconut;
 mod epoch_operations;
+mod latency_api;
+mod network_events;
 mod network_monitor;
 pub(crate) mod node_status_api;
 pub(crate) mod nym_contract_cache;
@@ -83,6 +87,8 @@ async fn start_nym_api_tasks(
     let nym_contract_cache_state = rocket.state::<NymContractCache>().unwrap();
     let node_status_cache_state = rocket.state::<NodeStatusCache>().unwrap();
     let circulating_supply_cache_state = rocket.state::<CirculatingSupplyCache>().unwrap();
+    let latency_cache_state = rocket.state::<LatencyCache>().unwrap();
+    let network_events_state = rocket.state::<NetworkEvents>().unwrap();
     let maybe_storage = rocket.state::<NymApiStorage>();
 
     // start all the caches first
@@ -106,6 +112,12 @@ async fn start_nym_api_tasks(
         circulating_supply_cache_state,
         &shutdown,
     );
+    latency_api::start_cache_refresh(
+        &config.latency_cacher,
+        nym_contract_cache_state,
+        latency_cache_state,
+        &shutdown,
+    );
 
     // start dkg task
     if config.coconut_signer.enabled {
@@ -134,12 +146,19 @@ async fn start_nym_api_tasks(
         )
         .await;
 
-        HistoricalUptimeUpdater::start(storage, &shutdown);
+        HistoricalUptimeUpdater::start(storage, network_events_state, &shutdown);
 
         // start 'rewarding' if its enabled
         if config.rewarding.enabled {
             epoch_operations::ensure_rewarding_permission(&nyxd_client).await?;
-            RewardedSetUpdater::start(nyxd_client, nym_contract_cache_state, storage, &shutdown);
+            RewardedSetUpdater::start(
+                nyxd_client,
+                nym_contract_cache_state,
+                storage,
+                network_events_state,
+                &config.rewarding.layer_assignment,
+                &shutdown,
+            );
         }
     }
 
@@ -152,7 +171,8 @@ async fn start_nym_api_tasks(
     })
 }
 
-async fn run_nym_api(cli_args: CliArgs) -> Result<(), Box<dyn Error + Send + Sync>> {
+async fn run_nym_api(mut cli_args: CliArgs) -> Result<(), Box<dyn Error + Send + Sync>> {
+    let command = cli_args.command.take();
     let save_to_file = cli_args.save_config;
     let config = cli::build_config(cli_args)?;
 
@@ -163,6 +183,10 @@ async fn run_nym_api(cli_args: CliArgs) -> Result<(), Box<dyn Error + Send + Syn
         return Ok(());
     }
 
+    if let Some(Commands::DkgState(args)) = command {
+        return Ok(cli::dkg_state::execute(&config, args).await?);
+    }
+
     let shutdown_handlers = start_nym_api_tasks(config).await?;
 
     let res = shutdown_handlers
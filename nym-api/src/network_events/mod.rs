@@ -0,0 +1,57 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_api_requests::events::NetworkEvent;
+use okapi::openapi3::OpenApi;
+use rocket::fairing::AdHoc;
+use rocket::Route;
+use rocket_okapi::{openapi_get_routes_spec, settings::OpenApiSettings};
+use tokio::sync::broadcast;
+
+pub(crate) mod routes;
+
+// the events are rather infrequent, so this should be more than enough for any reasonable subscriber
+const EVENTS_CHANNEL_CAPACITY: usize = 1024;
+
+/// Broadcaster of the network events, such as epoch transitions or node status changes,
+/// to all the currently subscribed clients.
+///
+/// The events are published by the tasks refreshing the relevant data.
+#[derive(Clone)]
+pub(crate) struct NetworkEvents {
+    sender: broadcast::Sender<NetworkEvent>,
+}
+
+impl NetworkEvents {
+    pub(crate) fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENTS_CHANNEL_CAPACITY);
+        NetworkEvents { sender }
+    }
+
+    pub(crate) fn stage(self) -> AdHoc {
+        AdHoc::on_ignite("Network Events Stage", |rocket| async {
+            rocket.manage(self)
+        })
+    }
+
+    pub(crate) fn publish(&self, event: NetworkEvent) {
+        log::trace!("publishing network event: {event:?}");
+
+        // it's perfectly fine if there are no subscribers at the moment
+        let _ = self.sender.send(event);
+    }
+
+    pub(crate) fn publish_all(&self, events: impl IntoIterator<Item = NetworkEvent>) {
+        for event in events {
+            self.publish(event)
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<NetworkEvent> {
+        self.sender.subscribe()
+    }
+}
+
+pub(crate) fn network_events_routes(settings: &OpenApiSettings) -> (Vec<Route>, OpenApi) {
+    openapi_get_routes_spec![settings: routes::network_events]
+}
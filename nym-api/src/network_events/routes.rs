@@ -0,0 +1,58 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::network_events::NetworkEvents;
+use crate::node_status_api::models::ErrorResponse;
+use nym_api_requests::events::{NetworkEvent, NetworkEventFilter, NetworkEventKind};
+use nym_mixnet_contract_common::MixId;
+use rocket::http::Status;
+use rocket::response::stream::{Event, EventStream};
+use rocket::tokio::select;
+use rocket::tokio::sync::broadcast::error::RecvError;
+use rocket::{Shutdown, State};
+use rocket_okapi::openapi;
+
+/// Streams the network events as server-sent events.
+///
+/// The stream can be restricted to only particular mixnodes (`mix_id`), gateways (`gateway`)
+/// or event kinds (`kind`). Each of the parameters can be repeated.
+// server-sent events are not representable in the openapi spec, hence the `skip`
+#[openapi(skip)]
+#[get("/events?<mix_id>&<gateway>&<kind>")]
+pub(crate) fn network_events(
+    events: &State<NetworkEvents>,
+    mut shutdown: Shutdown,
+    mix_id: Vec<MixId>,
+    gateway: Vec<String>,
+    kind: Vec<String>,
+) -> Result<EventStream![], ErrorResponse> {
+    let kinds = kind
+        .iter()
+        .map(|kind| kind.parse::<NetworkEventKind>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::BadRequest))?;
+
+    let filter = NetworkEventFilter {
+        mixnodes: mix_id,
+        gateways: gateway,
+        kinds,
+    };
+    let mut receiver = events.subscribe();
+
+    Ok(EventStream! {
+        loop {
+            let event = select! {
+                received = receiver.recv() => match received {
+                    Ok(event) => event,
+                    Err(RecvError::Closed) => break,
+                    Err(RecvError::Lagged(count)) => NetworkEvent::MissedEvents { count },
+                },
+                _ = &mut shutdown => break,
+            };
+
+            if filter.matches(&event) {
+                yield Event::json(&event).event(event.kind().as_str());
+            }
+        }
+    })
+}
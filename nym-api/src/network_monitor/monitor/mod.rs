@@ -5,7 +5,7 @@ use crate::network_monitor::monitor::preparer::PacketPreparer;
 use crate::network_monitor::monitor::processor::ReceivedProcessor;
 use crate::network_monitor::monitor::sender::PacketSender;
 use crate::network_monitor::monitor::summary_producer::{SummaryProducer, TestSummary};
-use crate::network_monitor::test_packet::NodeTestMessage;
+use crate::network_monitor::test_packet::ReceivedTestMessage;
 use crate::network_monitor::test_route::TestRoute;
 use crate::storage::NymApiStorage;
 use crate::support::config;
@@ -24,6 +24,7 @@ pub(crate) mod processor;
 pub(crate) mod receiver;
 pub(crate) mod sender;
 pub(crate) mod summary_producer;
+pub(crate) mod timing;
 
 pub(super) struct Monitor<R: MessageReceiver + Send + 'static> {
     test_nonce: u64,
@@ -91,6 +92,7 @@ impl<R: MessageReceiver + Send> Monitor<R> {
                     .into_iter()
                     .map(|result| result.route)
                     .collect(),
+                test_summary.timing,
             )
             .await
         {
@@ -103,11 +105,13 @@ impl<R: MessageReceiver + Send> Monitor<R> {
 
     fn analyse_received_test_route_packets(
         &self,
-        packets: &[NodeTestMessage],
+        packets: &[ReceivedTestMessage],
     ) -> HashMap<u64, usize> {
         let mut received = HashMap::new();
         for packet in packets {
-            *received.entry(packet.ext.route_id).or_insert(0usize) += 1usize
+            *received
+                .entry(packet.message.ext.route_id)
+                .or_insert(0usize) += 1usize
         }
 
         received
@@ -255,7 +259,8 @@ impl<R: MessageReceiver + Send> Monitor<R> {
             .await;
 
         info!("Sending packets to all gateways...");
-        self.packet_sender
+        let sent_packets = self
+            .packet_sender
             .send_packets(prepared_packets.packets)
             .await;
 
@@ -274,7 +279,9 @@ impl<R: MessageReceiver + Send> Monitor<R> {
 
         let summary = self.summary_producer.produce_summary(
             prepared_packets.tested_mixnodes,
+            &prepared_packets.mixnode_layers,
             prepared_packets.tested_gateways,
+            &sent_packets,
             received,
             prepared_packets.invalid_mixnodes,
             prepared_packets.invalid_gateways,
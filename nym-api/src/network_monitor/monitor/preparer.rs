@@ -2,11 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::sender::GatewayPackets;
+use crate::network_monitor::test_packet::TestPacketId;
 use crate::network_monitor::test_route::TestRoute;
 use crate::nym_contract_cache::cache::NymContractCache;
 use log::info;
 use nym_crypto::asymmetric::{encryption, identity};
-use nym_mixnet_contract_common::{GatewayBond, Layer, MixNodeBond};
+use nym_mixnet_contract_common::{GatewayBond, Layer, MixId, MixNodeBond};
 use nym_node_tester_utils::node::TestableNode;
 use nym_node_tester_utils::NodeTester;
 use nym_sphinx::acknowledgements::AckKey;
@@ -55,6 +56,9 @@ pub(crate) struct PreparedPackets {
     /// Vector containing list of public keys and owners of all nodes mixnodes being tested.
     pub(super) tested_mixnodes: Vec<TestableNode>,
 
+    /// Layers of all mixnodes being tested.
+    pub(super) mixnode_layers: HashMap<MixId, Layer>,
+
     /// Vector containing list of public keys and owners of all gateways being tested.
     pub(super) tested_gateways: Vec<TestableNode>,
 
@@ -306,11 +310,13 @@ impl PacketPreparer {
             .map(|p| tester.wrap_plaintext_data(p, topology, None).unwrap())
             .map(MixPacket::from)
             .collect();
+        let packet_ids = TestPacketId::for_node(route.id(), route.layer_one_mix(), num as u32);
 
         GatewayPackets::new(
             route.gateway_clients_address(),
             route.gateway_identity(),
             mix_packets,
+            packet_ids,
         )
     }
 
@@ -374,6 +380,10 @@ impl PacketPreparer {
         let (gateways, invalid_gateways) = self.filter_outdated_and_malformed_gateways(gateways);
 
         let tested_mixnodes = mixnodes.iter().map(|node| node.into()).collect::<Vec<_>>();
+        let mixnode_layers = mixnodes
+            .iter()
+            .map(|node| (node.mix_id, node.layer))
+            .collect();
         let tested_gateways = gateways.iter().map(|node| node.into()).collect::<Vec<_>>();
 
         let packets_to_create = (test_routes.len() * self.per_node_test_packets)
@@ -396,20 +406,29 @@ impl PacketPreparer {
             // 1. the topology is definitely valid (otherwise we wouldn't be here)
             // 2. the recipient is specified (by calling **mix**_tester)
             // 3. the test message is not too long, i.e. when serialized it will fit in a single sphinx packet
-            let mixnode_test_packets = mix_tester
-                .mixnodes_test_packets(
-                    &mixnodes,
-                    route_ext,
+            let mut mix_packets = Vec::new();
+            let mut mix_packet_ids = Vec::new();
+            for mixnode in &mixnodes {
+                let mixnode_test_packets = mix_tester
+                    .mixnode_test_packets(
+                        mixnode,
+                        route_ext,
+                        self.per_node_test_packets as u32,
+                        None,
+                    )
+                    .unwrap();
+                mix_packets.extend(mixnode_test_packets.into_iter().map(MixPacket::from));
+                mix_packet_ids.append(&mut TestPacketId::for_node(
+                    test_route.id(),
+                    mixnode,
                     self.per_node_test_packets as u32,
-                    None,
-                )
-                .unwrap();
-            let mix_packets = mixnode_test_packets.into_iter().map(Into::into).collect();
+                ));
+            }
 
             let gateway_packets = all_gateway_packets
                 .entry(gateway_identity.to_bytes())
                 .or_insert_with(|| GatewayPackets::empty(gateway_address, gateway_identity));
-            gateway_packets.push_packets(mix_packets);
+            gateway_packets.push_packets(mix_packets, mix_packet_ids);
 
             // and generate test packets for gateways (note the variable recipient)
             for gateway in &gateways {
@@ -431,13 +450,18 @@ impl PacketPreparer {
                     .unwrap();
                 let gateway_mix_packets =
                     gateway_test_packets.into_iter().map(Into::into).collect();
+                let gateway_packet_ids = TestPacketId::for_node(
+                    test_route.id(),
+                    gateway,
+                    self.per_node_test_packets as u32,
+                );
 
                 // and push it into existing struct (if it's a "core" gateway being tested against another route)
                 // or create a new one
                 let gateway_packets = all_gateway_packets
                     .entry(gateway_identity.to_bytes())
                     .or_insert_with(|| GatewayPackets::empty(gateway_address, gateway_identity));
-                gateway_packets.push_packets(gateway_mix_packets);
+                gateway_packets.push_packets(gateway_mix_packets, gateway_packet_ids);
             }
         }
 
@@ -447,6 +471,7 @@ impl PacketPreparer {
         PreparedPackets {
             packets,
             tested_mixnodes,
+            mixnode_layers,
             tested_gateways,
             invalid_mixnodes,
             invalid_gateways,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::gateways_reader::GatewayMessages;
-use crate::network_monitor::test_packet::{NodeTestMessage, NymApiTestMessageExt};
+use crate::network_monitor::test_packet::{NymApiTestMessageExt, ReceivedTestMessage};
 use crate::network_monitor::ROUTE_TESTING_TEST_NONCE;
 use futures::channel::mpsc;
 use futures::lock::{Mutex, MutexGuard};
@@ -16,6 +16,7 @@ use nym_sphinx::receiver::{MessageReceiver, MessageRecoveryError};
 use std::mem;
 use std::sync::Arc;
 use thiserror::Error;
+use tokio::time::Instant;
 
 pub(crate) type ReceivedProcessorSender = mpsc::UnboundedSender<GatewayMessages>;
 pub(crate) type ReceivedProcessorReceiver = mpsc::UnboundedReceiver<GatewayMessages>;
@@ -54,11 +55,13 @@ struct ReceivedProcessorInner<R: MessageReceiver> {
 
     /// Vector containing all received (and decrypted) packets in the current test run.
     // TODO: perhaps a different structure would be better here
-    received_packets: Vec<NodeTestMessage>,
+    received_packets: Vec<ReceivedTestMessage>,
 }
 
 impl<R: MessageReceiver> ReceivedProcessorInner<R> {
     fn on_received_data(&mut self, raw_message: Vec<u8>) -> Result<(), ProcessingError> {
+        let received_at = Instant::now();
+
         // if the nonce is none it means the packet was received during the 'waiting' for the
         // next test run
         if self.test_nonce.is_none() {
@@ -74,7 +77,10 @@ impl<R: MessageReceiver> ReceivedProcessorInner<R> {
             });
         }
 
-        self.received_packets.push(test_msg);
+        self.received_packets.push(ReceivedTestMessage {
+            message: test_msg,
+            received_at,
+        });
         Ok(())
     }
 
@@ -111,7 +117,7 @@ impl<R: MessageReceiver> ReceivedProcessorInner<R> {
         }
     }
 
-    fn finish_run(&mut self) -> Vec<NodeTestMessage> {
+    fn finish_run(&mut self) -> Vec<ReceivedTestMessage> {
         self.test_nonce = None;
         mem::take(&mut self.received_packets)
     }
@@ -220,7 +226,7 @@ impl<R: MessageReceiver + Send + 'static> ReceivedProcessor<R> {
             .expect("processing task has died!");
     }
 
-    pub(super) async fn return_received(&mut self) -> Vec<NodeTestMessage> {
+    pub(super) async fn return_received(&mut self) -> Vec<ReceivedTestMessage> {
         // ask for the lock back
         self.permit_changer
             .as_mut()
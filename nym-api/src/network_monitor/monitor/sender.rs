@@ -6,6 +6,7 @@ use crate::network_monitor::monitor::gateway_clients_cache::{
 };
 use crate::network_monitor::monitor::gateways_pinger::GatewayPinger;
 use crate::network_monitor::monitor::receiver::{GatewayClientUpdate, GatewayClientUpdateSender};
+use crate::network_monitor::test_packet::TestPacketId;
 use crate::support::nyxd;
 use futures::channel::mpsc;
 use futures::stream::{self, FuturesUnordered, StreamExt};
@@ -21,12 +22,14 @@ use nym_gateway_client::{AcknowledgementReceiver, GatewayClient, MixnetMessageRe
 use nym_sphinx::forwarding::packet::MixPacket;
 use nym_task::TaskClient;
 use pin_project::pin_project;
+use std::collections::HashMap;
 use std::mem;
 use std::num::NonZeroUsize;
 use std::pin::Pin;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::task::Poll;
 use std::time::Duration;
+use tokio::time::Instant;
 
 const TIME_CHUNK_SIZE: Duration = Duration::from_millis(50);
 
@@ -40,6 +43,9 @@ pub(crate) struct GatewayPackets {
 
     /// All the packets that are going to get sent to the gateway.
     pub(crate) packets: Vec<MixPacket>,
+
+    /// Ids of the test messages contained in the packets, in the same order as the packets themselves.
+    pub(crate) packet_ids: Vec<TestPacketId>,
 }
 
 impl GatewayPackets {
@@ -47,11 +53,14 @@ impl GatewayPackets {
         clients_address: String,
         pub_key: identity::PublicKey,
         packets: Vec<MixPacket>,
+        packet_ids: Vec<TestPacketId>,
     ) -> Self {
+        debug_assert_eq!(packets.len(), packet_ids.len());
         GatewayPackets {
             clients_address,
             pub_key,
             packets,
+            packet_ids,
         }
     }
 
@@ -60,19 +69,61 @@ impl GatewayPackets {
             clients_address,
             pub_key,
             packets: Vec::new(),
+            packet_ids: Vec::new(),
         }
     }
 
-    pub(super) fn push_packets(&mut self, mut packets: Vec<MixPacket>) {
+    pub(super) fn push_packets(
+        &mut self,
+        mut packets: Vec<MixPacket>,
+        mut packet_ids: Vec<TestPacketId>,
+    ) {
+        debug_assert_eq!(packets.len(), packet_ids.len());
         if self.packets.is_empty() {
-            self.packets = packets
+            self.packets = packets;
+            self.packet_ids = packet_ids;
         } else if self.packets.len() > packets.len() {
-            self.packets.append(&mut packets)
+            self.packets.append(&mut packets);
+            self.packet_ids.append(&mut packet_ids);
         } else {
             packets.append(&mut self.packets);
+            packet_ids.append(&mut self.packet_ids);
             self.packets = packets;
+            self.packet_ids = packet_ids;
+        }
+    }
+}
+
+/// Times at which particular test packets got handed over to their gateways.
+pub(crate) type SentPackets = HashMap<TestPacketId, Instant>;
+
+// shared between all concurrent senders so that we'd also know about packets sent
+// before the sending has failed or timed out
+#[derive(Clone, Default)]
+struct SentPacketsLog {
+    inner: Arc<Mutex<SentPackets>>,
+}
+
+impl SentPacketsLog {
+    fn record(&self, packet_ids: Vec<TestPacketId>) {
+        let now = Instant::now();
+        let mut guard = self
+            .inner
+            .lock()
+            .expect("sent packets log mutex got poisoned");
+        for id in packet_ids {
+            guard.insert(id, now);
         }
     }
+
+    fn take(&self) -> SentPackets {
+        mem::take(
+            &mut *self
+                .inner
+                .lock()
+                .expect("sent packets log mutex got poisoned"),
+        )
+    }
 }
 
 // struct consisting of all external data required to construct a fresh gateway client
@@ -208,7 +259,9 @@ impl PacketSender {
     async fn attempt_to_send_packets(
         client: &mut GatewayClient<nyxd::Client, PersistentStorage>,
         mut mix_packets: Vec<MixPacket>,
+        mut packet_ids: Vec<TestPacketId>,
         max_sending_rate: usize,
+        sent_packets: &SentPacketsLog,
     ) -> Result<(), GatewayClientError> {
         let gateway_id = client.gateway_identity().to_base58_string();
         info!(
@@ -219,6 +272,7 @@ impl PacketSender {
 
         if mix_packets.len() <= max_sending_rate {
             debug!("Everything is going to get sent as one.");
+            sent_packets.record(packet_ids);
             client.batch_send_mix_packets(mix_packets).await?;
         } else {
             let packets_per_time_chunk =
@@ -231,7 +285,7 @@ impl PacketSender {
                 max_sending_rate, total_expected_time, gateway_id
             );
 
-            fn split_off_vec(vec: &mut Vec<MixPacket>, at: usize) -> Option<Vec<MixPacket>> {
+            fn split_off_vec<T>(vec: &mut Vec<T>, at: usize) -> Option<Vec<T>> {
                 if vec.is_empty() {
                     None
                 } else {
@@ -248,6 +302,10 @@ impl PacketSender {
             while let Some(retained) = split_off_vec(&mut mix_packets, packets_per_time_chunk) {
                 trace!("Sending {} packets...", mix_packets.len());
 
+                let retained_ids =
+                    split_off_vec(&mut packet_ids, packets_per_time_chunk).unwrap_or_default();
+                sent_packets.record(mem::replace(&mut packet_ids, retained_ids));
+
                 if mix_packets.len() == 1 {
                     client.send_mix_packet(mix_packets.pop().unwrap()).await?;
                 } else {
@@ -334,6 +392,7 @@ impl PacketSender {
         fresh_gateway_client_data: Arc<FreshGatewayClientData>,
         client: Option<GatewayClientHandle>,
         max_sending_rate: usize,
+        sent_packets: SentPacketsLog,
     ) -> Option<GatewayClientHandle> {
         let existing_client = client.is_some();
 
@@ -380,7 +439,13 @@ impl PacketSender {
 
         match tokio::time::timeout(
             timeout,
-            Self::attempt_to_send_packets(unwrapped_client, packets.packets, max_sending_rate),
+            Self::attempt_to_send_packets(
+                unwrapped_client,
+                packets.packets,
+                packets.packet_ids,
+                max_sending_rate,
+                &sent_packets,
+            ),
         )
         .await
         {
@@ -443,7 +508,8 @@ impl PacketSender {
         }
     }
 
-    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) {
+    /// Sends all the provided packets and returns the times at which they got sent.
+    pub(super) async fn send_packets(&mut self, packets: Vec<GatewayPackets>) -> SentPackets {
         // we know that each of the elements in the packets array will only ever access a single,
         // unique element from the existing clients
 
@@ -454,6 +520,7 @@ impl PacketSender {
             None
         };
         let max_sending_rate = self.max_sending_rate;
+        let sent_packets = SentPacketsLog::default();
 
         let guard = self.active_gateway_clients.lock().await;
         // this clippy warning is a false positive as we cannot get rid of the collect by moving
@@ -470,6 +537,7 @@ impl PacketSender {
                     packets,
                     Arc::clone(&self.fresh_gateway_client_data),
                     existing_client,
+                    sent_packets.clone(),
                 )
             })
             .collect::<Vec<_>>();
@@ -484,13 +552,14 @@ impl PacketSender {
         let used_clients = ForEachConcurrentClientUse::new(
             stream::iter(stream_data.into_iter()),
             max_concurrent_clients,
-            |(packets, fresh_data, client)| async move {
+            |(packets, fresh_data, client, sent_packets)| async move {
                 Self::send_gateway_packets(
                     gateway_connection_timeout,
                     packets,
                     fresh_data,
                     client,
                     max_sending_rate,
+                    sent_packets,
                 )
                 .await
             },
@@ -501,6 +570,7 @@ impl PacketSender {
         .collect();
 
         self.merge_client_handles(used_clients).await;
+        sent_packets.take()
     }
 }
 
@@ -2,9 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::preparer::InvalidNode;
-use crate::network_monitor::test_packet::NodeTestMessage;
+use crate::network_monitor::monitor::sender::SentPackets;
+use crate::network_monitor::monitor::timing::{TimingAnalyser, TimingSummary};
+use crate::network_monitor::test_packet::ReceivedTestMessage;
 use crate::network_monitor::test_route::TestRoute;
-use nym_mixnet_contract_common::MixId;
+use nym_mixnet_contract_common::{Layer, MixId};
 use nym_node_tester_utils::node::{NodeType, TestableNode};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -245,6 +247,7 @@ pub(crate) struct TestSummary {
     pub(crate) mixnode_results: Vec<MixnodeResult>,
     pub(crate) gateway_results: Vec<GatewayResult>,
     pub(crate) route_results: Vec<RouteResult>,
+    pub(crate) timing: TimingSummary,
 }
 
 impl TestSummary {
@@ -278,15 +281,26 @@ impl SummaryProducer {
         self
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn produce_summary(
         &self,
         tested_mixnodes: Vec<TestableNode>,
+        mixnode_layers: &HashMap<MixId, Layer>,
         tested_gateways: Vec<TestableNode>,
-        received_packets: Vec<NodeTestMessage>,
+        sent_packets: &SentPackets,
+        received_packets: Vec<ReceivedTestMessage>,
         invalid_mixnodes: Vec<InvalidNode>,
         invalid_gateways: Vec<InvalidNode>,
         test_routes: &[TestRoute],
     ) -> TestSummary {
+        let tested_nodes = tested_mixnodes
+            .iter()
+            .chain(tested_gateways.iter())
+            .cloned()
+            .collect::<Vec<_>>();
+        let timing = TimingAnalyser::new(test_routes, mixnode_layers, self.per_node_test_packets)
+            .analyse(&tested_nodes, sent_packets, &received_packets);
+
         // we expect each route to receive this many packets in the ideal world
         let per_route_expected =
             (tested_mixnodes.len() + tested_gateways.len()) * self.per_node_test_packets;
@@ -316,6 +330,7 @@ impl SummaryProducer {
         }
 
         for received in received_packets {
+            let received = received.message;
             *raw_results.entry(received.tested_node).or_default() += 1usize;
             *raw_route_results.entry(received.ext.route_id).or_default() += 1usize;
         }
@@ -358,6 +373,7 @@ impl SummaryProducer {
             mixnode_results,
             gateway_results,
             route_results,
+            timing,
         }
     }
 }
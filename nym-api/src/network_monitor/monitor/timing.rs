@@ -0,0 +1,437 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Estimation of delivery times and per-node delay and loss out of the timestamped test packets.
+//!
+//! Every tested node gets its packets sent through each test route, where the only hop that differs
+//! between packets of nodes of the same kind (i.e. mixnodes on the same layer or gateways) is the tested
+//! node itself. Thus, for each route, the typical (median) delay and loss of all nodes of given kind
+//! is used as the baseline that the tested node is compared against. The node's excess delay and
+//! loss are then averaged across all the routes it got tested through.
+//!
+//! Note that delivery times include the artificial sphinx delays introduced by the mixnodes.
+
+use crate::network_monitor::monitor::sender::SentPackets;
+use crate::network_monitor::test_packet::{ReceivedTestMessage, TestPacketId};
+use crate::network_monitor::test_route::TestRoute;
+use nym_mixnet_contract_common::{Layer, MixId};
+use nym_node_tester_utils::node::{NodeType, TestableNode};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub(crate) struct RouteTiming {
+    pub(crate) route: TestRoute,
+
+    /// Number of received packets sent through this route whose delivery time is known.
+    pub(crate) measured_packets: usize,
+    pub(crate) median_delivery_ms: Option<f32>,
+    pub(crate) p90_delivery_ms: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct NodeTiming {
+    pub(crate) node: TestableNode,
+
+    /// Median delivery time of the node's packets, averaged across all routes.
+    pub(crate) median_delivery_ms: Option<f32>,
+
+    /// Delay of this node relative to other nodes of the same kind, averaged across all routes.
+    pub(crate) excess_delay_ms: Option<f32>,
+
+    /// Fraction of the node's packets that got lost on top of the typical loss on given route,
+    /// averaged across all routes.
+    pub(crate) estimated_loss: f32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TimingSummary {
+    pub(crate) route_timings: Vec<RouteTiming>,
+    pub(crate) mixnode_timings: Vec<NodeTiming>,
+    pub(crate) gateway_timings: Vec<NodeTiming>,
+}
+
+// nodes are only compared against other nodes that share all the other hops on given route
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum NodeKind {
+    Mixnode(Layer),
+    Gateway,
+}
+
+#[derive(Default)]
+struct RouteNodeResults {
+    received: usize,
+    delays_ms: Vec<f64>,
+}
+
+// a single route's results of a single node
+struct RouteNodeStats {
+    loss: f64,
+    median_delay_ms: Option<f64>,
+}
+
+fn sort(values: &mut [f64]) {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+}
+
+fn median(values: &mut [f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    sort(values);
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        Some((values[mid - 1] + values[mid]) / 2.)
+    } else {
+        Some(values[mid])
+    }
+}
+
+// nearest-rank percentile
+fn percentile(values: &mut [f64], percent: usize) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    sort(values);
+    let rank = (percent * values.len() + 99) / 100;
+    Some(values[rank.max(1) - 1])
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+pub(crate) struct TimingAnalyser<'a> {
+    routes: &'a [TestRoute],
+    mixnode_layers: &'a HashMap<MixId, Layer>,
+    per_node_test_packets: usize,
+}
+
+impl<'a> TimingAnalyser<'a> {
+    pub(crate) fn new(
+        routes: &'a [TestRoute],
+        mixnode_layers: &'a HashMap<MixId, Layer>,
+        per_node_test_packets: usize,
+    ) -> Self {
+        TimingAnalyser {
+            routes,
+            mixnode_layers,
+            per_node_test_packets,
+        }
+    }
+
+    fn node_kind(&self, node: &TestableNode) -> Option<NodeKind> {
+        match node.typ {
+            NodeType::Mixnode { mix_id } => self
+                .mixnode_layers
+                .get(&mix_id)
+                .map(|layer| NodeKind::Mixnode(*layer)),
+            NodeType::Gateway => Some(NodeKind::Gateway),
+        }
+    }
+
+    fn route_node_stats(&self, results: &mut RouteNodeResults) -> RouteNodeStats {
+        let expected = self.per_node_test_packets.max(1) as f64;
+        let received = (results.received as f64).min(expected);
+        RouteNodeStats {
+            loss: 1. - received / expected,
+            median_delay_ms: median(&mut results.delays_ms),
+        }
+    }
+
+    pub(crate) fn analyse(
+        &self,
+        tested_nodes: &[TestableNode],
+        sent_packets: &SentPackets,
+        received_packets: &[ReceivedTestMessage],
+    ) -> TimingSummary {
+        let mut route_delays: HashMap<u64, Vec<f64>> = HashMap::new();
+        let mut node_results: HashMap<(u64, &TestableNode), RouteNodeResults> = HashMap::new();
+        for route in self.routes {
+            route_delays.insert(route.id(), Vec::new());
+            for node in tested_nodes {
+                node_results.insert((route.id(), node), RouteNodeResults::default());
+            }
+        }
+
+        for received in received_packets {
+            let message = &received.message;
+            let key = (message.ext.route_id, &message.tested_node);
+            let Some(results) = node_results.get_mut(&key) else {
+                continue;
+            };
+            results.received += 1;
+
+            let Some(sent_at) = sent_packets.get(&TestPacketId::from(message)) else {
+                continue;
+            };
+            let delay_ms = received
+                .received_at
+                .saturating_duration_since(*sent_at)
+                .as_secs_f64()
+                * 1000.;
+            results.delays_ms.push(delay_ms);
+            if let Some(delays) = route_delays.get_mut(&message.ext.route_id) {
+                delays.push(delay_ms)
+            }
+        }
+
+        let route_timings = self
+            .routes
+            .iter()
+            .map(|route| {
+                let mut delays = route_delays.remove(&route.id()).unwrap_or_default();
+                RouteTiming {
+                    route: route.clone(),
+                    measured_packets: delays.len(),
+                    median_delivery_ms: median(&mut delays).map(|v| v as f32),
+                    p90_delivery_ms: percentile(&mut delays, 90).map(|v| v as f32),
+                }
+            })
+            .collect();
+
+        // per route statistics of every node alongside the baselines of every kind of node
+        let mut node_stats: HashMap<(u64, &TestableNode), RouteNodeStats> = HashMap::new();
+        let mut kind_delays: HashMap<(u64, NodeKind), Vec<f64>> = HashMap::new();
+        let mut kind_losses: HashMap<(u64, NodeKind), Vec<f64>> = HashMap::new();
+        for ((route_id, node), mut results) in node_results {
+            let stats = self.route_node_stats(&mut results);
+            if let Some(kind) = self.node_kind(node) {
+                if let Some(delay) = stats.median_delay_ms {
+                    kind_delays.entry((route_id, kind)).or_default().push(delay);
+                }
+                kind_losses
+                    .entry((route_id, kind))
+                    .or_default()
+                    .push(stats.loss);
+            }
+            node_stats.insert((route_id, node), stats);
+        }
+
+        let delay_baselines = kind_delays
+            .into_iter()
+            .filter_map(|(key, mut delays)| median(&mut delays).map(|baseline| (key, baseline)))
+            .collect::<HashMap<_, _>>();
+        let loss_baselines = kind_losses
+            .into_iter()
+            .filter_map(|(key, mut losses)| median(&mut losses).map(|baseline| (key, baseline)))
+            .collect::<HashMap<_, _>>();
+
+        let mut mixnode_timings = Vec::new();
+        let mut gateway_timings = Vec::new();
+        for node in tested_nodes {
+            let kind = self.node_kind(node);
+
+            let mut medians = Vec::new();
+            let mut excess_delays = Vec::new();
+            let mut excess_losses = Vec::new();
+            for route in self.routes {
+                let Some(stats) = node_stats.get(&(route.id(), node)) else {
+                    continue;
+                };
+                let delay_baseline = kind.and_then(|kind| delay_baselines.get(&(route.id(), kind)));
+                let loss_baseline = kind
+                    .and_then(|kind| loss_baselines.get(&(route.id(), kind)))
+                    .copied()
+                    .unwrap_or_default();
+
+                if let Some(delay) = stats.median_delay_ms {
+                    medians.push(delay);
+                    if let Some(baseline) = delay_baseline {
+                        excess_delays.push(delay - baseline);
+                    }
+                }
+                excess_losses.push((stats.loss - loss_baseline).max(0.));
+            }
+
+            let timing = NodeTiming {
+                node: node.clone(),
+                median_delivery_ms: mean(&medians).map(|v| v as f32),
+                excess_delay_ms: mean(&excess_delays).map(|v| v as f32),
+                estimated_loss: mean(&excess_losses).unwrap_or_default() as f32,
+            };
+            if node.is_mixnode() {
+                mixnode_timings.push(timing)
+            } else {
+                gateway_timings.push(timing)
+            }
+        }
+
+        TimingSummary {
+            route_timings,
+            mixnode_timings,
+            gateway_timings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::network_monitor::test_packet::{NodeTestMessage, NymApiTestMessageExt};
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_topology::{gateway, mix};
+    use std::time::Duration;
+    use tokio::time::Instant;
+
+    const PACKETS: usize = 4;
+
+    fn mixnode(mix_id: MixId, layer: Layer) -> mix::Node {
+        let mut rng = rand_07::thread_rng();
+        mix::Node {
+            mix_id,
+            owner: format!("owner{mix_id}"),
+            host: "1.1.1.1".parse().unwrap(),
+            mix_host: "1.1.1.1:1789".parse().unwrap(),
+            identity_key: *identity::KeyPair::new(&mut rng).public_key(),
+            sphinx_key: *encryption::KeyPair::new(&mut rng).public_key(),
+            layer,
+            version: "1.1.0".into(),
+        }
+    }
+
+    fn gateway_node(id: u8) -> gateway::Node {
+        let mut rng = rand_07::thread_rng();
+        gateway::Node {
+            owner: format!("gateway-owner{id}"),
+            host: "1.1.1.1".parse().unwrap(),
+            mix_host: "1.1.1.1:1789".parse().unwrap(),
+            clients_port: 9000,
+            identity_key: *identity::KeyPair::new(&mut rng).public_key(),
+            sphinx_key: *encryption::KeyPair::new(&mut rng).public_key(),
+            version: "1.1.0".into(),
+        }
+    }
+
+    fn route(id: u64) -> TestRoute {
+        TestRoute::new(
+            id,
+            mixnode(100 + id as MixId * 3, Layer::One),
+            mixnode(101 + id as MixId * 3, Layer::Two),
+            mixnode(102 + id as MixId * 3, Layer::Three),
+            gateway_node(id as u8),
+        )
+    }
+
+    struct Run {
+        start: Instant,
+        sent: SentPackets,
+        received: Vec<ReceivedTestMessage>,
+    }
+
+    impl Run {
+        fn new() -> Self {
+            Run {
+                start: Instant::now(),
+                sent: HashMap::new(),
+                received: Vec::new(),
+            }
+        }
+
+        // sends all packets of the node through the route, `delivered` of which arrive after `delay_ms`
+        fn deliver(&mut self, route_id: u64, node: &TestableNode, delivered: usize, delay_ms: u64) {
+            for msg_id in 1..=PACKETS as u32 {
+                let id = TestPacketId::new(route_id, node.clone(), msg_id);
+                self.sent.insert(id, self.start);
+                if msg_id as usize <= delivered {
+                    self.received.push(ReceivedTestMessage {
+                        message: NodeTestMessage::new(
+                            node.clone(),
+                            msg_id,
+                            PACKETS as u32,
+                            NymApiTestMessageExt::new(route_id, 1),
+                        ),
+                        received_at: self.start + Duration::from_millis(delay_ms),
+                    })
+                }
+            }
+        }
+    }
+
+    fn find<'a>(timings: &'a [NodeTiming], node: &TestableNode) -> &'a NodeTiming {
+        timings.iter().find(|t| &t.node == node).unwrap()
+    }
+
+    #[test]
+    fn slow_and_lossy_nodes_are_identified() {
+        let routes = vec![route(1), route(2)];
+        let layer_one = (1..=5)
+            .map(|mix_id| mixnode(mix_id, Layer::One))
+            .collect::<Vec<_>>();
+        let layers = layer_one
+            .iter()
+            .map(|node| (node.mix_id, node.layer))
+            .collect::<HashMap<_, _>>();
+        let tested = layer_one.iter().map(TestableNode::from).collect::<Vec<_>>();
+
+        let mut run = Run::new();
+        for route in &routes {
+            // route 2 is generally slower
+            let route_delay = 100 * route.id();
+            for node in &tested[..3] {
+                run.deliver(route.id(), node, PACKETS, route_delay);
+            }
+            // slow node
+            run.deliver(route.id(), &tested[3], PACKETS, route_delay + 500);
+            // lossy node
+            run.deliver(route.id(), &tested[4], PACKETS / 2, route_delay);
+        }
+
+        let summary = TimingAnalyser::new(&routes, &layers, PACKETS).analyse(
+            &tested,
+            &run.sent,
+            &run.received,
+        );
+
+        assert!(summary.gateway_timings.is_empty());
+        assert_eq!(summary.mixnode_timings.len(), 5);
+
+        let healthy = find(&summary.mixnode_timings, &tested[0]);
+        assert_eq!(healthy.median_delivery_ms, Some(150.));
+        assert_eq!(healthy.excess_delay_ms, Some(0.));
+        assert_eq!(healthy.estimated_loss, 0.);
+
+        let slow = find(&summary.mixnode_timings, &tested[3]);
+        assert_eq!(slow.excess_delay_ms, Some(500.));
+        assert_eq!(slow.estimated_loss, 0.);
+
+        let lossy = find(&summary.mixnode_timings, &tested[4]);
+        assert_eq!(lossy.excess_delay_ms, Some(0.));
+        assert_eq!(lossy.estimated_loss, 0.5);
+
+        let route_two = summary
+            .route_timings
+            .iter()
+            .find(|timing| timing.route.id() == 2)
+            .unwrap();
+        assert_eq!(route_two.measured_packets, 4 * PACKETS + PACKETS / 2);
+        assert_eq!(route_two.median_delivery_ms, Some(200.));
+        assert_eq!(route_two.p90_delivery_ms, Some(700.));
+    }
+
+    #[test]
+    fn nodes_without_any_delivered_packets() {
+        let routes = vec![route(1)];
+        let gateway = TestableNode::from(&gateway_node(42));
+        let tested = vec![gateway.clone()];
+
+        let mut run = Run::new();
+        run.deliver(1, &gateway, 0, 0);
+
+        let summary = TimingAnalyser::new(&routes, &HashMap::new(), PACKETS).analyse(
+            &tested,
+            &run.sent,
+            &run.received,
+        );
+
+        let timing = find(&summary.gateway_timings, &gateway);
+        assert_eq!(timing.median_delivery_ms, None);
+        assert_eq!(timing.excess_delay_ms, None);
+        // it's the only gateway tested, so it defines the baseline itself
+        assert_eq!(timing.estimated_loss, 0.);
+        assert_eq!(summary.route_timings[0].measured_packets, 0);
+    }
+}
@@ -2,12 +2,56 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use nym_node_tester_utils::error::NetworkTestingError;
+use nym_node_tester_utils::node::TestableNode;
 use nym_node_tester_utils::TestMessage;
 use nym_topology::mix;
 use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
 
 pub(crate) type NodeTestMessage = TestMessage<NymApiTestMessageExt>;
 
+/// Uniquely identifies a test packet within a single test run.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub(crate) struct TestPacketId {
+    pub(crate) route_id: u64,
+    pub(crate) tested_node: TestableNode,
+    pub(crate) msg_id: u32,
+}
+
+impl TestPacketId {
+    pub(crate) fn new(route_id: u64, tested_node: TestableNode, msg_id: u32) -> Self {
+        TestPacketId {
+            route_id,
+            tested_node,
+            msg_id,
+        }
+    }
+
+    /// Ids of all `test_packets` messages created for the particular node, as created by `TestMessage::new_plaintexts`.
+    pub(crate) fn for_node<N: Into<TestableNode>>(
+        route_id: u64,
+        node: N,
+        test_packets: u32,
+    ) -> Vec<Self> {
+        let tested_node = node.into();
+        (1..=test_packets)
+            .map(|msg_id| TestPacketId::new(route_id, tested_node.clone(), msg_id))
+            .collect()
+    }
+}
+
+impl<'a> From<&'a NodeTestMessage> for TestPacketId {
+    fn from(value: &'a NodeTestMessage) -> Self {
+        TestPacketId::new(value.ext.route_id, value.tested_node.clone(), value.msg_id)
+    }
+}
+
+/// Test message alongside the time at which it got received.
+pub(crate) struct ReceivedTestMessage {
+    pub(crate) message: NodeTestMessage,
+    pub(crate) received_at: Instant,
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Hash)]
 pub(crate) struct NymApiTestMessageExt {
     pub(crate) route_id: u64,
@@ -1,22 +1,34 @@
 // Copyright 2021-2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::epoch_operations::rewarded_set_selection::{
+    verify_rewarded_set_selection, SelectionCandidate,
+};
 use crate::node_status_api::models::ErrorResponse;
+use crate::node_status_api::ONE_DAY;
+use crate::storage::models::NodeTimingRecord;
 use crate::storage::NymApiStorage;
 use crate::support::caching::Cache;
+use crate::support::nyxd;
 use crate::{NodeStatusCache, NymContractCache};
 use cosmwasm_std::Decimal;
 use nym_api_requests::models::{
     AllInclusionProbabilitiesResponse, ComputeRewardEstParam, GatewayBondAnnotated,
-    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayUptimeHistoryResponse,
-    GatewayUptimeResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayTimingResponse,
+    GatewayUptimeHistoryResponse, GatewayUptimeResponse, InclusionProbabilityResponse,
+    MixNodeBondAnnotated, MixnodeCoreStatusResponse, MixnodeStatusReportResponse,
+    MixnodeStatusResponse, MixnodeTimingResponse, MixnodeUptimeHistoryResponse,
+    NetworkMonitorRoutesTimingResponse, NodeTimingMeasurement, RewardEstimationResponse,
+    RewardedSetSelectionCandidate, RewardedSetSelectionResponse, RouteTimingResponse,
+    SelectionVerificationResult, StakeSaturationResponse, UptimeResponse,
 };
-use nym_mixnet_contract_common::{MixId, RewardedSetNodeStatus};
+use nym_mixnet_contract_common::{EpochId, MixId, RewardedSetNodeStatus};
 use rocket::http::Status;
 use rocket::State;
+use std::collections::HashSet;
+use std::convert::TryInto;
+use std::num::TryFromIntError;
+use time::OffsetDateTime;
 
 use super::reward_estimate::compute_reward_estimate;
 
@@ -424,3 +436,138 @@ pub(crate) async fn _get_gateways_detailed_unfiltered(
         .unwrap_or_default()
         .into_inner()
 }
+
+pub(crate) async fn _get_rewarded_set_selection(
+    storage: &NymApiStorage,
+    contract_cache: &NymContractCache,
+    nyxd_client: &nyxd::Client,
+    epoch_id: Option<EpochId>,
+) -> Result<RewardedSetSelectionResponse, ErrorResponse> {
+    let current_epoch_id = contract_cache
+        .current_interval()
+        .await
+        .into_inner()
+        .map(|interval| interval.current_epoch_absolute_id())
+        .ok_or_else(|| ErrorResponse::new("no data available", Status::ServiceUnavailable))?;
+    let absolute_epoch_id = epoch_id.unwrap_or(current_epoch_id);
+
+    let (selection, candidates) = storage
+        .get_rewarded_set_selection(absolute_epoch_id)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?
+        .ok_or_else(|| {
+            ErrorResponse::new(
+                format!("rewarded set selection for epoch {absolute_epoch_id} not found"),
+                Status::NotFound,
+            )
+        })?;
+
+    let candidates = candidates
+        .into_iter()
+        .map(|candidate| RewardedSetSelectionCandidate {
+            mix_id: candidate.mix_id,
+            weight: candidate.weight as u64,
+        })
+        .collect::<Vec<_>>();
+
+    // we can only compare the selection against the rewarded set of the current epoch
+    let verification = if absolute_epoch_id == current_epoch_id {
+        let result =
+            verify_current_selection(contract_cache, nyxd_client, current_epoch_id, &candidates)
+                .await;
+
+        Some(SelectionVerificationResult {
+            verified: result.is_ok(),
+            error: result.err(),
+        })
+    } else {
+        None
+    };
+
+    Ok(RewardedSetSelectionResponse {
+        absolute_epoch_id,
+        algorithm_version: selection.algorithm_version,
+        seed_block_height: selection.seed_block_height as u64,
+        seed: selection.seed,
+        candidates_commitment: selection.candidates_commitment,
+        rewarded_set_size: selection.rewarded_set_size,
+        active_set_size: selection.active_set_size,
+        candidates,
+        verification,
+    })
+}
+
+// apart from the list of candidates, which is bound by the commitment stored in the contract,
+// everything used for the verification comes from the chain rather than from our own records
+async fn verify_current_selection(
+    contract_cache: &NymContractCache,
+    nyxd_client: &nyxd::Client,
+    current_epoch_id: EpochId,
+    candidates: &[RewardedSetSelectionCandidate],
+) -> Result<(), String> {
+    let on_chain = nyxd_client
+        .get_rewarded_set_selection()
+        .await
+        .map_err(|err| err.to_string())?;
+    let selection = match on_chain.selection {
+        Some(selection) if on_chain.epoch_id == current_epoch_id => selection,
+        _ => {
+            return Err(format!(
+                "the mixnet contract holds no verifiable selection for epoch {current_epoch_id}"
+            ))
+        }
+    };
+
+    let seed_block_height = selection
+        .seed_block_height
+        .try_into()
+        .map_err(|err: TryFromIntError| err.to_string())?;
+    let (chain_id, block_time) = nyxd_client
+        .get_block_environment(seed_block_height)
+        .await
+        .map_err(|err| err.to_string())?;
+    let block_time_nanos = block_time
+        .unix_timestamp_nanos()
+        .try_into()
+        .map_err(|err: TryFromIntError| err.to_string())?;
+
+    let rewarding_params = contract_cache
+        .interval_reward_params()
+        .await
+        .into_inner()
+        .ok_or_else(|| "rewarding parameters are not available".to_string())?;
+
+    let active_set = contract_cache
+        .active_set()
+        .await
+        .into_inner()
+        .into_iter()
+        .map(|mix| mix.mix_id())
+        .collect::<HashSet<_>>();
+    let standby_set = contract_cache
+        .rewarded_set()
+        .await
+        .into_inner()
+        .into_iter()
+        .map(|mix| mix.mix_id())
+        .filter(|mix_id| !active_set.contains(mix_id))
+        .collect::<HashSet<_>>();
+
+    let selection_candidates = candidates
+        .iter()
+        .map(|candidate| SelectionCandidate::new(candidate.mix_id, candidate.weight))
+        .collect::<Vec<_>>();
+
+    verify_rewarded_set_selection(
+        &selection,
+        current_epoch_id,
+        &chain_id,
+        block_time_nanos,
+        &selection_candidates,
+        rewarding_params.rewarded_set_size,
+        rewarding_params.active_set_size,
+        &active_set,
+        &standby_set,
+    )
+    .map_err(|err| err.to_string())
+}
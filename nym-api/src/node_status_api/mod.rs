@@ -37,9 +37,12 @@ pub(crate) fn node_status_routes(
             settings: routes::gateway_report,
             routes::gateway_uptime_history,
             routes::gateway_core_status_count,
+            routes::gateway_timing,
             routes::mixnode_report,
             routes::mixnode_uptime_history,
             routes::mixnode_core_status_count,
+            routes::mixnode_timing,
+            routes::network_monitor_routes_timing,
             routes::get_mixnode_status,
             routes::get_mixnode_reward_estimation,
             routes::compute_mixnode_reward_estimation,
@@ -51,6 +54,7 @@ pub(crate) fn node_status_routes(
             routes::get_mixnodes_detailed,
             routes::get_mixnodes_detailed_unfiltered,
             routes::get_rewarded_set_detailed,
+            routes::get_rewarded_set_selection,
             routes::get_active_set_detailed,
             routes::get_gateways_detailed,
             routes::get_gateways_detailed_unfiltered,
@@ -5,25 +5,28 @@ use super::helpers::_get_gateways_detailed;
 use super::NodeStatusCache;
 use crate::node_status_api::helpers::{
     _compute_mixnode_reward_estimation, _gateway_core_status_count, _gateway_report,
-    _gateway_uptime_history, _get_active_set_detailed, _get_gateway_avg_uptime,
+    _gateway_timing, _gateway_uptime_history, _get_active_set_detailed, _get_gateway_avg_uptime,
     _get_gateways_detailed_unfiltered, _get_mixnode_avg_uptime,
     _get_mixnode_inclusion_probabilities, _get_mixnode_inclusion_probability,
     _get_mixnode_reward_estimation, _get_mixnode_stake_saturation, _get_mixnode_status,
     _get_mixnodes_detailed, _get_mixnodes_detailed_unfiltered, _get_rewarded_set_detailed,
-    _mixnode_core_status_count, _mixnode_report, _mixnode_uptime_history,
+    _get_rewarded_set_selection, _mixnode_core_status_count, _mixnode_report, _mixnode_timing,
+    _mixnode_uptime_history, _network_monitor_routes_timing,
 };
 use crate::node_status_api::models::ErrorResponse;
 use crate::storage::NymApiStorage;
+use crate::support::nyxd;
 use crate::NymContractCache;
 use nym_api_requests::models::{
     AllInclusionProbabilitiesResponse, ComputeRewardEstParam, GatewayBondAnnotated,
-    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayUptimeHistoryResponse,
-    GatewayUptimeResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayCoreStatusResponse, GatewayStatusReportResponse, GatewayTimingResponse,
+    GatewayUptimeHistoryResponse, GatewayUptimeResponse, InclusionProbabilityResponse,
+    MixNodeBondAnnotated, MixnodeCoreStatusResponse, MixnodeStatusReportResponse,
+    MixnodeStatusResponse, MixnodeTimingResponse, MixnodeUptimeHistoryResponse,
+    NetworkMonitorRoutesTimingResponse, RewardEstimationResponse, RewardedSetSelectionResponse,
+    StakeSaturationResponse, UptimeResponse,
 };
-use nym_mixnet_contract_common::MixId;
+use nym_mixnet_contract_common::{EpochId, MixId};
 use rocket::serde::json::Json;
 use rocket::State;
 use rocket_okapi::openapi;
@@ -58,6 +61,16 @@ pub(crate) async fn gateway_core_status_count(
     ))
 }
 
+#[openapi(tag = "status")]
+#[get("/gateway/<identity>/timing?<since>")]
+pub(crate) async fn gateway_timing(
+    storage: &State<NymApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+) -> Result<Json<GatewayTimingResponse>, ErrorResponse> {
+    Ok(Json(_gateway_timing(storage, identity, since).await?))
+}
+
 #[openapi(tag = "status")]
 #[get("/mixnode/<mix_id>/report")]
 pub(crate) async fn mixnode_report(
@@ -88,6 +101,24 @@ pub(crate) async fn mixnode_core_status_count(
     ))
 }
 
+#[openapi(tag = "status")]
+#[get("/mixnode/<mix_id>/timing?<since>")]
+pub(crate) async fn mixnode_timing(
+    storage: &State<NymApiStorage>,
+    mix_id: MixId,
+    since: Option<i64>,
+) -> Result<Json<MixnodeTimingResponse>, ErrorResponse> {
+    Ok(Json(_mixnode_timing(storage, mix_id, since).await?))
+}
+
+#[openapi(tag = "status")]
+#[get("/network-monitor/routes/timing")]
+pub(crate) async fn network_monitor_routes_timing(
+    storage: &State<NymApiStorage>,
+) -> Result<Json<NetworkMonitorRoutesTimingResponse>, ErrorResponse> {
+    Ok(Json(_network_monitor_routes_timing(storage).await?))
+}
+
 #[openapi(tag = "status")]
 #[get("/mixnode/<mix_id>/status")]
 pub(crate) async fn get_mixnode_status(
@@ -204,6 +235,19 @@ pub async fn get_rewarded_set_detailed(
     Json(_get_rewarded_set_detailed(cache).await)
 }
 
+#[openapi(tag = "status")]
+#[get("/mixnodes/rewarded/selection?<epoch_id>")]
+pub async fn get_rewarded_set_selection(
+    storage: &State<NymApiStorage>,
+    contract_cache: &State<NymContractCache>,
+    nyxd_client: &State<nyxd::Client>,
+    epoch_id: Option<EpochId>,
+) -> Result<Json<RewardedSetSelectionResponse>, ErrorResponse> {
+    Ok(Json(
+        _get_rewarded_set_selection(storage, contract_cache, nyxd_client, epoch_id).await?,
+    ))
+}
+
 #[openapi(tag = "status")]
 #[get("/mixnodes/active/detailed")]
 pub async fn get_active_set_detailed(
@@ -1,12 +1,14 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::network_events::NetworkEvents;
 use crate::node_status_api::models::{
     GatewayStatusReport, MixnodeStatusReport, NymApiStorageError,
 };
 use crate::node_status_api::ONE_DAY;
 use crate::storage::NymApiStorage;
 use log::error;
+use nym_api_requests::events::NetworkEvent;
 use nym_task::{TaskClient, TaskManager};
 use std::time::Duration;
 use time::{OffsetDateTime, PrimitiveDateTime, Time};
@@ -14,11 +16,12 @@ use tokio::time::{interval, sleep};
 
 pub(crate) struct HistoricalUptimeUpdater {
     storage: NymApiStorage,
+    events: NetworkEvents,
 }
 
 impl HistoricalUptimeUpdater {
-    pub(crate) fn new(storage: NymApiStorage) -> Self {
-        HistoricalUptimeUpdater { storage }
+    pub(crate) fn new(storage: NymApiStorage, events: NetworkEvents) -> Self {
+        HistoricalUptimeUpdater { storage, events }
     }
 
     /// Obtains the lists of all mixnodes and gateways that were tested at least a single time
@@ -63,6 +66,12 @@ impl HistoricalUptimeUpdater {
             self.storage
                 .update_historical_uptimes(&today_iso_8601, &active_mixnodes, &active_gateways)
                 .await?;
+
+            self.events.publish(NetworkEvent::HistoricalUptimesUpdated {
+                date: today_iso_8601,
+                mixnodes: active_mixnodes.len() as u32,
+                gateways: active_gateways.len() as u32,
+            });
         }
 
         Ok(())
@@ -118,8 +127,8 @@ impl HistoricalUptimeUpdater {
         }
     }
 
-    pub(crate) fn start(storage: &NymApiStorage, shutdown: &TaskManager) {
-        let uptime_updater = HistoricalUptimeUpdater::new(storage.to_owned());
+    pub(crate) fn start(storage: &NymApiStorage, events: &NetworkEvents, shutdown: &TaskManager) {
+        let uptime_updater = HistoricalUptimeUpdater::new(storage.to_owned(), events.clone());
         let shutdown_listener = shutdown.subscribe();
         tokio::spawn(async move { uptime_updater.run(shutdown_listener).await });
     }
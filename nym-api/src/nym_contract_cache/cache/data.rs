@@ -1,6 +1,7 @@
 // Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::nym_contract_cache::cache::topology::VersionedTopology;
 use crate::support::caching::Cache;
 use nym_mixnet_contract_common::{
     families::FamilyHead, GatewayBond, IdentityKey, Interval, MixId, MixNodeDetails,
@@ -27,6 +28,8 @@ pub(crate) struct ValidatorCacheData {
 
     pub(crate) service_providers: Cache<Vec<Service>>,
     pub(crate) registered_names: Cache<Vec<NameEntry>>,
+
+    pub(crate) topology: VersionedTopology,
 }
 
 impl ValidatorCacheData {
@@ -43,6 +46,7 @@ impl ValidatorCacheData {
             mix_to_family: Cache::default(),
             service_providers: Cache::default(),
             registered_names: Cache::default(),
+            topology: VersionedTopology::new(),
         }
     }
 }
@@ -0,0 +1,200 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Network events derived from the changes in the cached contract data.
+
+use nym_api_requests::events::NetworkEvent;
+use nym_api_requests::models::MixnodeStatus;
+use nym_mixnet_contract_common::{GatewayBond, IdentityKey, Interval, MixId, MixNodeDetails};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+fn mix_ids(nodes: &[MixNodeDetails]) -> BTreeSet<MixId> {
+    nodes.iter().map(|node| node.mix_id()).collect()
+}
+
+pub(super) fn epoch_event(old: &Interval, new: &Interval) -> Option<NetworkEvent> {
+    if old.current_epoch_absolute_id() == new.current_epoch_absolute_id() {
+        return None;
+    }
+
+    Some(NetworkEvent::EpochAdvanced {
+        absolute_epoch_id: new.current_epoch_absolute_id(),
+        epoch_start: new.current_epoch_start_unix_timestamp(),
+        epoch_end: new.current_epoch_end_unix_timestamp(),
+    })
+}
+
+pub(super) fn rewarded_set_event(
+    old_rewarded_set: &[MixNodeDetails],
+    old_active_set: &[MixNodeDetails],
+    new_rewarded_set: &[MixNodeDetails],
+    new_active_set: &[MixNodeDetails],
+) -> Option<NetworkEvent> {
+    let active_set = mix_ids(new_active_set);
+    let rewarded_set = mix_ids(new_rewarded_set);
+    if active_set == mix_ids(old_active_set) && rewarded_set == mix_ids(old_rewarded_set) {
+        return None;
+    }
+
+    Some(NetworkEvent::RewardedSetChanged {
+        standby_set: rewarded_set.difference(&active_set).copied().collect(),
+        active_set: active_set.into_iter().collect(),
+    })
+}
+
+fn mixnode_statuses<'a>(
+    mixnodes: &'a [MixNodeDetails],
+    rewarded_set: &[MixNodeDetails],
+    active_set: &[MixNodeDetails],
+) -> BTreeMap<MixId, (&'a str, MixnodeStatus)> {
+    let rewarded_set = mix_ids(rewarded_set);
+    let active_set = mix_ids(active_set);
+
+    mixnodes
+        .iter()
+        .map(|node| {
+            let mix_id = node.mix_id();
+            let status = if active_set.contains(&mix_id) {
+                MixnodeStatus::Active
+            } else if rewarded_set.contains(&mix_id) {
+                MixnodeStatus::Standby
+            } else {
+                MixnodeStatus::Inactive
+            };
+            (mix_id, (node.bond_information.identity(), status))
+        })
+        .collect()
+}
+
+pub(super) fn mixnode_status_events(
+    old_mixnodes: &[MixNodeDetails],
+    old_rewarded_set: &[MixNodeDetails],
+    old_active_set: &[MixNodeDetails],
+    new_mixnodes: &[MixNodeDetails],
+    new_rewarded_set: &[MixNodeDetails],
+    new_active_set: &[MixNodeDetails],
+) -> Vec<NetworkEvent> {
+    let old = mixnode_statuses(old_mixnodes, old_rewarded_set, old_active_set);
+    let new = mixnode_statuses(new_mixnodes, new_rewarded_set, new_active_set);
+
+    let mut events = Vec::new();
+    for (mix_id, (identity, current)) in &new {
+        let previous = old
+            .get(mix_id)
+            .map(|(_, status)| *status)
+            .unwrap_or(MixnodeStatus::NotFound);
+        if previous != *current {
+            events.push(NetworkEvent::MixnodeStatusChanged {
+                mix_id: *mix_id,
+                identity: identity.to_string(),
+                previous,
+                current: *current,
+            })
+        }
+    }
+
+    for (mix_id, (identity, previous)) in old {
+        if !new.contains_key(&mix_id) {
+            events.push(NetworkEvent::MixnodeStatusChanged {
+                mix_id,
+                identity: identity.to_string(),
+                previous,
+                current: MixnodeStatus::NotFound,
+            })
+        }
+    }
+
+    events
+}
+
+pub(super) fn gateway_events(old: &[GatewayBond], new: &[GatewayBond]) -> Vec<NetworkEvent> {
+    let old = old
+        .iter()
+        .map(|gateway| gateway.identity())
+        .collect::<BTreeSet<_>>();
+    let new = new
+        .iter()
+        .map(|gateway| gateway.identity())
+        .collect::<BTreeSet<_>>();
+
+    let bonded = new
+        .difference(&old)
+        .map(|identity| NetworkEvent::GatewayBonded {
+            identity: identity.to_string(),
+        });
+    let unbonded = old
+        .difference(&new)
+        .map(|identity| NetworkEvent::GatewayUnbonded {
+            identity: identity.to_string(),
+        });
+
+    bonded.chain(unbonded).collect()
+}
+
+pub(super) fn mixnode_blacklist_events(
+    old: &HashSet<MixId>,
+    new: &HashSet<MixId>,
+) -> Vec<NetworkEvent> {
+    let added = new
+        .difference(old)
+        .map(|&mix_id| NetworkEvent::MixnodeBlacklistChanged {
+            mix_id,
+            blacklisted: true,
+        });
+    let removed = old
+        .difference(new)
+        .map(|&mix_id| NetworkEvent::MixnodeBlacklistChanged {
+            mix_id,
+            blacklisted: false,
+        });
+
+    added.chain(removed).collect()
+}
+
+pub(super) fn gateway_blacklist_events(
+    old: &HashSet<IdentityKey>,
+    new: &HashSet<IdentityKey>,
+) -> Vec<NetworkEvent> {
+    let added = new
+        .difference(old)
+        .map(|identity| NetworkEvent::GatewayBlacklistChanged {
+            identity: identity.clone(),
+            blacklisted: true,
+        });
+    let removed = old
+        .difference(new)
+        .map(|identity| NetworkEvent::GatewayBlacklistChanged {
+            identity: identity.clone(),
+            blacklisted: false,
+        });
+
+    added.chain(removed).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blacklist_changes() {
+        let old = HashSet::from([1, 2, 3]);
+        let new = HashSet::from([2, 3, 4]);
+
+        let events = mixnode_blacklist_events(&old, &new);
+        assert_eq!(
+            events,
+            vec![
+                NetworkEvent::MixnodeBlacklistChanged {
+                    mix_id: 4,
+                    blacklisted: true
+                },
+                NetworkEvent::MixnodeBlacklistChanged {
+                    mix_id: 1,
+                    blacklisted: false
+                },
+            ]
+        );
+
+        assert!(mixnode_blacklist_events(&new, &new).is_empty());
+    }
+}
@@ -1,6 +1,9 @@
+use crate::network_events::NetworkEvents;
 use crate::support::caching::Cache;
 use data::ValidatorCacheData;
+use nym_api_requests::events::NetworkEvent;
 use nym_api_requests::models::MixnodeStatus;
+use nym_api_requests::topology::{CompactTopology, TopologyChangesResponse};
 use nym_mixnet_contract_common::{
     families::FamilyHead, GatewayBond, IdentityKey, Interval, MixId, MixNodeBond, MixNodeDetails,
     RewardingParams,
@@ -20,28 +23,66 @@ use tokio::sync::RwLock;
 use tokio::time;
 
 mod data;
+mod events;
 pub(crate) mod refresher;
+mod topology;
 
 #[derive(Clone)]
 pub struct NymContractCache {
     pub(crate) initialised: Arc<AtomicBool>,
     pub(crate) inner: Arc<RwLock<ValidatorCacheData>>,
+    events: NetworkEvents,
 }
 
 impl NymContractCache {
-    fn new() -> Self {
+    fn new(events: NetworkEvents) -> Self {
         NymContractCache {
             initialised: Arc::new(AtomicBool::new(false)),
             inner: Arc::new(RwLock::new(ValidatorCacheData::new())),
+            events,
         }
     }
 
-    pub fn stage() -> AdHoc {
+    pub(crate) fn stage(events: NetworkEvents) -> AdHoc {
         AdHoc::on_ignite("Validator Cache Stage", |rocket| async {
-            rocket.manage(Self::new())
+            rocket.manage(Self::new(events))
         })
     }
 
+    // determine what has changed since the last update
+    fn contract_data_events(
+        cache: &ValidatorCacheData,
+        mixnodes: &[MixNodeDetails],
+        gateways: &[GatewayBond],
+        rewarded_set: &[MixNodeDetails],
+        active_set: &[MixNodeDetails],
+        current_interval: &Interval,
+    ) -> Vec<NetworkEvent> {
+        // nothing has "changed" if there was nothing there before
+        let Some(old_interval) = &cache.current_interval.value else {
+            return Vec::new();
+        };
+
+        let mut network_events = Vec::new();
+        network_events.extend(events::epoch_event(old_interval, current_interval));
+        network_events.extend(events::rewarded_set_event(
+            &cache.rewarded_set,
+            &cache.active_set,
+            rewarded_set,
+            active_set,
+        ));
+        network_events.extend(events::mixnode_status_events(
+            &cache.mixnodes,
+            &cache.rewarded_set,
+            &cache.active_set,
+            mixnodes,
+            rewarded_set,
+            active_set,
+        ));
+        network_events.extend(events::gateway_events(&cache.gateways, gateways));
+        network_events
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) async fn update(
         &self,
@@ -57,6 +98,35 @@ impl NymContractCache {
     ) {
         match time::timeout(Duration::from_millis(100), self.inner.write()).await {
             Ok(mut cache) => {
+                let network_events = Self::contract_data_events(
+                    &cache,
+                    &mixnodes,
+                    &gateways,
+                    &rewarded_set,
+                    &active_set,
+                    &current_interval,
+                );
+
+                let topology_gateways = gateways
+                    .iter()
+                    .filter(|gateway| !cache.gateways_blacklist.contains(gateway.identity()))
+                    .cloned()
+                    .collect();
+                let topology_mixnodes = active_set
+                    .iter()
+                    .map(|mix| mix.bond_information.clone())
+                    .collect();
+                if cache.topology.update(
+                    current_interval.current_epoch_absolute_id(),
+                    topology_mixnodes,
+                    topology_gateways,
+                ) {
+                    debug!(
+                        "topology got updated to version {}",
+                        cache.topology.version()
+                    );
+                }
+
                 cache.mixnodes.update(mixnodes);
                 cache.gateways.update(gateways);
                 cache.rewarded_set.update(rewarded_set);
@@ -67,6 +137,9 @@ impl NymContractCache {
                 // Just return empty lists when these are not available
                 cache.service_providers.update(services.unwrap_or_default());
                 cache.registered_names.update(names.unwrap_or_default());
+
+                drop(cache);
+                self.events.publish_all(network_events);
             }
             Err(err) => {
                 error!("{err}");
@@ -110,7 +183,12 @@ impl NymContractCache {
         }
         match time::timeout(Duration::from_millis(100), self.inner.write()).await {
             Ok(mut cache) => {
+                let network_events =
+                    events::mixnode_blacklist_events(&cache.mixnodes_blacklist, &blacklist);
                 cache.mixnodes_blacklist.update(blacklist);
+
+                drop(cache);
+                self.events.publish_all(network_events);
             }
             Err(err) => {
                 error!("Failed to update mixnodes blacklist: {err}");
@@ -138,7 +216,12 @@ impl NymContractCache {
         }
         match time::timeout(Duration::from_millis(100), self.inner.write()).await {
             Ok(mut cache) => {
+                let network_events =
+                    events::gateway_blacklist_events(&cache.gateways_blacklist, &blacklist);
                 cache.gateways_blacklist.update(blacklist);
+
+                drop(cache);
+                self.events.publish_all(network_events);
             }
             Err(err) => {
                 error!("Failed to update gateways blacklist: {err}");
@@ -313,6 +396,36 @@ impl NymContractCache {
         }
     }
 
+    pub(crate) async fn topology(&self) -> Option<CompactTopology> {
+        match time::timeout(Duration::from_millis(100), self.inner.read()).await {
+            Ok(cache) => Some(cache.topology.full()),
+            Err(err) => {
+                error!("{err}");
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn topology_version(&self) -> Option<u64> {
+        match time::timeout(Duration::from_millis(100), self.inner.read()).await {
+            Ok(cache) => Some(cache.topology.version()),
+            Err(err) => {
+                error!("{err}");
+                None
+            }
+        }
+    }
+
+    pub(crate) async fn topology_changes(&self, since: u64) -> Option<TopologyChangesResponse> {
+        match time::timeout(Duration::from_millis(100), self.inner.read()).await {
+            Ok(cache) => Some(cache.topology.changes_since(since)),
+            Err(err) => {
+                error!("{err}");
+                None
+            }
+        }
+    }
+
     pub fn initialised(&self) -> bool {
         self.initialised.load(Ordering::Relaxed)
     }
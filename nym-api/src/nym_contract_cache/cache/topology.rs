@@ -0,0 +1,290 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_api_requests::topology::{CompactTopology, TopologyChangesResponse, TopologyDiff};
+use nym_mixnet_contract_common::{EpochId, GatewayBond, IdentityKey, MixId, MixNodeBond};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use time::OffsetDateTime;
+
+// with the default caching interval this is well over a day's worth of changes
+const MAX_TOPOLOGY_HISTORY: usize = 512;
+
+/// Topology (active set mixnodes and gateways) served to the clients alongside its version
+/// and the history of the recent changes.
+///
+/// The version is derived from the unix timestamp of the change, so that it stays monotonically
+/// increasing even across the restarts of the api. Note that versions are local to given api
+/// instance and thus clients must not use them with a different one.
+pub(crate) struct VersionedTopology {
+    version: u64,
+    epoch_id: EpochId,
+    mixnodes: BTreeMap<MixId, MixNodeBond>,
+    gateways: BTreeMap<IdentityKey, GatewayBond>,
+
+    history: VecDeque<TopologyDiff>,
+}
+
+impl VersionedTopology {
+    pub(crate) fn new() -> Self {
+        VersionedTopology {
+            version: 0,
+            epoch_id: 0,
+            mixnodes: BTreeMap::new(),
+            gateways: BTreeMap::new(),
+            history: VecDeque::new(),
+        }
+    }
+
+    pub(crate) fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn next_version(&self) -> u64 {
+        let now = OffsetDateTime::now_utc().unix_timestamp() as u64;
+        (self.version + 1).max(now)
+    }
+
+    /// Updates the topology with the new data and bumps its version if the epoch
+    /// has advanced or any of the nodes has changed.
+    ///
+    /// Returns a boolean indicating whether the version got bumped.
+    pub(crate) fn update(
+        &mut self,
+        epoch_id: EpochId,
+        mixnodes: Vec<MixNodeBond>,
+        gateways: Vec<GatewayBond>,
+    ) -> bool {
+        let mixnodes = mixnodes
+            .into_iter()
+            .map(|mix| (mix.mix_id, mix))
+            .collect::<BTreeMap<_, _>>();
+        let gateways = gateways
+            .into_iter()
+            .map(|gateway| (gateway.identity().clone(), gateway))
+            .collect::<BTreeMap<_, _>>();
+
+        let diff = TopologyDiff {
+            from_version: self.version,
+            version: self.next_version(),
+            epoch_id,
+            added_or_updated_mixnodes: changed_entries(&self.mixnodes, &mixnodes),
+            removed_mixnodes: removed_keys(&self.mixnodes, &mixnodes),
+            added_or_updated_gateways: changed_entries(&self.gateways, &gateways),
+            removed_gateways: removed_keys(&self.gateways, &gateways),
+        };
+
+        if diff.is_empty() && epoch_id == self.epoch_id {
+            return false;
+        }
+
+        // the very first topology is not a change anyone could have observed
+        if self.version != 0 {
+            if self.history.len() == MAX_TOPOLOGY_HISTORY {
+                self.history.pop_front();
+            }
+            self.history.push_back(diff.clone());
+        }
+
+        self.version = diff.version;
+        self.epoch_id = epoch_id;
+        self.mixnodes = mixnodes;
+        self.gateways = gateways;
+        true
+    }
+
+    pub(crate) fn full(&self) -> CompactTopology {
+        CompactTopology {
+            version: self.version,
+            epoch_id: self.epoch_id,
+            mixnodes: self.mixnodes.values().cloned().collect(),
+            gateways: self.gateways.values().cloned().collect(),
+        }
+    }
+
+    /// Returns all changes to the topology since the provided version or the full topology
+    /// if the version is not known (anymore).
+    pub(crate) fn changes_since(&self, since: u64) -> TopologyChangesResponse {
+        if since == self.version {
+            return TopologyChangesResponse::Diff(TopologyDiff {
+                from_version: since,
+                version: self.version,
+                epoch_id: self.epoch_id,
+                ..Default::default()
+            });
+        }
+
+        let Some(start) = self
+            .history
+            .iter()
+            .position(|diff| diff.from_version == since)
+        else {
+            return TopologyChangesResponse::Full(self.full());
+        };
+
+        let mut mixnodes = BTreeMap::new();
+        let mut removed_mixnodes = BTreeSet::new();
+        let mut gateways = BTreeMap::new();
+        let mut removed_gateways = BTreeSet::new();
+
+        for diff in self.history.range(start..) {
+            for mix in &diff.added_or_updated_mixnodes {
+                removed_mixnodes.remove(&mix.mix_id);
+                mixnodes.insert(mix.mix_id, mix.clone());
+            }
+            for mix_id in &diff.removed_mixnodes {
+                mixnodes.remove(mix_id);
+                removed_mixnodes.insert(*mix_id);
+            }
+            for gateway in &diff.added_or_updated_gateways {
+                removed_gateways.remove(gateway.identity());
+                gateways.insert(gateway.identity().clone(), gateway.clone());
+            }
+            for identity in &diff.removed_gateways {
+                gateways.remove(identity);
+                removed_gateways.insert(identity.clone());
+            }
+        }
+
+        TopologyChangesResponse::Diff(TopologyDiff {
+            from_version: since,
+            version: self.version,
+            epoch_id: self.epoch_id,
+            added_or_updated_mixnodes: mixnodes.into_values().collect(),
+            removed_mixnodes: removed_mixnodes.into_iter().collect(),
+            added_or_updated_gateways: gateways.into_values().collect(),
+            removed_gateways: removed_gateways.into_iter().collect(),
+        })
+    }
+}
+
+fn changed_entries<K: Ord, V: Clone + PartialEq>(
+    old: &BTreeMap<K, V>,
+    new: &BTreeMap<K, V>,
+) -> Vec<V> {
+    new.iter()
+        .filter(|(key, value)| old.get(key) != Some(value))
+        .map(|(_, value)| value.clone())
+        .collect()
+}
+
+fn removed_keys<K: Ord + Clone, V>(old: &BTreeMap<K, V>, new: &BTreeMap<K, V>) -> Vec<K> {
+    old.keys()
+        .filter(|key| !new.contains_key(key))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{coin, Addr};
+    use nym_mixnet_contract_common::{Gateway, Layer, MixNode};
+
+    fn mixnode(mix_id: MixId, version: &str) -> MixNodeBond {
+        MixNodeBond {
+            mix_id,
+            owner: Addr::unchecked(format!("owner{mix_id}")),
+            original_pledge: coin(100_000_000, "unym"),
+            layer: Layer::One,
+            mix_node: MixNode {
+                host: "1.2.3.4".to_string(),
+                mix_port: 1789,
+                verloc_port: 1790,
+                http_api_port: 8000,
+                sphinx_key: format!("sphinx{mix_id}"),
+                identity_key: format!("identity{mix_id}"),
+                version: version.to_string(),
+            },
+            proxy: None,
+            bonding_height: 123,
+            is_unbonding: false,
+        }
+    }
+
+    fn gateway(identity: &str) -> GatewayBond {
+        GatewayBond {
+            pledge_amount: coin(100_000_000, "unym"),
+            owner: Addr::unchecked(format!("owner-{identity}")),
+            block_height: 123,
+            gateway: Gateway {
+                host: "1.2.3.4".to_string(),
+                mix_port: 1789,
+                clients_port: 9000,
+                location: "Nym Land".to_string(),
+                sphinx_key: format!("sphinx-{identity}"),
+                identity_key: identity.to_string(),
+                version: "1.1.0".to_string(),
+            },
+            proxy: None,
+        }
+    }
+
+    #[test]
+    fn version_is_only_bumped_on_changes() {
+        let mut topology = VersionedTopology::new();
+        assert!(topology.update(1, vec![mixnode(1, "1.1.0")], vec![gateway("gw1")]));
+        let initial = topology.version();
+        assert!(initial > 0);
+
+        assert!(!topology.update(1, vec![mixnode(1, "1.1.0")], vec![gateway("gw1")]));
+        assert_eq!(initial, topology.version());
+
+        assert!(topology.update(1, vec![mixnode(1, "1.1.1")], vec![gateway("gw1")]));
+        assert!(topology.version() > initial);
+
+        let updated = topology.version();
+        assert!(topology.update(2, vec![mixnode(1, "1.1.1")], vec![gateway("gw1")]));
+        assert!(topology.version() > updated);
+    }
+
+    #[test]
+    fn changes_since_known_version_can_be_applied() {
+        let mut topology = VersionedTopology::new();
+        topology.update(
+            1,
+            vec![mixnode(1, "1.1.0"), mixnode(2, "1.1.0")],
+            vec![gateway("gw1"), gateway("gw2")],
+        );
+        let mut client_topology = topology.full();
+
+        topology.update(
+            1,
+            vec![
+                mixnode(1, "1.1.1"),
+                mixnode(2, "1.1.0"),
+                mixnode(3, "1.1.0"),
+            ],
+            vec![gateway("gw1")],
+        );
+        topology.update(
+            2,
+            vec![mixnode(1, "1.1.1"), mixnode(3, "1.1.0")],
+            vec![gateway("gw1"), gateway("gw3")],
+        );
+
+        let changes = topology.changes_since(client_topology.version);
+        let TopologyChangesResponse::Diff(diff) = &changes else {
+            panic!("expected a diff")
+        };
+        assert_eq!(diff.removed_mixnodes, vec![2]);
+        assert_eq!(diff.added_or_updated_mixnodes.len(), 2);
+        assert_eq!(diff.removed_gateways, vec!["gw2".to_string()]);
+
+        client_topology.apply_changes(changes).unwrap();
+        assert_eq!(client_topology, topology.full());
+    }
+
+    #[test]
+    fn unknown_version_results_in_full_topology() {
+        let mut topology = VersionedTopology::new();
+        topology.update(1, vec![mixnode(1, "1.1.0")], vec![gateway("gw1")]);
+
+        assert_eq!(
+            topology.changes_since(42),
+            TopologyChangesResponse::Full(topology.full())
+        );
+
+        let diff = topology.changes_since(topology.version());
+        assert!(matches!(diff, TopologyChangesResponse::Diff(diff) if diff.is_empty()));
+    }
+}
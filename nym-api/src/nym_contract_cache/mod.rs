@@ -28,7 +28,9 @@ pub(crate) fn nym_contract_cache_routes(settings: &OpenApiSettings) -> (Vec<Rout
         routes::get_interval_reward_params,
         routes::get_current_epoch,
         routes::get_services,
-        routes::get_registered_names
+        routes::get_registered_names,
+        routes::get_topology,
+        routes::get_topology_changes
     ]
 }
 
@@ -4,18 +4,21 @@
 use crate::{
     node_status_api::{
         helpers::{_get_active_set_detailed, _get_mixnodes_detailed, _get_rewarded_set_detailed},
+        models::ErrorResponse,
         NodeStatusCache,
     },
     nym_contract_cache::cache::NymContractCache,
+    support::http::conditional::{IfNoneMatch, Versioned},
 };
 use nym_api_requests::models::MixNodeBondAnnotated;
+use nym_api_requests::topology::{CompactTopology, TopologyChangesResponse};
 use nym_mixnet_contract_common::{
     mixnode::MixNodeDetails, reward_params::RewardingParams, GatewayBond, Interval, MixId,
 };
 
 use nym_name_service_common::response::NamesListResponse;
 use nym_service_provider_directory_common::response::ServicesListResponse;
-use rocket::{serde::json::Json, State};
+use rocket::{http::Status, serde::json::Json, State};
 use rocket_okapi::openapi;
 use std::collections::HashSet;
 
@@ -141,3 +144,48 @@ pub async fn get_registered_names(cache: &State<NymContractCache>) -> Json<Names
     let names = cache.names().await.value;
     Json(names.as_slice().into())
 }
+
+fn topology_unavailable() -> ErrorResponse {
+    ErrorResponse::new("topology is not available yet", Status::ServiceUnavailable)
+}
+
+async fn current_topology_version(cache: &NymContractCache) -> Result<u64, ErrorResponse> {
+    match cache.topology_version().await {
+        None | Some(0) => Err(topology_unavailable()),
+        Some(version) => Ok(version),
+    }
+}
+
+#[openapi(tag = "contract-cache")]
+#[get("/topology")]
+pub async fn get_topology(
+    cache: &State<NymContractCache>,
+    if_none_match: IfNoneMatch,
+) -> Result<Versioned<CompactTopology>, ErrorResponse> {
+    let version = current_topology_version(cache).await?;
+    if if_none_match.matches_version(version) {
+        return Ok(Versioned::not_modified(version));
+    }
+
+    let topology = cache.topology().await.ok_or_else(topology_unavailable)?;
+    Ok(Versioned::modified(topology.version, topology))
+}
+
+#[openapi(tag = "contract-cache")]
+#[get("/topology/changes?<since>")]
+pub async fn get_topology_changes(
+    cache: &State<NymContractCache>,
+    since: u64,
+    if_none_match: IfNoneMatch,
+) -> Result<Versioned<TopologyChangesResponse>, ErrorResponse> {
+    let version = current_topology_version(cache).await?;
+    if if_none_match.matches_version(version) {
+        return Ok(Versioned::not_modified(version));
+    }
+
+    let changes = cache
+        .topology_changes(since)
+        .await
+        .ok_or_else(topology_unavailable)?;
+    Ok(Versioned::modified(changes.version(), changes))
+}
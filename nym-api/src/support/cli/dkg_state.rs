@@ -0,0 +1,35 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::coconut::dkg::client::DkgClient;
+use crate::coconut::dkg::status::dkg_state_report;
+use crate::support::config::Config;
+use crate::support::nyxd;
+use anyhow::Result;
+use nym_bin_common::output_format::OutputFormat;
+use nym_coconut_dkg_common::types::EpochId;
+
+#[derive(clap::Args, Clone)]
+pub(crate) struct Args {
+    /// Id of the DKG epoch to report on. If not provided, the current epoch is used
+    #[clap(long)]
+    pub(crate) epoch_id: Option<EpochId>,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    pub(crate) output: OutputFormat,
+}
+
+pub(crate) async fn execute(config: &Config, args: Args) -> Result<()> {
+    let dkg_client = DkgClient::new(nyxd::Client::new(config));
+    let report = dkg_state_report(
+        &dkg_client,
+        &config
+            .coconut_signer
+            .storage_paths
+            .dkg_persistent_state_path,
+        args.epoch_id,
+    )
+    .await?;
+    println!("{}", args.output.format(&report));
+    Ok(())
+}
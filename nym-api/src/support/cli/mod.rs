@@ -6,12 +6,14 @@ use crate::support::config::default_config_filepath;
 use crate::support::config::helpers::{initialise_new, try_load_current_config};
 use ::nym_config::defaults::var_names::{MIXNET_CONTRACT_ADDRESS, VESTING_CONTRACT_ADDRESS};
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use lazy_static::lazy_static;
 use nym_bin_common::build_information::BinaryBuildInformation;
 use nym_config::OptionalSet;
 use nym_validator_client::nyxd;
 
+pub(crate) mod dkg_state;
+
 lazy_static! {
     pub static ref PRETTY_BUILD_INFORMATION: String =
         BinaryBuildInformation::new(env!("CARGO_PKG_VERSION")).pretty_print();
@@ -36,9 +38,18 @@ fn threshold_in_range(s: &str) -> Result<u8, String> {
     }
 }
 
+#[derive(Subcommand, Clone)]
+pub(crate) enum Commands {
+    /// Report the local DKG state of this signer and compare it with the coconut-dkg contract
+    DkgState(dkg_state::Args),
+}
+
 #[derive(Parser)]
 #[clap(author = "Nymtech", version, long_version = pretty_build_info_static(), about)]
 pub(crate) struct CliArgs {
+    #[clap(subcommand)]
+    pub(crate) command: Option<Commands>,
+
     /// Path pointing to an env file that configures the Nym API.
     #[clap(short, long)]
     pub(crate) config_env_file: Option<std::path::PathBuf>,
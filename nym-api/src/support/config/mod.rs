@@ -50,6 +50,10 @@ const DEFAULT_PER_NODE_TEST_PACKETS: usize = 3;
 const DEFAULT_TOPOLOGY_CACHE_INTERVAL: Duration = Duration::from_secs(30);
 const DEFAULT_NODE_STATUS_CACHE_INTERVAL: Duration = Duration::from_secs(120);
 const DEFAULT_CIRCULATING_SUPPLY_CACHE_INTERVAL: Duration = Duration::from_secs(3600);
+// mixnodes only rerun their verloc measurements every few hours, so there's no point in querying them more often
+const DEFAULT_LATENCY_CACHE_INTERVAL: Duration = Duration::from_secs(3600);
+const DEFAULT_VERLOC_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_MAX_CONCURRENT_VERLOC_REQUESTS: usize = 50;
 const DEFAULT_MONITOR_THRESHOLD: u8 = 60;
 const DEFAULT_MIN_MIXNODE_RELIABILITY: u8 = 50;
 const DEFAULT_MIN_GATEWAY_RELIABILITY: u8 = 20;
@@ -93,6 +97,9 @@ pub struct Config {
 
     pub circulating_supply_cacher: CirculatingSupplyCacher,
 
+    #[serde(default)]
+    pub latency_cacher: LatencyCacher,
+
     pub rewarding: Rewarding,
 
     pub coconut_signer: CoconutSigner,
@@ -113,6 +120,7 @@ impl Config {
             node_status_api: NodeStatusAPI::new_default(&base_data_dir),
             topology_cacher: Default::default(),
             circulating_supply_cacher: Default::default(),
+            latency_cacher: Default::default(),
             rewarding: Default::default(),
             coconut_signer: CoconutSigner::new_default(base_data_dir),
         }
@@ -443,6 +451,50 @@ impl Default for CirculatingSupplyCacherDebug {
     }
 }
 
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct LatencyCacher {
+    /// Specifies whether the verloc measurements of bonded mixnodes should be periodically collected
+    /// and aggregated into a network-wide latency matrix.
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub debug: LatencyCacherDebug,
+}
+
+impl Default for LatencyCacher {
+    fn default() -> Self {
+        LatencyCacher {
+            enabled: true,
+            debug: LatencyCacherDebug::default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct LatencyCacherDebug {
+    #[serde(with = "humantime_serde")]
+    pub caching_interval: Duration,
+
+    /// Timeout for querying verloc results of a single mixnode.
+    #[serde(with = "humantime_serde")]
+    pub request_timeout: Duration,
+
+    /// Maximum number of mixnodes queried at the same time.
+    pub max_concurrent_requests: usize,
+}
+
+impl Default for LatencyCacherDebug {
+    fn default() -> Self {
+        LatencyCacherDebug {
+            caching_interval: DEFAULT_LATENCY_CACHE_INTERVAL,
+            request_timeout: DEFAULT_VERLOC_REQUEST_TIMEOUT,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_VERLOC_REQUESTS,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
 pub struct Rewarding {
@@ -451,6 +503,9 @@ pub struct Rewarding {
 
     // this should really be a thing too...
     // pub paths: RewardingPathfinder,
+    #[serde(default)]
+    pub layer_assignment: LayerAssignment,
+
     #[serde(default)]
     pub debug: RewardingDebug,
 }
@@ -460,11 +515,42 @@ impl Default for Rewarding {
     fn default() -> Self {
         Rewarding {
             enabled: false,
+            layer_assignment: Default::default(),
             debug: Default::default(),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayerSpreading {
+    /// Nodes are only balanced between layers (while keeping families together).
+    None,
+
+    /// Nodes are additionally spread across layers based on their autonomous system number.
+    Asn,
+
+    /// Nodes are additionally spread across layers based on their country.
+    Country,
+}
+
+impl Default for LayerSpreading {
+    fn default() -> Self {
+        LayerSpreading::None
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct LayerAssignment {
+    /// Specifies whether nodes should additionally be spread across layers based on their location.
+    pub spreading: LayerSpreading,
+
+    /// Path to a JSON file containing location information (ASN and country) of mixnodes, keyed by their identity keys.
+    /// It is only used if `spreading` is enabled.
+    pub node_locations_path: Option<PathBuf>,
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
 pub struct RewardingDebug {
@@ -134,8 +134,10 @@ impl From<ConfigV1_1_21> for Config {
                     caching_interval: value.circulating_supply_cacher.caching_interval,
                 },
             },
+            latency_cacher: Default::default(),
             rewarding: Rewarding {
                 enabled: value.rewarding.enabled,
+                layer_assignment: Default::default(),
                 debug: RewardingDebug {
                     minimum_interval_monitor_threshold: value
                         .rewarding
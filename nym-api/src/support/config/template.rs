@@ -91,6 +91,21 @@ enabled = {{ circulating_supply_cacher.enabled }}
 caching_interval = '{{ circulating_supply_cacher.debug.caching_interval }}'
 
 
+##### latency cacher config options #####
+
+[latency_cacher]
+
+# Specifies whether the verloc measurements of bonded mixnodes should be periodically collected
+# and aggregated into a network-wide latency matrix.
+enabled = {{ latency_cacher.enabled }}
+
+[latency_cacher.debug]
+
+caching_interval = '{{ latency_cacher.debug.caching_interval }}'
+request_timeout = '{{ latency_cacher.debug.request_timeout }}'
+max_concurrent_requests = {{ latency_cacher.debug.max_concurrent_requests }}
+
+
 ##### rewarding config options #####
 
 [rewarding]
@@ -105,6 +120,19 @@ enabled = {{ rewarding.enabled }}
 # Note, only values in range 0-100 are valid
 minimum_interval_monitor_threshold = {{ rewarding.debug.minimum_interval_monitor_threshold }}
 
+[rewarding.layer_assignment]
+
+# Specifies whether nodes should additionally be spread across layers based on their location.
+# Possible values are 'none', 'asn' and 'country'.
+spreading = '{{ rewarding.layer_assignment.spreading }}'
+
+# Path to a JSON file containing location information of mixnodes, keyed by their identity keys,
+# for example: { "<identity key>": { "asn": 24940, "country": "DE" } }
+# It is only used if spreading is enabled.
+{{#if rewarding.layer_assignment.node_locations_path }}
+node_locations_path = '{{ rewarding.layer_assignment.node_locations_path }}'
+{{/if}}
+
 [coconut_signer]
 
 # Specifies whether coconut signing protocol is enabled in this process.
@@ -0,0 +1,125 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use okapi::openapi3::Responses;
+use rocket::http::{Header, Status};
+use rocket::request::{FromRequest, Outcome};
+use rocket::response::{self, Responder, Response};
+use rocket::serde::json::Json;
+use rocket::Request;
+use rocket_okapi::gen::OpenApiGenerator;
+use rocket_okapi::request::{OpenApiFromRequest, RequestHeaderInput};
+use rocket_okapi::response::OpenApiResponderInner;
+use rocket_okapi::util::ensure_status_code_exists;
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::convert::Infallible;
+
+const ETAG_HEADER: &str = "ETag";
+const IF_NONE_MATCH_HEADER: &str = "If-None-Match";
+
+fn version_etag(version: u64) -> String {
+    format!("\"{version}\"")
+}
+
+/// Request guard exposing the value of the `If-None-Match` header (if present).
+pub(crate) struct IfNoneMatch(Option<String>);
+
+impl IfNoneMatch {
+    fn matches(&self, etag: &str) -> bool {
+        let Some(header) = &self.0 else {
+            return false;
+        };
+
+        header
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+    }
+
+    /// Checks whether the client already has the provided version of the data.
+    pub(crate) fn matches_version(&self, version: u64) -> bool {
+        self.matches(&version_etag(version))
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for IfNoneMatch {
+    type Error = Infallible;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let header = request.headers().get_one(IF_NONE_MATCH_HEADER);
+        Outcome::Success(IfNoneMatch(header.map(ToOwned::to_owned)))
+    }
+}
+
+impl<'a> OpenApiFromRequest<'a> for IfNoneMatch {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::None)
+    }
+}
+
+/// Json response tagged with an `ETag` derived from the version of the underlying data,
+/// that results in `304 Not Modified` if the client already has that version.
+pub(crate) enum Versioned<T> {
+    NotModified { etag: String },
+    Modified { etag: String, value: Json<T> },
+}
+
+impl<T> Versioned<T> {
+    pub(crate) fn not_modified(version: u64) -> Self {
+        Versioned::NotModified {
+            etag: version_etag(version),
+        }
+    }
+
+    pub(crate) fn modified(version: u64, value: T) -> Self {
+        Versioned::Modified {
+            etag: version_etag(version),
+            value: Json(value),
+        }
+    }
+}
+
+impl<'r, 'o: 'r, T: Serialize> Responder<'r, 'o> for Versioned<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'o> {
+        match self {
+            Versioned::NotModified { etag } => Response::build()
+                .status(Status::NotModified)
+                .header(Header::new(ETAG_HEADER, etag))
+                .ok(),
+            Versioned::Modified { etag, value } => Response::build()
+                .merge(value.respond_to(req)?)
+                .header(Header::new(ETAG_HEADER, etag))
+                .ok(),
+        }
+    }
+}
+
+impl<T: Serialize + JsonSchema + Send> OpenApiResponderInner for Versioned<T> {
+    fn responses(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<Responses> {
+        let mut responses = Json::<T>::responses(gen)?;
+        ensure_status_code_exists(&mut responses, 304);
+        Ok(responses)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matching_versions() {
+        assert!(!IfNoneMatch(None).matches_version(42));
+        assert!(IfNoneMatch(Some("\"42\"".into())).matches_version(42));
+        assert!(IfNoneMatch(Some("W/\"42\"".into())).matches_version(42));
+        assert!(IfNoneMatch(Some("\"41\", \"42\"".into())).matches_version(42));
+        assert!(IfNoneMatch(Some("*".into())).matches_version(42));
+        assert!(!IfNoneMatch(Some("\"41\"".into())).matches_version(42));
+        assert!(!IfNoneMatch(Some("42".into())).matches_version(42));
+    }
+}
@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::circulating_supply_api::cache::CirculatingSupplyCache;
+use crate::coconut::dkg::status::DkgStatus;
 use crate::coconut::{self, comm::QueryCommunicationChannel, InternalSignRequest};
+use crate::latency_api::cache::LatencyCache;
+use crate::network_events::NetworkEvents;
 use crate::node_status_api::{self, NodeStatusCache};
 use crate::nym_contract_cache::cache::NymContractCache;
 use crate::support::config::Config;
 use crate::support::{nyxd, storage};
-use crate::{circulating_supply_api, nym_contract_cache};
+use crate::{circulating_supply_api, latency_api, network_events, nym_contract_cache};
 use anyhow::Result;
 use rocket::http::Method;
 use rocket::{Ignite, Rocket};
@@ -15,6 +18,7 @@ use rocket_cors::{AllowedHeaders, AllowedOrigins, Cors};
 use rocket_okapi::mount_endpoints_and_merged_docs;
 use rocket_okapi::swagger_ui::make_swagger_ui;
 
+pub(crate) mod conditional;
 pub(crate) mod openapi;
 
 pub(crate) async fn setup_rocket(
@@ -33,15 +37,21 @@ pub(crate) async fn setup_rocket(
         "/" => (vec![], openapi::custom_openapi_spec()),
         "" => circulating_supply_api::circulating_supply_routes(&openapi_settings),
         "" => nym_contract_cache::nym_contract_cache_routes(&openapi_settings),
+        "" => latency_api::latency_routes(&openapi_settings),
+        "" => network_events::network_events_routes(&openapi_settings),
         "/status" => node_status_api::node_status_routes(&openapi_settings, config.network_monitor.enabled),
     }
 
+    let network_events = NetworkEvents::new();
+
     let rocket = rocket
         .mount("/swagger", make_swagger_ui(&openapi::get_docs()))
         .attach(setup_cors()?)
-        .attach(NymContractCache::stage())
+        .attach(network_events.clone().stage())
+        .attach(NymContractCache::stage(network_events))
         .attach(NodeStatusCache::stage())
-        .attach(CirculatingSupplyCache::stage(mix_denom.clone()));
+        .attach(CirculatingSupplyCache::stage(mix_denom.clone()))
+        .attach(LatencyCache::stage());
 
     // This is not a very nice approach. A lazy value would be more suitable, but that's still
     // a nightly feature: https://github.com/rust-lang/rust/issues/74465
@@ -56,20 +66,31 @@ pub(crate) async fn setup_rocket(
 
     let rocket = if config.coconut_signer.enabled {
         let comm_channel = QueryCommunicationChannel::new(_nyxd_client.clone());
-        rocket.attach(InternalSignRequest::stage(
-            _nyxd_client.clone(),
-            mix_denom,
-            coconut_keypair,
-            comm_channel,
-            storage.clone().unwrap(),
-        ))
+        rocket
+            .attach(InternalSignRequest::stage(
+                _nyxd_client.clone(),
+                mix_denom,
+                coconut_keypair,
+                comm_channel,
+                storage.clone().unwrap(),
+            ))
+            .attach(DkgStatus::stage(
+                _nyxd_client.clone(),
+                config
+                    .coconut_signer
+                    .storage_paths
+                    .dkg_persistent_state_path
+                    .clone(),
+            ))
     } else {
         rocket
     };
 
     // see if we should start up network monitor
     let rocket = if config.network_monitor.enabled {
-        rocket.attach(storage::NymApiStorage::stage(storage.unwrap()))
+        rocket
+            .manage(_nyxd_client.clone())
+            .attach(storage::NymApiStorage::stage(storage.unwrap()))
     } else {
         rocket
     };
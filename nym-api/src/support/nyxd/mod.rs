@@ -23,7 +23,8 @@ use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::reward_params::RewardingParams;
 use nym_mixnet_contract_common::{
     CurrentIntervalResponse, EpochStatus, ExecuteMsg, GatewayBond, IdentityKey, LayerAssignment,
-    MixId, RewardedSetNodeStatus,
+    MixId, RewardedSetNodeStatus, RewardedSetSelection, RewardedSetSelectionResponse,
+    RewardedSetSelectionSeed,
 };
 use nym_name_service_common::msg::QueryMsg as NameServiceQueryMsg;
 use nym_service_provider_directory_common::msg::QueryMsg as SpQueryMsg;
@@ -151,6 +152,29 @@ impl Client {
         Ok(hash)
     }
 
+    /// Obtains the chain id and the time of the block at the provided height,
+    /// i.e. the environment any contract executed in that block would have seen.
+    ///
+    /// # Arguments
+    ///
+    /// * `height`: height of the block for which we want to obtain the information.
+    pub(crate) async fn get_block_environment(
+        &self,
+        height: u32,
+    ) -> Result<(String, TendermintTime), ValidatorClientError> {
+        let header = self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_block(Some(height))
+            .await?
+            .block
+            .header;
+
+        Ok((header.chain_id.to_string(), header.time))
+    }
+
     pub(crate) async fn get_mixnodes(&self) -> Result<Vec<MixNodeDetails>, ValidatorClientError> {
         self.0.read().await.get_all_nyxd_mixnodes_detailed().await
     }
@@ -171,6 +195,31 @@ impl Client {
         Ok(self.0.read().await.nyxd.get_current_epoch_status().await?)
     }
 
+    pub(crate) async fn get_rewarded_set_selection(
+        &self,
+    ) -> Result<RewardedSetSelectionResponse, ValidatorClientError> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_rewarded_set_selection()
+            .await?)
+    }
+
+    pub(crate) async fn get_rewarded_set_selection_seed(
+        &self,
+    ) -> Result<Option<RewardedSetSelectionSeed>, ValidatorClientError> {
+        Ok(self
+            .0
+            .read()
+            .await
+            .nyxd
+            .get_rewarded_set_selection_seed()
+            .await?
+            .seed)
+    }
+
     pub(crate) async fn get_current_rewarding_parameters(
         &self,
     ) -> Result<RewardingParams, ValidatorClientError> {
@@ -229,7 +278,7 @@ impl Client {
 
     pub(crate) async fn get_pending_events_count(&self) -> Result<u32, ValidatorClientError> {
         let pending = self.0.read().await.get_number_of_pending_events().await?;
-        Ok(pending.epoch_events + pending.interval_events)
+        Ok(pending.epoch_events + pending.interval_events + pending.due_compoundings)
     }
 
     pub(crate) async fn begin_epoch_transition(&self) -> Result<(), ValidatorClientError> {
@@ -287,12 +336,18 @@ impl Client {
         &self,
         new_rewarded_set: Vec<LayerAssignment>,
         expected_active_set_size: u32,
+        selection: RewardedSetSelection,
     ) -> Result<(), ValidatorClientError> {
         self.0
             .write()
             .await
             .nyxd
-            .advance_current_epoch(new_rewarded_set, expected_active_set_size, None)
+            .advance_current_epoch(
+                new_rewarded_set,
+                expected_active_set_size,
+                Some(selection),
+                None,
+            )
             .await?;
         Ok(())
     }
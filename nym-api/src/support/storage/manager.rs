@@ -1,12 +1,16 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeResult};
+use crate::network_monitor::monitor::timing::TimingSummary;
 use crate::node_status_api::models::{HistoricalUptime, Uptime};
 use crate::node_status_api::utils::{ActiveGatewayStatuses, ActiveMixnodeStatuses};
 use crate::support::storage::models::{
-    ActiveGateway, ActiveMixnode, NodeStatus, RewardingReport, TestingRoute,
+    ActiveGateway, ActiveMixnode, NodeStatus, NodeTimingRecord,
+    RewardedSetSelectionCandidateRecord, RewardedSetSelectionRecord, RewardingReport,
+    RouteTimingRecord, TestingRoute,
 };
 use nym_mixnet_contract_common::{EpochId, IdentityKey, MixId};
+use nym_node_tester_utils::node::NodeType;
 use std::convert::TryFrom;
 
 #[derive(Clone)]
@@ -284,6 +288,89 @@ impl StorageManager {
         .await
     }
 
+    /// Gets all network monitor timing results of mixnode with particular id that were inserted
+    /// into the database after the specified unix timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `timestamp`: unix timestamp of the lower bound of the selection.
+    pub(crate) async fn get_mixnode_timings_since(
+        &self,
+        mix_id: MixId,
+        timestamp: i64,
+    ) -> Result<Vec<NodeTimingRecord>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeTimingRecord,
+            r#"
+                SELECT timestamp, median_delivery_ms, excess_delay_ms, estimated_loss
+                    FROM mixnode_timing
+                    JOIN mixnode_details
+                    ON mixnode_timing.mixnode_details_id = mixnode_details.id
+                    WHERE mixnode_details.mix_id=? AND mixnode_timing.timestamp > ?
+                    ORDER BY mixnode_timing.timestamp;
+            "#,
+            mix_id,
+            timestamp,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets all network monitor timing results of gateway with particular identity that were inserted
+    /// into the database after the specified unix timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity (base58-encoded public key) of the gateway.
+    /// * `timestamp`: unix timestamp of the lower bound of the selection.
+    pub(crate) async fn get_gateway_timings_since(
+        &self,
+        identity: &str,
+        timestamp: i64,
+    ) -> Result<Vec<NodeTimingRecord>, sqlx::Error> {
+        sqlx::query_as!(
+            NodeTimingRecord,
+            r#"
+                SELECT timestamp, median_delivery_ms, excess_delay_ms, estimated_loss
+                    FROM gateway_timing
+                    JOIN gateway_details
+                    ON gateway_timing.gateway_details_id = gateway_details.id
+                    WHERE gateway_details.identity=? AND gateway_timing.timestamp > ?
+                    ORDER BY gateway_timing.timestamp;
+            "#,
+            identity,
+            timestamp,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Gets timing results of all test routes used during the most recent network monitor run.
+    pub(crate) async fn get_latest_route_timings(
+        &self,
+    ) -> Result<Vec<RouteTimingRecord>, sqlx::Error> {
+        sqlx::query_as!(
+            RouteTimingRecord,
+            r#"
+                SELECT
+                    monitor_run_id,
+                    timestamp,
+                    gateway_identity,
+                    layer1_mix_id as "layer1_mix_id: MixId",
+                    layer2_mix_id as "layer2_mix_id: MixId",
+                    layer3_mix_id as "layer3_mix_id: MixId",
+                    measured_packets,
+                    median_delivery_ms,
+                    p90_delivery_ms
+                FROM testing_route_timing
+                WHERE monitor_run_id = (SELECT MAX(monitor_run_id) FROM testing_route_timing);
+            "#,
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
     /// Gets the historical daily uptime associated with the particular mixnode
     ///
     /// # Arguments
@@ -578,6 +665,108 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Saves the delivery times and per-node timing estimates obtained during this particular
+    /// network monitor test run.
+    ///
+    /// # Arguments
+    ///
+    /// * `monitor_run_id`: id (as saved in the database) of the network monitor run.
+    /// * `timestamp`: unix timestamp indicating when the measurements took place.
+    /// * `timing`: timing results of the test routes and all tested nodes.
+    pub(crate) async fn submit_monitor_run_timings(
+        &self,
+        monitor_run_id: i64,
+        timestamp: i64,
+        timing: TimingSummary,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+
+        for route_timing in timing.route_timings {
+            let gateway_identity = route_timing.route.gateway().identity_key.to_base58_string();
+            let measured_packets = route_timing.measured_packets as i64;
+            sqlx::query!(
+                r#"
+                    INSERT INTO testing_route_timing
+                    (monitor_run_id, timestamp, gateway_identity, layer1_mix_id, layer2_mix_id, layer3_mix_id, measured_packets, median_delivery_ms, p90_delivery_ms)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?);
+                "#,
+                monitor_run_id,
+                timestamp,
+                gateway_identity,
+                route_timing.route.layer_one_mix().mix_id,
+                route_timing.route.layer_two_mix().mix_id,
+                route_timing.route.layer_three_mix().mix_id,
+                measured_packets,
+                route_timing.median_delivery_ms,
+                route_timing.p90_delivery_ms,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        for mixnode_timing in timing.mixnode_timings {
+            let NodeType::Mixnode { mix_id } = mixnode_timing.node.typ else {
+                continue;
+            };
+
+            // the details must have been inserted alongside the node statuses
+            let Some(mixnode_id) =
+                sqlx::query!("SELECT id FROM mixnode_details WHERE mix_id = ?", mix_id)
+                    .fetch_optional(&mut tx)
+                    .await?
+                    .map(|row| row.id)
+            else {
+                continue;
+            };
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO mixnode_timing
+                    (mixnode_details_id, monitor_run_id, timestamp, median_delivery_ms, excess_delay_ms, estimated_loss)
+                    VALUES (?, ?, ?, ?, ?, ?);
+                "#,
+                mixnode_id,
+                monitor_run_id,
+                timestamp,
+                mixnode_timing.median_delivery_ms,
+                mixnode_timing.excess_delay_ms,
+                mixnode_timing.estimated_loss,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        for gateway_timing in timing.gateway_timings {
+            let Some(gateway_id) = sqlx::query!(
+                "SELECT id FROM gateway_details WHERE identity = ?",
+                gateway_timing.node.encoded_identity
+            )
+            .fetch_optional(&mut tx)
+            .await?
+            .map(|row| row.id) else {
+                continue;
+            };
+
+            sqlx::query!(
+                r#"
+                    INSERT INTO gateway_timing
+                    (gateway_details_id, monitor_run_id, timestamp, median_delivery_ms, excess_delay_ms, estimated_loss)
+                    VALUES (?, ?, ?, ?, ?, ?);
+                "#,
+                gateway_id,
+                monitor_run_id,
+                timestamp,
+                gateway_timing.median_delivery_ms,
+                gateway_timing.excess_delay_ms,
+                gateway_timing.estimated_loss,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
     /// Get the number of times mixnode with the particular id is present in any `testing_route`
     /// since the provided unix timestamp.
     ///
@@ -780,6 +969,28 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Removes all network monitor timing results that are older than the
+    /// provided timestamp. This method is indirectly called at every reward cycle.
+    ///
+    /// # Arguments
+    ///
+    /// * `until`: timestamp specifying the purge cutoff.
+    pub(crate) async fn purge_old_timings(&self, timestamp: i64) -> Result<(), sqlx::Error> {
+        sqlx::query!("DELETE FROM mixnode_timing WHERE timestamp < ?", timestamp)
+            .execute(&self.connection_pool)
+            .await?;
+        sqlx::query!("DELETE FROM gateway_timing WHERE timestamp < ?", timestamp)
+            .execute(&self.connection_pool)
+            .await?;
+        sqlx::query!(
+            "DELETE FROM testing_route_timing WHERE timestamp < ?",
+            timestamp
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
+
     /// Returns public key, owner and id of all mixnodes that have had any statuses submitted
     /// within the provided time interval.
     ///
@@ -906,6 +1117,108 @@ impl StorageManager {
         .await
     }
 
+    /// Inserts inputs used for selecting the rewarded set alongside all the candidates into the database.
+    ///
+    /// # Arguments
+    ///
+    /// * `selection`: information about the selection of the rewarded set.
+    /// * `candidates`: all nodes that were eligible for the selection.
+    pub(crate) async fn insert_rewarded_set_selection(
+        &self,
+        selection: &RewardedSetSelectionRecord,
+        candidates: &[RewardedSetSelectionCandidateRecord],
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        sqlx::query!(
+            r#"
+                INSERT OR REPLACE INTO rewarded_set_selection
+                (absolute_epoch_id, algorithm_version, seed_block_height, seed, candidates_commitment, rewarded_set_size, active_set_size)
+                VALUES (?, ?, ?, ?, ?, ?, ?);
+            "#,
+            selection.absolute_epoch_id,
+            selection.algorithm_version,
+            selection.seed_block_height,
+            selection.seed,
+            selection.candidates_commitment,
+            selection.rewarded_set_size,
+            selection.active_set_size,
+        )
+        .execute(&mut tx)
+        .await?;
+
+        sqlx::query!(
+            "DELETE FROM rewarded_set_selection_candidate WHERE absolute_epoch_id = ?",
+            selection.absolute_epoch_id
+        )
+        .execute(&mut tx)
+        .await?;
+
+        for candidate in candidates {
+            sqlx::query!(
+                "INSERT INTO rewarded_set_selection_candidate (absolute_epoch_id, mix_id, weight) VALUES (?, ?, ?)",
+                selection.absolute_epoch_id,
+                candidate.mix_id,
+                candidate.weight,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Tries to obtain inputs used for selecting the rewarded set of the specified epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `absolute_epoch_id`: id of the epoch for which the rewarded set got selected.
+    pub(crate) async fn get_rewarded_set_selection(
+        &self,
+        absolute_epoch_id: EpochId,
+    ) -> Result<Option<RewardedSetSelectionRecord>, sqlx::Error> {
+        sqlx::query_as!(
+            RewardedSetSelectionRecord,
+            r#"
+                SELECT
+                    absolute_epoch_id as "absolute_epoch_id: u32",
+                    algorithm_version as "algorithm_version: u32",
+                    seed_block_height,
+                    seed,
+                    candidates_commitment,
+                    rewarded_set_size as "rewarded_set_size: u32",
+                    active_set_size as "active_set_size: u32"
+                FROM rewarded_set_selection
+                WHERE absolute_epoch_id = ?
+            "#,
+            absolute_epoch_id
+        )
+        .fetch_optional(&self.connection_pool)
+        .await
+    }
+
+    /// Obtains all the candidates that were eligible for the rewarded set selection of the specified epoch.
+    ///
+    /// # Arguments
+    ///
+    /// * `absolute_epoch_id`: id of the epoch for which the rewarded set got selected.
+    pub(crate) async fn get_rewarded_set_selection_candidates(
+        &self,
+        absolute_epoch_id: EpochId,
+    ) -> Result<Vec<RewardedSetSelectionCandidateRecord>, sqlx::Error> {
+        sqlx::query_as!(
+            RewardedSetSelectionCandidateRecord,
+            r#"
+                SELECT mix_id as "mix_id: MixId", weight
+                FROM rewarded_set_selection_candidate
+                WHERE absolute_epoch_id = ?
+                ORDER BY mix_id
+            "#,
+            absolute_epoch_id
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
     /// Obtains all statuses of active mixnodes from the specified time interval.
     ///
     /// # Arguments
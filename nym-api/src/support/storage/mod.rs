@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeResult};
+use crate::network_monitor::monitor::timing::TimingSummary;
 use crate::network_monitor::test_route::TestRoute;
 use crate::node_status_api::models::{
     GatewayStatusReport, GatewayUptimeHistory, MixnodeStatusReport, MixnodeUptimeHistory,
@@ -9,8 +10,11 @@ use crate::node_status_api::models::{
 };
 use crate::node_status_api::{ONE_DAY, ONE_HOUR};
 use crate::storage::manager::StorageManager;
-use crate::storage::models::{NodeStatus, TestingRoute};
-use nym_mixnet_contract_common::MixId;
+use crate::storage::models::{
+    NodeStatus, NodeTimingRecord, RewardedSetSelectionCandidateRecord, RewardedSetSelectionRecord,
+    RouteTimingRecord, TestingRoute,
+};
+use nym_mixnet_contract_common::{EpochId, MixId};
 use rocket::fairing::AdHoc;
 use sqlx::ConnectOptions;
 use std::path::Path;
@@ -154,6 +158,53 @@ impl NymApiStorage {
         Ok(statuses)
     }
 
+    /// Gets all network monitor timing results of particular mixnode that were inserted
+    /// since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(crate) async fn get_mixnode_timings(
+        &self,
+        mix_id: MixId,
+        since: i64,
+    ) -> Result<Vec<NodeTimingRecord>, NymApiStorageError> {
+        let timings = self
+            .manager
+            .get_mixnode_timings_since(mix_id, since)
+            .await?;
+
+        Ok(timings)
+    }
+
+    /// Gets all network monitor timing results of particular gateway that were inserted
+    /// since the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the gateway to query.
+    /// * `since`: unix timestamp indicating the lower bound interval of the selection.
+    pub(crate) async fn get_gateway_timings(
+        &self,
+        identity: &str,
+        since: i64,
+    ) -> Result<Vec<NodeTimingRecord>, NymApiStorageError> {
+        let timings = self
+            .manager
+            .get_gateway_timings_since(identity, since)
+            .await?;
+
+        Ok(timings)
+    }
+
+    /// Gets the timing results of all routes used during the most recent network monitor run.
+    pub(crate) async fn get_latest_route_timings(
+        &self,
+    ) -> Result<Vec<RouteTimingRecord>, NymApiStorageError> {
+        Ok(self.manager.get_latest_route_timings().await?)
+    }
+
     /// Tries to construct a status report for mixnode with the specified mix_id.
     ///
     /// # Arguments
@@ -594,6 +645,7 @@ impl NymApiStorage {
         mixnode_results: Vec<MixnodeResult>,
         gateway_results: Vec<GatewayResult>,
         test_routes: Vec<TestRoute>,
+        timing: TimingSummary,
     ) -> Result<(), NymApiStorageError> {
         info!("Submitting new node results to the database. There are {} mixnode results and {} gateway results", mixnode_results.len(), gateway_results.len());
 
@@ -613,6 +665,10 @@ impl NymApiStorage {
             self.insert_test_route(monitor_run_id, test_route).await?;
         }
 
+        self.manager
+            .submit_monitor_run_timings(monitor_run_id, now, timing)
+            .await?;
+
         Ok(())
     }
 
@@ -704,15 +760,17 @@ impl NymApiStorage {
     }
 
     /// Removes all ipv4 and ipv6 statuses for all mixnodes and gateways that are older than the
-    /// provided timestamp. This method is called at every reward cycle.
+    /// provided timestamp, alongside the network monitor timing results.
+    /// This method is called at every reward cycle.
     ///
     /// # Arguments
     ///
     /// * `until`: timestamp specifying the purge cutoff.
     pub(crate) async fn purge_old_statuses(&self, until: i64) -> Result<(), NymApiStorageError> {
         self.manager.purge_old_mixnode_statuses(until).await?;
+        self.manager.purge_old_gateway_statuses(until).await?;
         self.manager
-            .purge_old_gateway_statuses(until)
+            .purge_old_timings(until)
             .await
             .map_err(|err| err.into())
     }
@@ -737,4 +795,43 @@ impl NymApiStorage {
             .await
             .map_err(|err| err.into())
     }
+
+    pub(crate) async fn insert_rewarded_set_selection(
+        &self,
+        selection: &RewardedSetSelectionRecord,
+        candidates: &[RewardedSetSelectionCandidateRecord],
+    ) -> Result<(), NymApiStorageError> {
+        self.manager
+            .insert_rewarded_set_selection(selection, candidates)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Obtains inputs used for selecting the rewarded set of the specified epoch,
+    /// alongside all the candidates that were eligible for the selection.
+    pub(crate) async fn get_rewarded_set_selection(
+        &self,
+        absolute_epoch_id: EpochId,
+    ) -> Result<
+        Option<(
+            RewardedSetSelectionRecord,
+            Vec<RewardedSetSelectionCandidateRecord>,
+        )>,
+        NymApiStorageError,
+    > {
+        let selection = match self
+            .manager
+            .get_rewarded_set_selection(absolute_epoch_id)
+            .await?
+        {
+            Some(selection) => selection,
+            None => return Ok(None),
+        };
+        let candidates = self
+            .manager
+            .get_rewarded_set_selection_candidates(absolute_epoch_id)
+            .await?;
+
+        Ok(Some((selection, candidates)))
+    }
 }
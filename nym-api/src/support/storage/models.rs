@@ -49,3 +49,38 @@ pub(crate) struct RewardingReport {
 
     pub(crate) eligible_mixnodes: u32,
 }
+
+pub(crate) struct RewardedSetSelectionRecord {
+    pub(crate) absolute_epoch_id: u32,
+    pub(crate) algorithm_version: u32,
+    pub(crate) seed_block_height: i64,
+    pub(crate) seed: String,
+    pub(crate) candidates_commitment: String,
+    pub(crate) rewarded_set_size: u32,
+    pub(crate) active_set_size: u32,
+}
+
+pub(crate) struct RewardedSetSelectionCandidateRecord {
+    pub(crate) mix_id: MixId,
+    pub(crate) weight: i64,
+}
+
+// Internally used struct to catch network monitor timing results of particular mixnode/gateway
+pub(crate) struct NodeTimingRecord {
+    pub(crate) timestamp: i64,
+    pub(crate) median_delivery_ms: Option<f64>,
+    pub(crate) excess_delay_ms: Option<f64>,
+    pub(crate) estimated_loss: f64,
+}
+
+pub(crate) struct RouteTimingRecord {
+    pub(crate) monitor_run_id: i64,
+    pub(crate) timestamp: i64,
+    pub(crate) gateway_identity: String,
+    pub(crate) layer1_mix_id: MixId,
+    pub(crate) layer2_mix_id: MixId,
+    pub(crate) layer3_mix_id: MixId,
+    pub(crate) measured_packets: i64,
+    pub(crate) median_delivery_ms: Option<f64>,
+    pub(crate) p90_delivery_ms: Option<f64>,
+}
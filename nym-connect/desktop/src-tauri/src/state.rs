@@ -40,6 +40,9 @@ impl TryFrom<&ClientCoreStatusMessage> for GatewayConnectivity {
             ClientCoreStatusMessage::GatewayIsVerySlow => GatewayConnectivity::VeryBad {
                 when: Instant::now(),
             },
+            ClientCoreStatusMessage::OutOfBandwidthCredentials => {
+                return Err(BackendError::NotGatewayConnectivityStatus)
+            }
         };
         Ok(conn)
     }
@@ -134,6 +134,8 @@ pub enum BackendError {
     WalletValidatorConnectionFailed,
     #[error("No defined default validator URL")]
     WalletNoDefaultValidator,
+    #[error("There is no pending transfer of the specified node")]
+    NoPendingBondTransfer,
 
     #[error(transparent)]
     WalletError {
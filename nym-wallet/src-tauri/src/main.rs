@@ -69,6 +69,14 @@ fn main() {
             mixnet::bond::update_mixnode_cost_params,
             mixnet::bond::update_mixnode_config,
             mixnet::bond::update_gateway_config,
+            mixnet::bond::propose_mixnode_transfer,
+            mixnet::bond::accept_mixnode_transfer,
+            mixnet::bond::cancel_mixnode_transfer,
+            mixnet::bond::get_pending_mixnode_transfer,
+            mixnet::bond::propose_gateway_transfer,
+            mixnet::bond::accept_gateway_transfer,
+            mixnet::bond::cancel_gateway_transfer,
+            mixnet::bond::get_pending_gateway_transfer,
             mixnet::bond::get_number_of_mixnode_delegators,
             mixnet::bond::get_mix_node_description,
             mixnet::bond::get_mixnode_avg_uptime,
@@ -187,6 +195,8 @@ fn main() {
             signatures::ed25519_signing_payload::vesting_generate_mixnode_bonding_msg_payload,
             signatures::ed25519_signing_payload::generate_gateway_bonding_msg_payload,
             signatures::ed25519_signing_payload::vesting_generate_gateway_bonding_msg_payload,
+            signatures::ed25519_signing_payload::generate_mixnode_transfer_msg_payload,
+            signatures::ed25519_signing_payload::generate_gateway_transfer_msg_payload,
             help::log::help_log_toggle_window,
             app::window::create_main_window,
             app::window::create_auth_window,
@@ -8,6 +8,9 @@ use crate::operations::helpers::{
 use crate::state::WalletState;
 use crate::{nyxd_client, Gateway, MixNode};
 use nym_contracts_common::signing::MessageSignature;
+use nym_mixnet_contract_common::bond_transfer::{
+    PendingGatewayTransferResponse, PendingMixnodeTransferResponse,
+};
 use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
 use nym_mixnet_contract_common::{MixId, MixNodeConfigUpdate};
 use nym_types::currency::DecCoin;
@@ -330,6 +333,182 @@ pub async fn update_gateway_config(
     )?)
 }
 
+#[tauri::command]
+pub async fn propose_mixnode_transfer(
+    new_owner: String,
+    new_identity_key: Option<String>,
+    new_sphinx_key: Option<String>,
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(
+        ">>> Propose mixnode transfer: new_owner = {}, new_identity_key = {:?}, new_sphinx_key = {:?}, fee = {:?}",
+        new_owner,
+        new_identity_key,
+        new_sphinx_key,
+        fee,
+    );
+    let res = guard
+        .current_client()?
+        .nyxd
+        .propose_mixnode_transfer(new_owner.parse()?, new_identity_key, new_sphinx_key, fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn accept_mixnode_transfer(
+    mix_id: MixId,
+    msg_signature: MessageSignature,
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(
+        ">>> Accept mixnode transfer: mix_id = {}, fee = {:?}",
+        mix_id,
+        fee
+    );
+    let res = guard
+        .current_client()?
+        .nyxd
+        .accept_mixnode_transfer(mix_id, msg_signature, fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn cancel_mixnode_transfer(
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(">>> Cancel mixnode transfer, fee = {:?}", fee);
+    let res = guard
+        .current_client()?
+        .nyxd
+        .cancel_mixnode_transfer(fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn get_pending_mixnode_transfer(
+    mix_id: MixId,
+    state: tauri::State<'_, WalletState>,
+) -> Result<PendingMixnodeTransferResponse, BackendError> {
+    log::info!(">>> Get pending mixnode transfer: mix_id = {}", mix_id);
+    let res = nyxd_client!(state)
+        .get_pending_mixnode_transfer(mix_id)
+        .await?;
+    log::trace!("<<< {:?}", res);
+    Ok(res)
+}
+
+#[tauri::command]
+pub async fn propose_gateway_transfer(
+    new_owner: String,
+    new_identity_key: Option<String>,
+    new_sphinx_key: Option<String>,
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(
+        ">>> Propose gateway transfer: new_owner = {}, new_identity_key = {:?}, new_sphinx_key = {:?}, fee = {:?}",
+        new_owner,
+        new_identity_key,
+        new_sphinx_key,
+        fee,
+    );
+    let res = guard
+        .current_client()?
+        .nyxd
+        .propose_gateway_transfer(new_owner.parse()?, new_identity_key, new_sphinx_key, fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn accept_gateway_transfer(
+    identity: String,
+    msg_signature: MessageSignature,
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(
+        ">>> Accept gateway transfer: identity = {}, fee = {:?}",
+        identity,
+        fee
+    );
+    let res = guard
+        .current_client()?
+        .nyxd
+        .accept_gateway_transfer(identity, msg_signature, fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn cancel_gateway_transfer(
+    fee: Option<Fee>,
+    state: tauri::State<'_, WalletState>,
+) -> Result<TransactionExecuteResult, BackendError> {
+    let guard = state.read().await;
+    let fee_amount = guard.convert_tx_fee(fee.as_ref());
+    log::info!(">>> Cancel gateway transfer, fee = {:?}", fee);
+    let res = guard
+        .current_client()?
+        .nyxd
+        .cancel_gateway_transfer(fee)
+        .await?;
+    log::info!("<<< tx hash = {}", res.transaction_hash);
+    log::trace!("<<< {:?}", res);
+    Ok(TransactionExecuteResult::from_execute_result(
+        res, fee_amount,
+    )?)
+}
+
+#[tauri::command]
+pub async fn get_pending_gateway_transfer(
+    identity: String,
+    state: tauri::State<'_, WalletState>,
+) -> Result<PendingGatewayTransferResponse, BackendError> {
+    log::info!(">>> Get pending gateway transfer: identity = {}", identity);
+    let res = nyxd_client!(state)
+        .get_pending_gateway_transfer(identity)
+        .await?;
+    log::trace!("<<< {:?}", res);
+    Ok(res)
+}
+
 #[tauri::command]
 pub async fn get_mixnode_avg_uptime(
     state: tauri::State<'_, WalletState>,
@@ -6,9 +6,13 @@ use crate::operations::helpers::{
     create_gateway_bonding_sign_payload, create_mixnode_bonding_sign_payload,
 };
 use crate::state::WalletState;
-use nym_mixnet_contract_common::{Gateway, MixNode};
+use nym_mixnet_contract_common::{
+    construct_gateway_transfer_sign_payload, construct_mixnode_transfer_sign_payload, Gateway,
+    MixId, MixNode,
+};
 use nym_types::currency::DecCoin;
 use nym_types::mixnode::MixNodeCostParams;
+use nym_validator_client::nyxd::traits::MixnetQueryClient;
 
 async fn mixnode_bonding_msg_payload(
     mixnode: MixNode,
@@ -98,3 +102,52 @@ pub async fn vesting_generate_gateway_bonding_msg_payload(
 ) -> Result<String, BackendError> {
     gateway_bonding_msg_payload(gateway, pledge, true, state).await
 }
+
+// note: transfer payloads have to be generated (and submitted) by the NEW owner of the node
+#[tauri::command]
+pub async fn generate_mixnode_transfer_msg_payload(
+    mix_id: MixId,
+    state: tauri::State<'_, WalletState>,
+) -> Result<String, BackendError> {
+    log::info!(">>> Mixnode transfer signature: mix_id = {mix_id}");
+    let guard = state.read().await;
+    let client = guard.current_client()?;
+
+    let proposal = client
+        .nyxd
+        .get_pending_mixnode_transfer(mix_id)
+        .await?
+        .proposal
+        .ok_or(BackendError::NoPendingBondTransfer)?;
+    let nonce = client.nyxd.get_signing_nonce(client.nyxd.address()).await?;
+
+    let msg =
+        construct_mixnode_transfer_sign_payload(nonce, client.nyxd.cw_address(), mix_id, proposal);
+    Ok(msg.to_base58_string()?)
+}
+
+#[tauri::command]
+pub async fn generate_gateway_transfer_msg_payload(
+    identity: String,
+    state: tauri::State<'_, WalletState>,
+) -> Result<String, BackendError> {
+    log::info!(">>> Gateway transfer signature: identity = {identity}");
+    let guard = state.read().await;
+    let client = guard.current_client()?;
+
+    let proposal = client
+        .nyxd
+        .get_pending_gateway_transfer(identity.clone())
+        .await?
+        .proposal
+        .ok_or(BackendError::NoPendingBondTransfer)?;
+    let nonce = client.nyxd.get_signing_nonce(client.nyxd.address()).await?;
+
+    let msg = construct_gateway_transfer_sign_payload(
+        nonce,
+        client.nyxd.cw_address(),
+        identity,
+        proposal,
+    );
+    Ok(msg.to_base58_string()?)
+}
@@ -13,6 +13,9 @@ import {
   TBondGatewaySignatureArgs,
   TBondMixNodeArgs,
   TBondMixnodeSignatureArgs,
+  TPendingGatewayTransfer,
+  TPendingMixnodeTransfer,
+  TProposeBondTransferArgs,
   TUpdateBondArgs,
 } from '../types';
 import { invokeWrapper } from './wrapper';
@@ -42,6 +45,36 @@ export const updateMixnodeConfig = async (update: MixNodeConfigUpdate, fee?: Fee
 export const updateGatewayConfig = async (update: GatewayConfigUpdate, fee?: Fee) =>
   invokeWrapper<TransactionExecuteResult>('update_gateway_config', { update, fee });
 
+export const proposeMixnodeTransfer = async (args: TProposeBondTransferArgs) =>
+  invokeWrapper<TransactionExecuteResult>('propose_mixnode_transfer', args);
+
+export const generateMixnodeTransferMsgPayload = async (mixId: number) =>
+  invokeWrapper<string>('generate_mixnode_transfer_msg_payload', { mixId });
+
+export const acceptMixnodeTransfer = async (mixId: number, msgSignature: string, fee?: Fee) =>
+  invokeWrapper<TransactionExecuteResult>('accept_mixnode_transfer', { mixId, msgSignature, fee });
+
+export const cancelMixnodeTransfer = async (fee?: Fee) =>
+  invokeWrapper<TransactionExecuteResult>('cancel_mixnode_transfer', { fee });
+
+export const getPendingMixnodeTransfer = async (mixId: number) =>
+  invokeWrapper<TPendingMixnodeTransfer>('get_pending_mixnode_transfer', { mixId });
+
+export const proposeGatewayTransfer = async (args: TProposeBondTransferArgs) =>
+  invokeWrapper<TransactionExecuteResult>('propose_gateway_transfer', args);
+
+export const generateGatewayTransferMsgPayload = async (identity: string) =>
+  invokeWrapper<string>('generate_gateway_transfer_msg_payload', { identity });
+
+export const acceptGatewayTransfer = async (identity: string, msgSignature: string, fee?: Fee) =>
+  invokeWrapper<TransactionExecuteResult>('accept_gateway_transfer', { identity, msgSignature, fee });
+
+export const cancelGatewayTransfer = async (fee?: Fee) =>
+  invokeWrapper<TransactionExecuteResult>('cancel_gateway_transfer', { fee });
+
+export const getPendingGatewayTransfer = async (identity: string) =>
+  invokeWrapper<TPendingGatewayTransfer>('get_pending_gateway_transfer', { identity });
+
 export const send = async (args: { amount: DecCoin; address: string; memo: string; fee?: Fee }) =>
   invokeWrapper<SendTxResult>('send', args);
 
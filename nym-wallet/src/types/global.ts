@@ -54,6 +54,31 @@ export type TBondGatewaySignatureArgs = {
   tokenPool: 'balance' | 'locked';
 };
 
+export type TProposeBondTransferArgs = {
+  newOwner: string;
+  newIdentityKey?: string;
+  newSphinxKey?: string;
+  fee?: Fee;
+};
+
+export type TBondTransferProposal = {
+  current_owner: string;
+  new_owner: string;
+  new_identity_key: string;
+  new_sphinx_key: string;
+  proposal_height: number;
+};
+
+export type TPendingMixnodeTransfer = {
+  mix_id: number;
+  proposal?: TBondTransferProposal;
+};
+
+export type TPendingGatewayTransfer = {
+  identity: string;
+  proposal?: TBondTransferProposal;
+};
+
 export type TUpdateBondArgs = {
   currentPledge: DecCoin;
   newPledge: DecCoin;
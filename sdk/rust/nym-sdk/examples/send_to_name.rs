@@ -0,0 +1,29 @@
+use nym_sdk::mixnet;
+
+#[tokio::main]
+async fn main() {
+    nym_bin_common::logging::setup_logging();
+
+    let Some(name) = std::env::args().nth(1) else {
+        eprintln!("usage: send_to_name <registered-name>");
+        return;
+    };
+
+    let mut client = mixnet::MixnetClient::connect_new().await.unwrap();
+
+    // Names registered in the name service are resolved using the nym API
+    let destination: mixnet::Destination = name.parse().unwrap();
+    match client.resolve(destination.clone()).await {
+        Ok(address) => println!("{name} is registered for {address}"),
+        Err(err) => {
+            eprintln!("failed to resolve {name}: {err}");
+            client.disconnect().await;
+            return;
+        }
+    }
+
+    // The name can also be passed directly when sending
+    client.send_str(destination, "hello there").await;
+
+    client.disconnect().await;
+}
@@ -0,0 +1,190 @@
+use kv_key::Key;
+use nym_bin_common::build_information::BinaryBuildInformation;
+use nym_sdk::mixnet;
+use nym_sdk::service_provider::interface::{
+    BinaryInformation, Empty, ProviderInterfaceVersion, Serializable,
+    ServiceProviderMessagingError, ServiceProviderRequest, ServiceProviderResponse,
+};
+use nym_sdk::service_provider::{
+    async_trait, AnonymousSenderTag, ServiceProvider, ServiceProviderRuntime,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, thiserror::Error)]
+enum KvError {
+    #[error(transparent)]
+    Messaging(#[from] ServiceProviderMessagingError),
+
+    #[error("the received key-value message was malformed")]
+    Malformed,
+
+    #[error(
+        "the key is {len} bytes long while at most {} are allowed",
+        Key::MAX_LEN
+    )]
+    KeyTooLong { len: usize },
+}
+
+mod kv_key {
+    use super::KvError;
+
+    /// Key of a stored value. Its length always fits in the single byte prefix of the `Set` request.
+    #[derive(Debug)]
+    pub struct Key(String);
+
+    impl Key {
+        pub const MAX_LEN: usize = u8::MAX as usize;
+
+        pub fn len_prefix(&self) -> u8 {
+            // the length is checked upon construction so this can't truncate
+            self.0.len() as u8
+        }
+
+        pub fn into_string(self) -> String {
+            self.0
+        }
+    }
+
+    impl TryFrom<String> for Key {
+        type Error = KvError;
+
+        fn try_from(key: String) -> Result<Self, Self::Error> {
+            if key.len() > Self::MAX_LEN {
+                return Err(KvError::KeyTooLong { len: key.len() });
+            }
+            Ok(Key(key))
+        }
+    }
+}
+
+/// Requests understood by our simple key-value store:
+/// - `[0x00, key...]` - get the value of the key,
+/// - `[0x01, key_len, key..., value...]` - set the value of the key.
+#[derive(Debug)]
+enum KvRequest {
+    Get { key: String },
+    Set { key: Key, value: String },
+}
+
+#[derive(Debug)]
+struct KvResponse {
+    value: Option<String>,
+}
+
+impl Serializable for KvRequest {
+    type Error = KvError;
+
+    fn into_bytes(self) -> Vec<u8> {
+        match self {
+            KvRequest::Get { key } => std::iter::once(0x00).chain(key.into_bytes()).collect(),
+            KvRequest::Set { key, value } => [0x01, key.len_prefix()]
+                .into_iter()
+                .chain(key.into_string().into_bytes())
+                .chain(value.into_bytes())
+                .collect(),
+        }
+    }
+
+    fn try_from_bytes(b: &[u8]) -> Result<Self, Self::Error> {
+        let to_string = |b: &[u8]| String::from_utf8(b.to_vec()).map_err(|_| KvError::Malformed);
+
+        match b.first() {
+            Some(0x00) => Ok(KvRequest::Get {
+                key: to_string(&b[1..])?,
+            }),
+            Some(0x01) => {
+                let key_len = *b.get(1).ok_or(KvError::Malformed)? as usize;
+                if b.len() < 2 + key_len {
+                    return Err(KvError::Malformed);
+                }
+                Ok(KvRequest::Set {
+                    key: to_string(&b[2..2 + key_len])?.try_into()?,
+                    value: to_string(&b[2 + key_len..])?,
+                })
+            }
+            _ => Err(KvError::Malformed),
+        }
+    }
+}
+
+impl Serializable for KvResponse {
+    type Error = KvError;
+
+    fn into_bytes(self) -> Vec<u8> {
+        self.value.map(String::into_bytes).unwrap_or_default()
+    }
+
+    fn try_from_bytes(b: &[u8]) -> Result<Self, Self::Error> {
+        if b.is_empty() {
+            return Ok(KvResponse { value: None });
+        }
+        let value = String::from_utf8(b.to_vec()).map_err(|_| KvError::Malformed)?;
+        Ok(KvResponse { value: Some(value) })
+    }
+}
+
+impl ServiceProviderResponse for KvResponse {}
+
+impl ServiceProviderRequest for KvRequest {
+    type ProtocolVersion = Empty;
+    type Response = KvResponse;
+    type Error = KvError;
+
+    fn provider_specific_version(&self) -> Self::ProtocolVersion {
+        Empty
+    }
+
+    fn max_supported_version() -> Self::ProtocolVersion {
+        Empty
+    }
+}
+
+#[derive(Default)]
+struct KvStore {
+    values: HashMap<String, String>,
+}
+
+#[async_trait]
+impl ServiceProvider<KvRequest> for KvStore {
+    type ServiceProviderError = KvError;
+
+    async fn handle_binary_info_control_request(
+        &self,
+    ) -> Result<BinaryInformation, Self::ServiceProviderError> {
+        Ok(BinaryInformation {
+            binary_name: "kv-store-example".to_string(),
+            build_information: BinaryBuildInformation::new(env!("CARGO_PKG_VERSION")).to_owned(),
+        })
+    }
+
+    async fn handle_provider_data_request(
+        &mut self,
+        _sender: Option<AnonymousSenderTag>,
+        request: KvRequest,
+        _interface_version: ProviderInterfaceVersion,
+    ) -> Result<Option<KvResponse>, Self::ServiceProviderError> {
+        let value = match request {
+            KvRequest::Get { key } => self.values.get(&key).cloned(),
+            KvRequest::Set { key, value } => self.values.insert(key.into_string(), value),
+        };
+        Ok(Some(KvResponse { value }))
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    nym_bin_common::logging::setup_logging();
+
+    let client = mixnet::MixnetClient::connect_new().await.unwrap();
+    println!(
+        "Our key-value store is available at: {}",
+        client.nym_address()
+    );
+
+    println!("Waiting for requests (ctrl-c to exit)");
+    let store = ServiceProviderRuntime::new(KvStore::default(), client)
+        .run()
+        .await;
+
+    println!("Stored {} values before shutting down", store.values.len());
+}
@@ -42,4 +42,4 @@
 
 mod client;
 
-pub use client::{BandwidthAcquireClient, VoucherBlob};
+pub use client::{BandwidthAcquireClient, TicketBookBlob, VoucherBlob};
@@ -2,9 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::{Error, Result};
-use nym_bandwidth_controller::acquire::state::State;
+use nym_bandwidth_controller::acquire::state::{State, TicketBookState};
 use nym_credential_storage::storage::Storage;
 use nym_credentials::coconut::bandwidth::BandwidthVoucher;
+use nym_credentials::coconut::ticketbook::TicketBook;
 use nym_network_defaults::NymNetworkDetails;
 use nym_validator_client::nyxd::{Coin, SigningNyxdClient};
 use nym_validator_client::signing::direct_wallet::DirectSecp256k1HdWallet;
@@ -15,6 +16,10 @@ use nym_validator_client::{Client, Config};
 /// Its serialized nature makes it easy to store and load it to e.g. disk.
 pub type VoucherBlob = Vec<u8>;
 
+/// The serialized version of the ticket book whose tickets haven't yet been all issued. It can be used
+/// to complete the acquirement process of the bandwidth tickets.
+pub type TicketBookBlob = Vec<u8>;
+
 /// Represents a client that can be used to acquire bandwidth. You typically create one when you
 /// want to connect to the mixnet using paid coconut bandwidth credentials.
 /// The way to create this client is by calling
@@ -71,4 +76,45 @@ where
 
         Ok(())
     }
+
+    /// Buy bandwidth worth amount utokens, split into the specified number of equally valued tickets.
+    /// Unlike a single credential, the tickets can be spent independently at different gateways, so
+    /// no paid bandwidth is lost when switching gateways. If [`Error::UnconvertedTicketBookDeposit`]
+    /// is returned, the tokens have been deposited, but the tickets haven't yet been issued and the
+    /// returned [`TicketBookBlob`] can be used for a later recovery using [`Self::recover_tickets`].
+    pub async fn acquire_tickets(&self, amount: u128, tickets: u32) -> Result<()> {
+        let amount = Coin::new(amount, &self.network_details.chain_details.mix_denom.base);
+        let state = nym_bandwidth_controller::acquire::deposit_ticketbook(
+            &self.client.nyxd,
+            amount,
+            tickets,
+        )
+        .await?;
+        nym_bandwidth_controller::acquire::get_ticketbook_credentials(
+            &state,
+            &self.client,
+            self.storage,
+        )
+        .await
+        .map_err(|reason| Error::UnconvertedTicketBookDeposit {
+            reason,
+            ticketbook_blob: state.ticketbook.to_bytes(),
+        })
+    }
+
+    /// In case of an error in the mid of the ticket acquire process, this function should be used
+    /// for later retries to recover the bandwidth tickets.
+    pub async fn recover_tickets(&self, ticketbook_blob: &TicketBookBlob) -> Result<()> {
+        let ticketbook = TicketBook::try_from_bytes(ticketbook_blob)
+            .map_err(|_| Error::InvalidTicketBookBlob)?;
+        let state = TicketBookState::new(ticketbook);
+        nym_bandwidth_controller::acquire::get_ticketbook_credentials(
+            &state,
+            &self.client,
+            self.storage,
+        )
+        .await?;
+
+        Ok(())
+    }
 }
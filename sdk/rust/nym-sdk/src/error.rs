@@ -48,6 +48,9 @@ pub enum Error {
     #[error("bad validator details: {0}")]
     BadValidatorDetails(#[from] nym_validator_client::ValidatorClientError),
 
+    #[error("failed to resolve the destination: {0}")]
+    NameResolutionError(#[from] nym_client_core::client::name_resolver::NameResolutionError),
+
     #[error("socks5 configuration set: {}, but expected to be {}", set, !set)]
     Socks5Config { set: bool },
 
@@ -63,12 +66,24 @@ pub enum Error {
         voucher_blob: crate::bandwidth::VoucherBlob,
     },
 
+    #[error(
+        "deposited funds were not converted to bandwidth tickets - {reason}; the ticket book blob can be used for \
+    later retry"
+    )]
+    UnconvertedTicketBookDeposit {
+        reason: nym_bandwidth_controller::error::BandwidthControllerError,
+        ticketbook_blob: crate::bandwidth::TicketBookBlob,
+    },
+
     #[error("bandwidth controller error: {0}")]
     BandwidthControllerError(#[from] nym_bandwidth_controller::error::BandwidthControllerError),
 
     #[error("invalid voucher blob")]
     InvalidVoucherBlob,
 
+    #[error("invalid ticket book blob")]
+    InvalidTicketBookBlob,
+
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(#[from] bip39::Error),
 
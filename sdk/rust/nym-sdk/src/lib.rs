@@ -1,10 +1,12 @@
 //! Rust SDK for the Nym platform
 //!
-//! The main component currently is [`mixnet`].
+//! The main component currently is [`mixnet`]. Custom services can be hosted on top of it
+//! using the [`service_provider`] runtime.
 
 mod error;
 
 pub mod bandwidth;
 pub mod mixnet;
+pub mod service_provider;
 
 pub use error::{Error, Result};
@@ -49,21 +49,25 @@ pub use nym_client_core::{
             persistence::{InMemEphemeralKeys, KeyStore, OnDiskKeys},
             KeyManager,
         },
+        name_resolver::{Destination, NameResolutionError},
         replies::reply_storage::{
             fs_backend::Backend as ReplyStorage, CombinedReplyStorage, Empty as EmptyReplyStorage,
             ReplyStorageBackend,
         },
     },
     config::GatewayEndpointConfig,
+    error::ClientCoreStatusMessage,
 };
 pub use nym_credential_storage::{
     ephemeral_storage::EphemeralStorage as EphemeralCredentialStorage, models::CoconutCredential,
     storage::Storage as CredentialStorage,
 };
 pub use nym_network_defaults::NymNetworkDetails;
-pub use nym_socks5_client_core::config::Socks5;
+pub use nym_socks5_client_core::config::{ProviderSelectionStrategy, Socks5};
+pub use nym_socks5_client_core::socks::provider_pool::ProviderStatus;
 pub use nym_sphinx::{
     addressing::clients::{ClientIdentity, Recipient},
+    anonymous_replies::requests::AnonymousSenderTag,
     receiver::ReconstructedMessage,
 };
 pub use nym_topology::{provider_trait::TopologyProvider, NymTopology};
@@ -23,6 +23,7 @@ use nym_client_core::{
 };
 use nym_network_defaults::NymNetworkDetails;
 use nym_socks5_client_core::config::Socks5;
+use nym_socks5_client_core::socks::provider_directory::query_network_requesters;
 use nym_task::manager::TaskStatus;
 use nym_topology::provider_trait::TopologyProvider;
 use nym_validator_client::nyxd::QueryNyxdClient;
@@ -511,6 +512,7 @@ where
             .ok_or(Error::Socks5Config { set: false })?;
         let debug_config = self.config.debug_config;
         let packet_type = self.config.packet_type();
+        let network_details = self.config.network_details.clone();
         let (mut started_client, nym_address) = self.connect_to_mixnet_common().await?;
         let (socks5_status_tx, mut socks5_status_rx) = mpsc::channel(128);
 
@@ -518,16 +520,24 @@ where
         let client_output = started_client.client_output.register_consumer();
         let client_state = started_client.client_state;
 
-        nym_socks5_client_core::NymClient::<S>::start_socks5_listener(
+        let providers = nym_socks5_client_core::NymClient::<S>::resolve_providers(
             &socks5_config,
-            debug_config,
-            client_input,
-            client_output,
-            client_state.clone(),
-            nym_address,
-            started_client.task_manager.subscribe(),
-            packet_type,
-        );
+            &client_state.name_resolver,
+        )
+        .await?;
+
+        let (provider_control, provider_pool) =
+            nym_socks5_client_core::NymClient::<S>::start_socks5_listener(
+                &socks5_config,
+                providers,
+                debug_config,
+                client_input,
+                client_output,
+                client_state.clone(),
+                nym_address,
+                started_client.task_manager.subscribe(),
+                packet_type,
+            );
         started_client
             .task_manager
             .start_status_listener(socks5_status_tx)
@@ -544,11 +554,22 @@ where
             }
         }
 
+        if socks5_config.use_provider_directory {
+            let client_config =
+                nym_validator_client::Config::try_from_nym_network_details(&network_details)?;
+            match query_network_requesters(client_config).await {
+                Ok(providers) => provider_pool.extend(providers),
+                Err(err) => log::warn!("failed to query the service provider directory: {err}"),
+            }
+        }
+
         Ok(Socks5MixnetClient {
             nym_address,
             client_state,
             task_manager: started_client.task_manager,
             socks5_config,
+            provider_control,
+            provider_pool,
         })
     }
 
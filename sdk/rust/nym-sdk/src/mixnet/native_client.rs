@@ -1,13 +1,15 @@
 use nym_client_core::client::{
     base_client::{ClientInput, ClientOutput, ClientState},
     inbound_messages::InputMessage,
+    name_resolver::Destination,
     received_buffer::ReconstructedMessagesReceiver,
 };
 use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
 use nym_sphinx::{params::PacketType, receiver::ReconstructedMessage};
 use nym_task::{
     connections::{ConnectionCommandSender, LaneQueueLengths, TransmissionLane},
-    TaskManager,
+    StatusSender, TaskManager,
 };
 
 use futures::StreamExt;
@@ -111,7 +113,32 @@ impl MixnetClient {
         self.client_state.topology_accessor.release_manual_control()
     }
 
-    /// Sends stringy data to the supplied Nym address
+    /// Resolves the provided destination into a Nym address. If it's a name registered in the
+    /// name service, it's looked up using the nym API.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use nym_sdk::mixnet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = mixnet::MixnetClient::connect_new().await.unwrap();
+    ///     let destination: mixnet::Destination = "my-service".parse().unwrap();
+    ///     let recipient = client.resolve(destination).await.unwrap();
+    /// }
+    /// ```
+    pub async fn resolve(&self, destination: impl Into<Destination>) -> Result<Recipient> {
+        Ok(self
+            .client_state
+            .name_resolver
+            .resolve(&destination.into())
+            .await?)
+    }
+
+    /// Sends stringy data to the supplied Nym address or registered name. If the name can't be
+    /// resolved, the message is dropped. Use [`MixnetClient::resolve`] beforehand in order to
+    /// handle such failures.
     ///
     /// # Example
     ///
@@ -124,16 +151,19 @@ impl MixnetClient {
     ///     let recipient = mixnet::Recipient::try_from_base58_string(address).unwrap();
     ///     let mut client = mixnet::MixnetClient::connect_new().await.unwrap();
     ///     client.send_str(recipient, "hi").await;
+    ///
+    ///     let name: mixnet::Destination = "my-service".parse().unwrap();
+    ///     client.send_str(name, "hi").await;
     /// }
     /// ```
-    pub async fn send_str(&self, address: Recipient, message: &str) {
+    pub async fn send_str(&self, address: impl Into<Destination>, message: &str) {
         let message_bytes = message.to_string().into_bytes();
         self.send_bytes(address, message_bytes, IncludedSurbs::default())
             .await;
     }
 
-    /// Sends bytes to the supplied Nym address. There is the option to specify the number of
-    /// reply-SURBs to include.
+    /// Sends bytes to the supplied Nym address or registered name. There is the option to specify
+    /// the number of reply-SURBs to include. If the name can't be resolved, the message is dropped.
     ///
     /// # Example
     ///
@@ -149,7 +179,20 @@ impl MixnetClient {
     ///     client.send_bytes(recipient, "hi".to_owned().into_bytes(), surbs).await;
     /// }
     /// ```
-    pub async fn send_bytes(&self, address: Recipient, message: Vec<u8>, surbs: IncludedSurbs) {
+    pub async fn send_bytes(
+        &self,
+        address: impl Into<Destination>,
+        message: Vec<u8>,
+        surbs: IncludedSurbs,
+    ) {
+        let address = match self.resolve(address).await {
+            Ok(address) => address,
+            Err(err) => {
+                log::error!("Failed to send message: {err}");
+                return;
+            }
+        };
+
         let lane = TransmissionLane::General;
         let input_msg = match surbs {
             IncludedSurbs::Amount(surbs) => {
@@ -162,6 +205,32 @@ impl MixnetClient {
         self.send(input_msg).await
     }
 
+    /// Sends bytes back to the party that has sent us a message alongside reply SURBs,
+    /// without ever learning its actual Nym address.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use nym_sdk::mixnet;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = mixnet::MixnetClient::connect_new().await.unwrap();
+    ///     if let Some(received) = client.wait_for_messages().await {
+    ///         for msg in received {
+    ///             if let Some(sender_tag) = msg.sender_tag {
+    ///                 client.send_reply(sender_tag, msg.message).await;
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_reply(&self, recipient_tag: AnonymousSenderTag, message: Vec<u8>) {
+        let lane = TransmissionLane::General;
+        let input_msg = InputMessage::new_reply(recipient_tag, message, lane, self.packet_type);
+        self.send(input_msg).await
+    }
+
     /// Sends a [`InputMessage`] to the mixnet. This is the most low-level sending function, for
     /// full customization.
     async fn send(&self, message: InputMessage) {
@@ -198,6 +267,17 @@ impl MixnetClient {
         }
     }
 
+    /// Forward the status messages emitted by the client to the provided channel, such as
+    /// [`OutOfBandwidthCredentials`](crate::mixnet::ClientCoreStatusMessage::OutOfBandwidthCredentials)
+    /// that indicates more credentials should be acquired with the
+    /// [`BandwidthAcquireClient`](crate::bandwidth::BandwidthAcquireClient).
+    ///
+    /// Note that the first message sent is going to be [`TaskStatus::Ready`](nym_task::manager::TaskStatus::Ready).
+    /// This can only be called once.
+    pub async fn start_status_listener(&mut self, sender: StatusSender) {
+        self.task_manager.start_status_listener(sender).await
+    }
+
     /// Disconnect from the mixnet. Currently it is not supported to reconnect a disconnected
     /// client.
     pub async fn disconnect(&mut self) {
@@ -1,5 +1,7 @@
 use nym_client_core::client::base_client::ClientState;
-use nym_socks5_client_core::config::Socks5;
+use nym_socks5_client_core::config::{ProviderSelectionStrategy, Socks5};
+use nym_socks5_client_core::socks::provider_control::ProviderControlHandle;
+use nym_socks5_client_core::socks::provider_pool::{ProviderPool, ProviderStatus};
 use nym_sphinx::addressing::clients::Recipient;
 use nym_task::{connections::LaneQueueLengths, TaskManager};
 
@@ -22,6 +24,12 @@ pub struct Socks5MixnetClient {
 
     /// SOCKS5 configuration parameters.
     pub(crate) socks5_config: Socks5,
+
+    /// Handle for sending control requests to the service providers.
+    pub(crate) provider_control: ProviderControlHandle,
+
+    /// Service providers used for new connections alongside their health status.
+    pub(crate) provider_pool: ProviderPool,
 }
 
 impl Socks5MixnetClient {
@@ -60,6 +68,22 @@ impl Socks5MixnetClient {
         format!("socks5h://127.0.0.1:{}", self.socks5_config.listening_port)
     }
 
+    /// Get the strategy used for choosing the service provider for each new connection.
+    pub fn provider_selection_strategy(&self) -> ProviderSelectionStrategy {
+        self.provider_pool.strategy()
+    }
+
+    /// Get the current health status of all service providers known to this client.
+    pub fn providers_status(&self) -> Vec<ProviderStatus> {
+        self.provider_pool.status()
+    }
+
+    /// Get a shallow clone of [`ProviderControlHandle`] that can be used for sending control
+    /// requests, such as capability or allow list queries, to the service providers.
+    pub fn provider_control(&self) -> ProviderControlHandle {
+        self.provider_control.clone()
+    }
+
     /// Get a shallow clone of [`LaneQueueLengths`]. This is useful to manually implement some form
     /// of backpressure logic.
     pub fn shared_lane_queue_lengths(&self) -> LaneQueueLengths {
@@ -0,0 +1,329 @@
+//! Runtime for hosting arbitrary service providers on the Nym mixnet.
+//!
+//! Anything implementing the [`ServiceProvider`] trait can be plugged into the
+//! [`ServiceProviderRuntime`] alongside a connected [`MixnetClient`]. The runtime takes care of
+//! deserializing the incoming requests, handling the control requests, such as health checks or
+//! version queries, and sending the responses back to the requesters using the attached reply
+//! SURBs.
+//!
+//! # Basic example
+//!
+//! ```ignore
+//! use nym_sdk::mixnet;
+//! use nym_sdk::service_provider::ServiceProviderRuntime;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = mixnet::MixnetClient::connect_new().await.unwrap();
+//!     println!("Our service provider is listening on {}", client.nym_address());
+//!
+//!     // `MyProvider` implements the `ServiceProvider` trait.
+//!     // The runtime is going to run until ctrl-c is received.
+//!     ServiceProviderRuntime::new(MyProvider::default(), client)
+//!         .run()
+//!         .await;
+//! }
+//! ```
+//!
+//! See `examples/service_provider.rs` for a complete, albeit very simple, key-value store provider.
+
+use crate::mixnet::MixnetClient;
+use futures::Future;
+use nym_service_providers_common::interface::{
+    ProviderInterfaceVersion, Request, Response, ServiceProviderRequest,
+};
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+pub use async_trait::async_trait;
+pub use nym_service_providers_common::{interface, ServiceProvider};
+pub use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
+
+/// Runtime driving a [`ServiceProvider`] implementation using the provided [`MixnetClient`].
+pub struct ServiceProviderRuntime<P, T: ServiceProviderRequest = interface::EmptyMessage> {
+    provider: P,
+    client: MixnetClient,
+    _request: PhantomData<fn() -> T>,
+}
+
+impl<P, T> ServiceProviderRuntime<P, T>
+where
+    P: ServiceProvider<T> + Send,
+    P::ServiceProviderError: Display,
+    T: ServiceProviderRequest + Send + 'static,
+    T::Error: Display,
+{
+    /// Creates a new runtime for the provided service provider that is going to receive
+    /// and respond to requests using the already connected mixnet client.
+    pub fn new(provider: P, client: MixnetClient) -> Self {
+        ServiceProviderRuntime {
+            provider,
+            client,
+            _request: PhantomData,
+        }
+    }
+
+    /// Get the nym address of the underlying client, i.e. the address
+    /// the service provider is reachable at.
+    pub fn nym_address(&self) -> &crate::mixnet::Recipient {
+        self.client.nym_address()
+    }
+
+    /// Get reference to the hosted service provider.
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Run the service provider until SIGINT is received. The underlying mixnet client
+    /// is disconnected afterwards.
+    pub async fn run(self) -> P {
+        self.run_with_shutdown(async {
+            if let Err(err) = tokio::signal::ctrl_c().await {
+                log::error!("failed to listen for SIGINT: {err}");
+                // if we can't listen for the signal, keep running forever
+                futures::future::pending::<()>().await
+            }
+            log::info!("Received SIGINT");
+        })
+        .await
+    }
+
+    /// Run the service provider until the provided shutdown future resolves
+    /// or the underlying mixnet client stops. The underlying mixnet client
+    /// is disconnected afterwards and the service provider is given back.
+    pub async fn run_with_shutdown<F>(mut self, shutdown: F) -> P
+    where
+        F: Future<Output = ()>,
+    {
+        tokio::pin!(shutdown);
+
+        loop {
+            tokio::select! {
+                biased;
+                _ = &mut shutdown => {
+                    log::debug!("ServiceProviderRuntime: Received shutdown");
+                    break;
+                }
+                received = self.client.wait_for_messages() => {
+                    let Some(received) = received else {
+                        log::error!("ServiceProviderRuntime: the mixnet client has stopped");
+                        break;
+                    };
+
+                    for reconstructed in received {
+                        self.on_message(reconstructed.sender_tag, &reconstructed.message).await
+                    }
+                }
+            }
+        }
+
+        self.client.disconnect().await;
+        log::debug!("ServiceProviderRuntime: Exiting");
+        self.provider
+    }
+
+    async fn on_message(&mut self, sender: Option<AnonymousSenderTag>, message: &[u8]) {
+        let Some(response) = Self::handle_message(&mut self.provider, sender, message).await else {
+            return;
+        };
+
+        // we can only reply to requests that included reply SURBs
+        let Some(sender) = sender else {
+            log::warn!("the received request did not contain any reply SURBs - can't send the response back");
+            return;
+        };
+
+        self.client.send_reply(sender, response.into_bytes()).await
+    }
+
+    /// Deserializes the received message and lets the provider resolve it, returning the response
+    /// that should be sent back to the requester, if any.
+    async fn handle_message(
+        provider: &mut P,
+        sender: Option<AnonymousSenderTag>,
+        message: &[u8],
+    ) -> Option<Response<T>> {
+        let mut request = match Request::<T>::try_from_bytes(message) {
+            Ok(req) => req,
+            Err(err) => {
+                log::warn!("Failed to deserialize received message: {err}");
+                return None;
+            }
+        };
+
+        // if the requester is using newer version of the interface than us, respond with the one
+        // we understand. otherwise we're going to use exactly the same version it has used.
+        request.interface_version = request
+            .interface_version
+            .min(ProviderInterfaceVersion::new_current());
+
+        match provider.handle_request(sender, request).await {
+            Ok(response) => response,
+            Err(err) => {
+                log::warn!("failed to resolve the received request: {err}");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_bin_common::build_information::BinaryBuildInformation;
+    use nym_service_providers_common::interface::{
+        BinaryInformation, ControlRequest, ControlResponse, Empty, ResponseContent, Serializable,
+        ServiceProviderMessagingError, ServiceProviderResponse,
+    };
+
+    #[derive(Debug)]
+    struct Echo(Vec<u8>);
+
+    impl Serializable for Echo {
+        type Error = ServiceProviderMessagingError;
+
+        fn into_bytes(self) -> Vec<u8> {
+            self.0
+        }
+
+        fn try_from_bytes(b: &[u8]) -> Result<Self, Self::Error> {
+            Ok(Echo(b.to_vec()))
+        }
+    }
+
+    impl ServiceProviderResponse for Echo {}
+
+    impl ServiceProviderRequest for Echo {
+        type ProtocolVersion = Empty;
+        type Response = Echo;
+        type Error = ServiceProviderMessagingError;
+
+        fn provider_specific_version(&self) -> Self::ProtocolVersion {
+            Empty
+        }
+
+        fn max_supported_version() -> Self::ProtocolVersion {
+            Empty
+        }
+    }
+
+    // echoes back any non-empty request
+    #[derive(Default)]
+    struct EchoProvider {
+        received: usize,
+    }
+
+    #[async_trait]
+    impl ServiceProvider<Echo> for EchoProvider {
+        type ServiceProviderError = ServiceProviderMessagingError;
+
+        async fn handle_binary_info_control_request(
+            &self,
+        ) -> Result<BinaryInformation, Self::ServiceProviderError> {
+            Ok(BinaryInformation {
+                binary_name: "echo".to_string(),
+                build_information: BinaryBuildInformation::new(env!("CARGO_PKG_VERSION"))
+                    .to_owned(),
+            })
+        }
+
+        async fn handle_provider_data_request(
+            &mut self,
+            _sender: Option<AnonymousSenderTag>,
+            request: Echo,
+            _interface_version: ProviderInterfaceVersion,
+        ) -> Result<Option<Echo>, Self::ServiceProviderError> {
+            self.received += 1;
+            if request.0.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(request))
+            }
+        }
+    }
+
+    async fn handle(provider: &mut EchoProvider, request: Request<Echo>) -> Option<Response<Echo>> {
+        ServiceProviderRuntime::<EchoProvider, Echo>::handle_message(
+            provider,
+            None,
+            &request.into_bytes(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn provider_data_requests_are_resolved_by_the_provider() {
+        let mut provider = EchoProvider::default();
+        let current = ProviderInterfaceVersion::new_current();
+
+        let request = Request::new_provider_data(current, Echo(b"hello".to_vec()));
+        let response = handle(&mut provider, request).await.unwrap();
+        assert_eq!(response.interface_version, current);
+        assert!(
+            matches!(response.content, ResponseContent::ProviderData(Echo(data)) if data == b"hello")
+        );
+
+        // the provider is free not to respond
+        let request = Request::new_provider_data(current, Echo(Vec::new()));
+        assert!(handle(&mut provider, request).await.is_none());
+        assert_eq!(provider.received, 2);
+    }
+
+    #[tokio::test]
+    async fn control_requests_are_answered_with_their_id() {
+        let mut provider = EchoProvider::default();
+        let current = ProviderInterfaceVersion::new_current();
+
+        let request = Request::new_identified_control(current, 42, ControlRequest::Health);
+        let response = handle(&mut provider, request).await.unwrap();
+        assert!(matches!(
+            response.content,
+            ResponseContent::Control {
+                request_id: Some(42),
+                response: ControlResponse::Health
+            }
+        ));
+
+        let request = Request::new_identified_control(current, 43, ControlRequest::BinaryInfo);
+        let response = handle(&mut provider, request).await.unwrap();
+        assert!(matches!(
+            response.content,
+            ResponseContent::Control {
+                request_id: Some(43),
+                response: ControlResponse::BinaryInfo(info)
+            } if info.binary_name == "echo"
+        ));
+
+        // control requests never reach the provider data handler
+        assert_eq!(provider.received, 0);
+    }
+
+    #[tokio::test]
+    async fn responses_use_the_highest_mutually_supported_interface_version() {
+        let mut provider = EchoProvider::default();
+        let current = ProviderInterfaceVersion::new_current();
+        let newer = ProviderInterfaceVersion::new_versioned(current.as_u8().unwrap() + 1);
+
+        let request = Request::new_provider_data(newer, Echo(b"hello".to_vec()));
+        let response = handle(&mut provider, request).await.unwrap();
+        assert_eq!(response.interface_version, current);
+
+        // legacy requests don't have the version prefix and are recognised by their first byte
+        // being lower than the initial interface version
+        let legacy = ProviderInterfaceVersion::new_legacy();
+        let request = Request::new_provider_data(legacy, Echo(vec![0, 1, 2]));
+        let response = handle(&mut provider, request).await.unwrap();
+        assert_eq!(response.interface_version, legacy);
+    }
+
+    #[tokio::test]
+    async fn malformed_messages_are_ignored() {
+        let mut provider = EchoProvider::default();
+
+        let response =
+            ServiceProviderRuntime::<EchoProvider, Echo>::handle_message(&mut provider, None, &[])
+                .await;
+        assert!(response.is_none());
+        assert_eq!(provider.received, 0);
+    }
+}
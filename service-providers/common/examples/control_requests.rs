@@ -12,7 +12,7 @@ fn parse_control_response(received: Vec<ReconstructedMessage>) -> ControlRespons
     assert_eq!(received.len(), 1);
     let response: Response = Response::try_from_bytes(&received[0].message).unwrap();
     match response.content {
-        ResponseContent::Control(control) => control,
+        ResponseContent::Control { response, .. } => response,
         ResponseContent::ProviderData(_) => {
             panic!("received provider data even though we sent control request!")
         }
@@ -42,11 +42,17 @@ async fn main() -> anyhow::Result<()> {
     let request_versions = ControlRequest::SupportedRequestVersions;
 
     let full_request_health: Request =
-        Request::new_control(ProviderInterfaceVersion::new_current(), request_health);
-    let full_request_binary_info: Request =
-        Request::new_control(ProviderInterfaceVersion::new_current(), request_binary_info);
-    let full_request_versions: Request =
-        Request::new_control(ProviderInterfaceVersion::new_current(), request_versions);
+        Request::new_identified_control(ProviderInterfaceVersion::new_current(), 1, request_health);
+    let full_request_binary_info: Request = Request::new_identified_control(
+        ProviderInterfaceVersion::new_current(),
+        2,
+        request_binary_info,
+    );
+    let full_request_versions: Request = Request::new_identified_control(
+        ProviderInterfaceVersion::new_current(),
+        3,
+        request_versions,
+    );
 
     // // TODO: currently we HAVE TO use surbs unfortunately
     println!("Sending 'Health' request...");
@@ -1,15 +1,71 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::interface::{Serializable, ServiceProviderMessagingError};
+use crate::interface::version::{
+    CONTROL_REQUEST_IDS_INTERFACE_VERSION, INITIAL_INTERFACE_VERSION,
+    POLICY_QUERIES_INTERFACE_VERSION,
+};
+use crate::interface::{ProviderInterfaceVersion, Serializable, ServiceProviderMessagingError};
+use log::warn;
 use nym_bin_common::build_information::BinaryBuildInformationOwned;
 use serde::{Deserialize, Serialize};
 
+/// Maximum number of policy entries that can be returned in a single [`PolicyPage`].
+pub const MAX_POLICY_PAGE_SIZE: u32 = 500;
+
+/// Identifier attached to control requests and echoed back in the associated responses,
+/// so that the requester could tell which request the received response belongs to.
+pub type ControlRequestId = u64;
+
+/// Checks whether control messages sent using the provided interface version carry a [`ControlRequestId`].
+fn has_control_request_id(interface_version: ProviderInterfaceVersion) -> bool {
+    interface_version
+        >= ProviderInterfaceVersion::new_versioned(CONTROL_REQUEST_IDS_INTERFACE_VERSION)
+}
+
+/// Splits the serialized control message into its [`ControlRequestId`], if the provided interface version
+/// defines one, and the remaining bytes of the message.
+pub(crate) fn split_control_request_id(
+    b: &[u8],
+    interface_version: ProviderInterfaceVersion,
+) -> Result<(Option<ControlRequestId>, &[u8]), ServiceProviderMessagingError> {
+    if !has_control_request_id(interface_version) {
+        return Ok((None, b));
+    }
+
+    if b.len() < 8 {
+        return Err(ServiceProviderMessagingError::MissingControlRequestId { received: b.len() });
+    }
+
+    // the unwrap is fine as we've just checked for the correct length
+    let request_id = ControlRequestId::from_be_bytes(b[..8].try_into().unwrap());
+    Ok((Some(request_id), &b[8..]))
+}
+
+/// Serializes the [`ControlRequestId`], if the provided interface version defines one.
+pub(crate) fn control_request_id_bytes(
+    request_id: Option<ControlRequestId>,
+    interface_version: ProviderInterfaceVersion,
+) -> Vec<u8> {
+    if !has_control_request_id(interface_version) {
+        return Vec::new();
+    }
+
+    if request_id.is_none() {
+        warn!("attempted to serialize a control message without its id using {interface_version} interface version");
+    }
+    request_id.unwrap_or_default().to_be_bytes().to_vec()
+}
+
 #[derive(Debug)]
 pub enum ControlRequest {
     Health,
     BinaryInfo,
     SupportedRequestVersions,
+
+    // Version 4 requests:
+    Capabilities,
+    PolicyPage { offset: u32, limit: u32 },
 }
 
 #[repr(u8)]
@@ -22,6 +78,12 @@ enum ControlRequestTag {
 
     /// Value tag representing [`SupportedRequestVersions`] variant of the [`ControlRequest`]
     RequestVersions = 0x02,
+
+    /// Value tag representing [`Capabilities`] variant of the [`ControlRequest`]
+    Capabilities = 0x03,
+
+    /// Value tag representing [`PolicyPage`] variant of the [`ControlRequest`]
+    PolicyPage = 0x04,
 }
 
 impl TryFrom<u8> for ControlRequestTag {
@@ -32,6 +94,8 @@ impl TryFrom<u8> for ControlRequestTag {
             _ if value == (Self::Health as u8) => Ok(Self::Health),
             _ if value == (Self::BinaryInfo as u8) => Ok(Self::BinaryInfo),
             _ if value == (Self::RequestVersions as u8) => Ok(Self::RequestVersions),
+            _ if value == (Self::Capabilities as u8) => Ok(Self::Capabilities),
+            _ if value == (Self::PolicyPage as u8) => Ok(Self::PolicyPage),
             received => Err(ServiceProviderMessagingError::InvalidControlRequestTag { received }),
         }
     }
@@ -41,8 +105,15 @@ impl Serializable for ControlRequest {
     type Error = ServiceProviderMessagingError;
 
     fn into_bytes(self) -> Vec<u8> {
-        // current variants do not require sending any data apart from the tag
-        vec![self.tag() as u8]
+        let tag = self.tag() as u8;
+        match self {
+            ControlRequest::PolicyPage { offset, limit } => std::iter::once(tag)
+                .chain(offset.to_be_bytes())
+                .chain(limit.to_be_bytes())
+                .collect(),
+            // remaining variants do not require sending any data apart from the tag
+            _ => vec![tag],
+        }
     }
 
     fn try_from_bytes(b: &[u8]) -> Result<Self, ServiceProviderMessagingError> {
@@ -55,6 +126,19 @@ impl Serializable for ControlRequest {
             ControlRequestTag::Health => Ok(ControlRequest::Health),
             ControlRequestTag::BinaryInfo => Ok(ControlRequest::BinaryInfo),
             ControlRequestTag::RequestVersions => Ok(ControlRequest::SupportedRequestVersions),
+            ControlRequestTag::Capabilities => Ok(ControlRequest::Capabilities),
+            ControlRequestTag::PolicyPage => {
+                if b.len() != 9 {
+                    return Err(ServiceProviderMessagingError::MalformedPolicyPageRequest {
+                        received: b.len(),
+                    });
+                }
+                // the unwraps are fine as we've just checked for the correct length
+                Ok(ControlRequest::PolicyPage {
+                    offset: u32::from_be_bytes(b[1..5].try_into().unwrap()),
+                    limit: u32::from_be_bytes(b[5..9].try_into().unwrap()),
+                })
+            }
         }
     }
 }
@@ -65,6 +149,22 @@ impl ControlRequest {
             ControlRequest::Health => ControlRequestTag::Health,
             ControlRequest::BinaryInfo => ControlRequestTag::BinaryInfo,
             ControlRequest::SupportedRequestVersions => ControlRequestTag::RequestVersions,
+            ControlRequest::Capabilities => ControlRequestTag::Capabilities,
+            ControlRequest::PolicyPage { .. } => ControlRequestTag::PolicyPage,
+        }
+    }
+
+    /// The minimum version of the provider interface that understands this request.
+    pub fn min_interface_version(&self) -> ProviderInterfaceVersion {
+        match self {
+            ControlRequest::Health
+            | ControlRequest::BinaryInfo
+            | ControlRequest::SupportedRequestVersions => {
+                ProviderInterfaceVersion::new_versioned(INITIAL_INTERFACE_VERSION)
+            }
+            ControlRequest::Capabilities | ControlRequest::PolicyPage { .. } => {
+                ProviderInterfaceVersion::new_versioned(POLICY_QUERIES_INTERFACE_VERSION)
+            }
         }
     }
 }
@@ -81,16 +181,85 @@ pub struct SupportedVersions {
     pub provider_version: String,
 }
 
+/// Summary of the outbound traffic policy of a service provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicySummary {
+    /// Indicates whether the provider accepts requests to any destination.
+    pub open: bool,
+
+    /// Total number of entries in the allow list.
+    pub total_entries: u32,
+
+    /// Hex-encoded hash of the full allow list. It can be used for detecting whether the policy
+    /// has changed between subsequent [`ControlRequest::PolicyPage`] queries.
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderCapabilities {
+    /// Optional features supported by this provider, such as "udp" or "compression".
+    pub features: Vec<String>,
+
+    /// Outbound policy of this provider, if it has any.
+    pub policy: Option<PolicySummary>,
+}
+
+impl ProviderCapabilities {
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+}
+
+/// A single page of the allow list of a service provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyPage {
+    /// Hash of the full allow list at the time of constructing this page.
+    pub hash: String,
+
+    pub offset: u32,
+    pub total_entries: u32,
+
+    /// Allowed destinations, such as domains or ip networks.
+    pub entries: Vec<String>,
+}
+
+impl PolicyPage {
+    pub fn next_offset(&self) -> Option<u32> {
+        // don't trust the provider to have sent sensible values
+        let entries = u32::try_from(self.entries.len()).ok()?;
+        let next = self.offset.checked_add(entries)?;
+        if self.entries.is_empty() || next >= self.total_entries {
+            None
+        } else {
+            Some(next)
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ErrorResponse {
     message: String,
 }
 
+impl ErrorResponse {
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        ErrorResponse {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
 #[derive(Debug)]
 pub enum ControlResponse {
     Health,
     BinaryInfo(Box<BinaryInformation>),
     SupportedRequestVersions(SupportedVersions),
+    Capabilities(ProviderCapabilities),
+    PolicyPage(PolicyPage),
     Error(ErrorResponse),
 }
 
@@ -105,6 +274,12 @@ enum ControlResponseTag {
     /// Value tag representing [`SupportedRequestVersions`] variant of the [`ControlResponse`]
     SupportedRequestVersions = 0x02,
 
+    /// Value tag representing [`Capabilities`] variant of the [`ControlResponse`]
+    Capabilities = 0x03,
+
+    /// Value tag representing [`PolicyPage`] variant of the [`ControlResponse`]
+    PolicyPage = 0x04,
+
     /// Value tag representing [`Error`] variant of the [`ControlResponse`]
     Error = 0xFF,
 }
@@ -119,6 +294,8 @@ impl TryFrom<u8> for ControlResponseTag {
             _ if value == (Self::SupportedRequestVersions as u8) => {
                 Ok(Self::SupportedRequestVersions)
             }
+            _ if value == (Self::Capabilities as u8) => Ok(Self::Capabilities),
+            _ if value == (Self::PolicyPage as u8) => Ok(Self::PolicyPage),
             _ if value == (Self::Error as u8) => Ok(Self::Error),
             received => Err(ServiceProviderMessagingError::InvalidControlResponseTag { received }),
         }
@@ -156,6 +333,18 @@ impl Serializable for ControlResponse {
                     Err(ServiceProviderMessagingError::MalformedErrorControlResponse { source })
                 }
             },
+            ControlResponseTag::Capabilities => match serde_json::from_slice(&b[1..]) {
+                Ok(capabilities) => Ok(ControlResponse::Capabilities(capabilities)),
+                Err(source) => Err(
+                    ServiceProviderMessagingError::MalformedCapabilitiesControlResponse { source },
+                ),
+            },
+            ControlResponseTag::PolicyPage => match serde_json::from_slice(&b[1..]) {
+                Ok(page) => Ok(ControlResponse::PolicyPage(page)),
+                Err(source) => Err(
+                    ServiceProviderMessagingError::MalformedPolicyPageControlResponse { source },
+                ),
+            },
             ControlResponseTag::Error => match serde_json::from_slice(&b[1..]) {
                 Ok(error_response) => Ok(ControlResponse::Error(error_response)),
                 Err(source) => {
@@ -174,6 +363,8 @@ impl ControlResponse {
             ControlResponse::SupportedRequestVersions(_) => {
                 ControlResponseTag::SupportedRequestVersions
             }
+            ControlResponse::Capabilities(_) => ControlResponseTag::Capabilities,
+            ControlResponse::PolicyPage(_) => ControlResponseTag::PolicyPage,
             ControlResponse::Error(_) => ControlResponseTag::Error,
         }
     }
@@ -199,6 +390,141 @@ impl ControlResponse {
             ControlResponse::SupportedRequestVersions(supported_versions) => {
                 serde_json::to_vec(&supported_versions).unwrap()
             }
+            ControlResponse::Capabilities(capabilities) => {
+                serde_json::to_vec(&capabilities).unwrap()
+            }
+            ControlResponse::PolicyPage(page) => serde_json::to_vec(&page).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::{EmptyMessage, Request, RequestContent, Response, ResponseContent};
+
+    #[test]
+    fn control_request_ids_are_sent_with_current_interface() {
+        let req: Request = Request::new_identified_control(
+            ProviderInterfaceVersion::new_current(),
+            42,
+            ControlRequest::PolicyPage {
+                offset: 1,
+                limit: 2,
+            },
+        );
+        let recovered: Request = Request::try_from_bytes(&req.into_bytes()).unwrap();
+        match recovered.content {
+            RequestContent::Control {
+                request_id,
+                request: ControlRequest::PolicyPage { offset, limit },
+            } => {
+                assert_eq!(request_id, Some(42));
+                assert_eq!(offset, 1);
+                assert_eq!(limit, 2);
+            }
+            other => panic!("unexpected request: {other:?}"),
         }
+
+        let res: Response = Response::new_identified_control(
+            ProviderInterfaceVersion::new_current(),
+            42,
+            ControlResponse::Health,
+        );
+        let recovered: Response = Response::try_from_bytes(&res.into_bytes()).unwrap();
+        match recovered.content {
+            ResponseContent::Control {
+                request_id,
+                response: ControlResponse::Health,
+            } => assert_eq!(request_id, Some(42)),
+            other => panic!("unexpected response: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn control_request_ids_are_not_sent_with_older_interfaces() {
+        let version = ProviderInterfaceVersion::new_versioned(POLICY_QUERIES_INTERFACE_VERSION);
+        let req: Request =
+            Request::new_identified_control(version, 42, ControlRequest::Capabilities);
+        let bytes = req.into_bytes();
+        assert_eq!(bytes.len(), 3);
+
+        let recovered: Request = Request::try_from_bytes(&bytes).unwrap();
+        match recovered.content {
+            RequestContent::Control {
+                request_id,
+                request: ControlRequest::Capabilities,
+            } => assert!(request_id.is_none()),
+            other => panic!("unexpected request: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn control_message_without_its_id_is_rejected() {
+        let current = ProviderInterfaceVersion::new_current();
+        let mut bytes = vec![current.as_u8().unwrap(), 0x00];
+        bytes.extend_from_slice(&[0u8; 4]);
+        assert!(Response::<EmptyMessage>::try_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn policy_page_request_serialization_roundtrip() {
+        let req = ControlRequest::PolicyPage {
+            offset: 42,
+            limit: 100,
+        };
+        let bytes = req.into_bytes();
+        assert_eq!(bytes.len(), 9);
+
+        match ControlRequest::try_from_bytes(&bytes).unwrap() {
+            ControlRequest::PolicyPage { offset, limit } => {
+                assert_eq!(offset, 42);
+                assert_eq!(limit, 100);
+            }
+            other => panic!("unexpected request: {other:?}"),
+        }
+
+        assert!(ControlRequest::try_from_bytes(&bytes[..5]).is_err());
+    }
+
+    #[test]
+    fn capabilities_response_serialization_roundtrip() {
+        let capabilities = ProviderCapabilities {
+            features: vec!["tcp".to_string()],
+            policy: Some(PolicySummary {
+                open: false,
+                total_entries: 2,
+                hash: "foomp".to_string(),
+            }),
+        };
+        let bytes = ControlResponse::Capabilities(capabilities.clone()).into_bytes();
+        match ControlResponse::try_from_bytes(&bytes).unwrap() {
+            ControlResponse::Capabilities(recovered) => assert_eq!(recovered, capabilities),
+            other => panic!("unexpected response: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn policy_page_next_offset() {
+        let mut page = PolicyPage {
+            hash: "foomp".to_string(),
+            offset: 0,
+            total_entries: 3,
+            entries: vec!["nymtech.net".to_string(), "10.0.0.0/8".to_string()],
+        };
+        assert_eq!(page.next_offset(), Some(2));
+
+        page.offset = 1;
+        assert_eq!(page.next_offset(), None);
+
+        page.entries = Vec::new();
+        page.offset = 0;
+        assert_eq!(page.next_offset(), None);
+
+        // a malicious provider can't make us overflow
+        page.entries = vec!["nymtech.net".to_string()];
+        page.offset = u32::MAX;
+        page.total_entries = u32::MAX;
+        assert_eq!(page.next_offset(), None);
     }
 }
@@ -1,7 +1,10 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-pub use control::{BinaryInformation, ControlRequest, ControlResponse, SupportedVersions};
+pub use control::{
+    BinaryInformation, ControlRequest, ControlRequestId, ControlResponse, ErrorResponse,
+    PolicyPage, PolicySummary, ProviderCapabilities, SupportedVersions, MAX_POLICY_PAGE_SIZE,
+};
 pub use request::{Request, RequestContent, ServiceProviderRequest};
 pub use response::{Response, ResponseContent, ServiceProviderResponse};
 pub use version::{ProviderInterfaceVersion, RequestVersion, Version};
@@ -65,6 +68,20 @@ pub enum ServiceProviderMessagingError {
 
     #[error("the received supported versions control response was malformed: {source}")]
     MalformedSupportedVersionsResponse { source: serde_json::Error },
+
+    #[error("the received capabilities control response was malformed: {source}")]
+    MalformedCapabilitiesControlResponse { source: serde_json::Error },
+
+    #[error("the received policy page control response was malformed: {source}")]
+    MalformedPolicyPageControlResponse { source: serde_json::Error },
+
+    #[error("the received policy page control request was malformed. It contained {received} bytes instead of 9")]
+    MalformedPolicyPageRequest { received: usize },
+
+    #[error(
+        "the received control message did not contain its id. It contained only {received} bytes"
+    )]
+    MissingControlRequestId { received: usize },
 }
 
 // can't use 'normal' trait (i.e. Serialize/Deserialize from serde) as `Socks5Message` uses custom serialization
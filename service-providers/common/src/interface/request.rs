@@ -1,9 +1,10 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::interface::control::{control_request_id_bytes, split_control_request_id};
 use crate::interface::version::Version;
 use crate::interface::{
-    ControlRequest, EmptyMessage, ProviderInterfaceVersion, Serializable,
+    ControlRequest, ControlRequestId, EmptyMessage, ProviderInterfaceVersion, Serializable,
     ServiceProviderMessagingError, ServiceProviderResponse,
 };
 use log::warn;
@@ -32,7 +33,11 @@ pub struct Request<T: ServiceProviderRequest = EmptyMessage> {
 
 #[derive(Debug)]
 pub enum RequestContent<T: ServiceProviderRequest = EmptyMessage> {
-    Control(ControlRequest),
+    Control {
+        /// Identifier of the request. It's only sent since the `CONTROL_REQUEST_IDS_INTERFACE_VERSION`.
+        request_id: Option<ControlRequestId>,
+        request: ControlRequest,
+    },
     ProviderData(T),
 }
 
@@ -67,7 +72,24 @@ where
     ) -> Self {
         Request {
             interface_version,
-            content: RequestContent::Control(content),
+            content: RequestContent::Control {
+                request_id: None,
+                request: content,
+            },
+        }
+    }
+
+    pub fn new_identified_control(
+        interface_version: ProviderInterfaceVersion,
+        request_id: ControlRequestId,
+        content: ControlRequest,
+    ) -> Self {
+        Request {
+            interface_version,
+            content: RequestContent::Control {
+                request_id: Some(request_id),
+                request: content,
+            },
         }
     }
 
@@ -113,31 +135,38 @@ where
 {
     fn tag(&self) -> RequestTag {
         match self {
-            RequestContent::Control(_) => RequestTag::Control,
+            RequestContent::Control { .. } => RequestTag::Control,
             RequestContent::ProviderData(_) => RequestTag::ProviderData,
         }
     }
 
-    fn serialize_inner(self) -> Vec<u8> {
+    fn serialize_inner(self, interface_version: ProviderInterfaceVersion) -> Vec<u8> {
         match self {
-            RequestContent::Control(control) => control.into_bytes(),
+            RequestContent::Control {
+                request_id,
+                request,
+            } => {
+                let mut bytes = control_request_id_bytes(request_id, interface_version);
+                bytes.extend(request.into_bytes());
+                bytes
+            }
             RequestContent::ProviderData(provider_data) => provider_data.into_bytes(),
         }
     }
 
     fn into_bytes(self, interface_version: ProviderInterfaceVersion) -> Vec<u8> {
         if interface_version.is_legacy() {
-            if matches!(self, RequestContent::Control(_)) {
+            if matches!(self, RequestContent::Control { .. }) {
                 // this shouldn't ever happen, since if client is aware of control requests,
                 // it should be aware of versioning and shouldn't attempt to send those
                 warn!("attempted to serialize a control request in legacy mode");
                 Vec::new()
             } else {
-                self.serialize_inner()
+                self.serialize_inner(interface_version)
             }
         } else {
             std::iter::once(self.tag() as u8)
-                .chain(self.serialize_inner().into_iter())
+                .chain(self.serialize_inner(interface_version).into_iter())
                 .collect()
         }
     }
@@ -160,9 +189,13 @@ where
 
             let request_tag = RequestTag::try_from(b[0])?;
             match request_tag {
-                RequestTag::Control => Ok(RequestContent::Control(ControlRequest::try_from_bytes(
-                    &b[1..],
-                )?)),
+                RequestTag::Control => {
+                    let (request_id, b) = split_control_request_id(&b[1..], interface_version)?;
+                    Ok(RequestContent::Control {
+                        request_id,
+                        request: ControlRequest::try_from_bytes(b)?,
+                    })
+                }
                 RequestTag::ProviderData => {
                     Ok(RequestContent::ProviderData(T::try_from_bytes(&b[1..])?))
                 }
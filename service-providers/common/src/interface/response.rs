@@ -1,8 +1,9 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::interface::control::{control_request_id_bytes, split_control_request_id};
 use crate::interface::{
-    ControlResponse, EmptyMessage, ProviderInterfaceVersion, Serializable,
+    ControlRequestId, ControlResponse, EmptyMessage, ProviderInterfaceVersion, Serializable,
     ServiceProviderMessagingError, ServiceProviderRequest,
 };
 use log::warn;
@@ -18,7 +19,12 @@ pub struct Response<T: ServiceProviderRequest = EmptyMessage> {
 
 #[derive(Debug)]
 pub enum ResponseContent<T: ServiceProviderRequest = EmptyMessage> {
-    Control(ControlResponse),
+    Control {
+        /// Identifier of the request this is a response to. It's only sent since the
+        /// `CONTROL_REQUEST_IDS_INTERFACE_VERSION`.
+        request_id: Option<ControlRequestId>,
+        response: ControlResponse,
+    },
     ProviderData(T::Response),
 }
 
@@ -53,7 +59,24 @@ where
     ) -> Self {
         Response {
             interface_version,
-            content: ResponseContent::Control(content),
+            content: ResponseContent::Control {
+                request_id: None,
+                response: content,
+            },
+        }
+    }
+
+    pub fn new_identified_control(
+        interface_version: ProviderInterfaceVersion,
+        request_id: ControlRequestId,
+        content: ControlResponse,
+    ) -> Self {
+        Response {
+            interface_version,
+            content: ResponseContent::Control {
+                request_id: Some(request_id),
+                response: content,
+            },
         }
     }
 
@@ -102,31 +125,38 @@ where
 {
     fn tag(&self) -> ResponseTag {
         match self {
-            ResponseContent::Control(_) => ResponseTag::Control,
+            ResponseContent::Control { .. } => ResponseTag::Control,
             ResponseContent::ProviderData(_) => ResponseTag::ProviderData,
         }
     }
 
-    fn serialize_inner(self) -> Vec<u8> {
+    fn serialize_inner(self, interface_version: ProviderInterfaceVersion) -> Vec<u8> {
         match self {
-            ResponseContent::Control(control) => control.into_bytes(),
+            ResponseContent::Control {
+                request_id,
+                response,
+            } => {
+                let mut bytes = control_request_id_bytes(request_id, interface_version);
+                bytes.extend(response.into_bytes());
+                bytes
+            }
             ResponseContent::ProviderData(provider_data) => provider_data.into_bytes(),
         }
     }
 
     fn into_bytes(self, interface_version: ProviderInterfaceVersion) -> Vec<u8> {
         if interface_version.is_legacy() {
-            if matches!(self, ResponseContent::Control(_)) {
+            if matches!(self, ResponseContent::Control { .. }) {
                 // this shouldn't ever happen, since if service provider received a legacy request
                 // it couldn't have possibly received a control request (unless client is trying to be funny)
                 warn!("attempted to serialize a control response in legacy mode");
                 Vec::new()
             } else {
-                self.serialize_inner()
+                self.serialize_inner(interface_version)
             }
         } else {
             std::iter::once(self.tag() as u8)
-                .chain(self.serialize_inner().into_iter())
+                .chain(self.serialize_inner(interface_version).into_iter())
                 .collect()
         }
     }
@@ -151,9 +181,13 @@ where
 
             let request_tag = ResponseTag::try_from(b[0])?;
             match request_tag {
-                ResponseTag::Control => Ok(ResponseContent::Control(
-                    ControlResponse::try_from_bytes(&b[1..])?,
-                )),
+                ResponseTag::Control => {
+                    let (request_id, b) = split_control_request_id(&b[1..], interface_version)?;
+                    Ok(ResponseContent::Control {
+                        request_id,
+                        response: ControlResponse::try_from_bytes(b)?,
+                    })
+                }
                 ResponseTag::ProviderData => Ok(ResponseContent::ProviderData(
                     T::Response::try_from_bytes(&b[1..])?,
                 )),
@@ -8,9 +8,17 @@ use crate::interface::{EmptyMessage, ServiceProviderRequest};
 // and legacy communication mode is used instead
 pub const INITIAL_INTERFACE_VERSION: u8 = 3;
 
+/// Defines version of the communication interface that introduced the capabilities
+/// and allow-list policy control requests.
+pub const POLICY_QUERIES_INTERFACE_VERSION: u8 = 4;
+
+/// Defines version of the communication interface that introduced identifiers attached to control
+/// requests and echoed back in the associated responses.
+pub const CONTROL_REQUEST_IDS_INTERFACE_VERSION: u8 = 5;
+
 /// Defines the current version of the communication interface between clients and service providers.
 /// It has to be incremented for any breaking change.
-pub const INTERFACE_VERSION: u8 = 3;
+pub const INTERFACE_VERSION: u8 = 5;
 
 /// Defines full version of particular request that includes version of common service provider interface
 /// and provider-specific protocol.
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::interface::{
-    BinaryInformation, ControlRequest, ControlResponse, EmptyMessage, ProviderInterfaceVersion,
-    Request, RequestContent, Response, ResponseContent, ServiceProviderRequest, SupportedVersions,
+    BinaryInformation, ControlRequest, ControlResponse, EmptyMessage, ErrorResponse, PolicyPage,
+    ProviderCapabilities, ProviderInterfaceVersion, Request, RequestContent, Response,
+    ResponseContent, ServiceProviderRequest, SupportedVersions, MAX_POLICY_PAGE_SIZE,
 };
 use async_trait::async_trait;
 use nym_sphinx_anonymous_replies::requests::AnonymousSenderTag;
@@ -25,13 +26,20 @@ where
 {
     type ServiceProviderError: From<<T as ServiceProviderRequest>::Error>;
 
+    /// Entry point for providers that manage their own mixnet connection and thus have to send
+    /// the responses themselves, which they must do by overriding this method.
+    /// The default implementation resolves the request and discards the response. This is enough for
+    /// providers hosted by the `nym-sdk` runtime, which calls [`ServiceProvider::handle_request`]
+    /// directly and replies on their behalf.
     // TODO: refactor to use some version of `reply::MixnetAddress`
     // in case explicit address was provided
     async fn on_request(
         &mut self,
         sender: Option<AnonymousSenderTag>,
         request: Request<T>,
-    ) -> Result<(), Self::ServiceProviderError>;
+    ) -> Result<(), Self::ServiceProviderError> {
+        self.handle_request(sender, request).await.map(|_| ())
+    }
 
     async fn handle_request(
         &mut self,
@@ -39,13 +47,20 @@ where
         request: Request<T>,
     ) -> Result<Option<Response<T>>, Self::ServiceProviderError> {
         match request.content {
-            RequestContent::Control(control_request) => self
+            RequestContent::Control {
+                request_id,
+                request: control_request,
+            } => self
                 .handle_control_request(sender, control_request, request.interface_version)
                 .await
                 .map(|maybe_res| {
+                    // make sure to echo the request id so that the requester could match the response
                     maybe_res.map(|control_res| Response {
                         interface_version: request.interface_version,
-                        content: ResponseContent::Control(control_res),
+                        content: ResponseContent::Control {
+                            request_id,
+                            response: control_res,
+                        },
                     })
                 }),
             RequestContent::ProviderData(provider_data_request) => self
@@ -73,6 +88,12 @@ where
         if interface_version.is_legacy() {
             // control requests didn't exist in the legacy version
             Ok(None)
+        } else if interface_version < request.min_interface_version() {
+            // the request couldn't have possibly been sent with this version of the interface
+            log::debug!(
+                "received {request:?} control request with incompatible interface version {interface_version}"
+            );
+            Ok(None)
         } else {
             let response = match request {
                 // Version 3 requests:
@@ -87,9 +108,24 @@ where
                 ControlRequest::SupportedRequestVersions => {
                     let versions = self.handle_supported_request_versions_request().await?;
                     Some(ControlResponse::SupportedRequestVersions(versions))
-                } //
-                  // TODO: if we ever add new request for interface version 4 (or higher),
-                  // we need to include a check to make sure we return a `None` if passed `interface_version` was 3
+                }
+                // Version 4 requests:
+                ControlRequest::Capabilities => {
+                    let capabilities = self.handle_capabilities_control_request().await?;
+                    Some(ControlResponse::Capabilities(capabilities))
+                }
+                ControlRequest::PolicyPage { offset, limit } => {
+                    let limit = limit.min(MAX_POLICY_PAGE_SIZE);
+                    match self
+                        .handle_policy_page_control_request(offset, limit)
+                        .await?
+                    {
+                        Some(page) => Some(ControlResponse::PolicyPage(page)),
+                        None => Some(ControlResponse::Error(ErrorResponse::new(
+                            "this service provider does not expose its outbound policy",
+                        ))),
+                    }
+                }
             };
             Ok(response)
         }
@@ -115,6 +151,27 @@ where
         })
     }
 
+    // by default assume the provider doesn't support any optional features
+    // and doesn't impose any outbound policy
+    async fn handle_capabilities_control_request(
+        &self,
+    ) -> Result<ProviderCapabilities, Self::ServiceProviderError> {
+        Ok(ProviderCapabilities {
+            features: Vec::new(),
+            policy: None,
+        })
+    }
+
+    /// Returns the requested page of the outbound allow list of this provider or `None`
+    /// if the provider does not expose it.
+    async fn handle_policy_page_control_request(
+        &self,
+        _offset: u32,
+        _limit: u32,
+    ) -> Result<Option<PolicyPage>, Self::ServiceProviderError> {
+        Ok(None)
+    }
+
     async fn handle_provider_data_request(
         &mut self,
         sender: Option<AnonymousSenderTag>,
@@ -5,6 +5,7 @@ use super::HostsStore;
 use crate::allowed_hosts::group::HostsGroup;
 use crate::allowed_hosts::standard_list::StandardList;
 use crate::allowed_hosts::stored_allowed_hosts::StoredAllowedHosts;
+use std::collections::BTreeSet;
 use std::net::{IpAddr, SocketAddr};
 
 #[derive(Debug)]
@@ -139,6 +140,20 @@ impl OutboundRequestFilter {
         allowed
     }
 
+    /// Returns a sorted list of all destinations explicitly allowed by either the local
+    /// allow list or the standard list.
+    pub(crate) async fn allowed_entries(&self) -> Vec<String> {
+        let allowed_hosts = self.allowed_hosts.get().await;
+        let standard_list = self.standard_list.get().await;
+
+        let entries: BTreeSet<_> = allowed_hosts
+            .data
+            .entries()
+            .chain(standard_list.entries())
+            .collect();
+        entries.into_iter().collect()
+    }
+
     fn trim_port(host: &str) -> String {
         let mut tmp: Vec<_> = host.split(':').collect();
         if tmp.len() > 1 {
@@ -514,4 +529,25 @@ mod tests {
                 .contains(&"5:6:7::/48".parse().unwrap()));
         }
     }
+
+    #[cfg(test)]
+    mod listing_allowed_entries {
+        use super::*;
+
+        #[tokio::test]
+        async fn returns_sorted_domains_and_networks() {
+            let filter = setup_with_allowed(&["nymtech.net", "1.2.3.4", "edwardsnowden.com"]);
+
+            assert_eq!(
+                filter.allowed_entries().await,
+                vec!["1.2.3.4/32", "edwardsnowden.com", "nymtech.net"]
+            );
+        }
+
+        #[tokio::test]
+        async fn is_empty_for_empty_lists() {
+            let filter = setup_empty();
+            assert!(filter.allowed_entries().await.is_empty());
+        }
+    }
 }
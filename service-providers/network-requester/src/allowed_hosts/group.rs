@@ -58,4 +58,12 @@ impl HostsGroup {
     pub(super) fn add_domain(&mut self, domain: &str) {
         self.domains.insert(domain.to_string());
     }
+
+    /// Returns string representations of all the domains and ip networks in this group.
+    pub(super) fn entries(&self) -> impl Iterator<Item = String> + '_ {
+        self.domains
+            .iter()
+            .cloned()
+            .chain(self.ip_nets.iter().map(|ip_net| ip_net.to_string()))
+    }
 }
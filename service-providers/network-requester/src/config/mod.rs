@@ -27,6 +27,11 @@ const DEFAULT_NETWORK_REQUESTERS_DIR: &str = "network-requester";
 
 pub const DEFAULT_STANDARD_LIST_UPDATE_INTERVAL: Duration = Duration::from_secs(30 * 60);
 
+pub const DEFAULT_MAX_CONCURRENT_CONNECTIONS_PER_SENDER: usize = 64;
+pub const DEFAULT_MAX_BYTES_PER_SECOND_PER_SENDER: u64 = 2 * 1024 * 1024;
+pub const DEFAULT_MAX_BYTES_PER_WINDOW_PER_SENDER: u64 = 1024 * 1024 * 1024;
+pub const DEFAULT_BANDWIDTH_WINDOW: Duration = Duration::from_secs(60 * 60);
+
 /// Derive default path to network requester's config directory.
 /// It should get resolved to `$HOME/.nym/service-providers/network-requester/<id>/config`
 pub fn default_config_directory<P: AsRef<Path>>(id: P) -> PathBuf {
@@ -160,7 +165,46 @@ impl Config {
 
 #[derive(Debug, Default, Clone, Deserialize, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
-pub struct NetworkRequester {}
+pub struct NetworkRequester {
+    /// Limits applied to each individual sender (either anonymous sender tag or explicit
+    /// return address) so that a single client could not saturate the entire uplink.
+    pub rate_limiting: RateLimiting,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimiting {
+    /// Specifies whether the per-sender limits should be enforced at all.
+    pub enabled: bool,
+
+    /// Maximum number of connections a single sender is allowed to have open at the same time.
+    pub max_concurrent_connections: usize,
+
+    /// Maximum sustained number of bytes (in either direction) a single sender is allowed
+    /// to transfer per second. Short bursts of up to one second worth of data are permitted.
+    /// Setting it to 0 disables the check.
+    pub max_bytes_per_second: u64,
+
+    /// Maximum total number of bytes (in either direction) a single sender is allowed
+    /// to transfer within a single `bandwidth_window`. Setting it to 0 disables the check.
+    pub max_bytes_per_window: u64,
+
+    /// Duration of the window used for accounting `max_bytes_per_window`.
+    #[serde(with = "humantime_serde")]
+    pub bandwidth_window: Duration,
+}
+
+impl Default for RateLimiting {
+    fn default() -> Self {
+        RateLimiting {
+            enabled: true,
+            max_concurrent_connections: DEFAULT_MAX_CONCURRENT_CONNECTIONS_PER_SENDER,
+            max_bytes_per_second: DEFAULT_MAX_BYTES_PER_SECOND_PER_SENDER,
+            max_bytes_per_window: DEFAULT_MAX_BYTES_PER_WINDOW_PER_SENDER,
+            bandwidth_window: DEFAULT_BANDWIDTH_WINDOW,
+        }
+    }
+}
 
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Serialize)]
 #[serde(default, deny_unknown_fields)]
@@ -83,6 +83,26 @@ gateway_owner = '{{ client.gateway_endpoint.gateway_owner }}'
 # Address of the gateway listener to which all client requests should be sent.
 gateway_listener = '{{ client.gateway_endpoint.gateway_listener }}'
 
+##### network requester config options #####
+
+[network_requester.rate_limiting]
+# Specifies whether the per-sender limits should be enforced at all.
+enabled = {{ network_requester.rate_limiting.enabled }}
+
+# Maximum number of connections a single sender is allowed to have open at the same time.
+max_concurrent_connections = {{ network_requester.rate_limiting.max_concurrent_connections }}
+
+# Maximum sustained number of bytes a single sender is allowed to transfer per second.
+# Setting it to 0 disables the check.
+max_bytes_per_second = {{ network_requester.rate_limiting.max_bytes_per_second }}
+
+# Maximum total number of bytes a single sender is allowed to transfer within a single window.
+# Setting it to 0 disables the check.
+max_bytes_per_window = {{ network_requester.rate_limiting.max_bytes_per_window }}
+
+# Duration of the window used for accounting `max_bytes_per_window`.
+bandwidth_window = '{{ network_requester.rate_limiting.bandwidth_window }}'
+
 ##### logging configuration options #####
 
 [logging]
@@ -7,6 +7,7 @@ use crate::allowed_hosts::stored_allowed_hosts::{start_allowed_list_reloader, St
 use crate::allowed_hosts::{OutboundRequestFilter, StandardList};
 use crate::config::{BaseClientConfig, Config};
 use crate::error::NetworkRequesterError;
+use crate::rate_limiter::{RateLimitExceeded, SenderKey, SenderRateLimiter, TrackedConnection};
 use crate::reply::MixnetMessage;
 use crate::statistics::ServiceStatisticsCollector;
 use crate::{reply, socks5};
@@ -17,7 +18,8 @@ use nym_bin_common::build_information::BinaryBuildInformation;
 use nym_client_core::config::disk_persistence::CommonClientPaths;
 use nym_network_defaults::NymNetworkDetails;
 use nym_service_providers_common::interface::{
-    BinaryInformation, ProviderInterfaceVersion, Request, RequestVersion,
+    BinaryInformation, PolicyPage, PolicySummary, ProviderCapabilities, ProviderInterfaceVersion,
+    Request, RequestVersion,
 };
 use nym_service_providers_common::ServiceProvider;
 use nym_socks5_proxy_helpers::connection_controller::{
@@ -34,11 +36,26 @@ use nym_sphinx::params::PacketSize;
 use nym_statistics_common::collector::StatisticsSender;
 use nym_task::connections::LaneQueueLengths;
 use nym_task::{TaskClient, TaskManager};
+use sha2::{Digest, Sha256};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 // Since it's an atomic, it's safe to be kept static and shared across threads
 static ACTIVE_PROXIES: AtomicUsize = AtomicUsize::new(0);
 
+/// Optional features always supported by this network requester.
+const SUPPORTED_FEATURES: &[&str] = &["tcp", "anonymous-replies"];
+const STATISTICS_FEATURE: &str = "statistics";
+const RATE_LIMITING_FEATURE: &str = "rate-limiting";
+
+fn policy_hash(entries: &[String]) -> String {
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        hasher.update(entry.as_bytes());
+        hasher.update(b"\n");
+    }
+    hex::encode(hasher.finalize())
+}
+
 pub(crate) fn new_legacy_request_version() -> RequestVersion<Socks5Request> {
     RequestVersion {
         provider_interface: ProviderInterfaceVersion::Legacy,
@@ -69,6 +86,7 @@ struct NRServiceProvider {
     mix_input_sender: MixProxySender<MixnetMessage>,
     //shared_lane_queue_lengths: LaneQueueLengths,
     stats_collector: Option<ServiceStatisticsCollector>,
+    rate_limiter: SenderRateLimiter,
     shutdown: TaskManager,
 }
 
@@ -105,6 +123,49 @@ impl ServiceProvider<Socks5Request> for NRServiceProvider {
         })
     }
 
+    async fn handle_capabilities_control_request(
+        &self,
+    ) -> Result<ProviderCapabilities, Self::ServiceProviderError> {
+        let mut features: Vec<_> = SUPPORTED_FEATURES.iter().map(|f| f.to_string()).collect();
+        if self.stats_collector.is_some() {
+            features.push(STATISTICS_FEATURE.to_string())
+        }
+        if self.config.network_requester.rate_limiting.enabled {
+            features.push(RATE_LIMITING_FEATURE.to_string())
+        }
+
+        let entries = self.policy_entries().await;
+        Ok(ProviderCapabilities {
+            features,
+            policy: Some(PolicySummary {
+                open: self.open_proxy,
+                total_entries: entries.len() as u32,
+                hash: policy_hash(&entries),
+            }),
+        })
+    }
+
+    async fn handle_policy_page_control_request(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Option<PolicyPage>, Self::ServiceProviderError> {
+        let entries = self.policy_entries().await;
+        let total_entries = entries.len() as u32;
+        let hash = policy_hash(&entries);
+
+        Ok(Some(PolicyPage {
+            hash,
+            offset,
+            total_entries,
+            entries: entries
+                .into_iter()
+                .skip(offset as usize)
+                .take(limit as usize)
+                .collect(),
+        }))
+    }
+
     async fn handle_provider_data_request(
         &mut self,
         sender: Option<AnonymousSenderTag>,
@@ -147,7 +208,7 @@ impl ServiceProvider<Socks5Request> for NRServiceProvider {
                             .processed(remote_addr, req.data.len() as u32);
                     }
                 }
-                self.handle_proxy_send(req)
+                self.handle_proxy_send(req).await
             }
         }
 
@@ -221,7 +282,11 @@ impl NRServiceProviderBuilder {
             None
         };
 
+        let rate_limiter = SenderRateLimiter::new(self.config.network_requester.rate_limiting);
+
         let stats_collector_clone = stats_collector.clone();
+        let rate_limiter_clone = rate_limiter.clone();
+        let controller_sender_clone = controller_sender.clone();
         let mixnet_client_sender = mixnet_client.sender();
         let self_address = *mixnet_client.nym_address();
 
@@ -231,6 +296,8 @@ impl NRServiceProviderBuilder {
                 mixnet_client_sender,
                 mix_input_receiver,
                 stats_collector_clone,
+                rate_limiter_clone,
+                controller_sender_clone,
             )
             .await;
         });
@@ -257,6 +324,7 @@ impl NRServiceProviderBuilder {
             mix_input_sender,
             //shared_lane_queue_lengths: mixnet_client.shared_lane_queue_lengths(),
             stats_collector,
+            rate_limiter,
             shutdown,
         };
 
@@ -267,6 +335,16 @@ impl NRServiceProviderBuilder {
 }
 
 impl NRServiceProvider {
+    /// Returns all destinations this network requester allows connecting to.
+    /// In the open proxy mode, there are no explicit entries.
+    async fn policy_entries(&self) -> Vec<String> {
+        if self.open_proxy {
+            Vec::new()
+        } else {
+            self.outbound_request_filter.allowed_entries().await
+        }
+    }
+
     async fn run(mut self) -> Result<(), NetworkRequesterError> {
         // TODO: incorporate graceful shutdowns
         while let Some(reconstructed_messages) = self.mixnet_client.wait_for_messages().await {
@@ -300,6 +378,8 @@ impl NRServiceProvider {
         mut mixnet_client_sender: nym_sdk::mixnet::MixnetClientSender,
         mut mix_input_reader: MixProxyReader<MixnetMessage>,
         stats_collector: Option<ServiceStatisticsCollector>,
+        rate_limiter: SenderRateLimiter,
+        controller_sender: ControllerSender,
     ) {
         loop {
             tokio::select! {
@@ -320,8 +400,30 @@ impl NRServiceProvider {
                             }
                         }
 
+                        let connection_id = msg.connection_id;
+                        let rate_limit_result = rate_limiter.consume(connection_id, msg.data_size());
+
                         let response_message = msg.into_input_message();
                         mixnet_client_sender.send_input_message(response_message).await;
+
+                        if let Err((violation, connection)) = rate_limit_result {
+                            // note: we can't push the error through `mix_input_sender` here
+                            // as we're the ones reading from it
+                            if let Some(error_message) = Self::terminate_rate_limited_connection(
+                                connection_id,
+                                violation,
+                                connection,
+                                &rate_limiter,
+                                &controller_sender,
+                                stats_collector.as_ref(),
+                            )
+                            .await
+                            {
+                                mixnet_client_sender
+                                    .send_input_message(error_message.into_input_message())
+                                    .await;
+                            }
+                        }
                     } else {
                         log::error!("Exiting: channel closed!");
                         break;
@@ -331,6 +433,49 @@ impl NRServiceProvider {
         }
     }
 
+    async fn record_rate_limited(
+        stats_collector: Option<&ServiceStatisticsCollector>,
+        violation: RateLimitExceeded,
+    ) {
+        if let Some(stats_collector) = stats_collector {
+            stats_collector
+                .rate_limit_stats_data
+                .write()
+                .await
+                .limited(violation.kind());
+        }
+    }
+
+    /// Closes the connection whose sender went over its limits and constructs the error response
+    /// for the sender. If the connection has already been terminated, nothing is done.
+    async fn terminate_rate_limited_connection(
+        connection_id: ConnectionId,
+        violation: RateLimitExceeded,
+        connection: TrackedConnection,
+        rate_limiter: &SenderRateLimiter,
+        controller_sender: &ControllerSender,
+        stats_collector: Option<&ServiceStatisticsCollector>,
+    ) -> Option<MixnetMessage> {
+        if !rate_limiter.terminate_connection(connection_id) {
+            return None;
+        }
+
+        log::info!("terminating connection {connection_id}: {violation}");
+        Self::record_rate_limited(stats_collector, violation).await;
+
+        // removing the connection from the controller will cause the proxy to shut down
+        controller_sender
+            .unbounded_send(ControllerCommand::Remove { connection_id })
+            .unwrap();
+
+        Some(MixnetMessage::new_connection_error(
+            connection.return_address,
+            connection.request_version,
+            connection_id,
+            violation.to_string(),
+        ))
+    }
+
     #[allow(clippy::too_many_arguments)]
     async fn start_proxy(
         remote_version: RequestVersion<Socks5Request>,
@@ -341,6 +486,7 @@ impl NRServiceProvider {
         controller_sender: ControllerSender,
         mix_input_sender: MixProxySender<MixnetMessage>,
         lane_queue_lengths: LaneQueueLengths,
+        rate_limiter: SenderRateLimiter,
         shutdown: TaskClient,
     ) {
         let mut conn = match socks5::tcp::Connection::new(
@@ -357,6 +503,7 @@ impl NRServiceProvider {
                     remote_addr.clone(),
                     err
                 );
+                rate_limiter.release_connection(connection_id);
 
                 // inform the remote that the connection is closed before it even was established
                 let mixnet_message = MixnetMessage::new_network_data_response(
@@ -403,9 +550,12 @@ impl NRServiceProvider {
         .await;
 
         // proxy is done - remove the access channel from the controller
-        controller_sender
-            .unbounded_send(ControllerCommand::Remove { connection_id })
-            .unwrap();
+        // (unless it has already been removed due to the sender going over its limits)
+        if !rate_limiter.release_connection(connection_id) {
+            controller_sender
+                .unbounded_send(ControllerCommand::Remove { connection_id })
+                .unwrap();
+        }
 
         let old_count = ACTIVE_PROXIES.fetch_sub(1, Ordering::SeqCst);
         log::info!(
@@ -447,6 +597,30 @@ impl NRServiceProvider {
             return;
         }
 
+        let tracked_connection = TrackedConnection {
+            sender: SenderKey::from(&return_address),
+            return_address: return_address.clone(),
+            request_version: remote_version.clone(),
+        };
+        if let Err(violation) = self
+            .rate_limiter
+            .try_open_connection(conn_id, tracked_connection)
+        {
+            log::info!("refusing connection {conn_id} to {remote_addr}: {violation}");
+            Self::record_rate_limited(self.stats_collector.as_ref(), violation).await;
+            let msg = MixnetMessage::new_connection_error(
+                return_address,
+                remote_version,
+                conn_id,
+                violation.to_string(),
+            );
+            self.mix_input_sender
+                .send(msg)
+                .await
+                .expect("InputMessageReceiver has stopped receiving!");
+            return;
+        }
+
         let traffic_config = self.config.base.debug.traffic;
         let packet_size = traffic_config
             .secondary_packet_size
@@ -455,6 +629,7 @@ impl NRServiceProvider {
         let controller_sender_clone = self.controller_sender.clone();
         let mix_input_sender_clone = self.mix_input_sender.clone();
         let lane_queue_lengths_clone = self.mixnet_client.shared_lane_queue_lengths();
+        let rate_limiter_clone = self.rate_limiter.clone();
         let shutdown = self.shutdown.subscribe();
 
         // and start the proxy for this connection
@@ -468,13 +643,36 @@ impl NRServiceProvider {
                 controller_sender_clone,
                 mix_input_sender_clone,
                 lane_queue_lengths_clone,
+                rate_limiter_clone,
                 shutdown,
             )
             .await
         });
     }
 
-    fn handle_proxy_send(&mut self, req: SendRequest) {
+    async fn handle_proxy_send(&mut self, req: SendRequest) {
+        let connection_id = req.conn_id;
+        if let Err((violation, connection)) =
+            self.rate_limiter.consume(connection_id, req.data.len())
+        {
+            if let Some(error_message) = Self::terminate_rate_limited_connection(
+                connection_id,
+                violation,
+                connection,
+                &self.rate_limiter,
+                &self.controller_sender,
+                self.stats_collector.as_ref(),
+            )
+            .await
+            {
+                self.mix_input_sender
+                    .send(error_message)
+                    .await
+                    .expect("InputMessageReceiver has stopped receiving!");
+            }
+            return;
+        }
+
         self.controller_sender.unbounded_send(req.into()).unwrap()
     }
 }
@@ -0,0 +1,407 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::RateLimiting;
+use crate::reply::MixnetAddress;
+use nym_service_providers_common::interface::RequestVersion;
+use nym_socks5_requests::{ConnectionId, Socks5Request};
+use nym_sphinx::addressing::clients::RecipientBytes;
+use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Identifier of a sender against which all the limits are accounted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum SenderKey {
+    Anonymous(AnonymousSenderTag),
+    Known(RecipientBytes),
+}
+
+impl From<&MixnetAddress> for SenderKey {
+    fn from(address: &MixnetAddress) -> Self {
+        match address {
+            MixnetAddress::Known(recipient) => SenderKey::Known(recipient.to_bytes()),
+            MixnetAddress::Anonymous(sender_tag) => SenderKey::Anonymous(*sender_tag),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RateLimitExceeded {
+    ConcurrentConnections { limit: usize },
+    Bandwidth { limit: u64 },
+    WindowQuota { limit: u64, window: Duration },
+}
+
+impl RateLimitExceeded {
+    /// Short, static, identifier of the violated limit used for the statistics reporting.
+    pub(crate) fn kind(&self) -> &'static str {
+        match self {
+            RateLimitExceeded::ConcurrentConnections { .. } => "concurrent_connections",
+            RateLimitExceeded::Bandwidth { .. } => "bandwidth",
+            RateLimitExceeded::WindowQuota { .. } => "window_quota",
+        }
+    }
+}
+
+impl Display for RateLimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RateLimitExceeded::ConcurrentConnections { limit } => write!(
+                f,
+                "rate limited: exceeded the maximum of {limit} concurrent connections"
+            ),
+            RateLimitExceeded::Bandwidth { limit } => {
+                write!(f, "rate limited: exceeded the maximum of {limit} bytes/s")
+            }
+            RateLimitExceeded::WindowQuota { limit, window } => write!(
+                f,
+                "rate limited: exceeded the quota of {limit} bytes per {}",
+                humantime_serde::re::humantime::format_duration(*window)
+            ),
+        }
+    }
+}
+
+/// Information required to terminate a connection of a sender that went over its limits.
+#[derive(Debug, Clone)]
+pub(crate) struct TrackedConnection {
+    pub(crate) sender: SenderKey,
+    pub(crate) return_address: MixnetAddress,
+    pub(crate) request_version: RequestVersion<Socks5Request>,
+}
+
+#[derive(Debug)]
+struct SenderUsage {
+    active_connections: HashSet<ConnectionId>,
+
+    // token bucket for the per second limit. it's allowed to go negative,
+    // in which case the sender is considered to be over its limit until the bucket refills
+    available_bytes: i64,
+    last_refill: Instant,
+
+    window_start: Instant,
+    window_bytes: u64,
+}
+
+impl SenderUsage {
+    fn new(config: &RateLimiting, now: Instant) -> Self {
+        SenderUsage {
+            active_connections: HashSet::new(),
+            available_bytes: config.max_bytes_per_second as i64,
+            last_refill: now,
+            window_start: now,
+            window_bytes: 0,
+        }
+    }
+
+    fn refresh(&mut self, config: &RateLimiting, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        let refill = (elapsed.as_secs_f64() * config.max_bytes_per_second as f64) as i64;
+        if refill > 0 {
+            self.available_bytes =
+                (self.available_bytes + refill).min(config.max_bytes_per_second as i64);
+            self.last_refill = now;
+        }
+
+        if now.saturating_duration_since(self.window_start) >= config.bandwidth_window {
+            self.window_start = now;
+            self.window_bytes = 0;
+        }
+    }
+
+    fn check(&self, config: &RateLimiting) -> Result<(), RateLimitExceeded> {
+        if config.max_bytes_per_second != 0 && self.available_bytes < 0 {
+            return Err(RateLimitExceeded::Bandwidth {
+                limit: config.max_bytes_per_second,
+            });
+        }
+        if config.max_bytes_per_window != 0 && self.window_bytes > config.max_bytes_per_window {
+            return Err(RateLimitExceeded::WindowQuota {
+                limit: config.max_bytes_per_window,
+                window: config.bandwidth_window,
+            });
+        }
+        Ok(())
+    }
+
+    fn is_idle(&self, config: &RateLimiting, now: Instant) -> bool {
+        self.active_connections.is_empty()
+            && now.saturating_duration_since(self.window_start) >= config.bandwidth_window
+    }
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    senders: HashMap<SenderKey, SenderUsage>,
+    connections: HashMap<ConnectionId, TrackedConnection>,
+    terminated: HashSet<ConnectionId>,
+    last_prune: Option<Instant>,
+}
+
+impl RateLimiterState {
+    fn prune(&mut self, config: &RateLimiting, now: Instant) {
+        match self.last_prune {
+            Some(last) if now.saturating_duration_since(last) < config.bandwidth_window => (),
+            _ => {
+                self.senders.retain(|_, usage| !usage.is_idle(config, now));
+                self.last_prune = Some(now);
+            }
+        }
+    }
+}
+
+/// Fair-share limiter keeping track of connections and bandwidth used by each sender
+/// of the network requester.
+#[derive(Debug, Clone)]
+pub(crate) struct SenderRateLimiter {
+    config: RateLimiting,
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+impl SenderRateLimiter {
+    pub(crate) fn new(config: RateLimiting) -> Self {
+        SenderRateLimiter {
+            config,
+            state: Arc::new(Mutex::new(RateLimiterState::default())),
+        }
+    }
+
+    /// Attempts to register a new connection for the provided sender.
+    pub(crate) fn try_open_connection(
+        &self,
+        connection_id: ConnectionId,
+        connection: TrackedConnection,
+    ) -> Result<(), RateLimitExceeded> {
+        self.try_open_connection_at(connection_id, connection, Instant::now())
+    }
+
+    fn try_open_connection_at(
+        &self,
+        connection_id: ConnectionId,
+        connection: TrackedConnection,
+        now: Instant,
+    ) -> Result<(), RateLimitExceeded> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let mut guard = self.state.lock().expect("rate limiter mutex got poisoned");
+        let state = &mut *guard;
+        state.prune(&self.config, now);
+
+        let usage = state
+            .senders
+            .entry(connection.sender)
+            .or_insert_with(|| SenderUsage::new(&self.config, now));
+        usage.refresh(&self.config, now);
+        usage.check(&self.config)?;
+
+        if usage.active_connections.len() >= self.config.max_concurrent_connections {
+            return Err(RateLimitExceeded::ConcurrentConnections {
+                limit: self.config.max_concurrent_connections,
+            });
+        }
+
+        usage.active_connections.insert(connection_id);
+        state.connections.insert(connection_id, connection);
+        Ok(())
+    }
+
+    /// Accounts the provided number of bytes, sent in either direction, against the sender
+    /// owning the connection. If the sender went over any of its limits, the information
+    /// required to terminate the connection is returned alongside the violated limit.
+    pub(crate) fn consume(
+        &self,
+        connection_id: ConnectionId,
+        bytes: usize,
+    ) -> Result<(), (RateLimitExceeded, TrackedConnection)> {
+        self.consume_at(connection_id, bytes, Instant::now())
+    }
+
+    fn consume_at(
+        &self,
+        connection_id: ConnectionId,
+        bytes: usize,
+        now: Instant,
+    ) -> Result<(), (RateLimitExceeded, TrackedConnection)> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let mut guard = self.state.lock().expect("rate limiter mutex got poisoned");
+        let state = &mut *guard;
+
+        // we can't attribute traffic of connections we don't know about
+        // (for example the ones created by the statistics collector)
+        let Some(connection) = state.connections.get(&connection_id) else {
+            return Ok(());
+        };
+        let Some(usage) = state.senders.get_mut(&connection.sender) else {
+            return Ok(());
+        };
+
+        usage.refresh(&self.config, now);
+        usage.available_bytes -= bytes as i64;
+        usage.window_bytes += bytes as u64;
+
+        usage
+            .check(&self.config)
+            .map_err(|violation| (violation, connection.clone()))
+    }
+
+    /// Marks the connection as forcefully terminated due to its sender going over the limits.
+    /// Returns `false` if the connection has already been terminated before.
+    pub(crate) fn terminate_connection(&self, connection_id: ConnectionId) -> bool {
+        self.state
+            .lock()
+            .expect("rate limiter mutex got poisoned")
+            .terminated
+            .insert(connection_id)
+    }
+
+    /// Removes the connection from the set of active connections of its sender.
+    /// Returns `true` if the connection has been previously terminated by the limiter.
+    pub(crate) fn release_connection(&self, connection_id: ConnectionId) -> bool {
+        let mut guard = self.state.lock().expect("rate limiter mutex got poisoned");
+        let state = &mut *guard;
+
+        if let Some(connection) = state.connections.remove(&connection_id) {
+            if let Some(usage) = state.senders.get_mut(&connection.sender) {
+                usage.active_connections.remove(&connection_id);
+            }
+        }
+        state.terminated.remove(&connection_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::new_legacy_request_version;
+
+    fn test_config() -> RateLimiting {
+        RateLimiting {
+            enabled: true,
+            max_concurrent_connections: 2,
+            max_bytes_per_second: 1000,
+            max_bytes_per_window: 10_000,
+            bandwidth_window: Duration::from_secs(60),
+        }
+    }
+
+    fn connection(tag: u8) -> TrackedConnection {
+        let sender_tag = AnonymousSenderTag::from_bytes([tag; 16]);
+        let return_address = MixnetAddress::Anonymous(sender_tag);
+        TrackedConnection {
+            sender: SenderKey::from(&return_address),
+            return_address,
+            request_version: new_legacy_request_version(),
+        }
+    }
+
+    #[test]
+    fn concurrent_connections_are_limited_per_sender() {
+        let limiter = SenderRateLimiter::new(test_config());
+        let now = Instant::now();
+
+        assert!(limiter
+            .try_open_connection_at(1, connection(1), now)
+            .is_ok());
+        assert!(limiter
+            .try_open_connection_at(2, connection(1), now)
+            .is_ok());
+        assert_eq!(
+            limiter.try_open_connection_at(3, connection(1), now),
+            Err(RateLimitExceeded::ConcurrentConnections { limit: 2 })
+        );
+
+        // other senders are unaffected
+        assert!(limiter
+            .try_open_connection_at(4, connection(2), now)
+            .is_ok());
+
+        // and once a connection is closed, a new one can be opened
+        assert!(!limiter.release_connection(1));
+        assert!(limiter
+            .try_open_connection_at(3, connection(1), now)
+            .is_ok());
+    }
+
+    #[test]
+    fn bandwidth_is_limited_and_refills_over_time() {
+        let limiter = SenderRateLimiter::new(test_config());
+        let now = Instant::now();
+
+        limiter
+            .try_open_connection_at(1, connection(1), now)
+            .unwrap();
+        assert!(limiter.consume_at(1, 1000, now).is_ok());
+
+        let (violation, tracked) = limiter.consume_at(1, 1, now).unwrap_err();
+        assert_eq!(violation, RateLimitExceeded::Bandwidth { limit: 1000 });
+        assert_eq!(tracked.sender, connection(1).sender);
+
+        // new connections are also refused while the sender is over its limit
+        assert_eq!(
+            limiter.try_open_connection_at(2, connection(1), now),
+            Err(RateLimitExceeded::Bandwidth { limit: 1000 })
+        );
+
+        let later = now + Duration::from_secs(1);
+        assert!(limiter.consume_at(1, 500, later).is_ok());
+    }
+
+    #[test]
+    fn connections_are_only_terminated_once() {
+        let limiter = SenderRateLimiter::new(test_config());
+        limiter.try_open_connection(1, connection(1)).unwrap();
+
+        assert!(limiter.terminate_connection(1));
+        assert!(!limiter.terminate_connection(1));
+        assert!(limiter.release_connection(1));
+        assert!(!limiter.release_connection(1));
+    }
+
+    #[test]
+    fn window_quota_is_reset_after_window_passes() {
+        let limiter = SenderRateLimiter::new(test_config());
+        let mut now = Instant::now();
+
+        limiter
+            .try_open_connection_at(1, connection(1), now)
+            .unwrap();
+        for _ in 0..10 {
+            assert!(limiter.consume_at(1, 1000, now).is_ok());
+            now += Duration::from_secs(1);
+        }
+        let (violation, _) = limiter.consume_at(1, 1, now).unwrap_err();
+        assert_eq!(
+            violation,
+            RateLimitExceeded::WindowQuota {
+                limit: 10_000,
+                window: Duration::from_secs(60)
+            }
+        );
+
+        now += Duration::from_secs(60);
+        assert!(limiter.consume_at(1, 1000, now).is_ok());
+    }
+
+    #[test]
+    fn unknown_connections_and_disabled_limiter_are_not_limited() {
+        let limiter = SenderRateLimiter::new(test_config());
+        assert!(limiter.consume(42, usize::MAX / 2).is_ok());
+
+        let limiter = SenderRateLimiter::new(RateLimiting {
+            enabled: false,
+            ..test_config()
+        });
+        for conn_id in 0..10 {
+            assert!(limiter.try_open_connection(conn_id, connection(1)).is_ok());
+            assert!(limiter.consume(conn_id, 1_000_000).is_ok());
+        }
+    }
+}
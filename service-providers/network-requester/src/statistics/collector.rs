@@ -17,7 +17,7 @@ use nym_statistics_common::api::{
 };
 use nym_statistics_common::{
     collector::StatisticsCollector, error::StatsError as CommonStatsError, StatsMessage,
-    StatsServiceData,
+    StatsRateLimitData, StatsServiceData,
 };
 use rand::RngCore;
 use serde::Deserialize;
@@ -52,6 +52,23 @@ impl StatsData {
     }
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct RateLimitStatsData {
+    limit_events: HashMap<String, u32>,
+}
+
+impl RateLimitStatsData {
+    pub fn new() -> Self {
+        RateLimitStatsData {
+            limit_events: HashMap::new(),
+        }
+    }
+
+    pub fn limited(&mut self, limit: &str) {
+        *self.limit_events.entry(limit.to_string()).or_default() += 1;
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 pub struct StatsProviderConfigEntry {
     stats_client_address: String,
@@ -77,6 +94,7 @@ pub(crate) struct ServiceStatisticsCollector {
     pub(crate) request_stats_data: Arc<RwLock<StatsData>>,
     pub(crate) response_stats_data: Arc<RwLock<StatsData>>,
     pub(crate) connected_services: Arc<RwLock<HashMap<ConnectionId, RemoteAddress>>>,
+    pub(crate) rate_limit_stats_data: Arc<RwLock<RateLimitStatsData>>,
     stats_provider_addr: Recipient,
     mix_input_sender: MixProxySender<MixnetMessage>,
     request_version: RequestVersion<Socks5Request>,
@@ -109,6 +127,7 @@ impl ServiceStatisticsCollector {
             request_stats_data: Arc::new(RwLock::new(StatsData::new())),
             response_stats_data: Arc::new(RwLock::new(StatsData::new())),
             connected_services: Arc::new(RwLock::new(HashMap::new())),
+            rate_limit_stats_data: Arc::new(RwLock::new(RateLimitStatsData::new())),
             stats_provider_addr,
             mix_input_sender,
             // for now always use legacy serialization since we'll never be sending control
@@ -125,7 +144,7 @@ impl StatisticsCollector for ServiceStatisticsCollector {
         interval: Duration,
         timestamp: DateTime<Utc>,
     ) -> StatsMessage {
-        let stats_data = {
+        let mut stats_data: Vec<_> = {
             let request_data_bytes = self.request_stats_data.read().await;
             let response_data_bytes = self.response_stats_data.read().await;
             let services: HashSet<String> = request_data_bytes
@@ -155,6 +174,19 @@ impl StatisticsCollector for ServiceStatisticsCollector {
                 })
                 .collect()
         };
+        stats_data.extend(
+            self.rate_limit_stats_data
+                .read()
+                .await
+                .limit_events
+                .iter()
+                .map(|(limit, events)| {
+                    nym_statistics_common::StatsData::RateLimit(StatsRateLimitData::new(
+                        limit.clone(),
+                        *events,
+                    ))
+                }),
+        );
 
         StatsMessage {
             stats_data,
@@ -215,5 +247,6 @@ impl StatisticsCollector for ServiceStatisticsCollector {
             .write()
             .await
             .client_processed_bytes = HashMap::new();
+        self.rate_limit_stats_data.write().await.limit_events = HashMap::new();
     }
 }
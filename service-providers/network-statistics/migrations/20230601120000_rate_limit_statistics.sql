@@ -0,0 +1,13 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE rate_limit_statistics
+(
+    id                         INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    rate_limit                 VARCHAR NOT NULL,
+    events                     INTEGER NOT NULL,
+    interval_seconds           INTEGER NOT NULL,
+    timestamp                  DATETIME NOT NULL
+);
@@ -22,6 +22,7 @@ pub struct StatisticsRequest {
 pub enum GenericStatistic {
     Service(ServiceStatistic),
     Gateway(GatewayStatistic),
+    RateLimit(RateLimitStatistic),
 }
 
 #[derive(Clone, Serialize, Deserialize, Debug)]
@@ -40,6 +41,14 @@ pub struct GatewayStatistic {
     pub timestamp: String,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct RateLimitStatistic {
+    pub rate_limit: String,
+    pub events: u32,
+    pub interval_seconds: u32,
+    pub timestamp: String,
+}
+
 #[rocket::post("/all-statistics", data = "<all_statistics_request>")]
 pub(crate) async fn post_all_statistics(
     all_statistics_request: Json<StatisticsRequest>,
@@ -77,6 +86,23 @@ pub(crate) async fn post_all_statistics(
                     })
                 }),
         )
+        .chain(
+            storage
+                .get_rate_limit_statistics_in_interval(
+                    &all_statistics_request.since,
+                    &all_statistics_request.until,
+                )
+                .await?
+                .into_iter()
+                .map(|data| {
+                    GenericStatistic::RateLimit(RateLimitStatistic {
+                        rate_limit: data.rate_limit,
+                        events: data.events as u32,
+                        interval_seconds: data.interval_seconds as u32,
+                        timestamp: data.timestamp.to_string(),
+                    })
+                }),
+        )
         .collect();
 
     Ok(Json(all_statistics))
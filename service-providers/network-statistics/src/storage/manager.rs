@@ -3,7 +3,7 @@
 
 use sqlx::types::chrono::{DateTime, Utc};
 
-use crate::storage::models::{GatewayStatistics, ServiceStatistics};
+use crate::storage::models::{GatewayStatistics, RateLimitStatistics, ServiceStatistics};
 
 #[derive(Clone)]
 pub(crate) struct StorageManager {
@@ -68,6 +68,34 @@ impl StorageManager {
         Ok(())
     }
 
+    /// Adds an entry for rate limiting statistical data.
+    ///
+    /// # Arguments
+    ///
+    /// * `rate_limit`: Identifier of the limit that got exceeded.
+    /// * `events`: Number of times the limit got exceeded.
+    /// * `interval_seconds`: Duration in seconds in which the data was gathered.
+    /// * `timestamp`: The moment in time when the data started being collected.
+    pub(super) async fn insert_rate_limit_statistics(
+        &self,
+        rate_limit: String,
+        events: u32,
+        interval_seconds: u32,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO rate_limit_statistics(rate_limit, events, interval_seconds, timestamp) VALUES (?, ?, ?, ?)",
+            rate_limit,
+            events,
+            interval_seconds,
+            timestamp,
+        )
+        .execute(&self.connection_pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Returns service statistical data submitted within the provided time interval.
     ///
     /// # Arguments
@@ -109,4 +137,25 @@ impl StorageManager {
         .fetch_all(&self.connection_pool)
         .await
     }
+
+    /// Returns rate limiting statistical data submitted within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: indicates the lower bound timestamp for the data
+    /// * `until`: indicates the upper bound timestamp for the data
+    pub(super) async fn get_rate_limit_statistics_in_interval(
+        &self,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<RateLimitStatistics>, sqlx::Error> {
+        sqlx::query_as!(
+            RateLimitStatistics,
+            "SELECT * FROM rate_limit_statistics WHERE timestamp BETWEEN ? AND ?",
+            since,
+            until
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
 }
@@ -10,7 +10,7 @@ use nym_statistics_common::StatsMessage;
 
 use crate::storage::error::NetworkStatisticsStorageError;
 use crate::storage::manager::StorageManager;
-use crate::storage::models::{GatewayStatistics, ServiceStatistics};
+use crate::storage::models::{GatewayStatistics, RateLimitStatistics, ServiceStatistics};
 
 pub(crate) mod error;
 mod manager;
@@ -78,6 +78,16 @@ impl NetworkStatisticsStorage {
                         )
                         .await?
                 }
+                nym_statistics_common::StatsData::RateLimit(rate_limit_data) => {
+                    self.manager
+                        .insert_rate_limit_statistics(
+                            rate_limit_data.limit,
+                            rate_limit_data.events,
+                            msg.interval_seconds,
+                            timestamp,
+                        )
+                        .await?
+                }
             }
         }
 
@@ -129,4 +139,27 @@ impl NetworkStatisticsStorage {
             .get_gateway_statistics_in_interval(since, until)
             .await?)
     }
+
+    /// Returns rate limiting data submitted within the provided time interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: indicates the lower bound timestamp for the data, RFC 3339 format
+    /// * `until`: indicates the upper bound timestamp for the data, RFC 3339 format
+    pub(super) async fn get_rate_limit_statistics_in_interval(
+        &self,
+        since: &str,
+        until: &str,
+    ) -> Result<Vec<RateLimitStatistics>, NetworkStatisticsStorageError> {
+        let since = DateTime::parse_from_rfc3339(since)
+            .map_err(|_| NetworkStatisticsStorageError::TimestampParse)?
+            .into();
+        let until = DateTime::parse_from_rfc3339(until)
+            .map_err(|_| NetworkStatisticsStorageError::TimestampParse)?
+            .into();
+        Ok(self
+            .manager
+            .get_rate_limit_statistics_in_interval(since, until)
+            .await?)
+    }
 }
@@ -21,3 +21,12 @@ pub(crate) struct GatewayStatistics {
     pub(crate) inbox_count: i64,
     pub(crate) timestamp: NaiveDateTime,
 }
+
+pub(crate) struct RateLimitStatistics {
+    #[allow(dead_code)]
+    pub(crate) id: i64,
+    pub(crate) rate_limit: String,
+    pub(crate) events: i64,
+    pub(crate) interval_seconds: i64,
+    pub(crate) timestamp: NaiveDateTime,
+}
@@ -29,6 +29,12 @@ pub(crate) async fn execute(
         nym_cli_commands::validator::mixnet::delegators::MixnetDelegatorsCommands::UndelegateVesting(args) => {
             nym_cli_commands::validator::mixnet::delegators::vesting_undelegate_from_mixnode::vesting_undelegate_from_mixnode(args, create_signing_client(global_args, network_details)?).await
         }
+        nym_cli_commands::validator::mixnet::delegators::MixnetDelegatorsCommands::Redelegate(args) => {
+            nym_cli_commands::validator::mixnet::delegators::redelegate::redelegate(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::delegators::MixnetDelegatorsCommands::RedelegateVesting(args) => {
+            nym_cli_commands::validator::mixnet::delegators::vesting_redelegate::vesting_redelegate(args, create_signing_client(global_args, network_details)?).await
+        }
         nym_cli_commands::validator::mixnet::delegators::MixnetDelegatorsCommands::List(args) => {
             nym_cli_commands::validator::mixnet::delegators::query_for_delegations::execute(args, create_signing_client_with_nym_api(global_args, network_details)?).await
         }
@@ -31,6 +31,18 @@ pub(crate) async fn execute(
             nym_cli_commands::validator::mixnet::operators::gateway::vesting_unbond_gateway::vesting_unbond_gateway(create_signing_client(global_args, network_details)?).await
 
         }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::ProposeTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::propose_gateway_transfer::propose_gateway_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::AcceptTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::accept_gateway_transfer::accept_gateway_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::CancelTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::cancel_gateway_transfer::cancel_gateway_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::CreateGatewayTransferSignPayload(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::gateway_transfer_sign_payload::create_payload(args, create_signing_client(global_args, network_details)?).await
+        }
     }
     Ok(())
 }
@@ -48,6 +48,18 @@ pub(crate) async fn execute(
         nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::DecreasePledgeVesting(args) => {
             nym_cli_commands::validator::mixnet::operators::mixnode::vesting_decrease_pledge::vesting_decrease_pledge(args, create_signing_client(global_args, network_details)?).await
         }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::ProposeTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::propose_mixnode_transfer::propose_mixnode_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::AcceptTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::accept_mixnode_transfer::accept_mixnode_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::CancelTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::cancel_mixnode_transfer::cancel_mixnode_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::CreateMixnodeTransferSignPayload(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::mixnode_transfer_sign_payload::create_payload(args, create_signing_client(global_args, network_details)?).await
+        }
         _ => unreachable!(),
     }
     Ok(())
@@ -23,6 +23,54 @@ pub(crate) async fn execute(
             };
             res
         },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Renew(args) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::renew::renew(args, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully renewed the name"),
+                Err(_) => println!("Failed to renew name")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::UpdateAddress(args) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::update_address::update_address(args, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully updated the address of the name"),
+                Err(_) => println!("Failed to update the address of the name")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Transfer(args) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::transfer::transfer(args, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully proposed the name transfer"),
+                Err(_) => println!("Failed to propose name transfer")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::AcceptTransfer(args) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::accept_transfer::accept_transfer(args, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully accepted the name transfer"),
+                Err(_) => println!("Failed to accept name transfer")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::CancelTransfer(args) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::cancel_transfer::cancel_transfer(args, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully cancelled the name transfer"),
+                Err(_) => println!("Failed to cancel name transfer")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::CreateNameRegisterSignPayload(args) => {
+            nym_cli_commands::validator::mixnet::operators::name::register_sign_payload::create_payload(args, create_signing_client(global_args, network_details)?).await;
+            Ok(())
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::CreateNameUpdateAddressSignPayload(args) => {
+            nym_cli_commands::validator::mixnet::operators::name::update_address_sign_payload::create_payload(args, create_signing_client(global_args, network_details)?).await;
+            Ok(())
+        },
     };
     Ok(res?)
 }